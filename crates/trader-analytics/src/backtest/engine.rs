@@ -6,6 +6,9 @@
 //!
 //! - **전략 시뮬레이션**: 과거 시장 데이터로 전략의 신호 생성 및 실행
 //! - **주문 체결 시뮬레이션**: 슬리피지, 수수료 등 현실적인 체결 모델
+//! - **체결 시점 정책**: 당일 종가 / 다음 봉 시가 / 다음 봉 VWAP 근사 ([`FillPolicy`])
+//! - **대기 주문**: 신호의 손절/익절가를 매칭 엔진에 걸어두고 이후 봉의 고가/저가로 체결 (갭 반영)
+//! - **포지션 사이징**: 고정 비율 또는 변동성 타깃/역변동성/리스크 패리티 ([`PositionSizingConfig`])
 //! - **성과 분석**: PerformanceTracker와 통합된 상세한 성과 지표
 //! - **자산 곡선**: 시간에 따른 자산 가치 변화 추적
 //!
@@ -39,16 +42,20 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
-use trader_core::{
-    unrealized_pnl, Kline, MarketData, Side, Signal, SignalMarker, SignalType, StrategyContext, Trade, Timeframe,
-};
-use uuid::Uuid;
+use trader_core::{Kline, MarketData, Signal, SignalMarker, StrategyContext, Timeframe};
+use trader_strategy::strategies::common::position_sizing::PositionSizingConfig;
+use trader_strategy::strategies::common::rebalance::RebalanceConfig;
+use trader_strategy::{StrategyScheduler, TradingCalendar, WeekdayCalendar};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use crate::StructuralFeaturesCalculator;
 
-use crate::backtest::slippage::SlippageModel;
-use crate::performance::{EquityPoint, PerformanceMetrics, PerformanceTracker, RoundTrip};
+use crate::backtest::execution::{FillSimulator, FillSizing};
+use crate::backtest::slippage::{SlippageModel, SlippageRecord};
+use crate::performance::{
+    BenchmarkMetrics, BenchmarkSeries, EquityPoint, PerformanceMetrics, PerformanceTracker,
+    RoundTrip,
+};
 
 /// 백테스트 오류
 #[derive(Debug, Error)]
@@ -80,6 +87,35 @@ pub enum BacktestError {
 /// 백테스트 결과 타입
 pub type BacktestResult<T> = Result<T, BacktestError>;
 
+/// 신호 체결 시점 정책
+///
+/// 전략은 캔들 완성 후 신호를 생성하므로, 같은 캔들 종가로 체결하면
+/// 실제로는 불가능한 가격에 체결되는 낙관적 편향이 생길 수 있습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FillPolicy {
+    /// 신호가 발생한 캔들의 종가로 즉시 체결 (기존 동작)
+    #[default]
+    SameClose,
+    /// 다음 캔들의 시가로 체결
+    NextOpen,
+    /// 다음 캔들의 VWAP 근사치 (고가+저가+종가)/3 로 체결
+    NextBarVwap,
+}
+
+impl FillPolicy {
+    /// 다음 캔들에서의 체결 기준 가격을 반환합니다.
+    ///
+    /// `SameClose`는 해당 캔들의 종가를 반환합니다.
+    pub fn fill_price(&self, kline: &Kline) -> Decimal {
+        match self {
+            FillPolicy::SameClose => kline.close,
+            FillPolicy::NextOpen => kline.open,
+            FillPolicy::NextBarVwap => (kline.high + kline.low + kline.close) / Decimal::from(3),
+        }
+    }
+}
+
 /// 백테스트 설정
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BacktestConfig {
@@ -101,6 +137,7 @@ pub struct BacktestConfig {
     ///
     /// 설정되면 slippage_rate 대신 이 모델을 사용합니다.
     /// Fixed, Linear, VolatilityBased, Tiered 모델 지원.
+    /// 체결 캔들의 거래량과 심볼별 ATR(14)이 모델 입력으로 전달됩니다.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub slippage_model: Option<SlippageModel>,
 
@@ -109,9 +146,19 @@ pub struct BacktestConfig {
     pub max_positions: usize,
 
     /// 포지션당 최대 자본 비율 (예: 0.1 = 10%)
+    ///
+    /// 고정 비율 사이징의 진입 비율이며, 변동성 기반 사이징에서 비중을 구할 수
+    /// 없을 때의 대체 비율입니다.
     #[serde(default = "default_max_position_size_pct")]
     pub max_position_size_pct: Decimal,
 
+    /// 진입 포지션 사이징 방식
+    ///
+    /// 고정 비율 외 방식은 심볼별 최근 캔들로 목표 비중을 구해
+    /// `자산 × 비중 × 신호 강도`만큼 진입합니다.
+    #[serde(default)]
+    pub position_sizing: PositionSizingConfig,
+
     /// 무위험 이자율 (연율화 계산용)
     #[serde(default = "default_risk_free_rate")]
     pub risk_free_rate: f64,
@@ -131,6 +178,30 @@ pub struct BacktestConfig {
     /// 숏 포지션 허용 여부
     #[serde(default)]
     pub allow_short: bool,
+
+    /// 신호 체결 시점 정책
+    #[serde(default)]
+    pub fill_policy: FillPolicy,
+
+    /// 워밍업 캔들 수
+    ///
+    /// 앞쪽 N개 캔들은 전략/지표 준비에만 사용하고 신호는 무시합니다.
+    /// 자산 곡선과 성과 지표는 워밍업 이후 구간만 반영합니다.
+    #[serde(default)]
+    pub warmup_bars: usize,
+
+    /// 벤치마크 가격 시계열 (Optional)
+    ///
+    /// 설정되면 리포트에 벤치마크 자산 곡선과 알파/베타 등 상대 지표가 추가됩니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkSeries>,
+
+    /// 목표 포트폴리오(`SignalType::Rebalance`) 신호 처리 설정
+    ///
+    /// 리밸런싱 임계값, 최소 주문 금액, 매도세를 적용합니다.
+    /// 수수료율은 백테스트 수수료 + 슬리피지보다 낮게 잡히지 않습니다.
+    #[serde(default)]
+    pub rebalance: RebalanceConfig,
 }
 
 // 설정 기본값 함수들 (serde default용)
@@ -165,11 +236,16 @@ impl Default for BacktestConfig {
             slippage_model: None,
             max_positions: default_max_positions(),
             max_position_size_pct: default_max_position_size_pct(),
+            position_sizing: PositionSizingConfig::default(),
             risk_free_rate: default_risk_free_rate(),
             exchange_name: default_exchange_name(),
             use_tick_simulation: false,
             allow_margin: false,
             allow_short: false,
+            fill_policy: FillPolicy::default(),
+            warmup_bars: 0,
+            benchmark: None,
+            rebalance: RebalanceConfig::default(),
         }
     }
 }
@@ -234,6 +310,12 @@ impl BacktestConfig {
         self
     }
 
+    /// 포지션 사이징 방식 설정
+    pub fn with_position_sizing(mut self, sizing: PositionSizingConfig) -> Self {
+        self.position_sizing = sizing;
+        self
+    }
+
     /// 무위험 이자율 설정
     pub fn with_risk_free_rate(mut self, rate: f64) -> Self {
         self.risk_free_rate = rate;
@@ -246,6 +328,50 @@ impl BacktestConfig {
         self
     }
 
+    /// 신호 체결 시점 정책 설정
+    pub fn with_fill_policy(mut self, policy: FillPolicy) -> Self {
+        self.fill_policy = policy;
+        self
+    }
+
+    /// 워밍업 캔들 수 설정
+    pub fn with_warmup_bars(mut self, bars: usize) -> Self {
+        self.warmup_bars = bars;
+        self
+    }
+
+    /// 목표 포트폴리오 리밸런싱 설정
+    pub fn with_rebalance_config(mut self, config: RebalanceConfig) -> Self {
+        self.rebalance = config;
+        self
+    }
+
+    /// 벤치마크 가격 시계열 설정 (예: "069500", "SPY", "BTCUSDT")
+    pub fn with_benchmark(mut self, ticker: impl Into<String>, klines: Vec<Kline>) -> Self {
+        self.benchmark = Some(BenchmarkSeries::new(ticker, klines));
+        self
+    }
+
+    /// 벤치마크가 설정되어 있으면 정렬된 벤치마크 자산 곡선을 만들고
+    /// `metrics.benchmark`에 상대 지표를 채웁니다.
+    pub fn apply_benchmark(
+        &self,
+        metrics: &mut PerformanceMetrics,
+        equity_curve: &[EquityPoint],
+    ) -> Vec<EquityPoint> {
+        let Some(benchmark) = &self.benchmark else {
+            return Vec::new();
+        };
+        let curve = benchmark.aligned_curve(equity_curve, self.initial_capital);
+        metrics.benchmark = BenchmarkMetrics::calculate(
+            &benchmark.ticker,
+            equity_curve,
+            &curve,
+            self.risk_free_rate,
+        );
+        curve
+    }
+
     /// 설정 검증
     pub fn validate(&self) -> BacktestResult<()> {
         if self.initial_capital <= Decimal::ZERO {
@@ -267,27 +393,6 @@ impl BacktestConfig {
     }
 }
 
-/// 시뮬레이션된 포지션
-#[derive(Debug, Clone)]
-struct SimulatedPosition {
-    /// 심볼
-    symbol: String,
-    /// 방향
-    side: Side,
-    /// 수량
-    quantity: Decimal,
-    /// 평균 진입가
-    entry_price: Decimal,
-    /// 총 수수료 (나중에 비용 계산에 사용 예정)
-    #[allow(dead_code)]
-    fees: Decimal,
-    /// 진입 시각 (보고서 생성에 사용 예정)
-    #[allow(dead_code)]
-    entry_time: DateTime<Utc>,
-    /// 전략 ID
-    strategy_id: String,
-}
-
 /// 백테스트 실행 리포트
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BacktestReport {
@@ -326,6 +431,14 @@ pub struct BacktestReport {
 
     /// 신호 마커 (차트 표시 및 분석용)
     pub signal_markers: Vec<SignalMarker>,
+
+    /// 체결별 슬리피지 기록
+    #[serde(default)]
+    pub slippage_records: Vec<SlippageRecord>,
+
+    /// 벤치마크 자산 곡선 (`equity_curve`와 같은 시점, 벤치마크 설정 시)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub benchmark_curve: Vec<EquityPoint>,
 }
 
 impl BacktestReport {
@@ -386,32 +499,20 @@ pub struct BacktestEngine {
     /// 설정
     config: BacktestConfig,
 
-    /// 현재 잔고
-    balance: Decimal,
-
-    /// 시뮬레이션 포지션
-    positions: HashMap<String, SimulatedPosition>,
+    /// 체결 및 포지션 시뮬레이터 (잔고, 포지션, 대기 주문)
+    sim: FillSimulator<()>,
 
     /// 성과 추적기
     tracker: PerformanceTracker,
 
-    /// 총 수수료
-    total_commission: Decimal,
-
-    /// 총 슬리피지
-    total_slippage: Decimal,
-
-    /// 총 주문 수
-    total_orders: usize,
-
-    /// 현재 시뮬레이션 시각
-    current_time: DateTime<Utc>,
-
-    /// 현재 가격 (심볼별)
-    current_prices: HashMap<String, Decimal>,
-
     /// 신호 마커 (차트 표시 및 분석용)
     signal_markers: Vec<SignalMarker>,
+
+    /// 심볼별 최근 완성 캔들 (변동성 기반 포지션 사이징 입력)
+    sizing_history: HashMap<String, Vec<Kline>>,
+
+    /// 스케줄 트리거용 거래일 캘린더
+    calendar: Arc<dyn TradingCalendar>,
 }
 
 impl BacktestEngine {
@@ -423,19 +524,59 @@ impl BacktestEngine {
             .without_equity_history_limit();
 
         Self {
-            balance: config.initial_capital,
+            sim: FillSimulator::new(config.clone(), Utc::now()),
             config,
-            positions: HashMap::new(),
             tracker,
-            total_commission: Decimal::ZERO,
-            total_slippage: Decimal::ZERO,
-            total_orders: 0,
-            current_time: Utc::now(),
-            current_prices: HashMap::new(),
             signal_markers: Vec::new(),
+            sizing_history: HashMap::new(),
+            calendar: Arc::new(WeekdayCalendar::default()),
         }
     }
 
+    /// 스케줄 트리거에 사용할 거래일 캘린더를 설정합니다 (기본값: 주말만 휴장).
+    pub fn with_calendar(mut self, calendar: Arc<dyn TradingCalendar>) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 전략의 스케줄 트리거를 등록한 시뮬레이션 시간 스케줄러를 생성합니다.
+    fn scheduler_for<S>(&self, strategy: &S, start: DateTime<Utc>) -> StrategyScheduler
+    where
+        S: trader_strategy::Strategy + ?Sized,
+    {
+        let mut scheduler = StrategyScheduler::new(Arc::clone(&self.calendar));
+        scheduler.register(strategy.name(), strategy.schedule_triggers(), start);
+        scheduler
+    }
+
+    /// 캔들 완성 시각까지 도래한 스케줄 트리거를 전략에 전달합니다.
+    ///
+    /// 실시간 엔진과 같은 트리거가 시뮬레이션 시간 기준으로 발생하며,
+    /// 워밍업 구간에서는 전략 상태만 갱신하고 신호는 버립니다.
+    async fn fire_schedule<S>(
+        &mut self,
+        strategy: &mut S,
+        scheduler: &mut StrategyScheduler,
+        kline: &Kline,
+        warming_up: bool,
+    ) -> BacktestResult<()>
+    where
+        S: trader_strategy::Strategy + ?Sized,
+    {
+        for event in scheduler.advance(kline.close_time).await {
+            let signals = strategy
+                .on_schedule(&event)
+                .await
+                .map_err(|e| BacktestError::StrategyError(e.to_string()))?;
+            if !warming_up {
+                for signal in signals {
+                    self.process_signal(&signal, kline)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// 캔들 데이터로 백테스트를 실행합니다.
     ///
     /// # 매개변수
@@ -472,21 +613,37 @@ impl BacktestEngine {
             }
         }
 
-        let start_time = klines.first().unwrap().open_time;
+        let warmup = self.config.warmup_bars;
+        if warmup >= klines.len() {
+            return Err(BacktestError::DataError(format!(
+                "워밍업 캔들 수({})가 데이터 길이({}) 이상입니다",
+                warmup,
+                klines.len()
+            )));
+        }
+
+        let start_time = klines[warmup].open_time;
         let end_time = klines.last().unwrap().close_time;
-        let data_points = klines.len();
+        let data_points = klines.len() - warmup;
 
         // 백테스트 시작 시간으로 equity curve 초기 timestamp 설정
         // (Utc::now() 대신 실제 백테스트 시작 시간 사용)
         self.tracker.set_initial_timestamp(start_time);
+        let mut scheduler = self.scheduler_for(strategy, klines[0].open_time);
 
         // 각 캔들에 대해 시뮬레이션
         // 중요: Look-Ahead Bias 방지를 위해 캔들 완성 후 신호 생성
-        for kline in klines {
+        for (idx, kline) in klines.iter().enumerate() {
+            // 워밍업 구간: 전략 상태만 갱신하고 신호는 버림
+            let warming_up = idx < warmup;
+
+            // 캔들 시작 시점: 대기 신호 및 손절/익절 주문 체결
+            if !warming_up {
+                self.on_bar_open(kline)?;
+            }
+
             // 캔들 완성 시점으로 현재 시간 설정 (데이터 누수 방지)
-            self.current_time = kline.close_time;
-            self.current_prices
-                .insert(kline.ticker.to_string(), kline.close);
+            self.sim.on_bar_close(kline);
 
             // 시장 데이터 생성 (완성된 캔들 정보 사용)
             let market_data = MarketData::from_kline(&self.config.exchange_name, kline.clone());
@@ -497,36 +654,52 @@ impl BacktestEngine {
                 .await
                 .map_err(|e| BacktestError::StrategyError(e.to_string()))?;
 
-            // 신호 처리 (다음 틱에서 체결된다고 가정)
-            for signal in signals {
-                self.process_signal(&signal, kline).await?;
+            // 캔들 완성 시각까지 도래한 스케줄 트리거
+            self.fire_schedule(strategy, &mut scheduler, kline, warming_up)
+                .await?;
+
+            if !warming_up {
+                // 신호 처리 (FillPolicy에 따라 즉시 또는 다음 캔들에서 체결)
+                for signal in signals {
+                    self.process_signal(&signal, kline)?;
+                }
+
+                // 미실현 손익 반영하여 자산 업데이트
+                let equity = self.calculate_equity();
+                self.tracker.update_equity(kline.close_time, equity);
             }
 
-            // 미실현 손익 반영하여 자산 업데이트
-            let equity = self.calculate_equity(kline);
-            self.tracker.update_equity(kline.close_time, equity);
+            // 완성된 캔들로 ATR과 사이징 이력 갱신 (다음 체결용)
+            self.record_completed_kline(kline);
         }
 
         // 미청산 포지션 강제 청산
-        self.close_all_positions(klines.last().unwrap()).await?;
+        self.close_all_positions(klines.last().unwrap())?;
 
         // 심볼별 성과 계산
         let performance_by_symbol = self.calculate_performance_by_symbol();
 
+        // 벤치마크 대비 지표
+        let equity_curve = self.tracker.get_equity_curve().to_vec();
+        let mut metrics = self.tracker.get_metrics();
+        let benchmark_curve = self.config.apply_benchmark(&mut metrics, &equity_curve);
+
         // 결과 생성
         Ok(BacktestReport {
             config: self.config.clone(),
-            metrics: self.tracker.get_metrics(),
+            metrics,
             trades: self.tracker.get_round_trips().to_vec(),
-            equity_curve: self.tracker.get_equity_curve().to_vec(),
-            total_orders: self.total_orders,
-            total_commission: self.total_commission,
-            total_slippage: self.total_slippage,
+            equity_curve,
+            total_orders: self.sim.total_orders,
+            total_commission: self.sim.total_commission,
+            total_slippage: self.sim.total_slippage,
             start_time,
             end_time,
             data_points,
             performance_by_symbol,
             signal_markers: self.signal_markers.clone(),
+            slippage_records: self.sim.slippage_records.clone(),
+            benchmark_curve,
         })
     }
 
@@ -569,21 +742,38 @@ impl BacktestEngine {
             }
         }
 
-        let start_time = klines.first().unwrap().open_time;
+        let warmup = self.config.warmup_bars;
+        if warmup >= klines.len() {
+            return Err(BacktestError::DataError(format!(
+                "워밍업 캔들 수({})가 데이터 길이({}) 이상입니다",
+                warmup,
+                klines.len()
+            )));
+        }
+
+        let start_time = klines[warmup].open_time;
         let end_time = klines.last().unwrap().close_time;
-        let data_points = klines.len();
+        let data_points = klines.len() - warmup;
 
         // 백테스트 시작 시간으로 equity curve 초기 timestamp 설정
         self.tracker.set_initial_timestamp(start_time);
 
+        let mut scheduler = self.scheduler_for(strategy, klines[0].open_time);
+
         // 최소 지표 계산에 필요한 캔들 수
         const MIN_CANDLES_FOR_INDICATORS: usize = 20;
 
         // 각 캔들에 대해 시뮬레이션
         for (idx, kline) in klines.iter().enumerate() {
-            self.current_time = kline.close_time;
-            self.current_prices
-                .insert(kline.ticker.to_string(), kline.close);
+            // 워밍업 구간: 전략 상태와 지표만 갱신하고 신호는 버림
+            let warming_up = idx < warmup;
+
+            // 캔들 시작 시점: 대기 신호 및 손절/익절 주문 체결
+            if !warming_up {
+                self.on_bar_open(kline)?;
+            }
+
+            self.sim.on_bar_close(kline);
 
             // 현재 시점까지의 캔들로 StructuralFeatures 계산 및 업데이트
             if idx >= MIN_CANDLES_FOR_INDICATORS {
@@ -607,41 +797,57 @@ impl BacktestEngine {
                 .await
                 .map_err(|e| BacktestError::StrategyError(e.to_string()))?;
 
-            // 신호 처리
-            for signal in signals {
-                self.process_signal(&signal, kline).await?;
+            // 캔들 완성 시각까지 도래한 스케줄 트리거
+            self.fire_schedule(strategy, &mut scheduler, kline, warming_up)
+                .await?;
+
+            if !warming_up {
+                // 신호 처리
+                for signal in signals {
+                    self.process_signal(&signal, kline)?;
+                }
+
+                // 미실현 손익 반영하여 자산 업데이트
+                let equity = self.calculate_equity();
+                self.tracker.update_equity(kline.close_time, equity);
             }
 
-            // 미실현 손익 반영하여 자산 업데이트
-            let equity = self.calculate_equity(kline);
-            self.tracker.update_equity(kline.close_time, equity);
+            // 완성된 캔들로 ATR과 사이징 이력 갱신 (다음 체결용)
+            self.record_completed_kline(kline);
         }
 
         // 미청산 포지션 강제 청산
-        self.close_all_positions(klines.last().unwrap()).await?;
+        self.close_all_positions(klines.last().unwrap())?;
 
         // 심볼별 성과 계산
         let performance_by_symbol = self.calculate_performance_by_symbol();
 
+        // 벤치마크 대비 지표
+        let equity_curve = self.tracker.get_equity_curve().to_vec();
+        let mut metrics = self.tracker.get_metrics();
+        let benchmark_curve = self.config.apply_benchmark(&mut metrics, &equity_curve);
+
         // 결과 생성
         Ok(BacktestReport {
             config: self.config.clone(),
-            metrics: self.tracker.get_metrics(),
+            metrics,
             trades: self.tracker.get_round_trips().to_vec(),
-            equity_curve: self.tracker.get_equity_curve().to_vec(),
-            total_orders: self.total_orders,
-            total_commission: self.total_commission,
-            total_slippage: self.total_slippage,
+            equity_curve,
+            total_orders: self.sim.total_orders,
+            total_commission: self.sim.total_commission,
+            total_slippage: self.sim.total_slippage,
             start_time,
             end_time,
             data_points,
             performance_by_symbol,
             signal_markers: self.signal_markers.clone(),
+            slippage_records: self.sim.slippage_records.clone(),
+            benchmark_curve,
         })
     }
 
     /// 신호를 처리합니다.
-    async fn process_signal(&mut self, signal: &Signal, kline: &Kline) -> BacktestResult<()> {
+    fn process_signal(&mut self, signal: &Signal, kline: &Kline) -> BacktestResult<()> {
         // 실행 가격 결정 (signal.suggested_price 또는 kline.close)
         let price = signal.suggested_price.unwrap_or(kline.close);
 
@@ -654,250 +860,61 @@ impl BacktestEngine {
         );
         self.signal_markers.push(marker);
 
-        match signal.signal_type {
-            SignalType::Entry | SignalType::AddToPosition => {
-                // 숏 포지션 확인
-                if signal.side == Side::Sell && !self.config.allow_short {
-                    return Ok(()); // 숏 비허용 시 무시
-                }
-
-                self.open_position(signal, kline).await?;
-            }
-            SignalType::Exit | SignalType::ReducePosition => {
-                self.close_position(signal, kline).await?;
-            }
-            SignalType::Scale => {
-                // 스케일 신호는 현재 포지션에 따라 처리
-                let key = signal.ticker.clone();
-                if self.positions.contains_key(&key) {
-                    self.close_position(signal, kline).await?;
-                } else {
-                    self.open_position(signal, kline).await?;
-                }
-            }
-            SignalType::Alert => {
-                // Alert는 실행하지 않고 무시 (SignalMarker에만 기록됨)
-            }
-        }
-
-        Ok(())
-    }
-
-    /// 포지션을 오픈합니다.
-    async fn open_position(&mut self, signal: &Signal, kline: &Kline) -> BacktestResult<()> {
-        let key = signal.ticker.clone();
-
-        // 최대 포지션 수 확인
-        if self.positions.len() >= self.config.max_positions {
-            return Ok(()); // 무시
-        }
-
-        // 이미 포지션이 있으면 무시 (간단한 구현)
-        if self.positions.contains_key(&key) {
-            return Ok(());
-        }
-
-        // 실행 가격 계산 (슬리피지 적용)
-        // 다중 자산 전략에서는 신호 심볼과 현재 kline 심볼이 다를 수 있음
-        // 1. signal.suggested_price가 있으면 사용
-        // 2. current_prices에서 해당 심볼의 가격 사용
-        // 3. fallback: kline.close (단일 자산 전략)
-        let base_price = signal
-            .suggested_price
-            .or_else(|| self.current_prices.get(&key).copied())
-            .unwrap_or(kline.close);
-        let slippage = base_price * self.config.slippage_rate;
-        let execution_price = match signal.side {
-            Side::Buy => base_price + slippage,  // 매수는 높은 가격
-            Side::Sell => base_price - slippage, // 매도는 낮은 가격
+        // FillPolicy에 따라 즉시 또는 다음 캔들 시작 시 체결
+        let sizing = EngineSizing {
+            history: &self.sizing_history,
         };
-
-        // 포지션 크기 계산
-        let max_amount = self.balance * self.config.max_position_size_pct;
-        let position_amount =
-            max_amount * Decimal::from_f64(signal.strength).unwrap_or(Decimal::ONE);
-
-        // Division by zero 방지
-        if execution_price <= Decimal::ZERO {
-            return Ok(()); // 유효하지 않은 가격
-        }
-        let quantity = position_amount / execution_price;
-
-        // 자금 확인
-        let required = position_amount;
-        if required > self.balance {
-            return Ok(()); // 자금 부족 시 무시
-        }
-
-        // 수수료 계산
-        let commission = position_amount * self.config.commission_rate;
-
-        // 잔고 차감
-        self.balance -= required + commission;
-        self.total_commission += commission;
-        self.total_slippage += slippage * quantity;
-        self.total_orders += 1;
-
-        // 포지션 생성 (체결 시점을 close_time으로 통일)
-        let position = SimulatedPosition {
-            symbol: signal.ticker.clone(),
-            side: signal.side,
-            quantity,
-            entry_price: execution_price,
-            fees: commission,
-            entry_time: kline.close_time,
-            strategy_id: signal.strategy_id.clone(),
-        };
-
-        self.positions.insert(key.clone(), position);
-
-        // 진입 거래 기록
-        let trade = self.create_trade(signal, execution_price, quantity, commission, true);
-        self.tracker
-            .record_trade(&trade, true, Some(signal.strategy_id.clone()))
-            .map_err(|e| BacktestError::ExecutionError(e.to_string()))?;
-
-        Ok(())
+        self.sim.submit_signal((), signal, kline, &sizing);
+        self.record_fills()
     }
 
-    /// 포지션을 청산합니다.
-    async fn close_position(&mut self, signal: &Signal, kline: &Kline) -> BacktestResult<()> {
-        let key = signal.ticker.clone();
-
-        let position = match self.positions.remove(&key) {
-            Some(p) => p,
-            None => return Ok(()), // 포지션 없으면 무시
-        };
-
-        // 실행 가격 계산 (슬리피지 적용)
-        // 다중 자산 전략에서는 신호 심볼과 현재 kline 심볼이 다를 수 있음
-        // 1. signal.suggested_price가 있으면 사용
-        // 2. current_prices에서 해당 심볼의 가격 사용
-        // 3. fallback: kline.close (단일 자산 전략)
-        let base_price = signal
-            .suggested_price
-            .or_else(|| self.current_prices.get(&key).copied())
-            .unwrap_or(kline.close);
-        let slippage = base_price * self.config.slippage_rate;
-        let execution_price = match position.side {
-            Side::Buy => base_price - slippage,  // 롱 청산은 낮은 가격
-            Side::Sell => base_price + slippage, // 숏 청산은 높은 가격
-        };
-
-        // 수수료 계산
-        let position_value = execution_price * position.quantity;
-        let commission = position_value * self.config.commission_rate;
-
-        // PnL 계산 (디버깅용)
-        let _gross_pnl = match position.side {
-            Side::Buy => (execution_price - position.entry_price) * position.quantity,
-            Side::Sell => (position.entry_price - execution_price) * position.quantity,
+    /// 캔들 시작 시점의 체결(대기 신호, 손절/익절 주문)을 처리합니다.
+    fn on_bar_open(&mut self, kline: &Kline) -> BacktestResult<()> {
+        let sizing = EngineSizing {
+            history: &self.sizing_history,
         };
-
-        // 잔고 업데이트
-        self.balance += position_value - commission;
-        self.total_commission += commission;
-        self.total_slippage += slippage * position.quantity;
-        self.total_orders += 1;
-
-        // 청산 거래 기록
-        let exit_side = match position.side {
-            Side::Buy => Side::Sell,
-            Side::Sell => Side::Buy,
-        };
-
-        let trade = Trade::new(
-            Uuid::new_v4(),
-            &self.config.exchange_name,
-            Uuid::new_v4().to_string(),
-            signal.ticker.clone(),
-            exit_side,
-            position.quantity,
-            execution_price,
-        )
-        .with_fee(commission, "USDT")
-        .with_executed_at(kline.close_time);
-
-        self.tracker
-            .record_trade(&trade, false, Some(position.strategy_id.clone()))
-            .map_err(|e| BacktestError::ExecutionError(e.to_string()))?;
-
-        Ok(())
+        self.sim.on_bar_open(kline, &sizing);
+        self.record_fills()
     }
 
     /// 모든 포지션을 청산합니다.
-    async fn close_all_positions(&mut self, kline: &Kline) -> BacktestResult<()> {
-        let positions: Vec<_> = self.positions.keys().cloned().collect();
-
-        for key in positions {
-            if let Some(position) = self.positions.get(&key) {
-                let signal = Signal::exit(
-                    &position.strategy_id,
-                    position.symbol.clone(),
-                    match position.side {
-                        Side::Buy => Side::Sell,
-                        Side::Sell => Side::Buy,
-                    },
-                );
-                self.close_position(&signal, kline).await?;
-            }
-        }
+    fn close_all_positions(&mut self, kline: &Kline) -> BacktestResult<()> {
+        self.sim.close_all_positions(kline);
+        self.record_fills()
+    }
 
+    /// 시뮬레이터의 체결을 성과 추적기에 기록합니다.
+    fn record_fills(&mut self) -> BacktestResult<()> {
+        for fill in self.sim.take_fills() {
+            self.tracker
+                .record_trade(&fill.trade, fill.is_entry, Some(fill.strategy_id))
+                .map_err(|e| BacktestError::ExecutionError(e.to_string()))?;
+        }
         Ok(())
     }
 
-    /// 현재 자산 가치를 계산합니다.
-    fn calculate_equity(&self, kline: &Kline) -> Decimal {
-        let mut equity = self.balance;
-
-        for position in self.positions.values() {
-            let current_price = self
-                .current_prices
-                .get(&position.symbol)
-                .copied()
-                .unwrap_or(kline.close);
-
-            let position_value = match position.side {
-                Side::Buy => current_price * position.quantity,
-                Side::Sell => {
-                    // 숏 포지션: 원금 + 미실현 손익
-                    let entry_value = position.entry_price * position.quantity;
-                    let pnl = unrealized_pnl(
-                        position.entry_price,
-                        current_price,
-                        position.quantity,
-                        position.side,
-                    );
-                    entry_value + pnl
-                }
-            };
-
-            equity += position_value;
+    /// 완성된 캔들로 심볼별 ATR과 사이징용 캔들 이력을 갱신합니다.
+    fn record_completed_kline(&mut self, kline: &Kline) {
+        self.sim.update_atr(kline);
+
+        let sizing = &self.config.position_sizing;
+        if !sizing.is_fixed() {
+            let history_len = sizing.history_len();
+            let history = self
+                .sizing_history
+                .entry(kline.ticker.to_string())
+                .or_default();
+            history.push(kline.clone());
+            if history.len() > history_len {
+                let excess = history.len() - history_len;
+                history.drain(..excess);
+            }
         }
-
-        equity
     }
 
-    /// Trade 객체를 생성합니다.
-    fn create_trade(
-        &self,
-        signal: &Signal,
-        price: Decimal,
-        quantity: Decimal,
-        fee: Decimal,
-        _is_entry: bool,
-    ) -> Trade {
-        Trade::new(
-            Uuid::new_v4(),
-            &self.config.exchange_name,
-            Uuid::new_v4().to_string(),
-            signal.ticker.clone(),
-            signal.side,
-            quantity,
-            price,
-        )
-        .with_fee(fee, "USDT")
-        .with_executed_at(self.current_time)
+    /// 현재 자산 가치를 계산합니다.
+    fn calculate_equity(&self) -> Decimal {
+        self.sim.equity()
     }
 
     /// 심볼별 성과를 계산합니다.
@@ -926,12 +943,12 @@ impl BacktestEngine {
 
     /// 현재 잔고를 반환합니다.
     pub fn balance(&self) -> Decimal {
-        self.balance
+        self.sim.cash
     }
 
     /// 열린 포지션 수를 반환합니다.
     pub fn open_positions_count(&self) -> usize {
-        self.positions.len()
+        self.sim.open_positions_count()
     }
 
     /// 다중 타임프레임 백테스트를 실행합니다.
@@ -996,22 +1013,38 @@ impl BacktestEngine {
             }
         }
 
-        let start_time = primary_klines.first().unwrap().open_time;
+        let warmup = self.config.warmup_bars;
+        if warmup >= primary_klines.len() {
+            return Err(BacktestError::DataError(format!(
+                "워밍업 캔들 수({})가 Primary 데이터 길이({}) 이상입니다",
+                warmup,
+                primary_klines.len()
+            )));
+        }
+
+        let start_time = primary_klines[warmup].open_time;
         let end_time = primary_klines.last().unwrap().close_time;
-        let data_points = primary_klines.len();
+        let data_points = primary_klines.len() - warmup;
 
         // 백테스트 시작 시간으로 equity curve 초기 timestamp 설정
         self.tracker.set_initial_timestamp(start_time);
 
         // 전략이 다중 타임프레임을 지원하는지 확인
         let is_multi_tf_strategy = strategy.multi_timeframe_config().is_some();
+        let mut scheduler = self.scheduler_for(strategy, primary_klines[0].open_time);
 
         // 각 Primary 캔들에 대해 시뮬레이션
-        for kline in primary_klines {
+        for (idx, kline) in primary_klines.iter().enumerate() {
+            // 워밍업 구간: 전략 상태만 갱신하고 신호는 버림
+            let warming_up = idx < warmup;
+
+            // 캔들 시작 시점: 대기 신호 및 손절/익절 주문 체결
+            if !warming_up {
+                self.on_bar_open(kline)?;
+            }
+
             // 캔들 완성 시점으로 현재 시간 설정 (데이터 누수 방지)
-            self.current_time = kline.close_time;
-            self.current_prices
-                .insert(kline.ticker.to_string(), kline.close);
+            self.sim.on_bar_close(kline);
 
             // 시장 데이터 생성
             let market_data = MarketData::from_kline(&self.config.exchange_name, kline.clone());
@@ -1034,48 +1067,104 @@ impl BacktestEngine {
                     .map_err(|e| BacktestError::StrategyError(e.to_string()))?
             };
 
-            // 신호 처리
-            for signal in signals {
-                self.process_signal(&signal, kline).await?;
+            if !warming_up {
+                // 신호 처리
+                for signal in signals {
+                    self.process_signal(&signal, kline)?;
+                }
+            }
+            self.fire_schedule(strategy, &mut scheduler, kline, warming_up)
+                .await?;
+
+            if !warming_up {
+                // 미실현 손익 반영하여 자산 업데이트
+                let equity = self.calculate_equity();
+                self.tracker.update_equity(kline.close_time, equity);
             }
 
-            // 미실현 손익 반영하여 자산 업데이트
-            let equity = self.calculate_equity(kline);
-            self.tracker.update_equity(kline.close_time, equity);
+            // 완성된 캔들로 ATR과 사이징 이력 갱신 (다음 체결용)
+            self.record_completed_kline(kline);
         }
 
         // 미청산 포지션 강제 청산
-        self.close_all_positions(primary_klines.last().unwrap())
-            .await?;
+        self.close_all_positions(primary_klines.last().unwrap())?;
 
         // 심볼별 성과 계산
         let performance_by_symbol = self.calculate_performance_by_symbol();
 
+        // 벤치마크 대비 지표
+        let equity_curve = self.tracker.get_equity_curve().to_vec();
+        let mut metrics = self.tracker.get_metrics();
+        let benchmark_curve = self.config.apply_benchmark(&mut metrics, &equity_curve);
+
         // 결과 생성
         Ok(BacktestReport {
             config: self.config.clone(),
-            metrics: self.tracker.get_metrics(),
+            metrics,
             trades: self.tracker.get_round_trips().to_vec(),
-            equity_curve: self.tracker.get_equity_curve().to_vec(),
-            total_orders: self.total_orders,
-            total_commission: self.total_commission,
-            total_slippage: self.total_slippage,
+            equity_curve,
+            total_orders: self.sim.total_orders,
+            total_commission: self.sim.total_commission,
+            total_slippage: self.sim.total_slippage,
             start_time,
             end_time,
             data_points,
             performance_by_symbol,
             signal_markers: self.signal_markers.clone(),
+            slippage_records: self.sim.slippage_records.clone(),
+            benchmark_curve,
         })
     }
 }
 
+/// 단일 전략 엔진의 진입 금액 산정
+///
+/// 고정 비율은 `잔고 × max_position_size_pct`, 변동성 기반 방식은
+/// 지금까지 관측한 심볼 전체를 바스켓으로 구한 목표 비중으로 `자산 × 비중`이며,
+/// 실시간 리스크 매니저와 같이 고정 비율 금액을 넘지 않습니다.
+/// 이력이 부족해 비중을 구할 수 없으면 고정 비율로 대체합니다.
+struct EngineSizing<'a> {
+    /// 심볼별 최근 완성 캔들
+    history: &'a HashMap<String, Vec<Kline>>,
+}
+
+impl FillSizing<()> for EngineSizing<'_> {
+    fn entry_amount(&self, sim: &FillSimulator<()>, _owner: &(), ticker: &str) -> Decimal {
+        let config = sim.config();
+        let fixed_amount = sim.cash * config.max_position_size_pct;
+        let sizing = &config.position_sizing;
+        if sizing.is_fixed() {
+            return fixed_amount;
+        }
+
+        let basket: Vec<(&str, &[Kline])> = self
+            .history
+            .iter()
+            .map(|(symbol, klines)| (symbol.as_str(), klines.as_slice()))
+            .collect();
+
+        match sizing
+            .target_weights(&basket)
+            .get(ticker)
+            .and_then(|weight| Decimal::from_f64(*weight))
+        {
+            Some(weight) => (sim.equity() * weight).min(fixed_amount),
+            None => fixed_amount,
+        }
+    }
+
+    fn rebalance_cash(&self, sim: &FillSimulator<()>, _owner: &()) -> Decimal {
+        sim.cash
+    }
+}
+
 /// 간단한 테스트용 전략
 #[cfg(test)]
 pub mod test_strategies {
     use super::*;
     use async_trait::async_trait;
     use serde_json::Value;
-    use trader_core::{MarketDataType, Order, Position};
+    use trader_core::{MarketDataType, Order, Position, Side};
 
     /// 단순 이동평균 크로스오버 전략 (테스트용)
     pub struct SimpleSmaStrategy {
@@ -1262,6 +1351,155 @@ pub mod test_strategies {
             serde_json::json!({ "bought": self.bought })
         }
     }
+
+    /// 캔들마다 미리 정해둔 신호를 순서대로 내는 전략 (테스트용)
+    pub struct ScriptedStrategy {
+        script: std::collections::VecDeque<Vec<Signal>>,
+    }
+
+    impl ScriptedStrategy {
+        pub fn new(script: Vec<Vec<Signal>>) -> Self {
+            Self {
+                script: script.into(),
+            }
+        }
+
+        /// 첫 캔들에서 한 번만 신호를 냅니다.
+        pub fn once(signal: Signal) -> Self {
+            Self::new(vec![vec![signal]])
+        }
+    }
+
+    #[async_trait]
+    impl trader_strategy::Strategy for ScriptedStrategy {
+        fn name(&self) -> &str {
+            "Scripted"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "정해진 신호를 순서대로 내는 테스트 전략"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            _data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(self.script.pop_front().unwrap_or_default())
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({ "remaining": self.script.len() })
+        }
+    }
+
+    /// 매월 첫 KRX 거래일 09:05에 목표 비중 신호를 내는 전략 (테스트용)
+    #[derive(Default)]
+    pub struct MonthlyTargetStrategy {
+        /// 트리거가 발생한 거래일
+        pub fired: Vec<chrono::NaiveDate>,
+    }
+
+    #[async_trait]
+    impl trader_strategy::Strategy for MonthlyTargetStrategy {
+        fn name(&self) -> &str {
+            "MonthlyTarget"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "월초 스케줄 트리거로 리밸런싱하는 테스트 전략"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            _data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(vec![])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn schedule_triggers(&self) -> Vec<trader_strategy::ScheduleTrigger> {
+            vec![trader_strategy::ScheduleTrigger::monthly(
+                "rebalance",
+                trader_strategy::ScheduleMarket::Krx,
+                1,
+                trader_strategy::ScheduleTime::at(
+                    chrono::NaiveTime::from_hms_opt(9, 5, 0).unwrap(),
+                ),
+            )]
+        }
+
+        async fn on_schedule(
+            &mut self,
+            event: &trader_strategy::ScheduleEvent,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            self.fired.push(event.trading_date);
+            Ok(vec![Signal::target_portfolio(
+                "MonthlyTarget",
+                [("BTC/USDT".to_string(), Decimal::new(5, 1))],
+            )])
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({ "fired": self.fired.len() })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1269,7 +1507,7 @@ mod tests {
     use super::*;
     use chrono::Duration;
     use rust_decimal_macros::dec;
-    use trader_core::Timeframe;
+    use trader_core::{Side, SignalType, Timeframe};
 
     fn create_test_klines(count: usize, start_price: Decimal, trend: Decimal) -> Vec<Kline> {
         let ticker = "BTC/USDT".to_string();
@@ -1392,6 +1630,544 @@ mod tests {
         assert!(!result.summary().is_empty());
     }
 
+    /// (시가, 고가, 저가, 종가) 목록으로 1시간봉을 생성합니다.
+    fn create_ohlc_klines(bars: &[(Decimal, Decimal, Decimal, Decimal)]) -> Vec<Kline> {
+        let base_time = Utc::now() - Duration::days(1);
+
+        bars.iter()
+            .enumerate()
+            .map(|(i, &(open, high, low, close))| {
+                let open_time = base_time + Duration::hours(i as i64);
+                Kline::new(
+                    "BTC/USDT".to_string(),
+                    Timeframe::H1,
+                    open_time,
+                    open,
+                    high,
+                    low,
+                    close,
+                    dec!(100),
+                    open_time + Duration::hours(1),
+                )
+            })
+            .collect()
+    }
+
+    fn frictionless_config(policy: FillPolicy) -> BacktestConfig {
+        BacktestConfig::new(dec!(100000))
+            .with_commission_rate(dec!(0))
+            .with_slippage_rate(dec!(0))
+            .with_fill_policy(policy)
+    }
+
+    #[tokio::test]
+    async fn test_fill_policy_next_open() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(101), dec!(99), dec!(100)),
+            (dec!(102), dec!(104), dec!(101), dec!(103)),
+            (dec!(103), dec!(105), dec!(102), dec!(104)),
+        ]);
+
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::NextOpen));
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 첫 캔들 종가(100)가 아닌 다음 캔들 시가(102)에 진입
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].entry_price, dec!(102));
+        assert_eq!(report.trades[0].entry_time, klines[1].open_time);
+    }
+
+    #[tokio::test]
+    async fn test_fill_policy_next_bar_vwap() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(101), dec!(99), dec!(100)),
+            (dec!(102), dec!(106), dec!(100), dec!(103)),
+        ]);
+
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::NextBarVwap));
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // (106 + 100 + 103) / 3 = 103
+        assert_eq!(report.trades[0].entry_price, dec!(103));
+    }
+
+    #[tokio::test]
+    async fn test_stop_loss_gap_through_fills_at_open() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(101), dec!(99), dec!(100)),
+            (dec!(99), dec!(100), dec!(97), dec!(98)),
+            (dec!(90), dec!(92), dec!(88), dec!(91)),
+            (dec!(91), dec!(93), dec!(90), dec!(92)),
+        ]);
+
+        let signal = Signal::entry("OneShot", "BTC/USDT".to_string(), Side::Buy).with_prices(
+            None,
+            Some(dec!(95)),
+            Some(dec!(120)),
+        );
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::SameClose));
+        let mut strategy = test_strategies::ScriptedStrategy::once(signal);
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 손절가(95)를 넘어 갭 하락한 캔들의 시가(90)로 체결
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].entry_price, dec!(100));
+        assert_eq!(report.trades[0].exit_price, dec!(90));
+        assert_eq!(report.trades[0].exit_time, klines[2].close_time);
+    }
+
+    #[tokio::test]
+    async fn test_take_profit_fills_intrabar_and_cancels_stop() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(101), dec!(99), dec!(100)),
+            (dec!(101), dec!(112), dec!(100), dec!(105)),
+            (dec!(105), dec!(106), dec!(80), dec!(82)),
+        ]);
+
+        let signal = Signal::entry("OneShot", "BTC/USDT".to_string(), Side::Buy).with_prices(
+            None,
+            Some(dec!(95)),
+            Some(dec!(110)),
+        );
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::SameClose));
+        let mut strategy = test_strategies::ScriptedStrategy::once(signal);
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 익절가(110)에서 체결되고 손절 주문은 취소되어 이후 하락에 영향 없음
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].exit_price, dec!(110));
+        assert_eq!(engine.open_positions_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_stop_loss_takes_priority_when_both_trigger() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(101), dec!(99), dec!(100)),
+            (dec!(100), dec!(115), dec!(90), dec!(100)),
+        ]);
+
+        let signal = Signal::entry("OneShot", "BTC/USDT".to_string(), Side::Buy).with_prices(
+            None,
+            Some(dec!(95)),
+            Some(dec!(110)),
+        );
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::SameClose));
+        let mut strategy = test_strategies::ScriptedStrategy::once(signal);
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].exit_price, dec!(95));
+    }
+
+    #[tokio::test]
+    async fn test_pyramiding_and_partial_reduce_by_quantity() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(100), dec!(100), dec!(100)),
+            (dec!(200), dec!(200), dec!(200), dec!(200)),
+            (dec!(150), dec!(150), dec!(150), dec!(150)),
+        ]);
+
+        let ticker = "BTC/USDT".to_string();
+        let add = Signal::new(
+            "Scripted",
+            ticker.clone(),
+            Side::Buy,
+            SignalType::AddToPosition,
+        )
+        .with_strength(0.5);
+        let reduce = Signal::new(
+            "Scripted",
+            ticker.clone(),
+            Side::Sell,
+            SignalType::ReducePosition,
+        )
+        .with_metadata("quantity", serde_json::json!("120"));
+        let mut strategy = test_strategies::ScriptedStrategy::new(vec![
+            vec![Signal::entry("Scripted", ticker.clone(), Side::Buy).with_strength(0.5)],
+            vec![add],
+            vec![reduce],
+        ]);
+
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::SameClose));
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // lot 1: 100,000 × 0.2 × 0.5 / 100 = 100개
+        // lot 2: 90,000 × 0.2 × 0.5 / 200 = 45개
+        // 120개 축소 → lot 1 전량(100) + lot 2 일부(20), 남은 25개는 종료 시 청산
+        assert_eq!(report.trades.len(), 3);
+        assert_eq!(report.trades[0].quantity, dec!(100));
+        assert_eq!(report.trades[0].entry_price, dec!(100));
+        assert_eq!(report.trades[0].exit_price, dec!(150));
+        assert_eq!(report.trades[1].quantity, dec!(20));
+        assert_eq!(report.trades[1].entry_price, dec!(200));
+        assert_eq!(report.trades[2].quantity, dec!(25));
+        assert_eq!(report.trades[2].entry_price, dec!(200));
+    }
+
+    #[tokio::test]
+    async fn test_monte_carlo_original_sequence_matches_report_with_fees() {
+        use crate::performance::{MonteCarloConfig, MonteCarloSimulator};
+        use rust_decimal::prelude::ToPrimitive;
+
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(100), dec!(100), dec!(100)),
+            (dec!(110), dec!(110), dec!(110), dec!(110)),
+            (dec!(105), dec!(105), dec!(105), dec!(105)),
+            (dec!(95), dec!(95), dec!(95), dec!(95)),
+        ]);
+        let ticker = "BTC/USDT".to_string();
+        let mut strategy = test_strategies::ScriptedStrategy::new(vec![
+            vec![Signal::entry("Scripted", ticker.clone(), Side::Buy)],
+            vec![Signal::exit("Scripted", ticker.clone(), Side::Sell)],
+            vec![Signal::entry("Scripted", ticker.clone(), Side::Buy)],
+            vec![Signal::exit("Scripted", ticker, Side::Sell)],
+        ]);
+
+        let config = frictionless_config(FillPolicy::SameClose).with_commission_rate(dec!(0.001));
+        let mut engine = BacktestEngine::new(config);
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+        assert_eq!(report.trades.len(), 2);
+        assert!(report.total_commission > Decimal::ZERO);
+
+        // RoundTrip.pnl은 이미 수수료 차감 후 순손익 → 원래 시퀀스 최종 자산 = 백테스트 최종 잔고
+        let monte_carlo = MonteCarloSimulator::new(MonteCarloConfig::default().with_seed(7))
+            .run(&report.trades, report.config.initial_capital)
+            .unwrap();
+        let final_balance = engine.balance().to_f64().unwrap();
+        assert!((monte_carlo.original.final_equity - final_balance).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_rebalance_signal_trades_toward_target_weights() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(100), dec!(100), dec!(100)),
+            (dec!(100), dec!(100), dec!(100), dec!(100)),
+            (dec!(200), dec!(200), dec!(200), dec!(200)),
+        ]);
+
+        let target = || Signal::target_portfolio("Scripted", [("BTC/USDT".to_string(), dec!(0.5))]);
+        let mut strategy =
+            test_strategies::ScriptedStrategy::new(vec![vec![target()], vec![], vec![target()]]);
+
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::SameClose));
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 진입: 100,000 × 50% / 100 = 500개 (max_position_size_pct 미적용)
+        // 가격 200: 자산 150,000 → 목표 75,000, 보유 100,000 → 125개 매도
+        assert_eq!(report.trades.len(), 2);
+        assert_eq!(report.trades[0].quantity, dec!(125));
+        assert_eq!(report.trades[0].entry_price, dec!(100));
+        assert_eq!(report.trades[0].exit_price, dec!(200));
+        assert_eq!(report.trades[1].quantity, dec!(375));
+    }
+
+    #[tokio::test]
+    async fn test_schedule_triggers_fire_in_simulated_time() {
+        use chrono::TimeZone;
+        use trader_strategy::{ScheduleMarket, WeekdayCalendar};
+
+        // 2024-01-15 ~ 2024-03-10 일봉 (가격 고정)
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let klines: Vec<Kline> = (0..56)
+            .map(|i| {
+                let open_time = start + Duration::days(i);
+                Kline::new(
+                    "BTC/USDT".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    dec!(100),
+                    dec!(100),
+                    dec!(100),
+                    dec!(100),
+                    dec!(100),
+                    open_time + Duration::days(1),
+                )
+            })
+            .collect();
+
+        // 3월 1일(삼일절) 휴장 → 3월 첫 거래일은 3월 4일(월)
+        let holiday = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let calendar = WeekdayCalendar::new().with_holidays(ScheduleMarket::Krx, [holiday]);
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::SameClose))
+            .with_calendar(Arc::new(calendar));
+        let mut strategy = test_strategies::MonthlyTargetStrategy::default();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        assert_eq!(
+            strategy.fired,
+            vec![
+                chrono::NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                chrono::NaiveDate::from_ymd_opt(2024, 3, 4).unwrap(),
+            ]
+        );
+        // 2월 리밸런싱에서 50% 매수, 3월에는 이미 목표 비중이므로 주문 없음
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].quantity, dec!(500));
+        assert_eq!(report.trades[0].entry_time, klines[17].close_time);
+    }
+
+    #[tokio::test]
+    async fn test_reduce_by_strength() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(100), dec!(100), dec!(100)),
+            (dec!(200), dec!(200), dec!(200), dec!(200)),
+            (dec!(150), dec!(150), dec!(150), dec!(150)),
+        ]);
+
+        let ticker = "BTC/USDT".to_string();
+        let add = Signal::new(
+            "Scripted",
+            ticker.clone(),
+            Side::Buy,
+            SignalType::AddToPosition,
+        )
+        .with_strength(0.5);
+        let reduce = Signal::new(
+            "Scripted",
+            ticker.clone(),
+            Side::Sell,
+            SignalType::ReducePosition,
+        )
+        .with_strength(0.2);
+        let mut strategy = test_strategies::ScriptedStrategy::new(vec![
+            vec![Signal::entry("Scripted", ticker.clone(), Side::Buy).with_strength(0.5)],
+            vec![add],
+            vec![reduce],
+        ]);
+
+        let mut engine = BacktestEngine::new(frictionless_config(FillPolicy::SameClose));
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 145개 중 20% (29개) 축소 → 가장 먼저 진입한 lot 1에서 차감
+        assert_eq!(report.trades.len(), 3);
+        assert_eq!(report.trades[0].quantity, dec!(29));
+        assert_eq!(report.trades[0].entry_price, dec!(100));
+        assert_eq!(report.trades[1].quantity, dec!(71));
+        assert_eq!(report.trades[2].quantity, dec!(45));
+    }
+
+    #[tokio::test]
+    async fn test_slippage_model_recorded_per_fill() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(101), dec!(99), dec!(100)),
+            (dec!(100), dec!(101), dec!(99), dec!(100)),
+        ]);
+
+        let config = frictionless_config(FillPolicy::SameClose)
+            .with_slippage_model(SlippageModel::fixed(dec!(0.01)));
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 진입(매수) + 종료 시 강제 청산(매도) 2건
+        assert_eq!(report.slippage_records.len(), 2);
+        assert_eq!(report.slippage_records[0].side, Side::Buy);
+        assert_eq!(report.slippage_records[0].result.execution_price, dec!(101));
+        assert_eq!(report.slippage_records[1].side, Side::Sell);
+        assert_eq!(report.slippage_records[1].result.execution_price, dec!(99));
+        assert_eq!(report.trades[0].entry_price, dec!(101));
+        assert_eq!(report.trades[0].exit_price, dec!(99));
+
+        let expected: Decimal = report
+            .slippage_records
+            .iter()
+            .map(|r| r.result.slippage_amount * r.quantity)
+            .sum();
+        assert_eq!(report.total_slippage, expected);
+    }
+
+    #[tokio::test]
+    async fn test_volatility_slippage_uses_atr() {
+        // 범위 2인 캔들 20개 → ATR(14) = 2
+        let bars: Vec<_> = (0..20)
+            .map(|_| (dec!(100), dec!(101), dec!(99), dec!(100)))
+            .collect();
+        let klines = create_ohlc_klines(&bars);
+
+        let mut script = vec![Vec::new(); 15];
+        script.push(vec![Signal::entry(
+            "Scripted",
+            "BTC/USDT".to_string(),
+            Side::Buy,
+        )]);
+        let mut strategy = test_strategies::ScriptedStrategy::new(script);
+
+        let config = frictionless_config(FillPolicy::SameClose)
+            .with_slippage_model(SlippageModel::volatility_based(0.5));
+        let mut engine = BacktestEngine::new(config);
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 2 / 100 × 0.5 = 1%
+        assert_eq!(report.slippage_records[0].model, "VolatilityBased");
+        assert_eq!(report.slippage_records[0].result.slippage_rate, dec!(0.01));
+        assert_eq!(report.trades[0].entry_price, dec!(101));
+    }
+
+    #[tokio::test]
+    async fn test_volatility_target_position_sizing() {
+        use rust_decimal::prelude::ToPrimitive;
+        use trader_strategy::strategies::common::position_sizing::{
+            SizingMode, VolatilityEstimator,
+        };
+
+        // 범위 4인 캔들 → ATR(20) = 4, 연율화 변동성 = 4% × √252
+        let bars: Vec<_> = (0..30)
+            .map(|_| (dec!(100), dec!(102), dec!(98), dec!(100)))
+            .collect();
+        let klines = create_ohlc_klines(&bars);
+        let sizing = PositionSizingConfig::new(SizingMode::VolatilityTarget)
+            .with_estimator(VolatilityEstimator::Atr);
+
+        let entry_at = |index: usize| {
+            let mut script = vec![Vec::new(); index];
+            script.push(vec![Signal::entry(
+                "Scripted",
+                "BTC/USDT".to_string(),
+                Side::Buy,
+            )]);
+            test_strategies::ScriptedStrategy::new(script)
+        };
+
+        // 이력(21개 캔들)이 쌓인 뒤 진입: 자산 × 10% / 변동성
+        let config =
+            frictionless_config(FillPolicy::SameClose).with_position_sizing(sizing.clone());
+        let mut engine = BacktestEngine::new(config);
+        let report = engine.run(&mut entry_at(21), &klines).await.unwrap();
+
+        let weight = 0.10 / (0.04 * 252f64.sqrt());
+        let expected = 100000.0 * weight / 100.0;
+        let quantity = report.trades[0].quantity.to_f64().unwrap();
+        assert!((quantity - expected).abs() < 1e-6);
+
+        // 목표 비중이 고정 비율(20%)을 넘으면 고정 비율로 제한
+        let calm_bars: Vec<_> = (0..30)
+            .map(|_| (dec!(100), dec!(101), dec!(99), dec!(100)))
+            .collect();
+        let config =
+            frictionless_config(FillPolicy::SameClose).with_position_sizing(sizing.clone());
+        let mut engine = BacktestEngine::new(config);
+        let report = engine
+            .run(&mut entry_at(21), &create_ohlc_klines(&calm_bars))
+            .await
+            .unwrap();
+        assert_eq!(report.trades[0].quantity, dec!(200));
+
+        // 이력이 부족하면 고정 비율 (20%)
+        let config = frictionless_config(FillPolicy::SameClose).with_position_sizing(sizing);
+        let mut engine = BacktestEngine::new(config);
+        let report = engine.run(&mut entry_at(5), &klines).await.unwrap();
+        assert_eq!(report.trades[0].quantity, dec!(200));
+    }
+
+    #[tokio::test]
+    async fn test_warmup_bars_ignore_signals() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(100), dec!(100), dec!(100)),
+            (dec!(110), dec!(110), dec!(110), dec!(110)),
+            (dec!(120), dec!(120), dec!(120), dec!(120)),
+            (dec!(130), dec!(130), dec!(130), dec!(130)),
+        ]);
+
+        let ticker = "BTC/USDT".to_string();
+        let entry = Signal::entry("Scripted", ticker.clone(), Side::Buy);
+        let mut strategy =
+            test_strategies::ScriptedStrategy::new(vec![vec![entry.clone()], vec![], vec![entry]]);
+
+        let config = frictionless_config(FillPolicy::SameClose).with_warmup_bars(2);
+        let mut engine = BacktestEngine::new(config);
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 워밍업 구간(첫 2개 캔들)의 신호는 무시되고 세 번째 캔들에서 진입
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].entry_price, dec!(120));
+        assert_eq!(report.start_time, klines[2].open_time);
+        assert_eq!(report.data_points, 2);
+        assert_eq!(report.equity_curve[0].timestamp, klines[2].open_time);
+
+        let mut engine = BacktestEngine::new(
+            frictionless_config(FillPolicy::SameClose).with_warmup_bars(klines.len()),
+        );
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        assert!(engine.run(&mut strategy, &klines).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_multi_timeframe_warmup_bars_ignore_signals() {
+        let klines = create_ohlc_klines(&[
+            (dec!(100), dec!(100), dec!(100), dec!(100)),
+            (dec!(110), dec!(110), dec!(110), dec!(110)),
+            (dec!(120), dec!(120), dec!(120), dec!(120)),
+            (dec!(130), dec!(130), dec!(130), dec!(130)),
+        ]);
+        let secondary = HashMap::new();
+
+        let ticker = "BTC/USDT".to_string();
+        let entry = Signal::entry("Scripted", ticker.clone(), Side::Buy);
+        let mut strategy =
+            test_strategies::ScriptedStrategy::new(vec![vec![entry.clone()], vec![], vec![entry]]);
+
+        let config = frictionless_config(FillPolicy::SameClose).with_warmup_bars(2);
+        let mut engine = BacktestEngine::new(config);
+        let report = engine
+            .run_multi_timeframe(&mut strategy, &klines, &secondary)
+            .await
+            .unwrap();
+
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].entry_price, dec!(120));
+        assert_eq!(report.start_time, klines[2].open_time);
+        assert_eq!(report.data_points, 2);
+        assert_eq!(report.equity_curve[0].timestamp, klines[2].open_time);
+
+        let mut engine = BacktestEngine::new(
+            frictionless_config(FillPolicy::SameClose).with_warmup_bars(klines.len()),
+        );
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        assert!(engine
+            .run_multi_timeframe(&mut strategy, &klines, &secondary)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_benchmark_curve_aligned_with_equity_curve() {
+        let klines = create_test_klines(30, dec!(50000), dec!(100));
+        let benchmark: Vec<Kline> = klines
+            .iter()
+            .enumerate()
+            .map(|(i, k)| {
+                let mut bench = k.clone();
+                bench.ticker = "SPY".to_string();
+                bench.close = if i % 2 == 0 { dec!(101) } else { dec!(99) };
+                bench
+            })
+            .collect();
+
+        let config = BacktestConfig::new(dec!(100000)).with_benchmark("SPY", benchmark);
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        assert!(!report.benchmark_curve.is_empty());
+        assert!(report.benchmark_curve.iter().all(|b| report
+            .equity_curve
+            .iter()
+            .any(|e| e.timestamp == b.timestamp)));
+
+        let metrics = report.metrics.benchmark.expect("벤치마크 지표");
+        assert_eq!(metrics.benchmark, "SPY");
+        assert!(metrics.periods > 2);
+
+        // 벤치마크 미설정 시 상대 지표 없음
+        let mut engine = BacktestEngine::new(BacktestConfig::new(dec!(100000)));
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+        assert!(report.benchmark_curve.is_empty());
+        assert!(report.metrics.benchmark.is_none());
+    }
+
     #[test]
     fn test_default_config() {
         let config = BacktestConfig::default();
@@ -0,0 +1,917 @@
+//! 백테스트 체결 시뮬레이터
+//!
+//! 단일 전략 엔진([`BacktestEngine`](super::BacktestEngine))과 포트폴리오 엔진이 공유하는
+//! 신호 체결, lot 단위 포지션 관리, 손절/익절 대기 주문 매칭, 슬리피지/수수료 적용 로직입니다.
+//!
+//! 포지션은 `(소유자, 심볼)` 키로 관리합니다. 단일 전략 엔진은 소유자로 `()`를,
+//! 포트폴리오 엔진은 슬리브 인덱스를 사용합니다. 엔진마다 다른 자금 배분은
+//! [`FillSizing`]으로 주입하고, 체결 결과는 [`Fill`]로 모아 엔진이 성과 추적기에 기록합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::hash::Hash;
+use trader_core::{
+    unrealized_pnl, Kline, OrderRequest, OrderType, Side, Signal, SignalType, TimeInForce, Trade,
+};
+use trader_exchange::simulated::MatchingEngine;
+use trader_strategy::strategies::common::rebalance::{PortfolioPosition, RebalanceCalculator};
+use uuid::Uuid;
+
+use crate::backtest::engine::{BacktestConfig, FillPolicy};
+use crate::backtest::slippage::{SlippageModel, SlippageRecord, SlippageResult};
+use crate::indicators::volatility::{AtrParams, VolatilityIndicators};
+
+/// 포지션 키 (소유자, 심볼)
+pub(super) type PositionKey<O> = (O, String);
+
+/// 포지션을 구성하는 개별 진입 단위 (lot)
+#[derive(Debug, Clone)]
+struct PositionLot {
+    /// 수량
+    quantity: Decimal,
+    /// 진입가
+    entry_price: Decimal,
+}
+
+/// 시뮬레이션된 포지션
+///
+/// 추가 진입(피라미딩)마다 lot을 쌓고, 청산은 먼저 진입한 lot부터(FIFO) 처리합니다.
+#[derive(Debug, Clone)]
+struct SimulatedPosition {
+    /// 심볼
+    symbol: String,
+    /// 방향
+    side: Side,
+    /// 총 수량
+    quantity: Decimal,
+    /// 가중 평균 진입가
+    entry_price: Decimal,
+    /// 총 수수료 (나중에 비용 계산에 사용 예정)
+    #[allow(dead_code)]
+    fees: Decimal,
+    /// 최초 진입 시각 (보고서 생성에 사용 예정)
+    #[allow(dead_code)]
+    entry_time: DateTime<Utc>,
+    /// 전략 ID
+    strategy_id: String,
+    /// 진입 lot 목록 (진입 순서)
+    lots: Vec<PositionLot>,
+    /// 손절가 (대기 주문 재등록용)
+    stop_loss: Option<Decimal>,
+    /// 익절가 (대기 주문 재등록용)
+    take_profit: Option<Decimal>,
+}
+
+impl SimulatedPosition {
+    /// lot을 추가하고 가중 평균 진입가를 갱신합니다.
+    fn add_lot(&mut self, quantity: Decimal, entry_price: Decimal, fee: Decimal) {
+        let total_quantity = self.quantity + quantity;
+        if total_quantity > Decimal::ZERO {
+            self.entry_price =
+                (self.entry_price * self.quantity + entry_price * quantity) / total_quantity;
+        }
+        self.quantity = total_quantity;
+        self.fees += fee;
+        self.lots.push(PositionLot {
+            quantity,
+            entry_price,
+        });
+    }
+
+    /// 먼저 진입한 lot부터 지정 수량을 줄이고, 청산된 lot 조각을 반환합니다.
+    fn reduce(&mut self, quantity: Decimal) -> Vec<PositionLot> {
+        let mut remaining = quantity.min(self.quantity);
+        let mut closed = Vec::new();
+
+        while remaining > Decimal::ZERO && !self.lots.is_empty() {
+            let lot = &mut self.lots[0];
+            if lot.quantity <= remaining {
+                remaining -= lot.quantity;
+                closed.push(self.lots.remove(0));
+            } else {
+                lot.quantity -= remaining;
+                closed.push(PositionLot {
+                    quantity: remaining,
+                    entry_price: lot.entry_price,
+                });
+                remaining = Decimal::ZERO;
+            }
+        }
+
+        self.quantity = self.lots.iter().map(|lot| lot.quantity).sum();
+        if self.quantity > Decimal::ZERO {
+            self.entry_price = self
+                .lots
+                .iter()
+                .map(|lot| lot.entry_price * lot.quantity)
+                .sum::<Decimal>()
+                / self.quantity;
+        }
+
+        closed
+    }
+
+    /// 모든 lot이 청산되었는지 확인합니다.
+    fn is_closed(&self) -> bool {
+        self.lots.is_empty()
+    }
+}
+
+/// 슬리피지 모델 입력용 ATR 기간
+const SLIPPAGE_ATR_PERIOD: usize = 14;
+
+/// ATR 계산용으로 유지하는 심볼별 최근 캔들 수 (EMA 초기값 영향이 충분히 줄어드는 길이)
+const SLIPPAGE_ATR_WINDOW: usize = SLIPPAGE_ATR_PERIOD * 4;
+
+/// 매칭 엔진에 걸어둔 청산 대기 주문
+#[derive(Debug, Clone)]
+struct RestingOrder<O> {
+    /// 포지션 키
+    key: PositionKey<O>,
+    /// 손절 주문 여부 (false면 익절 주문)
+    is_stop_loss: bool,
+}
+
+/// 체결 결과 (엔진이 성과 추적기에 기록)
+#[derive(Debug, Clone)]
+pub(super) struct Fill<O> {
+    /// 포지션 소유자
+    pub(super) owner: O,
+    /// 전략 ID
+    pub(super) strategy_id: String,
+    /// 체결 거래 (청산은 lot 단위)
+    pub(super) trade: Trade,
+    /// 진입 체결 여부
+    pub(super) is_entry: bool,
+    /// 현금 변동 (진입 시 -, 청산 시 +, 수수료 포함)
+    pub(super) cash_flow: Decimal,
+}
+
+/// 엔진별 자금 배분 규칙
+pub(super) trait FillSizing<O> {
+    /// 신호 강도 적용 전 신규/추가 진입 금액
+    fn entry_amount(&self, sim: &FillSimulator<O>, owner: &O, ticker: &str) -> Decimal;
+
+    /// 목표 포트폴리오 신호 분해에 사용할 현금
+    fn rebalance_cash(&self, sim: &FillSimulator<O>, owner: &O) -> Decimal;
+}
+
+/// 신호 체결 및 포지션 시뮬레이터 (공유 현금, 포지션, 대기 주문)
+pub(super) struct FillSimulator<O> {
+    /// 공통 백테스트 설정
+    config: BacktestConfig,
+    /// 현금 잔고
+    pub(super) cash: Decimal,
+    /// 포지션 (소유자별로 분리)
+    positions: HashMap<PositionKey<O>, SimulatedPosition>,
+    /// 손절/익절 대기 주문 매칭 엔진
+    matching_engine: MatchingEngine,
+    /// 대기 주문 (주문 ID → 주문 정보)
+    resting_orders: HashMap<String, RestingOrder<O>>,
+    /// 다음 캔들에서 체결될 신호 (SameClose 외 정책)
+    pending_signals: Vec<(O, Signal)>,
+    /// 심볼별 최근 완성 캔들 (슬리피지 모델 ATR 입력)
+    atr_history: HashMap<String, Vec<Kline>>,
+    /// 현재 가격 (심볼별)
+    current_prices: HashMap<String, Decimal>,
+    /// 현재 시뮬레이션 시각
+    current_time: DateTime<Utc>,
+    /// 총 수수료
+    pub(super) total_commission: Decimal,
+    /// 총 슬리피지
+    pub(super) total_slippage: Decimal,
+    /// 총 주문 수
+    pub(super) total_orders: usize,
+    /// 체결별 슬리피지 기록
+    pub(super) slippage_records: Vec<SlippageRecord>,
+    /// 엔진이 아직 기록하지 않은 체결
+    fills: Vec<Fill<O>>,
+}
+
+impl<O: Clone + Eq + Hash + Ord> FillSimulator<O> {
+    /// 초기 자본을 현금으로 하는 시뮬레이터를 생성합니다.
+    pub(super) fn new(config: BacktestConfig, start_time: DateTime<Utc>) -> Self {
+        Self {
+            cash: config.initial_capital,
+            positions: HashMap::new(),
+            // 수수료/슬리피지는 시뮬레이터에서 직접 적용하므로 매칭 엔진에서는 0
+            matching_engine: MatchingEngine::new(Decimal::ZERO, Decimal::ZERO),
+            resting_orders: HashMap::new(),
+            pending_signals: Vec::new(),
+            atr_history: HashMap::new(),
+            current_prices: HashMap::new(),
+            current_time: start_time,
+            total_commission: Decimal::ZERO,
+            total_slippage: Decimal::ZERO,
+            total_orders: 0,
+            slippage_records: Vec::new(),
+            fills: Vec::new(),
+            config,
+        }
+    }
+
+    /// 공통 백테스트 설정
+    pub(super) fn config(&self) -> &BacktestConfig {
+        &self.config
+    }
+
+    /// 열린 포지션 수
+    pub(super) fn open_positions_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// 해당 소유자의 포지션 보유 여부
+    fn has_position(&self, owner: &O, ticker: &str) -> bool {
+        self.positions
+            .contains_key(&(owner.clone(), ticker.to_string()))
+    }
+
+    /// 기록 대기 중인 체결을 가져갑니다.
+    pub(super) fn take_fills(&mut self) -> Vec<Fill<O>> {
+        std::mem::take(&mut self.fills)
+    }
+
+    /// 캔들 완성 시점으로 현재 시각과 가격을 갱신합니다 (데이터 누수 방지).
+    pub(super) fn on_bar_close(&mut self, kline: &Kline) {
+        self.current_time = kline.close_time;
+        self.current_prices
+            .insert(kline.ticker.to_string(), kline.close);
+    }
+
+    /// 완성된 캔들을 슬리피지 모델용 ATR 이력에 추가합니다.
+    pub(super) fn update_atr(&mut self, kline: &Kline) {
+        let history = self
+            .atr_history
+            .entry(kline.ticker.to_string())
+            .or_default();
+        history.push(kline.clone());
+        if history.len() > SLIPPAGE_ATR_WINDOW {
+            let excess = history.len() - SLIPPAGE_ATR_WINDOW;
+            history.drain(..excess);
+        }
+    }
+
+    /// 심볼의 현재 ATR (이력이 `SLIPPAGE_ATR_PERIOD + 1`개 미만이면 None)
+    fn atr(&self, ticker: &str) -> Option<Decimal> {
+        let history = self.atr_history.get(ticker)?;
+        let high: Vec<Decimal> = history.iter().map(|k| k.high).collect();
+        let low: Vec<Decimal> = history.iter().map(|k| k.low).collect();
+        let close: Vec<Decimal> = history.iter().map(|k| k.close).collect();
+
+        VolatilityIndicators::new()
+            .atr(
+                &high,
+                &low,
+                &close,
+                AtrParams {
+                    period: SLIPPAGE_ATR_PERIOD,
+                },
+            )
+            .ok()?
+            .last()
+            .copied()
+            .flatten()
+    }
+
+    /// 신호를 제출합니다.
+    ///
+    /// `SameClose` 정책이면 즉시 체결하고, 그 외 정책이면 다음 캔들 시작 시 체결합니다.
+    pub(super) fn submit_signal(
+        &mut self,
+        owner: O,
+        signal: &Signal,
+        kline: &Kline,
+        sizing: &impl FillSizing<O>,
+    ) {
+        if self.config.fill_policy != FillPolicy::SameClose
+            && signal.signal_type != SignalType::Alert
+        {
+            self.pending_signals.push((owner, signal.clone()));
+            return;
+        }
+
+        self.execute_signal(&owner, signal, kline, None, sizing);
+    }
+
+    /// 캔들 시작 시점의 체결을 처리합니다.
+    ///
+    /// 1. 이전 캔들에서 대기열에 들어간 신호를 `FillPolicy` 가격으로 체결
+    /// 2. 손절/익절 대기 주문을 이번 캔들의 시가/고가/저가로 매칭 (갭 반영)
+    pub(super) fn on_bar_open(&mut self, kline: &Kline, sizing: &impl FillSizing<O>) {
+        self.current_time = kline.open_time;
+
+        let ticker = kline.ticker.to_string();
+        if !self.pending_signals.is_empty() {
+            let (ready, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_signals)
+                .into_iter()
+                .partition(|(_, s)| s.ticker == ticker || s.is_rebalance());
+            self.pending_signals = rest;
+
+            let fill_price = self.config.fill_policy.fill_price(kline);
+            for (owner, signal) in ready {
+                self.execute_signal(&owner, &signal, kline, Some(fill_price), sizing);
+            }
+        }
+
+        self.match_resting_orders(kline);
+    }
+
+    /// 손절/익절 대기 주문을 캔들과 매칭하고 체결된 포지션을 청산합니다.
+    fn match_resting_orders(&mut self, kline: &Kline) {
+        if self.resting_orders.is_empty() {
+            return;
+        }
+
+        let ticker = kline.ticker.to_string();
+        let mut matches = self.matching_engine.process_kline(&ticker, kline);
+
+        // 같은 캔들에서 손절과 익절이 모두 트리거되면 보수적으로 손절을 우선
+        matches.sort_by_key(|m| {
+            !self
+                .resting_orders
+                .get(&m.order_id)
+                .map(|o| o.is_stop_loss)
+                .unwrap_or(false)
+        });
+
+        for order_match in matches {
+            let Some(order) = self.resting_orders.remove(&order_match.order_id) else {
+                continue; // 이미 OCO로 취소된 주문
+            };
+
+            self.current_time = order_match.timestamp;
+            // 손절은 시장가로 체결되므로 슬리피지 적용, 익절은 지정가 체결
+            self.settle_close(
+                &order.key,
+                order_match.fill_price,
+                order.is_stop_loss,
+                order_match.filled_quantity,
+                kline,
+            );
+        }
+    }
+
+    /// 신호를 실행합니다.
+    ///
+    /// `fill_price`가 주어지면 해당 가격을 기준 체결가로 사용합니다.
+    fn execute_signal(
+        &mut self,
+        owner: &O,
+        signal: &Signal,
+        kline: &Kline,
+        fill_price: Option<Decimal>,
+        sizing: &impl FillSizing<O>,
+    ) {
+        match signal.signal_type {
+            SignalType::Entry | SignalType::AddToPosition => {
+                if signal.side == Side::Sell && !self.config.allow_short {
+                    return; // 숏 비허용 시 무시
+                }
+                self.open_position(owner, signal, kline, fill_price, sizing);
+            }
+            SignalType::Exit | SignalType::ReducePosition => {
+                self.close_position(owner, signal, kline, fill_price);
+            }
+            SignalType::Scale => {
+                // 스케일 신호는 현재 포지션에 따라 처리
+                if self.has_position(owner, &signal.ticker) {
+                    self.close_position(owner, signal, kline, fill_price);
+                } else {
+                    self.open_position(owner, signal, kline, fill_price, sizing);
+                }
+            }
+            SignalType::Rebalance => {
+                // 목표 비중을 종목별 신호로 분해 (매도 먼저)
+                let ticker = kline.ticker.to_string();
+                let cash = sizing.rebalance_cash(self, owner);
+                for child in self.expand_rebalance(owner, signal, cash) {
+                    let child_fill = fill_price.filter(|_| child.ticker == ticker);
+                    if child.side == Side::Sell {
+                        self.close_position(owner, &child, kline, child_fill);
+                    } else {
+                        self.open_position(owner, &child, kline, child_fill, sizing);
+                    }
+                }
+            }
+            SignalType::Alert => {
+                // Alert는 실행하지 않고 무시
+            }
+        }
+    }
+
+    /// 목표 포트폴리오 신호를 소유자의 보유 현황 기준 종목별 신호로 분해합니다.
+    ///
+    /// 롱 포지션과 주어진 현금만 포트폴리오로 간주합니다.
+    fn expand_rebalance(&self, owner: &O, signal: &Signal, cash: Decimal) -> Vec<Signal> {
+        let mut config = self.config.rebalance.clone();
+        config.fee_rate = config
+            .fee_rate
+            .max(self.config.commission_rate + self.config.slippage_rate);
+
+        let mut positions: Vec<PortfolioPosition> = self
+            .positions
+            .iter()
+            .filter(|((key_owner, _), position)| key_owner == owner && position.side == Side::Buy)
+            .map(|((_, ticker), position)| {
+                let price = self
+                    .current_prices
+                    .get(ticker)
+                    .copied()
+                    .unwrap_or(position.entry_price);
+                PortfolioPosition::new(ticker.clone(), position.quantity, price)
+            })
+            .collect();
+        positions.push(PortfolioPosition::cash(cash, config.cash_ticker.clone()));
+
+        RebalanceCalculator::new(config).expand_target_signal(
+            signal,
+            &positions,
+            &self.current_prices,
+        )
+    }
+
+    /// 포지션을 오픈하거나 기존 포지션에 추가 진입합니다.
+    ///
+    /// 같은 방향의 `AddToPosition` 신호는 새 lot으로 쌓이고(피라미딩),
+    /// 그 외 신호는 이미 포지션이 있으면 무시됩니다.
+    fn open_position(
+        &mut self,
+        owner: &O,
+        signal: &Signal,
+        kline: &Kline,
+        fill_price: Option<Decimal>,
+        sizing: &impl FillSizing<O>,
+    ) {
+        let key = (owner.clone(), signal.ticker.clone());
+
+        if let Some(position) = self.positions.get(&key) {
+            // 같은 방향의 추가 진입만 허용
+            if signal.signal_type != SignalType::AddToPosition || position.side != signal.side {
+                return;
+            }
+        } else if self.positions.len() >= self.config.max_positions {
+            // 최대 포지션 수는 모든 소유자가 공유
+            return;
+        }
+
+        // 기준 체결가 (다중 자산 전략에서는 신호 심볼과 현재 kline 심볼이 다를 수 있음)
+        // 0. fill_price가 있으면 사용 (다음 캔들 체결 정책)
+        // 1. signal.suggested_price가 있으면 사용
+        // 2. current_prices에서 해당 심볼의 가격 사용
+        // 3. fallback: kline.close (단일 자산 전략)
+        let base_price = fill_price
+            .or(signal.suggested_price)
+            .or_else(|| self.current_prices.get(&signal.ticker).copied())
+            .unwrap_or(kline.close);
+
+        // 포지션 크기 계산 (메타데이터 수량이 있으면 그대로 사용)
+        let requested_quantity = signal.metadata_quantity();
+        let position_amount = match requested_quantity {
+            Some(quantity) => quantity * base_price,
+            None => {
+                let max_amount = sizing.entry_amount(self, owner, &signal.ticker);
+                max_amount * Decimal::from_f64(signal.strength).unwrap_or(Decimal::ONE)
+            }
+        };
+
+        // 슬리피지 모델 적용 (매수는 높은 가격, 매도는 낮은 가격)
+        let slippage = self.calculate_slippage(
+            &signal.ticker,
+            base_price,
+            signal.side,
+            position_amount,
+            kline,
+        );
+        let execution_price = slippage.execution_price;
+
+        // Division by zero 방지
+        if execution_price <= Decimal::ZERO || position_amount <= Decimal::ZERO {
+            return; // 유효하지 않은 가격/수량
+        }
+        let (quantity, position_amount) = match requested_quantity {
+            Some(quantity) => (quantity, quantity * execution_price),
+            None => (position_amount / execution_price, position_amount),
+        };
+
+        // 자금 확인
+        if position_amount > self.cash {
+            return; // 자금 부족 시 무시
+        }
+
+        let commission = position_amount * self.config.commission_rate;
+
+        self.cash -= position_amount + commission;
+        self.total_commission += commission;
+        self.total_slippage += slippage.slippage_amount * quantity;
+        self.total_orders += 1;
+        self.record_slippage(&signal.ticker, signal.side, quantity, slippage);
+
+        // 포지션 생성 또는 lot 추가 (체결 시점은 현재 시뮬레이션 시각)
+        let position = self
+            .positions
+            .entry(key.clone())
+            .or_insert_with(|| SimulatedPosition {
+                symbol: signal.ticker.clone(),
+                side: signal.side,
+                quantity: Decimal::ZERO,
+                entry_price: Decimal::ZERO,
+                fees: Decimal::ZERO,
+                entry_time: self.current_time,
+                strategy_id: signal.strategy_id.clone(),
+                lots: Vec::new(),
+                stop_loss: None,
+                take_profit: None,
+            });
+        position.add_lot(quantity, execution_price, commission);
+
+        // 신호에 손절/익절가가 있으면 갱신
+        if signal.stop_loss.is_some() {
+            position.stop_loss = signal.stop_loss;
+        }
+        if signal.take_profit.is_some() {
+            position.take_profit = signal.take_profit;
+        }
+        let strategy_id = position.strategy_id.clone();
+
+        // 손절/익절 대기 주문을 전체 수량 기준으로 (재)등록
+        self.place_exit_orders(&key, execution_price);
+
+        // 진입 거래 기록 (lot 단위)
+        let trade = self.create_trade(
+            &signal.ticker,
+            signal.side,
+            execution_price,
+            quantity,
+            commission,
+        );
+        self.fills.push(Fill {
+            owner: owner.clone(),
+            strategy_id,
+            trade,
+            is_entry: true,
+            cash_flow: -(position_amount + commission),
+        });
+    }
+
+    /// 포지션을 청산하거나 축소합니다.
+    ///
+    /// `ReducePosition`은 메타데이터 `quantity` 또는 보유 수량 × strength 만큼 줄이고,
+    /// 그 외 신호는 전량 청산합니다.
+    fn close_position(
+        &mut self,
+        owner: &O,
+        signal: &Signal,
+        kline: &Kline,
+        fill_price: Option<Decimal>,
+    ) {
+        let key = (owner.clone(), signal.ticker.clone());
+
+        let held = match self.positions.get(&key) {
+            Some(position) => position.quantity,
+            None => return, // 포지션 없으면 무시
+        };
+
+        let quantity = match signal.signal_type {
+            SignalType::ReducePosition => signal.metadata_quantity().unwrap_or_else(|| {
+                held * Decimal::from_f64(signal.strength).unwrap_or(Decimal::ONE)
+            }),
+            _ => held,
+        };
+        if quantity <= Decimal::ZERO {
+            return;
+        }
+
+        let base_price = fill_price
+            .or(signal.suggested_price)
+            .or_else(|| self.current_prices.get(&signal.ticker).copied())
+            .unwrap_or(kline.close);
+
+        self.settle_close(&key, base_price, true, quantity, kline);
+    }
+
+    /// 포지션을 기준 가격으로 청산하고 lot별 체결을 기록합니다.
+    ///
+    /// 전량 청산되면 남아있는 손절/익절 대기 주문도 함께 취소되고(OCO),
+    /// 부분 청산이면 남은 수량으로 대기 주문을 다시 등록합니다.
+    fn settle_close(
+        &mut self,
+        key: &PositionKey<O>,
+        base_price: Decimal,
+        apply_slippage: bool,
+        quantity: Decimal,
+        kline: &Kline,
+    ) {
+        let Some(position) = self.positions.get_mut(key) else {
+            return;
+        };
+
+        let closed_lots = position.reduce(quantity);
+        if closed_lots.is_empty() {
+            return;
+        }
+        let side = position.side;
+        let symbol = position.symbol.clone();
+        let strategy_id = position.strategy_id.clone();
+
+        if position.is_closed() {
+            self.positions.remove(key);
+            self.cancel_resting_orders(key);
+        } else {
+            self.place_exit_orders(key, base_price);
+        }
+
+        let exit_side = match side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+
+        // 실행 가격 계산 (롱 청산은 낮은 가격, 숏 청산은 높은 가격)
+        let closed_quantity: Decimal = closed_lots.iter().map(|lot| lot.quantity).sum();
+        let slippage_amount = if apply_slippage {
+            let slippage = self.calculate_slippage(
+                &symbol,
+                base_price,
+                exit_side,
+                base_price * closed_quantity,
+                kline,
+            );
+            let amount = slippage.slippage_amount;
+            self.record_slippage(&symbol, exit_side, closed_quantity, slippage);
+            amount
+        } else {
+            Decimal::ZERO
+        };
+        let execution_price = match side {
+            Side::Buy => base_price - slippage_amount,
+            Side::Sell => base_price + slippage_amount,
+        };
+
+        self.total_orders += 1;
+
+        // lot별로 청산 거래를 기록하여 RoundTrip을 lot 단위로 생성
+        for lot in closed_lots {
+            let position_value = execution_price * lot.quantity;
+            let commission = position_value * self.config.commission_rate;
+
+            self.cash += position_value - commission;
+            self.total_commission += commission;
+            self.total_slippage += slippage_amount * lot.quantity;
+
+            let trade = self.create_trade(
+                &symbol,
+                exit_side,
+                execution_price,
+                lot.quantity,
+                commission,
+            );
+            self.fills.push(Fill {
+                owner: key.0.clone(),
+                strategy_id: strategy_id.clone(),
+                trade,
+                is_entry: false,
+                cash_flow: position_value - commission,
+            });
+        }
+    }
+
+    /// 모든 포지션을 현재 가격으로 청산합니다.
+    pub(super) fn close_all_positions(&mut self, kline: &Kline) {
+        let mut keys: Vec<PositionKey<O>> = self.positions.keys().cloned().collect();
+        keys.sort();
+
+        for key in keys {
+            let Some(position) = self.positions.get(&key) else {
+                continue;
+            };
+            let quantity = position.quantity;
+            let price = self
+                .current_prices
+                .get(&position.symbol)
+                .copied()
+                .unwrap_or(kline.close);
+            self.settle_close(&key, price, true, quantity, kline);
+        }
+    }
+
+    /// 포지션의 손절/익절가로 청산 대기 주문을 등록합니다.
+    ///
+    /// 기존 대기 주문은 취소하고 현재 보유 수량으로 다시 등록합니다.
+    fn place_exit_orders(&mut self, key: &PositionKey<O>, current_price: Decimal) {
+        self.cancel_resting_orders(key);
+
+        let Some(position) = self.positions.get(key) else {
+            return;
+        };
+
+        let exit_side = match position.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+        let quantity = position.quantity;
+        let strategy_id = position.strategy_id.clone();
+        let orders = [(position.stop_loss, true), (position.take_profit, false)];
+
+        for (trigger_price, is_stop_loss) in orders {
+            let Some(trigger_price) = trigger_price else {
+                continue;
+            };
+
+            let request = OrderRequest {
+                ticker: key.1.clone(),
+                side: exit_side,
+                order_type: if is_stop_loss {
+                    OrderType::StopLoss
+                } else {
+                    OrderType::TakeProfit
+                },
+                quantity,
+                price: None,
+                stop_price: Some(trigger_price),
+                time_in_force: TimeInForce::GTC,
+                client_order_id: None,
+                strategy_id: Some(strategy_id.clone()),
+            };
+
+            let result =
+                self.matching_engine
+                    .submit_order(&request, current_price, self.current_time);
+            self.resting_orders.insert(
+                result.order_id,
+                RestingOrder {
+                    key: key.clone(),
+                    is_stop_loss,
+                },
+            );
+        }
+    }
+
+    /// 해당 포지션의 손절/익절 대기 주문을 모두 취소합니다.
+    fn cancel_resting_orders(&mut self, key: &PositionKey<O>) {
+        let order_ids: Vec<String> = self
+            .resting_orders
+            .iter()
+            .filter(|(_, order)| &order.key == key)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        for order_id in order_ids {
+            self.resting_orders.remove(&order_id);
+            self.matching_engine.cancel_order(&key.1, &order_id);
+        }
+    }
+
+    /// 설정된 슬리피지 모델로 체결가를 계산합니다.
+    ///
+    /// 모델이 없으면 `slippage_rate` 고정 비율을 사용합니다.
+    /// 체결 캔들이 같은 심볼이면 거래량/범위를, 심볼별 ATR이 있으면 ATR을 함께 전달합니다.
+    fn calculate_slippage(
+        &self,
+        ticker: &str,
+        base_price: Decimal,
+        side: Side,
+        order_value: Decimal,
+        kline: &Kline,
+    ) -> SlippageResult {
+        let fixed;
+        let model = match &self.config.slippage_model {
+            Some(model) => model,
+            None => {
+                fixed = SlippageModel::fixed(self.config.slippage_rate);
+                &fixed
+            }
+        };
+
+        let kline = (kline.ticker == ticker).then_some(kline);
+        let atr = self.atr(ticker);
+
+        model.calculate_execution_price_with_atr(base_price, side, order_value, kline, atr)
+    }
+
+    /// 체결 슬리피지를 리포트용으로 기록합니다.
+    fn record_slippage(
+        &mut self,
+        ticker: &str,
+        side: Side,
+        quantity: Decimal,
+        result: SlippageResult,
+    ) {
+        let model = self
+            .config
+            .slippage_model
+            .as_ref()
+            .map(|model| model.name())
+            .unwrap_or("Fixed");
+
+        self.slippage_records.push(SlippageRecord {
+            symbol: ticker.to_string(),
+            side,
+            quantity,
+            executed_at: self.current_time,
+            model: model.to_string(),
+            result,
+        });
+    }
+
+    /// Trade 객체를 생성합니다.
+    fn create_trade(
+        &self,
+        ticker: &str,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        fee: Decimal,
+    ) -> Trade {
+        Trade::new(
+            Uuid::new_v4(),
+            &self.config.exchange_name,
+            Uuid::new_v4().to_string(),
+            ticker.to_string(),
+            side,
+            quantity,
+            price,
+        )
+        .with_fee(fee, "USDT")
+        .with_executed_at(self.current_time)
+    }
+
+    /// 포지션 평가액 (숏은 원금 + 미실현 손익)
+    fn position_value(&self, position: &SimulatedPosition) -> Decimal {
+        let current_price = self
+            .current_prices
+            .get(&position.symbol)
+            .copied()
+            .unwrap_or(position.entry_price);
+
+        match position.side {
+            Side::Buy => current_price * position.quantity,
+            Side::Sell => {
+                position.entry_price * position.quantity
+                    + unrealized_pnl(
+                        position.entry_price,
+                        current_price,
+                        position.quantity,
+                        position.side,
+                    )
+            }
+        }
+    }
+
+    /// 소유자의 보유 포지션 평가액
+    pub(super) fn exposure(&self, owner: &O) -> Decimal {
+        self.positions
+            .iter()
+            .filter(|((key_owner, _), _)| key_owner == owner)
+            .map(|(_, position)| self.position_value(position))
+            .sum()
+    }
+
+    /// 전체 자산 (현금 + 전체 포지션 평가액)
+    pub(super) fn equity(&self) -> Decimal {
+        self.cash
+            + self
+                .positions
+                .values()
+                .map(|position| self.position_value(position))
+                .sum::<Decimal>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_position_lots_weighted_average() {
+        let mut position = SimulatedPosition {
+            symbol: "BTC/USDT".to_string(),
+            side: Side::Buy,
+            quantity: Decimal::ZERO,
+            entry_price: Decimal::ZERO,
+            fees: Decimal::ZERO,
+            entry_time: Utc::now(),
+            strategy_id: "test".to_string(),
+            lots: Vec::new(),
+            stop_loss: None,
+            take_profit: None,
+        };
+
+        position.add_lot(dec!(1), dec!(100), Decimal::ZERO);
+        position.add_lot(dec!(3), dec!(200), Decimal::ZERO);
+        assert_eq!(position.quantity, dec!(4));
+        assert_eq!(position.entry_price, dec!(175));
+
+        // lot 1 전량 + lot 2 일부 청산 → 남은 lot 기준으로 평균가 재계산
+        let closed = position.reduce(dec!(2));
+        assert_eq!(closed.len(), 2);
+        assert_eq!(closed[0].entry_price, dec!(100));
+        assert_eq!(closed[1].quantity, dec!(1));
+        assert_eq!(position.quantity, dec!(2));
+        assert_eq!(position.entry_price, dec!(200));
+
+        position.reduce(dec!(5));
+        assert!(position.is_closed());
+    }
+}
@@ -5,12 +5,35 @@
 //! # 주요 구성요소
 //!
 //! - [`BacktestConfig`]: 백테스트 설정 (초기 자본, 수수료, 슬리피지 등)
+//! - [`FillPolicy`]: 신호 체결 시점 (당일 종가/다음 봉 시가/다음 봉 VWAP 근사)
 //! - [`BacktestEngine`]: 백테스트 실행 엔진
 //! - [`BacktestReport`]: 백테스트 결과 리포트
 //! - [`SlippageModel`]: 동적 슬리피지 모델 (Fixed/Linear/VolatilityBased/Tiered)
+//! - [`SlippageRecord`]: 체결별 슬리피지 기록 (리포트 포함)
+//! - [`ParameterOptimizer`]: 전략 파라미터 격자/무작위 탐색 최적화
+//! - [`WalkForwardAnalyzer`]: IS 최적화 → OOS 검증을 반복하는 워크포워드 분석
+//! - [`PortfolioBacktestEngine`]: 여러 전략이 현금과 포지션 한도를 공유하는 포트폴리오 백테스트
 
 pub mod engine;
+mod execution;
+pub mod optimizer;
+pub mod portfolio;
 pub mod slippage;
+pub mod walk_forward;
 
-pub use engine::{BacktestConfig, BacktestEngine, BacktestError, BacktestReport, BacktestResult};
-pub use slippage::{SlippageModel, SlippageResult, SlippageTier};
+pub use engine::{
+    BacktestConfig, BacktestEngine, BacktestError, BacktestReport, BacktestResult, FillPolicy,
+};
+pub use optimizer::{
+    OptimizationFailure, OptimizationObjective, OptimizationResult, OptimizationRun,
+    OptimizerError, ParameterOptimizer, ParameterRange, ParameterSpace, SearchMethod,
+};
+pub use portfolio::{
+    AllocationMethod, AllocationSnapshot, PortfolioBacktestConfig, PortfolioBacktestEngine,
+    PortfolioBacktestReport, PortfolioSleeve, StrategyPerformance,
+};
+pub use slippage::{SlippageModel, SlippageRecord, SlippageResult, SlippageTier};
+pub use walk_forward::{
+    WalkForwardAnalyzer, WalkForwardConfig, WalkForwardError, WalkForwardMode, WalkForwardReport,
+    WalkForwardResult, WalkForwardSplit, WalkForwardWindow,
+};
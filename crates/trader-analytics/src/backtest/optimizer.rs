@@ -0,0 +1,1098 @@
+//! 전략 파라미터 최적화 모듈
+//!
+//! 전략의 JSON 설정값을 격자(Grid) 또는 무작위(Random) 탐색으로 변경하며
+//! [`BacktestEngine`]을 병렬 실행하고, 목적 함수 기준으로 정렬된 결과 테이블을 반환합니다.
+//!
+//! # 파라미터 공간
+//!
+//! 파라미터 공간은 전략의 [`StrategyUISchema`] 커스텀 필드 중
+//! `min`/`max`가 지정된 숫자 필드(integer/number)에서 도출됩니다.
+//! `step`이 없는 필드는 범위를 [`DEFAULT_GRID_POINTS`]개 지점으로 나눕니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::backtest::{
+//!     BacktestConfig, OptimizationObjective, ParameterOptimizer, ParameterSpace, SearchMethod,
+//! };
+//!
+//! let space = ParameterSpace::for_strategy("rsi")?.select(&["rsi_period", "oversold"])?;
+//! let optimizer = ParameterOptimizer::new("rsi", BacktestConfig::default(), space)
+//!     .with_objective(OptimizationObjective::Sharpe)
+//!     .with_method(SearchMethod::Random { samples: 50, seed: Some(42) });
+//!
+//! let result = optimizer.run(&klines).await?;
+//! println!("{}", result.summary(10));
+//! ```
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use futures::stream::{self, StreamExt};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use trader_core::{FieldSchema, FieldType, Kline, StrategyContext, StrategyUISchema};
+use trader_strategy::StrategyRegistry;
+
+use crate::backtest::engine::{BacktestConfig, BacktestEngine, BacktestReport};
+use crate::performance::PerformanceMetrics;
+
+/// `step`이 지정되지 않은 필드의 기본 격자 지점 수
+pub const DEFAULT_GRID_POINTS: usize = 5;
+
+/// 기본 최대 조합 수 (격자 탐색 폭주 방지)
+pub const DEFAULT_MAX_COMBINATIONS: usize = 1_000;
+
+/// 파라미터 공간 도출 시 제외하는 섹션 (종목/금액 등 최적화 대상이 아닌 필드)
+const EXCLUDED_SECTIONS: &[&str] = &["asset"];
+
+/// 섹션 지정 없이도 제외하는 필드 (거래 금액은 수익률 지표를 왜곡)
+const EXCLUDED_FIELDS: &[&str] = &["amount"];
+
+/// 최적화 오류
+#[derive(Debug, Error)]
+pub enum OptimizerError {
+    /// 전략을 찾을 수 없음
+    #[error("전략을 찾을 수 없습니다: {0}")]
+    StrategyNotFound(String),
+
+    /// 전략에 UI 스키마가 없음
+    #[error("전략 UI 스키마가 없습니다: {0}")]
+    SchemaNotFound(String),
+
+    /// 알 수 없는 파라미터
+    #[error("최적화할 수 없는 파라미터입니다: {0}")]
+    UnknownParameter(String),
+
+    /// 잘못된 파라미터 범위
+    #[error("잘못된 파라미터 범위: {0}")]
+    InvalidRange(String),
+
+    /// 빈 파라미터 공간
+    #[error("최적화할 파라미터가 없습니다")]
+    EmptySpace,
+
+    /// 조합 수 초과
+    #[error(
+        "파라미터 조합 수 초과: {count}개 (최대 {max}개), 범위를 좁히거나 무작위 탐색을 사용하세요"
+    )]
+    TooManyCombinations { count: usize, max: usize },
+
+    /// 데이터 오류
+    #[error("데이터 오류: {0}")]
+    DataError(String),
+
+    /// 백테스트 실행 실패
+    #[error("백테스트 실행 실패: {0}")]
+    BacktestFailed(String),
+}
+
+/// 최적화 결과 타입
+pub type OptimizerResult<T> = Result<T, OptimizerError>;
+
+/// 최적화 목적 함수
+///
+/// [`PerformanceMetrics`]의 지표 중 하나를 점수로 사용하며, 점수가 높을수록 상위에 정렬됩니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OptimizationObjective {
+    /// 샤프 비율
+    #[default]
+    Sharpe,
+    /// 소르티노 비율
+    Sortino,
+    /// 칼마 비율
+    Calmar,
+    /// 거래당 기대 수익
+    Expectancy,
+    /// 총 수익률 (%)
+    TotalReturn,
+    /// 프로핏 팩터
+    ProfitFactor,
+}
+
+impl OptimizationObjective {
+    /// 성과 지표에서 목적 함수 점수를 계산합니다.
+    pub fn score(&self, metrics: &PerformanceMetrics) -> Decimal {
+        match self {
+            Self::Sharpe => metrics.sharpe_ratio,
+            Self::Sortino => metrics.sortino_ratio,
+            Self::Calmar => metrics.calmar_ratio,
+            Self::Expectancy => metrics.expectancy,
+            Self::TotalReturn => metrics.total_return_pct,
+            Self::ProfitFactor => metrics.profit_factor,
+        }
+    }
+
+    /// 문자열에서 목적 함수를 파싱합니다.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "sharpe" | "sharpe_ratio" => Some(Self::Sharpe),
+            "sortino" | "sortino_ratio" => Some(Self::Sortino),
+            "calmar" | "calmar_ratio" => Some(Self::Calmar),
+            "expectancy" => Some(Self::Expectancy),
+            "total_return" | "return" => Some(Self::TotalReturn),
+            "profit_factor" => Some(Self::ProfitFactor),
+            _ => None,
+        }
+    }
+
+    /// 목적 함수 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Sharpe => "sharpe",
+            Self::Sortino => "sortino",
+            Self::Calmar => "calmar",
+            Self::Expectancy => "expectancy",
+            Self::TotalReturn => "total_return",
+            Self::ProfitFactor => "profit_factor",
+        }
+    }
+}
+
+/// 탐색 방식
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SearchMethod {
+    /// 모든 조합을 평가하는 격자 탐색
+    #[default]
+    Grid,
+    /// 격자 지점 중 일부를 무작위로 추출하는 탐색
+    Random {
+        /// 평가할 조합 수
+        samples: usize,
+        /// 재현용 시드 (없으면 엔트로피 사용)
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+}
+
+/// 단일 파라미터의 탐색 범위
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct ParameterRange {
+    /// 파라미터 이름 (전략 JSON 설정 키)
+    pub name: String,
+    /// 최소값
+    pub min: f64,
+    /// 최대값
+    pub max: f64,
+    /// 증감 단위
+    pub step: f64,
+    /// 정수 파라미터 여부
+    #[serde(default)]
+    pub integer: bool,
+}
+
+impl ParameterRange {
+    /// 새 파라미터 범위를 생성합니다.
+    pub fn new(name: impl Into<String>, min: f64, max: f64, step: f64) -> Self {
+        Self {
+            name: name.into(),
+            min,
+            max,
+            step,
+            integer: false,
+        }
+    }
+
+    /// 정수 파라미터로 설정합니다.
+    pub fn integer(mut self) -> Self {
+        self.integer = true;
+        self
+    }
+
+    /// UI 스키마 필드에서 범위를 도출합니다.
+    ///
+    /// 숨김 필드, 숫자가 아닌 필드, `min`/`max`가 없는 필드는 `None`을 반환합니다.
+    pub fn from_field(field: &FieldSchema) -> Option<Self> {
+        let integer = match field.field_type {
+            FieldType::Integer => true,
+            FieldType::Number => false,
+            _ => return None,
+        };
+        if field.hidden {
+            return None;
+        }
+        let (min, max) = (field.min?, field.max?);
+        if max <= min {
+            return None;
+        }
+
+        let step = field.step.filter(|s| *s > 0.0).unwrap_or_else(|| {
+            let step = (max - min) / (DEFAULT_GRID_POINTS - 1) as f64;
+            if integer {
+                step.ceil().max(1.0)
+            } else {
+                step
+            }
+        });
+
+        Some(Self {
+            name: field.name.clone(),
+            min,
+            max,
+            step,
+            integer,
+        })
+    }
+
+    /// 범위 유효성을 검증합니다.
+    pub fn validate(&self) -> OptimizerResult<()> {
+        if !self.min.is_finite() || !self.max.is_finite() || !self.step.is_finite() {
+            return Err(OptimizerError::InvalidRange(format!(
+                "{}: 유한한 값이어야 합니다",
+                self.name
+            )));
+        }
+        if self.max < self.min {
+            return Err(OptimizerError::InvalidRange(format!(
+                "{}: max({}) < min({})",
+                self.name, self.max, self.min
+            )));
+        }
+        if self.step <= 0.0 {
+            return Err(OptimizerError::InvalidRange(format!(
+                "{}: step은 0보다 커야 합니다",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// 범위 내 격자 지점 수
+    pub fn len(&self) -> usize {
+        ((self.max - self.min) / self.step + 1e-9).floor() as usize + 1
+    }
+
+    /// 격자 지점이 없는지 여부 (유효한 범위는 항상 1개 이상)
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// i번째 격자 지점의 JSON 값
+    pub fn value_at(&self, index: usize) -> Value {
+        let raw = (self.min + self.step * index as f64).min(self.max);
+        if self.integer {
+            Value::from(raw.round() as i64)
+        } else {
+            // 부동소수점 누적 오차 제거 (0.30000000000000004 → 0.3)
+            Value::from((raw * 1e10).round() / 1e10)
+        }
+    }
+
+    /// 범위 내 모든 격자 지점
+    pub fn values(&self) -> Vec<Value> {
+        (0..self.len()).map(|i| self.value_at(i)).collect()
+    }
+}
+
+/// 파라미터 탐색 공간
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct ParameterSpace {
+    /// 파라미터 범위 목록
+    pub ranges: Vec<ParameterRange>,
+}
+
+impl ParameterSpace {
+    /// 범위 목록으로 파라미터 공간을 생성합니다.
+    pub fn new(ranges: Vec<ParameterRange>) -> Self {
+        Self { ranges }
+    }
+
+    /// UI 스키마에서 파라미터 공간을 도출합니다.
+    ///
+    /// 종목/금액 섹션(`asset`)과 거래 금액 필드를 제외한 숫자 커스텀 필드가 대상입니다.
+    pub fn from_ui_schema(schema: &StrategyUISchema) -> Self {
+        let ranges = schema
+            .custom_fields
+            .iter()
+            .filter(|f| {
+                !EXCLUDED_FIELDS.contains(&f.name.as_str())
+                    && !f
+                        .section
+                        .as_deref()
+                        .is_some_and(|s| EXCLUDED_SECTIONS.contains(&s))
+            })
+            .filter_map(ParameterRange::from_field)
+            .collect();
+        Self { ranges }
+    }
+
+    /// 레지스트리에 등록된 전략의 UI 스키마에서 파라미터 공간을 도출합니다.
+    pub fn for_strategy(strategy_id: &str) -> OptimizerResult<Self> {
+        let meta = StrategyRegistry::find(strategy_id)
+            .ok_or_else(|| OptimizerError::StrategyNotFound(strategy_id.to_string()))?;
+        let schema_factory = meta
+            .ui_schema_factory
+            .ok_or_else(|| OptimizerError::SchemaNotFound(strategy_id.to_string()))?;
+        Ok(Self::from_ui_schema(&schema_factory()))
+    }
+
+    /// 지정한 파라미터만 남깁니다.
+    ///
+    /// 빈 목록이면 공간을 그대로 유지합니다.
+    pub fn select<S: AsRef<str>>(self, names: &[S]) -> OptimizerResult<Self> {
+        if names.is_empty() {
+            return Ok(self);
+        }
+        let mut ranges = Vec::with_capacity(names.len());
+        for name in names {
+            let name = name.as_ref();
+            let range = self
+                .ranges
+                .iter()
+                .find(|r| r.name == name)
+                .ok_or_else(|| OptimizerError::UnknownParameter(name.to_string()))?;
+            ranges.push(range.clone());
+        }
+        Ok(Self { ranges })
+    }
+
+    /// 파라미터 범위를 추가하거나 같은 이름의 범위를 덮어씁니다.
+    pub fn with_range(mut self, range: ParameterRange) -> Self {
+        if let Some(existing) = self.ranges.iter_mut().find(|r| r.name == range.name) {
+            *existing = range;
+        } else {
+            self.ranges.push(range);
+        }
+        self
+    }
+
+    /// 범위를 덮어쓴 뒤 지정한 파라미터만 남깁니다.
+    ///
+    /// `overrides`로 지정한 파라미터는 `names`에 없어도 탐색 대상에 포함되며,
+    /// `names`가 비어 있으면 전체 공간(덮어쓰기 반영)을 유지합니다.
+    pub fn customize<S: AsRef<str>>(
+        mut self,
+        names: &[S],
+        overrides: Vec<ParameterRange>,
+    ) -> OptimizerResult<Self> {
+        let mut selected: Vec<String> = names.iter().map(|n| n.as_ref().to_string()).collect();
+        if !selected.is_empty() {
+            for range in &overrides {
+                if !selected.contains(&range.name) {
+                    selected.push(range.name.clone());
+                }
+            }
+        }
+        for range in overrides {
+            self = self.with_range(range);
+        }
+        self.select(&selected)
+    }
+
+    /// 모든 범위를 검증합니다.
+    pub fn validate(&self) -> OptimizerResult<()> {
+        if self.ranges.is_empty() {
+            return Err(OptimizerError::EmptySpace);
+        }
+        self.ranges.iter().try_for_each(ParameterRange::validate)
+    }
+
+    /// 전체 격자 조합 수 (오버플로 시 `usize::MAX`)
+    pub fn combination_count(&self) -> usize {
+        self.ranges
+            .iter()
+            .try_fold(1usize, |acc, r| acc.checked_mul(r.len()))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// 격자 인덱스 조합을 파라미터 맵으로 변환합니다.
+    fn params_at(&self, indices: &[usize]) -> Map<String, Value> {
+        self.ranges
+            .iter()
+            .zip(indices)
+            .map(|(range, &i)| (range.name.clone(), range.value_at(i)))
+            .collect()
+    }
+
+    /// 모든 격자 조합을 생성합니다.
+    pub fn grid(&self) -> Vec<Map<String, Value>> {
+        let lens: Vec<usize> = self.ranges.iter().map(ParameterRange::len).collect();
+        if lens.is_empty() || lens.contains(&0) {
+            return Vec::new();
+        }
+
+        let mut combos = Vec::with_capacity(self.combination_count());
+        let mut indices = vec![0usize; lens.len()];
+        loop {
+            combos.push(self.params_at(&indices));
+
+            // 마지막 파라미터부터 자리올림
+            let mut pos = lens.len();
+            loop {
+                if pos == 0 {
+                    return combos;
+                }
+                pos -= 1;
+                indices[pos] += 1;
+                if indices[pos] < lens[pos] {
+                    break;
+                }
+                indices[pos] = 0;
+            }
+        }
+    }
+
+    /// 격자 지점 중 중복 없이 `samples`개 조합을 무작위로 추출합니다.
+    pub fn sample(&self, samples: usize, seed: Option<u64>) -> Vec<Map<String, Value>> {
+        let lens: Vec<usize> = self.ranges.iter().map(ParameterRange::len).collect();
+        if lens.is_empty() || lens.contains(&0) {
+            return Vec::new();
+        }
+
+        let target = samples.min(self.combination_count());
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut seen = HashSet::with_capacity(target);
+        let mut combos = Vec::with_capacity(target);
+        while combos.len() < target {
+            let indices: Vec<usize> = lens.iter().map(|&len| rng.gen_range(0..len)).collect();
+            if seen.insert(indices.clone()) {
+                combos.push(self.params_at(&indices));
+            }
+        }
+        combos
+    }
+}
+
+/// 단일 파라미터 조합의 최적화 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizationRun {
+    /// 순위 (1부터 시작)
+    pub rank: usize,
+    /// 탐색한 파라미터 값
+    pub params: Map<String, Value>,
+    /// 목적 함수 점수
+    pub score: Decimal,
+    /// 성과 지표
+    pub metrics: PerformanceMetrics,
+}
+
+/// 실패한 파라미터 조합
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizationFailure {
+    /// 탐색한 파라미터 값
+    pub params: Map<String, Value>,
+    /// 오류 메시지
+    pub error: String,
+}
+
+/// 최적화 결과 테이블
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizationResult {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 목적 함수
+    pub objective: OptimizationObjective,
+    /// 탐색 방식
+    pub method: SearchMethod,
+    /// 탐색 공간
+    pub space: ParameterSpace,
+    /// 점수 내림차순으로 정렬된 결과
+    pub runs: Vec<OptimizationRun>,
+    /// 실패한 조합
+    #[serde(default)]
+    pub failures: Vec<OptimizationFailure>,
+}
+
+impl OptimizationResult {
+    /// 최고 점수 결과
+    pub fn best(&self) -> Option<&OptimizationRun> {
+        self.runs.first()
+    }
+
+    /// 평가한 전체 조합 수 (실패 포함)
+    pub fn evaluated(&self) -> usize {
+        self.runs.len() + self.failures.len()
+    }
+
+    /// 상위 N개 결과를 표 형식 문자열로 반환합니다.
+    pub fn summary(&self, top: usize) -> String {
+        let mut out = format!(
+            "=== 파라미터 최적화 결과: {} (목적 함수: {}, 평가 {}건, 실패 {}건) ===\n",
+            self.strategy_id,
+            self.objective.name(),
+            self.evaluated(),
+            self.failures.len()
+        );
+        out.push_str(&format!(
+            "{:>4}  {:>10}  {:>9}  {:>8}  {:>7}  {:>6}  파라미터\n",
+            "순위", "점수", "수익률%", "MDD%", "승률%", "거래"
+        ));
+        for run in self.runs.iter().take(top) {
+            let params = run
+                .params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!(
+                "{:>4}  {:>10.4}  {:>9.2}  {:>8.2}  {:>7.2}  {:>6}  {}\n",
+                run.rank,
+                run.score,
+                run.metrics.total_return_pct,
+                run.metrics.max_drawdown_pct,
+                run.metrics.win_rate_pct,
+                run.metrics.total_trades,
+                params
+            ));
+        }
+        out
+    }
+}
+
+/// 전략 파라미터 최적화기
+///
+/// 레지스트리에서 전략을 생성하고, 조합마다 기본 설정(`base_params`)에
+/// 탐색 값을 덮어쓴 설정으로 초기화하여 [`BacktestEngine::run_with_context`]를 실행합니다.
+/// 조합마다 기본 [`StrategyContext`]의 복제본을 주입하므로 실행 간 지표 상태가 공유되지 않으며,
+/// 각 백테스트는 blocking 스레드 풀에서 병렬로 실행됩니다.
+#[derive(Debug, Clone)]
+pub struct ParameterOptimizer {
+    strategy_id: String,
+    backtest_config: BacktestConfig,
+    space: ParameterSpace,
+    base_params: Value,
+    base_context: StrategyContext,
+    objective: OptimizationObjective,
+    method: SearchMethod,
+    max_combinations: usize,
+    parallelism: usize,
+}
+
+impl ParameterOptimizer {
+    /// 새 최적화기를 생성합니다.
+    pub fn new(
+        strategy_id: impl Into<String>,
+        backtest_config: BacktestConfig,
+        space: ParameterSpace,
+    ) -> Self {
+        let parallelism = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4);
+        Self {
+            strategy_id: strategy_id.into(),
+            backtest_config,
+            space,
+            base_params: Value::Object(Map::new()),
+            base_context: StrategyContext::default(),
+            objective: OptimizationObjective::default(),
+            method: SearchMethod::default(),
+            max_combinations: DEFAULT_MAX_COMBINATIONS,
+            parallelism,
+        }
+    }
+
+    /// 탐색 값 외의 기본 전략 설정을 지정합니다.
+    pub fn with_base_params(mut self, params: Value) -> Self {
+        self.base_params = params;
+        self
+    }
+
+    /// 조합마다 복제하여 주입할 기본 StrategyContext를 지정합니다.
+    ///
+    /// GlobalScore/RouteState 등 DB에서 미리 로드한 분석 결과를 전달할 때 사용합니다.
+    pub fn with_context(mut self, context: StrategyContext) -> Self {
+        self.base_context = context;
+        self
+    }
+
+    /// 목적 함수를 설정합니다.
+    pub fn with_objective(mut self, objective: OptimizationObjective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// 탐색 방식을 설정합니다.
+    pub fn with_method(mut self, method: SearchMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// 최대 조합 수를 설정합니다.
+    pub fn with_max_combinations(mut self, max: usize) -> Self {
+        self.max_combinations = max;
+        self
+    }
+
+    /// 동시 실행 백테스트 수를 설정합니다.
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// 백테스트 설정을 교체합니다.
+    pub fn with_backtest_config(mut self, config: BacktestConfig) -> Self {
+        self.backtest_config = config;
+        self
+    }
+
+    /// 전략 ID
+    pub fn strategy_id(&self) -> &str {
+        &self.strategy_id
+    }
+
+    /// 탐색 공간
+    pub fn space(&self) -> &ParameterSpace {
+        &self.space
+    }
+
+    /// 백테스트 설정
+    pub fn backtest_config(&self) -> &BacktestConfig {
+        &self.backtest_config
+    }
+
+    /// 목적 함수
+    pub fn objective(&self) -> OptimizationObjective {
+        self.objective
+    }
+
+    /// 평가할 파라미터 조합을 생성합니다.
+    pub fn combinations(&self) -> OptimizerResult<Vec<Map<String, Value>>> {
+        self.space.validate()?;
+
+        let combos = match &self.method {
+            SearchMethod::Grid => {
+                let count = self.space.combination_count();
+                if count > self.max_combinations {
+                    return Err(OptimizerError::TooManyCombinations {
+                        count,
+                        max: self.max_combinations,
+                    });
+                }
+                self.space.grid()
+            }
+            SearchMethod::Random { samples, seed } => {
+                if *samples > self.max_combinations {
+                    return Err(OptimizerError::TooManyCombinations {
+                        count: *samples,
+                        max: self.max_combinations,
+                    });
+                }
+                self.space.sample(*samples, *seed)
+            }
+        };
+        Ok(combos)
+    }
+
+    /// 최적화를 실행합니다.
+    pub async fn run(&self, klines: &[Kline]) -> OptimizerResult<OptimizationResult> {
+        self.run_with_progress(klines, |_, _| {}).await
+    }
+
+    /// 최적화를 실행하며 조합이 완료될 때마다 `(완료 수, 전체 수)`로 진행 상황을 알립니다.
+    pub async fn run_with_progress<F>(
+        &self,
+        klines: &[Kline],
+        mut on_progress: F,
+    ) -> OptimizerResult<OptimizationResult>
+    where
+        F: FnMut(usize, usize) + Send,
+    {
+        if klines.is_empty() {
+            return Err(OptimizerError::DataError(
+                "캔들 데이터가 없습니다".to_string(),
+            ));
+        }
+        if StrategyRegistry::find(&self.strategy_id).is_none() {
+            return Err(OptimizerError::StrategyNotFound(self.strategy_id.clone()));
+        }
+
+        let combos = self.combinations()?;
+        let total = combos.len();
+        info!(
+            strategy_id = %self.strategy_id,
+            objective = self.objective.name(),
+            combinations = total,
+            parallelism = self.parallelism,
+            "파라미터 최적화 시작"
+        );
+
+        let klines = Arc::new(klines.to_vec());
+        let mut evaluations = stream::iter(combos)
+            .map(|params| {
+                let strategy_id = self.strategy_id.clone();
+                let config = self.backtest_config.clone();
+                let context = self.base_context.clone();
+                let strategy_params = self.merge_params(&params, &klines);
+                let klines = Arc::clone(&klines);
+                async move {
+                    let outcome = tokio::task::spawn_blocking(move || {
+                        evaluate(&strategy_id, config, context, &klines, strategy_params)
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(format!("백테스트 태스크 실행 실패: {}", e)));
+                    (params, outcome)
+                }
+            })
+            .buffer_unordered(self.parallelism);
+
+        let mut runs = Vec::with_capacity(total);
+        let mut failures = Vec::new();
+        let mut completed = 0;
+        while let Some((params, outcome)) = evaluations.next().await {
+            match outcome {
+                Ok(report) => runs.push(OptimizationRun {
+                    rank: 0,
+                    score: self.objective.score(&report.metrics),
+                    params,
+                    metrics: report.metrics,
+                }),
+                Err(error) => {
+                    warn!(?params, %error, "파라미터 조합 백테스트 실패");
+                    failures.push(OptimizationFailure { params, error });
+                }
+            }
+            completed += 1;
+            on_progress(completed, total);
+        }
+
+        // 점수 내림차순, 동점이면 총 수익률 내림차순
+        runs.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| b.metrics.total_return_pct.cmp(&a.metrics.total_return_pct))
+        });
+        for (i, run) in runs.iter_mut().enumerate() {
+            run.rank = i + 1;
+        }
+
+        debug!(
+            evaluated = runs.len(),
+            failed = failures.len(),
+            "파라미터 최적화 완료"
+        );
+
+        Ok(OptimizationResult {
+            strategy_id: self.strategy_id.clone(),
+            objective: self.objective,
+            method: self.method.clone(),
+            space: self.space.clone(),
+            runs,
+            failures,
+        })
+    }
+
+    /// 지정한 파라미터 조합으로 단일 백테스트를 실행하고 전체 리포트를 반환합니다.
+    pub async fn backtest(
+        &self,
+        params: &Map<String, Value>,
+        klines: &[Kline],
+    ) -> OptimizerResult<BacktestReport> {
+        if StrategyRegistry::find(&self.strategy_id).is_none() {
+            return Err(OptimizerError::StrategyNotFound(self.strategy_id.clone()));
+        }
+
+        let strategy_id = self.strategy_id.clone();
+        let config = self.backtest_config.clone();
+        let context = self.base_context.clone();
+        let strategy_params = self.merge_params(params, klines);
+        let klines = klines.to_vec();
+        tokio::task::spawn_blocking(move || {
+            evaluate(&strategy_id, config, context, &klines, strategy_params)
+        })
+        .await
+        .unwrap_or_else(|e| Err(format!("백테스트 태스크 실행 실패: {}", e)))
+        .map_err(OptimizerError::BacktestFailed)
+    }
+
+    /// 기본 설정에 탐색 값을 덮어쓰고, 티커가 없으면 캔들 데이터의 티커를 주입합니다.
+    fn merge_params(&self, params: &Map<String, Value>, klines: &[Kline]) -> Value {
+        let mut merged = match &self.base_params {
+            Value::Object(map) => map.clone(),
+            _ => Map::new(),
+        };
+        if !merged.contains_key("ticker") {
+            if let Some(kline) = klines.first() {
+                merged.insert("ticker".to_string(), Value::from(kline.ticker.clone()));
+            }
+        }
+        for (key, value) in params {
+            merged.insert(key.clone(), value.clone());
+        }
+        Value::Object(merged)
+    }
+}
+
+/// 단일 조합 백테스트 (blocking 컨텍스트에서 실행)
+fn evaluate(
+    strategy_id: &str,
+    config: BacktestConfig,
+    context: StrategyContext,
+    klines: &[Kline],
+    params: Value,
+) -> Result<BacktestReport, String> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("Runtime 생성 실패: {}", e))?;
+
+    let ticker = params
+        .get("ticker")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .or_else(|| klines.first().map(|k| k.ticker.clone()))
+        .unwrap_or_default();
+
+    rt.block_on(async {
+        let context = Arc::new(RwLock::new(context));
+        let mut strategy = StrategyRegistry::create_instance(strategy_id)?;
+        // StrategyContext는 initialize 전에 주입해야 함
+        strategy.set_context(Arc::clone(&context));
+        strategy
+            .initialize(params)
+            .await
+            .map_err(|e| format!("전략 초기화 실패: {}", e))?;
+
+        let mut engine = BacktestEngine::new(config);
+        engine
+            .run_with_context(&mut *strategy, klines, context, &ticker)
+            .await
+            .map_err(|e| e.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+    use rust_decimal_macros::dec;
+    use trader_core::Timeframe;
+
+    fn integer_field(name: &str, min: f64, max: f64, step: Option<f64>) -> FieldSchema {
+        FieldSchema {
+            name: name.to_string(),
+            field_type: FieldType::Integer,
+            min: Some(min),
+            max: Some(max),
+            step,
+            section: Some("indicator".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn oscillating_klines(count: usize) -> Vec<Kline> {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..count)
+            .map(|i| {
+                // 하락/상승 구간이 반복되어 RSI 과매도/과매수가 발생하도록 구성
+                let phase = (i % 40) as i64;
+                let offset = if phase < 20 { -phase } else { phase - 40 };
+                let close = Decimal::from(1000 + offset * 15);
+                Kline::new(
+                    "TEST".to_string(),
+                    Timeframe::D1,
+                    base + Duration::days(i as i64),
+                    close,
+                    close + dec!(5),
+                    close - dec!(5),
+                    close,
+                    dec!(10000),
+                    base + Duration::days(i as i64 + 1),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_range_from_field() {
+        let stepped =
+            ParameterRange::from_field(&integer_field("period", 10.0, 20.0, Some(5.0))).unwrap();
+        assert_eq!(
+            stepped.values(),
+            vec![Value::from(10), Value::from(15), Value::from(20)]
+        );
+
+        // step이 없으면 DEFAULT_GRID_POINTS개 지점으로 분할
+        let default_step =
+            ParameterRange::from_field(&integer_field("period", 0.0, 100.0, None)).unwrap();
+        assert_eq!(default_step.len(), DEFAULT_GRID_POINTS);
+
+        let number = FieldSchema {
+            name: "threshold".to_string(),
+            field_type: FieldType::Number,
+            min: Some(0.1),
+            max: Some(0.3),
+            step: Some(0.1),
+            ..Default::default()
+        };
+        let values = ParameterRange::from_field(&number).unwrap().values();
+        assert_eq!(
+            values,
+            vec![Value::from(0.1), Value::from(0.2), Value::from(0.3)]
+        );
+
+        let symbol = FieldSchema {
+            name: "ticker".to_string(),
+            field_type: FieldType::Symbol,
+            ..Default::default()
+        };
+        assert!(ParameterRange::from_field(&symbol).is_none());
+    }
+
+    #[test]
+    fn test_space_from_ui_schema_skips_asset_section() {
+        let schema = StrategyUISchema::new("test", "테스트", "Intraday")
+            .with_custom_field(FieldSchema {
+                name: "amount".to_string(),
+                field_type: FieldType::Number,
+                min: Some(1000.0),
+                max: Some(100000.0),
+                section: Some("asset".to_string()),
+                ..Default::default()
+            })
+            .with_custom_field(integer_field("period", 2.0, 10.0, Some(2.0)))
+            .with_custom_field(FieldSchema {
+                hidden: true,
+                ..integer_field("internal", 0.0, 1.0, None)
+            });
+
+        let space = ParameterSpace::from_ui_schema(&schema);
+        assert_eq!(space.ranges.len(), 1);
+        assert_eq!(space.ranges[0].name, "period");
+
+        assert!(matches!(
+            space.clone().select(&["amount"]),
+            Err(OptimizerError::UnknownParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_customize_includes_overridden_ranges() {
+        let space = ParameterSpace::new(vec![
+            ParameterRange::new("a", 1.0, 3.0, 1.0),
+            ParameterRange::new("b", 1.0, 3.0, 1.0),
+            ParameterRange::new("c", 1.0, 3.0, 1.0),
+        ]);
+
+        let custom = space
+            .clone()
+            .customize(&["a"], vec![ParameterRange::new("b", 5.0, 6.0, 1.0)])
+            .unwrap();
+        let names: Vec<&str> = custom.ranges.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(custom.ranges[1].min, 5.0);
+
+        // 이름을 지정하지 않으면 전체 공간 유지
+        let all = space.customize::<&str>(&[], Vec::new()).unwrap();
+        assert_eq!(all.ranges.len(), 3);
+    }
+
+    #[test]
+    fn test_grid_enumerates_all_combinations() {
+        let space = ParameterSpace::new(vec![
+            ParameterRange::new("a", 1.0, 3.0, 1.0).integer(),
+            ParameterRange::new("b", 0.0, 1.0, 0.5),
+        ]);
+        assert_eq!(space.combination_count(), 9);
+
+        let grid = space.grid();
+        assert_eq!(grid.len(), 9);
+        assert_eq!(grid[0]["a"], Value::from(1));
+        assert_eq!(grid[0]["b"], Value::from(0.0));
+        assert_eq!(grid[8]["a"], Value::from(3));
+        assert_eq!(grid[8]["b"], Value::from(1.0));
+    }
+
+    #[test]
+    fn test_random_sample_is_unique_and_reproducible() {
+        let space = ParameterSpace::new(vec![
+            ParameterRange::new("a", 1.0, 10.0, 1.0).integer(),
+            ParameterRange::new("b", 1.0, 10.0, 1.0).integer(),
+        ]);
+
+        let first = space.sample(20, Some(7));
+        let second = space.sample(20, Some(7));
+        assert_eq!(first, second);
+
+        let unique: HashSet<String> = first.iter().map(|m| format!("{:?}", m)).collect();
+        assert_eq!(unique.len(), 20);
+
+        // 전체 조합 수보다 많이 요청하면 전체 조합 수로 제한
+        let small = ParameterSpace::new(vec![ParameterRange::new("a", 1.0, 3.0, 1.0)]);
+        assert_eq!(small.sample(10, Some(1)).len(), 3);
+    }
+
+    #[test]
+    fn test_grid_rejects_too_many_combinations() {
+        let space = ParameterSpace::new(vec![
+            ParameterRange::new("a", 0.0, 99.0, 1.0),
+            ParameterRange::new("b", 0.0, 99.0, 1.0),
+        ]);
+        let optimizer = ParameterOptimizer::new("rsi", BacktestConfig::default(), space)
+            .with_max_combinations(500);
+        assert!(matches!(
+            optimizer.combinations(),
+            Err(OptimizerError::TooManyCombinations {
+                count: 10000,
+                max: 500
+            })
+        ));
+    }
+
+    #[test]
+    fn test_objective_score() {
+        let metrics = PerformanceMetrics {
+            sharpe_ratio: dec!(1.5),
+            calmar_ratio: dec!(2.0),
+            expectancy: dec!(12.5),
+            ..Default::default()
+        };
+        assert_eq!(OptimizationObjective::Sharpe.score(&metrics), dec!(1.5));
+        assert_eq!(OptimizationObjective::Calmar.score(&metrics), dec!(2.0));
+        assert_eq!(
+            OptimizationObjective::Expectancy.score(&metrics),
+            dec!(12.5)
+        );
+        assert_eq!(
+            OptimizationObjective::parse("calmar_ratio"),
+            Some(OptimizationObjective::Calmar)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_optimizer_ranks_grid_strategy() {
+        let space = ParameterSpace::for_strategy("grid")
+            .unwrap()
+            .select(&["spacing_pct", "levels"])
+            .unwrap()
+            .with_range(ParameterRange::new("spacing_pct", 1.0, 3.0, 1.0))
+            .with_range(ParameterRange::new("levels", 2.0, 6.0, 2.0).integer());
+        let config = BacktestConfig::new(dec!(10_000_000)).with_commission_rate(Decimal::ZERO);
+
+        let optimizer = ParameterOptimizer::new("grid", config, space)
+            .with_base_params(serde_json::json!({"amount": "1000000"}))
+            .with_objective(OptimizationObjective::TotalReturn)
+            .with_parallelism(2);
+
+        let mut progress = Vec::new();
+        let result = optimizer
+            .run_with_progress(&oscillating_klines(200), |done, total| {
+                progress.push((done, total))
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.evaluated(), 9);
+        assert!(result.failures.is_empty(), "{:?}", result.failures);
+        assert_eq!(progress.last(), Some(&(9, 9)));
+        assert_eq!(result.best().unwrap().rank, 1);
+        assert!(result.runs.iter().any(|r| r.metrics.total_trades > 0));
+        assert!(result.runs.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!(result.summary(3).contains("spacing_pct"));
+    }
+}
@@ -0,0 +1,1306 @@
+//! 다중 전략 포트폴리오 백테스트
+//!
+//! 여러 전략(슬리브)을 하나의 계좌에서 동시에 시뮬레이션합니다.
+//! 모든 전략은 하나의 현금 잔고와 `max_positions` 제한을 공유하며,
+//! 전략별 자본 배분 비중에 따라 신규 진입 규모가 결정됩니다.
+//!
+//! # 자본 배분
+//!
+//! - 슬리브의 목표 자본은 `비중 × 포트폴리오 자산`입니다.
+//! - 신규 진입 금액은 슬리브의 여유 자본(목표 자본 - 보유 포지션 평가액)에
+//!   `max_position_size_pct`와 신호 강도를 곱해 계산하며, 공유 현금이 부족하면 무시됩니다.
+//! - 리밸런싱은 비중만 다시 계산하며 보유 포지션을 강제로 조정하지는 않습니다.
+//! - 전략의 목표 포트폴리오(`SignalType::Rebalance`) 신호는 슬리브 자본
+//!   (보유 포지션 + 여유 자본) 안에서 종목별 주문으로 분해됩니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::backtest::{
+//!     AllocationMethod, BacktestConfig, PortfolioBacktestConfig, PortfolioBacktestEngine,
+//!     PortfolioSleeve,
+//! };
+//!
+//! let config = PortfolioBacktestConfig::new(BacktestConfig::new(dec!(100_000_000)))
+//!     .with_allocation(AllocationMethod::EqualRisk)
+//!     .with_rebalance_bars(20);
+//!
+//! let mut engine = PortfolioBacktestEngine::new(config)
+//!     .with_sleeve(PortfolioSleeve::new("asset_allocation", allocation).with_weight(dec!(0.5)))
+//!     .with_sleeve(PortfolioSleeve::new("rotation", rotation).with_weight(dec!(0.3)))
+//!     .with_sleeve(PortfolioSleeve::new("day_trading", day_trading).with_weight(dec!(0.2)));
+//!
+//! // 모든 심볼의 캔들을 시간순으로 병합하여 전달
+//! let report = engine.run(&merged_klines).await?;
+//! for strategy in &report.strategies {
+//!     println!("{}: {:.2}%", strategy.strategy_id, strategy.metrics.total_return_pct);
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use trader_core::{Kline, MarketData, Signal};
+use trader_strategy::{Strategy, StrategyScheduler, TradingCalendar, WeekdayCalendar};
+
+use crate::backtest::engine::{BacktestConfig, BacktestError, BacktestResult};
+use crate::backtest::execution::{FillSimulator, FillSizing};
+use crate::correlation::{calculate_correlation_matrix, CorrelationMatrix};
+use crate::performance::{EquityPoint, PerformanceMetrics, PerformanceTracker, RoundTrip};
+
+/// 기본 비중 재계산 주기 (캔들 시점 수)
+pub const DEFAULT_REBALANCE_BARS: usize = 20;
+
+/// 기본 위험/성과 추정 구간 (캔들 시점 수)
+pub const DEFAULT_LOOKBACK_BARS: usize = 60;
+
+/// 전략별 자본 배분 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AllocationMethod {
+    /// 슬리브에 지정한 고정 비중
+    #[default]
+    FixedWeight,
+    /// 최근 수익률 변동성의 역수 비중 (전략별 위험 기여 균등화)
+    EqualRisk,
+    /// 최근 위험 조정 수익률(샤프 비율)에 비례하는 비중
+    Dynamic,
+}
+
+/// 포트폴리오 백테스트 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioBacktestConfig {
+    /// 공통 백테스트 설정 (초기 자본, 수수료, 슬리피지, 최대 포지션 수 등)
+    pub backtest: BacktestConfig,
+
+    /// 자본 배분 방식
+    #[serde(default)]
+    pub allocation: AllocationMethod,
+
+    /// 비중 재계산 주기 (캔들 시점 수)
+    #[serde(default = "default_rebalance_bars")]
+    pub rebalance_bars: usize,
+
+    /// 위험/성과 추정 구간 (캔들 시점 수)
+    #[serde(default = "default_lookback_bars")]
+    pub lookback_bars: usize,
+
+    /// 동일 위험/동적 배분 시 슬리브별 최소 비중 (0 이상 1 미만)
+    #[serde(default)]
+    pub min_weight: f64,
+}
+
+fn default_rebalance_bars() -> usize {
+    DEFAULT_REBALANCE_BARS
+}
+
+fn default_lookback_bars() -> usize {
+    DEFAULT_LOOKBACK_BARS
+}
+
+impl PortfolioBacktestConfig {
+    /// 공통 백테스트 설정으로 포트폴리오 설정을 생성합니다.
+    pub fn new(backtest: BacktestConfig) -> Self {
+        Self {
+            backtest,
+            allocation: AllocationMethod::default(),
+            rebalance_bars: DEFAULT_REBALANCE_BARS,
+            lookback_bars: DEFAULT_LOOKBACK_BARS,
+            min_weight: 0.0,
+        }
+    }
+
+    /// 자본 배분 방식 설정
+    pub fn with_allocation(mut self, allocation: AllocationMethod) -> Self {
+        self.allocation = allocation;
+        self
+    }
+
+    /// 비중 재계산 주기 설정
+    pub fn with_rebalance_bars(mut self, bars: usize) -> Self {
+        self.rebalance_bars = bars;
+        self
+    }
+
+    /// 위험/성과 추정 구간 설정
+    pub fn with_lookback_bars(mut self, bars: usize) -> Self {
+        self.lookback_bars = bars;
+        self
+    }
+
+    /// 슬리브별 최소 비중 설정
+    pub fn with_min_weight(mut self, weight: f64) -> Self {
+        self.min_weight = weight;
+        self
+    }
+
+    /// 설정 검증
+    pub fn validate(&self) -> BacktestResult<()> {
+        self.backtest.validate()?;
+
+        if self.rebalance_bars == 0 {
+            return Err(BacktestError::ConfigError(
+                "리밸런싱 주기는 0보다 커야 합니다".to_string(),
+            ));
+        }
+        if self.lookback_bars < 2 {
+            return Err(BacktestError::ConfigError(
+                "위험 추정 구간은 2 이상이어야 합니다".to_string(),
+            ));
+        }
+        if !(0.0..1.0).contains(&self.min_weight) {
+            return Err(BacktestError::ConfigError(
+                "최소 비중은 0 이상 1 미만이어야 합니다".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 포트폴리오에 편입되는 전략 슬리브
+pub struct PortfolioSleeve {
+    /// 슬리브 식별자 (리포트 및 거래 기록의 전략 ID)
+    strategy_id: String,
+    /// 전략 인스턴스 (초기화 완료 상태)
+    strategy: Box<dyn Strategy>,
+    /// 고정 배분 비중 (정규화 전)
+    weight: Decimal,
+    /// 전략에 전달할 심볼 (None이면 모든 캔들 전달)
+    tickers: Option<HashSet<String>>,
+}
+
+impl PortfolioSleeve {
+    /// 초기화된 전략으로 슬리브를 생성합니다 (비중 1).
+    pub fn new(strategy_id: impl Into<String>, strategy: Box<dyn Strategy>) -> Self {
+        Self {
+            strategy_id: strategy_id.into(),
+            strategy,
+            weight: Decimal::ONE,
+            tickers: None,
+        }
+    }
+
+    /// 고정 배분 비중 설정 (슬리브 간 상대값, 합계로 정규화)
+    pub fn with_weight(mut self, weight: Decimal) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// 전략에 전달할 심볼 제한
+    pub fn with_tickers<I, S>(mut self, tickers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tickers = Some(tickers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// 슬리브 식별자
+    pub fn strategy_id(&self) -> &str {
+        &self.strategy_id
+    }
+
+    /// 해당 심볼의 캔들을 전략에 전달하는지 확인합니다.
+    fn accepts(&self, ticker: &str) -> bool {
+        self.tickers
+            .as_ref()
+            .map(|tickers| tickers.contains(ticker))
+            .unwrap_or(true)
+    }
+}
+
+/// 리밸런싱 시점의 전략별 비중
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllocationSnapshot {
+    /// 비중 적용 시각
+    pub timestamp: DateTime<Utc>,
+    /// 전략 ID → 비중 (합계 1)
+    pub weights: HashMap<String, f64>,
+}
+
+/// 전략(슬리브)별 성과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyPerformance {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 시작 비중
+    pub initial_weight: f64,
+    /// 종료 시점 비중
+    pub final_weight: f64,
+    /// 배분 자본 (시작 비중 × 초기 자본)
+    pub initial_capital: Decimal,
+    /// 최종 자산 (배분 자본 + 실현 손익)
+    pub final_equity: Decimal,
+    /// 성과 지표
+    pub metrics: PerformanceMetrics,
+    /// 슬리브 자산 곡선
+    pub equity_curve: Vec<EquityPoint>,
+}
+
+/// 포트폴리오 백테스트 리포트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioBacktestReport {
+    /// 설정 정보
+    pub config: PortfolioBacktestConfig,
+
+    /// 포트폴리오 전체 성과 지표
+    pub metrics: PerformanceMetrics,
+
+    /// 완료된 거래 (라운드트립, `strategy_id`로 전략 구분)
+    pub trades: Vec<RoundTrip>,
+
+    /// 포트폴리오 자산 곡선
+    pub equity_curve: Vec<EquityPoint>,
+
+    /// 전략별 성과
+    pub strategies: Vec<StrategyPerformance>,
+
+    /// 전략 간 수익률 상관행렬 (슬리브 자산 곡선 기준, 전략 2개 이상)
+    pub correlation: Option<CorrelationMatrix>,
+
+    /// 비중 변경 이력 (시작 비중 포함)
+    pub allocation_history: Vec<AllocationSnapshot>,
+
+    /// 총 거래 횟수 (진입 + 청산)
+    pub total_orders: usize,
+
+    /// 총 수수료
+    pub total_commission: Decimal,
+
+    /// 총 슬리피지 비용
+    pub total_slippage: Decimal,
+
+    /// 백테스트 기간 시작
+    pub start_time: DateTime<Utc>,
+
+    /// 백테스트 기간 종료
+    pub end_time: DateTime<Utc>,
+
+    /// 데이터 포인트 수 (캔들 시점 기준)
+    pub data_points: usize,
+
+    /// 벤치마크 자산 곡선 (`equity_curve`와 같은 시점, 벤치마크 설정 시)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub benchmark_curve: Vec<EquityPoint>,
+}
+
+impl PortfolioBacktestReport {
+    /// 전략 ID로 전략별 성과를 찾습니다.
+    pub fn strategy(&self, strategy_id: &str) -> Option<&StrategyPerformance> {
+        self.strategies
+            .iter()
+            .find(|s| s.strategy_id == strategy_id)
+    }
+
+    /// 요약 문자열 반환
+    pub fn summary(&self) -> String {
+        let mut lines = vec![
+            "포트폴리오 백테스트 결과 요약".to_string(),
+            "═══════════════════════════════════════".to_string(),
+            format!(
+                "기간: {} → {} ({} 일)",
+                self.start_time.format("%Y-%m-%d"),
+                self.end_time.format("%Y-%m-%d"),
+                (self.end_time - self.start_time).num_days()
+            ),
+            format!("배분 방식: {:?}", self.config.allocation),
+            format!("총 수익률: {:.2}%", self.metrics.total_return_pct),
+            format!("최대 낙폭: {:.2}%", self.metrics.max_drawdown_pct),
+            format!("총 거래: {}", self.metrics.total_trades),
+            "───────────────────────────────────────".to_string(),
+        ];
+
+        for strategy in &self.strategies {
+            lines.push(format!(
+                "{}: 비중 {:.1}% → {:.1}%, 수익률 {:.2}%, 거래 {}",
+                strategy.strategy_id,
+                strategy.initial_weight * 100.0,
+                strategy.final_weight * 100.0,
+                strategy.metrics.total_return_pct,
+                strategy.metrics.total_trades
+            ));
+        }
+        lines.push("═══════════════════════════════════════".to_string());
+
+        lines.join("\n")
+    }
+}
+
+/// 다중 전략 포트폴리오 백테스트 엔진
+pub struct PortfolioBacktestEngine {
+    /// 설정
+    config: PortfolioBacktestConfig,
+    /// 전략 슬리브
+    sleeves: Vec<PortfolioSleeve>,
+    /// 스케줄 트리거용 거래일 캘린더
+    calendar: Arc<dyn TradingCalendar>,
+}
+
+impl PortfolioBacktestEngine {
+    /// 새로운 포트폴리오 백테스트 엔진을 생성합니다.
+    pub fn new(config: PortfolioBacktestConfig) -> Self {
+        Self {
+            config,
+            sleeves: Vec::new(),
+            calendar: Arc::new(WeekdayCalendar::default()),
+        }
+    }
+
+    /// 스케줄 트리거에 사용할 거래일 캘린더 설정 (빌더, 기본값: 주말만 휴장)
+    pub fn with_calendar(mut self, calendar: Arc<dyn TradingCalendar>) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 전략 슬리브 추가 (빌더)
+    pub fn with_sleeve(mut self, sleeve: PortfolioSleeve) -> Self {
+        self.sleeves.push(sleeve);
+        self
+    }
+
+    /// 전략 슬리브 추가
+    pub fn add_sleeve(&mut self, sleeve: PortfolioSleeve) {
+        self.sleeves.push(sleeve);
+    }
+
+    /// 설정 반환
+    pub fn config(&self) -> &PortfolioBacktestConfig {
+        &self.config
+    }
+
+    /// 포트폴리오 백테스트를 실행합니다.
+    ///
+    /// # 매개변수
+    ///
+    /// * `klines` - 모든 심볼의 캔들을 시간순으로 병합한 데이터.
+    ///   같은 `open_time`의 캔들은 하나의 시점으로 묶어 자산과 비중을 갱신합니다.
+    pub async fn run(&mut self, klines: &[Kline]) -> BacktestResult<PortfolioBacktestReport> {
+        self.config.validate()?;
+        let base_weights = self.validate_sleeves()?;
+
+        if klines.is_empty() {
+            return Err(BacktestError::DataError(
+                "캔들 데이터가 비어있습니다".to_string(),
+            ));
+        }
+
+        // 시간순 정렬 확인
+        for window in klines.windows(2) {
+            if window[0].open_time > window[1].open_time {
+                return Err(BacktestError::DataError(
+                    "캔들 데이터가 시간순으로 정렬되어 있지 않습니다".to_string(),
+                ));
+            }
+        }
+
+        let bars = group_by_open_time(klines);
+
+        let warmup = self.config.backtest.warmup_bars;
+        if warmup >= bars.len() {
+            return Err(BacktestError::DataError(format!(
+                "워밍업 시점 수({})가 데이터 시점 수({}) 이상입니다",
+                warmup,
+                bars.len()
+            )));
+        }
+
+        let start_time = bars[warmup][0].open_time;
+        let end_time = klines.last().unwrap().close_time;
+        let data_points = bars.len() - warmup;
+
+        let strategy_ids: Vec<String> =
+            self.sleeves.iter().map(|s| s.strategy_id.clone()).collect();
+        let mut sim = PortfolioSimulation::new(
+            &self.config.backtest,
+            &strategy_ids,
+            &base_weights,
+            start_time,
+        );
+
+        let mut allocation_history = vec![sim.allocation_snapshot(start_time)];
+        let mut bars_since_rebalance = 0;
+
+        // 슬리브별 스케줄 트리거 (시뮬레이션 시간 기준)
+        let mut scheduler = StrategyScheduler::new(Arc::clone(&self.calendar));
+        for sleeve in &self.sleeves {
+            scheduler.register(
+                sleeve.strategy_id.clone(),
+                sleeve.strategy.schedule_triggers(),
+                bars[0][0].open_time,
+            );
+        }
+
+        for (idx, bar) in bars.iter().enumerate() {
+            // 워밍업 구간: 전략 상태만 갱신하고 신호는 버림
+            let warming_up = idx < warmup;
+
+            for kline in bar.iter() {
+                // 캔들 시작 시점: 대기 신호 및 손절/익절 주문 체결
+                if !warming_up {
+                    sim.on_bar_open(kline)?;
+                }
+
+                // 캔들 완성 시점으로 현재 시간 설정 (데이터 누수 방지)
+                let ticker = kline.ticker.to_string();
+                sim.sim.on_bar_close(kline);
+
+                let market_data =
+                    MarketData::from_kline(&self.config.backtest.exchange_name, kline.clone());
+
+                for (sleeve_idx, sleeve) in self.sleeves.iter_mut().enumerate() {
+                    if !sleeve.accepts(&ticker) {
+                        continue;
+                    }
+
+                    let signals =
+                        sleeve
+                            .strategy
+                            .on_market_data(&market_data)
+                            .await
+                            .map_err(|e| {
+                                BacktestError::StrategyError(format!(
+                                    "{}: {}",
+                                    sleeve.strategy_id, e
+                                ))
+                            })?;
+
+                    if warming_up {
+                        continue;
+                    }
+                    for signal in &signals {
+                        sim.process_signal(sleeve_idx, signal, kline)?;
+                    }
+                }
+
+                // 완성된 캔들로 ATR 갱신 (다음 체결의 슬리피지 계산용)
+                sim.sim.update_atr(kline);
+            }
+
+            // 시점의 캔들이 모두 완성된 뒤 도래한 스케줄 트리거 처리
+            let bar_close = bar.iter().map(|k| k.close_time).max().unwrap_or(start_time);
+            for event in scheduler.advance(bar_close).await {
+                let Some(sleeve_idx) = self
+                    .sleeves
+                    .iter()
+                    .position(|s| s.strategy_id == event.strategy_id)
+                else {
+                    continue;
+                };
+                let signals = self.sleeves[sleeve_idx]
+                    .strategy
+                    .on_schedule(&event)
+                    .await
+                    .map_err(|e| {
+                        BacktestError::StrategyError(format!("{}: {}", event.strategy_id, e))
+                    })?;
+
+                if warming_up {
+                    continue;
+                }
+                for signal in &signals {
+                    // 신호 종목의 캔들이 없으면 (목표 포트폴리오 등) 시점의 마지막 캔들 기준
+                    let kline = bar
+                        .iter()
+                        .find(|k| k.ticker == signal.ticker)
+                        .unwrap_or(&bar[bar.len() - 1]);
+                    sim.process_signal(sleeve_idx, signal, kline)?;
+                }
+            }
+
+            if warming_up {
+                continue;
+            }
+
+            let timestamp = bar_close;
+            sim.record_bar(timestamp);
+
+            bars_since_rebalance += 1;
+            if self.config.allocation != AllocationMethod::FixedWeight
+                && bars_since_rebalance >= self.config.rebalance_bars
+            {
+                bars_since_rebalance = 0;
+                let weights = compute_weights(
+                    self.config.allocation,
+                    &sim.recent_histories(self.config.lookback_bars),
+                    &base_weights,
+                    &sim.weights(),
+                    self.config.min_weight,
+                );
+                if sim.set_weights(&weights) {
+                    allocation_history.push(sim.allocation_snapshot(timestamp));
+                }
+            }
+        }
+
+        // 미청산 포지션 강제 청산
+        sim.close_all_positions(klines.last().unwrap())?;
+
+        Ok(sim.into_report(
+            self.config.clone(),
+            &base_weights,
+            allocation_history,
+            start_time,
+            end_time,
+            data_points,
+        ))
+    }
+
+    /// 슬리브 구성을 검증하고 정규화된 고정 비중을 반환합니다.
+    fn validate_sleeves(&self) -> BacktestResult<Vec<f64>> {
+        if self.sleeves.is_empty() {
+            return Err(BacktestError::ConfigError(
+                "포트폴리오에 전략이 없습니다".to_string(),
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        for sleeve in &self.sleeves {
+            if !seen.insert(sleeve.strategy_id.as_str()) {
+                return Err(BacktestError::ConfigError(format!(
+                    "중복된 전략 ID: {}",
+                    sleeve.strategy_id
+                )));
+            }
+            if sleeve.weight <= Decimal::ZERO {
+                return Err(BacktestError::ConfigError(format!(
+                    "전략 비중은 0보다 커야 합니다: {}",
+                    sleeve.strategy_id
+                )));
+            }
+        }
+
+        if self.config.min_weight * self.sleeves.len() as f64 > 1.0 {
+            return Err(BacktestError::ConfigError(
+                "최소 비중 × 전략 수가 1을 초과합니다".to_string(),
+            ));
+        }
+
+        let weights: Vec<f64> = self
+            .sleeves
+            .iter()
+            .map(|s| s.weight.to_f64().unwrap_or(0.0))
+            .collect();
+        Ok(normalize(&weights).unwrap_or_else(|| equal_weights(weights.len())))
+    }
+}
+
+/// 슬리브별 시뮬레이션 상태
+struct SleeveState {
+    /// 전략 ID
+    strategy_id: String,
+    /// 배분 자본 (시작 비중 기준)
+    initial_capital: Decimal,
+    /// 현재 비중
+    weight: f64,
+    /// 누적 현금 흐름 (진입 시 -, 청산 시 +)
+    cash_flow: Decimal,
+    /// 슬리브 성과 추적기
+    tracker: PerformanceTracker,
+    /// 시점별 슬리브 자산 (위험 추정 및 상관관계 계산용)
+    equity_history: Vec<f64>,
+}
+
+/// 포트폴리오 시뮬레이션 상태 (공유 체결 시뮬레이터 + 슬리브별 성과)
+struct PortfolioSimulation {
+    /// 공유 현금, 포지션(슬리브 인덱스별), 대기 주문
+    sim: FillSimulator<usize>,
+    /// 슬리브 상태
+    sleeves: Vec<SleeveState>,
+    /// 포트폴리오 자산 곡선 추적기
+    tracker: PerformanceTracker,
+}
+
+impl PortfolioSimulation {
+    fn new(
+        config: &BacktestConfig,
+        strategy_ids: &[String],
+        weights: &[f64],
+        start_time: DateTime<Utc>,
+    ) -> Self {
+        let new_tracker = |capital: Decimal| {
+            let mut tracker = PerformanceTracker::new(capital)
+                .with_risk_free_rate(config.risk_free_rate)
+                .without_equity_history_limit();
+            tracker.set_initial_timestamp(start_time);
+            tracker
+        };
+
+        let sleeves = strategy_ids
+            .iter()
+            .zip(weights)
+            .map(|(strategy_id, &weight)| {
+                let initial_capital =
+                    config.initial_capital * Decimal::from_f64(weight).unwrap_or(Decimal::ZERO);
+                SleeveState {
+                    strategy_id: strategy_id.clone(),
+                    initial_capital,
+                    weight,
+                    cash_flow: Decimal::ZERO,
+                    tracker: new_tracker(initial_capital),
+                    equity_history: Vec::new(),
+                }
+            })
+            .collect();
+
+        Self {
+            sim: FillSimulator::new(config.clone(), start_time),
+            sleeves,
+            tracker: new_tracker(config.initial_capital),
+        }
+    }
+
+    /// 현재 비중 목록
+    fn weights(&self) -> Vec<f64> {
+        self.sleeves.iter().map(|s| s.weight).collect()
+    }
+
+    /// 비중을 갱신합니다. 변경이 있으면 true를 반환합니다.
+    fn set_weights(&mut self, weights: &[f64]) -> bool {
+        let changed = self
+            .sleeves
+            .iter()
+            .zip(weights)
+            .any(|(sleeve, &weight)| (sleeve.weight - weight).abs() > f64::EPSILON);
+
+        for (sleeve, &weight) in self.sleeves.iter_mut().zip(weights) {
+            sleeve.weight = weight;
+        }
+        changed
+    }
+
+    fn allocation_snapshot(&self, timestamp: DateTime<Utc>) -> AllocationSnapshot {
+        AllocationSnapshot {
+            timestamp,
+            weights: self
+                .sleeves
+                .iter()
+                .map(|s| (s.strategy_id.clone(), s.weight))
+                .collect(),
+        }
+    }
+
+    /// 슬리브별 최근 자산 기록 (최대 `lookback + 1` 시점)
+    fn recent_histories(&self, lookback: usize) -> Vec<&[f64]> {
+        self.sleeves
+            .iter()
+            .map(|s| {
+                let start = s.equity_history.len().saturating_sub(lookback + 1);
+                &s.equity_history[start..]
+            })
+            .collect()
+    }
+
+    /// 신호를 처리합니다 (FillPolicy에 따라 즉시 또는 다음 캔들 시작 시 체결).
+    fn process_signal(
+        &mut self,
+        sleeve: usize,
+        signal: &Signal,
+        kline: &Kline,
+    ) -> BacktestResult<()> {
+        // 포지션과 거래 기록은 슬리브 전략 ID 기준
+        let mut signal = signal.clone();
+        signal.strategy_id = self.sleeves[sleeve].strategy_id.clone();
+
+        let sizing = SleeveSizing {
+            sleeves: &self.sleeves,
+        };
+        self.sim.submit_signal(sleeve, &signal, kline, &sizing);
+        self.record_fills()
+    }
+
+    /// 캔들 시작 시점의 체결(대기 신호, 손절/익절 주문)을 처리합니다.
+    fn on_bar_open(&mut self, kline: &Kline) -> BacktestResult<()> {
+        let sizing = SleeveSizing {
+            sleeves: &self.sleeves,
+        };
+        self.sim.on_bar_open(kline, &sizing);
+        self.record_fills()
+    }
+
+    /// 모든 포지션을 현재 가격으로 청산합니다.
+    fn close_all_positions(&mut self, kline: &Kline) -> BacktestResult<()> {
+        self.sim.close_all_positions(kline);
+        self.record_fills()
+    }
+
+    /// 시뮬레이터의 체결을 슬리브 현금 흐름과 추적기에 기록합니다.
+    fn record_fills(&mut self) -> BacktestResult<()> {
+        for fill in self.sim.take_fills() {
+            let sleeve = &mut self.sleeves[fill.owner];
+            sleeve.cash_flow += fill.cash_flow;
+            sleeve
+                .tracker
+                .record_trade(&fill.trade, fill.is_entry, Some(fill.strategy_id))
+                .map_err(|e| BacktestError::ExecutionError(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// 슬리브 자산 (배분 자본 + 현금 흐름 + 포지션 평가액)
+    fn sleeve_equity(&self, sleeve: usize) -> Decimal {
+        let state = &self.sleeves[sleeve];
+        state.initial_capital + state.cash_flow + self.sim.exposure(&sleeve)
+    }
+
+    /// 시점 종료 시 포트폴리오와 슬리브 자산을 기록합니다.
+    fn record_bar(&mut self, timestamp: DateTime<Utc>) {
+        let equity = self.sim.equity();
+        self.tracker.update_equity(timestamp, equity);
+
+        for idx in 0..self.sleeves.len() {
+            let equity = self.sleeve_equity(idx);
+            let sleeve = &mut self.sleeves[idx];
+            sleeve.tracker.update_equity(timestamp, equity);
+            sleeve.equity_history.push(equity.to_f64().unwrap_or(0.0));
+        }
+    }
+
+    /// 리포트를 생성합니다.
+    fn into_report(
+        self,
+        config: PortfolioBacktestConfig,
+        base_weights: &[f64],
+        allocation_history: Vec<AllocationSnapshot>,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        data_points: usize,
+    ) -> PortfolioBacktestReport {
+        let mut trades: Vec<RoundTrip> = self
+            .sleeves
+            .iter()
+            .flat_map(|s| s.tracker.get_round_trips().iter().cloned())
+            .collect();
+        trades.sort_by_key(|rt| rt.exit_time);
+
+        let equity_curve = self.tracker.get_equity_curve().to_vec();
+        let backtest = self.sim.config();
+        let mut metrics = PerformanceMetrics::from_round_trips(
+            &trades,
+            backtest.initial_capital,
+            Some(backtest.risk_free_rate),
+        );
+        let benchmark_curve = backtest.apply_benchmark(&mut metrics, &equity_curve);
+
+        // 전략 간 상관관계 (슬리브 자산 곡선의 수익률 기준)
+        let correlation = if self.sleeves.len() >= 2 {
+            let histories: HashMap<String, Vec<f64>> = self
+                .sleeves
+                .iter()
+                .map(|s| (s.strategy_id.clone(), s.equity_history.clone()))
+                .collect();
+            let order = self.sleeves.iter().map(|s| s.strategy_id.clone()).collect();
+            calculate_correlation_matrix(&histories, Some(order))
+        } else {
+            None
+        };
+
+        let strategies = self
+            .sleeves
+            .iter()
+            .zip(base_weights)
+            .map(|(sleeve, &initial_weight)| StrategyPerformance {
+                strategy_id: sleeve.strategy_id.clone(),
+                initial_weight,
+                final_weight: sleeve.weight,
+                initial_capital: sleeve.initial_capital,
+                final_equity: sleeve.initial_capital + sleeve.cash_flow,
+                metrics: sleeve.tracker.get_metrics(),
+                equity_curve: sleeve.tracker.get_equity_curve().to_vec(),
+            })
+            .collect();
+
+        PortfolioBacktestReport {
+            config,
+            metrics,
+            trades,
+            equity_curve,
+            strategies,
+            correlation,
+            allocation_history,
+            total_orders: self.sim.total_orders,
+            total_commission: self.sim.total_commission,
+            total_slippage: self.sim.total_slippage,
+            start_time,
+            end_time,
+            data_points,
+            benchmark_curve,
+        }
+    }
+}
+
+/// 슬리브 여유 자본 기준 진입 금액 산정
+///
+/// 슬리브의 여유 자본은 `비중 × 포트폴리오 자산 - 슬리브 보유 포지션 평가액`입니다.
+struct SleeveSizing<'a> {
+    /// 슬리브 상태 (현재 비중)
+    sleeves: &'a [SleeveState],
+}
+
+impl SleeveSizing<'_> {
+    /// 슬리브 여유 자본 (목표 자본 - 보유 포지션 평가액)
+    fn free_capital(&self, sim: &FillSimulator<usize>, sleeve: usize) -> Decimal {
+        let weight = Decimal::from_f64(self.sleeves[sleeve].weight).unwrap_or(Decimal::ZERO);
+        let target = sim.equity() * weight;
+        (target - sim.exposure(&sleeve)).max(Decimal::ZERO)
+    }
+}
+
+impl FillSizing<usize> for SleeveSizing<'_> {
+    fn entry_amount(&self, sim: &FillSimulator<usize>, sleeve: &usize, _ticker: &str) -> Decimal {
+        self.free_capital(sim, *sleeve) * sim.config().max_position_size_pct
+    }
+
+    fn rebalance_cash(&self, sim: &FillSimulator<usize>, sleeve: &usize) -> Decimal {
+        self.free_capital(sim, *sleeve).min(sim.cash)
+    }
+}
+
+/// 같은 `open_time`의 연속된 캔들을 한 시점으로 묶습니다.
+fn group_by_open_time(klines: &[Kline]) -> Vec<&[Kline]> {
+    let mut bars = Vec::new();
+    let mut start = 0;
+    for idx in 1..=klines.len() {
+        if idx == klines.len() || klines[idx].open_time != klines[start].open_time {
+            bars.push(&klines[start..idx]);
+            start = idx;
+        }
+    }
+    bars
+}
+
+/// 배분 방식에 따라 새 비중을 계산합니다.
+///
+/// 추정에 필요한 데이터가 부족하면 현재 비중을 유지하고,
+/// 동적 배분에서 양의 위험 조정 수익률을 낸 전략이 없으면 고정 비중으로 돌아갑니다.
+fn compute_weights(
+    method: AllocationMethod,
+    histories: &[&[f64]],
+    base: &[f64],
+    current: &[f64],
+    min_weight: f64,
+) -> Vec<f64> {
+    let stats: Vec<Option<(f64, f64)>> = histories
+        .iter()
+        .map(|history| return_stats(history))
+        .collect();
+
+    let raw = match method {
+        AllocationMethod::FixedWeight => return base.to_vec(),
+        AllocationMethod::EqualRisk => {
+            let inverse_vols: Option<Vec<f64>> = stats
+                .iter()
+                .map(|s| s.filter(|(_, std)| *std > 0.0).map(|(_, std)| 1.0 / std))
+                .collect();
+            match inverse_vols.and_then(|v| normalize(&v)) {
+                Some(weights) => weights,
+                None => return current.to_vec(),
+            }
+        }
+        AllocationMethod::Dynamic => {
+            let scores: Option<Vec<f64>> = stats
+                .iter()
+                .map(|s| {
+                    s.map(|(mean, std)| {
+                        if std > 0.0 {
+                            (mean / std).max(0.0)
+                        } else {
+                            0.0
+                        }
+                    })
+                })
+                .collect();
+            let Some(scores) = scores else {
+                return current.to_vec();
+            };
+            normalize(&scores).unwrap_or_else(|| base.to_vec())
+        }
+    };
+
+    apply_min_weight(&raw, min_weight)
+}
+
+/// 자산 기록의 수익률 평균과 표준편차. 수익률이 2개 미만이면 None.
+fn return_stats(history: &[f64]) -> Option<(f64, f64)> {
+    let returns: Vec<f64> = history
+        .windows(2)
+        .filter(|w| w[0] > 0.0)
+        .map(|w| w[1] / w[0] - 1.0)
+        .collect();
+    if returns.len() < 2 {
+        return None;
+    }
+
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    Some((mean, variance.sqrt()))
+}
+
+/// 합계가 1이 되도록 정규화합니다. 합계가 0 이하이면 None.
+fn normalize(weights: &[f64]) -> Option<Vec<f64>> {
+    let total: f64 = weights.iter().sum();
+    if !total.is_finite() || total <= 0.0 {
+        return None;
+    }
+    Some(weights.iter().map(|w| w / total).collect())
+}
+
+fn equal_weights(n: usize) -> Vec<f64> {
+    vec![1.0 / n as f64; n]
+}
+
+/// 최소 비중 미만인 전략을 최소 비중으로 고정하고 나머지를 비례 배분합니다.
+fn apply_min_weight(weights: &[f64], min_weight: f64) -> Vec<f64> {
+    if min_weight <= 0.0 {
+        return weights.to_vec();
+    }
+
+    let mut fixed = vec![false; weights.len()];
+    loop {
+        let fixed_count = fixed.iter().filter(|f| **f).count();
+        let free_total = 1.0 - min_weight * fixed_count as f64;
+        let free_sum: f64 = weights
+            .iter()
+            .zip(&fixed)
+            .filter(|(_, f)| !**f)
+            .map(|(w, _)| w)
+            .sum();
+
+        let scaled: Vec<f64> = weights
+            .iter()
+            .zip(&fixed)
+            .map(|(w, f)| {
+                if *f {
+                    min_weight
+                } else if free_sum > 0.0 {
+                    w / free_sum * free_total
+                } else {
+                    free_total / (weights.len() - fixed_count) as f64
+                }
+            })
+            .collect();
+
+        let mut changed = false;
+        for (idx, weight) in scaled.iter().enumerate() {
+            if !fixed[idx] && *weight < min_weight {
+                fixed[idx] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            return scaled;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::engine::test_strategies::{
+        MonthlyTargetStrategy, ScriptedStrategy, SimpleSmaStrategy,
+    };
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+    use trader_core::{Side, Timeframe};
+
+    fn create_klines(ticker: &str, closes: &[Decimal]) -> Vec<Kline> {
+        let base_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, &close)| {
+                let open_time = base_time + Duration::days(i as i64);
+                Kline::new(
+                    ticker.to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    close,
+                    close,
+                    close,
+                    close,
+                    dec!(1000),
+                    open_time + Duration::days(1),
+                )
+            })
+            .collect()
+    }
+
+    fn merge(series: Vec<Vec<Kline>>) -> Vec<Kline> {
+        let mut merged: Vec<Kline> = series.into_iter().flatten().collect();
+        merged.sort_by_key(|k| k.open_time);
+        merged
+    }
+
+    fn frictionless_config() -> BacktestConfig {
+        BacktestConfig::new(dec!(100000))
+            .with_commission_rate(Decimal::ZERO)
+            .with_slippage_rate(Decimal::ZERO)
+            .with_max_position_size_pct(Decimal::ONE)
+    }
+
+    fn entry(strategy_id: &str, ticker: &str) -> Signal {
+        Signal::entry(strategy_id, ticker.to_string(), Side::Buy)
+    }
+
+    #[tokio::test]
+    async fn test_fixed_weights_size_entries_per_sleeve() {
+        let klines = merge(vec![
+            create_klines("AAA", &[dec!(100), dec!(110), dec!(120)]),
+            create_klines("BBB", &[dec!(50), dec!(50), dec!(40)]),
+        ]);
+
+        let mut engine =
+            PortfolioBacktestEngine::new(PortfolioBacktestConfig::new(frictionless_config()))
+                .with_sleeve(
+                    PortfolioSleeve::new(
+                        "growth",
+                        Box::new(ScriptedStrategy::once(entry("growth", "AAA"))),
+                    )
+                    .with_weight(dec!(3))
+                    .with_tickers(["AAA"]),
+                )
+                .with_sleeve(
+                    PortfolioSleeve::new(
+                        "value",
+                        Box::new(ScriptedStrategy::once(entry("value", "BBB"))),
+                    )
+                    .with_weight(dec!(1))
+                    .with_tickers(["BBB"]),
+                );
+
+        let report = engine.run(&klines).await.unwrap();
+
+        let growth = report.strategy("growth").unwrap();
+        let value = report.strategy("value").unwrap();
+        assert!((growth.initial_weight - 0.75).abs() < 1e-9);
+        assert_eq!(growth.initial_capital, dec!(75000));
+        assert_eq!(value.initial_capital, dec!(25000));
+
+        // 75,000 @ 100 → 120, 25,000 @ 50 → 40
+        assert_eq!(growth.final_equity, dec!(90000));
+        assert_eq!(value.final_equity, dec!(20000));
+        assert_eq!(growth.metrics.total_trades, 1);
+        assert_eq!(report.trades.len(), 2);
+        assert!(report
+            .trades
+            .iter()
+            .all(|t| t.strategy_id.as_deref() == Some("growth")
+                || t.strategy_id.as_deref() == Some("value")));
+        assert_eq!(report.metrics.net_profit, dec!(10000));
+    }
+
+    #[tokio::test]
+    async fn test_max_positions_shared_across_strategies() {
+        let klines = merge(vec![
+            create_klines("AAA", &[dec!(100), dec!(100)]),
+            create_klines("BBB", &[dec!(100), dec!(100)]),
+        ]);
+
+        let config = PortfolioBacktestConfig::new(frictionless_config().with_max_positions(1));
+        let mut engine = PortfolioBacktestEngine::new(config)
+            .with_sleeve(
+                PortfolioSleeve::new(
+                    "first",
+                    Box::new(ScriptedStrategy::once(entry("first", "AAA"))),
+                )
+                .with_tickers(["AAA"]),
+            )
+            .with_sleeve(
+                PortfolioSleeve::new(
+                    "second",
+                    Box::new(ScriptedStrategy::once(entry("second", "BBB"))),
+                )
+                .with_tickers(["BBB"]),
+            );
+
+        let report = engine.run(&klines).await.unwrap();
+
+        assert_eq!(report.strategy("first").unwrap().metrics.total_trades, 1);
+        assert_eq!(report.strategy("second").unwrap().metrics.total_trades, 0);
+    }
+
+    #[tokio::test]
+    async fn test_same_ticker_positions_are_kept_per_strategy() {
+        let klines = create_klines("AAA", &[dec!(100), dec!(100), dec!(120)]);
+
+        let mut engine =
+            PortfolioBacktestEngine::new(PortfolioBacktestConfig::new(frictionless_config()))
+                .with_sleeve(PortfolioSleeve::new(
+                    "holder",
+                    Box::new(ScriptedStrategy::once(entry("holder", "AAA"))),
+                ))
+                .with_sleeve(PortfolioSleeve::new(
+                    "trader",
+                    Box::new(ScriptedStrategy::new(vec![
+                        vec![entry("trader", "AAA")],
+                        vec![Signal::exit("trader", "AAA".to_string(), Side::Sell)],
+                    ])),
+                ));
+
+        let report = engine.run(&klines).await.unwrap();
+
+        // trader 청산이 holder 포지션에 영향을 주지 않음
+        let holder = report.strategy("holder").unwrap();
+        let trader = report.strategy("trader").unwrap();
+        assert_eq!(holder.final_equity, dec!(60000));
+        assert_eq!(trader.final_equity, dec!(50000));
+    }
+
+    #[tokio::test]
+    async fn test_correlation_and_equity_curves_per_strategy() {
+        let closes: Vec<Decimal> = (0..40)
+            .map(|i| dec!(100) + Decimal::from((i % 7) * 3) - Decimal::from(i % 3))
+            .collect();
+        let klines = merge(vec![
+            create_klines("AAA", &closes),
+            create_klines("BBB", &closes),
+        ]);
+
+        let config = PortfolioBacktestConfig::new(frictionless_config())
+            .with_allocation(AllocationMethod::EqualRisk)
+            .with_rebalance_bars(5)
+            .with_lookback_bars(10);
+        let mut engine = PortfolioBacktestEngine::new(config)
+            .with_sleeve(
+                PortfolioSleeve::new("sma_fast", Box::new(SimpleSmaStrategy::new(2, 4)))
+                    .with_tickers(["AAA"]),
+            )
+            .with_sleeve(
+                PortfolioSleeve::new("sma_slow", Box::new(SimpleSmaStrategy::new(3, 6)))
+                    .with_tickers(["BBB"]),
+            );
+
+        let report = engine.run(&klines).await.unwrap();
+
+        assert_eq!(report.data_points, 40);
+        assert_eq!(report.strategies.len(), 2);
+        for strategy in &report.strategies {
+            // 초기 포인트 + 시점별 기록 (청산 시 추가 기록 포함)
+            assert!(strategy.equity_curve.len() > report.data_points);
+            assert!(strategy
+                .equity_curve
+                .windows(2)
+                .all(|w| w[0].timestamp <= w[1].timestamp));
+        }
+
+        let correlation = report.correlation.as_ref().unwrap();
+        assert_eq!(correlation.symbols, vec!["sma_fast", "sma_slow"]);
+        assert_eq!(correlation.matrix[0][0], 1.0);
+        assert_eq!(correlation.matrix[0][1], correlation.matrix[1][0]);
+
+        let total: f64 = report.strategies.iter().map(|s| s.final_weight).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(!report.allocation_history.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_schedule_triggers_fire_per_sleeve() {
+        use trader_strategy::ScheduleMarket;
+
+        // 1월 1일(신정) 휴장 → 1월 첫 거래일 2일에 진입, 2월 1일에는 이미 목표 비중
+        let klines = create_klines("BTC/USDT", &[dec!(100); 40]);
+        let holiday = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let calendar = WeekdayCalendar::new().with_holidays(ScheduleMarket::Krx, [holiday]);
+
+        let mut engine =
+            PortfolioBacktestEngine::new(PortfolioBacktestConfig::new(frictionless_config()))
+                .with_calendar(Arc::new(calendar))
+                .with_sleeve(PortfolioSleeve::new(
+                    "monthly",
+                    Box::new(MonthlyTargetStrategy::default()),
+                ));
+
+        let report = engine.run(&klines).await.unwrap();
+
+        // 슬리브 자본 100,000 × 50% / 100 = 500개
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].quantity, dec!(500));
+        assert_eq!(report.trades[0].entry_time, klines[1].close_time);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_sleeves_rejected() {
+        let klines = create_klines("AAA", &[dec!(100), dec!(100)]);
+
+        let mut empty =
+            PortfolioBacktestEngine::new(PortfolioBacktestConfig::new(frictionless_config()));
+        assert!(matches!(
+            empty.run(&klines).await,
+            Err(BacktestError::ConfigError(_))
+        ));
+
+        let mut duplicate =
+            PortfolioBacktestEngine::new(PortfolioBacktestConfig::new(frictionless_config()))
+                .with_sleeve(PortfolioSleeve::new(
+                    "dup",
+                    Box::new(ScriptedStrategy::new(vec![])),
+                ))
+                .with_sleeve(PortfolioSleeve::new(
+                    "dup",
+                    Box::new(ScriptedStrategy::new(vec![])),
+                ));
+        assert!(matches!(
+            duplicate.run(&klines).await,
+            Err(BacktestError::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_equal_risk_favors_low_volatility() {
+        let calm = [100.0, 101.0, 100.5, 101.5, 101.0, 102.0];
+        let wild = [100.0, 110.0, 95.0, 112.0, 90.0, 108.0];
+
+        let weights = compute_weights(
+            AllocationMethod::EqualRisk,
+            &[&calm, &wild],
+            &[0.5, 0.5],
+            &[0.5, 0.5],
+            0.0,
+        );
+
+        assert!(weights[0] > weights[1]);
+        assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        // 데이터 부족 시 현재 비중 유지
+        let kept = compute_weights(
+            AllocationMethod::EqualRisk,
+            &[&calm[..2], &wild[..2]],
+            &[0.5, 0.5],
+            &[0.7, 0.3],
+            0.0,
+        );
+        assert_eq!(kept, vec![0.7, 0.3]);
+    }
+
+    #[test]
+    fn test_dynamic_weights_with_floor() {
+        let rising = [100.0, 101.0, 103.0, 104.0, 106.0];
+        let falling = [100.0, 99.0, 97.0, 96.0, 94.0];
+
+        let weights = compute_weights(
+            AllocationMethod::Dynamic,
+            &[&rising, &falling],
+            &[0.5, 0.5],
+            &[0.5, 0.5],
+            0.2,
+        );
+        assert!((weights[0] - 0.8).abs() < 1e-9);
+        assert!((weights[1] - 0.2).abs() < 1e-9);
+
+        // 양의 성과가 없으면 고정 비중으로 복귀
+        let weights = compute_weights(
+            AllocationMethod::Dynamic,
+            &[&falling, &falling],
+            &[0.6, 0.4],
+            &[0.5, 0.5],
+            0.0,
+        );
+        assert_eq!(weights, vec![0.6, 0.4]);
+    }
+}
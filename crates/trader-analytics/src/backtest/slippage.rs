@@ -12,8 +12,9 @@
 //! # 거래소 중립 설계
 //!
 //! 모든 모델은 거래소에 독립적으로 동작합니다.
-//! Kline 데이터만으로 슬리피지를 계산할 수 있습니다.
+//! Kline 데이터(및 선택적으로 ATR)만으로 슬리피지를 계산할 수 있습니다.
 
+use chrono::{DateTime, Utc};
 use rust_decimal::prelude::*;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
@@ -24,6 +25,7 @@ use trader_core::{Kline, Side};
 ///
 /// 시장 상황에 따라 적절한 슬리피지를 계산합니다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SlippageModel {
     /// 고정 비율 슬리피지.
@@ -51,7 +53,7 @@ pub enum SlippageModel {
     /// 변동성 기반 슬리피지.
     ///
     /// ATR(Average True Range) 또는 캔들 범위를 기반으로 슬리피지를 계산합니다.
-    /// slippage = (high - low) / close * multiplier
+    /// slippage = ATR / close * multiplier (ATR이 없으면 (high - low) / close * multiplier)
     VolatilityBased {
         /// 변동성 승수 (기본: 0.5 = 변동성의 50%)
         #[serde(default = "default_volatility_multiplier")]
@@ -77,6 +79,7 @@ pub enum SlippageModel {
 
 /// 구간별 슬리피지 설정.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
 pub struct SlippageTier {
     /// 금액 임계값 (이 금액 이하에 적용)
     pub threshold: Decimal,
@@ -159,7 +162,21 @@ impl SlippageModel {
         order_value: Decimal,
         kline: Option<&Kline>,
     ) -> SlippageResult {
-        let slippage_rate = self.calculate_rate(price, order_value, kline);
+        self.calculate_execution_price_with_atr(price, side, order_value, kline, None)
+    }
+
+    /// ATR을 함께 사용하여 슬리피지를 계산합니다.
+    ///
+    /// `atr`이 주어지면 VolatilityBased 모델은 단일 캔들 범위 대신 ATR을 사용합니다.
+    pub fn calculate_execution_price_with_atr(
+        &self,
+        price: Decimal,
+        side: Side,
+        order_value: Decimal,
+        kline: Option<&Kline>,
+        atr: Option<Decimal>,
+    ) -> SlippageResult {
+        let slippage_rate = self.calculate_rate_with_atr(price, order_value, kline, atr);
         let slippage_amount = price * slippage_rate;
 
         let execution_price = match side {
@@ -178,9 +195,20 @@ impl SlippageModel {
     /// 슬리피지 비율만 계산.
     pub fn calculate_rate(
         &self,
-        _price: Decimal,
+        price: Decimal,
+        order_value: Decimal,
+        kline: Option<&Kline>,
+    ) -> Decimal {
+        self.calculate_rate_with_atr(price, order_value, kline, None)
+    }
+
+    /// ATR을 함께 사용하여 슬리피지 비율을 계산합니다.
+    pub fn calculate_rate_with_atr(
+        &self,
+        price: Decimal,
         order_value: Decimal,
         kline: Option<&Kline>,
+        atr: Option<Decimal>,
     ) -> Decimal {
         match self {
             SlippageModel::Fixed { rate } => *rate,
@@ -202,16 +230,16 @@ impl SlippageModel {
                 min_rate,
                 max_rate,
             } => {
-                let volatility_rate = kline
-                    .map(|k| {
-                        if k.close > Decimal::ZERO {
-                            (k.high - k.low) / k.close
-                                * Decimal::from_f64(*multiplier).unwrap_or(dec!(0.5))
-                        } else {
-                            *min_rate
-                        }
-                    })
-                    .unwrap_or(*min_rate);
+                let multiplier = Decimal::from_f64(*multiplier).unwrap_or(dec!(0.5));
+
+                // ATR이 있으면 ATR 기준, 없으면 현재 캔들 범위 기준
+                let volatility_rate = match (atr, kline) {
+                    (Some(atr), _) if price > Decimal::ZERO => atr / price * multiplier,
+                    (_, Some(k)) if k.close > Decimal::ZERO => {
+                        (k.high - k.low) / k.close * multiplier
+                    }
+                    _ => *min_rate,
+                };
 
                 // 최소/최대 범위로 클램핑
                 volatility_rate.max(*min_rate).min(*max_rate)
@@ -242,7 +270,8 @@ impl SlippageModel {
 }
 
 /// 슬리피지 계산 결과.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
 pub struct SlippageResult {
     /// 기준 가격
     pub base_price: Decimal,
@@ -254,6 +283,27 @@ pub struct SlippageResult {
     pub slippage_amount: Decimal,
 }
 
+/// 체결별 슬리피지 기록.
+///
+/// 백테스트 리포트에 체결마다 어떤 모델로 얼마의 슬리피지가 적용되었는지 남깁니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+pub struct SlippageRecord {
+    /// 심볼
+    pub symbol: String,
+    /// 체결 방향
+    pub side: Side,
+    /// 체결 수량
+    pub quantity: Decimal,
+    /// 체결 시각
+    pub executed_at: DateTime<Utc>,
+    /// 적용된 모델 이름
+    pub model: String,
+    /// 슬리피지 계산 결과
+    #[serde(flatten)]
+    pub result: SlippageResult,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -299,6 +349,42 @@ mod tests {
         assert_eq!(large, dec!(0.001));
     }
 
+    #[test]
+    fn test_volatility_slippage_uses_atr() {
+        let model = SlippageModel::volatility_based(0.5);
+
+        // ATR 2 / 가격 100 * 0.5 = 1%
+        let rate = model.calculate_rate_with_atr(dec!(100), dec!(10000), None, Some(dec!(2)));
+        assert_eq!(rate, dec!(0.01));
+
+        // 최대 비율로 제한
+        let rate = model.calculate_rate_with_atr(dec!(100), dec!(10000), None, Some(dec!(10)));
+        assert_eq!(rate, dec!(0.01));
+
+        let rate = model.calculate_rate_with_atr(dec!(100), dec!(10000), None, Some(dec!(0.4)));
+        assert_eq!(rate, dec!(0.002));
+    }
+
+    #[test]
+    fn test_linear_slippage_uses_volume() {
+        let model = SlippageModel::linear(dec!(0.0003), dec!(0.1));
+        let kline = Kline::new(
+            "BTC/USDT".to_string(),
+            trader_core::Timeframe::D1,
+            Utc::now(),
+            dec!(100),
+            dec!(101),
+            dec!(99),
+            dec!(100),
+            dec!(1000),
+            Utc::now(),
+        );
+
+        // 거래대금 100,000 중 10,000 주문 → 0.0003 + 0.1 * 0.1 = 0.0103
+        let rate = model.calculate_rate(dec!(100), dec!(10000), Some(&kline));
+        assert_eq!(rate, dec!(0.0103));
+    }
+
     #[test]
     fn test_default_model() {
         let model = SlippageModel::default();
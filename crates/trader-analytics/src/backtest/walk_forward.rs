@@ -0,0 +1,676 @@
+//! 워크포워드(Walk-Forward) 분석 모듈
+//!
+//! 데이터를 In-Sample(IS)/Out-of-Sample(OOS) 구간으로 나누어, 각 IS 구간에서
+//! [`ParameterOptimizer`]로 찾은 최적 파라미터를 바로 다음 OOS 구간에 적용합니다.
+//! OOS 구간 결과는 자본을 이어받으며 하나의 [`BacktestReport`]로 연결되므로
+//! 과최적화(overfitting) 여부를 실제 운용과 가까운 조건에서 판단할 수 있습니다.
+//!
+//! # 구간 분할 방식
+//!
+//! - [`WalkForwardMode::Rolling`]: IS 구간 길이를 고정하고 OOS 길이만큼 앞으로 이동
+//! - [`WalkForwardMode::Anchored`]: IS 구간 시작을 고정하고 끝만 OOS 길이만큼 확장
+//!
+//! ```text
+//! Rolling   [IS1----][OOS1]
+//!                 [IS2----][OOS2]
+//!                       [IS3----][OOS3]
+//!
+//! Anchored  [IS1----][OOS1]
+//!           [IS2----------][OOS2]
+//!           [IS3----------------][OOS3]
+//! ```
+//!
+//! # 워크포워드 효율 (WFE)
+//!
+//! 캔들당 OOS 수익률 ÷ 캔들당 IS 수익률입니다. 구간 길이가 달라도 비교할 수 있도록
+//! 캔들 수로 정규화하며, 1에 가까울수록 IS 성과가 OOS에서도 유지됨을 의미합니다.
+//! IS 수익률이 0 이하이면 계산하지 않습니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::backtest::{
+//!     ParameterOptimizer, ParameterSpace, WalkForwardAnalyzer, WalkForwardConfig,
+//! };
+//!
+//! let space = ParameterSpace::for_strategy("rsi")?.select(&["rsi_period", "oversold"])?;
+//! let optimizer = ParameterOptimizer::new("rsi", BacktestConfig::default(), space);
+//!
+//! let analyzer = WalkForwardAnalyzer::new(optimizer, WalkForwardConfig::new(250, 60).anchored());
+//! let result = analyzer.run(&klines).await?;
+//! println!("{}", result.summary());
+//! ```
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+use tracing::info;
+use trader_core::Kline;
+
+use crate::backtest::engine::{BacktestConfig, BacktestReport};
+use crate::backtest::optimizer::{OptimizationObjective, OptimizerError, ParameterOptimizer};
+use crate::performance::{PerformanceMetrics, PerformanceTracker, RoundTrip};
+
+/// 기본 OOS 워밍업 캔들 수
+pub const DEFAULT_WARMUP_BARS: usize = 50;
+
+/// 워크포워드 분석 오류
+#[derive(Debug, Error)]
+pub enum WalkForwardError {
+    /// 잘못된 구간 설정
+    #[error("잘못된 워크포워드 설정: {0}")]
+    InvalidConfig(String),
+
+    /// 데이터 부족
+    #[error("데이터 부족: 최소 {required}개 캔들 필요 (현재 {available}개)")]
+    InsufficientData { required: usize, available: usize },
+
+    /// IS 구간에서 유효한 파라미터를 찾지 못함
+    #[error("{window}번째 구간 IS 최적화에서 유효한 파라미터를 찾지 못했습니다")]
+    NoValidParameters { window: usize },
+
+    /// 최적화/백테스트 오류
+    #[error(transparent)]
+    Optimizer(#[from] OptimizerError),
+}
+
+/// 워크포워드 결과 타입
+pub type WalkForwardResult<T> = Result<T, WalkForwardError>;
+
+/// IS 구간 분할 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WalkForwardMode {
+    /// 고정 길이 IS 구간을 이동
+    #[default]
+    Rolling,
+    /// IS 시작점을 고정하고 구간을 확장
+    Anchored,
+}
+
+/// 워크포워드 구간 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardConfig {
+    /// IS(최적화) 구간 캔들 수 (Anchored는 첫 구간 길이)
+    pub in_sample_bars: usize,
+    /// OOS(검증) 구간 캔들 수
+    pub out_of_sample_bars: usize,
+    /// 구간 분할 방식
+    #[serde(default)]
+    pub mode: WalkForwardMode,
+    /// OOS 실행 시 앞에 붙이는 워밍업 캔들 수 (지표 준비용, 신호 무시)
+    #[serde(default = "default_warmup_bars")]
+    pub warmup_bars: usize,
+}
+
+fn default_warmup_bars() -> usize {
+    DEFAULT_WARMUP_BARS
+}
+
+/// 분할된 구간 (캔들 인덱스 범위)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalkForwardSplit {
+    /// IS 구간
+    pub in_sample: Range<usize>,
+    /// OOS 구간
+    pub out_of_sample: Range<usize>,
+}
+
+impl WalkForwardConfig {
+    /// Rolling 방식 설정을 생성합니다.
+    pub fn new(in_sample_bars: usize, out_of_sample_bars: usize) -> Self {
+        Self {
+            in_sample_bars,
+            out_of_sample_bars,
+            mode: WalkForwardMode::Rolling,
+            warmup_bars: DEFAULT_WARMUP_BARS,
+        }
+    }
+
+    /// 분할 방식 설정
+    pub fn with_mode(mut self, mode: WalkForwardMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Anchored 방식으로 설정
+    pub fn anchored(self) -> Self {
+        self.with_mode(WalkForwardMode::Anchored)
+    }
+
+    /// OOS 워밍업 캔들 수 설정
+    pub fn with_warmup_bars(mut self, bars: usize) -> Self {
+        self.warmup_bars = bars;
+        self
+    }
+
+    /// 설정 검증
+    pub fn validate(&self) -> WalkForwardResult<()> {
+        if self.in_sample_bars == 0 {
+            return Err(WalkForwardError::InvalidConfig(
+                "IS 구간 캔들 수는 0보다 커야 합니다".to_string(),
+            ));
+        }
+        if self.out_of_sample_bars == 0 {
+            return Err(WalkForwardError::InvalidConfig(
+                "OOS 구간 캔들 수는 0보다 커야 합니다".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 캔들 수에 맞춰 IS/OOS 구간을 분할합니다.
+    ///
+    /// OOS 구간은 겹치지 않고 연속되며, 마지막 OOS 구간은 남은 캔들 수만큼 짧아질 수 있습니다.
+    pub fn split(&self, len: usize) -> WalkForwardResult<Vec<WalkForwardSplit>> {
+        self.validate()?;
+
+        let required = self.in_sample_bars + self.out_of_sample_bars;
+        if len < required {
+            return Err(WalkForwardError::InsufficientData {
+                required,
+                available: len,
+            });
+        }
+
+        let mut splits = Vec::new();
+        let mut is_end = self.in_sample_bars;
+        while is_end < len {
+            let is_start = match self.mode {
+                WalkForwardMode::Rolling => is_end - self.in_sample_bars,
+                WalkForwardMode::Anchored => 0,
+            };
+            let oos_end = (is_end + self.out_of_sample_bars).min(len);
+            splits.push(WalkForwardSplit {
+                in_sample: is_start..is_end,
+                out_of_sample: is_end..oos_end,
+            });
+            is_end = oos_end;
+        }
+        Ok(splits)
+    }
+}
+
+/// 구간별 워크포워드 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardWindow {
+    /// 구간 번호 (1부터)
+    pub index: usize,
+    /// IS 구간 시작
+    pub in_sample_start: DateTime<Utc>,
+    /// IS 구간 종료
+    pub in_sample_end: DateTime<Utc>,
+    /// OOS 구간 시작
+    pub out_of_sample_start: DateTime<Utc>,
+    /// OOS 구간 종료
+    pub out_of_sample_end: DateTime<Utc>,
+    /// IS 캔들 수
+    pub in_sample_bars: usize,
+    /// OOS 캔들 수
+    pub out_of_sample_bars: usize,
+    /// IS에서 선택된 파라미터
+    pub params: Map<String, Value>,
+    /// IS에서 평가한 조합 수
+    pub evaluated: usize,
+    /// IS 목적 함수 점수
+    pub in_sample_score: Decimal,
+    /// IS 성과 지표
+    pub in_sample_metrics: PerformanceMetrics,
+    /// OOS 목적 함수 점수
+    pub out_of_sample_score: Decimal,
+    /// OOS 성과 지표
+    pub out_of_sample_metrics: PerformanceMetrics,
+    /// 구간 워크포워드 효율
+    pub efficiency: Option<Decimal>,
+}
+
+/// 워크포워드 분석 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardReport {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 목적 함수
+    pub objective: OptimizationObjective,
+    /// 구간 설정
+    pub config: WalkForwardConfig,
+    /// 구간별 결과
+    pub windows: Vec<WalkForwardWindow>,
+    /// 전체 워크포워드 효율
+    pub efficiency: Option<Decimal>,
+    /// OOS 구간을 연결한 백테스트 리포트
+    pub report: BacktestReport,
+}
+
+impl WalkForwardReport {
+    /// 연결된 OOS 성과 지표
+    pub fn oos_metrics(&self) -> &PerformanceMetrics {
+        &self.report.metrics
+    }
+
+    /// 구간별 결과를 표 형식 문자열로 반환합니다.
+    pub fn summary(&self) -> String {
+        let mode = match self.config.mode {
+            WalkForwardMode::Rolling => "rolling",
+            WalkForwardMode::Anchored => "anchored",
+        };
+        let mut out = format!(
+            "=== 워크포워드 분석: {} ({}, IS {} / OOS {} 캔들, 목적 함수: {}) ===\n",
+            self.strategy_id,
+            mode,
+            self.config.in_sample_bars,
+            self.config.out_of_sample_bars,
+            self.objective.name()
+        );
+        out.push_str(&format!(
+            "{:>4}  {:<10}  {:<10}  {:>9}  {:>9}  {:>8}  파라미터\n",
+            "구간", "OOS 시작", "OOS 종료", "IS 수익%", "OOS 수익%", "WFE"
+        ));
+        for window in &self.windows {
+            let params = window
+                .params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!(
+                "{:>4}  {:<10}  {:<10}  {:>9.2}  {:>9.2}  {:>8}  {}\n",
+                window.index,
+                window.out_of_sample_start.format("%Y-%m-%d"),
+                window.out_of_sample_end.format("%Y-%m-%d"),
+                window.in_sample_metrics.total_return_pct,
+                window.out_of_sample_metrics.total_return_pct,
+                format_efficiency(window.efficiency),
+                params
+            ));
+        }
+
+        let metrics = self.oos_metrics();
+        out.push_str(&format!(
+            "\n[OOS 합산] 수익률 {:.2}% | MDD {:.2}% | 샤프 {:.2} | 승률 {:.2}% | 거래 {}건 | WFE {}\n",
+            metrics.total_return_pct,
+            metrics.max_drawdown_pct,
+            metrics.sharpe_ratio,
+            metrics.win_rate_pct,
+            metrics.total_trades,
+            format_efficiency(self.efficiency)
+        ));
+        out
+    }
+}
+
+fn format_efficiency(efficiency: Option<Decimal>) -> String {
+    efficiency
+        .map(|e| format!("{:.2}", e))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// 워크포워드 분석기
+///
+/// IS 구간마다 [`ParameterOptimizer::run`]으로 최적 조합을 찾고,
+/// 해당 조합으로 OOS 구간을 [`ParameterOptimizer::backtest`]로 실행합니다.
+/// OOS 구간은 직전 구간의 종료 자본으로 시작하며, 지표 준비를 위해 앞쪽 캔들을
+/// [`BacktestConfig::warmup_bars`]로 붙여 실행합니다.
+#[derive(Debug, Clone)]
+pub struct WalkForwardAnalyzer {
+    optimizer: ParameterOptimizer,
+    config: WalkForwardConfig,
+}
+
+impl WalkForwardAnalyzer {
+    /// 새 분석기를 생성합니다.
+    pub fn new(optimizer: ParameterOptimizer, config: WalkForwardConfig) -> Self {
+        Self { optimizer, config }
+    }
+
+    /// 구간 설정
+    pub fn config(&self) -> &WalkForwardConfig {
+        &self.config
+    }
+
+    /// 워크포워드 분석을 실행합니다.
+    pub async fn run(&self, klines: &[Kline]) -> WalkForwardResult<WalkForwardReport> {
+        self.run_with_progress(klines, |_, _| {}).await
+    }
+
+    /// 워크포워드 분석을 실행하며 구간이 완료될 때마다 `(완료 수, 전체 수)`로 알립니다.
+    pub async fn run_with_progress<F>(
+        &self,
+        klines: &[Kline],
+        mut on_progress: F,
+    ) -> WalkForwardResult<WalkForwardReport>
+    where
+        F: FnMut(usize, usize) + Send,
+    {
+        let splits = self.config.split(klines.len())?;
+        let total = splits.len();
+        let base_config = self.optimizer.backtest_config().clone();
+        let objective = self.optimizer.objective();
+
+        info!(
+            strategy_id = self.optimizer.strategy_id(),
+            windows = total,
+            mode = ?self.config.mode,
+            "워크포워드 분석 시작"
+        );
+
+        let mut capital = base_config.initial_capital;
+        let mut windows = Vec::with_capacity(total);
+        let mut oos_reports = Vec::with_capacity(total);
+
+        for (i, split) in splits.iter().enumerate() {
+            let index = i + 1;
+
+            // 1. IS 구간 최적화
+            let in_sample = &klines[split.in_sample.clone()];
+            let optimization = self.optimizer.run(in_sample).await?;
+            let best = optimization
+                .best()
+                .ok_or(WalkForwardError::NoValidParameters { window: index })?;
+
+            // 2. 선택된 파라미터로 OOS 구간 실행 (직전 캔들로 워밍업)
+            let warmup = self.config.warmup_bars.min(split.out_of_sample.start);
+            let oos_klines = &klines[split.out_of_sample.start - warmup..split.out_of_sample.end];
+            let mut oos_config = base_config.clone().with_warmup_bars(warmup);
+            oos_config.initial_capital = capital;
+
+            let report = self
+                .optimizer
+                .clone()
+                .with_backtest_config(oos_config)
+                .backtest(&best.params, oos_klines)
+                .await?;
+
+            // 다음 구간은 이번 구간의 실현 손익을 반영한 자본으로 시작
+            capital += report.trades.iter().map(|t| t.pnl).sum::<Decimal>();
+
+            let in_sample_bars = split.in_sample.len();
+            let out_of_sample_bars = split.out_of_sample.len();
+            windows.push(WalkForwardWindow {
+                index,
+                in_sample_start: in_sample.first().unwrap().open_time,
+                in_sample_end: in_sample.last().unwrap().close_time,
+                out_of_sample_start: report.start_time,
+                out_of_sample_end: report.end_time,
+                in_sample_bars,
+                out_of_sample_bars,
+                params: best.params.clone(),
+                evaluated: optimization.evaluated(),
+                in_sample_score: best.score,
+                in_sample_metrics: best.metrics.clone(),
+                out_of_sample_score: objective.score(&report.metrics),
+                out_of_sample_metrics: report.metrics.clone(),
+                efficiency: efficiency(
+                    report.metrics.total_return_pct,
+                    out_of_sample_bars,
+                    best.metrics.total_return_pct,
+                    in_sample_bars,
+                ),
+            });
+            oos_reports.push(report);
+            on_progress(index, total);
+        }
+
+        let overall_efficiency = efficiency(
+            windows
+                .iter()
+                .map(|w| w.out_of_sample_metrics.total_return_pct)
+                .sum(),
+            windows.iter().map(|w| w.out_of_sample_bars).sum(),
+            windows
+                .iter()
+                .map(|w| w.in_sample_metrics.total_return_pct)
+                .sum(),
+            windows.iter().map(|w| w.in_sample_bars).sum(),
+        );
+
+        Ok(WalkForwardReport {
+            strategy_id: self.optimizer.strategy_id().to_string(),
+            objective,
+            config: self.config.clone(),
+            windows,
+            efficiency: overall_efficiency,
+            report: stitch_reports(&base_config, &oos_reports),
+        })
+    }
+}
+
+/// 캔들당 수익률 기준 워크포워드 효율을 계산합니다.
+fn efficiency(
+    oos_return_pct: Decimal,
+    oos_bars: usize,
+    is_return_pct: Decimal,
+    is_bars: usize,
+) -> Option<Decimal> {
+    if is_return_pct <= Decimal::ZERO || oos_bars == 0 || is_bars == 0 {
+        return None;
+    }
+    let oos_per_bar = oos_return_pct / Decimal::from(oos_bars);
+    let is_per_bar = is_return_pct / Decimal::from(is_bars);
+    Some((oos_per_bar / is_per_bar).round_dp(4))
+}
+
+/// OOS 구간 리포트를 하나의 리포트로 연결합니다.
+///
+/// 자산 곡선은 [`PerformanceTracker`]로 다시 기록하여 구간을 넘는 낙폭을 반영하고,
+/// 성과 지표는 전체 라운드트립으로 초기 자본 기준 재계산합니다.
+fn stitch_reports(config: &BacktestConfig, reports: &[BacktestReport]) -> BacktestReport {
+    let start_time = reports
+        .first()
+        .map(|r| r.start_time)
+        .unwrap_or_else(Utc::now);
+    let end_time = reports.last().map(|r| r.end_time).unwrap_or(start_time);
+
+    let mut tracker = PerformanceTracker::new(config.initial_capital)
+        .with_risk_free_rate(config.risk_free_rate)
+        .with_start_time(start_time)
+        .without_equity_history_limit();
+    for report in reports {
+        // 각 구간의 첫 지점은 시작 자본이므로 건너뜀
+        for point in report.equity_curve.iter().skip(1) {
+            tracker.update_equity(point.timestamp, point.equity);
+        }
+    }
+
+    let trades: Vec<RoundTrip> = reports.iter().flat_map(|r| r.trades.clone()).collect();
+
+    let mut by_symbol: HashMap<String, Vec<RoundTrip>> = HashMap::new();
+    for rt in &trades {
+        by_symbol
+            .entry(rt.symbol.clone())
+            .or_default()
+            .push(rt.clone());
+    }
+    let performance_by_symbol = by_symbol
+        .into_iter()
+        .map(|(symbol, trades)| {
+            let metrics = PerformanceMetrics::from_round_trips(
+                &trades,
+                config.initial_capital,
+                Some(config.risk_free_rate),
+            );
+            (symbol, metrics)
+        })
+        .collect();
+
+    let equity_curve = tracker.get_equity_curve().to_vec();
+    let mut metrics = PerformanceMetrics::from_round_trips(
+        &trades,
+        config.initial_capital,
+        Some(config.risk_free_rate),
+    );
+    let benchmark_curve = config.apply_benchmark(&mut metrics, &equity_curve);
+
+    BacktestReport {
+        config: config.clone(),
+        metrics,
+        trades,
+        equity_curve,
+        total_orders: reports.iter().map(|r| r.total_orders).sum(),
+        total_commission: reports.iter().map(|r| r.total_commission).sum(),
+        total_slippage: reports.iter().map(|r| r.total_slippage).sum(),
+        start_time,
+        end_time,
+        data_points: reports.iter().map(|r| r.data_points).sum(),
+        performance_by_symbol,
+        signal_markers: reports
+            .iter()
+            .flat_map(|r| r.signal_markers.clone())
+            .collect(),
+        slippage_records: reports
+            .iter()
+            .flat_map(|r| r.slippage_records.clone())
+            .collect(),
+        benchmark_curve,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::optimizer::{ParameterRange, ParameterSpace};
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+    use trader_core::Timeframe;
+
+    fn oscillating_klines(count: usize) -> Vec<Kline> {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..count)
+            .map(|i| {
+                let phase = (i % 40) as i64;
+                let offset = if phase < 20 { -phase } else { phase - 40 };
+                let close = Decimal::from(1000 + offset * 15);
+                Kline::new(
+                    "TEST".to_string(),
+                    Timeframe::D1,
+                    base + Duration::days(i as i64),
+                    close,
+                    close + dec!(5),
+                    close - dec!(5),
+                    close,
+                    dec!(10000),
+                    base + Duration::days(i as i64 + 1),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split_rolling_and_anchored() {
+        let rolling = WalkForwardConfig::new(100, 50).split(260).unwrap();
+        assert_eq!(
+            rolling,
+            vec![
+                WalkForwardSplit {
+                    in_sample: 0..100,
+                    out_of_sample: 100..150
+                },
+                WalkForwardSplit {
+                    in_sample: 50..150,
+                    out_of_sample: 150..200
+                },
+                WalkForwardSplit {
+                    in_sample: 100..200,
+                    out_of_sample: 200..250
+                },
+                WalkForwardSplit {
+                    in_sample: 150..250,
+                    out_of_sample: 250..260
+                },
+            ]
+        );
+
+        let anchored = WalkForwardConfig::new(100, 50)
+            .anchored()
+            .split(200)
+            .unwrap();
+        assert_eq!(anchored.len(), 2);
+        assert_eq!(anchored[1].in_sample, 0..150);
+        assert_eq!(anchored[1].out_of_sample, 150..200);
+    }
+
+    #[test]
+    fn test_split_rejects_invalid_config() {
+        assert!(matches!(
+            WalkForwardConfig::new(100, 50).split(120),
+            Err(WalkForwardError::InsufficientData {
+                required: 150,
+                available: 120
+            })
+        ));
+        assert!(WalkForwardConfig::new(0, 50).split(200).is_err());
+        assert!(WalkForwardConfig::new(100, 0).split(200).is_err());
+    }
+
+    #[test]
+    fn test_efficiency() {
+        // IS 100캔들 10% → 0.1%/캔들, OOS 50캔들 2.5% → 0.05%/캔들
+        assert_eq!(efficiency(dec!(2.5), 50, dec!(10), 100), Some(dec!(0.5)));
+        assert_eq!(efficiency(dec!(2.5), 50, dec!(-1), 100), None);
+    }
+
+    #[tokio::test]
+    async fn test_walk_forward_stitches_oos_windows() {
+        let space = ParameterSpace::for_strategy("grid")
+            .unwrap()
+            .select(&["spacing_pct", "levels"])
+            .unwrap()
+            .with_range(ParameterRange::new("spacing_pct", 1.0, 2.0, 1.0))
+            .with_range(ParameterRange::new("levels", 3.0, 5.0, 2.0).integer());
+        let config = BacktestConfig::new(dec!(10_000_000)).with_commission_rate(Decimal::ZERO);
+        let optimizer = ParameterOptimizer::new("grid", config, space)
+            .with_base_params(serde_json::json!({"amount": "1000000"}))
+            .with_objective(OptimizationObjective::TotalReturn)
+            .with_parallelism(2);
+
+        let klines = oscillating_klines(300);
+        let analyzer = WalkForwardAnalyzer::new(
+            optimizer,
+            WalkForwardConfig::new(120, 60).with_warmup_bars(30),
+        );
+
+        let mut progress = Vec::new();
+        let result = analyzer
+            .run_with_progress(&klines, |done, total| progress.push((done, total)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.windows.len(), 3);
+        assert_eq!(progress.last(), Some(&(3, 3)));
+        assert!(result.windows.iter().all(|w| w.evaluated == 4));
+        assert!(result
+            .windows
+            .iter()
+            .all(|w| w.params.contains_key("levels")));
+
+        // OOS 구간은 IS 바로 다음부터 이어짐
+        assert_eq!(result.windows[0].out_of_sample_start, klines[120].open_time);
+        assert_eq!(result.report.start_time, klines[120].open_time);
+        assert_eq!(result.report.end_time, klines[299].close_time);
+        assert_eq!(result.report.data_points, 180);
+
+        let window_trades: usize = result
+            .windows
+            .iter()
+            .map(|w| w.out_of_sample_metrics.total_trades)
+            .sum();
+        assert_eq!(result.report.trades.len(), window_trades);
+        assert_eq!(result.report.metrics.total_trades, window_trades);
+        assert!(window_trades > 0);
+
+        // 연결된 자산 곡선은 첫 OOS 시작 시점의 초기 자본에서 시작하여 시간순으로 이어짐
+        let first = &result.report.equity_curve[0];
+        assert_eq!(first.timestamp, klines[120].open_time);
+        assert_eq!(first.equity, dec!(10_000_000));
+        assert!(result
+            .report
+            .equity_curve
+            .windows(2)
+            .all(|w| w[0].timestamp <= w[1].timestamp));
+        assert!(result.summary().contains("OOS 합산"));
+    }
+}
@@ -295,7 +295,7 @@ pub fn detect_weekly_ma_cross(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
+    use chrono::{TimeZone, Weekday};
     use rust_decimal_macros::dec;
 
     fn create_test_daily_klines() -> Vec<Kline> {
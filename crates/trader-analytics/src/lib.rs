@@ -38,9 +38,13 @@ pub mod trigger_calculator;
 pub mod volume_profile;
 
 // Performance 모듈 re-exports
+pub use performance::benchmark::{BenchmarkMetrics, BenchmarkSeries};
 pub use performance::metrics::{
     PerformanceMetrics, RollingMetrics, RoundTrip, DEFAULT_RISK_FREE_RATE, TRADING_DAYS_PER_YEAR,
 };
+pub use performance::monte_carlo::{
+    MonteCarloConfig, MonteCarloMethod, MonteCarloReport, MonteCarloSimulator,
+};
 pub use performance::tracker::{PerformanceEvent, PerformanceThresholds, PerformanceTracker};
 
 // Portfolio 모듈 re-exports
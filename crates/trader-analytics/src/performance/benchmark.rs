@@ -0,0 +1,501 @@
+//! 벤치마크 대비 성과 분석
+//!
+//! 전략 자산 곡선을 벤치마크(예: KOSPI 069500, SPY, BTCUSDT) 가격 시계열과
+//! 같은 시점으로 정렬하고, 벤치마크 대비 상대 지표를 계산합니다.
+//!
+//! # 지표
+//!
+//! - **알파 / 베타**: CAPM 기준 초과 수익과 시장 민감도
+//! - **추적 오차 / 정보 비율**: 초과 수익의 변동성과 그 대비 초과 수익
+//! - **상승/하락 포착률**: 벤치마크 상승·하락 구간에서 전략이 따라간 비율
+//! - **상관계수**: 전략과 벤치마크 수익률의 Pearson 상관계수
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::performance::{BenchmarkMetrics, BenchmarkSeries};
+//!
+//! let benchmark = BenchmarkSeries::new("SPY", spy_klines);
+//! let curve = benchmark.aligned_curve(&report.equity_curve, dec!(10_000_000));
+//! let metrics = BenchmarkMetrics::calculate("SPY", &report.equity_curve, &curve, 0.05);
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::Kline;
+
+use super::metrics::TRADING_DAYS_PER_YEAR;
+use super::tracker::EquityPoint;
+use crate::correlation::calculate_correlation;
+
+/// 지표 계산에 필요한 최소 수익률 개수
+const MIN_RETURN_PERIODS: usize = 2;
+
+/// 하루 (초)
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// 벤치마크 가격 시계열
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkSeries {
+    /// 벤치마크 티커 (예: "069500", "SPY", "BTCUSDT")
+    pub ticker: String,
+    /// 벤치마크 캔들 (종료 시각 순)
+    pub klines: Vec<Kline>,
+}
+
+impl BenchmarkSeries {
+    /// 새 벤치마크 시계열을 생성합니다.
+    ///
+    /// 캔들은 종료 시각 기준으로 정렬됩니다.
+    pub fn new(ticker: impl Into<String>, mut klines: Vec<Kline>) -> Self {
+        klines.sort_by_key(|k| k.close_time);
+        Self {
+            ticker: ticker.into(),
+            klines,
+        }
+    }
+
+    /// 주어진 시각에 알려진 벤치마크 가격을 반환합니다.
+    ///
+    /// 해당 시각 이전에 종료된 마지막 캔들의 종가를 사용하고,
+    /// 첫 캔들이 진행 중인 시점이면 시가를 사용합니다.
+    pub fn price_at(&self, timestamp: DateTime<Utc>) -> Option<Decimal> {
+        let idx = self.klines.partition_point(|k| k.close_time <= timestamp);
+        if idx > 0 {
+            return Some(self.klines[idx - 1].close);
+        }
+        self.klines
+            .first()
+            .filter(|k| k.open_time <= timestamp)
+            .map(|k| k.open)
+    }
+
+    /// 전략 자산 곡선과 같은 시점으로 정렬된 벤치마크 자산 곡선을 생성합니다.
+    ///
+    /// 벤치마크를 `initial_capital`만큼 매수 후 보유했을 때의 자산 가치로,
+    /// 차트에서 전략 곡선과 겹쳐 볼 수 있습니다. 벤치마크 데이터가 없는 시점은 제외됩니다.
+    pub fn aligned_curve(
+        &self,
+        equity_curve: &[EquityPoint],
+        initial_capital: Decimal,
+    ) -> Vec<EquityPoint> {
+        let mut curve = Vec::with_capacity(equity_curve.len());
+        let mut base: Option<Decimal> = None;
+        let mut peak = Decimal::ZERO;
+
+        for point in equity_curve {
+            let Some(price) = self.price_at(point.timestamp) else {
+                continue;
+            };
+            let base_price = *base.get_or_insert(price);
+            if base_price.is_zero() {
+                continue;
+            }
+
+            let equity = initial_capital * price / base_price;
+            peak = peak.max(equity);
+            let drawdown_pct = if peak > Decimal::ZERO {
+                (peak - equity) / peak * Decimal::from(100)
+            } else {
+                Decimal::ZERO
+            };
+
+            curve.push(EquityPoint {
+                timestamp: point.timestamp,
+                equity,
+                drawdown_pct,
+            });
+        }
+
+        curve
+    }
+}
+
+/// 벤치마크 대비 성과 지표
+///
+/// 수익률은 두 자산 곡선에 공통으로 존재하는 시점 사이의 기간 수익률로 계산하고,
+/// 데이터 간격으로 추정한 연간 기간 수로 연율화합니다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BenchmarkMetrics {
+    /// 벤치마크 티커
+    pub benchmark: String,
+
+    /// 벤치마크 총 수익률 (%)
+    pub benchmark_return_pct: Decimal,
+
+    /// 초과 수익률 (%) (전략 총 수익률 - 벤치마크 총 수익률)
+    pub excess_return_pct: Decimal,
+
+    /// 젠센 알파 (연율화, %)
+    ///
+    /// 공식: (Rp - Rf) - β × (Rb - Rf)
+    pub alpha_pct: Decimal,
+
+    /// 베타
+    ///
+    /// 공식: Cov(Rp, Rb) / Var(Rb)
+    pub beta: Decimal,
+
+    /// 추적 오차 (연율화, %)
+    ///
+    /// 초과 수익률(Rp - Rb)의 표준편차입니다.
+    pub tracking_error_pct: Decimal,
+
+    /// 정보 비율 (Information Ratio)
+    ///
+    /// 연율화 초과 수익률 / 추적 오차
+    pub information_ratio: Decimal,
+
+    /// 상승 포착률 (%)
+    ///
+    /// 벤치마크 상승 기간의 전략 평균 수익률 / 벤치마크 평균 수익률 × 100
+    pub up_capture_pct: Decimal,
+
+    /// 하락 포착률 (%)
+    ///
+    /// 벤치마크 하락 기간의 전략 평균 수익률 / 벤치마크 평균 수익률 × 100
+    /// (낮을수록 하락장 방어력이 좋음)
+    pub down_capture_pct: Decimal,
+
+    /// 수익률 상관계수 (-1 ~ 1)
+    pub correlation: Decimal,
+
+    /// 계산에 사용된 수익률 개수
+    pub periods: usize,
+}
+
+impl BenchmarkMetrics {
+    /// 전략 자산 곡선과 벤치마크 자산 곡선으로 상대 지표를 계산합니다.
+    ///
+    /// 같은 시점에 여러 값이 기록된 경우 마지막 값을 사용합니다.
+    /// 공통 시점이 부족하면 `None`을 반환합니다.
+    pub fn calculate(
+        benchmark: &str,
+        equity_curve: &[EquityPoint],
+        benchmark_curve: &[EquityPoint],
+        risk_free_rate: f64,
+    ) -> Option<Self> {
+        let strategy = last_by_timestamp(equity_curve);
+        let bench = last_by_timestamp(benchmark_curve);
+
+        let paired: Vec<(DateTime<Utc>, f64, f64)> = strategy
+            .iter()
+            .filter_map(|(ts, s)| bench.get(ts).map(|b| (*ts, *s, *b)))
+            .collect();
+        if paired.len() < MIN_RETURN_PERIODS + 1 {
+            return None;
+        }
+
+        let mut strategy_returns = Vec::with_capacity(paired.len() - 1);
+        let mut benchmark_returns = Vec::with_capacity(paired.len() - 1);
+        for window in paired.windows(2) {
+            let (_, prev_s, prev_b) = window[0];
+            let (_, cur_s, cur_b) = window[1];
+            if prev_s <= 0.0 || prev_b <= 0.0 {
+                continue;
+            }
+            strategy_returns.push(cur_s / prev_s - 1.0);
+            benchmark_returns.push(cur_b / prev_b - 1.0);
+        }
+        if strategy_returns.len() < MIN_RETURN_PERIODS {
+            return None;
+        }
+
+        let timestamps: Vec<DateTime<Utc>> = paired.iter().map(|(ts, _, _)| *ts).collect();
+        let periods_per_year = periods_per_year(&timestamps);
+        let rf = risk_free_rate / periods_per_year;
+
+        let mean_s = mean(&strategy_returns);
+        let mean_b = mean(&benchmark_returns);
+        let var_b = variance(&benchmark_returns, mean_b);
+        let cov = covariance(&strategy_returns, mean_s, &benchmark_returns, mean_b);
+        let beta = if var_b > 0.0 { cov / var_b } else { 0.0 };
+        let alpha = ((mean_s - rf) - beta * (mean_b - rf)) * periods_per_year;
+
+        let active: Vec<f64> = strategy_returns
+            .iter()
+            .zip(&benchmark_returns)
+            .map(|(s, b)| s - b)
+            .collect();
+        let mean_active = mean(&active);
+        let active_std = variance(&active, mean_active).sqrt();
+        let tracking_error = active_std * periods_per_year.sqrt();
+        let information_ratio = if active_std > 0.0 {
+            mean_active / active_std * periods_per_year.sqrt()
+        } else {
+            0.0
+        };
+
+        let up_capture = capture_ratio(&strategy_returns, &benchmark_returns, |b| b > 0.0);
+        let down_capture = capture_ratio(&strategy_returns, &benchmark_returns, |b| b < 0.0);
+        let correlation = calculate_correlation(&strategy_returns, &benchmark_returns);
+
+        let (_, first_s, first_b) = paired[0];
+        let (_, last_s, last_b) = paired[paired.len() - 1];
+        let strategy_return = (last_s / first_s - 1.0) * 100.0;
+        let benchmark_return = (last_b / first_b - 1.0) * 100.0;
+
+        Some(Self {
+            benchmark: benchmark.to_string(),
+            benchmark_return_pct: to_decimal(benchmark_return),
+            excess_return_pct: to_decimal(strategy_return - benchmark_return),
+            alpha_pct: to_decimal(alpha * 100.0),
+            beta: to_decimal(beta),
+            tracking_error_pct: to_decimal(tracking_error * 100.0),
+            information_ratio: to_decimal(information_ratio),
+            up_capture_pct: to_decimal(up_capture * 100.0),
+            down_capture_pct: to_decimal(down_capture * 100.0),
+            correlation: to_decimal(correlation.unwrap_or(0.0)),
+            periods: strategy_returns.len(),
+        })
+    }
+}
+
+/// 같은 시점의 마지막 자산 값만 남깁니다.
+fn last_by_timestamp(curve: &[EquityPoint]) -> BTreeMap<DateTime<Utc>, f64> {
+    curve
+        .iter()
+        .filter_map(|p| p.equity.to_f64().map(|e| (p.timestamp, e)))
+        .collect()
+}
+
+/// 데이터 간격(중앙값)으로 연간 기간 수를 추정합니다.
+///
+/// 하루 이하 간격은 연 252 거래일 기준, 그보다 긴 간격(주봉/월봉)은 달력 기준으로 환산합니다.
+fn periods_per_year(timestamps: &[DateTime<Utc>]) -> f64 {
+    let mut gaps: Vec<i64> = timestamps
+        .windows(2)
+        .map(|w| (w[1] - w[0]).num_seconds())
+        .filter(|&s| s > 0)
+        .collect();
+    if gaps.is_empty() {
+        return TRADING_DAYS_PER_YEAR as f64;
+    }
+    gaps.sort_unstable();
+    let median = gaps[gaps.len() / 2] as f64;
+
+    if median <= SECONDS_PER_DAY {
+        TRADING_DAYS_PER_YEAR as f64 * SECONDS_PER_DAY / median
+    } else {
+        365.25 * SECONDS_PER_DAY / median
+    }
+}
+
+/// 벤치마크 조건을 만족하는 기간의 평균 수익률 비율 (전략 / 벤치마크)
+fn capture_ratio(strategy: &[f64], benchmark: &[f64], pick: impl Fn(f64) -> bool) -> f64 {
+    let (s, b): (Vec<f64>, Vec<f64>) = strategy
+        .iter()
+        .zip(benchmark)
+        .filter(|(_, &b)| pick(b))
+        .map(|(&s, &b)| (s, b))
+        .unzip();
+    let mean_b = mean(&b);
+    if b.is_empty() || mean_b == 0.0 {
+        return 0.0;
+    }
+    mean(&s) / mean_b
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// 표본 분산
+fn variance(values: &[f64], mean: f64) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+}
+
+/// 표본 공분산
+fn covariance(x: &[f64], mean_x: f64, y: &[f64], mean_y: f64) -> f64 {
+    if x.len() < 2 {
+        return 0.0;
+    }
+    x.iter()
+        .zip(y)
+        .map(|(a, b)| (a - mean_x) * (b - mean_y))
+        .sum::<f64>()
+        / (x.len() - 1) as f64
+}
+
+fn to_decimal(value: f64) -> Decimal {
+    if value.is_finite() {
+        Decimal::from_f64(value)
+            .unwrap_or(Decimal::ZERO)
+            .round_dp(6)
+    } else {
+        Decimal::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+    use trader_core::Timeframe;
+
+    fn day(i: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::days(i)
+    }
+
+    fn kline(i: i64, open: Decimal, close: Decimal) -> Kline {
+        Kline::new(
+            "SPY".to_string(),
+            Timeframe::D1,
+            day(i),
+            open,
+            open.max(close),
+            open.min(close),
+            close,
+            dec!(1000),
+            day(i + 1),
+        )
+    }
+
+    fn point(i: i64, equity: Decimal) -> EquityPoint {
+        EquityPoint {
+            timestamp: day(i + 1),
+            equity,
+            drawdown_pct: Decimal::ZERO,
+        }
+    }
+
+    /// 100에서 시작해 번갈아 오르내리는 벤치마크
+    fn zigzag_benchmark(n: i64) -> BenchmarkSeries {
+        let mut price = dec!(100);
+        let klines = (0..n)
+            .map(|i| {
+                let open = price;
+                price = if i % 2 == 0 {
+                    price * dec!(1.02)
+                } else {
+                    price * dec!(0.99)
+                };
+                kline(i, open, price)
+            })
+            .collect();
+        BenchmarkSeries::new("SPY", klines)
+    }
+
+    #[test]
+    fn test_aligned_curve_tracks_buy_and_hold() {
+        let benchmark = zigzag_benchmark(10);
+        // 첫 지점은 첫 캔들 진행 중(시가 기준)
+        let mut equity_curve = vec![EquityPoint {
+            timestamp: day(0),
+            equity: dec!(1000),
+            drawdown_pct: Decimal::ZERO,
+        }];
+        equity_curve.extend((0..10).map(|i| point(i, dec!(1000))));
+
+        let curve = benchmark.aligned_curve(&equity_curve, dec!(1000));
+
+        assert_eq!(curve.len(), equity_curve.len());
+        assert_eq!(curve[0].equity, dec!(1000));
+        assert_eq!(curve[1].equity, dec!(1020));
+        assert_eq!(curve[2].equity, dec!(1009.8));
+        assert!(curve[2].drawdown_pct > Decimal::ZERO);
+        assert!(curve
+            .iter()
+            .zip(&equity_curve)
+            .all(|(b, s)| b.timestamp == s.timestamp));
+    }
+
+    #[test]
+    fn test_aligned_curve_skips_points_before_benchmark_data() {
+        let benchmark = BenchmarkSeries::new(
+            "SPY",
+            (5..10).map(|i| kline(i, dec!(100), dec!(100))).collect(),
+        );
+        let equity_curve: Vec<EquityPoint> = (0..10).map(|i| point(i, dec!(1000))).collect();
+
+        let curve = benchmark.aligned_curve(&equity_curve, dec!(1000));
+
+        // 첫 벤치마크 캔들 시작 시점(시가)부터 정렬
+        assert_eq!(curve.len(), 6);
+        assert_eq!(curve[0].timestamp, day(5));
+    }
+
+    #[test]
+    fn test_leveraged_strategy_has_beta_two() {
+        let benchmark = zigzag_benchmark(60);
+        let initial = dec!(1000);
+        let equity_curve: Vec<EquityPoint> = (0..60).map(|i| point(i, initial)).collect();
+        let bench_curve = benchmark.aligned_curve(&equity_curve, initial);
+
+        // 벤치마크 기간 수익률의 2배를 내는 전략
+        let mut equity = initial;
+        let mut strategy_curve = vec![EquityPoint {
+            timestamp: bench_curve[0].timestamp,
+            equity,
+            drawdown_pct: Decimal::ZERO,
+        }];
+        for w in bench_curve.windows(2) {
+            let ret = w[1].equity / w[0].equity - Decimal::ONE;
+            equity *= Decimal::ONE + ret * dec!(2);
+            strategy_curve.push(EquityPoint {
+                timestamp: w[1].timestamp,
+                equity,
+                drawdown_pct: Decimal::ZERO,
+            });
+        }
+
+        let metrics = BenchmarkMetrics::calculate("SPY", &strategy_curve, &bench_curve, 0.0)
+            .expect("충분한 데이터");
+
+        assert_eq!(metrics.benchmark, "SPY");
+        assert_eq!(metrics.periods, 59);
+        assert!((metrics.beta - dec!(2)).abs() < dec!(0.0001));
+        assert!((metrics.correlation - Decimal::ONE).abs() < dec!(0.0001));
+        assert!((metrics.up_capture_pct - dec!(200)).abs() < dec!(0.01));
+        assert!((metrics.down_capture_pct - dec!(200)).abs() < dec!(0.01));
+        assert!(metrics.tracking_error_pct > Decimal::ZERO);
+        assert!(metrics.excess_return_pct > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_identical_curves_have_no_active_risk() {
+        let benchmark = zigzag_benchmark(30);
+        let equity_curve: Vec<EquityPoint> = (0..30).map(|i| point(i, dec!(1000))).collect();
+        let bench_curve = benchmark.aligned_curve(&equity_curve, dec!(1000));
+
+        let metrics = BenchmarkMetrics::calculate("SPY", &bench_curve, &bench_curve, 0.05).unwrap();
+
+        assert!((metrics.beta - Decimal::ONE).abs() < dec!(0.0001));
+        assert!(metrics.alpha_pct.abs() < dec!(0.0001));
+        assert_eq!(metrics.tracking_error_pct, Decimal::ZERO);
+        assert_eq!(metrics.information_ratio, Decimal::ZERO);
+        assert_eq!(metrics.excess_return_pct, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_insufficient_overlap_returns_none() {
+        let benchmark = zigzag_benchmark(2);
+        let equity_curve: Vec<EquityPoint> = (0..2).map(|i| point(i, dec!(1000))).collect();
+        let bench_curve = benchmark.aligned_curve(&equity_curve, dec!(1000));
+
+        assert!(BenchmarkMetrics::calculate("SPY", &equity_curve, &bench_curve, 0.05).is_none());
+    }
+
+    #[test]
+    fn test_periods_per_year_from_spacing() {
+        let daily: Vec<_> = (0..5).map(day).collect();
+        assert!((periods_per_year(&daily) - 252.0).abs() < 1e-9);
+
+        let hourly: Vec<_> = (0..5).map(|i| day(0) + Duration::hours(i)).collect();
+        assert!((periods_per_year(&hourly) - 252.0 * 24.0).abs() < 1e-9);
+
+        let weekly: Vec<_> = (0..5).map(|i| day(i * 7)).collect();
+        assert!((periods_per_year(&weekly) - 365.25 / 7.0).abs() < 1e-9);
+    }
+}
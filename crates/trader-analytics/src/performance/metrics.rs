@@ -36,6 +36,8 @@ use std::collections::VecDeque;
 use trader_core::{net_pnl, realized_pnl, Side, TradeInfo, TradeStatistics};
 use uuid::Uuid;
 
+use super::benchmark::BenchmarkMetrics;
+
 /// 연간 거래일 수 (연율화 계산에 사용)
 ///
 /// 일반적으로 주식 시장은 연간 약 252일 거래됩니다.
@@ -416,6 +418,12 @@ pub struct PerformanceMetrics {
     /// 양수: 장기적으로 수익
     /// 음수: 장기적으로 손실
     pub expectancy: Decimal,
+
+    /// 벤치마크 대비 지표
+    ///
+    /// 백테스트에 벤치마크가 설정된 경우에만 계산됩니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkMetrics>,
 }
 
 impl PerformanceMetrics {
@@ -559,6 +567,7 @@ impl PerformanceMetrics {
             recovery_factor,
             avg_return_per_trade,
             expectancy: stats.expectancy,
+            benchmark: None,
         }
     }
 
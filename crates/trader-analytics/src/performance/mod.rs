@@ -6,9 +6,18 @@
 //!
 //! - [`metrics`]: 성과 지표 계산 (샤프비율, 최대낙폭, 승률 등)
 //! - [`tracker`]: 실시간 성과 추적 및 이벤트 발생
+//! - [`monte_carlo`]: 거래 시퀀스 몬테카를로 강건성 분석
+//! - [`benchmark`]: 벤치마크 대비 상대 지표 (알파, 베타, 정보 비율 등)
 
+pub mod benchmark;
 pub mod metrics;
+pub mod monte_carlo;
 pub mod tracker;
 
+pub use benchmark::{BenchmarkMetrics, BenchmarkSeries};
 pub use metrics::*;
+pub use monte_carlo::{
+    DistributionSummary, MonteCarloConfig, MonteCarloError, MonteCarloMethod, MonteCarloReport,
+    MonteCarloResult, MonteCarloSimulation, MonteCarloSimulator, PathOutcome,
+};
 pub use tracker::*;
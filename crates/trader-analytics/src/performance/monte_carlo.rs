@@ -0,0 +1,618 @@
+//! 몬테카를로 강건성 분석 모듈
+//!
+//! 백테스트 거래 시퀀스를 무작위로 재구성하여, 거래 순서나 표본에 따른
+//! 최종 자산/최대 낙폭/CAGR의 분포와 파산 위험(Risk of Ruin)을 추정합니다.
+//!
+//! # 시뮬레이션 방식
+//!
+//! - [`MonteCarloMethod::Reshuffle`]: 거래 순서만 무작위로 섞음 (최종 자산 동일, 낙폭 분포 확인)
+//! - [`MonteCarloMethod::Bootstrap`]: 거래를 복원 추출하여 같은 수의 시퀀스 생성
+//! - [`MonteCarloMethod::Skip`]: 각 거래를 일정 확률로 건너뜀 (신호 누락/미체결 가정)
+//!
+//! 각 거래는 원래 시퀀스에서 거래 직전 자산 대비 수익률로 변환한 뒤 복리로 재적용하므로,
+//! 자산 비례로 포지션 크기를 정하는 전략의 특성이 유지됩니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::performance::{MonteCarloConfig, MonteCarloSimulator};
+//!
+//! let result = MonteCarloSimulator::new(MonteCarloConfig::default().with_seed(42))
+//!     .with_period(report.start_time, report.end_time)
+//!     .run(&report.trades, report.config.initial_capital)?;
+//!
+//! for sim in &result.simulations {
+//!     println!("{:?}: MDD p95 = {:.2}%, 파산 위험 = {:.2}%",
+//!         sim.method, sim.max_drawdown_pct.p95, sim.risk_of_ruin_pct);
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use trader_core::TradeInfo;
+
+/// 기본 시뮬레이션 반복 횟수
+pub const DEFAULT_ITERATIONS: usize = 1_000;
+
+/// 기본 거래 누락 확률 (Skip 방식)
+pub const DEFAULT_SKIP_PROBABILITY: f64 = 0.1;
+
+/// 기본 파산 기준 낙폭 (%)
+pub const DEFAULT_RUIN_DRAWDOWN_PCT: f64 = 50.0;
+
+/// 1년 일수 (CAGR 계산용)
+const DAYS_PER_YEAR: f64 = 365.25;
+
+/// 몬테카를로 분석 오류
+#[derive(Debug, Error)]
+pub enum MonteCarloError {
+    /// 청산된 거래 없음
+    #[error("시뮬레이션할 청산 거래가 없습니다")]
+    NoTrades,
+
+    /// 잘못된 설정
+    #[error("잘못된 몬테카를로 설정: {0}")]
+    InvalidConfig(String),
+}
+
+/// 몬테카를로 결과 타입
+pub type MonteCarloResult<T> = Result<T, MonteCarloError>;
+
+/// 시뮬레이션 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa-support", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MonteCarloMethod {
+    /// 거래 순서 재배열
+    Reshuffle,
+    /// 복원 추출 부트스트랩
+    Bootstrap,
+    /// 무작위 거래 누락
+    Skip,
+}
+
+impl MonteCarloMethod {
+    /// 모든 방식
+    pub const ALL: [MonteCarloMethod; 3] = [
+        MonteCarloMethod::Reshuffle,
+        MonteCarloMethod::Bootstrap,
+        MonteCarloMethod::Skip,
+    ];
+}
+
+/// 몬테카를로 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloConfig {
+    /// 방식별 반복 횟수
+    pub iterations: usize,
+    /// 실행할 방식
+    pub methods: Vec<MonteCarloMethod>,
+    /// 거래 누락 확률 (0.0 ~ 1.0, Skip 방식)
+    pub skip_probability: f64,
+    /// 파산으로 간주할 최대 낙폭 (%)
+    pub ruin_drawdown_pct: f64,
+    /// 난수 시드 (재현용)
+    pub seed: Option<u64>,
+}
+
+impl Default for MonteCarloConfig {
+    fn default() -> Self {
+        Self {
+            iterations: DEFAULT_ITERATIONS,
+            methods: MonteCarloMethod::ALL.to_vec(),
+            skip_probability: DEFAULT_SKIP_PROBABILITY,
+            ruin_drawdown_pct: DEFAULT_RUIN_DRAWDOWN_PCT,
+            seed: None,
+        }
+    }
+}
+
+impl MonteCarloConfig {
+    /// 반복 횟수 설정
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// 실행할 방식 설정
+    pub fn with_methods(mut self, methods: Vec<MonteCarloMethod>) -> Self {
+        self.methods = methods;
+        self
+    }
+
+    /// 거래 누락 확률 설정
+    pub fn with_skip_probability(mut self, probability: f64) -> Self {
+        self.skip_probability = probability;
+        self
+    }
+
+    /// 파산 기준 낙폭 설정
+    pub fn with_ruin_drawdown_pct(mut self, pct: f64) -> Self {
+        self.ruin_drawdown_pct = pct;
+        self
+    }
+
+    /// 난수 시드 설정
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// 설정 검증
+    pub fn validate(&self) -> MonteCarloResult<()> {
+        if self.iterations == 0 {
+            return Err(MonteCarloError::InvalidConfig(
+                "반복 횟수는 0보다 커야 합니다".to_string(),
+            ));
+        }
+        if self.methods.is_empty() {
+            return Err(MonteCarloError::InvalidConfig(
+                "시뮬레이션 방식을 하나 이상 지정해야 합니다".to_string(),
+            ));
+        }
+        if !(0.0..1.0).contains(&self.skip_probability) {
+            return Err(MonteCarloError::InvalidConfig(
+                "거래 누락 확률은 0 이상 1 미만이어야 합니다".to_string(),
+            ));
+        }
+        if !(self.ruin_drawdown_pct > 0.0 && self.ruin_drawdown_pct <= 100.0) {
+            return Err(MonteCarloError::InvalidConfig(
+                "파산 기준 낙폭은 0 초과 100 이하이어야 합니다".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 분포 요약 통계
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DistributionSummary {
+    /// 평균
+    pub mean: f64,
+    /// 표준편차
+    pub std_dev: f64,
+    /// 최솟값
+    pub min: f64,
+    /// 5 백분위
+    pub p5: f64,
+    /// 25 백분위
+    pub p25: f64,
+    /// 중앙값
+    pub p50: f64,
+    /// 75 백분위
+    pub p75: f64,
+    /// 95 백분위
+    pub p95: f64,
+    /// 최댓값
+    pub max: f64,
+}
+
+impl DistributionSummary {
+    /// 표본으로부터 요약 통계를 계산합니다.
+    pub fn from_samples(samples: &[f64]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+            min: sorted[0],
+            p5: percentile(&sorted, 5.0),
+            p25: percentile(&sorted, 25.0),
+            p50: percentile(&sorted, 50.0),
+            p75: percentile(&sorted, 75.0),
+            p95: percentile(&sorted, 95.0),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// 정렬된 표본의 백분위수 (선형 보간)
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    let rank = pct / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let weight = rank - lower as f64;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+}
+
+/// 단일 자산 경로의 결과
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PathOutcome {
+    /// 최종 자산
+    pub final_equity: f64,
+    /// 최대 낙폭 (%)
+    pub max_drawdown_pct: f64,
+    /// 연평균 복리 수익률 (%)
+    pub cagr_pct: f64,
+}
+
+/// 방식별 시뮬레이션 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloSimulation {
+    /// 시뮬레이션 방식
+    pub method: MonteCarloMethod,
+    /// 반복 횟수
+    pub iterations: usize,
+    /// 최종 자산 분포
+    pub final_equity: DistributionSummary,
+    /// 최대 낙폭 분포 (%)
+    pub max_drawdown_pct: DistributionSummary,
+    /// CAGR 분포 (%)
+    pub cagr_pct: DistributionSummary,
+    /// 파산 위험 (%): 최대 낙폭이 기준 이상인 경로 비율
+    pub risk_of_ruin_pct: f64,
+}
+
+/// 몬테카를로 분석 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloReport {
+    /// 사용한 설정
+    pub config: MonteCarloConfig,
+    /// 청산 거래 수
+    pub trade_count: usize,
+    /// 초기 자본
+    pub initial_capital: Decimal,
+    /// CAGR 계산 기간 (일)
+    pub period_days: f64,
+    /// 원래 거래 순서의 결과
+    pub original: PathOutcome,
+    /// 방식별 결과
+    pub simulations: Vec<MonteCarloSimulation>,
+}
+
+impl MonteCarloReport {
+    /// 특정 방식의 결과
+    pub fn simulation(&self, method: MonteCarloMethod) -> Option<&MonteCarloSimulation> {
+        self.simulations.iter().find(|s| s.method == method)
+    }
+}
+
+/// 몬테카를로 시뮬레이터
+#[derive(Debug, Clone, Default)]
+pub struct MonteCarloSimulator {
+    config: MonteCarloConfig,
+    period: Option<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+impl MonteCarloSimulator {
+    /// 새 시뮬레이터를 생성합니다.
+    pub fn new(config: MonteCarloConfig) -> Self {
+        Self {
+            config,
+            period: None,
+        }
+    }
+
+    /// CAGR 계산 기간을 지정합니다.
+    ///
+    /// 지정하지 않으면 첫 진입부터 마지막 청산까지를 기간으로 사용합니다.
+    pub fn with_period(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.period = Some((start, end));
+        self
+    }
+
+    /// 거래 목록으로 시뮬레이션을 실행합니다.
+    ///
+    /// 미청산 거래는 제외하며, 거래는 청산 시각 순으로 정렬하여 원래 시퀀스로 사용합니다.
+    /// 손익은 [`TradeInfo::pnl`]의 순손익(수수료 차감 후)을 그대로 사용합니다.
+    pub fn run<T: TradeInfo>(
+        &self,
+        trades: &[T],
+        initial_capital: Decimal,
+    ) -> MonteCarloResult<MonteCarloReport> {
+        self.config.validate()?;
+
+        let capital = initial_capital.to_f64().unwrap_or(0.0);
+        if capital <= 0.0 {
+            return Err(MonteCarloError::InvalidConfig(
+                "초기 자본은 0보다 커야 합니다".to_string(),
+            ));
+        }
+
+        let mut closed: Vec<(&T, DateTime<Utc>, Decimal)> = trades
+            .iter()
+            .filter_map(|t| {
+                let exit = t.exit_time()?;
+                Some((t, exit, t.pnl()?))
+            })
+            .collect();
+        if closed.is_empty() {
+            return Err(MonteCarloError::NoTrades);
+        }
+        closed.sort_by_key(|(_, exit, _)| *exit);
+
+        let period_days = match self.period {
+            Some((start, end)) => duration_days(start, end),
+            None => {
+                let start = closed.iter().map(|(t, _, _)| t.entry_time()).min().unwrap();
+                let end = closed.last().unwrap().1;
+                duration_days(start, end)
+            }
+        };
+
+        // 거래 직전 자산 대비 수익률로 변환
+        let mut equity = capital;
+        let returns: Vec<f64> = closed
+            .iter()
+            .map(|(_, _, pnl)| {
+                let pnl = pnl.to_f64().unwrap_or(0.0);
+                let r = if equity > 0.0 { pnl / equity } else { 0.0 };
+                equity += pnl;
+                r.max(-1.0)
+            })
+            .collect();
+
+        let original = simulate_path(returns.iter().copied(), capital, period_days);
+
+        let simulations = self
+            .config
+            .methods
+            .iter()
+            .enumerate()
+            .map(|(i, &method)| {
+                // 방식마다 다른 시드를 사용하되 같은 설정이면 재현 가능
+                let mut rng = match self.config.seed {
+                    Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(i as u64)),
+                    None => StdRng::from_entropy(),
+                };
+                self.simulate_method(method, &returns, capital, period_days, &mut rng)
+            })
+            .collect();
+
+        Ok(MonteCarloReport {
+            config: self.config.clone(),
+            trade_count: returns.len(),
+            initial_capital,
+            period_days,
+            original,
+            simulations,
+        })
+    }
+
+    fn simulate_method(
+        &self,
+        method: MonteCarloMethod,
+        returns: &[f64],
+        capital: f64,
+        period_days: f64,
+        rng: &mut StdRng,
+    ) -> MonteCarloSimulation {
+        let iterations = self.config.iterations;
+        let mut outcomes = Vec::with_capacity(iterations);
+        let mut sequence = returns.to_vec();
+
+        for _ in 0..iterations {
+            let outcome = match method {
+                MonteCarloMethod::Reshuffle => {
+                    sequence.shuffle(rng);
+                    simulate_path(sequence.iter().copied(), capital, period_days)
+                }
+                MonteCarloMethod::Bootstrap => {
+                    let sampled =
+                        (0..returns.len()).map(|_| returns[rng.gen_range(0..returns.len())]);
+                    simulate_path(
+                        sampled.collect::<Vec<_>>().into_iter(),
+                        capital,
+                        period_days,
+                    )
+                }
+                MonteCarloMethod::Skip => {
+                    let kept = returns
+                        .iter()
+                        .copied()
+                        .filter(|_| !rng.gen_bool(self.config.skip_probability));
+                    simulate_path(kept.collect::<Vec<_>>().into_iter(), capital, period_days)
+                }
+            };
+            outcomes.push(outcome);
+        }
+
+        let ruined = outcomes
+            .iter()
+            .filter(|o| o.max_drawdown_pct >= self.config.ruin_drawdown_pct)
+            .count();
+
+        let collect = |f: fn(&PathOutcome) -> f64| outcomes.iter().map(f).collect::<Vec<_>>();
+        MonteCarloSimulation {
+            method,
+            iterations,
+            final_equity: DistributionSummary::from_samples(&collect(|o| o.final_equity)),
+            max_drawdown_pct: DistributionSummary::from_samples(&collect(|o| o.max_drawdown_pct)),
+            cagr_pct: DistributionSummary::from_samples(&collect(|o| o.cagr_pct)),
+            risk_of_ruin_pct: ruined as f64 / iterations as f64 * 100.0,
+        }
+    }
+}
+
+/// 두 시각 사이의 일수 (최소 1일)
+fn duration_days(start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+    ((end - start).num_seconds() as f64 / 86_400.0).max(1.0)
+}
+
+/// 수익률 시퀀스를 복리로 적용하여 경로 결과를 계산합니다.
+fn simulate_path(
+    returns: impl Iterator<Item = f64>,
+    capital: f64,
+    period_days: f64,
+) -> PathOutcome {
+    let mut equity = capital;
+    let mut peak = capital;
+    let mut max_drawdown_pct: f64 = 0.0;
+
+    for r in returns {
+        equity = (equity * (1.0 + r)).max(0.0);
+        peak = peak.max(equity);
+        if peak > 0.0 {
+            max_drawdown_pct = max_drawdown_pct.max((peak - equity) / peak * 100.0);
+        }
+    }
+
+    let cagr_pct = if equity <= 0.0 {
+        -100.0
+    } else {
+        ((equity / capital).powf(DAYS_PER_YEAR / period_days) - 1.0) * 100.0
+    };
+
+    PathOutcome {
+        final_equity: equity,
+        max_drawdown_pct,
+        cagr_pct,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::performance::RoundTrip;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    /// 손익이 번갈아 발생하는 거래 (수수료 없음)
+    fn sample_trades() -> Vec<RoundTrip> {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let exits = [
+            dec!(110),
+            dec!(95),
+            dec!(108),
+            dec!(90),
+            dec!(112),
+            dec!(97),
+            dec!(105),
+            dec!(93),
+        ];
+        exits
+            .iter()
+            .enumerate()
+            .map(|(i, &exit)| {
+                let entry_time = base + Duration::days(i as i64 * 45);
+                RoundTrip::new(
+                    "TEST",
+                    Side::Buy,
+                    dec!(100),
+                    exit,
+                    dec!(100),
+                    Decimal::ZERO,
+                    entry_time,
+                    entry_time + Duration::days(30),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_distribution_summary() {
+        let summary = DistributionSummary::from_samples(&[5.0, 1.0, 3.0, 2.0, 4.0]);
+        assert_eq!(summary.min, 1.0);
+        assert_eq!(summary.max, 5.0);
+        assert_eq!(summary.p50, 3.0);
+        assert_eq!(summary.p25, 2.0);
+        assert!((summary.p95 - 4.8).abs() < 1e-9);
+        assert!((summary.mean - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_simulate_path_drawdown() {
+        // 100 → 120 → 90 → 99: 고점 120 대비 25% 낙폭
+        let outcome = simulate_path([0.2, -0.25, 0.1].into_iter(), 100.0, DAYS_PER_YEAR);
+        assert!((outcome.final_equity - 99.0).abs() < 1e-9);
+        assert!((outcome.max_drawdown_pct - 25.0).abs() < 1e-9);
+        assert!((outcome.cagr_pct + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reshuffle_preserves_final_equity() {
+        let trades = sample_trades();
+        let config = MonteCarloConfig::default()
+            .with_iterations(200)
+            .with_seed(7);
+        let result = MonteCarloSimulator::new(config)
+            .run(&trades, dec!(100_000))
+            .unwrap();
+
+        assert_eq!(result.trade_count, 8);
+        assert_eq!(result.simulations.len(), 3);
+
+        // 순서만 바뀌므로 최종 자산은 원래 결과와 동일
+        let reshuffle = result.simulation(MonteCarloMethod::Reshuffle).unwrap();
+        assert!((reshuffle.final_equity.min - result.original.final_equity).abs() < 1e-6);
+        assert!((reshuffle.final_equity.max - result.original.final_equity).abs() < 1e-6);
+        assert!(reshuffle.max_drawdown_pct.max >= reshuffle.max_drawdown_pct.min);
+
+        // 원래 최종 자산 = 초기 자본 + 순손익 합계
+        let net: f64 = trades.iter().map(|t| t.pnl.to_f64().unwrap()).sum();
+        assert!((result.original.final_equity - (100_000.0 + net)).abs() < 1e-6);
+
+        let bootstrap = result.simulation(MonteCarloMethod::Bootstrap).unwrap();
+        assert!(bootstrap.final_equity.max > bootstrap.final_equity.min);
+    }
+
+    #[test]
+    fn test_seed_is_reproducible() {
+        let trades = sample_trades();
+        let run = || {
+            MonteCarloSimulator::new(
+                MonteCarloConfig::default()
+                    .with_iterations(100)
+                    .with_seed(1),
+            )
+            .run(&trades, dec!(100_000))
+            .unwrap()
+        };
+        let (a, b) = (run(), run());
+        for (x, y) in a.simulations.iter().zip(&b.simulations) {
+            assert_eq!(x.final_equity, y.final_equity);
+            assert_eq!(x.max_drawdown_pct, y.max_drawdown_pct);
+        }
+    }
+
+    #[test]
+    fn test_risk_of_ruin_threshold() {
+        let trades = sample_trades();
+        let base = MonteCarloConfig::default()
+            .with_iterations(100)
+            .with_methods(vec![MonteCarloMethod::Bootstrap])
+            .with_seed(3);
+
+        // 원래 거래 기준 손실 구간이 있으므로 아주 낮은 기준이면 대부분 파산
+        let strict = MonteCarloSimulator::new(base.clone().with_ruin_drawdown_pct(0.01))
+            .run(&trades, dec!(100_000))
+            .unwrap();
+        assert!(strict.simulations[0].risk_of_ruin_pct > 90.0);
+
+        let lenient = MonteCarloSimulator::new(base.with_ruin_drawdown_pct(100.0))
+            .run(&trades, dec!(100_000))
+            .unwrap();
+        assert_eq!(lenient.simulations[0].risk_of_ruin_pct, 0.0);
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        let empty: Vec<RoundTrip> = Vec::new();
+        assert!(matches!(
+            MonteCarloSimulator::default().run(&empty, dec!(100_000)),
+            Err(MonteCarloError::NoTrades)
+        ));
+
+        let trades = sample_trades();
+        let config = MonteCarloConfig::default().with_skip_probability(1.0);
+        assert!(MonteCarloSimulator::new(config)
+            .run(&trades, dec!(100_000))
+            .is_err());
+    }
+}
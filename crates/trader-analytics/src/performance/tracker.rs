@@ -646,7 +646,7 @@ impl PerformanceTracker {
 
         let key = Self::position_key(&exit_trade.ticker.clone(), entry_side);
 
-        let open_position = self
+        let mut open_position = self
             .open_positions
             .get_mut(&key)
             .and_then(|positions| positions.pop_front())
@@ -655,6 +655,22 @@ impl PerformanceTracker {
                 side: entry_side,
             })?;
 
+        // 부분 청산: 남은 수량은 수수료를 비례 배분하여 대기열 맨 앞에 유지
+        if exit_trade.quantity > Decimal::ZERO && exit_trade.quantity < open_position.quantity {
+            let closed_fee = open_position.fee * exit_trade.quantity / open_position.quantity;
+
+            let mut remainder = open_position.clone();
+            remainder.quantity -= exit_trade.quantity;
+            remainder.fee -= closed_fee;
+            self.open_positions
+                .entry(key.clone())
+                .or_default()
+                .push_front(remainder);
+
+            open_position.quantity = exit_trade.quantity;
+            open_position.fee = closed_fee;
+        }
+
         // RoundTrip 생성
         let total_fees = open_position.fee + exit_trade.fee;
         let round_trip = RoundTrip::new(
@@ -891,6 +907,32 @@ mod tests {
         assert_eq!(tracker.current_equity(), dec!(10190));
     }
 
+    #[test]
+    fn test_partial_exit_keeps_remainder() {
+        let mut tracker = PerformanceTracker::new(dec!(10000));
+
+        // 진입 0.2 (수수료 10)
+        let entry = create_test_trade(Side::Buy, dec!(50000), dec!(0.2), dec!(10));
+        tracker.record_trade(&entry, true, None).unwrap();
+
+        // 0.1 부분 청산 → 진입 수수료 절반 배분
+        let exit = create_test_trade(Side::Sell, dec!(52000), dec!(0.1), dec!(5));
+        let round_trip = tracker.record_trade(&exit, false, None).unwrap().unwrap();
+
+        // PnL = (52000 - 50000) * 0.1 - (5 + 5) = 190
+        assert_eq!(round_trip.quantity, dec!(0.1));
+        assert_eq!(round_trip.pnl, dec!(190));
+        assert_eq!(tracker.open_positions_count(), 1);
+
+        // 나머지 0.1 청산
+        let exit = create_test_trade(Side::Sell, dec!(49000), dec!(0.1), dec!(5));
+        let round_trip = tracker.record_trade(&exit, false, None).unwrap().unwrap();
+
+        // PnL = (49000 - 50000) * 0.1 - (5 + 5) = -110
+        assert_eq!(round_trip.pnl, dec!(-110));
+        assert_eq!(tracker.open_positions_count(), 0);
+    }
+
     #[test]
     fn test_short_position() {
         let mut tracker = PerformanceTracker::new(dec!(10000));
@@ -23,7 +23,7 @@ use trader_api::middleware::{
 use trader_api::openapi::swagger_ui_router;
 use trader_api::repository::StrategyRepository;
 use trader_api::routes::create_api_router;
-use trader_api::services::ApiBotHandler;
+use trader_api::services::{ApiBotHandler, KisTradingCalendar};
 use trader_notification::{NotificationManager, TelegramConfig, TelegramSender};
 use trader_api::state::AppState;
 use trader_api::websocket::{
@@ -33,14 +33,29 @@ use trader_api::websocket::{
 use trader_core::crypto::CredentialEncryptor;
 use trader_data::cache::CachedHistoricalDataProvider;
 use trader_exchange::connector::kis::{
-    KisConfig, KisKrClient, KisOAuth, KisUsClient,
+    HolidayChecker, KisConfig, KisKrClient, KisOAuth, KisUsClient,
 };
+use trader_exchange::connector::BinanceClient;
 use trader_exchange::stream::UnifiedMarketStream;
-use trader_exchange::traits::MarketStream;
+use trader_exchange::traits::{Exchange, MarketStream};
 use trader_exchange::KisKrProvider;
 use trader_execution::{ConversionConfig, OrderExecutor};
-use trader_risk::{RiskConfig, RiskManager};
-use trader_strategy::{EngineConfig, StrategyEngine};
+use trader_risk::{
+    BreakerAction, ComplianceConfig, ConcentrationConfig, DrawdownConfig, PortfolioVarConfig,
+    RiskConfig, RiskManager, SymbolFilter, VarBreachAction, VarMethod,
+};
+use trader_strategy::strategies::common::{PositionSizingConfig, SizingMode, VolatilityEstimator};
+use trader_strategy::{EngineConfig, StrategyEngine, TradingCalendar};
+
+/// 실수 환경 변수 로드 (없거나 파싱 실패 시 None).
+fn env_f64(key: &str) -> Option<f64> {
+    std::env::var(key).ok().and_then(|v| v.parse().ok())
+}
+
+/// 정수 환경 변수 로드 (없거나 파싱 실패 시 None).
+fn env_u32(key: &str) -> Option<u32> {
+    std::env::var(key).ok().and_then(|v| v.parse().ok())
+}
 
 /// 서버 설정 구조체.
 struct ServerConfig {
@@ -50,6 +65,16 @@ struct ServerConfig {
     port: u16,
     /// 초기 잔고 (리스크 매니저용)
     initial_balance: rust_decimal::Decimal,
+    /// 포트폴리오 VaR/ES 한도 (리스크 매니저용)
+    portfolio_var: PortfolioVarConfig,
+    /// 섹터/국가/통화/상관 클러스터 집중도 한도 (리스크 매니저용)
+    concentration: ConcentrationConfig,
+    /// 드로다운/연속 손실 서킷 브레이커 (리스크 매니저용)
+    drawdown: DrawdownConfig,
+    /// 주문 전 컴플라이언스 규칙 (리스크 매니저용)
+    compliance: ComplianceConfig,
+    /// 진입 포지션 사이징 방식 (리스크 매니저용)
+    position_sizing: PositionSizingConfig,
 }
 
 impl Default for ServerConfig {
@@ -58,6 +83,11 @@ impl Default for ServerConfig {
             host: "127.0.0.1".to_string(),
             port: 3000,
             initial_balance: rust_decimal_macros::dec!(10000),
+            portfolio_var: PortfolioVarConfig::default(),
+            concentration: ConcentrationConfig::default(),
+            drawdown: DrawdownConfig::default(),
+            compliance: ComplianceConfig::default(),
+            position_sizing: PositionSizingConfig::default(),
         }
     }
 }
@@ -79,7 +109,175 @@ impl ServerConfig {
             host,
             port,
             initial_balance,
+            portfolio_var: Self::portfolio_var_from_env(),
+            concentration: Self::concentration_from_env(),
+            drawdown: Self::drawdown_from_env(),
+            compliance: Self::compliance_from_env(),
+            position_sizing: Self::position_sizing_from_env(),
+        }
+    }
+
+    /// 포트폴리오 VaR/ES 한도 환경 변수 로드.
+    ///
+    /// `PORTFOLIO_MAX_VAR_PCT`, `PORTFOLIO_MAX_ES_PCT`가 없으면 한도 검사를 하지 않습니다.
+    fn portfolio_var_from_env() -> PortfolioVarConfig {
+        let mut var_config = PortfolioVarConfig::default();
+
+        if let Ok(method) = std::env::var("PORTFOLIO_VAR_METHOD") {
+            match method.to_lowercase().as_str() {
+                "historical" => var_config.method = VarMethod::Historical,
+                "parametric" => var_config.method = VarMethod::Parametric,
+                other => {
+                    warn!(
+                        method = other,
+                        "알 수 없는 PORTFOLIO_VAR_METHOD, historical 사용"
+                    )
+                }
+            }
+        }
+        if let Some(confidence) = env_f64("PORTFOLIO_VAR_CONFIDENCE") {
+            var_config.confidence = confidence;
+        }
+        var_config.max_var_pct = env_f64("PORTFOLIO_MAX_VAR_PCT");
+        var_config.max_es_pct = env_f64("PORTFOLIO_MAX_ES_PCT");
+        if let Ok(action) = std::env::var("PORTFOLIO_VAR_BREACH_ACTION") {
+            var_config.breach_action = match action.to_lowercase().as_str() {
+                "reject" => VarBreachAction::Reject,
+                _ => VarBreachAction::Downsize,
+            };
+        }
+
+        var_config
+    }
+
+    /// 집중도 한도 환경 변수 로드.
+    ///
+    /// `PORTFOLIO_MAX_SECTOR_PCT` 등 설정되지 않은 한도는 검사하지 않습니다.
+    fn concentration_from_env() -> ConcentrationConfig {
+        let mut concentration = ConcentrationConfig {
+            max_sector_pct: env_f64("PORTFOLIO_MAX_SECTOR_PCT"),
+            max_country_pct: env_f64("PORTFOLIO_MAX_COUNTRY_PCT"),
+            max_currency_pct: env_f64("PORTFOLIO_MAX_CURRENCY_PCT"),
+            max_cluster_pct: env_f64("PORTFOLIO_MAX_CLUSTER_PCT"),
+            ..ConcentrationConfig::default()
+        };
+        if let Some(correlation) = env_f64("PORTFOLIO_CLUSTER_MIN_CORRELATION") {
+            concentration.cluster_min_correlation = correlation;
+        }
+
+        concentration
+    }
+
+    /// 서킷 브레이커 환경 변수 로드.
+    ///
+    /// `PORTFOLIO_MAX_DRAWDOWN_PCT`, `MAX_CONSECUTIVE_LOSSES` 등 설정되지 않은 한도는
+    /// 검사하지 않습니다.
+    fn drawdown_from_env() -> DrawdownConfig {
+        let mut drawdown = DrawdownConfig {
+            max_drawdown_pct: env_f64("PORTFOLIO_MAX_DRAWDOWN_PCT"),
+            max_window_drawdown_pct: env_f64("PORTFOLIO_MAX_WINDOW_DRAWDOWN_PCT"),
+            max_consecutive_losses: env_u32("MAX_CONSECUTIVE_LOSSES"),
+            max_strategy_consecutive_losses: env_u32("MAX_STRATEGY_CONSECUTIVE_LOSSES"),
+            recovery_drawdown_pct: env_f64("PORTFOLIO_DRAWDOWN_RECOVERY_PCT"),
+            ..DrawdownConfig::default()
+        };
+        if let Some(days) = env_u32("PORTFOLIO_DRAWDOWN_WINDOW_DAYS") {
+            drawdown.window_days = days;
+        }
+        if let Some(multiplier) = env_f64("CIRCUIT_BREAKER_SIZE_MULTIPLIER") {
+            drawdown.size_multiplier = multiplier;
+        }
+        if let Ok(action) = std::env::var("CIRCUIT_BREAKER_ACTION") {
+            drawdown.action = match action.to_lowercase().as_str() {
+                "halt" => BreakerAction::Halt,
+                _ => BreakerAction::ReduceSize,
+            };
+        }
+
+        drawdown
+    }
+
+    /// 주문 전 컴플라이언스 환경 변수 로드.
+    ///
+    /// `PRE_TRADE_COMPLIANCE_ENABLED`가 true일 때만 검사합니다.
+    /// 심볼 필터(`COMPLIANCE_SYMBOL_FILTERS`)는 종목별 JSON 객체이며,
+    /// 시작 시 Binance exchangeInfo에서 로드한 필터보다 우선합니다.
+    fn compliance_from_env() -> ComplianceConfig {
+        let mut compliance = ComplianceConfig {
+            enabled: std::env::var("PRE_TRADE_COMPLIANCE_ENABLED")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            allow_short_selling: std::env::var("ALLOW_SHORT_SELLING")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            ..ComplianceConfig::default()
+        };
+        if let Ok(tickers) = std::env::var("RESTRICTED_TICKERS") {
+            compliance.restricted_tickers = tickers
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+        }
+        if let Some(pct) = env_f64("KRX_PRICE_LIMIT_PCT") {
+            compliance.krx_price_limit_pct = pct;
+        }
+        if let Some(equity) = std::env::var("PDT_MIN_EQUITY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            compliance.pdt_min_equity = equity;
+        }
+        if let Some(day_trades) = env_u32("PDT_MAX_DAY_TRADES") {
+            compliance.pdt_max_day_trades = day_trades;
+        }
+        if let Ok(filters) = std::env::var("COMPLIANCE_SYMBOL_FILTERS") {
+            match serde_json::from_str(&filters) {
+                Ok(filters) => compliance.symbol_filters = filters,
+                Err(e) => warn!(error = %e, "COMPLIANCE_SYMBOL_FILTERS 파싱 실패, 무시"),
+            }
+        }
+
+        compliance
+    }
+
+    /// 포지션 사이징 환경 변수 로드.
+    ///
+    /// `POSITION_SIZING_MODE`가 없으면 고정 비율(`max_position_pct`)을 사용합니다.
+    fn position_sizing_from_env() -> PositionSizingConfig {
+        let mut sizing = PositionSizingConfig::default();
+
+        if let Ok(mode) = std::env::var("POSITION_SIZING_MODE") {
+            match mode.to_lowercase().as_str() {
+                "fixed" => sizing.mode = SizingMode::Fixed,
+                "volatility_target" => sizing.mode = SizingMode::VolatilityTarget,
+                "inverse_volatility" => sizing.mode = SizingMode::InverseVolatility,
+                "risk_parity" => sizing.mode = SizingMode::RiskParity,
+                other => {
+                    warn!(mode = other, "알 수 없는 POSITION_SIZING_MODE, fixed 사용")
+                }
+            }
+        }
+        if let Ok(estimator) = std::env::var("POSITION_VOLATILITY_ESTIMATOR") {
+            sizing.estimator = match estimator.to_lowercase().as_str() {
+                "atr" => VolatilityEstimator::Atr,
+                _ => VolatilityEstimator::Realized,
+            };
+        }
+        if let Some(pct) = env_f64("POSITION_TARGET_VOLATILITY_PCT") {
+            sizing.target_volatility_pct = pct;
+        }
+        if let Some(lookback) = env_u32("POSITION_SIZING_LOOKBACK") {
+            sizing.lookback = lookback as usize;
         }
+        if let Some(periods) = env_f64("POSITION_SIZING_PERIODS_PER_YEAR") {
+            sizing.periods_per_year = periods;
+        }
+        if let Some(max_weight) = env_f64("POSITION_MAX_WEIGHT") {
+            sizing.max_weight = max_weight;
+        }
+
+        sizing
     }
 
     /// 소켓 주소 반환.
@@ -114,6 +312,18 @@ fn load_kis_config() -> Option<KisConfig> {
     KisConfig::from_env()
 }
 
+/// KIS 휴장일 API 기반 거래일 캘린더 생성.
+fn create_kis_calendar(config: &KisConfig) -> Option<Arc<dyn TradingCalendar>> {
+    let checker = KisOAuth::new(config.clone()).and_then(HolidayChecker::new);
+    match checker {
+        Ok(checker) => Some(Arc::new(KisTradingCalendar::new(checker))),
+        Err(e) => {
+            warn!(error = %e, "KIS 휴장일 캘린더 생성 실패, 주말 기준 캘린더 사용");
+            None
+        }
+    }
+}
+
 /// 실시간 시장 데이터 소스 시작.
 ///
 /// KIS 설정이 있고 USE_REAL_EXCHANGE=true면 실제 거래소 데이터를 사용하고,
@@ -283,17 +493,68 @@ fn create_kis_clients() -> (Option<KisKrClient>, Option<KisUsClient>) {
 }
 
 /// Active credential에서 KIS 클라이언트 생성.
+/// Binance exchangeInfo에서 심볼 필터(PRICE_FILTER / LOT_SIZE / MIN_NOTIONAL)를 로드합니다.
+///
+/// `COMPLIANCE_SYMBOL_FILTERS`에 지정한 종목은 환경 변수 값이 우선합니다.
+/// Binance 인증 정보가 없거나 조회에 실패하면 환경 변수 필터만 사용합니다.
+async fn load_binance_symbol_filters(compliance: &mut ComplianceConfig) {
+    let Some(client) = BinanceClient::from_env() else {
+        return;
+    };
+
+    match client.get_symbol_filters().await {
+        Ok(filters) => {
+            let count = filters.len();
+            for filter in filters {
+                compliance
+                    .symbol_filters
+                    .entry(filter.ticker)
+                    .or_insert(SymbolFilter {
+                        tick_size: filter.tick_size,
+                        step_size: filter.step_size,
+                        min_qty: filter.min_qty,
+                        max_qty: filter.max_qty,
+                        min_notional: filter.min_notional,
+                    });
+            }
+            info!(symbols = count, "Binance 심볼 필터 로드 완료");
+        }
+        Err(e) => {
+            warn!(error = %e, "Binance exchangeInfo 조회 실패, 환경 변수 심볼 필터만 사용");
+        }
+    }
+}
+
 /// AppState 초기화.
 async fn create_app_state(config: &ServerConfig) -> AppState {
     // 전략 엔진 생성
     let strategy_engine = StrategyEngine::new(EngineConfig::default());
 
+    // 거래소 심볼 필터 (Binance exchangeInfo + COMPLIANCE_SYMBOL_FILTERS 재정의)
+    let mut compliance = config.compliance.clone();
+    load_binance_symbol_filters(&mut compliance).await;
+
     // 리스크 매니저 생성
-    let risk_manager = RiskManager::new(RiskConfig::default(), config.initial_balance);
+    let risk_config = RiskConfig {
+        portfolio_var: config.portfolio_var.clone(),
+        concentration: config.concentration.clone(),
+        drawdown: config.drawdown.clone(),
+        compliance,
+        position_sizing: config.position_sizing.clone(),
+        ..RiskConfig::default()
+    };
+    let risk_config = match risk_config.validate() {
+        Ok(()) => risk_config,
+        Err(e) => {
+            warn!("리스크 설정 오류, 기본값 사용: {}", e);
+            RiskConfig::default()
+        }
+    };
+    let risk_manager = RiskManager::new(risk_config.clone(), config.initial_balance);
 
     // 주문 실행기 생성
     let executor = OrderExecutor::new_complete(
-        RiskManager::new(RiskConfig::default(), config.initial_balance),
+        RiskManager::new(risk_config, config.initial_balance),
         "default_exchange",
         ConversionConfig::default(),
     );
@@ -721,6 +982,45 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         warn!("ContextSyncService 시작 실패: ExchangeProvider 또는 AnalyticsProvider 미설정");
     }
 
+    // 전략 상태 영속화 (전략 시작 전에 저장소를 연결해야 스냅샷이 복원됨)
+    let snapshot_handle = if state.enable_strategy_state_persistence().await {
+        info!("Strategy state persistence enabled");
+        state.start_strategy_snapshots(shutdown_token.clone()).await
+    } else {
+        None
+    };
+
+    // 전략 스케줄 트리거 (KIS 설정이 있으면 휴장일 API로 거래일 판정)
+    let calendar = kis_config.as_ref().and_then(create_kis_calendar);
+    let _schedule_handle = state
+        .start_strategy_schedule(calendar, shutdown_token.clone())
+        .await;
+    info!("전략 스케줄 서비스 시작됨 (15초 주기)");
+
+    // 전략 생명주기 전이 감사 로그 (DB 연결 시)
+    let lifecycle_audit_handle = state
+        .start_strategy_lifecycle_audit(shutdown_token.clone())
+        .await;
+
+    // 전략 의사결정 로그 (DB 연결 시)
+    let decision_log_handle = state
+        .start_strategy_decision_log(shutdown_token.clone())
+        .await;
+
+    // 포트폴리오 VaR/ES 모니터링 (데이터 제공자 설정 시)
+    if state.start_portfolio_risk(shutdown_token.clone()).is_some() {
+        info!("포트폴리오 리스크 서비스 시작됨 (5분 주기)");
+    }
+
+    // 드로다운 서킷 브레이커 (DB 연결 시, 저장된 상태 복원)
+    let circuit_breaker_handle = state.start_circuit_breaker(shutdown_token.clone()).await;
+    if circuit_breaker_handle.is_some() {
+        info!("서킷 브레이커 서비스 시작됨 (5분 주기)");
+    }
+
+    // 섀도 전략 가상 실행
+    let _shadow_handle = state.start_shadow_trading(shutdown_token.clone()).await;
+
     // 데이터베이스에서 저장된 전략 로드
     if let Some(ref pool) = state.db_pool {
         let engine = state.strategy_engine.read().await;
@@ -738,11 +1038,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    // 실시간 매매 런타임 시작 (ENABLE_LIVE_TRADING=true인 경우)
+    start_live_trading(&state, shutdown_token.clone()).await;
+
     // 텔레그램 봇 시작 (백그라운드 태스크)
     if let Some(ref pool) = state.db_pool {
         let pool_clone = pool.clone();
+        let engine = state.strategy_engine.clone();
         tokio::spawn(async move {
-            ApiBotHandler::start(pool_clone).await;
+            ApiBotHandler::start(pool_clone, engine).await;
         });
     }
 
@@ -775,6 +1079,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cleanup_timeout = tokio::time::timeout(Duration::from_secs(10), async {
         // 진행 중인 요청 완료 대기
         tokio::time::sleep(Duration::from_millis(500)).await;
+        // 종료 직전 전략 상태 스냅샷 저장 완료 대기
+        if let Some(handle) = snapshot_handle {
+            let _ = handle.await;
+        }
+        // 수신한 생명주기 전이 저장 완료 대기
+        if let Some(handle) = lifecycle_audit_handle {
+            let _ = handle.await;
+        }
+        // 수신한 의사결정 기록 저장 완료 대기
+        if let Some(handle) = decision_log_handle {
+            let _ = handle.await;
+        }
+        // 서킷 브레이커 상태 저장 완료 대기
+        if let Some(handle) = circuit_breaker_handle {
+            let _ = handle.await;
+        }
         info!("Cleanup completed");
     })
     .await;
@@ -788,6 +1108,69 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// 실시간 매매 런타임 시작.
+///
+/// 전략 신호를 실제 거래소 주문으로 연결합니다. 기본값은 비활성화이며,
+/// `LIVE_TRADING_EXCHANGE`로 주문을 제출할 거래소를 선택합니다.
+/// 현재 주문 제출(`Exchange`)을 지원하는 거래소는 Binance뿐이며
+/// (사용자 스트림 미지원으로 미체결 주문을 폴링), KIS 등 지원하지 않는
+/// 거래소를 지정하면 오류를 기록하고 런타임을 시작하지 않습니다.
+///
+/// # 환경변수
+///
+/// - `ENABLE_LIVE_TRADING`: "true"면 실시간 매매 시작 (기본값: false)
+/// - `LIVE_TRADING_EXCHANGE`: 주문 거래소 (기본값: "binance")
+/// - `BINANCE_API_KEY`, `BINANCE_API_SECRET`: Binance 인증 정보
+///   (`BINANCE_TESTNET=true`면 `BINANCE_TESTNET_API_KEY`, `BINANCE_TESTNET_API_SECRET`)
+async fn start_live_trading(state: &AppState, shutdown_token: CancellationToken) {
+    let enabled = std::env::var("ENABLE_LIVE_TRADING")
+        .map(|v| v == "true" || v == "1")
+        .unwrap_or(false);
+    if !enabled {
+        return;
+    }
+
+    let exchange = std::env::var("LIVE_TRADING_EXCHANGE")
+        .unwrap_or_else(|_| "binance".to_string())
+        .trim()
+        .to_lowercase();
+    let mut client = match exchange.as_str() {
+        "binance" => match BinanceClient::from_env() {
+            Some(client) => client,
+            None => {
+                error!("ENABLE_LIVE_TRADING=true but Binance credentials not configured");
+                return;
+            }
+        },
+        "kis" | "kis_kr" | "kis_us" => {
+            error!(
+                exchange = %exchange,
+                "Live trading is not supported for KIS: no order-routing Exchange implementation"
+            );
+            return;
+        }
+        other => {
+            error!(
+                exchange = %other,
+                "Unknown LIVE_TRADING_EXCHANGE (supported: binance)"
+            );
+            return;
+        }
+    };
+    if let Err(e) = client.connect().await {
+        error!(error = %e, "Failed to connect exchange for live trading");
+        return;
+    }
+
+    match state
+        .start_live_trading(Arc::new(client), None, shutdown_token)
+        .await
+    {
+        Some(_handle) => info!("LiveTradingService 시작됨 (Binance, 주문 폴링)"),
+        None => warn!("LiveTradingService 시작 실패: 신호 수신기가 이미 사용 중"),
+    }
+}
+
 /// Graceful shutdown 시그널 대기.
 ///
 /// Ctrl+C 또는 SIGTERM 시그널을 수신하면 종료 토큰을 취소합니다.
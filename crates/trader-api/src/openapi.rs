@@ -233,10 +233,16 @@ use crate::routes::{
         crate::routes::strategies::get_strategy,
         crate::routes::strategies::start_strategy,
         crate::routes::strategies::stop_strategy,
+        crate::routes::strategies::get_strategy_lifecycle,
+        crate::routes::strategies::apply_strategy_lifecycle,
         crate::routes::strategies::update_config,
         crate::routes::strategies::update_risk_settings,
         crate::routes::strategies::update_symbols,
         crate::routes::strategies::clone_strategy,
+        crate::routes::strategies::list_strategy_shadows,
+        crate::routes::strategies::create_strategy_shadow,
+        crate::routes::strategies::delete_strategy_shadow,
+        crate::routes::strategies::list_strategy_decisions,
         crate::routes::strategies::get_engine_stats,
         crate::routes::strategies::get_strategy_timeframes,
         crate::routes::strategies::update_strategy_timeframes,
@@ -285,6 +291,10 @@ use crate::routes::{
         crate::routes::backtest::get_backtest_result,
         crate::routes::backtest::run_multi_backtest,
         crate::routes::backtest::run_batch_backtest,
+        crate::routes::backtest::run_portfolio_backtest,
+        crate::routes::backtest::optimize::start_optimization,
+        crate::routes::backtest::optimize::list_optimizations,
+        crate::routes::backtest::optimize::get_optimization,
 
         // ===== Orders =====
         crate::routes::orders::create_order,
@@ -302,6 +312,10 @@ use crate::routes::{
         crate::routes::portfolio::get_portfolio_summary,
         crate::routes::portfolio::get_balance,
         crate::routes::portfolio::get_holdings,
+        crate::routes::portfolio::get_portfolio_risk,
+        crate::routes::portfolio::get_circuit_breakers,
+        crate::routes::portfolio::reset_circuit_breakers,
+        crate::routes::portfolio::override_circuit_breakers,
         crate::routes::portfolio::get_order_history,
 
         // ===== Journal =====
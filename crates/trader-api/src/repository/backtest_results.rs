@@ -33,6 +33,8 @@ pub struct BacktestResultRecord {
     pub deleted_at: Option<DateTime<Utc>>,
     /// 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시)
     pub timeframes_used: Option<serde_json::Value>,
+    /// 몬테카를로 강건성 분석 결과
+    pub monte_carlo: Option<serde_json::Value>,
 }
 
 // ==================== 요청/응답 타입 ====================
@@ -88,6 +90,9 @@ pub struct BacktestResultDto {
     /// 백테스트에 사용된 타임프레임 설정
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeframes_used: Option<serde_json::Value>,
+    /// 몬테카를로 강건성 분석 결과
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monte_carlo: Option<serde_json::Value>,
 }
 
 impl From<BacktestResultRecord> for BacktestResultDto {
@@ -108,6 +113,7 @@ impl From<BacktestResultRecord> for BacktestResultDto {
             success: record.success,
             created_at: record.created_at.to_rfc3339(),
             timeframes_used: record.timeframes_used,
+            monte_carlo: record.monte_carlo,
         }
     }
 }
@@ -218,7 +224,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   monte_carlo
             FROM backtest_results
             WHERE id = $1 AND deleted_at IS NULL
             "#,
@@ -259,7 +266,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   monte_carlo
             FROM backtest_results
             WHERE deleted_at IS NULL
               AND ($1::text IS NULL OR strategy_id = $1)
@@ -306,6 +314,31 @@ impl BacktestResultsRepository {
         }
     }
 
+    /// 몬테카를로 분석 결과 저장.
+    ///
+    /// 기존 분석 결과가 있으면 덮어씁니다. 삭제된 결과에는 저장하지 않습니다.
+    pub async fn save_monte_carlo(
+        pool: &PgPool,
+        id: Uuid,
+        monte_carlo: &serde_json::Value,
+    ) -> Result<bool, sqlx::Error> {
+        debug!("몬테카를로 분석 결과 저장: id={}", id);
+
+        let result = sqlx::query(
+            r#"
+            UPDATE backtest_results
+            SET monte_carlo = $2
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(monte_carlo)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     /// 특정 전략의 모든 결과 조회.
     pub async fn get_by_strategy_id(
         pool: &PgPool,
@@ -317,7 +350,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   monte_carlo
             FROM backtest_results
             WHERE strategy_id = $1 AND deleted_at IS NULL
             ORDER BY created_at DESC
@@ -341,7 +375,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   monte_carlo
             FROM backtest_results
             WHERE deleted_at IS NULL
             ORDER BY created_at DESC
@@ -422,6 +457,7 @@ mod tests {
             created_at: Utc::now(),
             deleted_at: None,
             timeframes_used: None,
+            monte_carlo: None,
         };
 
         let dto: BacktestResultDto = record.into();
@@ -16,11 +16,15 @@ pub mod orders;
 pub mod portfolio;
 pub mod positions;
 pub mod reality_check;
+pub mod risk_circuit_breaker;
 pub mod score_history;
 pub mod screening;
 pub mod signal_alert_rule;
 pub mod signal_marker;
 pub mod strategies;
+pub mod strategy_decisions;
+pub mod strategy_lifecycle;
+pub mod strategy_state;
 pub mod symbol_fundamental;
 pub mod symbol_info;
 pub mod watchlist;
@@ -51,18 +55,22 @@ pub use reality_check::{
     CalculationResult, DailyStats, PriceSnapshot, RankStats, RealityCheckRecord,
     RealityCheckRepository, SnapshotInput, SourceStats,
 };
+pub use risk_circuit_breaker::{RiskCircuitBreakerRepository, LIVE_CIRCUIT_BREAKER_SCOPE};
 pub use screening::{
     CreatePresetRequest, MomentumScreenResult, ScreeningFilter, ScreeningPreset,
     ScreeningPresetRecord, ScreeningRepository, ScreeningResult, SectorRsResult,
 };
 pub use strategies::StrategyRepository;
+pub use strategy_decisions::{DecisionEventRecord, StrategyDecisionRepository};
+pub use strategy_lifecycle::{LifecycleEventRecord, StrategyLifecycleRepository};
+pub use strategy_state::{PgStrategyStateStore, StrategyStateRecord, StrategyStateRepository};
 pub use symbol_fundamental::{
     IndicatorUpdate, NewSymbolFundamental, SymbolFundamental, SymbolFundamentalRepository,
     SymbolWithFundamental,
 };
 pub use symbol_info::{
     DeactivatedStats, ExternalFetchError, FailedSymbolInfo, FetchFailureResult, NewSymbolInfo,
-    SymbolInfo, SymbolInfoRepository, SymbolSearchResult, MAX_FETCH_FAILURES,
+    SymbolInfo, SymbolInfoRepository, SymbolRiskProfile, SymbolSearchResult, MAX_FETCH_FAILURES,
 };
 
 pub use global_score::{
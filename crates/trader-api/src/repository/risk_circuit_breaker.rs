@@ -0,0 +1,57 @@
+//! 서킷 브레이커 상태 Repository.
+//!
+//! RiskManager의 드로다운/연속 손실 서킷 브레이커 상태를 `risk_circuit_breaker_state`
+//! 테이블에 저장하여 서버 재시작 후에도 발동 중인 브레이커와 연속 손실 수를 복원합니다.
+
+use sqlx::PgPool;
+use trader_risk::CircuitBreakerState;
+
+/// 실전 주문 실행기의 리스크 매니저 구분 값.
+pub const LIVE_CIRCUIT_BREAKER_SCOPE: &str = "live";
+
+/// 서킷 브레이커 상태 Repository.
+pub struct RiskCircuitBreakerRepository;
+
+impl RiskCircuitBreakerRepository {
+    /// 상태 저장 (upsert).
+    pub async fn save(
+        pool: &PgPool,
+        scope: &str,
+        state: &CircuitBreakerState,
+    ) -> Result<(), sqlx::Error> {
+        let state = serde_json::to_value(state).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO risk_circuit_breaker_state (scope, state, updated_at)
+            VALUES ($1, $2, NOW())
+            ON CONFLICT (scope) DO UPDATE SET
+                state = EXCLUDED.state,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(scope)
+        .bind(state)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 저장된 상태 조회.
+    pub async fn load(
+        pool: &PgPool,
+        scope: &str,
+    ) -> Result<Option<CircuitBreakerState>, sqlx::Error> {
+        let state: Option<serde_json::Value> =
+            sqlx::query_scalar("SELECT state FROM risk_circuit_breaker_state WHERE scope = $1")
+                .bind(scope)
+                .fetch_optional(pool)
+                .await?;
+
+        state
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))
+    }
+}
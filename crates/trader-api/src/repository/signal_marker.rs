@@ -342,6 +342,7 @@ impl SignalMarkerRow {
             "ADD_TO_POSITION" | "AddToPosition" => SignalType::AddToPosition,
             "REDUCE_POSITION" | "ReducePosition" => SignalType::ReducePosition,
             "SCALE" | "Scale" => SignalType::Scale,
+            "REBALANCE" | "Rebalance" => SignalType::Rebalance,
             _ => {
                 return Err((
                     StatusCode::INTERNAL_SERVER_ERROR,
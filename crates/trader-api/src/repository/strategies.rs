@@ -419,6 +419,9 @@ impl StrategyRepository {
                 {
                     Ok(_) => {
                         tracing::info!("Loaded strategy from DB: {} ({})", record.name, record.id);
+                        engine
+                            .set_allocated_capital(&record.id, record.allocated_capital)
+                            .await?;
                         loaded_count += 1;
                     }
                     Err(e) => {
@@ -0,0 +1,129 @@
+//! 전략 의사결정 로그 Repository.
+//!
+//! `StrategyEngine`과 실시간 매매 서비스가 기록한 의사결정(조건 평가, 필터 거부, 생명주기 억제,
+//! 리스크 거부, 주문 제출)을 `strategy_decisions` 테이블에 저장합니다.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use trader_strategy::{DecisionQuery, DecisionRecord};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 의사결정 DB 행.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, ToSchema)]
+pub struct DecisionEventRecord {
+    /// 기록 ID
+    pub id: Uuid,
+    /// 추적 ID (같은 캔들에서 시작된 기록 공유)
+    pub trace_id: String,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 종목
+    pub ticker: String,
+    /// 단계 (strategy, lifecycle, risk, execution)
+    pub stage: String,
+    /// 결과 (emitted, no_signal, filtered, suppressed, rejected, submitted)
+    pub outcome: String,
+    /// 평가한 조건 목록
+    pub conditions: serde_json::Value,
+    /// 판단에 사용한 지표 값
+    pub indicators: serde_json::Value,
+    /// 거부 주체
+    pub rejected_by: Option<String>,
+    /// 사유
+    pub reason: Option<String>,
+    /// 관련 신호 ID
+    pub signal_id: Option<Uuid>,
+    /// 평가한 데이터 시각
+    pub data_time: Option<DateTime<Utc>>,
+    /// 기록 시각
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl From<&DecisionRecord> for DecisionEventRecord {
+    fn from(record: &DecisionRecord) -> Self {
+        Self {
+            id: record.id,
+            trace_id: record.trace_id.clone(),
+            strategy_id: record.strategy_id.clone(),
+            ticker: record.ticker.clone(),
+            stage: record.stage.to_string(),
+            outcome: record.outcome.to_string(),
+            conditions: serde_json::to_value(&record.conditions).unwrap_or_default(),
+            indicators: serde_json::to_value(&record.indicators).unwrap_or_default(),
+            rejected_by: record.rejected_by.clone(),
+            reason: record.reason.clone(),
+            signal_id: record.signal_id,
+            data_time: record.data_time,
+            recorded_at: record.recorded_at,
+        }
+    }
+}
+
+/// 전략 의사결정 Repository.
+pub struct StrategyDecisionRepository;
+
+impl StrategyDecisionRepository {
+    /// 의사결정 기록 저장.
+    pub async fn insert(pool: &PgPool, record: &DecisionRecord) -> Result<(), sqlx::Error> {
+        let row = DecisionEventRecord::from(record);
+        sqlx::query(
+            r#"
+            INSERT INTO strategy_decisions
+                (id, trace_id, strategy_id, ticker, stage, outcome, conditions, indicators,
+                 rejected_by, reason, signal_id, data_time, recorded_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+        )
+        .bind(row.id)
+        .bind(&row.trace_id)
+        .bind(&row.strategy_id)
+        .bind(&row.ticker)
+        .bind(&row.stage)
+        .bind(&row.outcome)
+        .bind(&row.conditions)
+        .bind(&row.indicators)
+        .bind(&row.rejected_by)
+        .bind(&row.reason)
+        .bind(row.signal_id)
+        .bind(row.data_time)
+        .bind(row.recorded_at)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 조건에 맞는 의사결정 기록 조회 (최신순).
+    ///
+    /// `trace_id`를 지정하면 전략 ID와 무관하게 해당 추적의 모든 단계를 반환합니다.
+    pub async fn list(
+        pool: &PgPool,
+        query: &DecisionQuery,
+    ) -> Result<Vec<DecisionEventRecord>, sqlx::Error> {
+        sqlx::query_as::<_, DecisionEventRecord>(
+            r#"
+            SELECT id, trace_id, strategy_id, ticker, stage, outcome, conditions, indicators,
+                   rejected_by, reason, signal_id, data_time, recorded_at
+            FROM strategy_decisions
+            WHERE ($1::text IS NULL OR strategy_id = $1)
+              AND ($2::text IS NULL OR ticker = $2)
+              AND ($3::text IS NULL OR trace_id = $3)
+              AND ($4::timestamptz IS NULL OR recorded_at >= $4)
+              AND ($5::timestamptz IS NULL OR recorded_at <= $5)
+            ORDER BY recorded_at DESC
+            LIMIT $6
+            "#,
+        )
+        .bind(&query.strategy_id)
+        .bind(&query.ticker)
+        .bind(&query.trace_id)
+        .bind(query.from)
+        .bind(query.to)
+        .bind(query.limit as i64)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,90 @@
+//! 전략 생명주기 전이 감사 로그 Repository.
+//!
+//! `StrategyEngine`이 기록한 상태 전이(시작, 워밍업 완료, 일시 정지, 청산 대기, 중지, 오류)를
+//! `strategy_lifecycle_events` 테이블에 저장합니다.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use trader_strategy::LifecycleTransition;
+use utoipa::ToSchema;
+
+/// 생명주기 전이 DB 행.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow, ToSchema)]
+pub struct LifecycleEventRecord {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 이전 상태
+    pub from_state: String,
+    /// 다음 상태
+    pub to_state: String,
+    /// 요청 주체 (api, telegram, engine)
+    pub actor: String,
+    /// 전이 사유
+    pub reason: String,
+    /// 전이 시각
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<&LifecycleTransition> for LifecycleEventRecord {
+    fn from(transition: &LifecycleTransition) -> Self {
+        Self {
+            strategy_id: transition.strategy_id.clone(),
+            from_state: transition.from.to_string(),
+            to_state: transition.to.to_string(),
+            actor: transition.actor.clone(),
+            reason: transition.reason.clone(),
+            occurred_at: transition.at,
+        }
+    }
+}
+
+/// 전략 생명주기 전이 Repository.
+pub struct StrategyLifecycleRepository;
+
+impl StrategyLifecycleRepository {
+    /// 전이 기록 저장.
+    pub async fn insert(
+        pool: &PgPool,
+        transition: &LifecycleTransition,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO strategy_lifecycle_events
+                (strategy_id, from_state, to_state, actor, reason, occurred_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(&transition.strategy_id)
+        .bind(transition.from.as_str())
+        .bind(transition.to.as_str())
+        .bind(&transition.actor)
+        .bind(&transition.reason)
+        .bind(transition.at)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 전략의 최근 전이 기록 조회 (최신순).
+    pub async fn list_recent(
+        pool: &PgPool,
+        strategy_id: &str,
+        limit: i64,
+    ) -> Result<Vec<LifecycleEventRecord>, sqlx::Error> {
+        sqlx::query_as::<_, LifecycleEventRecord>(
+            r#"
+            SELECT strategy_id, from_state, to_state, actor, reason, occurred_at
+            FROM strategy_lifecycle_events
+            WHERE strategy_id = $1
+            ORDER BY occurred_at DESC, id DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
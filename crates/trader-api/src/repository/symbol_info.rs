@@ -43,6 +43,15 @@ pub struct SymbolSearchResult {
     pub yahoo_symbol: Option<String>,
 }
 
+/// 리스크 집중도 검사용 종목 분류 (섹터, 시장, 통화).
+#[derive(Debug, Clone, FromRow)]
+pub struct SymbolRiskProfile {
+    pub ticker: String,
+    pub market: String,
+    pub sector: Option<String>,
+    pub currency: Option<String>,
+}
+
 /// 새 심볼 정보 삽입용.
 #[derive(Debug, Clone)]
 pub struct NewSymbolInfo {
@@ -192,6 +201,33 @@ impl SymbolInfoRepository {
             .collect())
     }
 
+    /// 여러 티커의 리스크 분류 정보 조회.
+    ///
+    /// 섹터는 symbol_info, 통화는 symbol_fundamental에서 가져옵니다.
+    pub async fn get_risk_profiles(
+        pool: &PgPool,
+        tickers: &[String],
+    ) -> Result<Vec<SymbolRiskProfile>, sqlx::Error> {
+        if tickers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tickers_upper: Vec<String> = tickers.iter().map(|t| t.to_uppercase()).collect();
+
+        sqlx::query_as::<_, SymbolRiskProfile>(
+            r#"
+            SELECT si.ticker, si.market, si.sector, sf.currency
+            FROM symbol_info si
+            LEFT JOIN symbol_fundamental sf ON sf.symbol_info_id = si.id
+            WHERE UPPER(si.ticker) = ANY($1)
+              AND si.is_active = true
+            "#,
+        )
+        .bind(&tickers_upper)
+        .fetch_all(pool)
+        .await
+    }
+
     /// 심볼 정보 일괄 삽입 (upsert).
     pub async fn upsert_batch(
         pool: &PgPool,
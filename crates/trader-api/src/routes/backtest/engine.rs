@@ -19,25 +19,37 @@ use rust_decimal::Decimal;
 use std::collections::{BTreeMap, HashMap};
 use tracing::debug;
 
-use super::loader::parse_symbol;
+use super::loader::{expand_strategy_symbols, parse_symbol};
 use super::types::{
-    BacktestConfigSummary, BacktestMetricsResponse, BacktestMultiRunResponse, BacktestRunResponse,
-    EquityCurvePoint, TradeHistoryItem,
+    AllocationHistoryPoint, BacktestConfigSummary, BacktestExecutionMode, BacktestMetricsResponse,
+    BacktestMultiRunResponse, BacktestPortfolioResponse, BacktestRunResponse,
+    BenchmarkComparisonResponse, EquityCurvePoint, PortfolioStrategyItem, PortfolioStrategyResult,
+    SlippageRecordItem, StrategyCorrelationResponse, TradeHistoryItem,
 };
 
-use trader_analytics::backtest::{BacktestConfig, BacktestEngine, BacktestReport};
+use trader_analytics::backtest::{
+    BacktestConfig, BacktestEngine, BacktestReport, PortfolioBacktestConfig,
+    PortfolioBacktestEngine, PortfolioBacktestReport, PortfolioSleeve,
+};
+use trader_analytics::performance::{EquityPoint, PerformanceMetrics, RoundTrip};
 use trader_core::{Kline, MarketType, Symbol, Timeframe};
+use trader_strategy::strategies::common::PositionSizingConfig;
 use trader_strategy::StrategyRegistry;
 
+use crate::services::ReplayBacktest;
+
 /// 전략별 백테스트 실행
 ///
 /// CPU-intensive 백테스트 계산을 `spawn_blocking`으로 별도 thread pool에서 실행하여
 /// Tokio async runtime의 worker thread를 블로킹하지 않습니다.
+///
+/// `mode`가 [`BacktestExecutionMode::Replay`]이면 [`ReplayBacktest`]로 실거래 경로를 재생합니다.
 pub async fn run_strategy_backtest(
     strategy_id: &str,
     config: BacktestConfig,
     klines: &[Kline],
     params: &Option<serde_json::Value>,
+    mode: BacktestExecutionMode,
 ) -> Result<BacktestReport, String> {
     // 데이터를 owned 타입으로 변환하여 spawn_blocking으로 이동
     let strategy_id = strategy_id.to_string();
@@ -57,6 +69,7 @@ pub async fn run_strategy_backtest(
             config,
             &klines,
             &params,
+            mode,
         ))
     })
     .await
@@ -85,6 +98,24 @@ fn inject_ticker(params: Option<serde_json::Value>, ticker: &str) -> serde_json:
     config
 }
 
+/// 전략 params의 `position_sizing` 설정을 백테스트 진입 크기 계산에 반영
+///
+/// 설정이 없으면 고정 비율(`max_position_size_pct × strength`)을 유지하고,
+/// 형식이 잘못되면 에러를 반환합니다.
+fn apply_position_sizing(
+    config: BacktestConfig,
+    params: &Option<serde_json::Value>,
+) -> Result<BacktestConfig, String> {
+    let Some(params) = params else {
+        return Ok(config);
+    };
+    match PositionSizingConfig::from_strategy_config(params) {
+        Ok(Some(sizing)) => Ok(config.with_position_sizing(sizing)),
+        Ok(None) => Ok(config),
+        Err(e) => Err(format!("position_sizing 설정 오류: {}", e)),
+    }
+}
+
 /// 내부 백테스트 실행 함수 (sync 컨텍스트에서 호출됨)
 ///
 /// StrategyRegistry를 사용하여 전략 인스턴스를 동적으로 생성합니다.
@@ -94,8 +125,9 @@ async fn run_strategy_backtest_inner(
     config: BacktestConfig,
     klines: &[Kline],
     params: &Option<serde_json::Value>,
+    mode: BacktestExecutionMode,
 ) -> Result<BacktestReport, String> {
-    let mut engine = BacktestEngine::new(config);
+    let config = apply_position_sizing(config, params)?;
 
     // 심볼 추출 (klines에서)
     let symbol_str = if let Some(first_kline) = klines.first() {
@@ -118,12 +150,20 @@ async fn run_strategy_backtest_inner(
         "전략 초기화 (StrategyRegistry 기반)"
     );
 
+    // 재생 모드: StrategyEngine이 등록 후 직접 초기화
+    if mode == BacktestExecutionMode::Replay {
+        return ReplayBacktest::new(config)
+            .run(strategy_id, strategy, strategy_config, klines)
+            .await
+            .map_err(|e| e.to_string());
+    }
+
     strategy
         .initialize(strategy_config)
         .await
         .map_err(|e| format!("전략 초기화 실패: {}", e))?;
 
-    engine
+    BacktestEngine::new(config)
         .run(&mut *strategy, klines)
         .await
         .map_err(|e| e.to_string())
@@ -218,6 +258,7 @@ async fn run_multi_strategy_backtest_inner(
     multi_klines: &HashMap<String, Vec<Kline>>,
     params: &Option<serde_json::Value>,
 ) -> Result<BacktestReport, String> {
+    let config = apply_position_sizing(config, params)?;
     let initial_capital = config.initial_capital;
     let mut engine = BacktestEngine::new(config);
 
@@ -249,6 +290,103 @@ async fn run_multi_strategy_backtest_inner(
         .map_err(|e| e.to_string())
 }
 
+/// 포트폴리오(다중 전략) 백테스트 실행
+///
+/// 여러 전략을 하나의 현금 잔고와 `max_positions` 제약 아래에서 함께 실행합니다.
+/// CPU-intensive 계산은 `spawn_blocking`으로 별도 thread pool에서 실행합니다.
+pub async fn run_portfolio_strategy_backtest(
+    config: PortfolioBacktestConfig,
+    strategies: &[PortfolioStrategyItem],
+    merged_klines: &[Kline],
+    multi_klines: &HashMap<String, Vec<Kline>>,
+) -> Result<PortfolioBacktestReport, String> {
+    let strategies = strategies.to_vec();
+    let merged_klines = merged_klines.to_vec();
+    let multi_klines = multi_klines.clone();
+
+    let report = tokio::task::spawn_blocking(move || {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| format!("Runtime 생성 실패: {}", e))?;
+
+        rt.block_on(run_portfolio_strategy_backtest_inner(
+            config,
+            &strategies,
+            &merged_klines,
+            &multi_klines,
+        ))
+    })
+    .await
+    .map_err(|e| format!("포트폴리오 백테스트 태스크 실행 실패: {}", e))??;
+
+    Ok(report)
+}
+
+/// 내부 포트폴리오 백테스트 실행 함수 (sync 컨텍스트에서 호출됨)
+///
+/// 전략마다 StrategyRegistry로 인스턴스를 만들고, 배분 자본 기준으로 params를 주입한 뒤
+/// 자신의 심볼 캔들만 받도록 슬리브를 구성합니다.
+async fn run_portfolio_strategy_backtest_inner(
+    config: PortfolioBacktestConfig,
+    strategies: &[PortfolioStrategyItem],
+    merged_klines: &[Kline],
+    multi_klines: &HashMap<String, Vec<Kline>>,
+) -> Result<PortfolioBacktestReport, String> {
+    let initial_capital = config.backtest.initial_capital;
+    let total_weight: Decimal = strategies
+        .iter()
+        .map(|item| item.weight.unwrap_or(Decimal::ONE))
+        .sum();
+    let mut engine = PortfolioBacktestEngine::new(config);
+
+    for item in strategies {
+        let weight = item.weight.unwrap_or(Decimal::ONE);
+        let sleeve_capital = if total_weight > Decimal::ZERO {
+            initial_capital * weight / total_weight
+        } else {
+            initial_capital
+        };
+
+        // 전략 필수 심볼까지 확장한 뒤 실제 캔들의 ticker로 변환
+        let symbols = expand_strategy_symbols(&item.strategy_id, &item.symbols);
+        let tickers: Vec<String> = symbols
+            .iter()
+            .filter_map(|s| multi_klines.get(s).and_then(|k| k.first()))
+            .map(|k| k.ticker.to_string())
+            .collect();
+
+        let mut strategy = StrategyRegistry::create_instance(&item.strategy_id)
+            .map_err(|e| format!("전략 생성 실패 ({}): {}", item.sleeve_name(), e))?;
+
+        let strategy_config = match tickers.as_slice() {
+            [ticker] => inject_ticker(item.parameters.clone(), ticker),
+            _ => inject_multi_asset_params(item.parameters.clone(), &symbols, sleeve_capital),
+        };
+
+        debug!(
+            sleeve = item.sleeve_name(),
+            strategy_id = %item.strategy_id,
+            tickers = ?tickers,
+            config = ?strategy_config,
+            "포트폴리오 전략 초기화 (StrategyRegistry 기반)"
+        );
+
+        strategy
+            .initialize(strategy_config)
+            .await
+            .map_err(|e| format!("전략 초기화 실패 ({}): {}", item.sleeve_name(), e))?;
+
+        engine.add_sleeve(
+            PortfolioSleeve::new(item.sleeve_name(), strategy)
+                .with_weight(weight)
+                .with_tickers(tickers),
+        );
+    }
+
+    engine.run(merged_klines).await.map_err(|e| e.to_string())
+}
+
 /// BacktestReport를 API 응답으로 변환
 pub fn convert_report_to_response(
     report: &BacktestReport,
@@ -287,6 +425,23 @@ pub fn convert_report_to_response(
         })
         .collect();
 
+    // 체결별 슬리피지 변환
+    let slippage_records: Vec<SlippageRecordItem> = report
+        .slippage_records
+        .iter()
+        .map(|record| SlippageRecordItem {
+            symbol: record.symbol.clone(),
+            side: record.side,
+            quantity: record.quantity,
+            executed_at: record.executed_at,
+            model: record.model.clone(),
+            base_price: record.result.base_price,
+            execution_price: record.result.execution_price,
+            slippage_rate: record.result.slippage_rate,
+            slippage_amount: record.result.slippage_amount,
+        })
+        .collect();
+
     // 성과 지표 변환
     let metrics = BacktestMetricsResponse {
         total_return_pct: report.metrics.total_return_pct,
@@ -309,6 +464,11 @@ pub fn convert_report_to_response(
         initial_capital: report.config.initial_capital,
         commission_rate: report.config.commission_rate,
         slippage_rate: report.config.slippage_rate,
+        slippage_model: report
+            .config
+            .slippage_model
+            .as_ref()
+            .map(|model| model.name().to_string()),
         total_commission: report.total_commission,
         total_slippage: report.total_slippage,
         data_points: report.data_points,
@@ -324,7 +484,9 @@ pub fn convert_report_to_response(
         metrics,
         equity_curve,
         trades,
+        slippage_records,
         config_summary,
+        benchmark: convert_benchmark(&report.metrics, &report.benchmark_curve),
     }
 }
 
@@ -393,6 +555,11 @@ pub fn convert_multi_report_to_response(
         initial_capital: report.config.initial_capital,
         commission_rate: report.config.commission_rate,
         slippage_rate: report.config.slippage_rate,
+        slippage_model: report
+            .config
+            .slippage_model
+            .as_ref()
+            .map(|model| model.name().to_string()),
         total_commission: report.total_commission,
         total_slippage: report.total_slippage,
         data_points: report.data_points,
@@ -410,6 +577,169 @@ pub fn convert_multi_report_to_response(
         trades,
         config_summary,
         data_points_by_symbol,
+        benchmark: convert_benchmark(&report.metrics, &report.benchmark_curve),
+    }
+}
+
+/// 포트폴리오 BacktestReport를 API 응답으로 변환
+pub fn convert_portfolio_report_to_response(
+    report: &PortfolioBacktestReport,
+    strategies: &[PortfolioStrategyItem],
+    start_date: &str,
+    end_date: &str,
+) -> BacktestPortfolioResponse {
+    let strategy_results = report
+        .strategies
+        .iter()
+        .map(|perf| {
+            let item = strategies
+                .iter()
+                .find(|item| item.sleeve_name() == perf.strategy_id);
+            let trades = report
+                .trades
+                .iter()
+                .filter(|rt| rt.strategy_id.as_deref() == Some(perf.strategy_id.as_str()))
+                .map(convert_round_trip)
+                .collect();
+
+            PortfolioStrategyResult {
+                name: perf.strategy_id.clone(),
+                strategy_id: item
+                    .map(|item| item.strategy_id.clone())
+                    .unwrap_or_else(|| perf.strategy_id.clone()),
+                symbols: item.map(|item| item.symbols.clone()).unwrap_or_default(),
+                initial_weight: perf.initial_weight,
+                final_weight: perf.final_weight,
+                initial_capital: perf.initial_capital,
+                final_equity: perf.final_equity,
+                metrics: convert_metrics(&perf.metrics),
+                equity_curve: dedup_equity_curve(&perf.equity_curve),
+                trades,
+            }
+        })
+        .collect();
+
+    let correlation = report
+        .correlation
+        .as_ref()
+        .map(|c| StrategyCorrelationResponse {
+            strategies: c.symbols.clone(),
+            matrix: c.matrix.clone(),
+            period: c.period,
+        });
+
+    let allocation_history = report
+        .allocation_history
+        .iter()
+        .map(|snapshot| AllocationHistoryPoint {
+            timestamp: snapshot.timestamp.timestamp(),
+            weights: snapshot.weights.clone(),
+        })
+        .collect();
+
+    let backtest_config = &report.config.backtest;
+    let config_summary = BacktestConfigSummary {
+        initial_capital: backtest_config.initial_capital,
+        commission_rate: backtest_config.commission_rate,
+        slippage_rate: backtest_config.slippage_rate,
+        slippage_model: backtest_config
+            .slippage_model
+            .as_ref()
+            .map(|model| model.name().to_string()),
+        total_commission: report.total_commission,
+        total_slippage: report.total_slippage,
+        data_points: report.data_points,
+    };
+
+    BacktestPortfolioResponse {
+        id: uuid::Uuid::new_v4().to_string(),
+        success: true,
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+        allocation: report.config.allocation,
+        metrics: convert_metrics(&report.metrics),
+        equity_curve: dedup_equity_curve(&report.equity_curve),
+        strategies: strategy_results,
+        correlation,
+        allocation_history,
+        config_summary,
+        benchmark: convert_benchmark(&report.metrics, &report.benchmark_curve),
+    }
+}
+
+/// 같은 timestamp의 마지막 equity만 남긴 자산 곡선
+///
+/// 라운드트립 완료 시점에 추가 기록된 값이 있어도 바당 한 점만 유지합니다.
+fn dedup_equity_curve(points: &[EquityPoint]) -> Vec<EquityCurvePoint> {
+    let mut equity_map: BTreeMap<i64, EquityCurvePoint> = BTreeMap::new();
+    for ep in points {
+        let ts = ep.timestamp.timestamp();
+        equity_map.insert(
+            ts,
+            EquityCurvePoint {
+                timestamp: ts,
+                equity: ep.equity,
+                drawdown_pct: ep.drawdown_pct,
+            },
+        );
+    }
+    equity_map.into_values().collect()
+}
+
+/// 벤치마크 대비 지표와 벤치마크 자산 곡선을 응답으로 변환
+fn convert_benchmark(
+    metrics: &PerformanceMetrics,
+    benchmark_curve: &[EquityPoint],
+) -> Option<BenchmarkComparisonResponse> {
+    let benchmark = metrics.benchmark.as_ref()?;
+    Some(BenchmarkComparisonResponse {
+        symbol: benchmark.benchmark.clone(),
+        benchmark_return_pct: benchmark.benchmark_return_pct,
+        excess_return_pct: benchmark.excess_return_pct,
+        alpha_pct: benchmark.alpha_pct,
+        beta: benchmark.beta,
+        tracking_error_pct: benchmark.tracking_error_pct,
+        information_ratio: benchmark.information_ratio,
+        up_capture_pct: benchmark.up_capture_pct,
+        down_capture_pct: benchmark.down_capture_pct,
+        correlation: benchmark.correlation,
+        periods: benchmark.periods,
+        equity_curve: dedup_equity_curve(benchmark_curve),
+    })
+}
+
+/// RoundTrip을 거래 내역 항목으로 변환
+fn convert_round_trip(rt: &RoundTrip) -> TradeHistoryItem {
+    TradeHistoryItem {
+        symbol: rt.symbol.to_string(),
+        entry_time: rt.entry_time,
+        exit_time: rt.exit_time,
+        entry_price: rt.entry_price,
+        exit_price: rt.exit_price,
+        quantity: rt.quantity,
+        side: rt.side,
+        pnl: rt.pnl,
+        return_pct: rt.return_pct,
+    }
+}
+
+/// PerformanceMetrics를 성과 지표 응답으로 변환
+fn convert_metrics(metrics: &PerformanceMetrics) -> BacktestMetricsResponse {
+    BacktestMetricsResponse {
+        total_return_pct: metrics.total_return_pct,
+        annualized_return_pct: metrics.annualized_return_pct,
+        net_profit: metrics.net_profit,
+        total_trades: metrics.total_trades,
+        win_rate_pct: metrics.win_rate_pct,
+        profit_factor: metrics.profit_factor,
+        sharpe_ratio: metrics.sharpe_ratio,
+        sortino_ratio: metrics.sortino_ratio,
+        max_drawdown_pct: metrics.max_drawdown_pct,
+        calmar_ratio: metrics.calmar_ratio,
+        avg_win: metrics.avg_win,
+        avg_loss: metrics.avg_loss,
+        largest_win: metrics.largest_win,
+        largest_loss: metrics.largest_loss,
     }
 }
 
@@ -59,6 +59,31 @@ pub async fn load_klines_from_db(
     Ok(filtered)
 }
 
+/// 벤치마크 Kline 데이터 로드
+///
+/// DB에서 조회하고, DB가 없거나 데이터가 비어 있으면 샘플 데이터를 사용합니다.
+pub async fn load_benchmark_klines(
+    pool: Option<&sqlx::PgPool>,
+    symbol_str: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Vec<Kline> {
+    if let Some(pool) = pool {
+        match load_klines_from_db(pool, symbol_str, start_date, end_date).await {
+            Ok(data) if !data.is_empty() => return data,
+            Ok(_) => warn!(
+                symbol = symbol_str,
+                "벤치마크 데이터가 없어 샘플 데이터 사용"
+            ),
+            Err(e) => warn!(
+                symbol = symbol_str,
+                "벤치마크 로드 실패, 샘플 데이터 사용: {}", e
+            ),
+        }
+    }
+    generate_sample_klines(symbol_str, start_date, end_date)
+}
+
 /// 샘플 Kline 데이터 생성 (DB 데이터가 없을 경우 사용)
 pub fn generate_sample_klines(
     symbol_str: &str,
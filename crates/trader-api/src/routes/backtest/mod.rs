@@ -6,20 +6,29 @@
 //!
 //! - `GET /api/v1/backtest/strategies` - 백테스트 가능한 전략 목록
 //! - `POST /api/v1/backtest/run` - 백테스트 실행
+//! - `POST /api/v1/backtest/run-portfolio` - 포트폴리오(다중 전략) 백테스트 실행
+//! - `POST /api/v1/backtest/optimize` - 파라미터 최적화 작업 시작 (비동기)
+//! - `GET /api/v1/backtest/optimize/{job_id}` - 파라미터 최적화 진행 상황/결과 조회
 //! - `GET /api/v1/backtest/results/{id}` - 백테스트 결과 조회
 
 mod engine;
 mod loader;
+pub mod optimize;
 mod types;
 mod ui_schema;
 
 // Re-export public types
 pub use types::{
+    AllocationHistoryPoint,
     BacktestApiError,
     BacktestConfigSummary,
+    BacktestExecutionMode,
     BacktestMetricsResponse,
     BacktestMultiRunRequest,
     BacktestMultiRunResponse,
+    BacktestOptimizeRequest,
+    BacktestPortfolioRequest,
+    BacktestPortfolioResponse,
     BacktestRunRequest,
     BacktestRunResponse,
     BacktestStrategiesResponse,
@@ -29,11 +38,21 @@ pub use types::{
     BatchBacktestRequest,
     BatchBacktestResponse,
     BatchBacktestResultItem,
+    BenchmarkComparisonResponse,
     EquityCurvePoint,
     ExecutionSchedule,
     // 다중 타임프레임
     MultiTimeframeRequest,
+    OptimizeJob,
+    OptimizeJobListResponse,
+    OptimizeJobStartResponse,
+    OptimizeJobStatus,
+    OptimizeRunItem,
+    PortfolioStrategyItem,
+    PortfolioStrategyResult,
     SecondaryTimeframeConfig,
+    SlippageRecordItem,
+    StrategyCorrelationResponse,
     SymbolCategory,
     TradeHistoryItem,
     UiCondition,
@@ -63,17 +82,20 @@ use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 use crate::state::AppState;
-use trader_analytics::backtest::BacktestConfig;
+use trader_analytics::backtest::{BacktestConfig, PortfolioBacktestConfig};
+use trader_strategy::strategies::common::PositionSizingConfig;
 use trader_strategy::StrategyRegistry;
 
 use engine::{
-    convert_multi_report_to_response, convert_report_to_response, generate_multi_sample_klines,
-    run_multi_strategy_backtest, run_strategy_backtest,
+    convert_multi_report_to_response, convert_portfolio_report_to_response,
+    convert_report_to_response, generate_multi_sample_klines, run_multi_strategy_backtest,
+    run_portfolio_strategy_backtest, run_strategy_backtest,
 };
 use loader::{
-    expand_strategy_symbols, generate_sample_klines, load_klines_from_db,
+    expand_strategy_symbols, generate_sample_klines, load_benchmark_klines, load_klines_from_db,
     load_multi_klines_from_db, merge_multi_klines,
 };
+use optimize::{get_optimization, list_optimizations, start_optimization};
 // ui_schema 함수들은 get_ui_schema_for_strategy로 대체됨
 
 // ==================== 핸들러 ====================
@@ -141,6 +163,26 @@ pub async fn list_backtest_strategies(State(state): State<Arc<AppState>>) -> imp
     })
 }
 
+/// 전략 params의 `position_sizing` 설정 검증.
+fn validate_position_sizing(
+    params: &Option<serde_json::Value>,
+) -> Result<(), (StatusCode, Json<BacktestApiError>)> {
+    let Some(params) = params else {
+        return Ok(());
+    };
+    PositionSizingConfig::from_strategy_config(params)
+        .map(|_| ())
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(BacktestApiError::new(
+                    "INVALID_POSITION_SIZING",
+                    format!("잘못된 position_sizing 설정: {}", e),
+                )),
+            )
+        })
+}
+
 /// 백테스트 실행.
 ///
 /// 주어진 설정으로 백테스트를 실행하고 결과를 반환합니다.
@@ -164,6 +206,7 @@ pub async fn run_backtest(
         "백테스트 실행 요청: strategy={}, symbol={}",
         request.strategy_id, request.symbol
     );
+    validate_position_sizing(&request.parameters)?;
 
     // 날짜 파싱 검증
     let start_date = NaiveDate::parse_from_str(&request.start_date, "%Y-%m-%d").map_err(|_| {
@@ -274,9 +317,18 @@ pub async fn run_backtest(
         }
 
         // 백테스트 설정
-        let config = BacktestConfig::new(request.initial_capital)
+        let mut config = BacktestConfig::new(request.initial_capital)
             .with_commission_rate(commission_rate)
             .with_slippage_rate(slippage_rate);
+        if let Some(model) = request.slippage_model.clone() {
+            config = config.with_slippage_model(model);
+        }
+        if let Some(benchmark) = &request.benchmark {
+            let klines =
+                load_benchmark_klines(state.db_pool.as_ref(), benchmark, start_date, end_date)
+                    .await;
+            config = config.with_benchmark(benchmark, klines);
+        }
 
         // 모든 전략은 동일한 run_strategy_backtest 함수로 처리 (하드코딩 방지)
         // 병합된 캔들 데이터를 전달하여 전략이 필요한 심볼 데이터를 자체적으로 처리
@@ -285,6 +337,7 @@ pub async fn run_backtest(
             config,
             &merged_klines,
             &request.parameters,
+            request.execution_mode,
         )
         .await
         .map_err(|e| {
@@ -334,19 +387,33 @@ pub async fn run_backtest(
     };
 
     // 백테스트 설정
-    let config = BacktestConfig::new(request.initial_capital)
+    let mut config = BacktestConfig::new(request.initial_capital)
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate);
+    if let Some(model) = request.slippage_model.clone() {
+        config = config.with_slippage_model(model);
+    }
+    if let Some(benchmark) = &request.benchmark {
+        let klines =
+            load_benchmark_klines(state.db_pool.as_ref(), benchmark, start_date, end_date).await;
+        config = config.with_benchmark(benchmark, klines);
+    }
 
     // 전략별 백테스트 실행
-    let report = run_strategy_backtest(&request.strategy_id, config, &klines, &request.parameters)
-        .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(BacktestApiError::new("BACKTEST_ERROR", e.to_string())),
-            )
-        })?;
+    let report = run_strategy_backtest(
+        &request.strategy_id,
+        config,
+        &klines,
+        &request.parameters,
+        request.execution_mode,
+    )
+    .await
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(BacktestApiError::new("BACKTEST_ERROR", e.to_string())),
+        )
+    })?;
 
     // BacktestReport를 API 응답으로 변환
     let response = convert_report_to_response(
@@ -417,6 +484,7 @@ pub async fn run_multi_backtest(
         "다중 자산 백테스트 실행 요청: strategy={}, symbols={:?}",
         request.strategy_id, request.symbols
     );
+    validate_position_sizing(&request.parameters)?;
 
     // 날짜 파싱 검증
     let start_date = NaiveDate::parse_from_str(&request.start_date, "%Y-%m-%d").map_err(|_| {
@@ -556,9 +624,14 @@ pub async fn run_multi_backtest(
     }
 
     // 백테스트 설정
-    let config = BacktestConfig::new(request.initial_capital)
+    let mut config = BacktestConfig::new(request.initial_capital)
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate);
+    if let Some(benchmark) = &request.benchmark {
+        let klines =
+            load_benchmark_klines(state.db_pool.as_ref(), benchmark, start_date, end_date).await;
+        config = config.with_benchmark(benchmark, klines);
+    }
 
     // 전략별 백테스트 실행 (다중 심볼 지원)
     let report = run_multi_strategy_backtest(
@@ -594,6 +667,218 @@ pub async fn run_multi_backtest(
     Ok(Json(response))
 }
 
+/// 포트폴리오(다중 전략) 백테스트 실행.
+///
+/// 여러 전략을 하나의 계좌에서 함께 실행합니다. 모든 전략이 현금 잔고와
+/// 최대 포지션 수를 공유하며, 결과에는 전략별 성과와 전략 간 수익률 상관관계가 포함됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/backtest/run-portfolio",
+    tag = "backtest",
+    request_body = BacktestPortfolioRequest,
+    responses(
+        (status = 200, description = "포트폴리오 백테스트 성공", body = BacktestPortfolioResponse),
+        (status = 400, description = "잘못된 요청", body = BacktestApiError),
+        (status = 500, description = "서버 오류", body = BacktestApiError)
+    )
+)]
+pub async fn run_portfolio_backtest(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<BacktestPortfolioRequest>,
+) -> Result<Json<BacktestPortfolioResponse>, (StatusCode, Json<BacktestApiError>)> {
+    use validator::Validate;
+
+    if let Err(errors) = request.validate() {
+        let message = errors
+            .field_errors()
+            .iter()
+            .flat_map(|(field, errors)| {
+                errors.iter().map(move |e| {
+                    e.message
+                        .as_ref()
+                        .map(|m| m.to_string())
+                        .unwrap_or_else(|| format!("{}: 유효하지 않은 값", field))
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        // 중첩된 전략 항목 오류는 field_errors에 나타나지 않음
+        let message = if message.is_empty() {
+            errors.to_string()
+        } else {
+            message
+        };
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new("VALIDATION_ERROR", message)),
+        ));
+    }
+
+    info!(
+        "포트폴리오 백테스트 실행 요청: strategies={:?}, allocation={:?}",
+        request
+            .strategies
+            .iter()
+            .map(|s| s.sleeve_name())
+            .collect::<Vec<_>>(),
+        request.allocation
+    );
+
+    let start_date = NaiveDate::parse_from_str(&request.start_date, "%Y-%m-%d").map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_DATE",
+                format!("잘못된 시작 날짜 형식: {}", request.start_date),
+            )),
+        )
+    })?;
+
+    let end_date = NaiveDate::parse_from_str(&request.end_date, "%Y-%m-%d").map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_DATE",
+                format!("잘못된 종료 날짜 형식: {}", request.end_date),
+            )),
+        )
+    })?;
+
+    if end_date <= start_date {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_DATE_RANGE",
+                "종료 날짜는 시작 날짜보다 이후여야 합니다",
+            )),
+        ));
+    }
+
+    // 결과에서 전략을 구분할 수 있도록 이름 중복 금지
+    let mut names = std::collections::HashSet::new();
+    for item in &request.strategies {
+        if !names.insert(item.sleeve_name()) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(BacktestApiError::new(
+                    "DUPLICATE_STRATEGY",
+                    format!(
+                        "전략 이름이 중복됩니다: {} (같은 전략을 여러 번 넣으려면 name을 지정하세요)",
+                        item.sleeve_name()
+                    ),
+                )),
+            ));
+        }
+        if item.weight.is_some_and(|w| w < Decimal::ZERO) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(BacktestApiError::new(
+                    "INVALID_WEIGHT",
+                    format!("가중치는 0 이상이어야 합니다: {}", item.sleeve_name()),
+                )),
+            ));
+        }
+    }
+
+    // 모든 전략이 사용하는 심볼의 합집합 로드
+    let mut expanded_symbols: Vec<String> = request
+        .strategies
+        .iter()
+        .flat_map(|item| expand_strategy_symbols(&item.strategy_id, &item.symbols))
+        .collect();
+    expanded_symbols.sort();
+    expanded_symbols.dedup();
+
+    let multi_klines = if let Some(pool) = &state.db_pool {
+        match load_multi_klines_from_db(pool, &expanded_symbols, start_date, end_date).await {
+            Ok(data) if !data.is_empty() => {
+                info!("DB에서 {} 심볼의 데이터 로드 완료", data.len());
+                data
+            }
+            Ok(_) => {
+                warn!("DB에 데이터가 없어 샘플 데이터로 백테스트 실행");
+                generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
+            }
+            Err(e) => {
+                warn!("DB 로드 실패, 샘플 데이터 사용: {}", e);
+                generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
+            }
+        }
+    } else {
+        debug!("DB 연결 없음, 샘플 데이터로 백테스트 실행");
+        generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
+    };
+
+    let merged_klines = merge_multi_klines(&multi_klines);
+    if merged_klines.is_empty() {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(BacktestApiError::new(
+                "NO_DATA",
+                "백테스트를 위한 데이터가 없습니다",
+            )),
+        ));
+    }
+
+    let commission_rate = request.commission_rate.unwrap_or(Decimal::new(1, 3));
+    let slippage_rate = request.slippage_rate.unwrap_or(Decimal::new(5, 4));
+
+    let mut backtest_config = BacktestConfig::new(request.initial_capital)
+        .with_commission_rate(commission_rate)
+        .with_slippage_rate(slippage_rate);
+    if let Some(max_positions) = request.max_positions {
+        backtest_config = backtest_config.with_max_positions(max_positions);
+    }
+    if let Some(benchmark) = &request.benchmark {
+        let klines =
+            load_benchmark_klines(state.db_pool.as_ref(), benchmark, start_date, end_date).await;
+        backtest_config = backtest_config.with_benchmark(benchmark, klines);
+    }
+
+    let mut config =
+        PortfolioBacktestConfig::new(backtest_config).with_allocation(request.allocation);
+    if let Some(bars) = request.rebalance_bars {
+        config = config.with_rebalance_bars(bars);
+    }
+    if let Some(bars) = request.lookback_bars {
+        config = config.with_lookback_bars(bars);
+    }
+    if let Some(weight) = request.min_weight {
+        config = config.with_min_weight(weight);
+    }
+    config.validate().map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new("INVALID_CONFIG", e.to_string())),
+        )
+    })?;
+
+    let report =
+        run_portfolio_strategy_backtest(config, &request.strategies, &merged_klines, &multi_klines)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(BacktestApiError::new("BACKTEST_ERROR", e)),
+                )
+            })?;
+
+    let response = convert_portfolio_report_to_response(
+        &report,
+        &request.strategies,
+        &request.start_date,
+        &request.end_date,
+    );
+
+    info!(
+        "포트폴리오 백테스트 완료: total_return={:.2}%, strategies={}",
+        report.metrics.total_return_pct,
+        report.strategies.len()
+    );
+
+    Ok(Json(response))
+}
+
 // ==================== 라우터 ====================
 
 /// 백테스트 라우터 생성
@@ -607,6 +892,14 @@ pub fn backtest_router() -> Router<Arc<AppState>> {
         .route("/run-multi", post(run_multi_backtest))
         // 배치 백테스트 (병렬 실행)
         .route("/run-batch", post(run_batch_backtest))
+        // 포트폴리오 백테스트 (다중 전략, 공유 자본)
+        .route("/run-portfolio", post(run_portfolio_backtest))
+        // 파라미터 최적화 (비동기 작업)
+        .route(
+            "/optimize",
+            post(start_optimization).get(list_optimizations),
+        )
+        .route("/optimize/{job_id}", get(get_optimization))
     // 백테스트 결과 조회는 backtest_results_router에서 처리
 }
 
@@ -818,9 +1111,15 @@ async fn run_single_strategy_internal(
         .with_slippage_rate(slippage_rate);
 
     // 백테스트 실행
-    let report = run_strategy_backtest(strategy_id, config, &klines, params)
-        .await
-        .map_err(|e| e.to_string())?;
+    let report = run_strategy_backtest(
+        strategy_id,
+        config,
+        &klines,
+        params,
+        BacktestExecutionMode::Engine,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
 
     // 메트릭만 반환
     Ok(convert_report_to_metrics(&report))
@@ -880,7 +1179,13 @@ async fn run_multi_strategy_internal(
 fn convert_report_to_metrics(
     report: &trader_analytics::backtest::BacktestReport,
 ) -> BacktestMetricsResponse {
-    let metrics = &report.metrics;
+    convert_metrics(&report.metrics)
+}
+
+/// PerformanceMetrics를 API 응답 메트릭으로 변환.
+fn convert_metrics(
+    metrics: &trader_analytics::performance::PerformanceMetrics,
+) -> BacktestMetricsResponse {
     BacktestMetricsResponse {
         total_return_pct: metrics.total_return_pct,
         annualized_return_pct: metrics.annualized_return_pct,
@@ -921,6 +1226,7 @@ fn convert_core_schema_to_ui_schema(
                 trader_core::FieldType::Symbol => UiFieldType::SymbolPicker,
                 trader_core::FieldType::Symbols => UiFieldType::SymbolPicker,
                 trader_core::FieldType::MultiTimeframe => UiFieldType::Timeframe,
+                trader_core::FieldType::Rules => UiFieldType::Rules,
             };
 
             // options 변환 (Select 타입용)
@@ -1041,6 +1347,7 @@ mod tests {
         http::{Request, StatusCode},
     };
     use tower::ServiceExt;
+    use trader_core::Side;
 
     #[tokio::test]
     async fn test_list_backtest_strategies() {
@@ -1119,6 +1426,259 @@ mod tests {
         // 실제 DB 데이터에서는 trades가 생성됨
     }
 
+    #[tokio::test]
+    async fn test_run_backtest_with_slippage_model() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/run", post(run_backtest))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategy_id": "grid",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "initial_capital": 10000000,
+            "slippage_model": { "type": "volatility_based", "multiplier": 0.5 }
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/run")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: BacktestRunResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            result.config_summary.slippage_model.as_deref(),
+            Some("VolatilityBased")
+        );
+        // 체결(진입 매수, 청산 매도)마다 슬리피지가 기록됨.
+        // 한 번의 청산이 여러 lot을 닫을 수 있으므로 건수 대신 체결 시각과 수량으로 대조
+        assert!(!result.slippage_records.is_empty());
+        let traded_quantity: Decimal = result.trades.iter().map(|t| t.quantity).sum();
+        for side in [Side::Buy, Side::Sell] {
+            let records: Vec<_> = result
+                .slippage_records
+                .iter()
+                .filter(|record| record.side == side)
+                .collect();
+            let recorded_quantity: Decimal = records.iter().map(|record| record.quantity).sum();
+            assert_eq!(recorded_quantity, traded_quantity);
+            for record in records {
+                assert!(result.trades.iter().any(|trade| match side {
+                    Side::Buy => trade.entry_time == record.executed_at,
+                    Side::Sell => trade.exit_time == record.executed_at,
+                }));
+            }
+        }
+        assert!(result
+            .slippage_records
+            .iter()
+            .all(|record| record.model == "VolatilityBased"));
+    }
+
+    #[tokio::test]
+    async fn test_run_backtest_with_benchmark() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/run", post(run_backtest))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategy_id": "sma_crossover",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "initial_capital": 10000000,
+            "benchmark": "ETH/USDT"
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/run")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: BacktestRunResponse = serde_json::from_slice(&body).unwrap();
+
+        let benchmark = result.benchmark.expect("벤치마크 비교 결과");
+        assert_eq!(benchmark.symbol, "ETH/USDT");
+        assert!(benchmark.periods > 0);
+        assert!(!benchmark.equity_curve.is_empty());
+        assert!(benchmark.equity_curve.iter().all(|b| result
+            .equity_curve
+            .iter()
+            .any(|e| e.timestamp == b.timestamp)));
+    }
+
+    #[tokio::test]
+    async fn test_run_backtest_replay_mode() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/run", post(run_backtest))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategy_id": "sma_crossover",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "initial_capital": 10000000,
+            "execution_mode": "replay"
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/run")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: BacktestRunResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(result.strategy_id, "sma_crossover");
+        assert!(!result.equity_curve.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_optimize_requires_database() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/optimize", post(start_optimization))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategy_id": "grid",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-03-31",
+            "initial_capital": 10000000,
+            "search_parameters": ["spacing_pct"]
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/optimize")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_optimize_rejects_unknown_parameter() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/optimize", post(start_optimization))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategy_id": "grid",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-03-31",
+            "initial_capital": 10000000,
+            "search_parameters": ["not_a_field"]
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/optimize")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_optimize_rejects_zero_parallelism() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/optimize", post(start_optimization))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategy_id": "grid",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-03-31",
+            "initial_capital": 10000000,
+            "parallelism": 0
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/optimize")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_run_backtest_invalid_date() {
         use crate::state::create_test_state;
@@ -1198,6 +1758,46 @@ mod tests {
         assert_eq!(error.code, "INVALID_DATE_RANGE");
     }
 
+    #[tokio::test]
+    async fn test_run_backtest_invalid_position_sizing() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/run", post(run_backtest))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategy_id": "sma_crossover",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "initial_capital": 10000000,
+            "parameters": { "position_sizing": { "mode": "unknown" } }
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/run")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: BacktestApiError = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(error.code, "INVALID_POSITION_SIZING");
+    }
+
     #[tokio::test]
     async fn test_run_backtest_strategy_not_found() {
         use crate::state::create_test_state;
@@ -1265,4 +1865,99 @@ mod tests {
         assert_eq!(error.code, "TEST_ERROR");
         assert_eq!(error.message, "테스트 메시지");
     }
+
+    #[tokio::test]
+    async fn test_run_portfolio_backtest() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/run-portfolio", post(run_portfolio_backtest))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategies": [
+                { "strategy_id": "sma_crossover", "symbols": ["BTC/USDT"], "weight": 0.6 },
+                { "strategy_id": "rsi_mean_reversion", "symbols": ["ETH/USDT"], "weight": 0.4 }
+            ],
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "initial_capital": 10000000,
+            "max_positions": 2
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/run-portfolio")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: BacktestPortfolioResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.strategies.len(), 2);
+        assert_eq!(result.strategies[0].name, "sma_crossover");
+        assert!((result.strategies[0].initial_weight - 0.6).abs() < 1e-9);
+        assert_eq!(
+            result.strategies[1].initial_capital,
+            Decimal::from(4_000_000)
+        );
+        assert!(!result.equity_curve.is_empty());
+        assert!(!result.allocation_history.is_empty());
+
+        let correlation = result.correlation.expect("전략 2개면 상관관계 포함");
+        assert_eq!(correlation.strategies.len(), 2);
+        assert!((correlation.matrix[0][0] - 1.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_run_portfolio_backtest_rejects_duplicate_names() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/run-portfolio", post(run_portfolio_backtest))
+            .with_state(state);
+
+        let request_body = serde_json::json!({
+            "strategies": [
+                { "strategy_id": "sma_crossover", "symbols": ["BTC/USDT"] },
+                { "strategy_id": "sma_crossover", "symbols": ["ETH/USDT"] }
+            ],
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "initial_capital": 10000000
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/run-portfolio")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: BacktestApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "DUPLICATE_STRATEGY");
+    }
 }
@@ -0,0 +1,554 @@
+//! 파라미터 최적화 비동기 작업
+//!
+//! 전략 파라미터 격자/무작위 탐색을 백그라운드 작업으로 실행하고
+//! 진행 상황과 순위별 결과를 조회하는 핸들러를 제공합니다.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::state::AppState;
+use trader_analytics::backtest::{
+    BacktestConfig, OptimizationResult, ParameterOptimizer, ParameterSpace,
+};
+use trader_core::Kline;
+
+use super::convert_metrics;
+use super::loader::load_klines_from_db;
+use super::types::{
+    BacktestApiError, BacktestOptimizeRequest, OptimizeJob, OptimizeJobListResponse,
+    OptimizeJobStartResponse, OptimizeJobStatus, OptimizeRunItem,
+};
+
+/// 기본 최대 조합 수
+const DEFAULT_MAX_COMBINATIONS: usize = 1_000;
+
+/// 기본 병렬 실행 수
+const DEFAULT_PARALLELISM: usize = 4;
+
+/// 최대 병렬 실행 수 (서버 CPU 수가 더 적으면 CPU 수로 제한)
+const MAX_PARALLELISM: usize = 16;
+
+/// 보관할 최대 작업 수 (초과 시 오래된 완료 작업부터 제거)
+const MAX_STORED_JOBS: usize = 50;
+
+/// 동시에 대기/실행 중일 수 있는 최대 작업 수 (초과 시 새 작업 거부)
+const MAX_RUNNING_JOBS: usize = 4;
+
+// 인메모리 작업 저장소 (서버 재시작 시 초기화)
+lazy_static::lazy_static! {
+    static ref OPTIMIZE_JOBS: RwLock<HashMap<String, OptimizeJob>> = RwLock::new(HashMap::new());
+}
+
+type ApiError = (StatusCode, Json<BacktestApiError>);
+
+fn bad_request(code: &str, message: impl Into<String>) -> ApiError {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(BacktestApiError::new(code, message)),
+    )
+}
+
+/// 파라미터 최적화 작업 시작.
+///
+/// 요청을 검증하고 조합 수를 계산한 뒤 백그라운드에서 최적화를 실행합니다.
+/// 응답의 `job_id`로 진행 상황과 결과를 조회합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/backtest/optimize",
+    tag = "backtest",
+    request_body = BacktestOptimizeRequest,
+    responses(
+        (status = 202, description = "최적화 작업 시작", body = OptimizeJobStartResponse),
+        (status = 400, description = "잘못된 요청", body = BacktestApiError),
+        (status = 404, description = "전략을 찾을 수 없음", body = BacktestApiError),
+        (status = 429, description = "실행 중인 작업 수 초과", body = BacktestApiError),
+        (status = 503, description = "데이터베이스 연결 없음", body = BacktestApiError)
+    )
+)]
+pub async fn start_optimization(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<BacktestOptimizeRequest>,
+) -> Result<(StatusCode, Json<OptimizeJobStartResponse>), ApiError> {
+    // 입력 유효성 검사
+    if let Err(errors) = request.validate() {
+        let message = errors
+            .field_errors()
+            .iter()
+            .flat_map(|(field, errors)| {
+                errors.iter().map(move |e| {
+                    e.message
+                        .as_ref()
+                        .map(|m| m.to_string())
+                        .unwrap_or_else(|| format!("{}: 유효하지 않은 값", field))
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(bad_request("VALIDATION_ERROR", message));
+    }
+
+    let start_date = NaiveDate::parse_from_str(&request.start_date, "%Y-%m-%d").map_err(|_| {
+        bad_request(
+            "INVALID_DATE",
+            format!("잘못된 시작 날짜 형식: {}", request.start_date),
+        )
+    })?;
+    let end_date = NaiveDate::parse_from_str(&request.end_date, "%Y-%m-%d").map_err(|_| {
+        bad_request(
+            "INVALID_DATE",
+            format!("잘못된 종료 날짜 형식: {}", request.end_date),
+        )
+    })?;
+    if end_date <= start_date {
+        return Err(bad_request(
+            "INVALID_DATE_RANGE",
+            "종료 날짜는 시작 날짜보다 이후여야 합니다",
+        ));
+    }
+
+    // 탐색 공간 구성 (전략 SDUI 스키마 기반)
+    let space = ParameterSpace::for_strategy(&request.strategy_id).map_err(|e| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(BacktestApiError::new("STRATEGY_NOT_FOUND", e.to_string())),
+        )
+    })?;
+    let optimizer =
+        build_optimizer(&request, space).map_err(|e| bad_request("INVALID_PARAMETER_SPACE", e))?;
+    let total = optimizer
+        .combinations()
+        .map_err(|e| bad_request("INVALID_PARAMETER_SPACE", e.to_string()))?
+        .len();
+
+    // 샘플 데이터로 최적화한 결과는 실제 성과로 오인될 수 있으므로 DB 없이는 실행하지 않음
+    if state.db_pool.is_none() {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(BacktestApiError::new(
+                "DATABASE_UNAVAILABLE",
+                "데이터베이스 연결이 없어 최적화를 실행할 수 없습니다",
+            )),
+        ));
+    }
+
+    let job = new_job(&request, &optimizer, total);
+    let job_id = job.job_id.clone();
+
+    {
+        let mut jobs = OPTIMIZE_JOBS.write().await;
+        check_running_limit(&jobs)?;
+        evict_finished_jobs(&mut jobs);
+        jobs.insert(job_id.clone(), job);
+    }
+
+    info!(
+        job_id = %job_id,
+        strategy_id = %request.strategy_id,
+        symbol = %request.symbol,
+        combinations = total,
+        "파라미터 최적화 작업 시작"
+    );
+
+    let job_id_clone = job_id.clone();
+    tokio::spawn(async move {
+        run_optimization_job(
+            state,
+            job_id_clone,
+            request,
+            optimizer,
+            start_date,
+            end_date,
+        )
+        .await;
+    });
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(OptimizeJobStartResponse {
+            job_id,
+            status: OptimizeJobStatus::Pending,
+            total_combinations: total,
+        }),
+    ))
+}
+
+/// 파라미터 최적화 작업 조회.
+#[utoipa::path(
+    get,
+    path = "/api/v1/backtest/optimize/{job_id}",
+    tag = "backtest",
+    params(
+        ("job_id" = String, Path, description = "최적화 작업 ID")
+    ),
+    responses(
+        (status = 200, description = "작업 상태 및 결과", body = OptimizeJob),
+        (status = 404, description = "작업을 찾을 수 없음", body = BacktestApiError)
+    )
+)]
+pub async fn get_optimization(Path(job_id): Path<String>) -> Result<Json<OptimizeJob>, ApiError> {
+    let jobs = OPTIMIZE_JOBS.read().await;
+    jobs.get(&job_id).cloned().map(Json).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(BacktestApiError::new(
+                "JOB_NOT_FOUND",
+                format!("최적화 작업을 찾을 수 없습니다: {}", job_id),
+            )),
+        )
+    })
+}
+
+/// 파라미터 최적화 작업 목록 (결과 제외, 최신순).
+#[utoipa::path(
+    get,
+    path = "/api/v1/backtest/optimize",
+    tag = "backtest",
+    responses(
+        (status = 200, description = "작업 목록", body = OptimizeJobListResponse)
+    )
+)]
+pub async fn list_optimizations() -> Json<OptimizeJobListResponse> {
+    let jobs = OPTIMIZE_JOBS.read().await;
+    let mut list: Vec<OptimizeJob> = jobs
+        .values()
+        .map(|job| OptimizeJob {
+            results: Vec::new(),
+            ..job.clone()
+        })
+        .collect();
+    list.sort_by_key(|job| std::cmp::Reverse(job.created_at));
+
+    let total = list.len();
+    Json(OptimizeJobListResponse { jobs: list, total })
+}
+
+/// 대기 상태의 새 작업을 만듭니다.
+fn new_job(
+    request: &BacktestOptimizeRequest,
+    optimizer: &ParameterOptimizer,
+    total: usize,
+) -> OptimizeJob {
+    OptimizeJob {
+        job_id: Uuid::new_v4().to_string(),
+        strategy_id: request.strategy_id.clone(),
+        symbol: request.symbol.clone(),
+        objective: request.objective,
+        method: request.method.clone(),
+        space: optimizer.space().clone(),
+        status: OptimizeJobStatus::Pending,
+        completed: 0,
+        total,
+        created_at: Utc::now(),
+        finished_at: None,
+        error: None,
+        results: Vec::new(),
+        failed: 0,
+    }
+}
+
+/// 대기/실행 중인 작업이 [`MAX_RUNNING_JOBS`]에 도달했으면 거부합니다.
+fn check_running_limit(jobs: &HashMap<String, OptimizeJob>) -> Result<(), ApiError> {
+    let running = jobs
+        .values()
+        .filter(|j| {
+            matches!(
+                j.status,
+                OptimizeJobStatus::Pending | OptimizeJobStatus::Running
+            )
+        })
+        .count();
+    if running >= MAX_RUNNING_JOBS {
+        return Err((
+            StatusCode::TOO_MANY_REQUESTS,
+            Json(BacktestApiError::new(
+                "TOO_MANY_JOBS",
+                format!(
+                    "실행 중인 최적화 작업이 너무 많습니다 (최대 {}개)",
+                    MAX_RUNNING_JOBS
+                ),
+            )),
+        ));
+    }
+    Ok(())
+}
+
+/// 요청으로 최적화기를 구성합니다.
+fn build_optimizer(
+    request: &BacktestOptimizeRequest,
+    space: ParameterSpace,
+) -> Result<ParameterOptimizer, String> {
+    let space = space
+        .customize(&request.search_parameters, request.ranges.clone())
+        .map_err(|e| e.to_string())?;
+
+    let commission_rate = request.commission_rate.unwrap_or(Decimal::new(1, 3)); // 0.1%
+    let slippage_rate = request.slippage_rate.unwrap_or(Decimal::new(5, 4)); // 0.05%
+    let mut config = BacktestConfig::new(request.initial_capital)
+        .with_commission_rate(commission_rate)
+        .with_slippage_rate(slippage_rate);
+    if let Some(model) = request.slippage_model.clone() {
+        config = config.with_slippage_model(model);
+    }
+
+    let mut base_params = request
+        .parameters
+        .clone()
+        .unwrap_or_else(|| serde_json::json!({}));
+    if let Some(obj) = base_params.as_object_mut() {
+        obj.entry("ticker")
+            .or_insert_with(|| serde_json::json!(request.symbol));
+        obj.entry("amount")
+            .or_insert_with(|| serde_json::json!("100000"));
+    }
+
+    Ok(ParameterOptimizer::new(&request.strategy_id, config, space)
+        .with_base_params(base_params)
+        .with_objective(request.objective)
+        .with_method(request.method.clone())
+        .with_max_combinations(request.max_combinations.unwrap_or(DEFAULT_MAX_COMBINATIONS))
+        .with_parallelism(effective_parallelism(request.parallelism)))
+}
+
+/// 요청한 병렬 실행 수를 서버 CPU 수와 [`MAX_PARALLELISM`] 이내로 제한합니다.
+///
+/// 0은 요청 검증에서 거부되며, 여기서는 최소 1로 맞춥니다.
+fn effective_parallelism(requested: Option<usize>) -> usize {
+    let cpus = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    requested
+        .unwrap_or(DEFAULT_PARALLELISM)
+        .clamp(1, MAX_PARALLELISM.min(cpus))
+}
+
+/// 백그라운드 최적화 실행.
+async fn run_optimization_job(
+    state: Arc<AppState>,
+    job_id: String,
+    request: BacktestOptimizeRequest,
+    optimizer: ParameterOptimizer,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) {
+    update_job(&job_id, |job| job.status = OptimizeJobStatus::Running).await;
+
+    match load_klines(&state, &request.symbol, start_date, end_date).await {
+        Ok(klines) => execute_optimization(&job_id, optimizer, &klines).await,
+        Err(e) => {
+            warn!(job_id = %job_id, error = %e, "최적화 데이터 로드 실패");
+            fail_job(&job_id, e).await;
+        }
+    }
+}
+
+/// 로드한 캔들로 최적화를 실행하고 결과를 작업에 반영합니다.
+async fn execute_optimization(job_id: &str, optimizer: ParameterOptimizer, klines: &[Kline]) {
+    // 진행 상황은 동기 콜백에서 전달되므로 채널로 받아 저장소에 반영
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel::<usize>();
+    let progress_job_id = job_id.to_string();
+    let progress_task = tokio::spawn(async move {
+        while let Some(done) = progress_rx.recv().await {
+            update_job(&progress_job_id, |job| job.completed = done).await;
+        }
+    });
+
+    let outcome = optimizer
+        .run_with_progress(klines, move |done, _| {
+            let _ = progress_tx.send(done);
+        })
+        .await;
+    let _ = progress_task.await;
+
+    match outcome {
+        Ok(result) => {
+            info!(
+                job_id = %job_id,
+                evaluated = result.evaluated(),
+                failed = result.failures.len(),
+                "파라미터 최적화 작업 완료"
+            );
+            update_job(job_id, |job| apply_result(job, result)).await;
+        }
+        Err(e) => {
+            warn!(job_id = %job_id, error = %e, "파라미터 최적화 작업 실패");
+            fail_job(job_id, e.to_string()).await;
+        }
+    }
+}
+
+/// 작업을 실패 상태로 종료합니다.
+async fn fail_job(job_id: &str, error: String) {
+    update_job(job_id, |job| {
+        job.status = OptimizeJobStatus::Failed;
+        job.error = Some(error);
+        job.finished_at = Some(Utc::now());
+    })
+    .await;
+}
+
+/// 최적화 결과를 작업에 반영합니다.
+fn apply_result(job: &mut OptimizeJob, result: OptimizationResult) {
+    job.status = OptimizeJobStatus::Completed;
+    job.completed = result.evaluated();
+    job.failed = result.failures.len();
+    job.finished_at = Some(Utc::now());
+    job.results = result
+        .runs
+        .into_iter()
+        .map(|run| OptimizeRunItem {
+            rank: run.rank,
+            params: run.params,
+            score: run.score,
+            metrics: convert_metrics(&run.metrics),
+        })
+        .collect();
+}
+
+/// 캔들 데이터 로드 (DB 연결이 없거나 데이터가 없으면 오류)
+async fn load_klines(
+    state: &Arc<AppState>,
+    symbol: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<Kline>, String> {
+    let pool = state
+        .db_pool
+        .as_ref()
+        .ok_or_else(|| "데이터베이스 연결이 없습니다".to_string())?;
+    let klines = load_klines_from_db(pool, symbol, start_date, end_date)
+        .await
+        .map_err(|e| format!("캔들 데이터 로드 실패: {}", e))?;
+    if klines.is_empty() {
+        return Err(format!(
+            "{} ~ {} 기간의 {} 캔들 데이터가 없습니다",
+            start_date, end_date, symbol
+        ));
+    }
+    Ok(klines)
+}
+
+async fn update_job(job_id: &str, f: impl FnOnce(&mut OptimizeJob)) {
+    let mut jobs = OPTIMIZE_JOBS.write().await;
+    if let Some(job) = jobs.get_mut(job_id) {
+        f(job);
+    }
+}
+
+/// 보관 한도를 넘으면 오래된 완료/실패 작업부터 제거합니다.
+fn evict_finished_jobs(jobs: &mut HashMap<String, OptimizeJob>) {
+    while jobs.len() >= MAX_STORED_JOBS {
+        let oldest = jobs
+            .values()
+            .filter(|j| {
+                matches!(
+                    j.status,
+                    OptimizeJobStatus::Completed | OptimizeJobStatus::Failed
+                )
+            })
+            .min_by_key(|j| j.created_at)
+            .map(|j| j.job_id.clone());
+        match oldest {
+            Some(id) => {
+                jobs.remove(&id);
+            }
+            None => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_parallelism_is_bounded() {
+        let cpus = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let limit = MAX_PARALLELISM.min(cpus);
+
+        assert_eq!(effective_parallelism(Some(1)), 1);
+        assert_eq!(effective_parallelism(Some(0)), 1);
+        assert_eq!(effective_parallelism(Some(usize::MAX)), limit);
+        assert_eq!(effective_parallelism(None), DEFAULT_PARALLELISM.min(limit));
+    }
+
+    #[tokio::test]
+    async fn test_execute_optimization_completes_job() {
+        use super::super::loader::generate_sample_klines;
+
+        let request: BacktestOptimizeRequest = serde_json::from_value(serde_json::json!({
+            "strategy_id": "grid",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-03-31",
+            "initial_capital": 10000000,
+            "search_parameters": ["spacing_pct"],
+            "ranges": [
+                { "name": "spacing_pct", "min": 1, "max": 2, "step": 1 },
+                { "name": "levels", "min": 3, "max": 5, "step": 2, "integer": true }
+            ],
+            "objective": "calmar",
+            "parallelism": 2
+        }))
+        .unwrap();
+        let space = ParameterSpace::for_strategy(&request.strategy_id).unwrap();
+        let optimizer = build_optimizer(&request, space).unwrap();
+        let total = optimizer.combinations().unwrap().len();
+        assert_eq!(total, 4);
+
+        let job = new_job(&request, &optimizer, total);
+        let job_id = job.job_id.clone();
+        OPTIMIZE_JOBS.write().await.insert(job_id.clone(), job);
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+        let klines = generate_sample_klines(&request.symbol, start, end);
+        execute_optimization(&job_id, optimizer, &klines).await;
+
+        let job = OPTIMIZE_JOBS.write().await.remove(&job_id).unwrap();
+        assert_eq!(job.status, OptimizeJobStatus::Completed, "{:?}", job.error);
+        assert_eq!(job.completed, 4);
+        assert_eq!(job.results.len() + job.failed, 4);
+        assert_eq!(job.results[0].rank, 1);
+    }
+
+    #[test]
+    fn test_running_job_limit() {
+        let request: BacktestOptimizeRequest = serde_json::from_value(serde_json::json!({
+            "strategy_id": "grid",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-03-31",
+            "initial_capital": 10000000,
+            "search_parameters": ["spacing_pct"]
+        }))
+        .unwrap();
+        let space = ParameterSpace::for_strategy(&request.strategy_id).unwrap();
+        let optimizer = build_optimizer(&request, space).unwrap();
+
+        let mut jobs = HashMap::new();
+        for _ in 0..MAX_RUNNING_JOBS {
+            let job = new_job(&request, &optimizer, 1);
+            jobs.insert(job.job_id.clone(), job);
+        }
+        let (status, _) = check_running_limit(&jobs).unwrap_err();
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+
+        // 완료된 작업은 한도에 포함되지 않음
+        let finished = jobs.keys().next().cloned().unwrap();
+        jobs.get_mut(&finished).unwrap().status = OptimizeJobStatus::Completed;
+        assert!(check_running_limit(&jobs).is_ok());
+    }
+}
@@ -7,6 +7,10 @@ use rust_decimal::prelude::FromStr;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use trader_analytics::backtest::{
+    AllocationMethod, OptimizationObjective, ParameterRange, ParameterSpace, SearchMethod,
+    SlippageModel,
+};
 use trader_core::{Side, Timeframe, TradeInfo};
 use ts_rs::TS;
 use utoipa::ToSchema;
@@ -95,6 +99,8 @@ pub enum UiFieldType {
     Date,
     /// 시간대 선택
     Timeframe,
+    /// 조건 규칙 빌더 (규칙 기반 전략용)
+    Rules,
 }
 
 /// 유효성 검사 규칙
@@ -413,6 +419,17 @@ pub struct BacktestStrategiesResponse {
     pub total: usize,
 }
 
+/// 백테스트 실행 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BacktestExecutionMode {
+    /// 백테스트 엔진의 체결 모델 (FillPolicy, SlippageModel 적용)
+    #[default]
+    Engine,
+    /// SimulatedExchange 스트림을 실거래 경로(StrategyEngine → RiskManager → OrderExecutor)로 재생
+    Replay,
+}
+
 /// 백테스트 실행 요청
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct BacktestRunRequest {
@@ -439,6 +456,11 @@ pub struct BacktestRunRequest {
     #[serde(default)]
     #[validate(custom(function = "validate_slippage_rate"))]
     pub slippage_rate: Option<Decimal>,
+    /// 슬리피지 모델 (선택, 지정 시 slippage_rate 대신 사용)
+    ///
+    /// 예: `{"type": "volatility_based", "multiplier": 0.5}`
+    #[serde(default)]
+    pub slippage_model: Option<SlippageModel>,
     /// 전략 파라미터 (선택)
     #[serde(default)]
     pub parameters: Option<serde_json::Value>,
@@ -446,6 +468,17 @@ pub struct BacktestRunRequest {
     /// 지정 시 secondary 타임프레임 데이터도 로드하여 전략에 전달
     #[serde(default)]
     pub multi_timeframe_config: Option<MultiTimeframeRequest>,
+    /// 벤치마크 심볼 (선택, 예: "069500", "SPY", "BTC/USDT")
+    ///
+    /// 지정 시 알파/베타 등 벤치마크 대비 지표와 벤치마크 자산 곡선을 함께 반환합니다.
+    #[serde(default)]
+    #[validate(length(min = 1, max = 20, message = "벤치마크 심볼은 1-20자여야 합니다"))]
+    pub benchmark: Option<String>,
+    /// 실행 방식 (선택, 기본: "engine")
+    ///
+    /// "replay"는 브라켓 주문과 리스크 거부까지 실거래 코드로 처리합니다.
+    #[serde(default)]
+    pub execution_mode: BacktestExecutionMode,
 }
 
 /// 다중 자산 백테스트 실행 요청
@@ -477,6 +510,12 @@ pub struct BacktestMultiRunRequest {
     /// 전략 파라미터 (선택)
     #[serde(default)]
     pub parameters: Option<serde_json::Value>,
+    /// 벤치마크 심볼 (선택, 예: "069500", "SPY", "BTC/USDT")
+    ///
+    /// 지정 시 알파/베타 등 벤치마크 대비 지표와 벤치마크 자산 곡선을 함께 반환합니다.
+    #[serde(default)]
+    #[validate(length(min = 1, max = 20, message = "벤치마크 심볼은 1-20자여야 합니다"))]
+    pub benchmark: Option<String>,
 }
 
 /// 다중 자산 백테스트 실행 응답
@@ -504,6 +543,9 @@ pub struct BacktestMultiRunResponse {
     pub config_summary: BacktestConfigSummary,
     /// 심볼별 데이터 포인트 수
     pub data_points_by_symbol: HashMap<String, usize>,
+    /// 벤치마크 비교 결과 (벤치마크 지정 시)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkComparisonResponse>,
 }
 
 /// 백테스트 성과 지표 응답
@@ -611,6 +653,29 @@ impl TradeInfo for TradeHistoryItem {
     }
 }
 
+/// 체결별 슬리피지 항목
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SlippageRecordItem {
+    /// 심볼
+    pub symbol: String,
+    /// 체결 방향
+    pub side: Side,
+    /// 체결 수량
+    pub quantity: Decimal,
+    /// 체결 시각
+    pub executed_at: DateTime<Utc>,
+    /// 적용된 슬리피지 모델 이름
+    pub model: String,
+    /// 기준 가격
+    pub base_price: Decimal,
+    /// 슬리피지 적용 후 실행 가격
+    pub execution_price: Decimal,
+    /// 적용된 슬리피지 비율
+    pub slippage_rate: Decimal,
+    /// 슬리피지 금액 (단위 가격 기준)
+    pub slippage_amount: Decimal,
+}
+
 /// 백테스트 실행 응답
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct BacktestRunResponse {
@@ -632,8 +697,46 @@ pub struct BacktestRunResponse {
     pub equity_curve: Vec<EquityCurvePoint>,
     /// 거래 내역
     pub trades: Vec<TradeHistoryItem>,
+    /// 체결별 슬리피지 내역 (시간순)
+    #[serde(default)]
+    pub slippage_records: Vec<SlippageRecordItem>,
     /// 백테스트 설정 요약
     pub config_summary: BacktestConfigSummary,
+    /// 벤치마크 비교 결과 (벤치마크 지정 시)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkComparisonResponse>,
+}
+
+/// 벤치마크 비교 결과
+///
+/// 벤치마크 자산 곡선은 전략 `equity_curve`와 같은 시점으로 정렬되어 있어
+/// 차트에서 겹쳐 표시할 수 있습니다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BenchmarkComparisonResponse {
+    /// 벤치마크 심볼
+    pub symbol: String,
+    /// 벤치마크 총 수익률 (%)
+    pub benchmark_return_pct: Decimal,
+    /// 초과 수익률 (%)
+    pub excess_return_pct: Decimal,
+    /// 젠센 알파 (연율화, %)
+    pub alpha_pct: Decimal,
+    /// 베타
+    pub beta: Decimal,
+    /// 추적 오차 (연율화, %)
+    pub tracking_error_pct: Decimal,
+    /// 정보 비율
+    pub information_ratio: Decimal,
+    /// 상승 포착률 (%)
+    pub up_capture_pct: Decimal,
+    /// 하락 포착률 (%)
+    pub down_capture_pct: Decimal,
+    /// 수익률 상관계수
+    pub correlation: Decimal,
+    /// 계산에 사용된 수익률 개수
+    pub periods: usize,
+    /// 벤치마크 자산 곡선 (매수 후 보유, 초기 자본 기준)
+    pub equity_curve: Vec<EquityCurvePoint>,
 }
 
 /// 백테스트 설정 요약
@@ -645,6 +748,9 @@ pub struct BacktestConfigSummary {
     pub commission_rate: Decimal,
     /// 슬리피지율
     pub slippage_rate: Decimal,
+    /// 슬리피지 모델 이름 (미지정 시 고정 비율)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slippage_model: Option<String>,
     /// 총 수수료
     pub total_commission: Decimal,
     /// 총 슬리피지 비용
@@ -754,3 +860,312 @@ pub struct BatchBacktestResponse {
     /// 각 전략별 결과
     pub results: Vec<BatchBacktestResultItem>,
 }
+
+// ==================== 포트폴리오 백테스트 (다중 전략) ====================
+
+/// 포트폴리오 백테스트 전략 항목.
+///
+/// 공유 계좌에서 함께 실행될 전략 하나와 그 자본 배분을 정의합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PortfolioStrategyItem {
+    /// 전략 ID
+    #[validate(length(min = 1, max = 100, message = "전략 ID는 1-100자여야 합니다"))]
+    pub strategy_id: String,
+    /// 슬리브 이름 (선택, 기본: 전략 ID)
+    ///
+    /// 같은 전략을 다른 파라미터로 여러 번 넣을 때 구분용으로 사용합니다.
+    #[serde(default)]
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    /// 전략이 거래할 심볼 목록
+    #[validate(length(min = 1, max = 50, message = "심볼은 1-50개 사이여야 합니다"))]
+    pub symbols: Vec<String>,
+    /// 자본 배분 가중치 (선택, 기본: 1, 전체 합으로 정규화)
+    #[serde(default)]
+    pub weight: Option<Decimal>,
+    /// 전략 파라미터 (선택)
+    #[serde(default)]
+    pub parameters: Option<serde_json::Value>,
+}
+
+impl PortfolioStrategyItem {
+    /// 결과에서 전략을 구분하는 이름.
+    pub fn sleeve_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.strategy_id)
+    }
+}
+
+/// 포트폴리오 백테스트 요청.
+///
+/// 여러 전략이 하나의 현금 잔고와 최대 포지션 수를 공유합니다.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BacktestPortfolioRequest {
+    /// 함께 실행할 전략 목록 (최대 10개)
+    #[validate(length(min = 1, max = 10, message = "전략은 1-10개 사이여야 합니다"))]
+    #[validate(nested)]
+    pub strategies: Vec<PortfolioStrategyItem>,
+    /// 시작 날짜 (YYYY-MM-DD)
+    #[validate(custom(function = "validate_date_format"))]
+    pub start_date: String,
+    /// 종료 날짜 (YYYY-MM-DD)
+    #[validate(custom(function = "validate_date_format"))]
+    pub end_date: String,
+    /// 포트폴리오 전체 초기 자본금 (100 ~ 10억)
+    #[validate(custom(function = "validate_initial_capital"))]
+    pub initial_capital: Decimal,
+    /// 수수료율 (선택, 기본: 0.001, 최대: 10%)
+    #[serde(default)]
+    #[validate(custom(function = "validate_commission_rate"))]
+    pub commission_rate: Option<Decimal>,
+    /// 슬리피지율 (선택, 기본: 0.0005, 최대: 5%)
+    #[serde(default)]
+    #[validate(custom(function = "validate_slippage_rate"))]
+    pub slippage_rate: Option<Decimal>,
+    /// 자본 배분 방식 (기본: fixed_weight)
+    #[serde(default)]
+    pub allocation: AllocationMethod,
+    /// 재배분 주기 (바 수, 선택)
+    #[serde(default)]
+    #[validate(range(min = 1, max = 10000))]
+    pub rebalance_bars: Option<usize>,
+    /// 위험/성과 측정 룩백 (바 수, 선택)
+    #[serde(default)]
+    #[validate(range(min = 2, max = 10000))]
+    pub lookback_bars: Option<usize>,
+    /// 전략별 최소 가중치 (0 ~ 1, 선택)
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub min_weight: Option<f64>,
+    /// 포트폴리오 전체 최대 동시 포지션 수 (선택)
+    #[serde(default)]
+    #[validate(range(min = 1, max = 1000))]
+    pub max_positions: Option<usize>,
+    /// 벤치마크 심볼 (선택, 예: "069500", "SPY", "BTC/USDT")
+    ///
+    /// 지정 시 알파/베타 등 벤치마크 대비 지표와 벤치마크 자산 곡선을 함께 반환합니다.
+    #[serde(default)]
+    #[validate(length(min = 1, max = 20, message = "벤치마크 심볼은 1-20자여야 합니다"))]
+    pub benchmark: Option<String>,
+}
+
+/// 포트폴리오 백테스트의 전략별 결과.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PortfolioStrategyResult {
+    /// 슬리브 이름
+    pub name: String,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 심볼 목록
+    pub symbols: Vec<String>,
+    /// 초기 가중치
+    pub initial_weight: f64,
+    /// 종료 시점 가중치
+    pub final_weight: f64,
+    /// 배분된 초기 자본
+    pub initial_capital: Decimal,
+    /// 최종 자산 (배분 자본 + 실현/미실현 손익)
+    pub final_equity: Decimal,
+    /// 성과 지표
+    pub metrics: BacktestMetricsResponse,
+    /// 자산 곡선
+    pub equity_curve: Vec<EquityCurvePoint>,
+    /// 거래 내역
+    pub trades: Vec<TradeHistoryItem>,
+}
+
+/// 전략 간 수익률 상관관계 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct StrategyCorrelationResponse {
+    /// 행/열 순서의 전략 이름
+    pub strategies: Vec<String>,
+    /// 상관계수 행렬 (-1 ~ 1)
+    pub matrix: Vec<Vec<f64>>,
+    /// 계산에 사용된 수익률 개수
+    pub period: usize,
+}
+
+/// 자본 배분 이력 항목.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AllocationHistoryPoint {
+    /// 타임스탬프 (Unix)
+    pub timestamp: i64,
+    /// 전략별 가중치
+    pub weights: HashMap<String, f64>,
+}
+
+/// 포트폴리오 백테스트 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BacktestPortfolioResponse {
+    /// 백테스트 결과 ID
+    pub id: String,
+    /// 성공 여부
+    pub success: bool,
+    /// 시작 날짜
+    pub start_date: String,
+    /// 종료 날짜
+    pub end_date: String,
+    /// 자본 배분 방식
+    pub allocation: AllocationMethod,
+    /// 포트폴리오 전체 성과 지표
+    pub metrics: BacktestMetricsResponse,
+    /// 포트폴리오 자산 곡선 (시간순)
+    pub equity_curve: Vec<EquityCurvePoint>,
+    /// 전략별 결과
+    pub strategies: Vec<PortfolioStrategyResult>,
+    /// 전략 간 수익률 상관관계 (전략 2개 이상, 데이터 충분 시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation: Option<StrategyCorrelationResponse>,
+    /// 자본 배분 이력 (시작 시점 및 재배분 시점)
+    pub allocation_history: Vec<AllocationHistoryPoint>,
+    /// 백테스트 설정 요약
+    pub config_summary: BacktestConfigSummary,
+    /// 벤치마크 비교 결과 (벤치마크 지정 시)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkComparisonResponse>,
+}
+
+// ==================== 파라미터 최적화 ====================
+
+/// 파라미터 최적화 작업 요청.
+///
+/// 단일 심볼 데이터로 전략 파라미터를 격자/무작위 탐색합니다.
+/// `search_parameters`/`ranges`를 생략하면 전략 SDUI 스키마의 모든 숫자 필드를 탐색합니다.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct BacktestOptimizeRequest {
+    /// 전략 ID
+    #[validate(length(min = 1, max = 100, message = "전략 ID는 1-100자여야 합니다"))]
+    pub strategy_id: String,
+    /// 거래 심볼 (예: "BTC/USDT")
+    #[validate(length(min = 1, max = 20, message = "심볼은 1-20자여야 합니다"))]
+    pub symbol: String,
+    /// 시작 날짜 (YYYY-MM-DD)
+    #[validate(custom(function = "validate_date_format"))]
+    pub start_date: String,
+    /// 종료 날짜 (YYYY-MM-DD)
+    #[validate(custom(function = "validate_date_format"))]
+    pub end_date: String,
+    /// 초기 자본금 (100 ~ 10억)
+    #[validate(custom(function = "validate_initial_capital"))]
+    pub initial_capital: Decimal,
+    /// 수수료율 (선택, 기본: 0.001 = 0.1%, 최대: 10%)
+    #[serde(default)]
+    #[validate(custom(function = "validate_commission_rate"))]
+    pub commission_rate: Option<Decimal>,
+    /// 슬리피지율 (선택, 기본: 0.0005 = 0.05%, 최대: 5%)
+    #[serde(default)]
+    #[validate(custom(function = "validate_slippage_rate"))]
+    pub slippage_rate: Option<Decimal>,
+    /// 슬리피지 모델 (선택, 지정 시 slippage_rate 대신 사용)
+    #[serde(default)]
+    pub slippage_model: Option<SlippageModel>,
+    /// 탐색하지 않는 기본 전략 파라미터 (선택)
+    #[serde(default)]
+    pub parameters: Option<serde_json::Value>,
+    /// 탐색할 파라미터 이름 (선택, 기본: 스키마의 모든 숫자 필드)
+    #[serde(default)]
+    pub search_parameters: Vec<String>,
+    /// 탐색 범위 덮어쓰기 (선택, 지정한 파라미터는 탐색 대상에 포함)
+    #[serde(default)]
+    pub ranges: Vec<ParameterRange>,
+    /// 목적 함수 (기본: sharpe)
+    #[serde(default)]
+    pub objective: OptimizationObjective,
+    /// 탐색 방식 (기본: grid)
+    ///
+    /// 예: `{"type": "random", "samples": 50, "seed": 42}`
+    #[serde(default)]
+    pub method: SearchMethod,
+    /// 최대 조합 수 (선택, 기본: 1000, 최대: 5000)
+    #[serde(default)]
+    #[validate(range(min = 1, max = 5000))]
+    pub max_combinations: Option<usize>,
+    /// 병렬 실행 수 (선택, 기본: 4, 최대: 16)
+    #[serde(default)]
+    #[validate(range(min = 1, max = 16))]
+    pub parallelism: Option<usize>,
+}
+
+/// 파라미터 최적화 작업 상태.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OptimizeJobStatus {
+    /// 대기 중
+    Pending,
+    /// 실행 중
+    Running,
+    /// 완료
+    Completed,
+    /// 실패
+    Failed,
+}
+
+/// 파라미터 최적화 작업 시작 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OptimizeJobStartResponse {
+    /// 작업 ID
+    pub job_id: String,
+    /// 작업 상태
+    pub status: OptimizeJobStatus,
+    /// 평가할 조합 수
+    pub total_combinations: usize,
+}
+
+/// 파라미터 조합별 최적화 결과 항목.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OptimizeRunItem {
+    /// 순위 (1부터 시작)
+    pub rank: usize,
+    /// 탐색한 파라미터 값
+    #[schema(value_type = Object)]
+    pub params: serde_json::Map<String, serde_json::Value>,
+    /// 목적 함수 점수
+    pub score: Decimal,
+    /// 성과 지표
+    pub metrics: BacktestMetricsResponse,
+}
+
+/// 파라미터 최적화 작업.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OptimizeJob {
+    /// 작업 ID
+    pub job_id: String,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 거래 심볼
+    pub symbol: String,
+    /// 목적 함수
+    pub objective: OptimizationObjective,
+    /// 탐색 방식
+    pub method: SearchMethod,
+    /// 탐색 공간
+    pub space: ParameterSpace,
+    /// 작업 상태
+    pub status: OptimizeJobStatus,
+    /// 완료된 조합 수
+    pub completed: usize,
+    /// 전체 조합 수
+    pub total: usize,
+    /// 생성 시각
+    pub created_at: DateTime<Utc>,
+    /// 종료 시각
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+    /// 에러 메시지 (실패 시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 점수 내림차순 결과 (완료 시)
+    #[serde(default)]
+    pub results: Vec<OptimizeRunItem>,
+    /// 실패한 조합 수
+    #[serde(default)]
+    pub failed: usize,
+}
+
+/// 파라미터 최적화 작업 목록 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OptimizeJobListResponse {
+    /// 작업 목록 (결과 제외, 최신순)
+    pub jobs: Vec<OptimizeJob>,
+    /// 전체 작업 수
+    pub total: usize,
+}
@@ -12,6 +12,8 @@
 //! - `POST /api/v1/backtest/results` - 결과 저장
 //! - `GET /api/v1/backtest/results/{id}` - 단일 결과 조회
 //! - `DELETE /api/v1/backtest/results/{id}` - 결과 삭제
+//! - `POST /api/v1/backtest/results/{id}/monte-carlo` - 몬테카를로 강건성 분석 실행 및 저장
+//! - `GET /api/v1/backtest/results/{id}/monte-carlo` - 저장된 몬테카를로 분석 결과 조회
 
 use axum::{
     extract::{Path, Query, State},
@@ -20,19 +22,24 @@ use axum::{
     routing::get,
     Json, Router,
 };
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
+use trader_analytics::performance::{MonteCarloConfig, MonteCarloMethod, MonteCarloSimulator};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::repository::{
     BacktestResultDto, BacktestResultInput, BacktestResultsRepository, ListResultsFilter,
 };
+use crate::routes::backtest::TradeHistoryItem;
 use crate::state::AppState;
 
+/// 몬테카를로 분석 최대 반복 횟수 (요청당).
+const MAX_MONTE_CARLO_ITERATIONS: usize = 10_000;
+
 // ==================== 요청/응답 타입 (API용) ====================
 
 /// 결과 저장 요청.
@@ -106,6 +113,60 @@ pub struct SaveResultResponse {
     pub message: String,
 }
 
+/// 몬테카를로 분석 요청.
+///
+/// 지정하지 않은 항목은 `MonteCarloConfig` 기본값을 사용합니다.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct MonteCarloRequest {
+    /// 방식별 반복 횟수 (최대 10,000)
+    #[serde(default)]
+    pub iterations: Option<usize>,
+    /// 실행할 방식 목록 (reshuffle, bootstrap, skip)
+    #[serde(default)]
+    pub methods: Option<Vec<MonteCarloMethod>>,
+    /// skip 방식에서 각 거래를 건너뛸 확률 (0 이상 1 미만)
+    #[serde(default)]
+    pub skip_probability: Option<f64>,
+    /// 파산으로 간주할 최대 낙폭 (%)
+    #[serde(default)]
+    pub ruin_drawdown_pct: Option<f64>,
+    /// 재현용 난수 시드
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+impl MonteCarloRequest {
+    /// 요청을 시뮬레이션 설정으로 변환.
+    fn into_config(self) -> Result<MonteCarloConfig, String> {
+        let mut config = MonteCarloConfig::default();
+
+        if let Some(iterations) = self.iterations {
+            if iterations > MAX_MONTE_CARLO_ITERATIONS {
+                return Err(format!(
+                    "반복 횟수는 {} 이하여야 합니다",
+                    MAX_MONTE_CARLO_ITERATIONS
+                ));
+            }
+            config = config.with_iterations(iterations);
+        }
+        if let Some(methods) = self.methods {
+            config = config.with_methods(methods);
+        }
+        if let Some(probability) = self.skip_probability {
+            config = config.with_skip_probability(probability);
+        }
+        if let Some(pct) = self.ruin_drawdown_pct {
+            config = config.with_ruin_drawdown_pct(pct);
+        }
+        if let Some(seed) = self.seed {
+            config = config.with_seed(seed);
+        }
+
+        config.validate().map_err(|e| e.to_string())?;
+        Ok(config)
+    }
+}
+
 // ==================== 핸들러 ====================
 
 /// 저장된 백테스트 결과 목록 조회.
@@ -383,6 +444,246 @@ pub async fn delete_backtest_result(
     }
 }
 
+/// 저장된 백테스트 결과에 대해 몬테카를로 분석 실행.
+///
+/// `POST /api/v1/backtest/results/{id}/monte-carlo`
+///
+/// 저장된 거래 내역으로 분석을 수행하고 결과를 `monte_carlo` 컬럼에 저장합니다.
+pub async fn run_monte_carlo(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    body: Option<Json<MonteCarloRequest>>,
+) -> impl IntoResponse {
+    debug!("몬테카를로 분석 요청: id={}", id);
+
+    let pool = match &state.db_pool {
+        Some(p) => p,
+        None => {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({
+                    "error": "데이터베이스가 연결되지 않았습니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let uuid = match Uuid::parse_str(&id) {
+        Ok(u) => u,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "유효하지 않은 ID 형식입니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let config = match body.map(|Json(b)| b).unwrap_or_default().into_config() {
+        Ok(c) => c,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "몬테카를로 설정이 올바르지 않습니다",
+                    "details": e
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let record = match BacktestResultsRepository::get_by_id(pool, uuid).await {
+        Ok(Some(record)) => record,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({
+                    "error": "결과를 찾을 수 없습니다"
+                })),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            warn!("결과 조회 실패: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "결과 조회 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let trades: Vec<TradeHistoryItem> = match serde_json::from_value(record.trades) {
+        Ok(t) => t,
+        Err(e) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(serde_json::json!({
+                    "error": "저장된 거래 내역을 해석할 수 없습니다",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let start = record.start_date.and_time(NaiveTime::MIN).and_utc();
+    // 종료일은 포함 구간이므로 다음 날 00:00을 기간 끝으로 사용
+    let end = (record.end_date + chrono::Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+    let initial_capital = record.initial_capital;
+
+    // 반복 시뮬레이션은 CPU 작업이므로 블로킹 스레드에서 실행
+    let result = tokio::task::spawn_blocking(move || {
+        MonteCarloSimulator::new(config)
+            .with_period(start, end)
+            .run(&trades, initial_capital)
+    })
+    .await;
+
+    let report = match result {
+        Ok(Ok(report)) => report,
+        Ok(Err(e)) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(serde_json::json!({
+                    "error": "몬테카를로 분석 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            warn!("몬테카를로 분석 태스크 실패: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "몬테카를로 분석 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let value = match serde_json::to_value(&report) {
+        Ok(v) => v,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "분석 결과 직렬화 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    match BacktestResultsRepository::save_monte_carlo(pool, uuid, &value).await {
+        Ok(true) => {
+            info!(
+                "몬테카를로 분석 저장 완료: id={}, trades={}",
+                id, report.trade_count
+            );
+            Json(value).into_response()
+        }
+        Ok(false) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "결과를 찾을 수 없습니다"
+            })),
+        )
+            .into_response(),
+        Err(e) => {
+            warn!("몬테카를로 분석 저장 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "몬테카를로 분석 저장 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// 저장된 몬테카를로 분석 결과 조회.
+///
+/// `GET /api/v1/backtest/results/{id}/monte-carlo`
+pub async fn get_monte_carlo(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    debug!("몬테카를로 분석 결과 조회: id={}", id);
+
+    let pool = match &state.db_pool {
+        Some(p) => p,
+        None => {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({
+                    "error": "데이터베이스가 연결되지 않았습니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let uuid = match Uuid::parse_str(&id) {
+        Ok(u) => u,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "유효하지 않은 ID 형식입니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    match BacktestResultsRepository::get_by_id(pool, uuid).await {
+        Ok(Some(record)) => match record.monte_carlo {
+            Some(value) => Json(value).into_response(),
+            None => (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({
+                    "error": "몬테카를로 분석 결과가 없습니다"
+                })),
+            )
+                .into_response(),
+        },
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "결과를 찾을 수 없습니다"
+            })),
+        )
+            .into_response(),
+        Err(e) => {
+            warn!("결과 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "결과 조회 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
 // ==================== 라우터 ====================
 
 /// 백테스트 결과 라우터 생성.
@@ -394,4 +695,55 @@ pub fn backtest_results_router() -> Router<Arc<AppState>> {
         .route("/", get(list_backtest_results).post(save_backtest_result))
         // 단일 결과 조회 + 삭제 (같은 경로에 GET/DELETE)
         .route("/{id}", get(get_backtest_result).delete(delete_backtest_result))
+        // 몬테카를로 강건성 분석 (실행 + 조회)
+        .route(
+            "/{id}/monte-carlo",
+            get(get_monte_carlo).post(run_monte_carlo),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monte_carlo_request_defaults() {
+        let request: MonteCarloRequest = serde_json::from_str("{}").unwrap();
+        let config = request.into_config().unwrap();
+
+        assert_eq!(config.iterations, MonteCarloConfig::default().iterations);
+        assert_eq!(config.methods, MonteCarloMethod::ALL.to_vec());
+    }
+
+    #[test]
+    fn test_monte_carlo_request_overrides() {
+        let request: MonteCarloRequest = serde_json::from_value(serde_json::json!({
+            "iterations": 200,
+            "methods": ["bootstrap"],
+            "ruin_drawdown_pct": 30.0,
+            "seed": 7
+        }))
+        .unwrap();
+        let config = request.into_config().unwrap();
+
+        assert_eq!(config.iterations, 200);
+        assert_eq!(config.methods, vec![MonteCarloMethod::Bootstrap]);
+        assert_eq!(config.ruin_drawdown_pct, 30.0);
+        assert_eq!(config.seed, Some(7));
+    }
+
+    #[test]
+    fn test_monte_carlo_request_rejects_invalid() {
+        let too_many = MonteCarloRequest {
+            iterations: Some(MAX_MONTE_CARLO_ITERATIONS + 1),
+            ..Default::default()
+        };
+        assert!(too_many.into_config().is_err());
+
+        let bad_probability = MonteCarloRequest {
+            skip_probability: Some(1.5),
+            ..Default::default()
+        };
+        assert!(bad_probability.into_config().is_err());
+    }
 }
@@ -82,7 +82,8 @@ pub use patterns::{
     patterns_router, CandlestickPatternsResponse, ChartPatternsResponse, PatternTypesResponse,
 };
 pub use portfolio::{
-    portfolio_router, BalanceResponse, HoldingsResponse, PortfolioSummaryResponse,
+    portfolio_router, BalanceResponse, CircuitBreakerResponse, HoldingsResponse,
+    PortfolioRiskResponse, PortfolioSummaryResponse,
 };
 pub use positions::{
     positions_router, PositionResponse, PositionSummaryResponse, PositionsListResponse,
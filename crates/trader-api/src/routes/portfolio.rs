@@ -8,6 +8,10 @@
 //! - `GET /api/v1/portfolio/summary` - 포트폴리오 요약
 //! - `GET /api/v1/portfolio/balance` - 상세 잔고 조회
 //! - `GET /api/v1/portfolio/holdings` - 보유 종목 목록
+//! - `GET /api/v1/portfolio/risk` - 포트폴리오 VaR / Expected Shortfall
+//! - `GET /api/v1/portfolio/risk/circuit-breakers` - 드로다운 / 연속 손실 서킷 브레이커 상태
+//! - `POST /api/v1/portfolio/risk/circuit-breakers/reset` - 서킷 브레이커 리셋
+//! - `POST /api/v1/portfolio/risk/circuit-breakers/override` - 서킷 브레이커 재정의
 //!
 //! # 쿼리 파라미터
 //!
@@ -16,7 +20,7 @@
 use axum::{
     extract::{Query, State},
     http::StatusCode,
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use rust_decimal::Decimal;
@@ -31,9 +35,11 @@ use crate::repository::{
     PositionRepository,
 };
 use crate::routes::strategies::ApiError;
+use crate::services::circuit_breaker::persist_circuit_breaker;
 use crate::state::AppState;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use trader_core::{ExecutionHistoryRequest, ExecutionRecord};
+use trader_risk::{BreakerAction, BreakerTrip, DrawdownCircuitBreaker};
 
 // ==================== 응답 타입 ====================
 
@@ -59,6 +65,143 @@ pub struct PortfolioSummaryResponse {
     pub margin_used: Decimal,
 }
 
+/// 포트폴리오 VaR/ES 응답.
+///
+/// OrderExecutor가 추적하는 포지션과 RiskManager의 가격 이력으로 계산합니다.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioRiskResponse {
+    /// 계산 가능 여부 (노출 종목의 가격 이력이 없으면 false)
+    pub available: bool,
+    /// 계산 방식 ("historical" | "parametric")
+    pub method: String,
+    /// 신뢰수준
+    pub confidence: f64,
+    /// 1일 Value-at-Risk (금액)
+    pub var: Decimal,
+    /// 1일 Expected Shortfall (금액)
+    pub expected_shortfall: Decimal,
+    /// 잔고 대비 VaR 비율 (%)
+    pub var_pct: f64,
+    /// 잔고 대비 ES 비율 (%)
+    pub es_pct: f64,
+    /// VaR 한도 (%)
+    pub max_var_pct: Option<f64>,
+    /// ES 한도 (%)
+    pub max_es_pct: Option<f64>,
+    /// 한도 초과 사유
+    pub breach: Option<String>,
+    /// 총 노출 (절대값 합)
+    pub gross_exposure: Decimal,
+    /// 사용한 수익률 관측 수
+    pub observations: usize,
+    /// 가격 이력이 부족해 제외된 종목
+    pub missing_symbols: Vec<String>,
+}
+
+/// 발동 중인 서킷 브레이커.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreakerTripResponse {
+    /// 종류 ("peak_drawdown" | "window_drawdown" | "consecutive_losses")
+    pub kind: String,
+    /// 대상 전략 (없으면 계좌 전체)
+    pub strategy_id: Option<String>,
+    /// 처리 방식 ("reduce_size" | "halt")
+    pub action: String,
+    /// 현재 값 (드로다운 % 또는 연속 손실 수)
+    pub value: f64,
+    /// 한도
+    pub limit: f64,
+    /// 발동 사유
+    pub message: String,
+    /// 발동 시각
+    pub tripped_at: DateTime<Utc>,
+}
+
+impl From<&BreakerTrip> for CircuitBreakerTripResponse {
+    fn from(trip: &BreakerTrip) -> Self {
+        Self {
+            kind: snake_case_name(trip.kind),
+            strategy_id: trip.strategy_id.clone(),
+            action: snake_case_name(trip.action),
+            value: trip.value,
+            limit: trip.limit,
+            message: trip.message(),
+            tripped_at: trip.tripped_at,
+        }
+    }
+}
+
+/// 드로다운 / 연속 손실 서킷 브레이커 상태 응답.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreakerResponse {
+    /// 한도가 하나라도 설정되었는지 여부
+    pub enabled: bool,
+    /// 신규 진입 중지 여부 (재정의 중이면 false)
+    pub halted: bool,
+    /// 관리자 재정의로 발동 중인 브레이커를 무시하는지 여부
+    pub overridden: bool,
+    /// 고점 대비 현재 드로다운 (%)
+    pub drawdown_pct: f64,
+    /// 최근 기간 드로다운 (%)
+    pub window_drawdown_pct: f64,
+    /// 최고 자산 (마지막 리셋 이후)
+    pub peak_equity: Option<Decimal>,
+    /// 최근 자산
+    pub latest_equity: Option<Decimal>,
+    /// 계좌 전체 연속 손실 거래 수
+    pub account_loss_streak: u32,
+    /// 전략별 연속 손실 거래 수
+    pub strategy_loss_streaks: std::collections::BTreeMap<String, u32>,
+    /// 고점 대비 드로다운 한도 (%)
+    pub max_drawdown_pct: Option<f64>,
+    /// 기간 드로다운 한도 (%)
+    pub max_window_drawdown_pct: Option<f64>,
+    /// 기간 드로다운 계산 기간 (일)
+    pub window_days: u32,
+    /// 계좌 전체 연속 손실 한도
+    pub max_consecutive_losses: Option<u32>,
+    /// 전략별 연속 손실 한도
+    pub max_strategy_consecutive_losses: Option<u32>,
+    /// 발동 중인 브레이커
+    pub trips: Vec<CircuitBreakerTripResponse>,
+}
+
+impl From<&DrawdownCircuitBreaker> for CircuitBreakerResponse {
+    fn from(breaker: &DrawdownCircuitBreaker) -> Self {
+        let config = breaker.config();
+        let state = breaker.state();
+        Self {
+            enabled: config.has_limit(),
+            halted: !state.overridden
+                && state.trips.iter().any(|t| t.action == BreakerAction::Halt),
+            overridden: state.overridden,
+            drawdown_pct: breaker.drawdown_pct(),
+            window_drawdown_pct: breaker.window_drawdown_pct(),
+            peak_equity: state.peak_equity,
+            latest_equity: breaker.latest_equity(),
+            account_loss_streak: state.account_loss_streak,
+            strategy_loss_streaks: state.strategy_loss_streaks.clone(),
+            max_drawdown_pct: config.max_drawdown_pct,
+            max_window_drawdown_pct: config.max_window_drawdown_pct,
+            window_days: config.window_days,
+            max_consecutive_losses: config.max_consecutive_losses,
+            max_strategy_consecutive_losses: config.max_strategy_consecutive_losses,
+            trips: state.trips.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// 서킷 브레이커 재정의 요청.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreakerOverrideRequest {
+    /// true면 발동 중인 브레이커를 무시하고 거래 허용, false면 재정의 해제
+    pub allow_trading: bool,
+}
+
 /// 상세 잔고 응답.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -412,6 +555,150 @@ pub async fn get_portfolio_summary(
     }))
 }
 
+/// 포트폴리오 VaR / Expected Shortfall 조회.
+///
+/// 실행 엔진이 추적 중인 포지션 기준의 1일 VaR/ES와 설정된 한도를 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/portfolio/risk",
+    tag = "portfolio",
+    responses(
+        (status = 200, description = "포트폴리오 리스크 조회 성공", body = PortfolioRiskResponse)
+    )
+)]
+pub async fn get_portfolio_risk(State(state): State<Arc<AppState>>) -> Json<PortfolioRiskResponse> {
+    let executor = state.executor.read().await;
+    let positions = executor.get_open_positions().await;
+    let risk_manager = executor.risk_manager().read().await;
+    let config = &risk_manager.config().portfolio_var;
+
+    let method = serde_json::to_value(config.method)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+
+    let response = match risk_manager.portfolio_risk(&positions) {
+        Some(risk) => PortfolioRiskResponse {
+            available: true,
+            method,
+            confidence: risk.confidence,
+            var: risk.var,
+            expected_shortfall: risk.expected_shortfall,
+            var_pct: risk.var_pct,
+            es_pct: risk.es_pct,
+            max_var_pct: config.max_var_pct,
+            max_es_pct: config.max_es_pct,
+            breach: risk.breach(config),
+            gross_exposure: risk.gross_exposure,
+            observations: risk.observations,
+            missing_symbols: risk.missing_symbols,
+        },
+        None => PortfolioRiskResponse {
+            available: false,
+            method,
+            confidence: config.confidence,
+            var: Decimal::ZERO,
+            expected_shortfall: Decimal::ZERO,
+            var_pct: 0.0,
+            es_pct: 0.0,
+            max_var_pct: config.max_var_pct,
+            max_es_pct: config.max_es_pct,
+            breach: None,
+            gross_exposure: Decimal::ZERO,
+            observations: 0,
+            missing_symbols: positions.iter().map(|p| p.ticker.clone()).collect(),
+        },
+    };
+
+    Json(response)
+}
+
+/// enum의 serde 이름 (snake_case).
+fn snake_case_name<T: Serialize>(value: T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// 드로다운 / 연속 손실 서킷 브레이커 상태 조회.
+///
+/// 실행 엔진의 RiskManager가 추적하는 드로다운, 연속 손실과 발동 중인 브레이커를 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/portfolio/risk/circuit-breakers",
+    tag = "portfolio",
+    responses(
+        (status = 200, description = "서킷 브레이커 상태 조회 성공", body = CircuitBreakerResponse)
+    )
+)]
+pub async fn get_circuit_breakers(
+    State(state): State<Arc<AppState>>,
+) -> Json<CircuitBreakerResponse> {
+    let executor = state.executor.read().await;
+    let risk_manager = executor.risk_manager().read().await;
+
+    Json(risk_manager.circuit_breaker().into())
+}
+
+/// 서킷 브레이커 리셋.
+///
+/// 발동 중인 브레이커와 연속 손실 수를 초기화하고, 현재 자산을 새 고점으로 삼습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/portfolio/risk/circuit-breakers/reset",
+    tag = "portfolio",
+    responses(
+        (status = 200, description = "서킷 브레이커 리셋 성공", body = CircuitBreakerResponse)
+    )
+)]
+pub async fn reset_circuit_breakers(
+    State(state): State<Arc<AppState>>,
+) -> Json<CircuitBreakerResponse> {
+    let executor = state.executor.read().await;
+    let mut risk_manager = executor.risk_manager().write().await;
+    risk_manager.reset_circuit_breakers();
+    info!("서킷 브레이커 리셋 (API)");
+
+    if let Some(pool) = &state.db_pool {
+        persist_circuit_breaker(pool, risk_manager.circuit_breaker().state()).await;
+    }
+
+    Json(risk_manager.circuit_breaker().into())
+}
+
+/// 서킷 브레이커 재정의.
+///
+/// `allowTrading: true`면 발동 중인 브레이커를 무시하고 거래를 허용합니다.
+/// 이후 새 브레이커가 발동하면 재정의는 자동으로 해제됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/portfolio/risk/circuit-breakers/override",
+    tag = "portfolio",
+    request_body = CircuitBreakerOverrideRequest,
+    responses(
+        (status = 200, description = "서킷 브레이커 재정의 성공", body = CircuitBreakerResponse)
+    )
+)]
+pub async fn override_circuit_breakers(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CircuitBreakerOverrideRequest>,
+) -> Json<CircuitBreakerResponse> {
+    let executor = state.executor.read().await;
+    let mut risk_manager = executor.risk_manager().write().await;
+    risk_manager.override_circuit_breakers(request.allow_trading);
+    info!(
+        allow_trading = request.allow_trading,
+        "서킷 브레이커 재정의 (API)"
+    );
+
+    if let Some(pool) = &state.db_pool {
+        persist_circuit_breaker(pool, risk_manager.circuit_breaker().state()).await;
+    }
+
+    Json(risk_manager.circuit_breaker().into())
+}
+
 /// 상세 잔고 조회.
 #[utoipa::path(
     get,
@@ -889,6 +1176,13 @@ pub fn portfolio_router() -> Router<Arc<AppState>> {
         .route("/summary", get(get_portfolio_summary))
         .route("/balance", get(get_balance))
         .route("/holdings", get(get_holdings))
+        .route("/risk", get(get_portfolio_risk))
+        .route("/risk/circuit-breakers", get(get_circuit_breakers))
+        .route("/risk/circuit-breakers/reset", post(reset_circuit_breakers))
+        .route(
+            "/risk/circuit-breakers/override",
+            post(override_circuit_breakers),
+        )
         .route("/orders", get(get_order_history))
 }
 
@@ -933,6 +1227,127 @@ mod tests {
         assert!(summary.total_value > Decimal::ZERO);
     }
 
+    #[tokio::test]
+    async fn test_get_portfolio_risk_without_positions() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/portfolio/risk", get(get_portfolio_risk))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/portfolio/risk")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let risk: PortfolioRiskResponse = serde_json::from_slice(&body).unwrap();
+
+        // 노출이 없으면 VaR 0, 한도 초과 없음
+        assert!(risk.available);
+        assert_eq!(risk.var, Decimal::ZERO);
+        assert_eq!(risk.method, "historical");
+        assert!(risk.breach.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_override_and_reset() {
+        use crate::state::create_test_state;
+        use trader_risk::{BreakerKind, CircuitBreakerState};
+
+        let state = Arc::new(create_test_state());
+        {
+            let executor = state.executor.read().await;
+            executor
+                .risk_manager()
+                .write()
+                .await
+                .restore_circuit_breaker(CircuitBreakerState {
+                    account_loss_streak: 5,
+                    trips: vec![BreakerTrip {
+                        kind: BreakerKind::ConsecutiveLosses,
+                        strategy_id: None,
+                        action: BreakerAction::Halt,
+                        value: 5.0,
+                        limit: 5.0,
+                        tripped_at: Utc::now(),
+                    }],
+                    ..Default::default()
+                });
+        }
+        let app = Router::new()
+            .route("/risk/circuit-breakers", get(get_circuit_breakers))
+            .route("/risk/circuit-breakers/reset", post(reset_circuit_breakers))
+            .route(
+                "/risk/circuit-breakers/override",
+                post(override_circuit_breakers),
+            )
+            .with_state(state);
+
+        let send = |method: &str, uri: &str, body: &str| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+        let read = |response: axum::response::Response| async move {
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<CircuitBreakerResponse>(&body).unwrap()
+        };
+
+        let status = read(
+            app.clone()
+                .oneshot(send("GET", "/risk/circuit-breakers", ""))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert!(status.halted);
+        assert_eq!(status.trips[0].kind, "consecutive_losses");
+
+        // 재정의하면 중지 해제, 브레이커는 유지
+        let status = read(
+            app.clone()
+                .oneshot(send(
+                    "POST",
+                    "/risk/circuit-breakers/override",
+                    r#"{"allowTrading":true}"#,
+                ))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert!(!status.halted);
+        assert!(status.overridden);
+        assert_eq!(status.trips.len(), 1);
+
+        // 리셋하면 브레이커와 연속 손실 초기화
+        let status = read(
+            app.oneshot(send("POST", "/risk/circuit-breakers/reset", ""))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert!(status.trips.is_empty());
+        assert_eq!(status.account_loss_streak, 0);
+        assert!(!status.overridden);
+    }
+
     #[tokio::test]
     async fn test_get_holdings_empty() {
         use crate::state::create_test_state;
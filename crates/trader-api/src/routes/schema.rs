@@ -10,7 +10,7 @@ use axum::{
 use serde_json::json;
 use std::sync::Arc;
 use trader_core::FragmentCategory;
-use trader_strategy::{FragmentRegistry, SchemaComposer, StrategyRegistry};
+use trader_strategy::{rule_catalog, FragmentRegistry, SchemaComposer, StrategyRegistry};
 
 use crate::{error::ApiErrorResponse, state::AppState};
 
@@ -161,6 +161,19 @@ pub async fn get_fragment_detail(
     Ok(Json(json))
 }
 
+/// GET /api/v1/schema/rules
+///
+/// 규칙 기반 전략의 규칙 빌더 카탈로그를 반환합니다.
+///
+/// 조건 피연산자로 사용할 수 있는 지표/컨텍스트 소스(파라미터 필드 스키마 포함)와
+/// 비교 연산자 목록입니다.
+pub async fn get_rule_catalog(
+    State(_state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ApiErrorResponse>)> {
+    let registry = FragmentRegistry::with_builtins();
+    Ok(Json(rule_catalog(&registry)))
+}
+
 /// 스키마 라우터 생성.
 pub fn schema_router() -> axum::Router<Arc<AppState>> {
     use axum::routing::get;
@@ -169,6 +182,7 @@ pub fn schema_router() -> axum::Router<Arc<AppState>> {
         .route("/fragments", get(list_fragments))
         .route("/fragments/{:category}", get(list_fragments_by_category))
         .route("/fragments/{:fragment_id}/detail", get(get_fragment_detail))
+        .route("/rules", get(get_rule_catalog))
 }
 
 #[cfg(test)]
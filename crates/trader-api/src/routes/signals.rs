@@ -512,6 +512,7 @@ pub async fn create_signal(
         "scale" => SignalType::Scale,
         "addtoposition" => SignalType::AddToPosition,
         "reduceposition" => SignalType::ReducePosition,
+        "rebalance" => SignalType::Rebalance,
         _ => {
             return Err((
                 StatusCode::BAD_REQUEST,
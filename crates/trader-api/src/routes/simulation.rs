@@ -374,8 +374,9 @@ impl SimulationEngine {
                     self.open_position(signal, kline)?;
                 }
             }
-            SignalType::Alert => {
+            SignalType::Alert | SignalType::Rebalance => {
                 // Alert는 실행하지 않고 마커만 기록
+                // (단일 종목 시뮬레이션은 목표 포트폴리오 리밸런싱을 지원하지 않음)
             }
         }
 
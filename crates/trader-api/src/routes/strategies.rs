@@ -10,29 +10,56 @@
 //! - `DELETE /api/v1/strategies/{id}` - 전략 삭제
 //! - `POST /api/v1/strategies/{id}/start` - 전략 시작
 //! - `POST /api/v1/strategies/{id}/stop` - 전략 중지
+//! - `GET /api/v1/strategies/{id}/lifecycle` - 생명주기 상태 및 전이 기록 조회
+//! - `POST /api/v1/strategies/{id}/lifecycle` - 생명주기 전환 (start, pause, resume, drain, stop)
 //! - `PUT /api/v1/strategies/{id}/config` - 전략 설정 변경
+//! - `GET /api/v1/strategies/{id}/shadows` - 섀도 전략 목록 및 실전 대비 비교
+//! - `POST /api/v1/strategies/{id}/shadows` - 변경할 설정으로 섀도 전략 시작
+//! - `DELETE /api/v1/strategies/{id}/shadows/{shadow_id}` - 섀도 전략 중지 및 제거
+//! - `GET /api/v1/strategies/{id}/decisions` - 의사결정 로그 조회 (신호가 나오지 않은 이유 추적)
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
     Json, Router,
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
 use ts_rs::TS;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::repository::{strategies::CreateStrategyInput, StrategyRepository};
+use crate::repository::{
+    strategies::CreateStrategyInput, DecisionEventRecord, LifecycleEventRecord,
+    StrategyDecisionRepository, StrategyLifecycleRepository, StrategyRepository,
+};
+use crate::services::shadow_trading::ShadowComparison;
 use crate::state::AppState;
 use crate::websocket::{ServerMessage, StrategyUpdateData};
-use trader_strategy::{EngineError, EngineStats, Strategy, StrategyStatus};
+use trader_strategy::strategies::common::PositionSizingConfig;
+use trader_strategy::{
+    DecisionQuery, EngineError, EngineStats, LifecycleAction, RuleStrategy, ScriptStrategy,
+    Strategy, StrategyEngine, StrategyLifecycle, StrategyStatus, RULE_STRATEGY_ID,
+    SCRIPT_STRATEGY_ID,
+};
+
+/// API에서 요청한 생명주기 전이의 요청 주체.
+const API_ACTOR: &str = "api";
+
+/// 생명주기 조회 시 반환하는 최근 전이 기록 수.
+const LIFECYCLE_HISTORY_LIMIT: usize = 50;
+
+/// 의사결정 로그 조회 시 기본 반환 건수.
+const DEFAULT_DECISION_LIMIT: usize = 100;
+
+/// 의사결정 로그 조회 시 최대 반환 건수.
+const MAX_DECISION_LIMIT: usize = 1000;
 
 // ==================== 응답 타입 ====================
 
@@ -123,6 +150,64 @@ pub struct StrategyActionResponse {
     pub message: String,
 }
 
+/// 전략 생명주기 전환 요청.
+#[derive(Debug, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct LifecycleActionRequest {
+    /// 동작 (start, pause, resume, drain, stop)
+    #[ts(type = "\"start\" | \"pause\" | \"resume\" | \"drain\" | \"stop\"")]
+    #[schema(value_type = String, example = "drain")]
+    pub action: LifecycleAction,
+    /// 전환 사유 (감사 로그에 기록)
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// 전략 생명주기 응답.
+// Note: TS not derived - LifecycleEventRecord는 repository 타입이라 ts-rs 미지원
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StrategyLifecycleResponse {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 현재 생명주기 상태
+    #[schema(value_type = String, example = "active")]
+    pub lifecycle: StrategyLifecycle,
+    /// 실행 중 여부 (워밍업/실행/일시 정지/청산 대기)
+    pub running: bool,
+    /// 최근 상태 전이 기록 (최신순)
+    pub transitions: Vec<LifecycleEventRecord>,
+}
+
+/// 의사결정 로그 조회 조건.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DecisionLogQuery {
+    /// 종목 필터
+    #[serde(default)]
+    pub ticker: Option<String>,
+    /// 추적 ID (지정하면 리스크/실행 단계까지 해당 추적의 전체 기록 반환)
+    #[serde(default)]
+    pub trace_id: Option<String>,
+    /// 시작 시각 (RFC 3339)
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+    /// 종료 시각 (RFC 3339)
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+    /// 최대 건수 (기본 100, 최대 1000)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// 전략 의사결정 로그 응답.
+// Note: TS not derived - DecisionEventRecord는 repository 타입이라 ts-rs 미지원
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StrategyDecisionsResponse {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 의사결정 기록 (최신순)
+    pub decisions: Vec<DecisionEventRecord>,
+}
+
 /// 전략 설정 변경 요청.
 #[derive(Debug, Deserialize, ToSchema, TS)]
 #[ts(export, export_to = "strategies/")]
@@ -195,6 +280,52 @@ pub struct CloneStrategyResponse {
     pub message: String,
 }
 
+/// 섀도 전략 생성 요청.
+#[derive(Debug, Default, Deserialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct CreateShadowRequest {
+    /// 실전 설정에 덮어쓸 파라미터 (없으면 실전과 같은 설정)
+    #[serde(default)]
+    #[ts(type = "Record<string, unknown> | null")]
+    #[schema(value_type = Option<Object>)]
+    pub override_params: Option<Value>,
+    /// 섀도 전략 이름 (없으면 "<실전 이름> (shadow)")
+    #[serde(default)]
+    pub name: Option<String>,
+    /// 가상 원장 초기 자본 (없으면 기본값)
+    #[serde(default)]
+    #[ts(type = "string | null")]
+    pub initial_capital: Option<Decimal>,
+}
+
+/// 섀도 전략 생성 응답.
+#[derive(Debug, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct CreateShadowResponse {
+    /// 성공 여부
+    pub success: bool,
+    /// 실전 전략 ID
+    pub live_id: String,
+    /// 생성된 섀도 전략 ID
+    pub shadow_id: String,
+    /// 섀도 전략 생명주기 상태
+    #[ts(type = "string")]
+    #[schema(value_type = String, example = "active")]
+    pub lifecycle: StrategyLifecycle,
+    /// 메시지
+    pub message: String,
+}
+
+/// 섀도 전략 비교 목록 응답.
+// Note: TS not derived - ShadowComparison은 서비스 타입이라 ts-rs 미지원
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ShadowListResponse {
+    /// 실전 전략 ID
+    pub live_id: String,
+    /// 섀도 전략별 실전 대비 비교
+    pub shadows: Vec<ShadowComparison>,
+}
+
 /// 전략 생성 요청.
 #[derive(Debug, Deserialize, Validate, ToSchema, TS)]
 #[ts(export, export_to = "strategies/")]
@@ -374,6 +505,8 @@ fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ApiError>) {
         EngineError::InitializationFailed(_) => (StatusCode::INTERNAL_SERVER_ERROR, "INIT_FAILED"),
         EngineError::NotRunning(_) => (StatusCode::BAD_REQUEST, "NOT_RUNNING"),
         EngineError::AlreadyRunning(_) => (StatusCode::BAD_REQUEST, "ALREADY_RUNNING"),
+        EngineError::InvalidTransition(_) => (StatusCode::CONFLICT, "INVALID_TRANSITION"),
+        EngineError::InvalidShadow(_) => (StatusCode::BAD_REQUEST, "INVALID_SHADOW"),
         EngineError::ChannelError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "CHANNEL_ERROR"),
         EngineError::InternalError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR"),
     };
@@ -381,6 +514,21 @@ fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ApiError>) {
     (status, Json(ApiError::new(code, err.to_string())))
 }
 
+/// 전략 params의 `position_sizing` 설정 검증.
+fn validate_position_sizing(params: &Value) -> Result<(), (StatusCode, Json<ApiError>)> {
+    PositionSizingConfig::from_strategy_config(params)
+        .map(|_| ())
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new(
+                    "INVALID_POSITION_SIZING",
+                    format!("Invalid position_sizing: {}", e),
+                )),
+            )
+        })
+}
+
 // ==================== handler ====================
 
 /// 전략 생성.
@@ -417,6 +565,22 @@ pub async fn create_strategy(
         )
     })?;
 
+    // 스크립트/규칙 전략은 저장 전에 컴파일 검증
+    let validation = match trader_strategy::StrategyRegistry::find(&request.strategy_type) {
+        Some(meta) if meta.id == SCRIPT_STRATEGY_ID => {
+            ScriptStrategy::validate_config(&request.parameters)
+                .map_err(|e| ("INVALID_SCRIPT", e.to_string()))
+        }
+        Some(meta) if meta.id == RULE_STRATEGY_ID => {
+            RuleStrategy::validate_config(&request.parameters)
+                .map_err(|e| ("INVALID_RULES", e.to_string()))
+        }
+        _ => Ok(()),
+    };
+    validation
+        .map_err(|(code, message)| (StatusCode::BAD_REQUEST, Json(ApiError::new(code, message))))?;
+    validate_position_sizing(&request.parameters)?;
+
     // 전략 ID 생성 (UUID)
     let strategy_id = format!(
         "{}_{}",
@@ -508,6 +672,10 @@ pub async fn create_strategy(
         )
         .await
         .map_err(engine_error_to_response)?;
+    engine
+        .set_allocated_capital(&strategy_id, allocated_capital)
+        .await
+        .map_err(engine_error_to_response)?;
 
     // WebSocket 브로드캐스트: 전략 생성 알림
     state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
@@ -719,6 +887,7 @@ pub async fn get_strategy(
 /// POST /api/v1/strategies/{id}/start
 ///
 /// 다중 타임프레임 전략의 경우, 시작 전에 필요한 캔들 데이터를 자동으로 로드합니다.
+/// 워밍업이 필요한 전략은 과거 캔들로 지표를 채운 뒤 실행 상태로 전환됩니다.
 #[utoipa::path(
     post,
     path = "/api/v1/strategies/{id}/start",
@@ -743,19 +912,8 @@ pub async fn start_strategy(
         .map(|s| s.name)
         .unwrap_or_else(|_| id.clone());
 
-    // 다중 타임프레임 전략인 경우 데이터 자동 로드
-    if let Ok(Some(mtf_config)) = engine.get_strategy_multi_tf_config(&id).await {
-        if let Err(e) = load_multi_timeframe_data(&state, &engine, &id, &mtf_config).await {
-            tracing::warn!(
-                strategy_id = %id,
-                error = %e,
-                "다중 타임프레임 데이터 로드 실패 (전략은 계속 시작됨)"
-            );
-        }
-    }
-
-    match engine.start_strategy(&id).await {
-        Ok(()) => {
+    match start_with_warmup(&state, &engine, &id, "start").await {
+        Ok(_) => {
             // WebSocket 브로드캐스트: 전략 시작 알림
             state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
                 strategy_id: id.clone(),
@@ -777,6 +935,89 @@ pub async fn start_strategy(
     }
 }
 
+/// 전략 시작 (다중 타임프레임 데이터 로드 → 시작 → 과거 캔들 워밍업).
+///
+/// 데이터 로드나 워밍업에 실패해도 전략은 시작되며, 워밍업은 실시간 캔들로 이어집니다.
+async fn start_with_warmup(
+    state: &AppState,
+    engine: &StrategyEngine,
+    id: &str,
+    reason: &str,
+) -> Result<StrategyLifecycle, EngineError> {
+    // 다중 타임프레임 전략인 경우 데이터 자동 로드
+    if let Ok(Some(mtf_config)) = engine.get_strategy_multi_tf_config(id).await {
+        if let Err(e) = load_multi_timeframe_data(state, engine, id, &mtf_config).await {
+            tracing::warn!(
+                strategy_id = %id,
+                error = %e,
+                "다중 타임프레임 데이터 로드 실패 (전략은 계속 시작됨)"
+            );
+        }
+    }
+
+    let lifecycle = engine
+        .apply_lifecycle_action(id, LifecycleAction::Start, API_ACTOR, reason)
+        .await?;
+    if lifecycle != StrategyLifecycle::WarmingUp {
+        return Ok(lifecycle);
+    }
+
+    match warm_up_from_history(state, engine, id).await {
+        Ok(lifecycle) => Ok(lifecycle),
+        Err(e) => {
+            tracing::warn!(
+                strategy_id = %id,
+                error = %e,
+                "과거 캔들 워밍업 실패 (실시간 캔들로 워밍업 계속)"
+            );
+            Ok(StrategyLifecycle::WarmingUp)
+        }
+    }
+}
+
+/// 워밍업 중인 전략에 과거 캔들 공급.
+///
+/// 구독 티커별로 필요한 수만큼 캔들을 조회하여 시간순으로 합친 뒤 전달합니다.
+/// 모든 티커를 구독하는 전략은 과거 캔들을 조회할 수 없으므로 실시간 캔들로 워밍업합니다.
+async fn warm_up_from_history(
+    state: &AppState,
+    engine: &StrategyEngine,
+    strategy_id: &str,
+) -> Result<StrategyLifecycle, Box<dyn std::error::Error + Send + Sync>> {
+    let Some((timeframe, bars)) = engine.get_strategy_warmup(strategy_id).await? else {
+        return Ok(StrategyLifecycle::WarmingUp);
+    };
+    let data_provider = state
+        .data_provider
+        .as_ref()
+        .ok_or("데이터 프로바이더 없음")?;
+    let tickers = engine.get_strategy_subscriptions(strategy_id).await?;
+
+    let mut klines = Vec::new();
+    for ticker in &tickers {
+        match data_provider.get_klines(ticker, timeframe, bars).await {
+            Ok(history) => klines.extend(history),
+            Err(e) => {
+                tracing::warn!(
+                    strategy_id = %strategy_id,
+                    ticker = %ticker,
+                    error = %e,
+                    "워밍업 캔들 조회 실패"
+                );
+            }
+        }
+    }
+    klines.sort_by_key(|k| k.open_time);
+
+    tracing::info!(
+        strategy_id = %strategy_id,
+        tickers = tickers.len(),
+        klines = klines.len(),
+        "과거 캔들로 전략 워밍업"
+    );
+    Ok(engine.warm_up_strategy(strategy_id, klines).await?)
+}
+
 /// 다중 타임프레임 데이터 자동 로드 (전략 시작 전).
 ///
 /// 전략의 설정에서 심볼 목록을 가져와 각 심볼에 대해
@@ -890,8 +1131,11 @@ pub async fn stop_strategy(
         .map(|s| s.name)
         .unwrap_or_else(|_| id.clone());
 
-    match engine.stop_strategy(&id).await {
-        Ok(()) => {
+    match engine
+        .apply_lifecycle_action(&id, LifecycleAction::Stop, API_ACTOR, "stop")
+        .await
+    {
+        Ok(_) => {
             // WebSocket 브로드캐스트: 전략 중지 알림
             state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
                 strategy_id: id.clone(),
@@ -913,6 +1157,139 @@ pub async fn stop_strategy(
     }
 }
 
+/// 전략 생명주기 조회.
+///
+/// GET /api/v1/strategies/{id}/lifecycle
+///
+/// DB가 연결되어 있으면 감사 로그 테이블에서, 아니면 엔진 메모리에서 전이 기록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/lifecycle",
+    tag = "strategies",
+    params(("id" = String, Path, description = "전략 ID")),
+    responses(
+        (status = 200, description = "생명주기 조회 성공", body = StrategyLifecycleResponse),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn get_strategy_lifecycle(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<StrategyLifecycleResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
+    let status = engine
+        .get_strategy_status(&id)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    let persisted = match state.db_pool.as_ref() {
+        Some(pool) => {
+            match StrategyLifecycleRepository::list_recent(
+                pool,
+                &id,
+                LIFECYCLE_HISTORY_LIMIT as i64,
+            )
+            .await
+            {
+                Ok(records) => Some(records),
+                Err(e) => {
+                    tracing::warn!(strategy_id = %id, error = %e, "생명주기 기록 조회 실패");
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+    let transitions = persisted.unwrap_or_else(|| {
+        engine
+            .recent_transitions(Some(&id), LIFECYCLE_HISTORY_LIMIT)
+            .iter()
+            .map(LifecycleEventRecord::from)
+            .collect()
+    });
+
+    Ok(Json(StrategyLifecycleResponse {
+        strategy_id: id,
+        lifecycle: status.lifecycle,
+        running: status.running,
+        transitions,
+    }))
+}
+
+/// 전략 생명주기 전환.
+///
+/// POST /api/v1/strategies/{id}/lifecycle
+///
+/// - `pause`: 시장 데이터는 계속 받지만 신호를 내보내지 않습니다.
+/// - `drain`: 신규 진입을 막고 청산 신호만 허용하며, 보유 포지션이 없어지면 중지됩니다.
+/// - `resume`: 일시 정지/청산 대기를 취소하고 실행 상태로 돌아갑니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/lifecycle",
+    tag = "strategies",
+    params(("id" = String, Path, description = "전략 ID")),
+    request_body = LifecycleActionRequest,
+    responses(
+        (status = 200, description = "생명주기 전환 성공", body = StrategyActionResponse),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError),
+        (status = 409, description = "현재 상태에서 허용되지 않는 전환", body = ApiError)
+    )
+)]
+pub async fn apply_strategy_lifecycle(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(request): Json<LifecycleActionRequest>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
+    let action = request.action;
+    let reason = request
+        .reason
+        .unwrap_or_else(|| action.as_str().to_string());
+
+    let strategy_name = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| s.name)
+        .map_err(engine_error_to_response)?;
+
+    let result = match action {
+        LifecycleAction::Start => start_with_warmup(&state, &engine, &id, &reason).await,
+        LifecycleAction::Drain => {
+            // 엔진이 모르는 기존 포지션도 청산 대상에 포함
+            let positions = state.executor.read().await.get_open_positions().await;
+            engine
+                .sync_strategy_positions(&id, &positions)
+                .await
+                .map_err(engine_error_to_response)?;
+            engine
+                .apply_lifecycle_action(&id, action, API_ACTOR, &reason)
+                .await
+        }
+        _ => {
+            engine
+                .apply_lifecycle_action(&id, action, API_ACTOR, &reason)
+                .await
+        }
+    };
+    let lifecycle = result.map_err(engine_error_to_response)?;
+
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.clone(),
+        name: strategy_name,
+        running: lifecycle.is_running(),
+        event: lifecycle.to_string(),
+        data: None,
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(StrategyActionResponse {
+        success: true,
+        strategy_id: id.clone(),
+        action: action.as_str().to_string(),
+        message: format!("Strategy '{}' is now {}", id, lifecycle),
+    }))
+}
+
 /// 전략 설정 변경.
 ///
 /// PUT /api/v1/strategies/{id}/config
@@ -933,6 +1310,7 @@ pub async fn update_config(
     Path(id): Path<String>,
     Json(request): Json<UpdateConfigRequest>,
 ) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    validate_position_sizing(&request.config)?;
     let engine = state.strategy_engine.read().await;
 
     // 전략 상태 가져오기 (브로드캐스트용)
@@ -1039,6 +1417,11 @@ pub async fn update_risk_settings(
         .map(|s| (s.name, s.running))
         .unwrap_or_else(|_| (id.clone(), false));
 
+    // 엔진에 로드된 전략이면 할당 자본 반영
+    if let Err(e) = engine.set_allocated_capital(&id, allocated_capital).await {
+        tracing::debug!(strategy_id = %id, "할당 자본 엔진 반영 생략: {}", e);
+    }
+
     // WebSocket 브로드캐스트: 리스크 설정 변경 알림
     state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
         strategy_id: id.clone(),
@@ -1265,6 +1648,9 @@ pub async fn clone_strategy(
                 Some(request.new_name.clone()),
             )
             .await;
+        let _ = engine
+            .set_allocated_capital(&new_id, allocated_capital)
+            .await;
     }
 
     // WebSocket 브로드캐스트: 전략 복사 알림
@@ -1292,6 +1678,256 @@ pub async fn clone_strategy(
     }))
 }
 
+/// 섀도 전략 목록 및 실전 대비 비교 조회.
+///
+/// GET /api/v1/strategies/{id}/shadows
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/shadows",
+    tag = "strategies",
+    params(("id" = String, Path, description = "실전 전략 ID")),
+    responses(
+        (status = 200, description = "섀도 전략 비교 조회 성공", body = ShadowListResponse),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn list_strategy_shadows(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<ShadowListResponse>, (StatusCode, Json<ApiError>)> {
+    let shadow_ids = {
+        let engine = state.strategy_engine.read().await;
+        engine
+            .get_strategy_status(&id)
+            .await
+            .map_err(engine_error_to_response)?;
+        engine.get_shadow_strategies(&id).await
+    };
+
+    let live_pnl = {
+        let executor = state.executor.read().await;
+        let tracker = executor.position_tracker().read().await;
+        tracker.pnl_by_strategy()
+    };
+
+    let mut shadows = Vec::with_capacity(shadow_ids.len());
+    for shadow_id in &shadow_ids {
+        if let Some(comparison) = state.shadow_book.compare(shadow_id, &live_pnl).await {
+            shadows.push(comparison);
+        }
+    }
+
+    Ok(Json(ShadowListResponse {
+        live_id: id,
+        shadows,
+    }))
+}
+
+/// 섀도 전략 생성 및 시작.
+///
+/// POST /api/v1/strategies/{id}/shadows
+///
+/// 실전 전략 설정에 `override_params`를 덮어쓴 섀도 전략을 만들어 실전 전략과 나란히 실행합니다.
+/// 섀도 전략의 신호는 주문으로 나가지 않고 가상 원장에서 체결되며, 메모리에만 유지됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategies/{id}/shadows",
+    tag = "strategies",
+    params(("id" = String, Path, description = "실전 전략 ID")),
+    request_body = CreateShadowRequest,
+    responses(
+        (status = 200, description = "섀도 전략 시작 성공", body = CreateShadowResponse),
+        (status = 400, description = "섀도 전략을 만들 수 없는 전략", body = ApiError),
+        (status = 404, description = "전략을 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn create_strategy_shadow(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(request): Json<CreateShadowRequest>,
+) -> Result<Json<CreateShadowResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
+    let status = engine
+        .get_strategy_status(&id)
+        .await
+        .map_err(engine_error_to_response)?;
+    let strategy_type = engine
+        .get_strategy_type(&id)
+        .await
+        .map_err(engine_error_to_response)?;
+    let mut config = engine
+        .get_strategy_config(&id)
+        .await
+        .map_err(engine_error_to_response)?;
+
+    // 파라미터 병합 (실전 + 오버라이드)
+    if let (Some(base_obj), Some(override_obj)) = (
+        config.as_object_mut(),
+        request.override_params.as_ref().and_then(Value::as_object),
+    ) {
+        for (key, value) in override_obj {
+            base_obj.insert(key.clone(), value.clone());
+        }
+    }
+
+    let strategy = create_strategy_instance(&strategy_type).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new("UNKNOWN_STRATEGY_TYPE", e)),
+        )
+    })?;
+    let shadow_id = format!("{}_{}", strategy_type, &Uuid::new_v4().to_string()[..8]);
+    let name = request
+        .name
+        .unwrap_or_else(|| format!("{} (shadow)", status.name));
+
+    engine
+        .register_shadow_strategy(&shadow_id, &id, strategy, config, Some(name.clone()))
+        .await
+        .map_err(engine_error_to_response)?;
+    state
+        .shadow_book
+        .open(&shadow_id, &id, request.initial_capital)
+        .await;
+
+    let reason = format!("shadow of {}", id);
+    let lifecycle = match start_with_warmup(&state, &engine, &shadow_id, &reason).await {
+        Ok(lifecycle) => lifecycle,
+        Err(e) => {
+            let _ = engine.unregister_strategy(&shadow_id).await;
+            state.shadow_book.close(&shadow_id).await;
+            return Err(engine_error_to_response(e));
+        }
+    };
+
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: shadow_id.clone(),
+        name,
+        running: lifecycle.is_running(),
+        event: "shadow_started".to_string(),
+        data: Some(serde_json::json!({ "live_id": id })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(CreateShadowResponse {
+        success: true,
+        live_id: id.clone(),
+        shadow_id: shadow_id.clone(),
+        lifecycle,
+        message: format!("Shadow '{}' of strategy '{}' started", shadow_id, id),
+    }))
+}
+
+/// 섀도 전략 중지 및 제거.
+///
+/// DELETE /api/v1/strategies/{id}/shadows/{shadow_id}
+#[utoipa::path(
+    delete,
+    path = "/api/v1/strategies/{id}/shadows/{shadow_id}",
+    tag = "strategies",
+    params(
+        ("id" = String, Path, description = "실전 전략 ID"),
+        ("shadow_id" = String, Path, description = "섀도 전략 ID")
+    ),
+    responses(
+        (status = 200, description = "섀도 전략 제거 성공", body = StrategyActionResponse),
+        (status = 404, description = "섀도 전략을 찾을 수 없음", body = ApiError)
+    )
+)]
+pub async fn delete_strategy_shadow(
+    State(state): State<Arc<AppState>>,
+    Path((id, shadow_id)): Path<(String, String)>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
+    if engine.get_shadow_target(&shadow_id).await.as_deref() != Some(id.as_str()) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new(
+                "SHADOW_NOT_FOUND",
+                format!("Shadow '{}' of strategy '{}' not found", shadow_id, id),
+            )),
+        ));
+    }
+
+    match engine.stop_strategy(&shadow_id).await {
+        Ok(()) | Err(EngineError::NotRunning(_)) => {}
+        Err(e) => return Err(engine_error_to_response(e)),
+    }
+    engine
+        .unregister_strategy(&shadow_id)
+        .await
+        .map_err(engine_error_to_response)?;
+    state.shadow_book.close(&shadow_id).await;
+
+    Ok(Json(StrategyActionResponse {
+        success: true,
+        strategy_id: shadow_id.clone(),
+        action: "delete_shadow".to_string(),
+        message: format!("Shadow '{}' of strategy '{}' removed", shadow_id, id),
+    }))
+}
+
+/// 전략 의사결정 로그 조회.
+///
+/// GET /api/v1/strategies/{id}/decisions
+///
+/// 캔들별 조건 평가 결과, 필터/생명주기/리스크 거부 사유, 주문 제출 결과를 조회합니다.
+/// DB가 연결되어 있으면 의사결정 테이블에서, 아니면 엔진 메모리에서 조회합니다.
+/// `trace_id`를 지정하면 해당 추적의 모든 단계를 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategies/{id}/decisions",
+    tag = "strategies",
+    params(("id" = String, Path, description = "전략 ID"), DecisionLogQuery),
+    responses(
+        (status = 200, description = "의사결정 로그 조회 성공", body = StrategyDecisionsResponse)
+    )
+)]
+pub async fn list_strategy_decisions(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(params): Query<DecisionLogQuery>,
+) -> Json<StrategyDecisionsResponse> {
+    let query = DecisionQuery {
+        strategy_id: Some(id.clone()),
+        ticker: params.ticker,
+        trace_id: params.trace_id,
+        from: params.from,
+        to: params.to,
+        limit: params
+            .limit
+            .unwrap_or(DEFAULT_DECISION_LIMIT)
+            .min(MAX_DECISION_LIMIT),
+    };
+
+    let persisted = match state.db_pool.as_ref() {
+        Some(pool) => match StrategyDecisionRepository::list(pool, &query).await {
+            Ok(records) => Some(records),
+            Err(e) => {
+                tracing::warn!(strategy_id = %id, error = %e, "의사결정 기록 조회 실패");
+                None
+            }
+        },
+        None => None,
+    };
+    let decisions = match persisted {
+        Some(records) => records,
+        None => {
+            let engine = state.strategy_engine.read().await;
+            engine
+                .query_decisions(&query)
+                .iter()
+                .map(DecisionEventRecord::from)
+                .collect()
+        }
+    };
+
+    Json(StrategyDecisionsResponse {
+        strategy_id: id,
+        decisions,
+    })
+}
+
 /// 엔진 통계 조회.
 ///
 /// GET /api/v1/strategies/stats
@@ -1553,10 +2189,22 @@ pub fn strategies_router() -> Router<Arc<AppState>> {
         .route("/{id}", get(get_strategy).delete(delete_strategy))
         .route("/{id}/start", post(start_strategy))
         .route("/{id}/stop", post(stop_strategy))
+        .route(
+            "/{id}/lifecycle",
+            get(get_strategy_lifecycle).post(apply_strategy_lifecycle),
+        )
         .route("/{id}/config", put(update_config))
         .route("/{id}/risk", put(update_risk_settings))
         .route("/{id}/symbols", put(update_symbols))
         .route("/{id}/clone", post(clone_strategy))
+        // 섀도(모의) 실행
+        .route(
+            "/{id}/shadows",
+            get(list_strategy_shadows).post(create_strategy_shadow),
+        )
+        .route("/{id}/shadows/{shadow_id}", delete(delete_strategy_shadow))
+        // 의사결정 로그
+        .route("/{id}/decisions", get(list_strategy_decisions))
         // 전략 스키마 (SDUI)
         .route("/{id}/schema", get(get_strategy_schema))
         // 다중 타임프레임 설정
@@ -0,0 +1,195 @@
+//! 드로다운 서킷 브레이커 서비스.
+//!
+//! 시작 시 저장된 서킷 브레이커 상태를 OrderExecutor의 RiskManager에 복원하고,
+//! `portfolio_equity_history`의 일별 총 자산을 주기적으로 공급해 드로다운 브레이커를 확인합니다.
+//! 상태가 바뀌면 DB에 저장하고, 새 브레이커(드로다운, 연속 손실)가 발동하면 텔레그램으로 알립니다.
+
+use chrono::Utc;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use trader_execution::OrderExecutor;
+use trader_notification::NotificationManager;
+use trader_risk::{BreakerKind, BreakerTrip, CircuitBreakerState, RiskManager};
+
+use crate::repository::{
+    EquityHistoryRepository, RiskCircuitBreakerRepository, LIVE_CIRCUIT_BREAKER_SCOPE,
+};
+
+/// 기본 갱신 주기.
+pub const DEFAULT_CIRCUIT_BREAKER_INTERVAL: Duration = Duration::from_secs(300);
+
+/// 이전 상태에 없던 브레이커.
+fn new_trips<'a>(before: &[BreakerTrip], after: &'a [BreakerTrip]) -> Vec<&'a BreakerTrip> {
+    after
+        .iter()
+        .filter(|trip| {
+            !before.iter().any(|b| {
+                b.kind == trip.kind
+                    && b.strategy_id == trip.strategy_id
+                    && b.tripped_at == trip.tripped_at
+            })
+        })
+        .collect()
+}
+
+/// 알림 제목.
+fn alert_type(kind: BreakerKind) -> &'static str {
+    match kind {
+        BreakerKind::PeakDrawdown | BreakerKind::WindowDrawdown => "드로다운 서킷 브레이커",
+        BreakerKind::ConsecutiveLosses => "연속 손실 서킷 브레이커",
+    }
+}
+
+/// 서킷 브레이커 상태 저장.
+pub async fn persist_circuit_breaker(pool: &PgPool, state: &CircuitBreakerState) {
+    if let Err(e) =
+        RiskCircuitBreakerRepository::save(pool, LIVE_CIRCUIT_BREAKER_SCOPE, state).await
+    {
+        tracing::warn!(error = %e, "서킷 브레이커 상태 저장 실패");
+    }
+}
+
+/// 최근 기간의 일별 총 자산을 리스크 매니저에 공급.
+async fn refresh_equity(risk_manager: &RwLock<RiskManager>, pool: &PgPool) {
+    let window_days = risk_manager.read().await.config().drawdown.window_days;
+    let end = Utc::now();
+    let start = end - chrono::Duration::days(i64::from(window_days));
+
+    match EquityHistoryRepository::get_aggregated_equity_curve(pool, start, end).await {
+        Ok(curve) => {
+            let mut risk_manager = risk_manager.write().await;
+            for point in curve {
+                risk_manager.update_equity(point.timestamp, point.equity);
+            }
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "서킷 브레이커용 자산 곡선 조회 실패");
+        }
+    }
+}
+
+/// 드로다운 서킷 브레이커 서비스 시작.
+///
+/// 저장된 상태를 먼저 복원한 뒤 백그라운드 태스크를 시작합니다.
+///
+/// # Arguments
+///
+/// * `executor` - 서킷 브레이커를 가진 RiskManager의 주문 실행기
+/// * `pool` - 자산 곡선 조회 및 상태 저장용 DB
+/// * `notifier` - 브레이커 발동 알림 전송기 (None이면 로그만 남김)
+/// * `interval` - 갱신 주기
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+pub async fn start_circuit_breaker_service(
+    executor: Arc<RwLock<OrderExecutor>>,
+    pool: PgPool,
+    notifier: Option<Arc<NotificationManager>>,
+    interval: Duration,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let risk_manager = executor.read().await.risk_manager().clone();
+
+    // 거래 시작 전에 상태 복원
+    match RiskCircuitBreakerRepository::load(&pool, LIVE_CIRCUIT_BREAKER_SCOPE).await {
+        Ok(Some(state)) => {
+            tracing::info!(active_trips = state.trips.len(), "서킷 브레이커 상태 복원");
+            risk_manager.write().await.restore_circuit_breaker(state);
+        }
+        Ok(None) => {}
+        Err(e) => {
+            tracing::warn!(error = %e, "서킷 브레이커 상태 복원 실패");
+        }
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        let mut saved = risk_manager.read().await.circuit_breaker().state().clone();
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    refresh_equity(&risk_manager, &pool).await;
+
+                    let state = risk_manager.read().await.circuit_breaker().state().clone();
+                    for trip in new_trips(&saved.trips, &state.trips) {
+                        tracing::warn!(
+                            kind = %trip.kind,
+                            strategy_id = ?trip.strategy_id,
+                            action = ?trip.action,
+                            "{}",
+                            trip.message()
+                        );
+                        if let Some(notifier) = &notifier {
+                            if let Err(e) = notifier
+                                .notify_risk_alert(
+                                    alert_type(trip.kind),
+                                    &trip.message(),
+                                    Decimal::from_f64(trip.value).unwrap_or_default().round_dp(2),
+                                    Decimal::from_f64(trip.limit).unwrap_or_default(),
+                                )
+                                .await
+                            {
+                                tracing::error!(error = %e, "서킷 브레이커 알림 전송 실패");
+                            }
+                        }
+                    }
+
+                    if state != saved {
+                        persist_circuit_breaker(&pool, &state).await;
+                        saved = state;
+                    }
+                }
+
+                _ = shutdown.cancelled() => {
+                    let state = risk_manager.read().await.circuit_breaker().state().clone();
+                    if state != saved {
+                        persist_circuit_breaker(&pool, &state).await;
+                    }
+                    tracing::info!("서킷 브레이커 서비스 종료");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use trader_risk::BreakerAction;
+
+    fn trip(kind: BreakerKind, strategy_id: Option<&str>, day: u32) -> BreakerTrip {
+        BreakerTrip {
+            kind,
+            strategy_id: strategy_id.map(str::to_string),
+            action: BreakerAction::Halt,
+            value: 12.0,
+            limit: 10.0,
+            tripped_at: Utc.with_ymd_and_hms(2024, 3, day, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_new_trips() {
+        let peak = trip(BreakerKind::PeakDrawdown, None, 1);
+        let losses = trip(BreakerKind::ConsecutiveLosses, Some("s1"), 2);
+
+        let before = vec![peak.clone()];
+        let after = vec![peak.clone(), losses.clone()];
+        assert_eq!(new_trips(&before, &after), vec![&losses]);
+
+        // 리셋 후 같은 종류가 다시 발동하면 새 브레이커
+        let retripped = trip(BreakerKind::PeakDrawdown, None, 3);
+        assert_eq!(
+            new_trips(&before, std::slice::from_ref(&retripped)),
+            vec![&retripped]
+        );
+        assert!(new_trips(&after, &before).is_empty());
+        assert_eq!(alert_type(losses.kind), "연속 손실 서킷 브레이커");
+    }
+}
@@ -0,0 +1,1313 @@
+//! 실시간 매매 런타임 서비스.
+//!
+//! StrategyEngine이 생성한 신호를 실제 주문으로 연결하고,
+//! 거래소 체결 이벤트를 다시 실행기와 전략에 전달합니다.
+//!
+//! # 처리 흐름
+//!
+//! ```text
+//! StrategyEngine ──Signal──▶ LiveTradingService ──OrderRequest──▶ Exchange
+//!       ▲                         │   ▲                               │
+//!       │ notify_order_filled     │   └──────── UserEvent ────────────┘
+//!       └─────────────────────────┘   (OrderUpdate → handle_fill_with_brackets)
+//! ```
+//!
+//! - 신호 → `OrderExecutor::process_signal_with_context` (SignalConverter + RiskManager 검증
+//!   + 컴플라이언스 검사: 가격제한폭 기준가와 최우선 매수 호가는 거래소 시세에서 전달)
+//! - 검증된 주문 → `Exchange::place_order` → `OrderExecutor::submit_order`
+//! - 체결 이벤트 → `OrderExecutor::handle_fill_with_brackets` → 손절/익절 제출 또는 OCO 취소
+//! - 완전 체결 → `StrategyEngine::notify_order_filled`
+//! - 포지션 청산 완료 → 남아있는 손절/익절 주문 취소
+//! - 목표 포트폴리오 신호 → 보유 현황과 비교해 종목별 신호로 분해 (매도 먼저) 후 동일하게 처리
+//! - 신호별 리스크 거부/주문 제출 결과 → 전략 엔진 의사결정 로그 (신호의 추적 ID로 연결)
+//!
+//! 사용자 스트림이 없는 거래소는 미체결 주문을 주기적으로 폴링하여 동일하게 처리합니다.
+//! 워커 태스크가 패닉하면 supervisor가 신호 채널을 유지한 채 재시작합니다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use tokio::sync::{mpsc, Mutex, RwLock};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use trader_core::{
+    Order, OrderRequest, OrderStatus, OrderStatusType, OrderType, Side, Signal, SignalType,
+};
+use trader_exchange::{Exchange, UserEvent, UserStream};
+use trader_execution::{BracketFillResult, OrderExecutor, OrderFill};
+use trader_risk::ComplianceContext;
+use trader_strategy::strategies::common::rebalance::{
+    PortfolioPosition, RebalanceCalculator, RebalanceConfig,
+};
+use trader_strategy::{
+    signal_instance_id, DecisionOutcome, DecisionRecord, DecisionStage, StrategyEngine,
+};
+
+/// 주문 수량 소수점 자릿수 (거래소 최소 단위보다 세밀하게 유지).
+const QUANTITY_DECIMALS: u32 = 8;
+
+/// 실시간 매매 런타임 설정.
+#[derive(Debug, Clone)]
+pub struct LiveTradingConfig {
+    /// 사용자 스트림이 없을 때 미체결 주문 폴링 주기
+    pub poll_interval: Duration,
+    /// 워커 패닉 후 재시작 대기 시간
+    pub restart_backoff: Duration,
+    /// 최대 재시작 횟수 (None이면 무제한)
+    pub max_restarts: Option<u32>,
+    /// 목표 포트폴리오 신호 처리 설정 (`cash_ticker`는 거래소 현금 자산, 예: "KRW", "USDT")
+    pub rebalance: RebalanceConfig,
+}
+
+impl Default for LiveTradingConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(2),
+            restart_backoff: Duration::from_secs(1),
+            max_restarts: None,
+            rebalance: RebalanceConfig::default(),
+        }
+    }
+}
+
+/// 워커 종료 사유.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WorkerExit {
+    /// 종료 토큰 취소
+    Shutdown,
+    /// 신호 채널 닫힘 (StrategyEngine 해제)
+    SignalChannelClosed,
+}
+
+/// 워커 재시작 간에 유지되는 입력 채널.
+struct LiveTradingChannels {
+    signal_rx: mpsc::Receiver<Signal>,
+    user_stream: Option<Box<dyn UserStream>>,
+}
+
+/// 실시간 매매 런타임.
+///
+/// 신호 수신, 주문 제출, 체결 처리를 단일 워커에서 순차적으로 수행합니다.
+/// 순차 처리로 인해 주문 제출 직후 도착한 체결 이벤트도 항상
+/// 거래소 주문 ID 매핑이 끝난 뒤에 처리됩니다.
+pub struct LiveTradingService {
+    strategy_engine: Arc<RwLock<StrategyEngine>>,
+    executor: Arc<RwLock<OrderExecutor>>,
+    exchange: Arc<dyn Exchange>,
+    config: LiveTradingConfig,
+}
+
+impl LiveTradingService {
+    /// 새 서비스 인스턴스 생성.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy_engine` - 신호를 생성하고 체결 알림을 받을 전략 엔진
+    /// * `executor` - 신호→주문 변환, 리스크 검증, 포지션 추적
+    /// * `exchange` - 주문을 제출할 거래소
+    /// * `config` - 런타임 설정
+    pub fn new(
+        strategy_engine: Arc<RwLock<StrategyEngine>>,
+        executor: Arc<RwLock<OrderExecutor>>,
+        exchange: Arc<dyn Exchange>,
+        config: LiveTradingConfig,
+    ) -> Self {
+        Self {
+            strategy_engine,
+            executor,
+            exchange,
+            config,
+        }
+    }
+
+    /// 서비스 시작 (supervisor 루프).
+    ///
+    /// 워커 태스크를 실행하고, 패닉으로 종료되면 `restart_backoff` 후 재시작합니다.
+    /// 종료 토큰이 취소되거나 신호 채널이 닫히면 반환합니다.
+    pub async fn run(
+        self,
+        signal_rx: mpsc::Receiver<Signal>,
+        user_stream: Option<Box<dyn UserStream>>,
+        shutdown: CancellationToken,
+    ) {
+        let service = Arc::new(self);
+        let channels = Arc::new(Mutex::new(LiveTradingChannels {
+            signal_rx,
+            user_stream,
+        }));
+        let mut restarts: u32 = 0;
+
+        loop {
+            let worker = {
+                let service = service.clone();
+                let channels = channels.clone();
+                let shutdown = shutdown.clone();
+                tokio::spawn(async move { service.worker(channels, shutdown).await })
+            };
+
+            match worker.await {
+                Ok(WorkerExit::Shutdown) => {
+                    info!("LiveTradingService 종료");
+                    break;
+                }
+                Ok(WorkerExit::SignalChannelClosed) => {
+                    warn!("신호 채널이 닫혀 LiveTradingService 종료");
+                    break;
+                }
+                Err(e) if e.is_panic() => {
+                    restarts += 1;
+                    error!(restarts, "실시간 매매 워커 패닉, 재시작 예정");
+
+                    if restart_limit_reached(service.config.max_restarts, restarts) {
+                        error!(restarts, "최대 재시작 횟수 초과, LiveTradingService 중단");
+                        break;
+                    }
+
+                    tokio::select! {
+                        _ = tokio::time::sleep(service.config.restart_backoff) => {}
+                        _ = shutdown.cancelled() => break,
+                    }
+                }
+                Err(e) => {
+                    error!("실시간 매매 워커 비정상 종료: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// 워커 메인 루프.
+    async fn worker(
+        &self,
+        channels: Arc<Mutex<LiveTradingChannels>>,
+        shutdown: CancellationToken,
+    ) -> WorkerExit {
+        let mut guard = channels.lock().await;
+        let LiveTradingChannels {
+            signal_rx,
+            user_stream,
+        } = &mut *guard;
+
+        if let Some(stream) = user_stream.as_mut() {
+            if let Err(e) = stream.start().await {
+                warn!("사용자 스트림 시작 실패, 주문 폴링으로 전환: {}", e);
+                *user_stream = None;
+            }
+        }
+
+        let mut poll_ticker = tokio::time::interval(self.config.poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => {
+                    if let Some(stream) = user_stream.as_mut() {
+                        let _ = stream.stop().await;
+                    }
+                    return WorkerExit::Shutdown;
+                }
+
+                signal = signal_rx.recv() => match signal {
+                    Some(signal) => self.handle_signal(&signal).await,
+                    None => return WorkerExit::SignalChannelClosed,
+                },
+
+                event = next_user_event(user_stream) => match event {
+                    Some(event) => self.handle_user_event(event).await,
+                    None => {
+                        warn!("사용자 스트림 종료, 주문 폴링으로 전환");
+                        *user_stream = None;
+                    }
+                },
+
+                _ = poll_ticker.tick(), if user_stream.is_none() => {
+                    self.poll_open_orders().await;
+                }
+            }
+        }
+    }
+
+    // ==================== 신호 처리 ====================
+
+    /// 전략 신호를 주문으로 변환하여 거래소에 제출.
+    pub(crate) async fn handle_signal(&self, signal: &Signal) {
+        if signal.signal_type == SignalType::Alert {
+            debug!(signal_id = %signal.id, "Alert 신호는 주문으로 변환하지 않음");
+            return;
+        }
+
+        if signal.is_rebalance() {
+            // 매도 주문이 먼저 제출되도록 순서대로 처리
+            for child in self.expand_rebalance(signal).await {
+                self.execute_signal(&child).await;
+            }
+            return;
+        }
+
+        self.execute_signal(signal).await;
+    }
+
+    /// 목표 포트폴리오 신호를 현재 보유 현황 기준의 종목별 신호로 분해.
+    ///
+    /// 신호를 낸 전략의 롱 포지션과 할당 자본을 포트폴리오로 간주합니다.
+    /// 현금은 할당 자본에서 보유 평가액을 뺀 금액으로, 거래소의 `cash_ticker`
+    /// 가용 잔고를 넘지 않습니다. 주문 수량은 리스크 매니저의 수량 단위로 맞춥니다.
+    async fn expand_rebalance(&self, signal: &Signal) -> Vec<Signal> {
+        let instance_id = signal_instance_id(signal).unwrap_or(&signal.strategy_id);
+        let allocated = self
+            .strategy_engine
+            .read()
+            .await
+            .get_allocated_capital(instance_id)
+            .await;
+        let Some(allocated) = allocated else {
+            warn!(
+                strategy_id = %signal.strategy_id,
+                "할당 자본이 없는 전략의 목표 포트폴리오 신호 무시"
+            );
+            self.record_decision(
+                DecisionRecord::for_signal(signal, DecisionStage::Risk, DecisionOutcome::Rejected)
+                    .with_rejection("NoAllocatedCapital", "전략에 할당된 자본이 없음"),
+            )
+            .await;
+            return Vec::new();
+        };
+
+        let cash_ticker = &self.config.rebalance.cash_ticker;
+        let free_cash = match self.exchange.get_balance(cash_ticker).await {
+            Ok(balance) => balance.free,
+            Err(e) => {
+                warn!(cash_ticker = %cash_ticker, "현금 잔고 조회 실패, 리밸런싱 신호 무시: {}", e);
+                return Vec::new();
+            }
+        };
+
+        // 같은 계좌의 다른 전략 포지션은 제외
+        let (held, lot_steps) = {
+            let executor = self.executor.read().await;
+            let held: Vec<_> = executor
+                .get_open_positions()
+                .await
+                .into_iter()
+                .filter(|p| {
+                    p.side == Side::Buy && p.strategy_id.as_deref() == Some(&signal.strategy_id)
+                })
+                .collect();
+            let risk_manager = executor.risk_manager().read().await;
+            let lot_steps: HashMap<String, Decimal> = held
+                .iter()
+                .map(|p| &p.ticker)
+                .chain(signal.target_weights.keys())
+                .map(|ticker| (ticker.clone(), risk_manager.lot_step(ticker)))
+                .collect();
+            (held, lot_steps)
+        };
+
+        let mut prices = HashMap::new();
+        let tickers = held
+            .iter()
+            .map(|p| &p.ticker)
+            .chain(signal.target_weights.keys());
+        for ticker in tickers {
+            if prices.contains_key(ticker) {
+                continue;
+            }
+            match self.exchange.get_ticker(ticker).await {
+                Ok(quote) => {
+                    prices.insert(ticker.clone(), quote.last);
+                }
+                Err(e) => warn!(ticker = %ticker, "현재가 조회 실패: {}", e),
+            }
+        }
+
+        let mut positions: Vec<PortfolioPosition> = held
+            .iter()
+            .map(|p| {
+                let price = prices.get(&p.ticker).copied().unwrap_or(p.current_price);
+                PortfolioPosition::new(p.ticker.clone(), p.quantity, price)
+            })
+            .collect();
+
+        // 현금 = 할당 자본 - 보유 평가액 (계좌 가용 현금 한도 내)
+        let held_value: Decimal = positions.iter().map(|p| p.market_value).sum();
+        let cash = (allocated - held_value).max(Decimal::ZERO).min(free_cash);
+        positions.push(PortfolioPosition::cash(cash, cash_ticker.clone()));
+
+        let signals = RebalanceCalculator::new(self.config.rebalance.clone())
+            .with_lot_steps(lot_steps)
+            .expand_target_signal(signal, &positions, &prices);
+        info!(
+            signal_id = %signal.id,
+            strategy_id = %signal.strategy_id,
+            orders = signals.len(),
+            "목표 포트폴리오 신호 분해"
+        );
+        signals
+    }
+
+    /// 종목 신호를 검증하고 주문을 제출.
+    async fn execute_signal(&self, signal: &Signal) {
+        let executor = self.executor.read().await;
+
+        if !executor.can_trade().await {
+            warn!(
+                strategy_id = %signal.strategy_id,
+                ticker = %signal.ticker,
+                "거래 불가 상태 (일일 손실 한도 등), 신호 무시"
+            );
+            self.record_decision(
+                DecisionRecord::for_signal(signal, DecisionStage::Risk, DecisionOutcome::Rejected)
+                    .with_rejection("TradingHalt", "거래 불가 상태 (일일 손실 한도 등)"),
+            )
+            .await;
+            return;
+        }
+
+        // 제안 가격이 없거나 컴플라이언스 검사에 시세가 필요하면 조회
+        let compliance_enabled = !executor.risk_manager().read().await.compliance().is_empty();
+        let quote = if signal.suggested_price.is_none() || compliance_enabled {
+            match self.exchange.get_ticker(&signal.ticker).await {
+                Ok(ticker) => Some(ticker),
+                Err(e) => {
+                    warn!(ticker = %signal.ticker, "현재가 조회 실패: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let Some(price) = signal
+            .suggested_price
+            .or_else(|| quote.as_ref().map(|ticker| ticker.last))
+        else {
+            warn!(ticker = %signal.ticker, "현재가 없음, 신호 무시");
+            return;
+        };
+
+        let mut context = ComplianceContext::default();
+        if let Some(ticker) = &quote {
+            // 전일 대비 변동으로 가격제한폭 기준가(전일 종가) 계산
+            context = context.with_reference_price(ticker.last - ticker.change_24h);
+            if ticker.bid > Decimal::ZERO {
+                context = context.with_best_bid(ticker.bid);
+            }
+        }
+
+        let Some(quantity) = resolve_quantity(&executor, signal, price).await else {
+            debug!(
+                signal_id = %signal.id,
+                ticker = %signal.ticker,
+                signal_type = ?signal.signal_type,
+                "주문 수량이 0, 신호 무시"
+            );
+            return;
+        };
+
+        let result = executor
+            .process_signal_with_context(signal, price, Some(quantity), context)
+            .await;
+
+        if !result.success {
+            warn!(
+                signal_id = %signal.id,
+                strategy_id = %signal.strategy_id,
+                ticker = %signal.ticker,
+                error = ?result.error,
+                notes = ?result.notes,
+                "신호 실행 거부"
+            );
+            let stage = match result.rejected_by.as_deref() {
+                Some("RiskManager") | Some("Compliance") => DecisionStage::Risk,
+                _ => DecisionStage::Execution,
+            };
+            self.record_decision(
+                DecisionRecord::for_signal(signal, stage, DecisionOutcome::Rejected)
+                    .with_rejection(
+                        result.rejected_by.as_deref().unwrap_or("OrderExecutor"),
+                        result.error.clone().unwrap_or_default(),
+                    ),
+            )
+            .await;
+            return;
+        }
+
+        let (Some(order_id), Some(request)) = (result.order_id, result.order.as_ref()) else {
+            return;
+        };
+
+        let record = if let Some(exchange_order_id) =
+            self.submit_order(&executor, order_id, request).await
+        {
+            info!(
+                order_id = %order_id,
+                exchange_order_id = %exchange_order_id,
+                strategy_id = %signal.strategy_id,
+                ticker = %request.ticker,
+                side = ?request.side,
+                quantity = %request.quantity,
+                "신호 주문 제출"
+            );
+            DecisionRecord::for_signal(signal, DecisionStage::Execution, DecisionOutcome::Submitted)
+                .with_reason(format!("order {} → {}", order_id, exchange_order_id))
+        } else {
+            DecisionRecord::for_signal(signal, DecisionStage::Execution, DecisionOutcome::Rejected)
+                .with_rejection("Exchange", format!("order {} 거래소 제출 실패", order_id))
+        };
+        self.record_decision(record).await;
+    }
+
+    /// 신호 처리 결과를 전략 엔진의 의사결정 로그에 기록.
+    async fn record_decision(&self, record: DecisionRecord) {
+        let engine = self.strategy_engine.read().await;
+        engine.decision_log().record(record);
+    }
+
+    /// 등록된 주문을 거래소에 제출.
+    ///
+    /// 실패하면 주문을 거부 상태로 기록하고 `None`을 반환합니다.
+    async fn submit_order(
+        &self,
+        executor: &OrderExecutor,
+        order_id: Uuid,
+        request: &OrderRequest,
+    ) -> Option<String> {
+        match self.exchange.place_order(request).await {
+            Ok(exchange_order_id) => {
+                if let Err(e) = executor
+                    .submit_order(order_id, exchange_order_id.clone())
+                    .await
+                {
+                    error!(order_id = %order_id, "주문 상태 갱신 실패: {}", e);
+                }
+                Some(exchange_order_id)
+            }
+            Err(e) => {
+                error!(
+                    order_id = %order_id,
+                    ticker = %request.ticker,
+                    "거래소 주문 제출 실패: {}",
+                    e
+                );
+                let mut order_manager = executor.order_manager().write().await;
+                let _ = order_manager.reject_order(order_id, e.to_string());
+                None
+            }
+        }
+    }
+
+    // ==================== 체결 처리 ====================
+
+    /// 사용자 스트림 이벤트 처리.
+    pub(crate) async fn handle_user_event(&self, event: UserEvent) {
+        match event {
+            UserEvent::OrderUpdate(status) => self.handle_order_update(status).await,
+            UserEvent::PositionUpdate(position) => {
+                let engine = self.strategy_engine.read().await;
+                if let Err(e) = engine.notify_position_update(&position).await {
+                    warn!("포지션 업데이트 알림 실패: {}", e);
+                }
+            }
+            UserEvent::BalanceUpdate(balance) => {
+                debug!(asset = %balance.asset, free = %balance.free, "잔고 업데이트");
+            }
+        }
+    }
+
+    /// 거래소 주문 상태 업데이트 처리.
+    async fn handle_order_update(&self, status: OrderStatus) {
+        let executor = self.executor.read().await;
+
+        let order = {
+            let order_manager = executor.order_manager().read().await;
+            order_manager
+                .get_order_by_exchange_id(&status.order_id)
+                .cloned()
+        };
+
+        let Some(order) = order else {
+            debug!(exchange_order_id = %status.order_id, "추적하지 않는 주문 업데이트 무시");
+            return;
+        };
+
+        if order.status.is_final() {
+            return;
+        }
+
+        match status.status {
+            OrderStatusType::Filled | OrderStatusType::PartiallyFilled => {
+                self.apply_fill(&executor, &order, &status).await;
+            }
+            OrderStatusType::Cancelled | OrderStatusType::Expired => {
+                if let Err(e) = executor
+                    .cancel_order(order.id, Some(format!("exchange: {:?}", status.status)))
+                    .await
+                {
+                    warn!(order_id = %order.id, "주문 취소 반영 실패: {}", e);
+                }
+            }
+            OrderStatusType::Rejected => {
+                let mut order_manager = executor.order_manager().write().await;
+                let _ = order_manager.reject_order(order.id, "rejected by exchange");
+            }
+            OrderStatusType::Pending | OrderStatusType::Open => {}
+        }
+    }
+
+    /// 누적 체결 수량의 증분을 실행기에 반영하고 브라켓 주문을 처리.
+    async fn apply_fill(&self, executor: &OrderExecutor, order: &Order, status: &OrderStatus) {
+        let delta = status.filled_quantity - order.filled_quantity;
+        if delta <= Decimal::ZERO {
+            return;
+        }
+
+        let Some(price) = status.average_price.or(order.price).or(order.stop_price) else {
+            warn!(order_id = %order.id, "체결 가격 정보 없음, 체결 무시");
+            return;
+        };
+
+        let fill = OrderFill {
+            order_id: order.id,
+            quantity: delta,
+            price,
+            commission: None,
+            commission_asset: None,
+            timestamp: status.updated_at,
+        };
+        let is_complete = status.status == OrderStatusType::Filled;
+
+        match executor
+            .handle_fill_with_brackets(order.id, fill, is_complete)
+            .await
+        {
+            Ok(BracketFillResult::SubmitBracket {
+                parent_order_id,
+                stop_loss,
+                take_profit,
+            }) => {
+                if let Some(request) = stop_loss {
+                    self.submit_bracket_child(executor, order, parent_order_id, request, true)
+                        .await;
+                }
+                if let Some(request) = take_profit {
+                    self.submit_bracket_child(executor, order, parent_order_id, request, false)
+                        .await;
+                }
+            }
+            Ok(BracketFillResult::CancelOther {
+                filled_order_id,
+                cancel_order_id,
+            }) => {
+                self.cancel_bracket_sibling(executor, filled_order_id, cancel_order_id)
+                    .await;
+            }
+            Ok(BracketFillResult::None) => {}
+            Err(e) => {
+                error!(order_id = %order.id, "체결 처리 실패: {}", e);
+                return;
+            }
+        }
+
+        if is_complete {
+            if executor.get_position(&order.ticker).await.is_none() {
+                self.cancel_orphaned_exits(executor, order).await;
+            }
+            self.notify_filled(executor, order).await;
+        }
+    }
+
+    /// 손절/익절 주문을 등록하고 거래소에 제출.
+    async fn submit_bracket_child(
+        &self,
+        executor: &OrderExecutor,
+        parent: &Order,
+        parent_order_id: Uuid,
+        mut request: OrderRequest,
+        is_stop_loss: bool,
+    ) {
+        request.strategy_id = parent.strategy_id.clone();
+
+        let child = Order::from_request(request.clone(), executor.exchange());
+        let child_id = child.id;
+
+        {
+            let mut order_manager = executor.order_manager().write().await;
+            if let Err(e) = order_manager.add_order(child) {
+                error!(parent_order_id = %parent_order_id, "브라켓 주문 등록 실패: {}", e);
+                return;
+            }
+        }
+
+        if self
+            .submit_order(executor, child_id, &request)
+            .await
+            .is_some()
+        {
+            executor
+                .register_bracket_child(parent_order_id, child_id, is_stop_loss)
+                .await;
+            info!(
+                parent_order_id = %parent_order_id,
+                child_order_id = %child_id,
+                is_stop_loss,
+                trigger_price = ?request.stop_price,
+                "브라켓 주문 제출"
+            );
+        }
+    }
+
+    /// OCO: 체결된 브라켓 주문의 반대편 주문 취소.
+    async fn cancel_bracket_sibling(
+        &self,
+        executor: &OrderExecutor,
+        filled_order_id: Uuid,
+        cancel_order_id: Uuid,
+    ) {
+        let Some(sibling) = executor.get_order(cancel_order_id).await else {
+            return;
+        };
+
+        if let Some(exchange_order_id) = sibling.exchange_order_id.as_deref() {
+            if let Err(e) = self
+                .exchange
+                .cancel_order(&sibling.ticker, exchange_order_id)
+                .await
+            {
+                warn!(
+                    order_id = %cancel_order_id,
+                    exchange_order_id,
+                    "OCO 주문 거래소 취소 실패: {}",
+                    e
+                );
+            }
+        }
+
+        if let Err(e) = executor
+            .cancel_order(
+                cancel_order_id,
+                Some(format!("OCO: {} filled", filled_order_id)),
+            )
+            .await
+        {
+            warn!(order_id = %cancel_order_id, "OCO 주문 취소 반영 실패: {}", e);
+        }
+    }
+
+    /// 포지션이 모두 청산된 뒤 남아있는 손절/익절 주문 취소.
+    ///
+    /// 전략의 청산 신호로 포지션이 먼저 정리되면 브라켓 주문이 거래소에 남아
+    /// 나중에 트리거될 때 반대 포지션을 새로 만들게 되므로 함께 취소합니다.
+    async fn cancel_orphaned_exits(&self, executor: &OrderExecutor, closing: &Order) {
+        let orphans: Vec<Order> = executor
+            .get_active_orders()
+            .await
+            .into_iter()
+            .filter(|o| o.ticker == closing.ticker && o.id != closing.id)
+            .filter(|o| {
+                matches!(
+                    o.order_type,
+                    OrderType::StopLoss
+                        | OrderType::StopLossLimit
+                        | OrderType::TakeProfit
+                        | OrderType::TakeProfitLimit
+                        | OrderType::TrailingStop
+                )
+            })
+            .collect();
+
+        for orphan in orphans {
+            if let Some(exchange_order_id) = orphan.exchange_order_id.as_deref() {
+                if let Err(e) = self
+                    .exchange
+                    .cancel_order(&orphan.ticker, exchange_order_id)
+                    .await
+                {
+                    warn!(
+                        order_id = %orphan.id,
+                        exchange_order_id,
+                        "잔여 청산 주문 거래소 취소 실패: {}",
+                        e
+                    );
+                }
+            }
+
+            if let Err(e) = executor
+                .cancel_order(
+                    orphan.id,
+                    Some(format!("position closed by {}", closing.id)),
+                )
+                .await
+            {
+                warn!(order_id = %orphan.id, "잔여 청산 주문 취소 반영 실패: {}", e);
+            }
+        }
+    }
+
+    /// 완전 체결된 주문과 갱신된 포지션을 전략에 알림.
+    async fn notify_filled(&self, executor: &OrderExecutor, order: &Order) {
+        let filled = executor.get_order(order.id).await;
+        let position = executor.get_position(&order.ticker).await;
+
+        let engine = self.strategy_engine.read().await;
+        if let Some(filled) = filled {
+            if let Err(e) = engine.notify_order_filled(&filled).await {
+                warn!(order_id = %order.id, "체결 알림 실패: {}", e);
+            }
+        }
+        if let Some(position) = position {
+            if let Err(e) = engine.notify_position_update(&position).await {
+                warn!(ticker = %order.ticker, "포지션 업데이트 알림 실패: {}", e);
+            }
+        }
+    }
+
+    /// 사용자 스트림이 없을 때 미체결 주문 상태를 거래소에서 조회.
+    async fn poll_open_orders(&self) {
+        let active: Vec<Order> = {
+            let executor = self.executor.read().await;
+            executor.get_active_orders().await
+        };
+
+        for order in active {
+            let Some(exchange_order_id) = order.exchange_order_id.as_deref() else {
+                continue;
+            };
+
+            match self
+                .exchange
+                .get_order(&order.ticker, exchange_order_id)
+                .await
+            {
+                Ok(status) => self.handle_order_update(status).await,
+                Err(e) => debug!(exchange_order_id, "주문 상태 조회 실패: {}", e),
+            }
+        }
+    }
+}
+
+/// 사용자 스트림의 다음 이벤트 대기 (스트림이 없으면 영원히 대기).
+async fn next_user_event(stream: &mut Option<Box<dyn UserStream>>) -> Option<UserEvent> {
+    match stream {
+        Some(stream) => stream.next_event().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// 재시작 한도 도달 여부.
+fn restart_limit_reached(max_restarts: Option<u32>, restarts: u32) -> bool {
+    max_restarts.is_some_and(|max| restarts > max)
+}
+
+/// 신호의 주문 수량 결정.
+///
+/// 1. 메타데이터 `quantity`가 있으면 그대로 사용
+/// 2. 청산(Exit)은 보유 수량 전체, 축소(ReducePosition)는 보유 수량 × strength
+/// 3. 진입/추가는 `RiskManager::calculate_target_size` × strength ÷ 가격
+///    (`RiskConfig::position_sizing` 방식, 최대 포지션 크기로 제한)
+///
+/// 청산 방향 주문은 보유 수량을 넘지 않도록 제한합니다.
+async fn resolve_quantity(
+    executor: &OrderExecutor,
+    signal: &Signal,
+    price: Decimal,
+) -> Option<Decimal> {
+    let position = executor.get_position(&signal.ticker).await;
+    let closing_qty = position
+        .as_ref()
+        .filter(|p| p.side != signal.side)
+        .map(|p| p.quantity);
+    let strength = Decimal::from_f64_retain(signal.strength).unwrap_or(Decimal::ONE);
+
+    let quantity = if let Some(qty) = signal.metadata_quantity() {
+        match closing_qty {
+            Some(held) => qty.min(held),
+            None => qty,
+        }
+    } else {
+        match signal.signal_type {
+            SignalType::Exit => closing_qty?,
+            SignalType::ReducePosition => closing_qty? * strength,
+            SignalType::Scale if closing_qty.is_some() => closing_qty? * strength,
+            SignalType::Entry | SignalType::AddToPosition | SignalType::Scale => {
+                if price <= Decimal::ZERO {
+                    return None;
+                }
+                let target_value = {
+                    let risk_manager = executor.risk_manager().read().await;
+                    risk_manager.calculate_target_size(&signal.ticker)
+                };
+                target_value * strength / price
+            }
+            SignalType::Alert | SignalType::Rebalance => return None,
+        }
+    };
+
+    let quantity = quantity.round_dp_with_strategy(QUANTITY_DECIMALS, RoundingStrategy::ToZero);
+    (quantity > Decimal::ZERO).then_some(quantity)
+}
+
+/// LiveTradingService를 백그라운드 task로 시작.
+///
+/// StrategyEngine의 신호 수신기를 가져오므로 엔진당 한 번만 호출할 수 있습니다.
+///
+/// # Arguments
+///
+/// * `strategy_engine` - 전략 엔진
+/// * `executor` - 주문 실행기
+/// * `exchange` - 주문을 제출할 거래소
+/// * `user_stream` - 체결 이벤트 스트림 (None이면 주문 상태 폴링)
+/// * `config` - 런타임 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle. 신호 수신기를 이미 가져간 경우 None.
+pub async fn start_live_trading_service(
+    strategy_engine: Arc<RwLock<StrategyEngine>>,
+    executor: Arc<RwLock<OrderExecutor>>,
+    exchange: Arc<dyn Exchange>,
+    user_stream: Option<Box<dyn UserStream>>,
+    config: LiveTradingConfig,
+    shutdown: CancellationToken,
+) -> Option<tokio::task::JoinHandle<()>> {
+    let signal_rx = strategy_engine.write().await.take_signal_receiver()?;
+    let service = LiveTradingService::new(strategy_engine, executor, exchange, config);
+
+    Some(tokio::spawn(async move {
+        service.run(signal_rx, user_stream, shutdown).await;
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{Duration as ChronoDuration, Utc};
+    use rust_decimal_macros::dec;
+    use serde_json::{json, Value};
+    use trader_core::{Kline, MarketData, Position, Side, Timeframe};
+    use trader_exchange::{SimulatedConfig, SimulatedExchange};
+    use trader_execution::ConversionConfig;
+    use trader_risk::{RiskConfig, RiskManager};
+    use trader_strategy::{DecisionQuery, EngineConfig, Strategy, StrategyLifecycle};
+
+    const TICKER: &str = "BTC/USDT";
+
+    /// 첫 데이터에서 한 번만 매수 신호를 내는 테스트 전략.
+    struct OneShotStrategy {
+        fired: bool,
+    }
+
+    #[async_trait]
+    impl Strategy for OneShotStrategy {
+        fn name(&self) -> &str {
+            "one_shot"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Test strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            if self.fired {
+                return Ok(vec![]);
+            }
+            self.fired = true;
+            Ok(vec![Signal::entry(
+                "one_shot",
+                data.ticker.clone(),
+                Side::Buy,
+            )
+            .with_metadata("quantity", json!("0.1"))])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            json!({ "fired": self.fired })
+        }
+    }
+
+    fn kline(index: i64, high: Decimal, low: Decimal, close: Decimal) -> Kline {
+        let open_time = Utc::now() - ChronoDuration::minutes(10 - index);
+        Kline::new(
+            TICKER.to_string(),
+            Timeframe::M1,
+            open_time,
+            dec!(50000),
+            high,
+            low,
+            close,
+            dec!(10),
+            open_time + ChronoDuration::minutes(1),
+        )
+    }
+
+    /// 조건이 참이 될 때까지 최대 2초 대기.
+    async fn wait_for<F, Fut>(mut condition: F) -> bool
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = bool>,
+    {
+        for _ in 0..200 {
+            if condition().await {
+                return true;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        false
+    }
+
+    #[tokio::test]
+    async fn test_live_trading_end_to_end_with_simulated_exchange() {
+        // 거래소: 첫 봉은 진입, 두 번째 봉에서 익절(5%) 도달
+        let exchange = Arc::new(SimulatedExchange::new(
+            SimulatedConfig::default().with_initial_balance("USDT", dec!(100000)),
+        ));
+        exchange
+            .load_klines(
+                TICKER.to_string(),
+                Timeframe::M1,
+                vec![
+                    kline(0, dec!(50000), dec!(50000), dec!(50000)),
+                    kline(1, dec!(55000), dec!(50000), dec!(54000)),
+                ],
+            )
+            .await;
+        let first = exchange.step(TICKER, Timeframe::M1).await.unwrap();
+        let user_stream = exchange.create_user_stream().await;
+
+        // 전략 엔진 + 실행기
+        let engine = Arc::new(RwLock::new(StrategyEngine::new(EngineConfig::default())));
+        {
+            let engine = engine.read().await;
+            engine
+                .register_strategy(
+                    "one_shot",
+                    Box::new(OneShotStrategy { fired: false }),
+                    json!({}),
+                    None,
+                )
+                .await
+                .unwrap();
+            engine.start_strategy("one_shot").await.unwrap();
+        }
+        let executor = Arc::new(RwLock::new(OrderExecutor::new_complete(
+            RiskManager::new(RiskConfig::default(), dec!(100000)),
+            "simulated",
+            ConversionConfig::default(),
+        )));
+
+        let shutdown = CancellationToken::new();
+        let handle = start_live_trading_service(
+            engine.clone(),
+            executor.clone(),
+            exchange.clone(),
+            Some(Box::new(user_stream)),
+            LiveTradingConfig::default(),
+            shutdown.clone(),
+        )
+        .await
+        .expect("signal receiver available");
+
+        // 신호 수신기는 한 번만 가져갈 수 있음
+        assert!(engine.write().await.take_signal_receiver().is_none());
+
+        // 1. 시장 데이터 → 진입 신호 → 시장가 체결 → 손절/익절 제출
+        engine
+            .read()
+            .await
+            .process_market_data(MarketData::from_kline("simulated", first))
+            .await
+            .unwrap();
+
+        let brackets_placed = wait_for(|| {
+            let exchange = exchange.clone();
+            async move { exchange.get_open_orders(Some(TICKER)).await.unwrap().len() == 2 }
+        })
+        .await;
+        assert!(brackets_placed, "stop loss / take profit should be resting");
+
+        let position = executor.read().await.get_position(TICKER).await.unwrap();
+        assert_eq!(position.quantity, dec!(0.1));
+        assert_eq!(executor.read().await.active_bracket_count().await, 1);
+
+        // 2. 다음 봉에서 익절 체결 → 손절 OCO 취소 → 포지션 청산
+        exchange.step(TICKER, Timeframe::M1).await.unwrap();
+
+        let closed = wait_for(|| {
+            let exchange = exchange.clone();
+            let executor = executor.clone();
+            async move {
+                exchange
+                    .get_open_orders(Some(TICKER))
+                    .await
+                    .unwrap()
+                    .is_empty()
+                    && executor.read().await.get_position(TICKER).await.is_none()
+            }
+        })
+        .await;
+        assert!(
+            closed,
+            "take profit should close position and cancel stop loss"
+        );
+
+        let btc = exchange.get_balance("BTC").await.unwrap();
+        assert_eq!(btc.free + btc.locked, dec!(0));
+        assert!(executor.read().await.get_realized_pnl().await > dec!(0));
+
+        // 진입 + 익절 두 번의 완전 체결이 전략에 전달됨
+        let status = engine
+            .read()
+            .await
+            .get_strategy_status("one_shot")
+            .await
+            .unwrap();
+        assert_eq!(status.stats.orders_filled, 2);
+
+        shutdown.cancel();
+        handle.await.unwrap();
+    }
+
+    const XRP: &str = "XRP/USDT";
+    const ETH: &str = "ETH/USDT";
+
+    /// XRP(가격 100)와 ETH(가격 2000) 시세가 있는 모의 거래소 (USDT 100,000 + 추가 잔고).
+    async fn rebalance_exchange(balances: &[(&str, Decimal)]) -> Arc<SimulatedExchange> {
+        let config = balances.iter().fold(
+            SimulatedConfig::default().with_initial_balance("USDT", dec!(100000)),
+            |config, (asset, amount)| config.with_initial_balance(asset, *amount),
+        );
+        let exchange = Arc::new(SimulatedExchange::new(config));
+        let open_time = Utc::now() - ChronoDuration::minutes(1);
+        for (ticker, price) in [(XRP, dec!(100)), (ETH, dec!(2000))] {
+            exchange
+                .load_klines(
+                    ticker.to_string(),
+                    Timeframe::M1,
+                    vec![Kline::new(
+                        ticker.to_string(),
+                        Timeframe::M1,
+                        open_time,
+                        price,
+                        price,
+                        price,
+                        price,
+                        dec!(1000),
+                        open_time + ChronoDuration::minutes(1),
+                    )],
+                )
+                .await;
+            exchange.step(ticker, Timeframe::M1).await.unwrap();
+        }
+        exchange
+    }
+
+    fn rebalance_config() -> LiveTradingConfig {
+        LiveTradingConfig {
+            rebalance: RebalanceConfig {
+                min_trade_amount: dec!(10),
+                cash_ticker: "USDT".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// 할당 자본과 함께 전략 등록.
+    async fn register_allocation(engine: &StrategyEngine, id: &str, capital: Decimal) {
+        engine
+            .register_strategy(
+                id,
+                Box::new(OneShotStrategy { fired: true }),
+                json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        engine
+            .set_allocated_capital(id, Some(capital))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rebalance_signal_places_target_orders() {
+        let exchange = rebalance_exchange(&[]).await;
+        let engine = Arc::new(RwLock::new(StrategyEngine::new(EngineConfig::default())));
+        register_allocation(&*engine.read().await, "allocation", dec!(100000)).await;
+        let executor = Arc::new(RwLock::new(OrderExecutor::new_complete(
+            RiskManager::new(RiskConfig::default(), dec!(100000)),
+            "simulated",
+            ConversionConfig::default(),
+        )));
+        let service =
+            LiveTradingService::new(engine, executor, exchange.clone(), rebalance_config());
+
+        // 할당 자본 100,000 USDT → XRP 5% (가격 100 → 50개)
+        let signal = Signal::target_portfolio("allocation", [(XRP.to_string(), dec!(0.05))]);
+        service.handle_signal(&signal).await;
+
+        let xrp = exchange.get_balance("XRP").await.unwrap();
+        assert_eq!(xrp.free + xrp.locked, dec!(50));
+    }
+
+    #[tokio::test]
+    async fn test_rebalance_without_allocated_capital_is_rejected() {
+        let exchange = rebalance_exchange(&[]).await;
+        let engine = Arc::new(RwLock::new(StrategyEngine::new(EngineConfig::default())));
+        let executor = Arc::new(RwLock::new(OrderExecutor::new_complete(
+            RiskManager::new(RiskConfig::default(), dec!(100000)),
+            "simulated",
+            ConversionConfig::default(),
+        )));
+        let service = LiveTradingService::new(
+            engine.clone(),
+            executor,
+            exchange.clone(),
+            rebalance_config(),
+        );
+
+        let signal = Signal::target_portfolio("allocation", [(XRP.to_string(), dec!(0.05))]);
+        service.handle_signal(&signal).await;
+
+        let xrp = exchange.get_balance("XRP").await.unwrap();
+        assert_eq!(xrp.free + xrp.locked, Decimal::ZERO);
+        let decisions = engine.read().await.decision_log().query(&DecisionQuery {
+            strategy_id: Some("allocation".to_string()),
+            limit: 10,
+            ..Default::default()
+        });
+        assert_eq!(decisions.len(), 1);
+        assert_eq!(decisions[0].outcome, DecisionOutcome::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_rebalance_is_scoped_to_signalling_strategy() {
+        let exchange = rebalance_exchange(&[("ETH", dec!(10))]).await;
+        let engine = Arc::new(RwLock::new(StrategyEngine::new(EngineConfig::default())));
+        {
+            let engine = engine.read().await;
+            register_allocation(&engine, "alpha", dec!(20000)).await;
+            register_allocation(&engine, "beta", dec!(50000)).await;
+        }
+        let executor = Arc::new(RwLock::new(OrderExecutor::new_complete(
+            RiskManager::new(RiskConfig::default(), dec!(100000)),
+            "simulated",
+            ConversionConfig::default(),
+        )));
+        // 같은 실행기에서 beta가 ETH 10개(20,000 USDT) 보유
+        executor
+            .read()
+            .await
+            .position_tracker()
+            .write()
+            .await
+            .open_position(
+                ETH.to_string(),
+                Side::Buy,
+                dec!(10),
+                dec!(2000),
+                Some("beta".to_string()),
+            )
+            .unwrap();
+        let service =
+            LiveTradingService::new(engine, executor, exchange.clone(), rebalance_config());
+
+        // alpha: 할당 자본 20,000 USDT의 50% → XRP 100개, beta의 ETH는 건드리지 않음
+        let signal = Signal::target_portfolio("alpha", [(XRP.to_string(), dec!(0.5))]);
+        service.handle_signal(&signal).await;
+
+        let xrp = exchange.get_balance("XRP").await.unwrap();
+        assert_eq!(xrp.free + xrp.locked, dec!(100));
+        let eth = exchange.get_balance("ETH").await.unwrap();
+        assert_eq!(eth.free + eth.locked, dec!(10));
+    }
+
+    #[tokio::test]
+    async fn test_draining_rebalance_sells_only_draining_strategy_holdings() {
+        let exchange = rebalance_exchange(&[("XRP", dec!(50)), ("ETH", dec!(10))]).await;
+        let engine = Arc::new(RwLock::new(StrategyEngine::new(EngineConfig::default())));
+        {
+            let engine = engine.read().await;
+            register_allocation(&engine, "alpha", dec!(20000)).await;
+            register_allocation(&engine, "beta", dec!(50000)).await;
+        }
+        let executor = Arc::new(RwLock::new(OrderExecutor::new_complete(
+            RiskManager::new(RiskConfig::default(), dec!(100000)),
+            "simulated",
+            ConversionConfig::default(),
+        )));
+        // alpha는 XRP 50개, beta는 ETH 10개 보유
+        {
+            let executor = executor.read().await;
+            let mut tracker = executor.position_tracker().write().await;
+            tracker
+                .open_position(
+                    XRP.to_string(),
+                    Side::Buy,
+                    dec!(50),
+                    dec!(100),
+                    Some("alpha".to_string()),
+                )
+                .unwrap();
+            tracker
+                .open_position(
+                    ETH.to_string(),
+                    Side::Buy,
+                    dec!(10),
+                    dec!(2000),
+                    Some("beta".to_string()),
+                )
+                .unwrap();
+        }
+        let service =
+            LiveTradingService::new(engine, executor, exchange.clone(), rebalance_config());
+
+        // 청산 대기 중인 alpha의 목표 포트폴리오 신호 → 비중 0 (매도 전용)
+        let signal = StrategyLifecycle::Draining.restrict_signal(Signal::target_portfolio(
+            "alpha",
+            [(XRP.to_string(), dec!(0.5))],
+        ));
+        service.handle_signal(&signal).await;
+
+        let xrp = exchange.get_balance("XRP").await.unwrap();
+        assert_eq!(xrp.free + xrp.locked, Decimal::ZERO);
+        let eth = exchange.get_balance("ETH").await.unwrap();
+        assert_eq!(eth.free + eth.locked, dec!(10));
+    }
+}
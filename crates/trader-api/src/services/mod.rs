@@ -1,11 +1,29 @@
 //! 백그라운드 서비스 모듈.
 //!
-//! 전략 실행, 컨텍스트 동기화 등 백그라운드에서 실행되는 서비스들을 제공합니다.
+//! 실시간 매매, 컨텍스트 동기화, 포트폴리오 VaR 모니터링, 드로다운 서킷 브레이커, 전략 스케줄 트리거, 전략 상태 스냅샷, 생명주기 감사 로그, 의사결정 로그, 섀도 실행 등 백그라운드에서 실행되는 서비스들을 제공합니다.
 
+pub mod circuit_breaker;
 pub mod context_sync;
+pub mod live_trading;
+pub mod portfolio_risk;
+pub mod replay_backtest;
+pub mod shadow_trading;
 pub mod signal_alert;
+pub mod strategy_decision_log;
+pub mod strategy_lifecycle;
+pub mod strategy_schedule;
+pub mod strategy_snapshot;
 pub mod telegram_bot;
 
+pub use circuit_breaker::start_circuit_breaker_service;
 pub use context_sync::start_context_sync_service;
+pub use live_trading::{start_live_trading_service, LiveTradingConfig, LiveTradingService};
+pub use portfolio_risk::start_portfolio_risk_service;
+pub use replay_backtest::ReplayBacktest;
+pub use shadow_trading::{start_shadow_trading_service, ShadowBook, ShadowTradingConfig};
 pub use signal_alert::{SignalAlertFilter, SignalAlertService};
+pub use strategy_decision_log::start_strategy_decision_log_service;
+pub use strategy_lifecycle::start_strategy_lifecycle_audit_service;
+pub use strategy_schedule::{start_strategy_schedule_service, KisTradingCalendar};
+pub use strategy_snapshot::start_strategy_snapshot_service;
 pub use telegram_bot::ApiBotHandler;
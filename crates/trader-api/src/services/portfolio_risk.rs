@@ -0,0 +1,294 @@
+//! 포트폴리오 리스크 모니터링 서비스.
+//!
+//! 보유 포지션과 구독 종목의 일봉과 종목 분류(섹터, 국가, 통화)를 주기적으로
+//! OrderExecutor의 RiskManager에 공급해 주문 전 VaR/ES, 집중도 검사와
+//! 변동성 기반 포지션 사이징이 최신 데이터를 사용하도록 하고,
+//! 포트폴리오 VaR/ES가 한도를 넘어서는 순간 텔레그램으로 리스크 경고를 전송합니다.
+
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use trader_core::{Country, Timeframe};
+use trader_data::cache::CachedHistoricalDataProvider;
+use trader_execution::OrderExecutor;
+use trader_notification::NotificationManager;
+use trader_risk::{PortfolioRisk, PortfolioVarConfig, SymbolProfile};
+use trader_strategy::StrategyEngine;
+
+use crate::repository::{SymbolInfoRepository, SymbolRiskProfile};
+
+/// 기본 갱신 주기.
+pub const DEFAULT_PORTFOLIO_RISK_INTERVAL: Duration = Duration::from_secs(300);
+
+/// 한도 초과 경고 내용.
+#[derive(Debug, Clone, PartialEq)]
+struct BreachAlert {
+    alert_type: &'static str,
+    current_pct: f64,
+    limit_pct: f64,
+}
+
+/// VaR 한도를 먼저, ES 한도를 다음으로 확인합니다.
+fn breach_alert(risk: &PortfolioRisk, config: &PortfolioVarConfig) -> Option<BreachAlert> {
+    if let Some(limit) = config.max_var_pct {
+        if risk.var_pct > limit {
+            return Some(BreachAlert {
+                alert_type: "포트폴리오 VaR",
+                current_pct: risk.var_pct,
+                limit_pct: limit,
+            });
+        }
+    }
+    if let Some(limit) = config.max_es_pct {
+        if risk.es_pct > limit {
+            return Some(BreachAlert {
+                alert_type: "포트폴리오 Expected Shortfall",
+                current_pct: risk.es_pct,
+                limit_pct: limit,
+            });
+        }
+    }
+    None
+}
+
+/// DB 분류 정보를 리스크 매니저의 종목 프로필로 변환.
+fn to_symbol_profile(row: SymbolRiskProfile) -> SymbolProfile {
+    SymbolProfile {
+        sector: row.sector.filter(|s| !s.trim().is_empty()),
+        country: Country::from_code(&row.market),
+        currency: row.currency.filter(|c| !c.trim().is_empty()),
+    }
+}
+
+/// 가격 이력과 종목 분류를 갱신하고 현재 포트폴리오 VaR/ES를 계산.
+async fn refresh_portfolio_risk(
+    executor: &Arc<RwLock<OrderExecutor>>,
+    engine: &Arc<RwLock<StrategyEngine>>,
+    data_provider: &CachedHistoricalDataProvider,
+    db_pool: Option<&PgPool>,
+) -> Option<(PortfolioRisk, PortfolioVarConfig)> {
+    let executor = executor.read().await;
+    let positions = executor.get_open_positions().await;
+
+    let mut tickers: BTreeSet<String> = positions.iter().map(|p| p.ticker.clone()).collect();
+    tickers.extend(engine.read().await.subscribed_tickers().await);
+
+    let risk_manager = executor.risk_manager();
+    let lookback = {
+        let risk_manager = risk_manager.read().await;
+        let config = risk_manager.config();
+        // 변동성 기반 사이징은 VaR와 다른 추정 기간을 쓸 수 있음
+        config
+            .portfolio_var
+            .lookback
+            .max(config.position_sizing.lookback)
+    };
+
+    if let Some(pool) = db_pool {
+        let requested: Vec<String> = tickers.iter().cloned().collect();
+        match SymbolInfoRepository::get_risk_profiles(pool, &requested).await {
+            Ok(rows) => {
+                let mut risk_manager = risk_manager.write().await;
+                for row in rows {
+                    // 요청한 티커 표기(대소문자)로 등록
+                    let ticker = requested
+                        .iter()
+                        .find(|t| t.eq_ignore_ascii_case(&row.ticker))
+                        .cloned()
+                        .unwrap_or_else(|| row.ticker.clone());
+                    risk_manager.set_symbol_profile(&ticker, to_symbol_profile(row));
+                }
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "종목 분류 정보 조회 실패");
+            }
+        }
+    }
+
+    for ticker in &tickers {
+        match data_provider
+            .get_klines_readonly(ticker, Timeframe::D1, lookback + 1)
+            .await
+        {
+            Ok(klines) if !klines.is_empty() => {
+                risk_manager.write().await.update_klines(ticker, &klines);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::debug!(ticker = %ticker, error = %e, "리스크 계산용 일봉 조회 실패");
+            }
+        }
+    }
+
+    let risk_manager = risk_manager.read().await;
+    let risk = risk_manager.portfolio_risk(&positions)?;
+    Some((risk, risk_manager.config().portfolio_var.clone()))
+}
+
+/// 포트폴리오 리스크 모니터링 서비스 시작.
+///
+/// # Arguments
+///
+/// * `executor` - 가격 이력과 종목 분류를 공급할 RiskManager를 가진 주문 실행기
+/// * `engine` - 구독 종목을 조회할 전략 엔진
+/// * `data_provider` - 일봉 조회용 데이터 제공자
+/// * `db_pool` - 종목 분류(섹터, 국가, 통화) 조회용 DB (None이면 집중도 분류 생략)
+/// * `notifier` - 한도 초과 알림 전송기 (None이면 로그만 남김)
+/// * `interval` - 갱신 주기
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+pub fn start_portfolio_risk_service(
+    executor: Arc<RwLock<OrderExecutor>>,
+    engine: Arc<RwLock<StrategyEngine>>,
+    data_provider: Arc<CachedHistoricalDataProvider>,
+    db_pool: Option<PgPool>,
+    notifier: Option<Arc<NotificationManager>>,
+    interval: Duration,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // 한도 초과 상태가 유지되는 동안 같은 경고를 반복하지 않음
+        let mut in_breach = false;
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    let Some((risk, config)) = refresh_portfolio_risk(
+                        &executor,
+                        &engine,
+                        &data_provider,
+                        db_pool.as_ref(),
+                    )
+                    .await
+                    else {
+                        continue;
+                    };
+
+                    let alert = breach_alert(&risk, &config);
+                    match (&alert, in_breach) {
+                        (Some(alert), false) => {
+                            tracing::warn!(
+                                alert_type = alert.alert_type,
+                                current_pct = alert.current_pct,
+                                limit_pct = alert.limit_pct,
+                                "포트폴리오 리스크 한도 초과"
+                            );
+                            if let Some(notifier) = &notifier {
+                                let message = format!(
+                                    "{:.0}% 신뢰수준 1일 VaR {} / ES {} (총 노출 {})",
+                                    risk.confidence * 100.0,
+                                    risk.var.round_dp(2),
+                                    risk.expected_shortfall.round_dp(2),
+                                    risk.gross_exposure.round_dp(2),
+                                );
+                                if let Err(e) = notifier
+                                    .notify_risk_alert(
+                                        alert.alert_type,
+                                        &message,
+                                        Decimal::from_f64(alert.current_pct)
+                                            .unwrap_or_default()
+                                            .round_dp(2),
+                                        Decimal::from_f64(alert.limit_pct).unwrap_or_default(),
+                                    )
+                                    .await
+                                {
+                                    tracing::error!(error = %e, "포트폴리오 리스크 알림 전송 실패");
+                                }
+                            }
+                        }
+                        (None, true) => {
+                            tracing::info!(
+                                var_pct = risk.var_pct,
+                                es_pct = risk.es_pct,
+                                "포트폴리오 리스크 한도 이내로 복귀"
+                            );
+                        }
+                        _ => {}
+                    }
+                    in_breach = alert.is_some();
+                }
+
+                _ = shutdown.cancelled() => {
+                    tracing::info!("포트폴리오 리스크 서비스 종료");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use trader_risk::VarMethod;
+
+    fn risk(var_pct: f64, es_pct: f64) -> PortfolioRisk {
+        PortfolioRisk {
+            method: VarMethod::Historical,
+            confidence: 0.95,
+            var: dec!(100),
+            expected_shortfall: dec!(150),
+            var_pct,
+            es_pct,
+            gross_exposure: dec!(10000),
+            observations: 100,
+            missing_symbols: vec![],
+        }
+    }
+
+    #[test]
+    fn test_breach_alert_prefers_var_limit() {
+        let config = PortfolioVarConfig {
+            max_var_pct: Some(2.0),
+            max_es_pct: Some(3.0),
+            ..Default::default()
+        };
+
+        assert_eq!(breach_alert(&risk(1.5, 2.5), &config), None);
+
+        let alert = breach_alert(&risk(2.5, 4.0), &config).unwrap();
+        assert_eq!(alert.alert_type, "포트폴리오 VaR");
+        assert_eq!(alert.limit_pct, 2.0);
+
+        let alert = breach_alert(&risk(1.5, 3.5), &config).unwrap();
+        assert_eq!(alert.alert_type, "포트폴리오 Expected Shortfall");
+        assert_eq!(alert.current_pct, 3.5);
+    }
+
+    #[test]
+    fn test_to_symbol_profile() {
+        let profile = to_symbol_profile(SymbolRiskProfile {
+            ticker: "005930".to_string(),
+            market: "KR".to_string(),
+            sector: Some("전기전자".to_string()),
+            currency: None,
+        });
+        assert_eq!(profile.sector.as_deref(), Some("전기전자"));
+        assert_eq!(profile.country, Some(Country::KR));
+        assert_eq!(profile.currency().as_deref(), Some("KRW"));
+
+        let profile = to_symbol_profile(SymbolRiskProfile {
+            ticker: "BTC".to_string(),
+            market: "CRYPTO".to_string(),
+            sector: Some(" ".to_string()),
+            currency: Some("USDT".to_string()),
+        });
+        assert_eq!(profile.sector, None);
+        assert_eq!(profile.country, None);
+        assert_eq!(profile.currency().as_deref(), Some("USDT"));
+    }
+
+    #[test]
+    fn test_breach_alert_without_limits() {
+        assert_eq!(
+            breach_alert(&risk(50.0, 80.0), &PortfolioVarConfig::default()),
+            None
+        );
+    }
+}
@@ -0,0 +1,787 @@
+//! 실거래 경로 재생 백테스트.
+//!
+//! 과거 캔들을 `SimulatedExchange`의 `MarketStream`/`UserStream`으로 재생하면서
+//! 실거래와 동일한 `StrategyEngine` → `LiveTradingService`(RiskManager + OrderExecutor)
+//! 경로로 신호와 체결을 처리합니다. 브라켓(손절/익절) 주문, OCO 취소, 리스크 거부까지
+//! 실거래 코드가 그대로 실행되며 결과는 `BacktestEngine`과 같은 [`BacktestReport`]입니다.
+//!
+//! # 처리 순서 (캔들마다)
+//!
+//! ```text
+//! SimulatedExchange::step ──▶ 대기 주문 매칭 ──UserEvent──▶ LiveTradingService
+//!          │
+//!          └── MarketEvent::Kline ──▶ StrategyEngine ──Signal──▶ LiveTradingService
+//!                                                                   │ place_order
+//!                                                 UserEvent ◀───────┘
+//! ```
+//!
+//! 체결가는 `SimulatedExchange` 규칙을 따릅니다. 시장가는 현재 캔들 종가에
+//! `slippage_rate`를 더해 즉시 체결되고, 손절/익절은 다음 캔들의 시가/고가/저가로
+//! 매칭됩니다 (갭 반영). `FillPolicy`와 `SlippageModel`은 사용하지 않습니다.
+
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::FutureExt;
+use rust_decimal::Decimal;
+use serde_json::Value;
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+use trader_analytics::backtest::{BacktestConfig, BacktestError, BacktestReport, BacktestResult};
+use trader_analytics::performance::{PerformanceMetrics, PerformanceTracker, RoundTrip};
+use trader_core::{Kline, MarketData, OrderType, Side, Signal, SignalMarker, Timeframe, Trade};
+use trader_exchange::{
+    DataFeedConfig, Exchange, MarketEvent, MarketStream, SimulatedConfig, SimulatedExchange,
+    SimulatedMarketStream, SimulatedUserStream, UserEvent, UserStream,
+};
+use trader_execution::{ConversionConfig, OrderExecutor};
+use trader_risk::{RiskConfig, RiskManager};
+use trader_strategy::{EngineConfig, Strategy, StrategyEngine};
+
+use super::live_trading::{LiveTradingConfig, LiveTradingService};
+
+/// 실거래 경로 재생 백테스트.
+///
+/// # 사용 예시
+///
+/// ```rust,ignore
+/// let report = ReplayBacktest::new(BacktestConfig::new(dec!(10_000_000)))
+///     .with_risk_config(risk_config)
+///     .run("sma_crossover", strategy, json!({"ticker": "BTC/USDT"}), &klines)
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplayBacktest {
+    config: BacktestConfig,
+    risk_config: RiskConfig,
+    conversion_config: ConversionConfig,
+}
+
+impl ReplayBacktest {
+    /// 새 재생 백테스트 생성.
+    ///
+    /// 리스크 설정은 기본값에 `max_position_size_pct`, `max_positions`를 반영합니다.
+    pub fn new(config: BacktestConfig) -> Self {
+        let max_position_pct = (config.max_position_size_pct * Decimal::from(100))
+            .try_into()
+            .unwrap_or(10.0);
+        let risk_config = RiskConfig {
+            max_position_pct,
+            max_concurrent_positions: config.max_positions,
+            ..RiskConfig::default()
+        };
+
+        Self {
+            config,
+            risk_config,
+            conversion_config: ConversionConfig::default(),
+        }
+    }
+
+    /// 리스크 설정 지정.
+    pub fn with_risk_config(mut self, risk_config: RiskConfig) -> Self {
+        self.risk_config = risk_config;
+        self
+    }
+
+    /// 신호 → 주문 변환 설정 지정.
+    pub fn with_conversion_config(mut self, conversion_config: ConversionConfig) -> Self {
+        self.conversion_config = conversion_config;
+        self
+    }
+
+    /// 백테스트 실행.
+    ///
+    /// 전략은 `StrategyEngine`에 등록된 뒤 `start_strategy`에서 `strategy_config`로
+    /// 초기화되므로 미리 초기화하지 않은 인스턴스를 전달해야 합니다.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy_id` - 엔진 등록 ID
+    /// * `strategy` - 전략 인스턴스
+    /// * `strategy_config` - 전략 초기화 설정
+    /// * `klines` - 과거 캔들 (시간순 정렬, 단일 타임프레임)
+    pub async fn run(
+        &self,
+        strategy_id: &str,
+        strategy: Box<dyn Strategy>,
+        strategy_config: Value,
+        klines: &[Kline],
+    ) -> BacktestResult<BacktestReport> {
+        self.config.validate()?;
+        let timeframe = self.validate_klines(klines)?;
+
+        let quote = single_quote_asset(klines)?;
+        let warmup = self.config.warmup_bars;
+        let start_time = klines[warmup].open_time;
+        let end_time = klines.last().unwrap().close_time;
+
+        let mut session = ReplaySession::start(
+            self,
+            strategy_id,
+            strategy,
+            strategy_config,
+            klines,
+            timeframe,
+            quote,
+        )
+        .await?;
+        session.tracker.set_initial_timestamp(start_time);
+
+        for (idx, kline) in klines.iter().enumerate() {
+            session.step(kline, idx < warmup).await;
+        }
+
+        session.liquidate(strategy_id).await;
+        session
+            .finish(
+                self.config.clone(),
+                start_time,
+                end_time,
+                klines.len() - warmup,
+            )
+            .await
+    }
+
+    /// 캔들 데이터 검증 후 공통 타임프레임 반환.
+    fn validate_klines(&self, klines: &[Kline]) -> BacktestResult<Timeframe> {
+        let Some(first) = klines.first() else {
+            return Err(BacktestError::DataError(
+                "캔들 데이터가 비어있습니다".to_string(),
+            ));
+        };
+
+        for window in klines.windows(2) {
+            if window[0].open_time > window[1].open_time {
+                return Err(BacktestError::DataError(
+                    "캔들 데이터가 시간순으로 정렬되어 있지 않습니다".to_string(),
+                ));
+            }
+        }
+
+        // SimulatedExchange의 현재가는 기본 타임프레임 기준이므로 하나만 허용
+        if klines.iter().any(|k| k.timeframe != first.timeframe) {
+            return Err(BacktestError::DataError(
+                "재생 백테스트는 단일 타임프레임 캔들만 지원합니다".to_string(),
+            ));
+        }
+
+        if self.config.warmup_bars >= klines.len() {
+            return Err(BacktestError::DataError(format!(
+                "워밍업 캔들 수({})가 데이터 길이({}) 이상입니다",
+                self.config.warmup_bars,
+                klines.len()
+            )));
+        }
+
+        Ok(first.timeframe)
+    }
+}
+
+/// 재생 중 상태.
+struct ReplaySession {
+    exchange: Arc<SimulatedExchange>,
+    engine: Arc<RwLock<StrategyEngine>>,
+    executor: Arc<RwLock<OrderExecutor>>,
+    service: LiveTradingService,
+    market_stream: SimulatedMarketStream,
+    user_stream: SimulatedUserStream,
+    signal_rx: tokio::sync::mpsc::Receiver<Signal>,
+    tracker: PerformanceTracker,
+    exchange_name: String,
+    commission_rate: Decimal,
+    quote: String,
+    last_prices: HashMap<String, Decimal>,
+    signal_markers: Vec<SignalMarker>,
+    total_orders: usize,
+    total_commission: Decimal,
+    total_slippage: Decimal,
+}
+
+impl ReplaySession {
+    /// 거래소, 전략 엔진, 실행기를 구성하고 스트림을 연결.
+    async fn start(
+        backtest: &ReplayBacktest,
+        strategy_id: &str,
+        strategy: Box<dyn Strategy>,
+        strategy_config: Value,
+        klines: &[Kline],
+        timeframe: Timeframe,
+        quote: String,
+    ) -> BacktestResult<Self> {
+        let config = &backtest.config;
+
+        let exchange = Arc::new(SimulatedExchange::new(SimulatedConfig {
+            initial_balances: HashMap::from([(quote.clone(), config.initial_capital)]),
+            fee_rate: config.commission_rate,
+            slippage_rate: config.slippage_rate,
+            data_feed_config: DataFeedConfig {
+                default_timeframe: timeframe,
+                ..DataFeedConfig::default()
+            },
+            ..SimulatedConfig::default()
+        }));
+
+        let mut by_ticker: HashMap<&str, Vec<Kline>> = HashMap::new();
+        for kline in klines {
+            by_ticker
+                .entry(kline.ticker.as_str())
+                .or_default()
+                .push(kline.clone());
+        }
+
+        let mut market_stream = exchange.create_market_stream().await;
+        for (ticker, series) in by_ticker {
+            market_stream
+                .subscribe_kline(ticker, timeframe)
+                .await
+                .map_err(|e| BacktestError::ExecutionError(e.to_string()))?;
+            exchange
+                .load_klines(ticker.to_string(), timeframe, series)
+                .await;
+        }
+
+        let mut user_stream = exchange.create_user_stream().await;
+        user_stream
+            .start()
+            .await
+            .map_err(|e| BacktestError::ExecutionError(e.to_string()))?;
+
+        // 시뮬레이션 속도에서는 벽시계 기준 중복 제거/타임아웃이 결과를 바꾸므로 비활성화
+        let mut engine = StrategyEngine::new(EngineConfig {
+            deduplicate_signals: false,
+            strategy_timeout_ms: 0,
+            ..EngineConfig::default()
+        });
+        let signal_rx = engine
+            .take_signal_receiver()
+            .ok_or_else(|| BacktestError::ExecutionError("신호 수신기 없음".to_string()))?;
+        engine
+            .register_strategy(strategy_id, strategy, strategy_config, None)
+            .await
+            .map_err(|e| BacktestError::StrategyError(e.to_string()))?;
+        engine
+            .start_strategy(strategy_id)
+            .await
+            .map_err(|e| BacktestError::StrategyError(e.to_string()))?;
+        let engine = Arc::new(RwLock::new(engine));
+
+        let executor = Arc::new(RwLock::new(OrderExecutor::new_complete(
+            RiskManager::new(backtest.risk_config.clone(), config.initial_capital),
+            &config.exchange_name,
+            backtest.conversion_config.clone(),
+        )));
+
+        let service = LiveTradingService::new(
+            engine.clone(),
+            executor.clone(),
+            exchange.clone(),
+            LiveTradingConfig::default(),
+        );
+
+        let tracker = PerformanceTracker::new(config.initial_capital)
+            .with_risk_free_rate(config.risk_free_rate)
+            .without_equity_history_limit();
+
+        Ok(Self {
+            exchange,
+            engine,
+            executor,
+            service,
+            market_stream,
+            user_stream,
+            signal_rx,
+            tracker,
+            exchange_name: config.exchange_name.clone(),
+            commission_rate: config.commission_rate,
+            quote,
+            last_prices: HashMap::new(),
+            signal_markers: Vec::new(),
+            total_orders: 0,
+            total_commission: Decimal::ZERO,
+            total_slippage: Decimal::ZERO,
+        })
+    }
+
+    /// 캔들 하나를 재생.
+    ///
+    /// 워밍업 구간에서는 전략 상태만 갱신하고 신호는 버립니다.
+    async fn step(&mut self, kline: &Kline, warming_up: bool) {
+        // 대기 주문(손절/익절 등)은 이번 캔들로 먼저 매칭됨
+        if self
+            .exchange
+            .step(&kline.ticker, kline.timeframe)
+            .await
+            .is_none()
+        {
+            return;
+        }
+        self.drain_user_events().await;
+        self.last_prices.insert(kline.ticker.clone(), kline.close);
+
+        while let Some(event) = poll_ready(self.market_stream.next_event()) {
+            if let MarketEvent::Kline(bar) = event {
+                let data = MarketData::from_kline(&self.exchange_name, bar);
+                let engine = self.engine.read().await;
+                if let Err(e) = engine.process_market_data(data).await {
+                    warn!("시장 데이터 처리 실패: {}", e);
+                }
+            }
+        }
+
+        while let Ok(signal) = self.signal_rx.try_recv() {
+            if warming_up {
+                continue;
+            }
+
+            let price = signal
+                .suggested_price
+                .or_else(|| self.last_prices.get(&signal.ticker).copied())
+                .unwrap_or(kline.close);
+            let mut marker =
+                SignalMarker::from_signal(&signal, price, kline.open_time, &signal.strategy_id);
+
+            let orders_before = self.total_registered_orders().await;
+            self.service.handle_signal(&signal).await;
+            marker.executed = self.total_registered_orders().await > orders_before;
+            self.signal_markers.push(marker);
+
+            self.drain_user_events().await;
+        }
+
+        if !warming_up {
+            let equity = self.equity().await;
+            self.tracker.update_equity(kline.close_time, equity);
+        }
+    }
+
+    /// 남은 포지션을 청산 신호로 정리하고 미체결 주문을 취소.
+    async fn liquidate(&mut self, strategy_id: &str) {
+        let positions = self.executor.read().await.get_open_positions().await;
+        for position in positions {
+            let exit_side = match position.side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+            };
+            let owner = position
+                .strategy_id
+                .clone()
+                .unwrap_or_else(|| strategy_id.to_string());
+            let signal = Signal::exit(owner, position.ticker.clone(), exit_side);
+
+            self.service.handle_signal(&signal).await;
+            self.drain_user_events().await;
+        }
+
+        let executor = self.executor.read().await;
+        for order in executor.get_active_orders().await {
+            if let Some(exchange_order_id) = order.exchange_order_id.as_deref() {
+                let _ = self
+                    .exchange
+                    .cancel_order(&order.ticker, exchange_order_id)
+                    .await;
+            }
+            let _ = executor
+                .cancel_order(order.id, Some("backtest finished".to_string()))
+                .await;
+        }
+        drop(executor);
+
+        self.engine.read().await.stop_all_strategies().await;
+    }
+
+    /// 리포트 생성.
+    async fn finish(
+        self,
+        config: BacktestConfig,
+        start_time: chrono::DateTime<chrono::Utc>,
+        end_time: chrono::DateTime<chrono::Utc>,
+        data_points: usize,
+    ) -> BacktestResult<BacktestReport> {
+        let trades = self.tracker.get_round_trips().to_vec();
+        let performance_by_symbol = performance_by_symbol(&trades, &config);
+
+        let equity_curve = self.tracker.get_equity_curve().to_vec();
+        let mut metrics = self.tracker.get_metrics();
+        let benchmark_curve = config.apply_benchmark(&mut metrics, &equity_curve);
+
+        Ok(BacktestReport {
+            config,
+            metrics,
+            trades,
+            equity_curve,
+            total_orders: self.total_orders,
+            total_commission: self.total_commission,
+            total_slippage: self.total_slippage,
+            start_time,
+            end_time,
+            data_points,
+            performance_by_symbol,
+            signal_markers: self.signal_markers,
+            slippage_records: Vec::new(),
+            benchmark_curve,
+        })
+    }
+
+    /// 도착한 사용자 이벤트를 실거래 서비스로 전달하고 체결을 성과 추적기에 기록.
+    async fn drain_user_events(&mut self) {
+        while let Some(event) = poll_ready(self.user_stream.next_event()) {
+            let UserEvent::OrderUpdate(status) = &event else {
+                self.service.handle_user_event(event).await;
+                continue;
+            };
+
+            // 서비스가 반영하기 전의 주문/포지션 상태로 진입/청산 여부 판단
+            let (order, position) = {
+                let executor = self.executor.read().await;
+                let order = executor
+                    .order_manager()
+                    .read()
+                    .await
+                    .get_order_by_exchange_id(&status.order_id)
+                    .cloned();
+                let position = match &order {
+                    Some(order) => executor.get_position(&order.ticker).await,
+                    None => None,
+                };
+                (order, position)
+            };
+            let reference_price = order.as_ref().and_then(|o| match o.order_type {
+                OrderType::Market => self.last_prices.get(&o.ticker).copied(),
+                OrderType::StopLoss => o.stop_price,
+                _ => None,
+            });
+            let fill_price = status.average_price;
+
+            self.service.handle_user_event(event.clone()).await;
+
+            let Some(order) = order else {
+                continue;
+            };
+            let Some(updated) = self.executor.read().await.get_order(order.id).await else {
+                continue;
+            };
+            let quantity = updated.filled_quantity - order.filled_quantity;
+            let Some(price) = fill_price.or(order.price).or(order.stop_price) else {
+                continue;
+            };
+            if quantity <= Decimal::ZERO {
+                continue;
+            }
+
+            let is_entry = position.map_or(true, |p| p.side == order.side);
+            let commission = quantity * price * self.commission_rate;
+            self.total_orders += 1;
+            self.total_commission += commission;
+            if let Some(reference) = reference_price {
+                self.total_slippage += (price - reference).abs() * quantity;
+            }
+
+            let trade = Trade::new(
+                order.id,
+                &self.exchange_name,
+                status.order_id.clone(),
+                order.ticker.clone(),
+                order.side,
+                quantity,
+                price,
+            )
+            .with_fee(commission, self.quote.as_str())
+            .with_executed_at(status.updated_at);
+
+            if let Err(e) = self
+                .tracker
+                .record_trade(&trade, is_entry, order.strategy_id.clone())
+            {
+                debug!(order_id = %order.id, "체결 기록 실패: {}", e);
+            }
+        }
+    }
+
+    /// 실행기에 등록된 누적 주문 수.
+    async fn total_registered_orders(&self) -> usize {
+        let executor = self.executor.read().await;
+        let order_manager = executor.order_manager().read().await;
+        order_manager.total_orders()
+    }
+
+    /// 견적 통화 잔고 + 보유 자산 평가액.
+    async fn equity(&self) -> Decimal {
+        let mut equity = match self.exchange.get_balance(&self.quote).await {
+            Ok(balance) => balance.free + balance.locked,
+            Err(_) => Decimal::ZERO,
+        };
+
+        for (ticker, price) in &self.last_prices {
+            if let Ok(balance) = self.exchange.get_balance(base_asset(ticker)).await {
+                equity += (balance.free + balance.locked) * *price;
+            }
+        }
+
+        equity
+    }
+}
+
+/// 이미 도착한 스트림 이벤트만 꺼냄 (없으면 대기하지 않음).
+///
+/// 협조적 스케줄링 예산이 소진되어도 빈 채널로 오인하지 않도록 `unconstrained`로 감쌉니다.
+fn poll_ready<T>(next: impl Future<Output = Option<T>>) -> Option<T> {
+    tokio::task::unconstrained(next).now_or_never().flatten()
+}
+
+/// 티커의 기준 자산 (`SimulatedExchange`와 동일 규칙, 예: "BTC/USDT" → "BTC").
+fn base_asset(ticker: &str) -> &str {
+    ticker.split('/').next().unwrap_or(ticker)
+}
+
+/// 티커의 견적 자산 (`SimulatedExchange`와 동일 규칙, 구분자가 없으면 "USDT").
+fn quote_asset(ticker: &str) -> &str {
+    ticker.split('/').nth(1).unwrap_or("USDT")
+}
+
+/// 모든 캔들이 같은 견적 통화를 쓰는지 확인 (초기 자본을 하나의 잔고로 지급).
+fn single_quote_asset(klines: &[Kline]) -> BacktestResult<String> {
+    let quotes: BTreeSet<&str> = klines.iter().map(|k| quote_asset(&k.ticker)).collect();
+    if quotes.len() > 1 {
+        return Err(BacktestError::DataError(format!(
+            "재생 백테스트는 단일 견적 통화만 지원합니다: {:?}",
+            quotes
+        )));
+    }
+    Ok(quotes.into_iter().next().unwrap_or("USDT").to_string())
+}
+
+/// 라운드트립을 심볼별로 묶어 성과 계산.
+fn performance_by_symbol(
+    trades: &[RoundTrip],
+    config: &BacktestConfig,
+) -> HashMap<String, PerformanceMetrics> {
+    let mut by_symbol: HashMap<String, Vec<RoundTrip>> = HashMap::new();
+    for rt in trades {
+        by_symbol
+            .entry(rt.symbol.clone())
+            .or_default()
+            .push(rt.clone());
+    }
+
+    by_symbol
+        .into_iter()
+        .map(|(symbol, trades)| {
+            let metrics = PerformanceMetrics::from_round_trips(
+                &trades,
+                config.initial_capital,
+                Some(config.risk_free_rate),
+            );
+            (symbol, metrics)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{Duration, TimeZone, Utc};
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+    use trader_core::{Order, Position};
+
+    const TICKER: &str = "BTC/USDT";
+
+    /// 지정한 캔들 순번에서 진입/청산 신호를 내는 테스트 전략.
+    struct ScriptedStrategy {
+        bar: usize,
+        entry_at: usize,
+        exit_at: usize,
+        quantity: &'static str,
+    }
+
+    impl ScriptedStrategy {
+        fn new(entry_at: usize, exit_at: usize, quantity: &'static str) -> Self {
+            Self {
+                bar: 0,
+                entry_at,
+                exit_at,
+                quantity,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Strategy for ScriptedStrategy {
+        fn name(&self) -> &str {
+            "scripted"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Test strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            let bar = self.bar;
+            self.bar += 1;
+
+            let signal = if bar == self.entry_at {
+                Signal::entry("scripted", data.ticker.clone(), Side::Buy)
+                    .with_metadata("quantity", json!(self.quantity))
+            } else if bar == self.exit_at {
+                Signal::exit("scripted", data.ticker.clone(), Side::Sell)
+            } else {
+                return Ok(vec![]);
+            };
+            Ok(vec![signal])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            json!({ "bar": self.bar })
+        }
+    }
+
+    /// (저가, 종가) 목록으로 일봉 생성 (시가 = 종가, 고가 = 종가 + 0.5%).
+    fn daily_klines(bars: &[(Decimal, Decimal)]) -> Vec<Kline> {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        bars.iter()
+            .enumerate()
+            .map(|(i, &(low, close))| {
+                let open_time = base + Duration::days(i as i64);
+                Kline::new(
+                    TICKER.to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    close,
+                    close * dec!(1.005),
+                    low,
+                    close,
+                    dec!(100),
+                    open_time + Duration::days(1) - Duration::seconds(1),
+                )
+            })
+            .collect()
+    }
+
+    fn flat(close: Decimal) -> (Decimal, Decimal) {
+        (close * dec!(0.995), close)
+    }
+
+    async fn run(strategy: ScriptedStrategy, klines: &[Kline]) -> BacktestReport {
+        ReplayBacktest::new(BacktestConfig::new(dec!(100000)))
+            .run("scripted", Box::new(strategy), json!({}), klines)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_replay_round_trip_cancels_brackets_after_strategy_exit() {
+        // 1봉 진입 → 3봉 전략 청산 → 5봉 급락 (남은 손절이 있었다면 트리거됐을 구간)
+        let klines = daily_klines(&[
+            flat(dec!(50000)),
+            flat(dec!(50000)),
+            flat(dec!(50500)),
+            flat(dec!(51000)),
+            flat(dec!(51000)),
+            (dec!(44000), dec!(45000)),
+            flat(dec!(45000)),
+        ]);
+
+        let report = run(ScriptedStrategy::new(1, 3, "0.1"), &klines).await;
+
+        assert_eq!(report.trades.len(), 1);
+        assert!(report.trades[0].pnl > Decimal::ZERO);
+        assert_eq!(report.total_orders, 2);
+        assert!(report.total_commission > Decimal::ZERO);
+        assert_eq!(report.data_points, klines.len());
+        assert_eq!(report.signal_markers.len(), 2);
+        assert!(report.signal_markers.iter().all(|m| m.executed));
+        assert!(report.performance_by_symbol.contains_key(TICKER));
+
+        // 청산 이후 급락은 자산에 영향 없음 (잔여 브라켓 주문이 취소됨)
+        let last = report.equity_curve.last().unwrap();
+        assert!(last.equity > dec!(100000) - report.total_commission);
+    }
+
+    #[tokio::test]
+    async fn test_replay_stop_loss_bracket_closes_position() {
+        // 0봉 진입 → 2봉 저가가 손절가(-2%) 이탈 → 3봉 전략 청산 신호는 포지션 없음
+        let klines = daily_klines(&[
+            flat(dec!(50000)),
+            flat(dec!(50000)),
+            (dec!(48000), dec!(50000)),
+            flat(dec!(50000)),
+            flat(dec!(50000)),
+        ]);
+
+        let report = run(ScriptedStrategy::new(0, 3, "0.1"), &klines).await;
+
+        assert_eq!(report.trades.len(), 1);
+        assert!(report.trades[0].pnl < Decimal::ZERO);
+        assert!(report.trades[0].exit_price < dec!(49100));
+        assert_eq!(report.total_orders, 2);
+        assert!(report.signal_markers[0].executed);
+        assert!(!report.signal_markers[1].executed);
+    }
+
+    #[tokio::test]
+    async fn test_replay_risk_rejection_skips_order() {
+        // 1 BTC ≈ 자본의 50% → 최대 포지션 20% 초과로 RiskManager가 거부
+        let klines = daily_klines(&[flat(dec!(50000)), flat(dec!(50000)), flat(dec!(50000))]);
+
+        let report = run(ScriptedStrategy::new(0, 10, "1"), &klines).await;
+
+        assert!(report.trades.is_empty());
+        assert_eq!(report.total_orders, 0);
+        assert_eq!(report.signal_markers.len(), 1);
+        assert!(!report.signal_markers[0].executed);
+        assert_eq!(report.metrics.net_profit, Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_replay_rejects_mixed_timeframes() {
+        let mut klines = daily_klines(&[flat(dec!(50000)), flat(dec!(50000))]);
+        klines[1].timeframe = Timeframe::H1;
+
+        let result = ReplayBacktest::new(BacktestConfig::new(dec!(100000)))
+            .run(
+                "scripted",
+                Box::new(ScriptedStrategy::new(0, 1, "0.1")),
+                json!({}),
+                &klines,
+            )
+            .await;
+
+        assert!(matches!(result, Err(BacktestError::DataError(_))));
+    }
+}
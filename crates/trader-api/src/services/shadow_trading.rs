@@ -0,0 +1,948 @@
+//! 섀도(모의) 실행 서비스.
+//!
+//! 설정 변경을 실전에 반영하기 전에, 새 설정을 섀도 전략으로 실전 전략과 나란히 실행합니다.
+//! 섀도 전략은 실전 전략과 같은 시장 데이터를 받고, 신호는 브로커 대신 섀도 전략별
+//! `SimulatedExchange` 가상 원장에서 체결됩니다.
+//!
+//! # 처리 흐름
+//!
+//! ```text
+//! StrategyEngine ──MarketData──────────▶ ShadowTradingService ──push_kline──▶ SimulatedExchange
+//!       │                                    │   (섀도 전략별 가상 원장)            │
+//!       └──StrategySignalEvent──────────────▶│ 섀도 신호 → 시장가 가상 주문 ─────────┘
+//!       ▲                                    │ 실전 신호 → 신호 타이밍 비교
+//!       └── notify_shadow_order_filled ◀─────┘
+//! ```
+//!
+//! - 가상 원장은 현물 기준(롱 전용)이며, 모든 신호를 현재가 기준 시장가로 체결합니다.
+//! - 같은 티커·방향·신호 유형의 실전/섀도 신호가 `match_window` 안에 발생하면 일치로 보고
+//!   지연 시간을 기록합니다. 짝을 찾지 못하고 윈도우가 지난 신호는 발산(divergence)으로 셉니다.
+//! - 섀도 전략과 가상 원장은 메모리에만 유지됩니다 (재시작 시 사라짐).
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+use serde::Serialize;
+use tokio::sync::{broadcast, Mutex, RwLock};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use utoipa::ToSchema;
+
+use trader_core::{
+    domain::MarketDataType, Kline, MarketData, Order, OrderRequest, OrderStatusType, Position,
+    Side, Signal, SignalType, Timeframe,
+};
+use trader_exchange::{Exchange, SimulatedConfig, SimulatedExchange};
+use trader_strategy::strategies::common::rebalance::{
+    PortfolioPosition, RebalanceCalculator, RebalanceConfig,
+};
+use trader_strategy::{StrategyEngine, StrategySignalEvent};
+
+/// 가상 원장 주문/포지션의 거래소 이름.
+const SHADOW_EXCHANGE: &str = "shadow";
+
+/// 주문 수량 소수점 자릿수.
+const QUANTITY_DECIMALS: u32 = 8;
+
+/// 섀도 실행 설정.
+#[derive(Debug, Clone)]
+pub struct ShadowTradingConfig {
+    /// 가상 원장 초기 자본 (요청에서 지정하지 않은 경우)
+    pub initial_capital: Decimal,
+    /// 가상 원장 현금 자산 ("/"가 없는 티커는 시뮬레이터 규칙상 USDT로 정산)
+    pub cash_asset: String,
+    /// 거래 수수료율
+    pub fee_rate: Decimal,
+    /// 시장가 슬리피지율
+    pub slippage_rate: Decimal,
+    /// 진입 신호 1건당 투입 비율 (평가금액 대비, strength를 곱해 적용)
+    pub position_fraction: Decimal,
+    /// 실전/섀도 신호를 같은 신호로 볼 최대 시간 차이
+    pub match_window: Duration,
+}
+
+impl Default for ShadowTradingConfig {
+    fn default() -> Self {
+        Self {
+            initial_capital: dec!(100000),
+            cash_asset: "USDT".to_string(),
+            fee_rate: dec!(0.001),
+            slippage_rate: dec!(0.0005),
+            position_fraction: dec!(0.1),
+            match_window: Duration::from_secs(300),
+        }
+    }
+}
+
+/// 실전 전략과의 비교 결과.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ShadowComparison {
+    /// 섀도 전략 ID
+    pub shadow_id: String,
+    /// 비교 대상 실전 전략 ID
+    pub live_id: String,
+    /// 섀도 가상 원장 성과
+    pub shadow: ShadowPerformance,
+    /// 실전 전략 손익 (실행기에 기록이 없으면 None)
+    pub live: Option<LivePerformance>,
+    /// 섀도 총손익 - 실전 총손익 (실전 기록이 없으면 None)
+    pub pnl_difference: Option<Decimal>,
+    /// 신호 타이밍/발산 통계
+    pub signals: SignalDivergence,
+    /// 비교 시각
+    pub compared_at: DateTime<Utc>,
+}
+
+/// 섀도 가상 원장 성과.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ShadowPerformance {
+    /// 초기 자본
+    pub initial_capital: Decimal,
+    /// 현재 평가금액 (현금 + 보유 포지션 시가)
+    pub equity: Decimal,
+    /// 총손익 (평가금액 - 초기 자본, 수수료 포함)
+    pub total_pnl: Decimal,
+    /// 실현 손익 (수수료 제외)
+    pub realized_pnl: Decimal,
+    /// 미실현 손익
+    pub unrealized_pnl: Decimal,
+    /// 수익률 (%)
+    pub return_pct: Decimal,
+    /// 체결된 가상 주문 수
+    pub fills: u64,
+    /// 거부된 가상 주문 수 (잔고 부족, 공매도 등)
+    pub rejected: u64,
+    /// 보유 포지션
+    #[schema(value_type = Vec<Object>)]
+    pub positions: Vec<Position>,
+}
+
+/// 실전 전략 손익 (OrderExecutor 포지션 기록 기준).
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LivePerformance {
+    /// 실현 손익
+    pub realized_pnl: Decimal,
+    /// 미실현 손익
+    pub unrealized_pnl: Decimal,
+    /// 총손익
+    pub total_pnl: Decimal,
+}
+
+/// 신호 타이밍/발산 통계.
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct SignalDivergence {
+    /// 실전 신호 수
+    pub live_signals: u64,
+    /// 섀도 신호 수
+    pub shadow_signals: u64,
+    /// 일치한 신호 쌍 수
+    pub matched: u64,
+    /// 실전에만 있는 신호 수 (윈도우 경과)
+    pub live_only: u64,
+    /// 섀도에만 있는 신호 수 (윈도우 경과)
+    pub shadow_only: u64,
+    /// 아직 짝을 기다리는 신호 수
+    pub pending: u64,
+    /// 일치 신호의 평균 지연 (섀도 - 실전, 밀리초)
+    pub avg_lag_ms: Option<f64>,
+    /// 일치 신호의 최대 절대 지연 (밀리초)
+    pub max_abs_lag_ms: Option<i64>,
+    /// 발산 비율 (짝이 없는 신호 / 판정된 전체 신호)
+    pub divergence_rate: f64,
+}
+
+/// 비교용 신호 기록.
+#[derive(Debug, Clone)]
+struct SignalMark {
+    ticker: String,
+    side: Side,
+    signal_type: SignalType,
+    at: DateTime<Utc>,
+}
+
+impl SignalMark {
+    fn new(event: &StrategySignalEvent) -> Self {
+        Self {
+            ticker: event.signal.ticker.clone(),
+            side: event.signal.side,
+            signal_type: event.signal.signal_type,
+            at: event.emitted_at,
+        }
+    }
+
+    fn matches(&self, other: &SignalMark) -> bool {
+        self.ticker == other.ticker
+            && self.side == other.side
+            && self.signal_type == other.signal_type
+    }
+}
+
+/// 신호 매칭 상태.
+#[derive(Debug, Default)]
+struct SignalTracker {
+    pending_live: VecDeque<SignalMark>,
+    pending_shadow: VecDeque<SignalMark>,
+    stats: SignalDivergence,
+    lag_sum_ms: i64,
+}
+
+impl SignalTracker {
+    fn record_live(&mut self, mark: SignalMark, window: chrono::Duration) {
+        self.expire(mark.at, window);
+        self.stats.live_signals += 1;
+        match take_match(&mut self.pending_shadow, &mark, window) {
+            Some(shadow) => self.record_match((shadow.at - mark.at).num_milliseconds()),
+            None => self.pending_live.push_back(mark),
+        }
+    }
+
+    fn record_shadow(&mut self, mark: SignalMark, window: chrono::Duration) {
+        self.expire(mark.at, window);
+        self.stats.shadow_signals += 1;
+        match take_match(&mut self.pending_live, &mark, window) {
+            Some(live) => self.record_match((mark.at - live.at).num_milliseconds()),
+            None => self.pending_shadow.push_back(mark),
+        }
+    }
+
+    fn record_match(&mut self, lag_ms: i64) {
+        self.stats.matched += 1;
+        self.lag_sum_ms += lag_ms;
+        self.stats.max_abs_lag_ms = Some(self.stats.max_abs_lag_ms.unwrap_or(0).max(lag_ms.abs()));
+    }
+
+    /// 윈도우가 지난 대기 신호를 발산으로 확정.
+    fn expire(&mut self, now: DateTime<Utc>, window: chrono::Duration) {
+        while self
+            .pending_live
+            .front()
+            .is_some_and(|m| now - m.at > window)
+        {
+            self.pending_live.pop_front();
+            self.stats.live_only += 1;
+        }
+        while self
+            .pending_shadow
+            .front()
+            .is_some_and(|m| now - m.at > window)
+        {
+            self.pending_shadow.pop_front();
+            self.stats.shadow_only += 1;
+        }
+    }
+
+    fn snapshot(&mut self, now: DateTime<Utc>, window: chrono::Duration) -> SignalDivergence {
+        self.expire(now, window);
+        let mut stats = self.stats.clone();
+        stats.pending = (self.pending_live.len() + self.pending_shadow.len()) as u64;
+        if stats.matched > 0 {
+            stats.avg_lag_ms = Some(self.lag_sum_ms as f64 / stats.matched as f64);
+        }
+        let unmatched = stats.live_only + stats.shadow_only;
+        let decided = stats.matched * 2 + unmatched;
+        if decided > 0 {
+            stats.divergence_rate = unmatched as f64 / decided as f64;
+        }
+        stats
+    }
+}
+
+/// 대기 신호 중 윈도우 안에서 짝이 되는 가장 오래된 신호를 꺼냄.
+fn take_match(
+    pending: &mut VecDeque<SignalMark>,
+    mark: &SignalMark,
+    window: chrono::Duration,
+) -> Option<SignalMark> {
+    let index = pending
+        .iter()
+        .position(|m| m.matches(mark) && (mark.at - m.at).abs() <= window)?;
+    pending.remove(index)
+}
+
+/// 섀도 전략 하나의 가상 원장.
+struct ShadowLedger {
+    live_id: String,
+    exchange: SimulatedExchange,
+    initial_capital: Decimal,
+    holdings: HashMap<String, Position>,
+    last_prices: HashMap<String, Decimal>,
+    realized_pnl: Decimal,
+    fills: u64,
+    rejected: u64,
+    signals: SignalTracker,
+    /// 실전 신호의 `Signal::strategy_id` (실행기 포지션 기록 조회용)
+    live_signal_source: Option<String>,
+}
+
+impl ShadowLedger {
+    fn new(live_id: String, initial_capital: Decimal, config: &ShadowTradingConfig) -> Self {
+        let exchange = SimulatedExchange::new(
+            SimulatedConfig {
+                initial_balances: HashMap::new(),
+                ..SimulatedConfig::default()
+            }
+            .with_initial_balance(&config.cash_asset, initial_capital)
+            .with_fee_rate(config.fee_rate)
+            .with_slippage_rate(config.slippage_rate),
+        );
+
+        Self {
+            live_id,
+            exchange,
+            initial_capital,
+            holdings: HashMap::new(),
+            last_prices: HashMap::new(),
+            realized_pnl: Decimal::ZERO,
+            fills: 0,
+            rejected: 0,
+            signals: SignalTracker::default(),
+            live_signal_source: None,
+        }
+    }
+
+    async fn update_price(&mut self, kline: &Kline) {
+        self.last_prices.insert(kline.ticker.clone(), kline.close);
+        if let Some(position) = self.holdings.get_mut(&kline.ticker) {
+            position.update_price(kline.close);
+        }
+        self.exchange.push_kline(kline).await;
+    }
+
+    async fn cash(&self, cash_asset: &str) -> Decimal {
+        self.exchange
+            .get_balance(cash_asset)
+            .await
+            .map(|b| b.total())
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    async fn equity(&self, cash_asset: &str) -> Decimal {
+        let held: Decimal = self
+            .holdings
+            .values()
+            .map(|p| p.quantity * p.current_price)
+            .sum();
+        self.cash(cash_asset).await + held
+    }
+
+    async fn performance(&self, cash_asset: &str) -> ShadowPerformance {
+        let equity = self.equity(cash_asset).await;
+        let total_pnl = equity - self.initial_capital;
+        let return_pct = if self.initial_capital > Decimal::ZERO {
+            (total_pnl / self.initial_capital * dec!(100)).round_dp(4)
+        } else {
+            Decimal::ZERO
+        };
+        let mut positions: Vec<_> = self.holdings.values().cloned().collect();
+        positions.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+
+        ShadowPerformance {
+            initial_capital: self.initial_capital,
+            equity,
+            total_pnl,
+            realized_pnl: self.realized_pnl,
+            unrealized_pnl: positions.iter().map(|p| p.unrealized_pnl).sum(),
+            return_pct,
+            fills: self.fills,
+            rejected: self.rejected,
+            positions,
+        }
+    }
+}
+
+/// 섀도 전략별 가상 원장 모음.
+///
+/// 라우트(등록/해제/비교 조회)와 [`start_shadow_trading_service`]가 공유합니다.
+pub struct ShadowBook {
+    config: ShadowTradingConfig,
+    ledgers: RwLock<HashMap<String, Arc<Mutex<ShadowLedger>>>>,
+}
+
+impl Default for ShadowBook {
+    fn default() -> Self {
+        Self::new(ShadowTradingConfig::default())
+    }
+}
+
+impl ShadowBook {
+    /// 설정으로 생성.
+    pub fn new(config: ShadowTradingConfig) -> Self {
+        Self {
+            config,
+            ledgers: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 섀도 전략의 가상 원장 생성 (이미 있으면 초기화).
+    pub async fn open(&self, shadow_id: &str, live_id: &str, initial_capital: Option<Decimal>) {
+        let capital = initial_capital.unwrap_or(self.config.initial_capital);
+        let ledger = ShadowLedger::new(live_id.to_string(), capital, &self.config);
+        self.ledgers
+            .write()
+            .await
+            .insert(shadow_id.to_string(), Arc::new(Mutex::new(ledger)));
+        info!(shadow_id, live_id, initial_capital = %capital, "섀도 가상 원장 생성");
+    }
+
+    /// 섀도 전략의 가상 원장 제거. 원장이 있었으면 true.
+    pub async fn close(&self, shadow_id: &str) -> bool {
+        self.ledgers.write().await.remove(shadow_id).is_some()
+    }
+
+    /// 실전 전략과의 비교 결과.
+    ///
+    /// `live_pnl`은 실행기의 전략별 (미실현, 실현) 손익(`PositionTracker::pnl_by_strategy`)이며,
+    /// 실전 전략 ID 또는 실전 신호의 `strategy_id`로 조회합니다.
+    pub async fn compare(
+        &self,
+        shadow_id: &str,
+        live_pnl: &HashMap<String, (Decimal, Decimal)>,
+    ) -> Option<ShadowComparison> {
+        let ledger = self.ledger(shadow_id).await?;
+        let mut ledger = ledger.lock().await;
+        let now = Utc::now();
+
+        let shadow = ledger.performance(&self.config.cash_asset).await;
+        let live = live_pnl
+            .get(&ledger.live_id)
+            .or_else(|| {
+                ledger
+                    .live_signal_source
+                    .as_ref()
+                    .and_then(|source| live_pnl.get(source))
+            })
+            .map(|&(unrealized_pnl, realized_pnl)| LivePerformance {
+                realized_pnl,
+                unrealized_pnl,
+                total_pnl: realized_pnl + unrealized_pnl,
+            });
+        let window = self.match_window();
+
+        Some(ShadowComparison {
+            shadow_id: shadow_id.to_string(),
+            live_id: ledger.live_id.clone(),
+            pnl_difference: live.as_ref().map(|l| shadow.total_pnl - l.total_pnl),
+            shadow,
+            live,
+            signals: ledger.signals.snapshot(now, window),
+            compared_at: now,
+        })
+    }
+
+    /// 시장 데이터로 모든 가상 원장의 시세 갱신 (캔들/시세만 사용).
+    pub async fn on_market_data(&self, data: &MarketData) {
+        let kline = match &data.data {
+            MarketDataType::Kline(kline) => kline.clone(),
+            MarketDataType::Ticker(ticker) => Kline::new(
+                ticker.ticker.clone(),
+                Timeframe::M1,
+                ticker.timestamp,
+                ticker.last,
+                ticker.last,
+                ticker.last,
+                ticker.last,
+                Decimal::ZERO,
+                ticker.timestamp,
+            ),
+            MarketDataType::OrderBook(_) | MarketDataType::Trade(_) => return,
+        };
+
+        for ledger in self.all_ledgers().await {
+            ledger.lock().await.update_price(&kline).await;
+        }
+    }
+
+    /// 신호 이벤트 처리.
+    ///
+    /// 섀도 신호는 가상 원장에서 체결하고, 실전 신호는 연결된 섀도 원장의 비교 기록에 추가합니다.
+    pub async fn on_signal_event(
+        &self,
+        engine: &RwLock<StrategyEngine>,
+        event: &StrategySignalEvent,
+    ) {
+        let window = self.match_window();
+
+        if event.is_shadow() {
+            let Some(ledger) = self.ledger(&event.strategy_id).await else {
+                return;
+            };
+            let mut ledger = ledger.lock().await;
+            ledger.signals.record_shadow(SignalMark::new(event), window);
+            self.execute(engine, &event.strategy_id, &mut ledger, &event.signal)
+                .await;
+            return;
+        }
+
+        for ledger in self.all_ledgers().await {
+            let mut ledger = ledger.lock().await;
+            if ledger.live_id == event.strategy_id {
+                ledger.live_signal_source = Some(event.signal.strategy_id.clone());
+                ledger.signals.record_live(SignalMark::new(event), window);
+            }
+        }
+    }
+
+    /// 섀도 신호를 가상 주문으로 체결.
+    async fn execute(
+        &self,
+        engine: &RwLock<StrategyEngine>,
+        shadow_id: &str,
+        ledger: &mut ShadowLedger,
+        signal: &Signal,
+    ) {
+        if signal.signal_type == SignalType::Alert {
+            return;
+        }
+
+        let signals = if signal.is_rebalance() {
+            self.expand_rebalance(ledger, signal).await
+        } else {
+            vec![signal.clone()]
+        };
+
+        for signal in &signals {
+            let Some(request) = self.order_request(shadow_id, ledger, signal).await else {
+                ledger.rejected += 1;
+                debug!(shadow_id, ticker = %signal.ticker, "섀도 주문 수량 없음, 신호 무시");
+                continue;
+            };
+            self.fill(engine, shadow_id, ledger, request).await;
+        }
+    }
+
+    /// 목표 포트폴리오 신호를 가상 원장 보유 현황 기준 종목 신호로 분해.
+    async fn expand_rebalance(&self, ledger: &ShadowLedger, signal: &Signal) -> Vec<Signal> {
+        let cash_asset = &self.config.cash_asset;
+        let mut positions: Vec<_> = ledger
+            .holdings
+            .values()
+            .map(|p| PortfolioPosition::new(p.ticker.clone(), p.quantity, p.current_price))
+            .collect();
+        positions.push(PortfolioPosition::cash(
+            ledger.cash(cash_asset).await,
+            cash_asset.clone(),
+        ));
+
+        let config = RebalanceConfig {
+            cash_ticker: cash_asset.clone(),
+            ..RebalanceConfig::default()
+        };
+        RebalanceCalculator::new(config).expand_target_signal(
+            signal,
+            &positions,
+            &ledger.last_prices,
+        )
+    }
+
+    /// 신호의 가상 주문 요청 (수량 결정 규칙은 실시간 매매와 동일, 공매도 불가).
+    async fn order_request(
+        &self,
+        shadow_id: &str,
+        ledger: &ShadowLedger,
+        signal: &Signal,
+    ) -> Option<OrderRequest> {
+        let price = signal
+            .suggested_price
+            .or_else(|| ledger.last_prices.get(&signal.ticker).copied())?;
+        let held = ledger
+            .holdings
+            .get(&signal.ticker)
+            .map_or(Decimal::ZERO, |p| p.quantity);
+        let strength = Decimal::from_f64_retain(signal.strength).unwrap_or(Decimal::ONE);
+
+        let quantity = match (signal.side, signal.metadata_quantity()) {
+            (Side::Sell, Some(qty)) => qty.min(held),
+            (Side::Buy, Some(qty)) => qty,
+            (Side::Sell, None) => match signal.signal_type {
+                SignalType::Exit => held,
+                _ => held * strength,
+            },
+            (Side::Buy, None) => {
+                if price <= Decimal::ZERO {
+                    return None;
+                }
+                let equity = ledger.equity(&self.config.cash_asset).await;
+                equity * self.config.position_fraction * strength / price
+            }
+        };
+        let quantity = quantity.round_dp_with_strategy(QUANTITY_DECIMALS, RoundingStrategy::ToZero);
+        if quantity <= Decimal::ZERO {
+            return None;
+        }
+
+        let request = match signal.side {
+            Side::Buy => OrderRequest::market_buy(signal.ticker.clone(), quantity),
+            Side::Sell => OrderRequest::market_sell(signal.ticker.clone(), quantity),
+        };
+        Some(request.with_strategy(shadow_id))
+    }
+
+    /// 가상 주문 제출 후 체결을 원장과 섀도 전략에 반영.
+    async fn fill(
+        &self,
+        engine: &RwLock<StrategyEngine>,
+        shadow_id: &str,
+        ledger: &mut ShadowLedger,
+        request: OrderRequest,
+    ) {
+        let status = match ledger.exchange.place_order(&request).await {
+            Ok(order_id) => ledger.exchange.get_order(&request.ticker, &order_id).await,
+            Err(e) => Err(e),
+        };
+        let status = match status {
+            Ok(status) if status.status == OrderStatusType::Filled => status,
+            Ok(status) => {
+                ledger.rejected += 1;
+                warn!(shadow_id, order_id = %status.order_id, "섀도 주문 미체결");
+                return;
+            }
+            Err(e) => {
+                ledger.rejected += 1;
+                debug!(shadow_id, ticker = %request.ticker, "섀도 주문 거부: {}", e);
+                return;
+            }
+        };
+
+        let price = status.average_price.unwrap_or_default();
+        let quantity = status.filled_quantity;
+        let ticker = request.ticker.clone();
+        let position = match request.side {
+            Side::Buy => {
+                let position = ledger
+                    .holdings
+                    .entry(ticker.clone())
+                    .and_modify(|p| p.add(quantity, price))
+                    .or_insert_with(|| {
+                        Position::new(SHADOW_EXCHANGE, ticker.clone(), Side::Buy, quantity, price)
+                            .with_strategy(shadow_id)
+                    });
+                position.clone()
+            }
+            Side::Sell => {
+                let Some(mut position) = ledger.holdings.remove(&ticker) else {
+                    return;
+                };
+                ledger.realized_pnl += position.reduce(quantity, price);
+                if position.is_open() {
+                    ledger.holdings.insert(ticker.clone(), position.clone());
+                }
+                position
+            }
+        };
+        ledger.fills += 1;
+
+        let mut order = Order::from_request(request, SHADOW_EXCHANGE);
+        order.exchange_order_id = Some(status.order_id);
+        order.status = OrderStatusType::Filled;
+        order.filled_quantity = quantity;
+        order.average_fill_price = Some(price);
+        order.updated_at = status.updated_at;
+
+        let engine = engine.read().await;
+        if let Err(e) = engine.notify_shadow_order_filled(shadow_id, &order).await {
+            warn!(shadow_id, "섀도 체결 알림 실패: {}", e);
+        }
+        if let Err(e) = engine
+            .notify_shadow_position_update(shadow_id, &position)
+            .await
+        {
+            warn!(shadow_id, "섀도 포지션 알림 실패: {}", e);
+        }
+    }
+
+    fn match_window(&self) -> chrono::Duration {
+        chrono::Duration::from_std(self.config.match_window)
+            .unwrap_or_else(|_| chrono::Duration::minutes(5))
+    }
+
+    async fn ledger(&self, shadow_id: &str) -> Option<Arc<Mutex<ShadowLedger>>> {
+        self.ledgers.read().await.get(shadow_id).cloned()
+    }
+
+    async fn all_ledgers(&self) -> Vec<Arc<Mutex<ShadowLedger>>> {
+        self.ledgers.read().await.values().cloned().collect()
+    }
+}
+
+/// 섀도 실행 서비스 시작.
+///
+/// 엔진의 시장 데이터와 신호 이벤트를 구독하여 [`ShadowBook`]의 가상 원장을 갱신합니다.
+/// 같은 시장 데이터의 시세가 신호보다 먼저 반영되도록 시장 데이터를 우선 처리합니다.
+///
+/// # Arguments
+///
+/// * `engine` - 전략 엔진
+/// * `book` - 섀도 가상 원장 모음
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+pub async fn start_shadow_trading_service(
+    engine: Arc<RwLock<StrategyEngine>>,
+    book: Arc<ShadowBook>,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let (mut market_rx, mut signal_rx) = {
+        let engine = engine.read().await;
+        (
+            engine.market_data_sender().subscribe(),
+            engine.subscribe_signal_events(),
+        )
+    };
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                biased;
+
+                _ = shutdown.cancelled() => {
+                    info!("섀도 실행 서비스 종료");
+                    break;
+                }
+
+                result = market_rx.recv() => match result {
+                    Ok(data) => book.on_market_data(&data).await,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!(skipped = n, "섀도 실행 서비스 시장 데이터 수신 지연");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+
+                result = signal_rx.recv() => match result {
+                    Ok(event) => book.on_signal_event(&engine, &event).await,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!(skipped = n, "섀도 실행 서비스 신호 수신 지연, 일부 신호 누락");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use trader_strategy::{EngineConfig, Strategy};
+
+    const TICKER: &str = "BTC/USDT";
+
+    /// 종가가 기준가 이상이면 매수, 미만이면 청산하는 테스트 전략.
+    struct ThresholdStrategy {
+        threshold: Decimal,
+        holding: bool,
+    }
+
+    #[async_trait]
+    impl Strategy for ThresholdStrategy {
+        fn name(&self) -> &str {
+            "threshold"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Test strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            if let Some(threshold) = config.get("threshold").and_then(Value::as_i64) {
+                self.threshold = Decimal::from(threshold);
+            }
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            let MarketDataType::Kline(kline) = &data.data else {
+                return Ok(vec![]);
+            };
+            if !self.holding && kline.close >= self.threshold {
+                self.holding = true;
+                return Ok(vec![Signal::entry(
+                    "threshold",
+                    data.ticker.clone(),
+                    Side::Buy,
+                )
+                .with_metadata("quantity", json!("1"))]);
+            }
+            if self.holding && kline.close < self.threshold {
+                self.holding = false;
+                return Ok(vec![Signal::exit(
+                    "threshold",
+                    data.ticker.clone(),
+                    Side::Sell,
+                )]);
+            }
+            Ok(vec![])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            json!({ "holding": self.holding })
+        }
+    }
+
+    fn market_data(index: i64, close: Decimal) -> MarketData {
+        let open_time = Utc::now() + chrono::Duration::minutes(index);
+        MarketData::from_kline(
+            "test",
+            Kline::new(
+                TICKER.to_string(),
+                Timeframe::M1,
+                open_time,
+                close,
+                close,
+                close,
+                close,
+                dec!(10),
+                open_time + chrono::Duration::minutes(1),
+            ),
+        )
+    }
+
+    async fn start(engine: &StrategyEngine, id: &str, live_id: Option<&str>, threshold: i64) {
+        let strategy = Box::new(ThresholdStrategy {
+            threshold: Decimal::ZERO,
+            holding: false,
+        });
+        let config = json!({ "threshold": threshold });
+        match live_id {
+            Some(live_id) => engine
+                .register_shadow_strategy(id, live_id, strategy, config, None)
+                .await
+                .unwrap(),
+            None => engine
+                .register_strategy(id, strategy, config, None)
+                .await
+                .unwrap(),
+        }
+        engine.start_strategy(id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shadow_fills_in_virtual_ledger_and_compares_signals() {
+        // 같은 방향 신호가 연속으로 나오므로 중복 제거 비활성화
+        let config = EngineConfig {
+            deduplicate_signals: false,
+            ..EngineConfig::default()
+        };
+        let engine = Arc::new(RwLock::new(StrategyEngine::new(config)));
+        let book = Arc::new(ShadowBook::default());
+        {
+            let engine = engine.read().await;
+            start(&engine, "live", None, 100).await;
+            start(&engine, "shadow", Some("live"), 105).await;
+        }
+        book.open("shadow", "live", Some(dec!(1000))).await;
+
+        let shutdown = CancellationToken::new();
+        let handle =
+            start_shadow_trading_service(engine.clone(), book.clone(), shutdown.clone()).await;
+
+        // 100: 실전 진입, 90: 실전 청산, 110: 둘 다 진입, 80: 둘 다 청산
+        let closes = [dec!(100), dec!(90), dec!(110), dec!(80)];
+        for (index, close) in closes.into_iter().enumerate() {
+            let signals = engine
+                .read()
+                .await
+                .process_market_data(market_data(index as i64, close))
+                .await
+                .unwrap();
+            // 섀도 신호는 실전 주문 신호에 포함되지 않음
+            assert_eq!(signals.len(), 1);
+        }
+
+        // 실전 손익은 실전 신호의 strategy_id로 조회
+        let live_pnl = HashMap::from([("threshold".to_string(), (dec!(5), dec!(-3)))]);
+        let mut comparison = None;
+        for _ in 0..200 {
+            comparison = book.compare("shadow", &live_pnl).await;
+            if comparison.as_ref().is_some_and(|c| c.shadow.fills == 2) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let comparison = comparison.unwrap();
+
+        // 110에 1개 매수, 80에 매도 → 약 -30 (수수료/슬리피지 별도)
+        assert_eq!(comparison.shadow.fills, 2);
+        assert!(comparison.shadow.positions.is_empty());
+        assert!(comparison.shadow.realized_pnl < dec!(-30));
+        assert!(comparison.shadow.total_pnl < comparison.shadow.realized_pnl);
+        let live = comparison.live.unwrap();
+        assert_eq!(live.total_pnl, dec!(2));
+        assert_eq!(
+            comparison.pnl_difference,
+            Some(comparison.shadow.total_pnl - dec!(2))
+        );
+
+        // 섀도 신호는 윈도우 안의 가장 오래된 실전 신호와 짝지어짐
+        assert_eq!(comparison.signals.live_signals, 4);
+        assert_eq!(comparison.signals.shadow_signals, 2);
+        assert_eq!(comparison.signals.matched, 2);
+        assert_eq!(comparison.signals.pending, 2);
+
+        let status = engine
+            .read()
+            .await
+            .get_strategy_status("shadow")
+            .await
+            .unwrap();
+        assert_eq!(status.stats.orders_filled, 2);
+
+        shutdown.cancel();
+        handle.await.unwrap();
+    }
+
+    #[test]
+    fn test_signal_tracker_expires_unmatched_signals() {
+        let window = chrono::Duration::seconds(60);
+        let now = Utc::now();
+        let mark = |offset: i64, side: Side| SignalMark {
+            ticker: TICKER.to_string(),
+            side,
+            signal_type: SignalType::Entry,
+            at: now + chrono::Duration::seconds(offset),
+        };
+
+        let mut tracker = SignalTracker::default();
+        tracker.record_live(mark(0, Side::Buy), window);
+        tracker.record_shadow(mark(30, Side::Buy), window);
+        tracker.record_live(mark(40, Side::Sell), window);
+
+        let stats = tracker.snapshot(now + chrono::Duration::seconds(200), window);
+        assert_eq!(stats.matched, 1);
+        assert_eq!(stats.avg_lag_ms, Some(30_000.0));
+        assert_eq!(stats.live_only, 1);
+        assert_eq!(stats.pending, 0);
+        assert!((stats.divergence_rate - 1.0 / 3.0).abs() < 1e-9);
+    }
+}
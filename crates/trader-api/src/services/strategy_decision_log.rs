@@ -0,0 +1,62 @@
+//! 전략 의사결정 로그 서비스.
+//!
+//! StrategyEngine의 의사결정 기록을 구독하여 `strategy_decisions` 테이블에 저장합니다.
+//! 종료 시그널을 받으면 이미 수신한 기록까지 저장한 뒤 종료합니다.
+
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+use tokio_util::sync::CancellationToken;
+use trader_strategy::{DecisionRecord, StrategyEngine};
+
+use crate::repository::StrategyDecisionRepository;
+
+/// 전략 의사결정 로그 서비스 시작.
+///
+/// # Arguments
+///
+/// * `engine` - 전략 엔진
+/// * `pool` - 의사결정 로그를 저장할 DB 연결 풀
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+pub async fn start_strategy_decision_log_service(
+    engine: Arc<RwLock<StrategyEngine>>,
+    pool: PgPool,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut rx = engine.read().await.subscribe_decisions();
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                result = rx.recv() => {
+                    match result {
+                        Ok(record) => persist(&pool, &record).await,
+                        Err(broadcast::error::RecvError::Lagged(n)) => {
+                            tracing::warn!(skipped = n, "의사결정 로그 수신 지연, 일부 기록 누락");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+
+                _ = shutdown.cancelled() => {
+                    while let Ok(record) = rx.try_recv() {
+                        persist(&pool, &record).await;
+                    }
+                    tracing::info!("전략 의사결정 로그 서비스 종료");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+async fn persist(pool: &PgPool, record: &DecisionRecord) {
+    if let Err(e) = StrategyDecisionRepository::insert(pool, record).await {
+        tracing::warn!(
+            strategy_id = %record.strategy_id,
+            trace_id = %record.trace_id,
+            error = %e,
+            "의사결정 기록 저장 실패"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! 전략 생명주기 감사 로그 서비스.
+//!
+//! StrategyEngine의 상태 전이 이벤트를 구독하여 `strategy_lifecycle_events` 테이블에
+//! 기록합니다. 종료 시그널을 받으면 이미 수신한 전이까지 저장한 뒤 종료합니다.
+
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+use tokio_util::sync::CancellationToken;
+use trader_strategy::{LifecycleTransition, StrategyEngine};
+
+use crate::repository::StrategyLifecycleRepository;
+
+/// 전략 생명주기 감사 로그 서비스 시작.
+///
+/// # Arguments
+///
+/// * `engine` - 전략 엔진
+/// * `pool` - 감사 로그를 저장할 DB 연결 풀
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+pub async fn start_strategy_lifecycle_audit_service(
+    engine: Arc<RwLock<StrategyEngine>>,
+    pool: PgPool,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut rx = engine.read().await.subscribe_transitions();
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                result = rx.recv() => {
+                    match result {
+                        Ok(transition) => persist(&pool, &transition).await,
+                        Err(broadcast::error::RecvError::Lagged(n)) => {
+                            tracing::warn!(skipped = n, "생명주기 감사 로그 수신 지연, 일부 전이 누락");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+
+                _ = shutdown.cancelled() => {
+                    while let Ok(transition) = rx.try_recv() {
+                        persist(&pool, &transition).await;
+                    }
+                    tracing::info!("전략 생명주기 감사 로그 서비스 종료");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+async fn persist(pool: &PgPool, transition: &LifecycleTransition) {
+    if let Err(e) = StrategyLifecycleRepository::insert(pool, transition).await {
+        tracing::warn!(
+            strategy_id = %transition.strategy_id,
+            to = %transition.to,
+            error = %e,
+            "생명주기 전이 저장 실패"
+        );
+    }
+}
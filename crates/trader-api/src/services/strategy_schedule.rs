@@ -0,0 +1,94 @@
+//! 전략 스케줄 트리거 서비스.
+//!
+//! 일정 주기로 StrategyEngine의 스케줄러를 현재 시각까지 진행시켜,
+//! 발생 시각이 지난 트리거마다 전략의 `on_schedule()`을 호출합니다.
+//! 생성된 신호는 시장 데이터 신호와 같은 신호 채널로 전달됩니다.
+//!
+//! KIS 설정이 있으면 [`KisTradingCalendar`]로 KRX/미국 휴장일을 반영합니다.
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use trader_exchange::connector::kis::HolidayChecker;
+use trader_strategy::{ScheduleMarket, StrategyEngine, TradingCalendar, WeekdayCalendar};
+
+/// 기본 스케줄 확인 주기.
+pub const DEFAULT_SCHEDULE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// KIS 휴장일 API 기반 거래일 캘린더.
+///
+/// 조회 결과는 `HolidayChecker`가 월 단위로 캐시합니다.
+/// API 조회에 실패하면 주말만 휴장으로 보는 기본 캘린더로 판정합니다.
+pub struct KisTradingCalendar {
+    checker: HolidayChecker,
+    fallback: WeekdayCalendar,
+}
+
+impl KisTradingCalendar {
+    /// 새 캘린더 생성.
+    pub fn new(checker: HolidayChecker) -> Self {
+        Self {
+            checker,
+            fallback: WeekdayCalendar::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl TradingCalendar for KisTradingCalendar {
+    async fn is_trading_day(&self, market: ScheduleMarket, date: NaiveDate) -> bool {
+        let result = match market {
+            ScheduleMarket::Krx => self.checker.is_kr_holiday(date).await,
+            ScheduleMarket::Us => self.checker.is_us_holiday(date).await,
+            ScheduleMarket::Crypto => return true,
+        };
+
+        match result {
+            Ok(is_holiday) => !is_holiday,
+            Err(e) => {
+                tracing::warn!(?market, %date, error = %e, "휴장일 조회 실패, 주말 기준으로 판정");
+                self.fallback.is_open(market, date)
+            }
+        }
+    }
+}
+
+/// 전략 스케줄 트리거 서비스 시작.
+///
+/// # Arguments
+///
+/// * `engine` - 전략 엔진
+/// * `interval` - 스케줄 확인 주기 (트리거 발생 시각의 최대 지연)
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+pub fn start_strategy_schedule_service(
+    engine: Arc<RwLock<StrategyEngine>>,
+    interval: Duration,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    let engine = engine.read().await;
+                    match engine.process_schedule(Utc::now()).await {
+                        Ok(signals) if !signals.is_empty() => {
+                            tracing::info!(signals = signals.len(), "스케줄 트리거 신호 생성");
+                        }
+                        Ok(_) => {}
+                        Err(e) => tracing::error!(error = %e, "스케줄 트리거 처리 실패"),
+                    }
+                }
+
+                _ = shutdown.cancelled() => {
+                    tracing::info!("전략 스케줄 서비스 종료");
+                    break;
+                }
+            }
+        }
+    })
+}
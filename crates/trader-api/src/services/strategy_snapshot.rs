@@ -0,0 +1,144 @@
+//! 전략 상태 주기적 스냅샷 서비스.
+//!
+//! 실행 중인 전략의 내부 상태를 일정 주기로 상태 저장소에 저장하고,
+//! 종료 시그널을 받으면 마지막 스냅샷을 남긴 뒤 종료합니다.
+//! 비정상 종료 시에도 최대 한 주기만큼의 상태만 유실됩니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use trader_strategy::StrategyEngine;
+
+/// 기본 스냅샷 주기.
+pub const DEFAULT_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 전략 상태 스냅샷 서비스 시작.
+///
+/// # Arguments
+///
+/// * `engine` - 상태 저장소가 설정된 전략 엔진
+/// * `interval` - 스냅샷 주기
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+pub fn start_strategy_snapshot_service(
+    engine: Arc<RwLock<StrategyEngine>>,
+    interval: Duration,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // 첫 tick은 즉시 발생하므로 건너뜀 (시작 직후에는 저장할 변경이 없음)
+        ticker.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    engine.read().await.snapshot_all_strategies().await;
+                }
+
+                _ = shutdown.cancelled() => {
+                    let saved = engine.read().await.snapshot_all_strategies().await;
+                    tracing::info!(saved, "종료 전 전략 상태 스냅샷 저장");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use trader_core::{MarketData, Order, Position, Signal};
+    use trader_strategy::{InMemoryStateStore, Strategy, StrategyStateStore};
+
+    struct CounterStrategy {
+        count: u8,
+    }
+
+    #[async_trait]
+    impl Strategy for CounterStrategy {
+        fn name(&self) -> &str {
+            "counter"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "counter"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            _data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(vec![])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            Value::Null
+        }
+
+        fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(vec![self.count])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_saved_on_shutdown() {
+        let store = Arc::new(InMemoryStateStore::new());
+        let engine = StrategyEngine::new(Default::default()).with_state_store(store.clone());
+        engine
+            .register_strategy(
+                "c1",
+                Box::new(CounterStrategy { count: 7 }),
+                Value::Null,
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("c1").await.unwrap();
+
+        let shutdown = CancellationToken::new();
+        let handle = start_strategy_snapshot_service(
+            Arc::new(RwLock::new(engine)),
+            Duration::from_secs(3600),
+            shutdown.clone(),
+        );
+
+        shutdown.cancel();
+        handle.await.unwrap();
+
+        let snapshot = store.load("c1").await.unwrap().unwrap();
+        assert_eq!(snapshot.state, vec![7]);
+    }
+}
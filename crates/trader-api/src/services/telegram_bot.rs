@@ -1,6 +1,7 @@
 //! 텔레그램 봇 서비스.
 //!
 //! 실제 데이터를 조회하여 봇 명령어에 응답합니다.
+//! `/stop` 명령어는 전략 엔진의 생명주기 전이(중지, 청산 후 중지, 일시 정지)로 처리됩니다.
 
 use std::sync::Arc;
 
@@ -8,40 +9,82 @@ use async_trait::async_trait;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use sqlx::PgPool;
+use tokio::sync::RwLock;
 use tracing::{debug, error, info};
 
 use trader_notification::{
-    BotCommandHandler, CommandResponse, NotificationResult, ReportPeriod, TelegramBotHandler,
-    TelegramConfig,
+    BotCommandHandler, CommandResponse, NotificationResult, ReportPeriod, StopMode,
+    TelegramBotHandler, TelegramConfig,
 };
+use trader_strategy::{EngineError, LifecycleAction, StrategyEngine, StrategyLifecycle};
+
+/// 텔레그램에서 요청한 생명주기 전이의 요청 주체.
+const TELEGRAM_ACTOR: &str = "telegram";
 
 /// API 연동 봇 핸들러.
 ///
 /// 실제 데이터베이스를 조회하여 응답합니다.
 pub struct ApiBotHandler {
     db_pool: PgPool,
+    strategy_engine: Option<Arc<RwLock<StrategyEngine>>>,
 }
 
 impl ApiBotHandler {
     /// 새 핸들러 생성.
     pub fn new(db_pool: PgPool) -> Self {
-        Self { db_pool }
+        Self {
+            db_pool,
+            strategy_engine: None,
+        }
+    }
+
+    /// 전략 엔진 연결 (빌더).
+    ///
+    /// 연결되면 `/stop`이 실행 중인 전략에 바로 반영됩니다.
+    pub fn with_strategy_engine(mut self, engine: Arc<RwLock<StrategyEngine>>) -> Self {
+        self.strategy_engine = Some(engine);
+        self
     }
 
     /// 봇 핸들러 시작 (백그라운드 태스크).
-    pub async fn start(db_pool: PgPool) {
+    pub async fn start(db_pool: PgPool, strategy_engine: Arc<RwLock<StrategyEngine>>) {
         let Some(config) = TelegramConfig::from_env() else {
             info!("텔레그램 설정이 없어 봇 명령어 핸들러를 시작하지 않습니다.");
             return;
         };
 
-        let handler = Arc::new(ApiBotHandler::new(db_pool));
+        let handler = Arc::new(ApiBotHandler::new(db_pool).with_strategy_engine(strategy_engine));
         let bot = TelegramBotHandler::new(config, handler);
 
         info!("텔레그램 봇 명령어 핸들러 시작");
         bot.start_polling().await;
     }
 
+    /// 실행 중인 전략에 중지 방식에 맞는 생명주기 동작 적용.
+    ///
+    /// 엔진이 연결되지 않았으면 None을 반환합니다.
+    async fn apply_stop_to_engine(
+        &self,
+        id: &str,
+        mode: StopMode,
+    ) -> Option<Result<StrategyLifecycle, EngineError>> {
+        let engine = self.strategy_engine.as_ref()?;
+        let action = match mode {
+            StopMode::Immediate => LifecycleAction::Stop,
+            StopMode::Drain => LifecycleAction::Drain,
+            StopMode::Pause => LifecycleAction::Pause,
+        };
+        let reason = format!("/stop {:?}", mode).to_lowercase();
+
+        Some(
+            engine
+                .read()
+                .await
+                .apply_lifecycle_action(id, action, TELEGRAM_ACTOR, &reason)
+                .await,
+        )
+    }
+
     /// 금액 포맷팅 (한국 원화).
     fn format_krw(amount: Decimal) -> String {
         let amount_f64 = amount.to_f64().unwrap_or(0.0);
@@ -208,7 +251,11 @@ impl BotCommandHandler for ApiBotHandler {
         )))
     }
 
-    async fn handle_stop(&self, strategy_id: Option<&str>) -> NotificationResult<CommandResponse> {
+    async fn handle_stop(
+        &self,
+        strategy_id: Option<&str>,
+        mode: StopMode,
+    ) -> NotificationResult<CommandResponse> {
         let Some(id) = strategy_id else {
             // 활성 전략 목록 표시
             let strategies: Vec<(String, String)> = sqlx::query_as(
@@ -233,11 +280,49 @@ impl BotCommandHandler for ApiBotHandler {
             return Ok(CommandResponse::html(format!(
                 "⏹️ <b>전략 중지</b>\n\n\
                  <b>활성 전략:</b>\n{}\n\n\
-                 사용법: /stop [전략ID]",
+                 사용법: /stop [전략ID] [drain|pause]",
                 list.join("\n")
             )));
         };
 
+        match self.apply_stop_to_engine(id, mode).await {
+            // 엔진에 없는 전략이거나 이미 멈춘 전략은 DB 상태만 갱신
+            None
+            | Some(Err(EngineError::StrategyNotFound(_)))
+            | Some(Err(EngineError::NotRunning(_)))
+                if mode == StopMode::Immediate => {}
+            None | Some(Err(EngineError::StrategyNotFound(_))) => {
+                return Ok(CommandResponse::html(format!(
+                    "⏹️ <b>전략 전환 실패</b>\n\n\
+                     전략 ID <code>{}</code>가 실행 중이 아닙니다.",
+                    id
+                )));
+            }
+            Some(Ok(lifecycle)) if mode != StopMode::Immediate => {
+                info!(strategy_id = id, %lifecycle, "전략 생명주기 전환 성공");
+                let status = match lifecycle {
+                    StrategyLifecycle::Paused => "일시 정지 (신호 중단)",
+                    StrategyLifecycle::Draining => "청산 대기 (신규 진입 차단, 청산 후 자동 중지)",
+                    _ => "중지됨 (보유 포지션 없음)",
+                };
+                return Ok(CommandResponse::html(format!(
+                    "⏸️ <b>전략 전환 완료</b>\n\n\
+                     전략 ID: <code>{}</code>\n\
+                     상태: {}",
+                    id, status
+                )));
+            }
+            Some(Ok(_)) => {}
+            Some(Err(e)) => {
+                error!(strategy_id = id, error = %e, "전략 생명주기 전환 실패");
+                return Ok(CommandResponse::html(format!(
+                    "⏹️ <b>전략 중지 실패</b>\n\n\
+                     오류: {}",
+                    e
+                )));
+            }
+        }
+
         // 전략 상태 업데이트
         let result = sqlx::query(
             "UPDATE strategies SET status = 'stopped', updated_at = NOW() WHERE strategy_id = $1",
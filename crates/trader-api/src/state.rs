@@ -14,14 +14,31 @@ use trader_core::{AnalyticsProvider, ExchangeProvider, StrategyContext};
 use trader_data::cache::CachedHistoricalDataProvider;
 use trader_data::{RedisCache, RedisConfig, SymbolResolver};
 use trader_exchange::connector::kis::{KisKrClient, KisOAuth, KisUsClient};
+use trader_exchange::{Exchange, UserStream};
 use trader_execution::OrderExecutor;
 use trader_notification::NotificationManager;
 use trader_risk::RiskManager;
-use trader_strategy::StrategyEngine;
+use trader_strategy::{StrategyEngine, TradingCalendar};
 use uuid::Uuid;
 
-use crate::repository::ExchangeProviderPair;
+use crate::repository::{ExchangeProviderPair, PgStrategyStateStore};
+use crate::services::circuit_breaker::{
+    start_circuit_breaker_service, DEFAULT_CIRCUIT_BREAKER_INTERVAL,
+};
 use crate::services::context_sync::start_context_sync_service;
+use crate::services::live_trading::{start_live_trading_service, LiveTradingConfig};
+use crate::services::portfolio_risk::{
+    start_portfolio_risk_service, DEFAULT_PORTFOLIO_RISK_INTERVAL,
+};
+use crate::services::shadow_trading::{start_shadow_trading_service, ShadowBook};
+use crate::services::strategy_decision_log::start_strategy_decision_log_service;
+use crate::services::strategy_lifecycle::start_strategy_lifecycle_audit_service;
+use crate::services::strategy_schedule::{
+    start_strategy_schedule_service, DEFAULT_SCHEDULE_INTERVAL,
+};
+use crate::services::strategy_snapshot::{
+    start_strategy_snapshot_service, DEFAULT_SNAPSHOT_INTERVAL,
+};
 use crate::websocket::{ServerMessage, SharedSubscriptionManager};
 
 /// 애플리케이션 공유 상태.
@@ -39,6 +56,9 @@ pub struct AppState {
     /// 주문 실행기 - 신호→주문 변환, 포지션 추적
     pub executor: Arc<RwLock<OrderExecutor>>,
 
+    /// 섀도 전략 가상 원장 - 설정 변경 검증용 모의 실행 결과
+    pub shadow_book: Arc<ShadowBook>,
+
     /// 데이터베이스 연결 풀 (TimescaleDB/PostgreSQL)
     pub db_pool: Option<sqlx::PgPool>,
 
@@ -135,6 +155,7 @@ impl AppState {
             strategy_engine: Arc::new(RwLock::new(strategy_engine)),
             risk_manager: Arc::new(RwLock::new(risk_manager)),
             executor: Arc::new(RwLock::new(executor)),
+            shadow_book: Arc::new(ShadowBook::default()),
             db_pool: None,
             cache: None,
             kis_kr_client: None,
@@ -270,6 +291,206 @@ impl AppState {
         ))
     }
 
+    /// 전략 상태 영속화 활성화.
+    ///
+    /// DB 연결이 설정되어 있으면 StrategyEngine에 PostgreSQL 상태 저장소를 연결합니다.
+    /// 전략 시작 시 마지막 스냅샷으로 상태가 복원되므로, 전략을 시작하기 전에 호출해야 합니다.
+    ///
+    /// # Returns
+    ///
+    /// 상태 저장소가 연결되었으면 true
+    pub async fn enable_strategy_state_persistence(&self) -> bool {
+        let Some(pool) = self.db_pool.clone() else {
+            return false;
+        };
+
+        self.strategy_engine
+            .write()
+            .await
+            .set_state_store(Arc::new(PgStrategyStateStore::new(pool)));
+        true
+    }
+
+    /// 전략 상태 스냅샷 서비스 시작.
+    ///
+    /// 실행 중인 전략의 상태를 주기적으로 저장하고, 종료 시 마지막 스냅샷을 저장합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 상태 저장소가 설정되지 않은 것입니다.
+    pub async fn start_strategy_snapshots(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        if !self.strategy_engine.read().await.has_state_store() {
+            return None;
+        }
+
+        Some(start_strategy_snapshot_service(
+            self.strategy_engine.clone(),
+            DEFAULT_SNAPSHOT_INTERVAL,
+            shutdown,
+        ))
+    }
+
+    /// 전략 생명주기 감사 로그 서비스 시작.
+    ///
+    /// 전략 상태 전이(시작, 일시 정지, 청산 대기, 중지 등)를 DB에 기록합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 DB가 연결되지 않은 것입니다.
+    pub async fn start_strategy_lifecycle_audit(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let pool = self.db_pool.clone()?;
+
+        Some(
+            start_strategy_lifecycle_audit_service(self.strategy_engine.clone(), pool, shutdown)
+                .await,
+        )
+    }
+
+    /// 전략 의사결정 로그 서비스 시작.
+    ///
+    /// 캔들별 조건 평가, 필터/리스크 거부, 주문 제출 결과를 DB에 기록합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 DB가 연결되지 않은 것입니다.
+    pub async fn start_strategy_decision_log(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let pool = self.db_pool.clone()?;
+
+        Some(
+            start_strategy_decision_log_service(self.strategy_engine.clone(), pool, shutdown).await,
+        )
+    }
+
+    /// 포트폴리오 리스크 모니터링 서비스 시작.
+    ///
+    /// 보유/구독 종목의 일봉과 종목 분류(DB 연결 시)를 OrderExecutor의 RiskManager에 공급하고,
+    /// VaR/ES 한도 초과 시 리스크 경고 알림을 전송합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 데이터 제공자가 설정되지 않은 것입니다.
+    pub fn start_portfolio_risk(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let data_provider = self.data_provider.clone()?;
+
+        Some(start_portfolio_risk_service(
+            self.executor.clone(),
+            self.strategy_engine.clone(),
+            data_provider,
+            self.db_pool.clone(),
+            self.notification_manager.clone(),
+            DEFAULT_PORTFOLIO_RISK_INTERVAL,
+            shutdown,
+        ))
+    }
+
+    /// 드로다운 서킷 브레이커 서비스 시작.
+    ///
+    /// 저장된 서킷 브레이커 상태를 복원하고, `portfolio_equity_history`의 자산 곡선으로
+    /// 드로다운 브레이커를 확인하며 상태 변경을 DB에 저장합니다.
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 DB가 연결되지 않은 것입니다.
+    pub async fn start_circuit_breaker(
+        &self,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let pool = self.db_pool.clone()?;
+
+        Some(
+            start_circuit_breaker_service(
+                self.executor.clone(),
+                pool,
+                self.notification_manager.clone(),
+                DEFAULT_CIRCUIT_BREAKER_INTERVAL,
+                shutdown,
+            )
+            .await,
+        )
+    }
+
+    /// 섀도 실행 서비스 시작.
+    ///
+    /// 섀도 전략의 신호를 가상 원장에서 체결하고, 실전 전략과의 신호 타이밍을 기록합니다.
+    pub async fn start_shadow_trading(
+        &self,
+        shutdown: CancellationToken,
+    ) -> tokio::task::JoinHandle<()> {
+        start_shadow_trading_service(
+            self.strategy_engine.clone(),
+            self.shadow_book.clone(),
+            shutdown,
+        )
+        .await
+    }
+
+    /// 전략 스케줄 트리거 서비스 시작.
+    ///
+    /// 실행 중인 전략이 등록한 스케줄 트리거를 주기적으로 확인하여 `on_schedule()`을 호출합니다.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar` - 거래일 캘린더 (None이면 주말만 휴장으로 판정)
+    /// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+    pub async fn start_strategy_schedule(
+        &self,
+        calendar: Option<Arc<dyn TradingCalendar>>,
+        shutdown: CancellationToken,
+    ) -> tokio::task::JoinHandle<()> {
+        if let Some(calendar) = calendar {
+            self.strategy_engine.write().await.set_calendar(calendar);
+        }
+
+        start_strategy_schedule_service(
+            self.strategy_engine.clone(),
+            DEFAULT_SCHEDULE_INTERVAL,
+            shutdown,
+        )
+    }
+
+    /// 실시간 매매 런타임(LiveTradingService) 시작.
+    ///
+    /// StrategyEngine의 신호를 주어진 거래소로 주문하고, 체결 이벤트를
+    /// OrderExecutor와 전략에 전달하는 백그라운드 태스크를 시작합니다.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange` - 주문을 제출할 거래소
+    /// * `user_stream` - 체결 이벤트 스트림 (None이면 미체결 주문 폴링)
+    /// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+    ///
+    /// # Returns
+    ///
+    /// 백그라운드 태스크의 JoinHandle. None이면 신호 수신기가 이미 사용 중인 것입니다.
+    pub async fn start_live_trading(
+        &self,
+        exchange: Arc<dyn Exchange>,
+        user_stream: Option<Box<dyn UserStream>>,
+        shutdown: CancellationToken,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        start_live_trading_service(
+            self.strategy_engine.clone(),
+            self.executor.clone(),
+            exchange,
+            user_stream,
+            LiveTradingConfig::default(),
+            shutdown,
+        )
+        .await
+    }
+
     /// Redis 캐시 설정.
     ///
     /// trader-data의 RedisCache를 사용하여 API 응답 캐싱을 활성화합니다.
@@ -384,7 +384,7 @@ fn create_symbol(config: &BacktestCliConfig) -> Symbol {
 }
 
 /// 데이터베이스에서 캔들 데이터 로드
-async fn load_klines_from_db(
+pub(crate) async fn load_klines_from_db(
     kline_repo: &KlineRepository,
     symbol_id: uuid::Uuid,
     symbol: &Symbol,
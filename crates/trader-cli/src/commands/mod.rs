@@ -7,6 +7,7 @@ pub mod fetch_symbols;
 pub mod health;
 pub mod import;
 pub mod list_symbols;
+pub mod optimize;
 pub mod strategy_test;
 // sync_csv는 trader-collector로 이동됨
 
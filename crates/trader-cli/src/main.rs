@@ -220,6 +220,93 @@ enum Commands {
         list_strategies: bool,
     },
 
+    /// 전략 파라미터 최적화 (격자/무작위 탐색)
+    Optimize {
+        /// 전략 ID (예: rsi, grid, bollinger)
+        #[arg(short = 'i', long)]
+        strategy: String,
+
+        /// 시장 유형 (KR: 한국, US: 미국)
+        #[arg(short, long, default_value = "KR")]
+        market: String,
+
+        /// 종목 코드/심볼 (예: 005930, SPY)
+        #[arg(short, long)]
+        symbol: String,
+
+        /// 시작 날짜 (YYYY-MM-DD)
+        #[arg(short = 'f', long)]
+        from: Option<String>,
+
+        /// 종료 날짜 (YYYY-MM-DD)
+        #[arg(short, long)]
+        to: Option<String>,
+
+        /// 초기 자본금 (기본: 10,000,000원)
+        #[arg(long, default_value = "10000000")]
+        capital: String,
+
+        /// 기본 JSON 설정 (탐색하지 않는 값)
+        #[arg(short, long)]
+        config: Option<String>,
+
+        /// 탐색할 파라미터 (쉼표 구분, 기본: 스키마의 모든 숫자 필드)
+        #[arg(short, long)]
+        params: Option<String>,
+
+        /// 탐색 범위 덮어쓰기 (name=min:max:step, 반복 지정 가능)
+        #[arg(long = "range")]
+        ranges: Vec<String>,
+
+        /// 목적 함수 (sharpe, sortino, calmar, expectancy, total_return, profit_factor)
+        #[arg(short, long, default_value = "sharpe")]
+        objective: String,
+
+        /// 무작위 탐색 횟수 (지정하지 않으면 격자 탐색)
+        #[arg(long)]
+        samples: Option<usize>,
+
+        /// 무작위 탐색 시드
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// 최대 조합 수
+        #[arg(long, default_value = "1000")]
+        max_combinations: usize,
+
+        /// 동시 실행 백테스트 수 (기본: CPU 코어 수)
+        #[arg(long)]
+        parallel: Option<usize>,
+
+        /// 출력할 상위 결과 수
+        #[arg(long, default_value = "10")]
+        top: usize,
+
+        /// 데이터베이스 URL
+        #[arg(long)]
+        db_url: Option<String>,
+
+        /// 결과 저장 경로 (.json 또는 텍스트)
+        #[arg(long)]
+        output: Option<String>,
+
+        /// 워크포워드 IS(최적화) 구간 캔들 수 (--oos-bars와 함께 지정 시 워크포워드 분석)
+        #[arg(long, requires = "oos_bars")]
+        is_bars: Option<usize>,
+
+        /// 워크포워드 OOS(검증) 구간 캔들 수
+        #[arg(long, requires = "is_bars")]
+        oos_bars: Option<usize>,
+
+        /// IS 구간 시작점 고정 (기본: 고정 길이 이동)
+        #[arg(long)]
+        anchored: bool,
+
+        /// OOS 구간 앞에 붙이는 워밍업 캔들 수
+        #[arg(long, default_value = "50")]
+        warmup: usize,
+    },
+
     /// 전략 통합 테스트 (UI와 동일한 환경에서 전략 검증)
     StrategyTest {
         /// 전략 ID (예: rsi, grid, bollinger)
@@ -660,6 +747,121 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
+        Commands::Optimize {
+            strategy,
+            market,
+            symbol,
+            from,
+            to,
+            capital,
+            config,
+            params,
+            ranges,
+            objective,
+            samples,
+            seed,
+            max_combinations,
+            parallel,
+            top,
+            db_url,
+            output,
+            is_bars,
+            oos_bars,
+            anchored,
+            warmup,
+        } => {
+            use trader_analytics::backtest::{OptimizationObjective, WalkForwardConfig};
+
+            let market = Market::parse(&market)
+                .ok_or_else(|| format!("Invalid market: {}. Supported: KR, US", market))?;
+
+            let objective = OptimizationObjective::parse(&objective).ok_or_else(|| {
+                format!(
+                    "Invalid objective: {}. Supported: sharpe, sortino, calmar, expectancy, total_return, profit_factor",
+                    objective
+                )
+            })?;
+
+            let start_date = from.as_ref().map(|d| parse_date(d)).transpose()?;
+            let end_date = to.as_ref().map(|d| parse_date(d)).transpose()?;
+
+            let initial_capital = capital
+                .parse::<rust_decimal::Decimal>()
+                .map_err(|_| format!("Invalid capital: {}", capital))?;
+
+            let parameters = params
+                .map(|p| {
+                    p.split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let optimize_config = commands::optimize::OptimizeCliConfig {
+                strategy_id: strategy.clone(),
+                market,
+                symbol: symbol.clone(),
+                start_date,
+                end_date,
+                initial_capital,
+                base_params: config,
+                parameters,
+                ranges,
+                objective,
+                samples,
+                seed,
+                max_combinations,
+                parallelism: parallel,
+                top,
+                db_url,
+                output_path: output.clone(),
+                ..Default::default()
+            };
+
+            let walk_forward = is_bars.zip(oos_bars).map(|(is_bars, oos_bars)| {
+                let config = WalkForwardConfig::new(is_bars, oos_bars).with_warmup_bars(warmup);
+                if anchored {
+                    config.anchored()
+                } else {
+                    config
+                }
+            });
+
+            if walk_forward.is_some() {
+                println!("\n🔎 워크포워드 분석 실행 중...");
+            } else {
+                println!("\n🔎 파라미터 최적화 실행 중...");
+            }
+            println!("전략: {}", strategy);
+            println!("종목: {}", symbol.to_uppercase());
+            println!("목적 함수: {}", objective.name());
+
+            let outcome = match walk_forward {
+                Some(walk_forward) => {
+                    commands::optimize::run_walk_forward(optimize_config, walk_forward)
+                        .await
+                        .map(|_| ())
+                }
+                None => commands::optimize::run_optimize(optimize_config)
+                    .await
+                    .map(|_| ()),
+            };
+
+            match outcome {
+                Ok(()) => {
+                    info!("✅ Optimization completed successfully");
+                    if let Some(out) = output {
+                        println!("\n📁 결과 저장됨: {}", out);
+                    }
+                }
+                Err(e) => {
+                    error!("Optimization failed: {}", e);
+                    return Err(e.into());
+                }
+            }
+        }
+
         Commands::Health => {
             info!("Checking system health...");
             println!("\n시스템 상태 확인 중...");
@@ -33,6 +33,10 @@ min: number | null,
  * 최대값 (number/integer 타입)
  */
 max: number | null, 
+/**
+ * 값 증감 단위 (number/integer 타입, 파라미터 최적화 격자 간격으로도 사용)
+ */
+step: number | null, 
 /**
  * 선택 옵션 (select/multi_select 타입)
  */
@@ -52,4 +56,12 @@ hidden: boolean,
 /**
  * 표시 순서 (낮을수록 먼저 표시)
  */
-order: number | null, };
+order: number | null, 
+/**
+ * 섹션 ID (같은 섹션의 필드들을 UI에서 그룹화)
+ *
+ * 예: "indicator" → "기술적 지표" 섹션으로 그룹화
+ * 예: "filter" → "필터 조건" 섹션으로 그룹화
+ * 예: "asset" → "자산 선택" 섹션으로 그룹화
+ */
+section: string | null, };
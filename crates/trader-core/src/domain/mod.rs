@@ -11,6 +11,7 @@ mod market_data;
 mod market_regime;
 mod order;
 mod position;
+mod position_sizing;
 mod route_state;
 mod schema;
 mod signal;
@@ -31,6 +32,7 @@ pub use market_data::*;
 pub use market_regime::*;
 pub use order::*;
 pub use position::*;
+pub use position_sizing::*;
 pub use route_state::*;
 pub use schema::*;
 pub use signal::*;
@@ -0,0 +1,568 @@
+//! 변동성 기반 포지션 사이징.
+//!
+//! 전략 설정의 `position_sizing` 키, 리스크 설정, 백테스트 엔진이 공유하는
+//! 비중 계산 설정([`PositionSizingConfig`])과 순수 계산 함수를 제공합니다:
+//!
+//! - **변동성 타깃**: 종목별 연율화 변동성 기여가 목표치가 되도록 비중 결정
+//! - **역변동성 가중**: 바스켓 내 변동성의 역수에 비례해 배분
+//! - **리스크 패리티**: 공분산 행렬 기반 동일 위험 기여(ERC) 비중
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::market_data::Kline;
+
+/// 리스크 패리티 좌표 하강법 최대 반복 횟수
+const RISK_PARITY_MAX_ITERATIONS: usize = 500;
+
+/// 리스크 패리티 수렴 허용 오차 (위험 기여와 예산의 차이)
+const RISK_PARITY_TOLERANCE: f64 = 1e-10;
+
+/// 포지션 사이징 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SizingMode {
+    /// 고정 비율 (최대 포지션 비율 × 신호 강도)
+    #[default]
+    Fixed,
+    /// 변동성 타깃: 종목별 연율화 변동성 기여가 목표치가 되도록 비중 결정
+    VolatilityTarget,
+    /// 역변동성 가중: 바스켓 내 변동성의 역수에 비례해 배분
+    InverseVolatility,
+    /// 동일 위험 기여(ERC) 리스크 패리티: 수익률 공분산 행렬 기반
+    RiskParity,
+}
+
+/// 변동성 추정 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VolatilityEstimator {
+    /// 종가 수익률 표준편차 (실현 변동성)
+    #[default]
+    Realized,
+    /// ATR / 종가
+    Atr,
+}
+
+/// 변동성 기반 포지션 사이징 설정.
+///
+/// 전략 설정에서는 `position_sizing` 키로 지정합니다:
+///
+/// ```json
+/// { "position_sizing": { "mode": "volatility_target", "target_volatility_pct": 10.0 } }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PositionSizingConfig {
+    /// 사이징 방식
+    #[serde(default)]
+    pub mode: SizingMode,
+
+    /// 종목당 목표 연율화 변동성 (%, 예: 10.0 = 10%, VolatilityTarget 전용)
+    #[serde(default = "default_target_volatility_pct")]
+    pub target_volatility_pct: f64,
+
+    /// 변동성 추정 방식 (VolatilityTarget, InverseVolatility 전용)
+    #[serde(default)]
+    pub estimator: VolatilityEstimator,
+
+    /// 변동성/공분산 추정 기간 (캔들 수)
+    #[serde(default = "default_volatility_lookback")]
+    pub lookback: usize,
+
+    /// 연율화 계수 (일봉 주식 252, 24시간 시장 365)
+    #[serde(default = "default_periods_per_year")]
+    pub periods_per_year: f64,
+
+    /// 종목당 최대 비중 (자본 대비, 0.0 ~ 1.0)
+    #[serde(default = "default_max_weight")]
+    pub max_weight: f64,
+}
+
+fn default_target_volatility_pct() -> f64 {
+    10.0
+}
+fn default_volatility_lookback() -> usize {
+    20
+}
+fn default_periods_per_year() -> f64 {
+    252.0
+}
+fn default_max_weight() -> f64 {
+    1.0
+}
+
+impl Default for PositionSizingConfig {
+    fn default() -> Self {
+        Self {
+            mode: SizingMode::default(),
+            target_volatility_pct: default_target_volatility_pct(),
+            estimator: VolatilityEstimator::default(),
+            lookback: default_volatility_lookback(),
+            periods_per_year: default_periods_per_year(),
+            max_weight: default_max_weight(),
+        }
+    }
+}
+
+impl PositionSizingConfig {
+    /// 지정한 방식의 설정을 생성합니다 (나머지는 기본값).
+    pub fn new(mode: SizingMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// 목표 연율화 변동성 설정 (%)
+    pub fn with_target_volatility_pct(mut self, pct: f64) -> Self {
+        self.target_volatility_pct = pct;
+        self
+    }
+
+    /// 변동성 추정 방식 설정
+    pub fn with_estimator(mut self, estimator: VolatilityEstimator) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    /// 추정 기간 설정
+    pub fn with_lookback(mut self, lookback: usize) -> Self {
+        self.lookback = lookback;
+        self
+    }
+
+    /// 종목당 최대 비중 설정
+    pub fn with_max_weight(mut self, max_weight: f64) -> Self {
+        self.max_weight = max_weight;
+        self
+    }
+
+    /// 고정 비율 방식인지 확인.
+    pub fn is_fixed(&self) -> bool {
+        self.mode == SizingMode::Fixed
+    }
+
+    /// 전략 설정 JSON의 `position_sizing` 키에서 사이징 설정을 읽습니다.
+    ///
+    /// 키가 없으면 `Ok(None)`, 형식이 잘못되면 파싱 에러를 반환합니다.
+    pub fn from_strategy_config(
+        config: &serde_json::Value,
+    ) -> Result<Option<Self>, serde_json::Error> {
+        config
+            .get("position_sizing")
+            .map(|value| serde_json::from_value(value.clone()))
+            .transpose()
+    }
+
+    /// 비중 계산에 필요한 캔들 수 (추정 기간 + 직전 종가 1개).
+    pub fn history_len(&self) -> usize {
+        self.lookback + 1
+    }
+
+    /// 캔들 이력(시간순)으로 연율화 변동성을 추정합니다 (0.2 = 20%).
+    ///
+    /// 최근 `lookback`개 구간만 사용하며, 이력이 부족하면 `None`을 반환합니다.
+    pub fn volatility(&self, klines: &[Kline]) -> Option<f64> {
+        if self.lookback < 2 || klines.len() < self.history_len() {
+            return None;
+        }
+        let window = &klines[klines.len() - self.history_len()..];
+
+        match self.estimator {
+            VolatilityEstimator::Realized => {
+                realized_volatility(&closes(window), self.periods_per_year)
+            }
+            VolatilityEstimator::Atr => {
+                atr_volatility(window, self.lookback, self.periods_per_year)
+            }
+        }
+    }
+
+    /// 바스켓 종목별 목표 비중 (자본 대비, `max_weight`로 제한).
+    ///
+    /// - VolatilityTarget: `목표 변동성 / 종목 변동성`
+    /// - InverseVolatility: 변동성 역수에 비례, 합계 1
+    /// - RiskParity: 공분산 행렬의 동일 위험 기여 비중, 합계 1
+    ///   (공분산 행렬로 풀 수 없으면 역변동성 비중으로 대체)
+    ///
+    /// 고정 비율 방식이면 빈 맵을, 이력이 부족한 종목은 결과에서 제외합니다.
+    pub fn target_weights(&self, basket: &[(&str, &[Kline])]) -> HashMap<String, f64> {
+        let weights: Vec<(String, f64)> = match self.mode {
+            SizingMode::Fixed => Vec::new(),
+            SizingMode::VolatilityTarget => {
+                let target = self.target_volatility_pct / 100.0;
+                self.volatilities(basket)
+                    .into_iter()
+                    .map(|(ticker, vol)| (ticker, target / vol))
+                    .collect()
+            }
+            SizingMode::InverseVolatility => {
+                let (tickers, vols): (Vec<String>, Vec<f64>) =
+                    self.volatilities(basket).into_iter().unzip();
+                inverse_volatility_weights(&vols)
+                    .map(|w| tickers.into_iter().zip(w).collect())
+                    .unwrap_or_default()
+            }
+            SizingMode::RiskParity => self.risk_parity(basket),
+        };
+
+        weights
+            .into_iter()
+            .filter(|(_, w)| w.is_finite() && *w > 0.0)
+            .map(|(ticker, w)| (ticker, w.min(self.max_weight)))
+            .collect()
+    }
+
+    /// 변동성을 추정할 수 있는 종목과 연율화 변동성 목록.
+    fn volatilities(&self, basket: &[(&str, &[Kline])]) -> Vec<(String, f64)> {
+        basket
+            .iter()
+            .filter_map(|(ticker, klines)| {
+                let vol = self.volatility(klines)?;
+                (vol > 0.0).then(|| (ticker.to_string(), vol))
+            })
+            .collect()
+    }
+
+    /// 최근 `lookback`개 수익률의 공분산으로 ERC 비중 계산.
+    fn risk_parity(&self, basket: &[(&str, &[Kline])]) -> Vec<(String, f64)> {
+        if self.lookback < 2 {
+            return Vec::new();
+        }
+
+        let (tickers, returns): (Vec<String>, Vec<Vec<f64>>) = basket
+            .iter()
+            .filter(|(_, klines)| klines.len() >= self.history_len())
+            .map(|(ticker, klines)| {
+                let window = &klines[klines.len() - self.history_len()..];
+                (ticker.to_string(), simple_returns(&closes(window)))
+            })
+            .unzip();
+
+        let weights = covariance_matrix(&returns)
+            .and_then(|cov| risk_parity_weights(&cov))
+            .or_else(|| {
+                let annualize = self.periods_per_year.sqrt();
+                let vols: Vec<f64> = returns
+                    .iter()
+                    .map(|r| std_dev(r).unwrap_or(0.0) * annualize)
+                    .collect();
+                inverse_volatility_weights(&vols)
+            });
+
+        weights
+            .map(|w| tickers.into_iter().zip(w).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// 캔들 종가 시계열.
+fn closes(klines: &[Kline]) -> Vec<f64> {
+    klines.iter().filter_map(|k| k.close.to_f64()).collect()
+}
+
+/// 종가 시계열의 단순 수익률.
+fn simple_returns(closes: &[f64]) -> Vec<f64> {
+    closes
+        .windows(2)
+        .filter(|w| w[0] > 0.0)
+        .map(|w| w[1] / w[0] - 1.0)
+        .collect()
+}
+
+/// 표본 표준편차 (관측치 2개 미만이면 None).
+fn std_dev(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    Some(variance.sqrt())
+}
+
+/// 종가 시계열(시간순)의 연율화 실현 변동성.
+///
+/// # Arguments
+/// * `closes` - 종가 시계열
+/// * `periods_per_year` - 연율화 계수 (일봉 252)
+pub fn realized_volatility(closes: &[f64], periods_per_year: f64) -> Option<f64> {
+    std_dev(&simple_returns(closes)).map(|sd| sd * periods_per_year.sqrt())
+}
+
+/// ATR 기반 연율화 변동성 (`ATR / 최근 종가 × √연율화 계수`).
+///
+/// # Arguments
+/// * `klines` - 캔들 시계열 (최소 `period + 1`개)
+/// * `period` - ATR 기간
+/// * `periods_per_year` - 연율화 계수
+pub fn atr_volatility(klines: &[Kline], period: usize, periods_per_year: f64) -> Option<f64> {
+    if period == 0 {
+        return None;
+    }
+    let atr = average_true_range(klines, period)?.to_f64()?;
+    let last_close = klines.last()?.close.to_f64()?;
+    if last_close <= 0.0 {
+        return None;
+    }
+    Some(atr / last_close * periods_per_year.sqrt())
+}
+
+/// Wilder 방식 ATR (첫 `period`개 True Range 평균 후 지수 평활).
+fn average_true_range(klines: &[Kline], period: usize) -> Option<Decimal> {
+    if klines.len() < period + 1 {
+        return None;
+    }
+
+    let true_range = |i: usize| {
+        let (high, low, prev_close) = (klines[i].high, klines[i].low, klines[i - 1].close);
+        (high - low)
+            .max((high - prev_close).abs())
+            .max((low - prev_close).abs())
+    };
+
+    let mut atr = (1..=period).map(true_range).sum::<Decimal>() / Decimal::from(period);
+    for i in (period + 1)..klines.len() {
+        atr = (atr * Decimal::from(period - 1) + true_range(i)) / Decimal::from(period);
+    }
+    Some(atr)
+}
+
+/// 변동성 역수에 비례하는 비중 (합계 1).
+///
+/// 0 이하이거나 유효하지 않은 변동성이 있으면 `None`을 반환합니다.
+pub fn inverse_volatility_weights(volatilities: &[f64]) -> Option<Vec<f64>> {
+    if volatilities.is_empty() || volatilities.iter().any(|v| !v.is_finite() || *v <= 0.0) {
+        return None;
+    }
+    let inverse: Vec<f64> = volatilities.iter().map(|v| 1.0 / v).collect();
+    let total: f64 = inverse.iter().sum();
+    Some(inverse.into_iter().map(|w| w / total).collect())
+}
+
+/// 수익률 시계열들의 표본 공분산 행렬.
+///
+/// 길이가 다르면 최근 구간으로 맞추며, 공통 관측치가 2개 미만이면 `None`을 반환합니다.
+pub fn covariance_matrix(returns: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let len = returns.iter().map(Vec::len).min()?;
+    if len < 2 {
+        return None;
+    }
+
+    let aligned: Vec<&[f64]> = returns.iter().map(|r| &r[r.len() - len..]).collect();
+    let means: Vec<f64> = aligned
+        .iter()
+        .map(|r| r.iter().sum::<f64>() / len as f64)
+        .collect();
+
+    let covariance = aligned
+        .iter()
+        .zip(&means)
+        .map(|(a, mean_a)| {
+            aligned
+                .iter()
+                .zip(&means)
+                .map(|(b, mean_b)| {
+                    a.iter()
+                        .zip(b.iter())
+                        .map(|(x, y)| (x - mean_a) * (y - mean_b))
+                        .sum::<f64>()
+                        / (len as f64 - 1.0)
+                })
+                .collect()
+        })
+        .collect();
+    Some(covariance)
+}
+
+/// 동일 위험 기여(ERC) 리스크 패리티 비중 (합계 1).
+///
+/// 순환 좌표 하강법으로 `x_i (Σx)_i = 1/n`을 풀고 정규화합니다.
+/// 각 좌표는 `x_i = (-c_i + √(c_i² + 4Σ_ii b)) / 2Σ_ii`
+/// (`c_i = Σ_{j≠i} Σ_ij x_j`)로 갱신됩니다.
+///
+/// 분산이 0 이하인 종목이 있거나 정사각 행렬이 아니면 `None`을 반환합니다.
+pub fn risk_parity_weights(covariance: &[Vec<f64>]) -> Option<Vec<f64>> {
+    let n = covariance.len();
+    if n == 0 || covariance.iter().any(|row| row.len() != n) {
+        return None;
+    }
+    if covariance
+        .iter()
+        .enumerate()
+        .any(|(i, row)| !row[i].is_finite() || row[i] <= 0.0)
+    {
+        return None;
+    }
+
+    let budget = 1.0 / n as f64;
+    // 역변동성 비중에서 시작
+    let mut x: Vec<f64> = covariance
+        .iter()
+        .enumerate()
+        .map(|(i, row)| 1.0 / row[i].sqrt())
+        .collect();
+
+    for _ in 0..RISK_PARITY_MAX_ITERATIONS {
+        for (i, row) in covariance.iter().enumerate() {
+            let c: f64 = row
+                .iter()
+                .zip(&x)
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, (cov, w))| cov * w)
+                .sum();
+            let variance = row[i];
+            x[i] = (-c + (c * c + 4.0 * variance * budget).sqrt()) / (2.0 * variance);
+        }
+
+        let converged = covariance.iter().zip(&x).all(|(row, xi)| {
+            let marginal: f64 = row.iter().zip(&x).map(|(cov, w)| cov * w).sum();
+            (xi * marginal - budget).abs() < RISK_PARITY_TOLERANCE
+        });
+        if converged {
+            break;
+        }
+    }
+
+    let total: f64 = x.iter().sum();
+    if !total.is_finite() || total <= 0.0 {
+        return None;
+    }
+    Some(x.into_iter().map(|w| w / total).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    /// 수익률이 +r, -r로 번갈아 나오는 캔들 시계열.
+    fn alternating_klines(ticker: &str, r: f64, len: usize) -> Vec<Kline> {
+        use crate::Timeframe;
+        use chrono::{Duration, TimeZone, Utc};
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut close = 100.0;
+        (0..len)
+            .map(|i| {
+                if i > 0 {
+                    close *= if i % 2 == 1 { 1.0 + r } else { 1.0 - r };
+                }
+                let price = Decimal::try_from(close).unwrap();
+                let time = start + Duration::days(i as i64);
+                Kline::new(
+                    ticker.to_string(),
+                    Timeframe::D1,
+                    time,
+                    price,
+                    price,
+                    price,
+                    price,
+                    dec!(1000),
+                    time,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_volatility_target_and_inverse_volatility_weights() {
+        let calm = alternating_klines("AAA", 0.01, 30);
+        let wild = alternating_klines("BBB", 0.02, 30);
+        let basket: Vec<(&str, &[Kline])> = vec![("AAA", &calm), ("BBB", &wild)];
+
+        // 변동성 2배 종목은 비중 절반
+        let config = PositionSizingConfig::new(SizingMode::VolatilityTarget);
+        let calm_vol = config.volatility(&calm).unwrap();
+        let weights = config.target_weights(&basket);
+        assert!((weights["AAA"] - 0.10 / calm_vol).abs() < 1e-9);
+        assert!((weights["AAA"] / weights["BBB"] - 2.0).abs() < 1e-6);
+
+        // 최대 비중 제한
+        let capped = config.clone().with_target_volatility_pct(1000.0);
+        assert_eq!(capped.target_weights(&basket)["AAA"], 1.0);
+
+        let config = PositionSizingConfig::new(SizingMode::InverseVolatility);
+        let weights = config.target_weights(&basket);
+        assert!((weights["AAA"] - 2.0 / 3.0).abs() < 1e-6);
+        assert!((weights["BBB"] - 1.0 / 3.0).abs() < 1e-6);
+
+        // ATR 추정 (전일 종가 대비 갭이 True Range)
+        let atr = config.with_estimator(VolatilityEstimator::Atr);
+        let weights = atr.target_weights(&basket);
+        assert!((weights["AAA"] - 2.0 / 3.0).abs() < 1e-2);
+
+        // 고정 비율 방식과 이력 부족 종목은 비중 없음
+        assert!(PositionSizingConfig::default()
+            .target_weights(&basket)
+            .is_empty());
+        let short: Vec<(&str, &[Kline])> = vec![("AAA", &calm[..10])];
+        assert!(PositionSizingConfig::new(SizingMode::VolatilityTarget)
+            .target_weights(&short)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_risk_parity_weights() {
+        // 상관이 없으면 역변동성 비중과 같음
+        let diagonal = vec![vec![0.04, 0.0], vec![0.0, 0.01]];
+        let weights = risk_parity_weights(&diagonal).unwrap();
+        assert!((weights[0] - 1.0 / 3.0).abs() < 1e-6);
+        assert!((weights[1] - 2.0 / 3.0).abs() < 1e-6);
+
+        // 상관이 있으면 위험 기여 w_i (Σw)_i가 모두 같아야 함
+        let covariance = vec![
+            vec![0.04, 0.018, 0.002],
+            vec![0.018, 0.09, 0.012],
+            vec![0.002, 0.012, 0.01],
+        ];
+        let weights = risk_parity_weights(&covariance).unwrap();
+        assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        let contributions: Vec<f64> = covariance
+            .iter()
+            .zip(&weights)
+            .map(|(row, w)| w * row.iter().zip(&weights).map(|(c, x)| c * x).sum::<f64>())
+            .collect();
+        for rc in &contributions {
+            assert!((rc - contributions[0]).abs() < 1e-8);
+        }
+
+        // 분산이 0인 종목은 풀 수 없음
+        assert!(risk_parity_weights(&[vec![0.0]]).is_none());
+
+        // 캔들 바스켓: 비중 합계 1
+        let calm = alternating_klines("AAA", 0.01, 30);
+        let wild = alternating_klines("BBB", 0.03, 30);
+        let basket: Vec<(&str, &[Kline])> = vec![("AAA", &calm), ("BBB", &wild)];
+        let weights = PositionSizingConfig::new(SizingMode::RiskParity).target_weights(&basket);
+        assert!((weights["AAA"] + weights["BBB"] - 1.0).abs() < 1e-9);
+        assert!(weights["AAA"] > weights["BBB"]);
+    }
+
+    #[test]
+    fn test_position_sizing_from_strategy_config() {
+        let params = serde_json::json!({
+            "position_sizing": { "mode": "risk_parity", "lookback": 60 }
+        });
+        let config = PositionSizingConfig::from_strategy_config(&params)
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.mode, SizingMode::RiskParity);
+        assert_eq!(config.lookback, 60);
+        assert_eq!(config.target_volatility_pct, 10.0);
+        assert_eq!(config.history_len(), 61);
+
+        assert!(
+            PositionSizingConfig::from_strategy_config(&serde_json::json!({}))
+                .unwrap()
+                .is_none()
+        );
+        assert!(PositionSizingConfig::from_strategy_config(
+            &serde_json::json!({ "position_sizing": { "mode": "unknown" } })
+        )
+        .is_err());
+    }
+}
@@ -55,6 +55,8 @@ pub enum FieldType {
     /// 다중 타임프레임 (Primary + Secondary)
     #[serde(rename = "multi_timeframe")]
     MultiTimeframe,
+    /// 조건 규칙 그룹 (규칙 빌더)
+    Rules,
 }
 
 /// 필드 스키마.
@@ -88,6 +90,10 @@ pub struct FieldSchema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max: Option<f64>,
 
+    /// 값 증감 단위 (number/integer 타입, 파라미터 최적화 격자 간격으로도 사용)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub step: Option<f64>,
+
     /// 선택 옵션 (select/multi_select 타입)
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub options: Vec<String>,
@@ -127,6 +133,7 @@ impl Default for FieldSchema {
             default: None,
             min: None,
             max: None,
+            step: None,
             options: Vec::new(),
             condition: None,
             required: false,
@@ -10,7 +10,7 @@ use crate::domain::{RouteState, Side};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
 /// 수행할 액션의 종류를 나타내는 신호 유형.
@@ -30,6 +30,8 @@ pub enum SignalType {
     ReducePosition,
     /// 스케일 인/아웃
     Scale,
+    /// 목표 포트폴리오 비중 (실행 계층이 보유 현황과 비교해 주문 생성)
+    Rebalance,
 }
 
 impl std::fmt::Display for SignalType {
@@ -41,10 +43,14 @@ impl std::fmt::Display for SignalType {
             SignalType::AddToPosition => write!(f, "ADD_TO_POSITION"),
             SignalType::ReducePosition => write!(f, "REDUCE_POSITION"),
             SignalType::Scale => write!(f, "SCALE"),
+            SignalType::Rebalance => write!(f, "REBALANCE"),
         }
     }
 }
 
+/// 목표 포트폴리오 신호의 ticker (특정 종목이 아닌 포트폴리오 전체 대상).
+pub const TARGET_PORTFOLIO_TICKER: &str = "*";
+
 /// 전략이 생성한 트레이딩 신호.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Signal {
@@ -74,6 +80,11 @@ pub struct Signal {
     /// 추가 메타데이터
     #[serde(default)]
     pub metadata: HashMap<String, serde_json::Value>,
+    /// 목표 비중 (ticker → 0.0 ~ 1.0, `Rebalance` 신호 전용)
+    ///
+    /// 비중 합계가 1 미만이면 나머지는 현금으로 유지합니다.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub target_weights: BTreeMap<String, Decimal>,
 }
 
 impl Signal {
@@ -96,9 +107,27 @@ impl Signal {
             take_profit: None,
             timestamp: Utc::now(),
             metadata: HashMap::new(),
+            target_weights: BTreeMap::new(),
         }
     }
 
+    /// 목표 포트폴리오 신호를 생성합니다.
+    ///
+    /// 목록에 없는 보유 종목은 전량 매도 대상이 됩니다.
+    pub fn target_portfolio(
+        strategy_id: impl Into<String>,
+        weights: impl IntoIterator<Item = (String, Decimal)>,
+    ) -> Self {
+        let mut signal = Self::new(
+            strategy_id,
+            TARGET_PORTFOLIO_TICKER.to_string(),
+            Side::Buy,
+            SignalType::Rebalance,
+        );
+        signal.target_weights = weights.into_iter().collect();
+        signal
+    }
+
     /// 진입 신호를 생성합니다.
     pub fn entry(strategy_id: impl Into<String>, ticker: String, side: Side) -> Self {
         Self::new(strategy_id, ticker, side, SignalType::Entry)
@@ -148,6 +177,22 @@ impl Signal {
     pub fn is_exit(&self) -> bool {
         self.signal_type == SignalType::Exit
     }
+
+    /// 목표 포트폴리오 신호인지 확인합니다.
+    pub fn is_rebalance(&self) -> bool {
+        self.signal_type == SignalType::Rebalance
+    }
+
+    /// 메타데이터 `quantity`로 지정된 주문 수량을 반환합니다.
+    ///
+    /// 문자열(`"1.5"`)과 숫자(`1.5`) 표기를 모두 지원합니다.
+    pub fn metadata_quantity(&self) -> Option<Decimal> {
+        match self.metadata.get("quantity")? {
+            serde_json::Value::String(s) => s.parse().ok(),
+            serde_json::Value::Number(n) => n.to_string().parse().ok(),
+            _ => None,
+        }
+    }
 }
 
 /// 신호 검증 결과.
@@ -450,6 +495,45 @@ mod tests {
         assert!(signal.is_entry());
     }
 
+    #[test]
+    fn test_metadata_quantity() {
+        use rust_decimal_macros::dec;
+
+        let signal = Signal::entry("s", "BTC/USDT".to_string(), Side::Buy);
+        assert_eq!(signal.metadata_quantity(), None);
+
+        let signal = signal.with_metadata("quantity", serde_json::json!("1.5"));
+        assert_eq!(signal.metadata_quantity(), Some(dec!(1.5)));
+
+        let signal = Signal::entry("s", "BTC/USDT".to_string(), Side::Buy)
+            .with_metadata("quantity", serde_json::json!(3));
+        assert_eq!(signal.metadata_quantity(), Some(dec!(3)));
+    }
+
+    #[test]
+    fn test_target_portfolio_signal() {
+        use rust_decimal_macros::dec;
+
+        let signal = Signal::target_portfolio(
+            "asset_allocation",
+            [
+                ("SPY".to_string(), dec!(0.6)),
+                ("TLT".to_string(), dec!(0.4)),
+            ],
+        );
+        assert!(signal.is_rebalance());
+        assert_eq!(signal.ticker, TARGET_PORTFOLIO_TICKER);
+        assert_eq!(signal.target_weights.get("SPY"), Some(&dec!(0.6)));
+
+        // 일반 신호는 target_weights를 직렬화하지 않음
+        let json =
+            serde_json::to_value(Signal::entry("s", "BTC/USDT".to_string(), Side::Buy)).unwrap();
+        assert!(json.get("target_weights").is_none());
+        let restored: Signal =
+            serde_json::from_value(serde_json::to_value(&signal).unwrap()).unwrap();
+        assert_eq!(restored.target_weights, signal.target_weights);
+    }
+
     #[test]
     fn test_signal_strength_clamping() {
         let symbol = "ETH/USDT".to_string();
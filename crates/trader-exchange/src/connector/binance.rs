@@ -229,6 +229,40 @@ struct BinanceOrderResponse {
     side: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceExchangeInfo {
+    symbols: Vec<BinanceSymbolInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceSymbolInfo {
+    symbol: String,
+    status: String,
+    base_asset: String,
+    quote_asset: String,
+    #[serde(default)]
+    filters: Vec<BinanceFilter>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "filterType")]
+enum BinanceFilter {
+    #[serde(rename = "PRICE_FILTER", rename_all = "camelCase")]
+    Price { tick_size: String },
+    #[serde(rename = "LOT_SIZE", rename_all = "camelCase")]
+    LotSize {
+        step_size: String,
+        min_qty: String,
+        max_qty: String,
+    },
+    #[serde(rename = "MIN_NOTIONAL", alias = "NOTIONAL", rename_all = "camelCase")]
+    MinNotional { min_notional: String },
+    #[serde(other)]
+    Other,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BinanceError {
@@ -236,6 +270,25 @@ struct BinanceError {
     msg: String,
 }
 
+/// 심볼별 주문 필터 (exchangeInfo의 PRICE_FILTER / LOT_SIZE / MIN_NOTIONAL).
+///
+/// 값이 0인 필드는 Binance에서 비활성화된 필터이므로 `None`입니다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BinanceSymbolFilters {
+    /// 내부 티커 ("BTC/USDT")
+    pub ticker: String,
+    /// 가격 단위 (PRICE_FILTER.tickSize)
+    pub tick_size: Option<Decimal>,
+    /// 수량 단위 (LOT_SIZE.stepSize)
+    pub step_size: Option<Decimal>,
+    /// 최소 수량 (LOT_SIZE.minQty)
+    pub min_qty: Option<Decimal>,
+    /// 최대 수량 (LOT_SIZE.maxQty)
+    pub max_qty: Option<Decimal>,
+    /// 최소 주문 금액 (MIN_NOTIONAL / NOTIONAL.minNotional)
+    pub min_notional: Option<Decimal>,
+}
+
 // ============================================================================
 // Binance 클라이언트
 // ============================================================================
@@ -505,6 +558,55 @@ impl BinanceClient {
         s.parse().unwrap_or(Decimal::ZERO)
     }
 
+    /// 0보다 큰 값만 `Some`으로 파싱 (0은 비활성 필터).
+    fn parse_filter_value(s: &str) -> Option<Decimal> {
+        s.parse::<Decimal>()
+            .ok()
+            .filter(|v| *v > Decimal::ZERO)
+            .map(|v| v.normalize())
+    }
+
+    /// exchangeInfo 응답에서 거래 중인 심볼의 필터를 추출.
+    fn parse_symbol_filters(info: BinanceExchangeInfo) -> Vec<BinanceSymbolFilters> {
+        info.symbols
+            .into_iter()
+            .filter(|symbol| symbol.status == "TRADING")
+            .map(|symbol| {
+                let mut filters = BinanceSymbolFilters {
+                    ticker: format!("{}/{}", symbol.base_asset, symbol.quote_asset),
+                    ..Default::default()
+                };
+                for filter in symbol.filters {
+                    match filter {
+                        BinanceFilter::Price { tick_size } => {
+                            filters.tick_size = Self::parse_filter_value(&tick_size);
+                        }
+                        BinanceFilter::LotSize {
+                            step_size,
+                            min_qty,
+                            max_qty,
+                        } => {
+                            filters.step_size = Self::parse_filter_value(&step_size);
+                            filters.min_qty = Self::parse_filter_value(&min_qty);
+                            filters.max_qty = Self::parse_filter_value(&max_qty);
+                        }
+                        BinanceFilter::MinNotional { min_notional } => {
+                            filters.min_notional = Self::parse_filter_value(&min_notional);
+                        }
+                        BinanceFilter::Other => {}
+                    }
+                }
+                filters
+            })
+            .collect()
+    }
+
+    /// 거래 중인 전체 심볼의 주문 필터 조회 (`/api/v3/exchangeInfo`).
+    pub async fn get_symbol_filters(&self) -> ExchangeResult<Vec<BinanceSymbolFilters>> {
+        let info: BinanceExchangeInfo = self.public_get("/api/v3/exchangeInfo", &[]).await?;
+        Ok(Self::parse_symbol_filters(info))
+    }
+
     /// Binance 주문 상태를 내부 OrderStatus로 변환.
     fn parse_order_status(resp: &BinanceOrderResponse) -> OrderStatus {
         let status = match resp.status.as_str() {
@@ -887,6 +989,50 @@ mod tests {
         assert_eq!(parsed.quote, "USDT");
     }
 
+    #[test]
+    fn test_parse_symbol_filters() {
+        let info: BinanceExchangeInfo = serde_json::from_str(
+            r#"{
+                "symbols": [
+                    {
+                        "symbol": "BTCUSDT",
+                        "status": "TRADING",
+                        "baseAsset": "BTC",
+                        "quoteAsset": "USDT",
+                        "filters": [
+                            {"filterType": "PRICE_FILTER", "minPrice": "0.01000000", "maxPrice": "1000000.00000000", "tickSize": "0.01000000"},
+                            {"filterType": "LOT_SIZE", "minQty": "0.00001000", "maxQty": "9000.00000000", "stepSize": "0.00001000"},
+                            {"filterType": "ICEBERG_PARTS", "limit": 10},
+                            {"filterType": "NOTIONAL", "minNotional": "5.00000000", "maxNotional": "9000000.00000000"}
+                        ]
+                    },
+                    {
+                        "symbol": "OLDUSDT",
+                        "status": "BREAK",
+                        "baseAsset": "OLD",
+                        "quoteAsset": "USDT",
+                        "filters": []
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let filters = BinanceClient::parse_symbol_filters(info);
+        assert_eq!(filters.len(), 1);
+        assert_eq!(
+            filters[0],
+            BinanceSymbolFilters {
+                ticker: "BTC/USDT".to_string(),
+                tick_size: Some(Decimal::new(1, 2)),
+                step_size: Some(Decimal::new(1, 5)),
+                min_qty: Some(Decimal::new(1, 5)),
+                max_qty: Some(Decimal::from(9000)),
+                min_notional: Some(Decimal::from(5)),
+            }
+        );
+    }
+
     #[test]
     fn test_sign() {
         let config = BinanceConfig::new(
@@ -57,6 +57,8 @@ pub struct DataFeed {
     end_time: Option<DateTime<Utc>>,
     /// 현재 시뮬레이션 시간
     current_time: Option<DateTime<Utc>>,
+    /// 실시간으로 공급된 심볼별 최신 티커 (과거 데이터 재생과 별개)
+    live_tickers: HashMap<String, Ticker>,
 }
 
 impl DataFeed {
@@ -69,6 +71,7 @@ impl DataFeed {
             start_time: None,
             end_time: None,
             current_time: None,
+            live_tickers: HashMap::new(),
         }
     }
 
@@ -192,6 +195,7 @@ impl DataFeed {
     /// 재생을 처음으로 리셋합니다.
     pub fn reset(&mut self) {
         self.playback_position.clear();
+        self.live_tickers.clear();
         self.current_time = self.start_time;
     }
 
@@ -245,13 +249,26 @@ impl DataFeed {
         }
     }
 
+    /// 실시간 Kline을 공급합니다.
+    ///
+    /// 과거 데이터 재생 위치는 건드리지 않고 심볼의 최신 티커와 현재 시간만 갱신합니다.
+    pub fn push_kline(&mut self, kline: &Kline) {
+        self.live_tickers
+            .insert(kline.ticker.clone(), Self::kline_to_ticker(kline));
+        self.current_time = Some(kline.close_time);
+    }
+
     /// 심볼의 현재 티커를 가져옵니다.
+    ///
+    /// 재생 중인 데이터가 없으면 실시간으로 공급된 최신 티커를 반환합니다.
     pub fn get_ticker(&self, ticker: &str) -> Option<Ticker> {
         let key = (ticker.to_string(), self.config.default_timeframe);
-        let data = self.data.get(&key)?;
-
-        let pos = self.playback_position.get(ticker)?;
-        data.get(pos).map(|e| e.ticker.clone())
+        self.data
+            .get(&key)
+            .zip(self.playback_position.get(ticker))
+            .and_then(|(data, pos)| data.get(pos))
+            .map(|e| e.ticker.clone())
+            .or_else(|| self.live_tickers.get(ticker).cloned())
     }
 
     /// 진행 없이 심볼의 현재 Kline을 가져옵니다.
@@ -139,6 +139,8 @@ struct OrderState {
     filled_quantity: Decimal,
     /// 평균 체결 가격
     average_price: Option<Decimal>,
+    /// 주문 제출 시 잠근 잔고 (매수: 견적 통화, 매도: 기준 통화)
+    locked: Decimal,
     /// 생성 시각
     created_at: DateTime<Utc>,
     /// 갱신 시각
@@ -188,6 +190,44 @@ impl SimulatedExchange {
         ticker.split('/').nth(1).unwrap_or("USDT").to_string()
     }
 
+    /// ticker String에서 base 통화를 추출합니다 (예: "BTC/USDT" -> "BTC").
+    fn parse_base(ticker: &str) -> String {
+        ticker.split('/').next().unwrap_or(ticker).to_string()
+    }
+
+    /// 주문이 잔고를 잠그는 자산 (매수: 견적 통화, 매도: 기준 통화).
+    fn lock_asset(request: &OrderRequest) -> String {
+        match request.side {
+            Side::Buy => Self::parse_quote(&request.ticker),
+            Side::Sell => Self::parse_base(&request.ticker),
+        }
+    }
+
+    /// 주문이 잠가야 하는 자산과 수량을 계산합니다.
+    ///
+    /// 매수는 수량 × 가격(시장가는 현재가), 매도는 수량만큼 잠급니다.
+    /// 손절/익절 같은 조건부 주문은 잠그지 않고 트리거 시점에 정산합니다
+    /// (브라켓 OCO의 양쪽 주문이 같은 보유 수량을 공유하기 때문).
+    fn lock_requirement(request: &OrderRequest, current_price: Decimal) -> (String, Decimal) {
+        let is_conditional = matches!(
+            request.order_type,
+            OrderType::StopLoss
+                | OrderType::StopLossLimit
+                | OrderType::TakeProfit
+                | OrderType::TakeProfitLimit
+                | OrderType::TrailingStop
+        );
+        let amount = if is_conditional {
+            dec!(0)
+        } else {
+            match request.side {
+                Side::Buy => request.quantity * request.price.unwrap_or(current_price),
+                Side::Sell => request.quantity,
+            }
+        };
+        (Self::lock_asset(request), amount)
+    }
+
     /// ticker String을 Symbol로 변환합니다 (시뮬레이션용).
     fn ticker_to_symbol(ticker: &str) -> Symbol {
         let parts: Vec<&str> = ticker.split('/').collect();
@@ -253,10 +293,28 @@ impl SimulatedExchange {
             feed.next_kline(symbol, timeframe)?
         };
 
+        self.process_kline(symbol, &kline).await;
+        Some(kline)
+    }
+
+    /// 실시간 Kline을 공급합니다.
+    ///
+    /// 과거 데이터 대신 외부 시세(예: 실거래 시장 데이터)로 가상 원장을 운용할 때 사용합니다.
+    /// 대기 중인 주문을 이 Kline으로 매칭하고, 이후 시장가 주문은 종가 기준으로 체결됩니다.
+    pub async fn push_kline(&self, kline: &Kline) {
+        {
+            let mut feed = self.data_feed.write().await;
+            feed.push_kline(kline);
+        }
+        self.process_kline(&kline.ticker, kline).await;
+    }
+
+    /// Kline으로 대기 주문을 매칭하고 시장 이벤트를 브로드캐스트합니다.
+    async fn process_kline(&self, symbol: &str, kline: &Kline) {
         // 대기 중인 주문 처리
         let matches = {
             let mut engine = self.matching_engine.write().await;
-            engine.process_kline(&symbol.to_string(), &kline)
+            engine.process_kline(&symbol.to_string(), kline)
         };
 
         // 주문 매칭 결과를 계정에 적용
@@ -270,12 +328,10 @@ impl SimulatedExchange {
             .await;
 
         // 티커 업데이트도 브로드캐스트
-        let ticker = self.kline_to_ticker(&kline);
+        let ticker = self.kline_to_ticker(kline);
         self.market_broadcaster
             .broadcast(MarketEvent::Ticker(ticker))
             .await;
-
-        Some(kline)
     }
 
     /// 데이터가 소진될 때까지 시뮬레이션을 실행합니다.
@@ -368,6 +424,12 @@ impl SimulatedExchange {
             let request = &order_state.request;
             let ticker = &request.ticker;
 
+            // 주문 제출 시 잠근 잔고 해제 (체결 금액은 아래에서 free 잔고로 정산)
+            if order_state.locked > dec!(0) {
+                let asset = Self::lock_asset(request);
+                account.update_balance(&asset, order_state.locked, -order_state.locked);
+            }
+
             // ticker String을 Symbol로 변환 (예: "BTC/USDT" -> Symbol)
             // TODO: SymbolResolver를 통해 Symbol을 가져와야 함
             let parts: Vec<&str> = ticker.split('/').collect();
@@ -405,6 +467,7 @@ impl SimulatedExchange {
                     };
                     state.filled_quantity = order_match.filled_quantity;
                     state.average_price = Some(order_match.fill_price);
+                    state.locked = dec!(0);
                     state.updated_at = order_match.timestamp;
                 }
             }
@@ -641,50 +704,18 @@ impl Exchange for SimulatedExchange {
         // 주문 검증
         self.validate_order(request, current_price)?;
 
-        // 잔고 확인
-        {
-            let account = self.account.read().await;
-            match request.side {
-                Side::Buy => {
-                    let required = request.quantity * request.price.unwrap_or(current_price);
-                    let balance = account.get_balance(&Self::parse_quote(&request.ticker));
-                    if balance.free < required {
-                        return Err(ExchangeError::InsufficientBalance(format!(
-                            "Need {} {}, have {}",
-                            required,
-                            Self::parse_quote(&request.ticker),
-                            balance.free
-                        )));
-                    }
-                }
-                Side::Sell => {
-                    let balance = account.get_balance(&request.ticker);
-                    if balance.free < request.quantity {
-                        return Err(ExchangeError::InsufficientBalance(format!(
-                            "Need {} {}, have {}",
-                            request.quantity, request.ticker, balance.free
-                        )));
-                    }
-                }
-            }
-        }
-
-        // 잔고 잠금
+        // 잔고 확인 및 잠금
+        let (lock_asset, locked) = Self::lock_requirement(request, current_price);
         {
             let mut account = self.account.write().await;
-            match request.side {
-                Side::Buy => {
-                    let required = request.quantity * request.price.unwrap_or(current_price);
-                    account.update_balance(
-                        &Self::parse_quote(&request.ticker),
-                        -required,
-                        required,
-                    );
-                }
-                Side::Sell => {
-                    account.update_balance(&request.ticker, -request.quantity, request.quantity);
-                }
+            let balance = account.get_balance(&lock_asset);
+            if balance.free < locked {
+                return Err(ExchangeError::InsufficientBalance(format!(
+                    "Need {} {}, have {}",
+                    locked, lock_asset, balance.free
+                )));
             }
+            account.update_balance(&lock_asset, -locked, locked);
         }
 
         // 매칭 엔진에 제출
@@ -717,6 +748,7 @@ impl Exchange for SimulatedExchange {
             } else {
                 None
             },
+            locked,
             created_at: order_match.timestamp,
             updated_at: order_match.timestamp,
         };
@@ -727,30 +759,8 @@ impl Exchange for SimulatedExchange {
             orders.insert(order_id.clone(), order_state);
         }
 
-        // 즉시 체결된 경우, 매칭 적용
+        // 즉시 체결된 경우, 매칭 적용 (잠금 해제 포함)
         if order_match.fill_type != FillType::None {
-            // 먼저 잔고 잠금 해제 (위에서 잠갔으므로)
-            {
-                let mut account = self.account.write().await;
-                match request.side {
-                    Side::Buy => {
-                        let required = request.quantity * request.price.unwrap_or(current_price);
-                        account.update_balance(
-                            &Self::parse_quote(&request.ticker),
-                            required,
-                            -required,
-                        );
-                    }
-                    Side::Sell => {
-                        account.update_balance(
-                            &request.ticker,
-                            request.quantity,
-                            -request.quantity,
-                        );
-                    }
-                }
-            }
-
             self.apply_order_match(&order_match).await;
         }
 
@@ -776,24 +786,11 @@ impl Exchange for SimulatedExchange {
                 state.updated_at = Utc::now();
 
                 // 잔고 잠금 해제
-                let mut account = self.account.write().await;
-                let request = &state.request;
-                match request.side {
-                    Side::Buy => {
-                        let locked = request.quantity * request.price.unwrap_or(dec!(0));
-                        account.update_balance(
-                            &Self::parse_quote(&request.ticker),
-                            locked,
-                            -locked,
-                        );
-                    }
-                    Side::Sell => {
-                        account.update_balance(
-                            &request.ticker,
-                            request.quantity,
-                            -request.quantity,
-                        );
-                    }
+                if state.locked > dec!(0) {
+                    let mut account = self.account.write().await;
+                    let asset = Self::lock_asset(&state.request);
+                    account.update_balance(&asset, state.locked, -state.locked);
+                    state.locked = dec!(0);
                 }
             }
         }
@@ -1027,4 +1024,123 @@ mod tests {
         let balance = exchange.get_balance("USDT").await.unwrap();
         assert_eq!(balance.free, dec!(100000));
     }
+
+    #[tokio::test]
+    async fn test_sell_after_buy_uses_base_asset() {
+        let config = SimulatedConfig::default().with_initial_balance("USDT", dec!(100000));
+
+        let exchange = SimulatedExchange::new(config);
+        let symbol = create_test_symbol();
+        let ticker = symbol.to_string();
+
+        let klines = generate_sample_klines(
+            symbol.to_string(),
+            Timeframe::M1,
+            10,
+            dec!(50000),
+            dec!(0.01),
+        );
+        exchange
+            .load_klines(symbol.to_string(), Timeframe::M1, klines)
+            .await;
+        exchange.step(&ticker, Timeframe::M1).await;
+
+        let buy = OrderRequest {
+            ticker: ticker.clone(),
+            side: Side::Buy,
+            order_type: OrderType::Market,
+            quantity: dec!(0.1),
+            price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+        };
+        exchange.place_order(&buy).await.unwrap();
+
+        // 조건부 주문(손절)은 잔고를 잠그지 않음
+        let stop = OrderRequest {
+            side: Side::Sell,
+            order_type: OrderType::StopLoss,
+            stop_price: Some(dec!(10000)),
+            ..buy.clone()
+        };
+        let stop_id = exchange.place_order(&stop).await.unwrap();
+        let btc = exchange.get_balance("BTC").await.unwrap();
+        assert_eq!(btc.free, dec!(0.1));
+        exchange.cancel_order(&ticker, &stop_id).await.unwrap();
+
+        // 대기 중인 지정가 매도는 기준 통화(BTC)를 잠가야 함
+        let limit = OrderRequest {
+            side: Side::Sell,
+            order_type: OrderType::Limit,
+            price: Some(dec!(100000)),
+            ..buy.clone()
+        };
+        let limit_id = exchange.place_order(&limit).await.unwrap();
+        let btc = exchange.get_balance("BTC").await.unwrap();
+        assert_eq!(btc.free, dec!(0));
+        assert_eq!(btc.locked, dec!(0.1));
+
+        // 취소 시 잠금 해제
+        exchange.cancel_order(&ticker, &limit_id).await.unwrap();
+        let btc = exchange.get_balance("BTC").await.unwrap();
+        assert_eq!(btc.free, dec!(0.1));
+        assert_eq!(btc.locked, dec!(0));
+
+        // 시장가 매도는 보유 BTC로 체결되어야 함
+        let sell = OrderRequest {
+            side: Side::Sell,
+            ..buy
+        };
+        let sell_id = exchange.place_order(&sell).await.unwrap();
+        let status = exchange.get_order(&ticker, &sell_id).await.unwrap();
+        assert_eq!(status.status, OrderStatusType::Filled);
+
+        let btc = exchange.get_balance("BTC").await.unwrap();
+        assert_eq!(btc.free, dec!(0));
+        assert_eq!(btc.locked, dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_push_kline_prices_live_orders() {
+        let exchange = SimulatedExchange::new(
+            SimulatedConfig::default().with_initial_balance("USDT", dec!(100000)),
+        );
+        let ticker = create_test_symbol().to_string();
+        let live_kline = |close: Decimal| {
+            let open_time = Utc::now();
+            Kline::new(
+                ticker.clone(),
+                Timeframe::H1,
+                open_time,
+                close,
+                close,
+                close,
+                close,
+                dec!(10),
+                open_time + chrono::Duration::hours(1),
+            )
+        };
+
+        // 시세가 없으면 주문 불가
+        let buy = OrderRequest::market_buy(ticker.clone(), dec!(0.1));
+        assert!(exchange.place_order(&buy).await.is_err());
+
+        exchange.push_kline(&live_kline(dec!(50000))).await;
+        assert_eq!(
+            exchange.get_ticker(&ticker).await.unwrap().last,
+            dec!(50000)
+        );
+        let order_id = exchange.place_order(&buy).await.unwrap();
+        let status = exchange.get_order(&ticker, &order_id).await.unwrap();
+        assert_eq!(status.status, OrderStatusType::Filled);
+
+        // 대기 중인 지정가 매도는 다음 실시간 Kline에서 체결
+        let sell = OrderRequest::limit_sell(ticker.clone(), dec!(0.1), dec!(52000));
+        let sell_id = exchange.place_order(&sell).await.unwrap();
+        exchange.push_kline(&live_kline(dec!(53000))).await;
+        let status = exchange.get_order(&ticker, &sell_id).await.unwrap();
+        assert_eq!(status.status, OrderStatusType::Filled);
+    }
 }
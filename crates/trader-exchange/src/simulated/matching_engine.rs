@@ -328,7 +328,14 @@ impl MatchingEngine {
     }
 
     /// 대기 주문을 Kline과 매칭 시도합니다.
+    ///
+    /// # 갭 처리 (gap-through)
+    ///
+    /// 시가가 이미 트리거/지정가를 넘어선 경우(갭 상승/하락) 해당 가격이 아닌
+    /// 시가로 체결합니다. 손절은 더 불리한 시가로, 지정가/익절은 더 유리한 시가로
+    /// 체결되어 실제 거래소의 갭 체결과 동일하게 동작합니다.
     fn try_match_order(&self, order: &PendingOrder, kline: &Kline) -> Option<OrderMatch> {
+        let open = kline.open;
         let high = kline.high;
         let low = kline.low;
 
@@ -336,97 +343,97 @@ impl MatchingEngine {
             OrderType::Limit => {
                 let limit_price = order.price?;
 
-                let should_fill = match order.side {
-                    Side::Buy => low <= limit_price,
-                    Side::Sell => high >= limit_price,
+                // 갭으로 시가가 이미 지정가보다 유리하면 시가로 체결
+                let fill_price = match order.side {
+                    Side::Buy if open <= limit_price => open,
+                    Side::Sell if open >= limit_price => open,
+                    Side::Buy if low <= limit_price => limit_price,
+                    Side::Sell if high >= limit_price => limit_price,
+                    _ => return None,
                 };
 
-                if should_fill {
-                    let commission = order.remaining_quantity * limit_price * self.fee_rate;
+                let commission = order.remaining_quantity * fill_price * self.fee_rate;
 
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price: limit_price,
-                        commission,
-                        commission_asset: order.symbol.clone(),
-                        timestamp: kline.close_time,
-                    })
-                } else {
-                    None
-                }
+                Some(OrderMatch {
+                    order_id: order.order_id.clone(),
+                    fill_type: FillType::Full,
+                    filled_quantity: order.remaining_quantity,
+                    fill_price,
+                    commission,
+                    commission_asset: order.symbol.clone(),
+                    timestamp: kline.close_time,
+                })
             }
             OrderType::StopLoss | OrderType::StopLossLimit => {
                 let stop_price = order.stop_price?;
 
                 // 손절매는 가격이 스탑 레벨을 교차할 때 트리거됩니다
-                let should_trigger = match order.side {
-                    // 손절매 매도는 가격이 스탑 가격 이하로 떨어질 때 트리거됩니다
-                    Side::Sell => low <= stop_price,
-                    // 손절매 매수는 가격이 스탑 가격 이상으로 오를 때 트리거됩니다
-                    Side::Buy => high >= stop_price,
+                // - 매도: 가격이 스탑 가격 이하로 떨어질 때
+                // - 매수: 가격이 스탑 가격 이상으로 오를 때
+                // 시가가 이미 스탑을 넘어섰으면 시가에서 트리거됩니다 (갭)
+                let trigger_price = match order.side {
+                    Side::Sell if open <= stop_price => open,
+                    Side::Buy if open >= stop_price => open,
+                    Side::Sell if low <= stop_price => stop_price,
+                    Side::Buy if high >= stop_price => stop_price,
+                    _ => return None,
                 };
 
-                if should_trigger {
-                    // 스탑 가격으로 체결 (시장 스탑의 경우 슬리피지 포함)
-                    let fill_price = if order.order_type == OrderType::StopLoss {
-                        let slippage = stop_price * self.slippage_rate;
-                        match order.side {
-                            Side::Sell => stop_price - slippage,
-                            Side::Buy => stop_price + slippage,
-                        }
-                    } else {
-                        order.price.unwrap_or(stop_price)
-                    };
+                // 트리거 가격으로 체결 (시장 스탑의 경우 슬리피지 포함)
+                let fill_price = if order.order_type == OrderType::StopLoss {
+                    let slippage = trigger_price * self.slippage_rate;
+                    match order.side {
+                        Side::Sell => trigger_price - slippage,
+                        Side::Buy => trigger_price + slippage,
+                    }
+                } else {
+                    order.price.unwrap_or(trigger_price)
+                };
 
-                    let commission = order.remaining_quantity * fill_price * self.fee_rate;
+                let commission = order.remaining_quantity * fill_price * self.fee_rate;
 
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price,
-                        commission,
-                        commission_asset: Self::parse_quote(&order.symbol),
-                        timestamp: kline.close_time,
-                    })
-                } else {
-                    None
-                }
+                Some(OrderMatch {
+                    order_id: order.order_id.clone(),
+                    fill_type: FillType::Full,
+                    filled_quantity: order.remaining_quantity,
+                    fill_price,
+                    commission,
+                    commission_asset: Self::parse_quote(&order.symbol),
+                    timestamp: kline.close_time,
+                })
             }
             OrderType::TakeProfit | OrderType::TakeProfitLimit => {
                 let stop_price = order.stop_price?;
 
                 // 이익실현은 가격이 목표에 도달할 때 트리거됩니다
-                let should_trigger = match order.side {
-                    // 이익실현 매도는 가격이 목표 이상으로 오를 때 트리거됩니다
-                    Side::Sell => high >= stop_price,
-                    // 이익실현 매수는 가격이 목표 이하로 떨어질 때 트리거됩니다
-                    Side::Buy => low <= stop_price,
+                // - 매도: 가격이 목표 이상으로 오를 때
+                // - 매수: 가격이 목표 이하로 떨어질 때
+                // 시가가 이미 목표를 넘어섰으면 더 유리한 시가로 체결됩니다 (갭)
+                let trigger_price = match order.side {
+                    Side::Sell if open >= stop_price => open,
+                    Side::Buy if open <= stop_price => open,
+                    Side::Sell if high >= stop_price => stop_price,
+                    Side::Buy if low <= stop_price => stop_price,
+                    _ => return None,
                 };
 
-                if should_trigger {
-                    let fill_price = if order.order_type == OrderType::TakeProfit {
-                        stop_price
-                    } else {
-                        order.price.unwrap_or(stop_price)
-                    };
+                let fill_price = if order.order_type == OrderType::TakeProfit {
+                    trigger_price
+                } else {
+                    order.price.unwrap_or(trigger_price)
+                };
 
-                    let commission = order.remaining_quantity * fill_price * self.fee_rate;
+                let commission = order.remaining_quantity * fill_price * self.fee_rate;
 
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price,
-                        commission,
-                        commission_asset: Self::parse_quote(&order.symbol),
-                        timestamp: kline.close_time,
-                    })
-                } else {
-                    None
-                }
+                Some(OrderMatch {
+                    order_id: order.order_id.clone(),
+                    fill_type: FillType::Full,
+                    filled_quantity: order.remaining_quantity,
+                    fill_price,
+                    commission,
+                    commission_asset: Self::parse_quote(&order.symbol),
+                    timestamp: kline.close_time,
+                })
             }
             OrderType::Market | OrderType::TrailingStop => {
                 // 시장가 주문은 대기 상태가 아니어야 합니다
@@ -641,6 +648,46 @@ mod tests {
         assert!(matches[0].fill_price < dec!(48000));
     }
 
+    #[test]
+    fn test_stop_loss_gap_through_fills_at_open() {
+        let mut engine = MatchingEngine::new(dec!(0.001), dec!(0));
+        let symbol = create_test_symbol();
+
+        // 48000에 손절매 매도, 익절 52000 매도
+        let stop = OrderRequest {
+            ticker: symbol.to_string(),
+            side: Side::Sell,
+            order_type: OrderType::StopLoss,
+            quantity: dec!(0.1),
+            price: None,
+            stop_price: Some(dec!(48000)),
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+        };
+        let take_profit = OrderRequest {
+            order_type: OrderType::TakeProfit,
+            stop_price: Some(dec!(52000)),
+            ..stop.clone()
+        };
+
+        engine.submit_order(&stop, dec!(50000), Utc::now());
+        let mut tp_engine = MatchingEngine::new(dec!(0.001), dec!(0));
+        tp_engine.submit_order(&take_profit, dec!(50000), Utc::now());
+
+        // 시가가 46000으로 갭 하락 -> 스탑 가격이 아닌 시가로 체결
+        let kline = create_test_kline(46000.0, 47000.0, 45500.0, 46500.0);
+        let matches = engine.process_kline(&symbol, &kline);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].fill_price, dec!(46000));
+
+        // 시가가 53000으로 갭 상승 -> 익절은 더 유리한 시가로 체결
+        let kline = create_test_kline(53000.0, 54000.0, 52500.0, 53500.0);
+        let matches = tp_engine.process_kline(&symbol, &kline);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].fill_price, dec!(53000));
+    }
+
     #[test]
     fn test_cancel_order() {
         let mut engine = MatchingEngine::new(dec!(0.001), dec!(0.0005));
@@ -7,8 +7,10 @@
 //! - PositionTracker를 통한 포지션 추적
 //! - 브라켓 주문 (손절/익절) 자동 관리
 //! - OCO(One-Cancels-Other) 주문 관리
+//! - 거래소 제출 전 컴플라이언스 검사 (가격제한폭, 호가 단위, 거래소 필터 등)
 //! - 실행 추적 및 보고
 
+use chrono::Utc;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -20,7 +22,7 @@ use trader_core::{
     Order, OrderRequest, OrderStatus, OrderStatusType, OrderType, Position, Side, Signal,
     SignalType, TimeInForce,
 };
-use trader_risk::RiskManager;
+use trader_risk::{count_day_trades, ComplianceContext, ComplianceViolation, RiskManager};
 use uuid::Uuid;
 
 use crate::order_manager::{OrderFill, OrderManager};
@@ -368,6 +370,10 @@ pub struct ExecutionResult {
     pub error: Option<String>,
     /// 실행 노트/경고
     pub notes: Vec<String>,
+    /// 신호를 거부한 단계 (`SignalConverter`, `RiskManager`, `Compliance`, `OrderManager`)
+    pub rejected_by: Option<String>,
+    /// 컴플라이언스 위반 (컴플라이언스 검사에서 거부된 경우)
+    pub violation: Option<ComplianceViolation>,
 }
 
 impl ExecutionResult {
@@ -382,6 +388,8 @@ impl ExecutionResult {
             success: true,
             error: None,
             notes: vec![],
+            rejected_by: None,
+            violation: None,
         }
     }
 
@@ -396,6 +404,8 @@ impl ExecutionResult {
             success: false,
             error: Some(error.into()),
             notes: vec![],
+            rejected_by: None,
+            violation: None,
         }
     }
 
@@ -417,6 +427,18 @@ impl ExecutionResult {
         self
     }
 
+    /// 거부 단계 설정.
+    pub fn with_rejected_by(mut self, stage: impl Into<String>) -> Self {
+        self.rejected_by = Some(stage.into());
+        self
+    }
+
+    /// 컴플라이언스 위반 설정.
+    pub fn with_violation(mut self, violation: ComplianceViolation) -> Self {
+        self.violation = Some(violation);
+        self
+    }
+
     /// 노트 추가.
     pub fn with_note(mut self, note: impl Into<String>) -> Self {
         self.notes.push(note.into());
@@ -465,6 +487,13 @@ impl SignalConverter {
             ));
         }
 
+        // 목표 포트폴리오 신호는 종목별 신호로 분해된 뒤 실행되어야 함
+        if signal.signal_type == SignalType::Rebalance {
+            return Err(ExecutionError::InvalidSignal(
+                "Rebalance signals must be expanded into per-ticker signals".to_string(),
+            ));
+        }
+
         // 신호 강도 검증
         if signal.strength < self.config.min_strength {
             return Err(ExecutionError::InvalidSignal(format!(
@@ -503,9 +532,9 @@ impl SignalConverter {
                 // 스케일 인/아웃은 시장가 주문 사용
                 (OrderType::Market, None, None)
             }
-            SignalType::Alert => {
-                // Alert는 이미 위에서 필터링됨, 여기 도달 불가
-                unreachable!("Alert signals should be filtered out before reaching this point")
+            SignalType::Alert | SignalType::Rebalance => {
+                // Alert/Rebalance는 이미 위에서 필터링됨, 여기 도달 불가
+                unreachable!("Non-order signals should be filtered out before reaching this point")
             }
         };
 
@@ -645,16 +674,68 @@ impl OrderExecutor {
     /// * `signal` - 처리할 트레이딩 신호
     /// * `current_price` - 현재 시장 가격
     pub async fn process_signal(&self, signal: &Signal, current_price: Decimal) -> ExecutionResult {
+        self.process_signal_with_quantity(signal, current_price, None)
+            .await
+    }
+
+    /// 수량을 지정하여 신호 처리.
+    ///
+    /// `process_signal`과 동일하지만, 호출자가 포지션 사이징을 수행한 경우
+    /// 해당 수량을 사용함. `None`이면 `ConversionConfig::default_quantity`를 사용.
+    ///
+    /// # 인자
+    /// * `signal` - 처리할 트레이딩 신호
+    /// * `current_price` - 현재 시장 가격
+    /// * `quantity` - 주문 수량 (선택)
+    pub async fn process_signal_with_quantity(
+        &self,
+        signal: &Signal,
+        current_price: Decimal,
+        quantity: Option<Decimal>,
+    ) -> ExecutionResult {
+        self.process_signal_with_context(
+            signal,
+            current_price,
+            quantity,
+            ComplianceContext::default(),
+        )
+        .await
+    }
+
+    /// 컴플라이언스 컨텍스트를 지정하여 신호 처리.
+    ///
+    /// `process_signal_with_quantity`와 동일하지만, 호출자가 시세 정보
+    /// (기준가, 최우선 매수 호가 등)를 컴플라이언스 검사에 전달함.
+    /// 보유 수량, 데이 트레이드 횟수, 계좌 자산은 executor가 채움.
+    ///
+    /// # 인자
+    /// * `signal` - 처리할 트레이딩 신호
+    /// * `current_price` - 현재 시장 가격
+    /// * `quantity` - 주문 수량 (선택)
+    /// * `context` - 컴플라이언스 검사용 시세 정보
+    pub async fn process_signal_with_context(
+        &self,
+        signal: &Signal,
+        current_price: Decimal,
+        quantity: Option<Decimal>,
+        context: ComplianceContext,
+    ) -> ExecutionResult {
         // Signal을 주문 요청으로 변환
-        let order_request = match self.converter.convert(signal, current_price, None) {
+        let order_request = match self.converter.convert(signal, current_price, quantity) {
             Ok(o) => o,
-            Err(e) => return ExecutionResult::failure(signal.id, e.to_string()),
+            Err(e) => {
+                return ExecutionResult::failure(signal.id, e.to_string())
+                    .with_rejected_by("SignalConverter")
+            }
         };
 
-        // PositionTracker에서 현재 포지션 조회
-        let positions: Vec<Position> = {
+        // PositionTracker에서 현재 포지션과 최근 데이 트레이드 횟수 조회
+        let (positions, day_trades): (Vec<Position>, u32) = {
             let tracker = self.position_tracker.read().await;
-            tracker.get_open_positions().into_iter().cloned().collect()
+            (
+                tracker.get_open_positions().into_iter().cloned().collect(),
+                count_day_trades(tracker.get_closed_positions(), Utc::now()),
+            )
         };
 
         // 리스크 관리자로 검증
@@ -663,18 +744,48 @@ impl OrderExecutor {
         let validation =
             match risk_manager.validate_order(&order_request, &positions, current_price) {
                 Ok(v) => v,
-                Err(e) => return ExecutionResult::failure(signal.id, e.to_string()),
+                Err(e) => {
+                    return ExecutionResult::failure(signal.id, e.to_string())
+                        .with_rejected_by("RiskManager")
+                }
             };
 
         if !validation.is_valid {
             // 수정된 주문 제안이 있는지 확인
             if let Some(modified) = validation.modified_order {
                 return ExecutionResult::failure(signal.id, validation.messages.join("; "))
+                    .with_rejected_by("RiskManager")
                     .with_note(format!("Suggested adjusted order: {:?}", modified));
             }
-            return ExecutionResult::failure(signal.id, validation.messages.join("; "));
+            return ExecutionResult::failure(signal.id, validation.messages.join("; "))
+                .with_rejected_by("RiskManager");
         }
 
+        // 리스크 한도(포트폴리오 VaR 등)에 맞춰 축소된 주문으로 진행
+        let order_request = validation.modified_order.unwrap_or(order_request);
+
+        // 거래소 제출 전 컴플라이언스 검사 (호가 단위 등은 자동 보정)
+        let mut corrections = Vec::new();
+        let order_request = if risk_manager.compliance().is_empty() {
+            order_request
+        } else {
+            let context = Self::compliance_context(
+                &order_request,
+                &positions,
+                current_price,
+                day_trades,
+                context,
+            );
+            let compliance = risk_manager.check_compliance(&order_request, context);
+            if let Some(violation) = compliance.violation {
+                return ExecutionResult::failure(signal.id, violation.to_string())
+                    .with_rejected_by("Compliance")
+                    .with_violation(violation);
+            }
+            corrections = compliance.corrections;
+            compliance.order
+        };
+
         drop(risk_manager);
 
         // OrderRequest에서 Order를 생성하고 OrderManager에 등록
@@ -684,7 +795,8 @@ impl OrderExecutor {
         {
             let mut order_manager = self.order_manager.write().await;
             if let Err(e) = order_manager.add_order(order) {
-                return ExecutionResult::failure(signal.id, e.to_string());
+                return ExecutionResult::failure(signal.id, e.to_string())
+                    .with_rejected_by("OrderManager");
             }
         }
 
@@ -692,8 +804,8 @@ impl OrderExecutor {
         let mut result =
             ExecutionResult::success(signal.id, order_request.clone()).with_order_id(order_id);
 
-        // 경고가 있으면 추가
-        for msg in validation.messages {
+        // 경고 및 컴플라이언스 보정 내용이 있으면 추가
+        for msg in validation.messages.into_iter().chain(corrections) {
             result = result.with_note(msg);
         }
 
@@ -737,6 +849,28 @@ impl OrderExecutor {
         result
     }
 
+    /// 포지션 정보로 컴플라이언스 컨텍스트 보완.
+    fn compliance_context(
+        order: &OrderRequest,
+        positions: &[Position],
+        current_price: Decimal,
+        day_trades: u32,
+        mut context: ComplianceContext,
+    ) -> ComplianceContext {
+        let today = Utc::now().date_naive();
+        let longs: Vec<&Position> = positions
+            .iter()
+            .filter(|p| p.ticker == order.ticker && p.side == Side::Buy)
+            .collect();
+
+        context.last_price.get_or_insert(current_price);
+        context.position_quantity = longs.iter().map(|p| p.quantity).sum();
+        context.closes_same_day_position =
+            order.side == Side::Sell && longs.iter().any(|p| p.opened_at.date_naive() == today);
+        context.day_trades = day_trades;
+        context
+    }
+
     /// 거래소에 주문 제출.
     ///
     /// OrderManager의 주문 상태를 업데이트하며,
@@ -807,14 +941,39 @@ impl OrderExecutor {
         }
 
         // 체결에 따라 PositionTracker 업데이트
-        {
+        let closed_position = {
             let mut position_tracker = self.position_tracker.write().await;
 
+            // 반대 방향 체결이면 기존 포지션이 청산될 수 있음
+            let reduced_id = position_tracker
+                .get_position_for_symbol(&order.ticker)
+                .filter(|p| p.side != order.side)
+                .map(|p| p.id);
+
             // apply_fill은 새 포지션과 기존 포지션 모두 처리
             if let Err(e) = position_tracker.apply_fill(&order, &fill) {
                 // 오류 로그만 남기고 실패 처리하지 않음 - 포지션이 이미 청산되었을 수 있음
                 warn!("Failed to apply fill to position: {}", e);
             }
+
+            reduced_id.and_then(|id| {
+                position_tracker
+                    .get_closed_positions()
+                    .iter()
+                    .rev()
+                    .find(|p| p.id == id)
+                    .cloned()
+            })
+        };
+
+        // 청산된 거래 결과를 서킷 브레이커(연속 손실)에 기록
+        if let Some(position) = closed_position {
+            let mut risk_manager = self.risk_manager.write().await;
+            let tripped = risk_manager
+                .record_trade_result(position.strategy_id.as_deref(), position.realized_pnl);
+            for trip in tripped {
+                warn!(ticker = %position.ticker, "{}", trip.message());
+            }
         }
 
         Ok(())
@@ -979,6 +1138,11 @@ impl OrderExecutor {
         &self.position_tracker
     }
 
+    /// 리스크 관리자 참조 조회.
+    pub fn risk_manager(&self) -> &Arc<RwLock<RiskManager>> {
+        &self.risk_manager
+    }
+
     /// 여러 신호 처리.
     pub async fn process_signals(
         &self,
@@ -1181,6 +1345,20 @@ mod tests {
         assert_eq!(order.quantity, dec!(0.01));
     }
 
+    #[tokio::test]
+    async fn test_order_executor_process_signal_with_quantity() {
+        // 기본 수량이 0이어도 명시적 수량이 우선 적용되어야 함
+        let executor = create_test_executor(Decimal::ZERO);
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let result = executor
+            .process_signal_with_quantity(&signal, dec!(50000), Some(dec!(0.015)))
+            .await;
+
+        assert!(result.success);
+        assert_eq!(result.order.unwrap().quantity, dec!(0.015));
+    }
+
     #[tokio::test]
     async fn test_order_executor_auto_bracket_orders() {
         let config = RiskConfig::default();
@@ -1213,6 +1391,31 @@ mod tests {
 
         assert!(!result.success);
         assert!(result.error.is_some());
+        assert_eq!(result.rejected_by.as_deref(), Some("RiskManager"));
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_applies_var_downsized_order() {
+        // 잔고 10000, VaR 한도 1% ($100): 매일 ±2% 움직이는 BTC $1000 (VaR 약 $20) 이하로 축소
+        let mut config = RiskConfig::default();
+        config.portfolio_var.max_var_pct = Some(0.1);
+        let mut risk_manager = RiskManager::new(config, dec!(10000));
+        let closes: Vec<Decimal> = (0..60)
+            .map(|i| if i % 2 == 0 { dec!(50000) } else { dec!(51000) })
+            .collect();
+        risk_manager.update_price_history("BTC/USDT", &closes);
+        let executor =
+            OrderExecutor::new_complete(risk_manager, "test_exchange", ConversionConfig::default());
+
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let result = executor
+            .process_signal_with_quantity(&signal, dec!(50000), Some(dec!(0.02)))
+            .await;
+
+        assert!(result.success);
+        let order = result.order.unwrap();
+        assert!(order.quantity > Decimal::ZERO && order.quantity < dec!(0.02));
+        assert!(result.notes.iter().any(|n| n.contains("downsized")));
     }
 
     #[tokio::test]
@@ -1276,6 +1479,94 @@ mod tests {
         assert!(position.is_some());
     }
 
+    #[tokio::test]
+    async fn test_order_executor_records_closed_trade_result() {
+        let mut config = RiskConfig::default();
+        config.drawdown.max_strategy_consecutive_losses = Some(1);
+        config.drawdown.action = trader_risk::BreakerAction::Halt;
+        let executor = OrderExecutor::new_complete(
+            RiskManager::new(config, dec!(10000)),
+            "test_exchange",
+            ConversionConfig {
+                default_quantity: dec!(0.01),
+                ..Default::default()
+            },
+        );
+
+        // 50000 매수 후 49000 매도 → 손실 청산
+        for (side, signal_type, price) in [
+            (Side::Buy, SignalType::Entry, dec!(50000)),
+            (Side::Sell, SignalType::Exit, dec!(49000)),
+        ] {
+            let signal = create_test_signal(side, signal_type);
+            let result = executor.process_signal(&signal, price).await;
+            assert!(result.success, "{:?}", result.error);
+            let order_id = result.order_id.unwrap();
+            let fill = OrderFill {
+                order_id,
+                quantity: dec!(0.01),
+                price,
+                commission: None,
+                commission_asset: None,
+                timestamp: chrono::Utc::now(),
+            };
+            executor.handle_fill(order_id, fill, true).await.unwrap();
+        }
+
+        assert!(executor.get_position("BTC/USDT").await.is_none());
+        let risk_manager = executor.risk_manager().read().await;
+        let trips = risk_manager.circuit_breaker().active_trips();
+        assert_eq!(trips.len(), 1);
+        assert_eq!(trips[0].strategy_id.as_deref(), Some("test_strategy"));
+        drop(risk_manager);
+
+        // 같은 전략의 신규 진입은 중지
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Trading halted"));
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_applies_compliance_rules() {
+        let mut config = RiskConfig::default();
+        config.compliance.enabled = true;
+        config.compliance.symbol_filters.insert(
+            "BTC/USDT".to_string(),
+            trader_risk::SymbolFilter {
+                step_size: Some(dec!(0.001)),
+                min_notional: Some(dec!(100)),
+                ..Default::default()
+            },
+        );
+        let executor = OrderExecutor::new_complete(
+            RiskManager::new(config, dec!(10000)),
+            "test_exchange",
+            ConversionConfig {
+                default_quantity: dec!(0.0123),
+                ..Default::default()
+            },
+        );
+
+        // LOT_SIZE 단위로 수량 보정
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.order.unwrap().quantity, dec!(0.012));
+        assert!(result.notes.iter().any(|n| n.starts_with("symbol_filter")));
+
+        // MIN_NOTIONAL 미달 → 컴플라이언스 거부
+        let result = executor
+            .process_signal_with_quantity(&signal, dec!(50000), Some(dec!(0.001)))
+            .await;
+        assert!(!result.success);
+        assert_eq!(result.rejected_by.as_deref(), Some("Compliance"));
+        assert_eq!(
+            result.violation.map(|v| v.code),
+            Some(trader_risk::ComplianceCode::MinNotionalViolation)
+        );
+    }
+
     #[tokio::test]
     async fn test_order_executor_cancel_order() {
         let executor = create_test_executor(dec!(0.01));
@@ -24,7 +24,8 @@ pub mod position_tracker;
 
 // 주요 타입 재내보내기
 pub use executor::{
-    ConversionConfig, ExecutionError, ExecutionResult, OrderExecutor, SignalConverter,
+    BracketFillResult, ConversionConfig, ExecutionError, ExecutionResult, OrderExecutor,
+    SignalConverter,
 };
 pub use order_manager::{OrderEvent, OrderFill, OrderManager, OrderManagerError, OrderStats};
 pub use position_tracker::{PositionEvent, PositionTracker, PositionTrackerError};
@@ -3,7 +3,7 @@
 //! 사용자로부터 명령어를 수신하고 처리합니다.
 //! - `/portfolio` - 포트폴리오 현황 조회
 //! - `/status` - 시스템 상태 조회
-//! - `/stop` - 전략 중지
+//! - `/stop` - 전략 중지 (`drain`: 청산 후 중지, `pause`: 일시 정지)
 //! - `/report` - 일일/주간 리포트
 //! - `/attack` - ATTACK 상태 종목 조회
 
@@ -65,7 +65,10 @@ pub enum BotCommand {
     /// 시스템 상태
     Status,
     /// 전략 중지
-    Stop { strategy_id: Option<String> },
+    Stop {
+        strategy_id: Option<String>,
+        mode: StopMode,
+    },
     /// 리포트 조회
     Report { period: ReportPeriod },
     /// ATTACK 상태 종목
@@ -76,6 +79,18 @@ pub enum BotCommand {
     Unknown(String),
 }
 
+/// 전략 중지 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StopMode {
+    /// 즉시 중지
+    #[default]
+    Immediate,
+    /// 신규 진입을 막고 보유 포지션 청산 후 중지
+    Drain,
+    /// 데이터 수신은 유지하고 신호만 중단
+    Pause,
+}
+
 /// 리포트 기간.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum ReportPeriod {
@@ -103,7 +118,12 @@ impl BotCommand {
             Some("status") | Some("s") => BotCommand::Status,
             Some("stop") => {
                 let strategy_id = parts.get(1).map(|s| s.to_string());
-                BotCommand::Stop { strategy_id }
+                let mode = match parts.get(2).map(|s| s.to_lowercase()).as_deref() {
+                    Some("drain") | Some("d") => StopMode::Drain,
+                    Some("pause") | Some("p") => StopMode::Pause,
+                    _ => StopMode::Immediate,
+                };
+                BotCommand::Stop { strategy_id, mode }
             }
             Some("report") | Some("r") => {
                 let period = match parts.get(1).map(|s| s.to_lowercase()).as_deref() {
@@ -150,7 +170,11 @@ pub trait BotCommandHandler: Send + Sync {
     async fn handle_status(&self) -> NotificationResult<CommandResponse>;
 
     /// 전략 중지.
-    async fn handle_stop(&self, strategy_id: Option<&str>) -> NotificationResult<CommandResponse>;
+    async fn handle_stop(
+        &self,
+        strategy_id: Option<&str>,
+        mode: StopMode,
+    ) -> NotificationResult<CommandResponse>;
 
     /// 리포트 조회.
     async fn handle_report(&self, period: ReportPeriod) -> NotificationResult<CommandResponse>;
@@ -296,8 +320,8 @@ impl<H: BotCommandHandler> TelegramBotHandler<H> {
         match command {
             BotCommand::Portfolio => self.handler.handle_portfolio().await,
             BotCommand::Status => self.handler.handle_status().await,
-            BotCommand::Stop { strategy_id } => {
-                self.handler.handle_stop(strategy_id.as_deref()).await
+            BotCommand::Stop { strategy_id, mode } => {
+                self.handler.handle_stop(strategy_id.as_deref(), mode).await
             }
             BotCommand::Report { period } => self.handler.handle_report(period).await,
             BotCommand::Attack => self.handler.handle_attack().await,
@@ -318,7 +342,7 @@ impl<H: BotCommandHandler> TelegramBotHandler<H> {
              <b>사용 가능한 명령어:</b>\n\n\
              /portfolio (p) - 📊 포트폴리오 현황\n\
              /status (s) - 🔍 시스템 상태\n\
-             /stop [전략ID] - ⏹️ 전략 중지\n\
+             /stop [전략ID] [drain|pause] - ⏹️ 전략 중지\n\
              /report [daily|weekly|monthly] - 📈 리포트\n\
              /attack (a) - 🎯 ATTACK 상태 종목\n\
              /help (h) - ❓ 도움말\n\n\
@@ -389,18 +413,23 @@ impl BotCommandHandler for DefaultBotHandler {
         ))
     }
 
-    async fn handle_stop(&self, strategy_id: Option<&str>) -> NotificationResult<CommandResponse> {
+    async fn handle_stop(
+        &self,
+        strategy_id: Option<&str>,
+        mode: StopMode,
+    ) -> NotificationResult<CommandResponse> {
         match strategy_id {
             Some(id) => Ok(CommandResponse::html(format!(
                 "⏹️ <b>전략 중지 요청</b>\n\n\
                  전략 ID: <code>{}</code>\n\
+                 방식: {:?}\n\
                  <i>실제 중지 기능은 API 연동 후 가능합니다.</i>",
-                id
+                id, mode
             ))),
             None => Ok(CommandResponse::html(
                 "⏹️ <b>전략 중지</b>\n\n\
-                 사용법: /stop [전략ID]\n\
-                 예시: /stop rsi_mean_reversion",
+                 사용법: /stop [전략ID] [drain|pause]\n\
+                 예시: /stop rsi_mean_reversion drain",
             )),
         }
     }
@@ -448,12 +477,30 @@ mod tests {
     fn test_parse_stop_command() {
         assert_eq!(
             BotCommand::parse("/stop"),
-            BotCommand::Stop { strategy_id: None }
+            BotCommand::Stop {
+                strategy_id: None,
+                mode: StopMode::Immediate
+            }
         );
         assert_eq!(
             BotCommand::parse("/stop rsi_strategy"),
             BotCommand::Stop {
-                strategy_id: Some("rsi_strategy".to_string())
+                strategy_id: Some("rsi_strategy".to_string()),
+                mode: StopMode::Immediate
+            }
+        );
+        assert_eq!(
+            BotCommand::parse("/stop rsi_strategy drain"),
+            BotCommand::Stop {
+                strategy_id: Some("rsi_strategy".to_string()),
+                mode: StopMode::Drain
+            }
+        );
+        assert_eq!(
+            BotCommand::parse("/stop rsi_strategy P"),
+            BotCommand::Stop {
+                strategy_id: Some("rsi_strategy".to_string()),
+                mode: StopMode::Pause
             }
         );
     }
@@ -0,0 +1,1084 @@
+//! 주문 전 컴플라이언스 규칙 체인.
+//!
+//! 거래소(KIS, Binance)에 주문을 보내기 전에 규칙 위반으로 거부될 주문을 로컬에서 걸러냅니다.
+//! 규칙은 [`ComplianceRule`] trait으로 구현하며 [`ComplianceChain`]에 순서대로 연결됩니다.
+//! 각 규칙은 주문을 통과시키거나, 자동 보정(예: 호가 단위 라운딩)하거나,
+//! 구조화된 거부 코드([`ComplianceCode`])로 거부합니다.
+//!
+//! 기본 제공 규칙:
+//! - **거래 제한 종목**: 제한 목록에 있는 종목 주문 거부
+//! - **KRX 가격제한폭**: 기준가 대비 ±30% 밖의 지정가 주문 거부
+//! - **호가 단위**: `KrxTickSize` / `UsEquityTickSize` 기준으로 가격 보정
+//!   (매수는 내림, 매도는 올림 - 원래 가격보다 불리하게 체결되지 않음)
+//! - **심볼 필터**: Binance PRICE_FILTER / LOT_SIZE / MIN_NOTIONAL
+//! - **패턴 데이 트레이더**: 미국 주식 5영업일 데이 트레이드 한도
+//! - **공매도 제한**: 보유 수량 초과 매도 거부 (또는 보유 수량으로 축소), 공매도 가격 제한(SSR)
+
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+use trader_core::{
+    KrxTickSize, MarketType, OrderRequest, OrderType, Position, RoundMethod, Side,
+    TickSizeProvider, UsEquityTickSize,
+};
+
+/// 패턴 데이 트레이더 판정 기간 (영업일).
+const DAY_TRADE_WINDOW_BUSINESS_DAYS: u32 = 5;
+
+/// 심볼 필터가 없는 암호화폐의 기본 수량 단위.
+const DEFAULT_CRYPTO_LOT_STEP: Decimal = dec!(0.00000001);
+
+/// 컴플라이언스 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceConfig {
+    /// 주문 전 컴플라이언스 검사 활성화 여부 (기본값: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 거래 제한 종목 목록
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub restricted_tickers: Vec<String>,
+
+    /// KRX 가격제한폭 (%, 기본값: 30)
+    #[serde(default = "default_krx_price_limit_pct")]
+    pub krx_price_limit_pct: f64,
+
+    /// 심볼별 거래소 필터 (Binance exchangeInfo 기준)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub symbol_filters: HashMap<String, SymbolFilter>,
+
+    /// 패턴 데이 트레이더 규칙이 면제되는 최소 계좌 자산 (기본값: 25,000 USD)
+    #[serde(default = "default_pdt_min_equity")]
+    pub pdt_min_equity: Decimal,
+
+    /// 5영업일 동안 허용되는 데이 트레이드 횟수 (기본값: 3)
+    #[serde(default = "default_pdt_max_day_trades")]
+    pub pdt_max_day_trades: u32,
+
+    /// 주식 공매도 허용 여부 (기본값: false)
+    #[serde(default)]
+    pub allow_short_selling: bool,
+}
+
+fn default_krx_price_limit_pct() -> f64 {
+    30.0
+}
+
+fn default_pdt_min_equity() -> Decimal {
+    dec!(25000)
+}
+
+fn default_pdt_max_day_trades() -> u32 {
+    3
+}
+
+impl Default for ComplianceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            restricted_tickers: Vec::new(),
+            krx_price_limit_pct: default_krx_price_limit_pct(),
+            symbol_filters: HashMap::new(),
+            pdt_min_equity: default_pdt_min_equity(),
+            pdt_max_day_trades: default_pdt_max_day_trades(),
+            allow_short_selling: false,
+        }
+    }
+}
+
+impl ComplianceConfig {
+    /// 종목의 주문 수량 단위.
+    ///
+    /// 심볼 필터의 `step_size`가 있으면 그 값을 쓰고, 없으면
+    /// 주식은 1주, 암호화폐는 1e-8 단위를 사용합니다.
+    pub fn lot_step(&self, ticker: &str) -> Decimal {
+        if let Some(step) = self
+            .symbol_filters
+            .get(ticker)
+            .and_then(|f| f.step_size)
+            .filter(|step| *step > Decimal::ZERO)
+        {
+            return step;
+        }
+        match infer_market(ticker) {
+            MarketType::Crypto => DEFAULT_CRYPTO_LOT_STEP,
+            _ => Decimal::ONE,
+        }
+    }
+}
+
+/// 컴플라이언스 거부 코드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ComplianceCode {
+    /// 거래 제한 종목
+    RestrictedTicker,
+    /// 가격제한폭 초과
+    PriceLimitExceeded,
+    /// 호가 단위 위반 (보정 불가)
+    InvalidTickSize,
+    /// 수량 단위/범위 위반 (LOT_SIZE)
+    LotSizeViolation,
+    /// 최소 주문 금액 미달 (MIN_NOTIONAL)
+    MinNotionalViolation,
+    /// 패턴 데이 트레이더 한도 초과
+    PatternDayTrader,
+    /// 공매도 불허
+    ShortSaleNotAllowed,
+    /// 공매도 가격 제한 (SSR)
+    ShortSaleRestricted,
+}
+
+impl ComplianceCode {
+    /// 코드 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComplianceCode::RestrictedTicker => "RESTRICTED_TICKER",
+            ComplianceCode::PriceLimitExceeded => "PRICE_LIMIT_EXCEEDED",
+            ComplianceCode::InvalidTickSize => "INVALID_TICK_SIZE",
+            ComplianceCode::LotSizeViolation => "LOT_SIZE_VIOLATION",
+            ComplianceCode::MinNotionalViolation => "MIN_NOTIONAL_VIOLATION",
+            ComplianceCode::PatternDayTrader => "PATTERN_DAY_TRADER",
+            ComplianceCode::ShortSaleNotAllowed => "SHORT_SALE_NOT_ALLOWED",
+            ComplianceCode::ShortSaleRestricted => "SHORT_SALE_RESTRICTED",
+        }
+    }
+}
+
+impl fmt::Display for ComplianceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 컴플라이언스 규칙 위반.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[error("{code}: {message}")]
+pub struct ComplianceViolation {
+    /// 거부 코드
+    pub code: ComplianceCode,
+    /// 위반한 규칙 이름
+    pub rule: String,
+    /// 상세 메시지
+    pub message: String,
+}
+
+impl ComplianceViolation {
+    /// 새 위반 생성.
+    pub fn new(code: ComplianceCode, rule: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            rule: rule.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// 규칙 검사 결과.
+#[derive(Debug, Clone)]
+pub enum RuleOutcome {
+    /// 통과
+    Pass,
+    /// 보정된 주문으로 통과
+    Corrected {
+        /// 보정된 주문
+        order: OrderRequest,
+        /// 보정 내용
+        note: String,
+    },
+    /// 거부
+    Reject(ComplianceViolation),
+}
+
+/// 규칙 검사에 필요한 시장/계좌 정보.
+///
+/// 알 수 없는 값은 `None`으로 두며, 해당 값이 필요한 검사는 건너뜁니다.
+#[derive(Debug, Clone, Default)]
+pub struct ComplianceContext {
+    /// 시장 유형 (없으면 티커로 추론)
+    pub market: Option<MarketType>,
+    /// 최근 체결가
+    pub last_price: Option<Decimal>,
+    /// 최우선 매수 호가
+    pub best_bid: Option<Decimal>,
+    /// 가격제한폭 기준가 (전일 종가)
+    pub reference_price: Option<Decimal>,
+    /// 해당 종목 롱 보유 수량
+    pub position_quantity: Decimal,
+    /// 계좌 자산
+    pub account_equity: Option<Decimal>,
+    /// 최근 5영업일 데이 트레이드 횟수
+    pub day_trades: u32,
+    /// 이 주문이 당일 진입한 포지션을 청산하는지 여부 (데이 트레이드)
+    pub closes_same_day_position: bool,
+    /// 공매도 가격 제한(SSR) 발동 여부
+    pub short_sale_restricted: bool,
+}
+
+impl ComplianceContext {
+    /// 시장 유형 설정.
+    pub fn with_market(mut self, market: MarketType) -> Self {
+        self.market = Some(market);
+        self
+    }
+
+    /// 최근 체결가 설정.
+    pub fn with_last_price(mut self, price: Decimal) -> Self {
+        self.last_price = Some(price);
+        self
+    }
+
+    /// 최우선 매수 호가 설정.
+    pub fn with_best_bid(mut self, bid: Decimal) -> Self {
+        self.best_bid = Some(bid);
+        self
+    }
+
+    /// 가격제한폭 기준가 설정.
+    pub fn with_reference_price(mut self, price: Decimal) -> Self {
+        self.reference_price = Some(price);
+        self
+    }
+
+    /// 공매도 가격 제한(SSR) 발동 여부 설정.
+    pub fn with_short_sale_restricted(mut self, restricted: bool) -> Self {
+        self.short_sale_restricted = restricted;
+        self
+    }
+}
+
+/// 티커 형식으로 시장 유형 추론.
+///
+/// - 6자리 숫자: 한국 주식
+/// - `/` 포함 (예: BTC/USDT): 암호화폐
+/// - 그 외: 미국 주식
+pub fn infer_market(ticker: &str) -> MarketType {
+    if ticker.len() == 6 && ticker.chars().all(|c| c.is_ascii_digit()) {
+        MarketType::KrStock
+    } else if ticker.contains('/') {
+        MarketType::Crypto
+    } else {
+        MarketType::UsStock
+    }
+}
+
+/// 최근 5영업일 동안의 데이 트레이드(같은 날 진입/청산) 횟수.
+pub fn count_day_trades<'a>(
+    closed_positions: impl IntoIterator<Item = &'a Position>,
+    now: DateTime<Utc>,
+) -> u32 {
+    let window_start = business_days_ago(now, DAY_TRADE_WINDOW_BUSINESS_DAYS - 1);
+    closed_positions
+        .into_iter()
+        .filter(|p| {
+            p.closed_at.is_some_and(|closed_at| {
+                closed_at.date_naive() == p.opened_at.date_naive()
+                    && closed_at.date_naive() >= window_start
+            })
+        })
+        .count() as u32
+}
+
+/// `days` 영업일 전 날짜 (주말 제외).
+fn business_days_ago(now: DateTime<Utc>, days: u32) -> chrono::NaiveDate {
+    let mut date = now.date_naive();
+    let mut remaining = days;
+    while remaining > 0 {
+        date -= Duration::days(1);
+        if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            remaining -= 1;
+        }
+    }
+    date
+}
+
+/// 주문 전 컴플라이언스 규칙.
+pub trait ComplianceRule: Send + Sync {
+    /// 규칙 이름.
+    fn name(&self) -> &str;
+
+    /// 규칙이 적용되는 시장인지 확인 (기본: 모든 시장).
+    fn applies_to(&self, _market: MarketType) -> bool {
+        true
+    }
+
+    /// 주문 검사.
+    fn check(&self, order: &OrderRequest, ctx: &ComplianceContext) -> RuleOutcome;
+}
+
+/// 컴플라이언스 체인 검사 결과.
+#[derive(Debug, Clone)]
+pub struct ComplianceResult {
+    /// 최종 주문 (보정 반영)
+    pub order: OrderRequest,
+    /// 적용된 보정 내용
+    pub corrections: Vec<String>,
+    /// 거부 사유 (거부된 경우)
+    pub violation: Option<ComplianceViolation>,
+}
+
+impl ComplianceResult {
+    /// 주문이 승인되었는지 확인.
+    pub fn is_approved(&self) -> bool {
+        self.violation.is_none()
+    }
+
+    /// 주문이 보정되었는지 확인.
+    pub fn is_corrected(&self) -> bool {
+        !self.corrections.is_empty()
+    }
+}
+
+/// 순서대로 적용되는 컴플라이언스 규칙 체인.
+///
+/// 보정된 주문은 다음 규칙으로 전달되며, 첫 거부에서 중단합니다.
+#[derive(Clone, Default)]
+pub struct ComplianceChain {
+    rules: Vec<Arc<dyn ComplianceRule>>,
+}
+
+impl fmt::Debug for ComplianceChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.rules.iter().map(|rule| rule.name()))
+            .finish()
+    }
+}
+
+impl ComplianceChain {
+    /// 빈 체인 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 설정에서 기본 규칙 체인 생성.
+    ///
+    /// 비활성화된 경우 빈 체인을 반환합니다.
+    pub fn from_config(config: &ComplianceConfig) -> Self {
+        if !config.enabled {
+            return Self::new();
+        }
+
+        let mut chain = Self::new();
+        if !config.restricted_tickers.is_empty() {
+            chain = chain.with_rule(RestrictedListRule::new(
+                config.restricted_tickers.iter().cloned(),
+            ));
+        }
+        chain = chain
+            .with_rule(PriceLimitRule::krx(
+                Decimal::from_f64(config.krx_price_limit_pct).unwrap_or(dec!(30)),
+            ))
+            .with_rule(TickSizeRule::krx())
+            .with_rule(TickSizeRule::us_equity());
+        if !config.symbol_filters.is_empty() {
+            chain = chain.with_rule(SymbolFilterRule::new(config.symbol_filters.clone()));
+        }
+        chain
+            .with_rule(PatternDayTraderRule::new(
+                config.pdt_min_equity,
+                config.pdt_max_day_trades,
+            ))
+            .with_rule(ShortSaleRule::new(config.allow_short_selling))
+    }
+
+    /// 규칙 추가.
+    pub fn with_rule(mut self, rule: impl ComplianceRule + 'static) -> Self {
+        self.rules.push(Arc::new(rule));
+        self
+    }
+
+    /// 규칙이 없는지 확인.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// 규칙 이름 목록.
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// 주문 검사.
+    pub fn check(&self, order: &OrderRequest, ctx: &ComplianceContext) -> ComplianceResult {
+        let market = ctx.market.unwrap_or_else(|| infer_market(&order.ticker));
+        let mut result = ComplianceResult {
+            order: order.clone(),
+            corrections: Vec::new(),
+            violation: None,
+        };
+
+        for rule in self.rules.iter().filter(|rule| rule.applies_to(market)) {
+            match rule.check(&result.order, ctx) {
+                RuleOutcome::Pass => {}
+                RuleOutcome::Corrected { order, note } => {
+                    result.order = order;
+                    result
+                        .corrections
+                        .push(format!("{}: {}", rule.name(), note));
+                }
+                RuleOutcome::Reject(violation) => {
+                    result.violation = Some(violation);
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// 거래 제한 종목 규칙.
+#[derive(Debug, Clone)]
+pub struct RestrictedListRule {
+    tickers: HashSet<String>,
+}
+
+impl RestrictedListRule {
+    /// 제한 종목 목록으로 생성.
+    pub fn new(tickers: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            tickers: tickers.into_iter().map(|t| t.to_uppercase()).collect(),
+        }
+    }
+}
+
+impl ComplianceRule for RestrictedListRule {
+    fn name(&self) -> &str {
+        "restricted_list"
+    }
+
+    fn check(&self, order: &OrderRequest, _ctx: &ComplianceContext) -> RuleOutcome {
+        if self.tickers.contains(&order.ticker.to_uppercase()) {
+            return RuleOutcome::Reject(ComplianceViolation::new(
+                ComplianceCode::RestrictedTicker,
+                self.name(),
+                format!("{} is on the restricted list", order.ticker),
+            ));
+        }
+        RuleOutcome::Pass
+    }
+}
+
+/// 가격제한폭 규칙.
+///
+/// 기준가 대비 상/하한을 호가 단위로 안쪽 라운딩한 범위 밖의 지정가 주문을 거부합니다.
+/// 기준가를 모르면 검사하지 않습니다.
+pub struct PriceLimitRule {
+    market: MarketType,
+    limit_pct: Decimal,
+    tick_size: Arc<dyn TickSizeProvider>,
+}
+
+impl PriceLimitRule {
+    /// KRX 가격제한폭 규칙.
+    pub fn krx(limit_pct: Decimal) -> Self {
+        Self {
+            market: MarketType::KrStock,
+            limit_pct,
+            tick_size: Arc::new(KrxTickSize::new()),
+        }
+    }
+
+    /// (하한, 상한) 가격.
+    pub fn bounds(&self, reference_price: Decimal) -> (Decimal, Decimal) {
+        let band = reference_price * self.limit_pct / dec!(100);
+        let lower = self
+            .tick_size
+            .round_to_tick(reference_price - band, RoundMethod::Ceil);
+        let upper = self
+            .tick_size
+            .round_to_tick(reference_price + band, RoundMethod::Floor);
+        (lower, upper)
+    }
+}
+
+impl ComplianceRule for PriceLimitRule {
+    fn name(&self) -> &str {
+        "price_limit"
+    }
+
+    fn applies_to(&self, market: MarketType) -> bool {
+        market == self.market
+    }
+
+    fn check(&self, order: &OrderRequest, ctx: &ComplianceContext) -> RuleOutcome {
+        let (Some(price), Some(reference)) = (order.price, ctx.reference_price) else {
+            return RuleOutcome::Pass;
+        };
+        if reference <= Decimal::ZERO {
+            return RuleOutcome::Pass;
+        }
+
+        let (lower, upper) = self.bounds(reference);
+        if price < lower || price > upper {
+            return RuleOutcome::Reject(ComplianceViolation::new(
+                ComplianceCode::PriceLimitExceeded,
+                self.name(),
+                format!(
+                    "{} limit price {} outside ±{}% band [{}, {}] of reference {}",
+                    order.ticker, price, self.limit_pct, lower, upper, reference
+                ),
+            ));
+        }
+        RuleOutcome::Pass
+    }
+}
+
+/// 호가 단위 규칙.
+///
+/// 지정가/스탑 가격을 호가 단위로 보정합니다. 매수는 내림, 매도는 올림으로
+/// 원래 가격보다 불리하게 체결되지 않도록 합니다.
+pub struct TickSizeRule {
+    market: MarketType,
+    tick_size: Arc<dyn TickSizeProvider>,
+}
+
+impl TickSizeRule {
+    /// 시장과 호가 단위 제공자로 생성.
+    pub fn new(market: MarketType, tick_size: Arc<dyn TickSizeProvider>) -> Self {
+        Self { market, tick_size }
+    }
+
+    /// KRX 호가 단위 규칙.
+    pub fn krx() -> Self {
+        Self::new(MarketType::KrStock, Arc::new(KrxTickSize::new()))
+    }
+
+    /// 미국 주식 호가 단위 규칙.
+    pub fn us_equity() -> Self {
+        Self::new(MarketType::UsStock, Arc::new(UsEquityTickSize::new()))
+    }
+}
+
+impl ComplianceRule for TickSizeRule {
+    fn name(&self) -> &str {
+        "tick_size"
+    }
+
+    fn applies_to(&self, market: MarketType) -> bool {
+        market == self.market
+    }
+
+    fn check(&self, order: &OrderRequest, _ctx: &ComplianceContext) -> RuleOutcome {
+        let provider = self.tick_size.as_ref();
+        round_prices(self.name(), order, |price| {
+            if provider.is_valid_price(price) {
+                return price;
+            }
+            provider.round_to_tick(price, round_method(order.side))
+        })
+    }
+}
+
+/// 보정 방향 (매수는 내림, 매도는 올림).
+fn round_method(side: Side) -> RoundMethod {
+    match side {
+        Side::Buy => RoundMethod::Floor,
+        Side::Sell => RoundMethod::Ceil,
+    }
+}
+
+/// 지정가/스탑 가격에 라운딩을 적용한 결과.
+fn round_prices(
+    rule: &str,
+    order: &OrderRequest,
+    round: impl Fn(Decimal) -> Decimal,
+) -> RuleOutcome {
+    let mut corrected = order.clone();
+    let mut notes = Vec::new();
+
+    for (label, slot) in [
+        ("price", &mut corrected.price),
+        ("stop price", &mut corrected.stop_price),
+    ] {
+        let Some(price) = *slot else { continue };
+        let rounded = round(price);
+        if rounded == price {
+            continue;
+        }
+        if rounded <= Decimal::ZERO {
+            return RuleOutcome::Reject(ComplianceViolation::new(
+                ComplianceCode::InvalidTickSize,
+                rule,
+                format!(
+                    "{} {} {} cannot be rounded to tick",
+                    order.ticker, label, price
+                ),
+            ));
+        }
+        notes.push(format!("{} {} → {}", label, price, rounded));
+        *slot = Some(rounded);
+    }
+
+    if notes.is_empty() {
+        RuleOutcome::Pass
+    } else {
+        RuleOutcome::Corrected {
+            order: corrected,
+            note: notes.join(", "),
+        }
+    }
+}
+
+/// 거래소 심볼 필터 (Binance exchangeInfo의 PRICE_FILTER / LOT_SIZE / MIN_NOTIONAL).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SymbolFilter {
+    /// 가격 단위 (PRICE_FILTER.tickSize)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick_size: Option<Decimal>,
+    /// 수량 단위 (LOT_SIZE.stepSize)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step_size: Option<Decimal>,
+    /// 최소 수량 (LOT_SIZE.minQty)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_qty: Option<Decimal>,
+    /// 최대 수량 (LOT_SIZE.maxQty)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_qty: Option<Decimal>,
+    /// 최소 주문 금액 (MIN_NOTIONAL.minNotional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_notional: Option<Decimal>,
+}
+
+/// 심볼 필터 규칙.
+///
+/// 가격은 tick_size, 수량은 step_size 단위로 내림 보정한 뒤
+/// 수량 범위와 최소 주문 금액을 검사합니다. 필터가 없는 종목은 통과합니다.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolFilterRule {
+    filters: HashMap<String, SymbolFilter>,
+}
+
+impl SymbolFilterRule {
+    /// 심볼별 필터로 생성.
+    pub fn new(filters: HashMap<String, SymbolFilter>) -> Self {
+        Self { filters }
+    }
+
+    /// 심볼 필터 추가.
+    pub fn with_symbol(mut self, ticker: impl Into<String>, filter: SymbolFilter) -> Self {
+        self.filters.insert(ticker.into(), filter);
+        self
+    }
+}
+
+/// `step` 단위로 내림.
+pub fn floor_to_step(value: Decimal, step: Decimal) -> Decimal {
+    if step <= Decimal::ZERO {
+        return value;
+    }
+    ((value / step).round_dp_with_strategy(0, RoundingStrategy::ToZero) * step).normalize()
+}
+
+impl ComplianceRule for SymbolFilterRule {
+    fn name(&self) -> &str {
+        "symbol_filter"
+    }
+
+    fn check(&self, order: &OrderRequest, ctx: &ComplianceContext) -> RuleOutcome {
+        let Some(filter) = self.filters.get(&order.ticker) else {
+            return RuleOutcome::Pass;
+        };
+
+        let (mut corrected, mut notes) = match filter.tick_size {
+            Some(tick) => match round_prices(self.name(), order, |price| {
+                let ticks = price / tick;
+                let rounded = match round_method(order.side) {
+                    RoundMethod::Ceil => ticks.ceil(),
+                    _ => ticks.floor(),
+                };
+                (rounded * tick).normalize()
+            }) {
+                RuleOutcome::Pass => (order.clone(), Vec::new()),
+                RuleOutcome::Corrected { order, note } => (order, vec![note]),
+                reject @ RuleOutcome::Reject(_) => return reject,
+            },
+            None => (order.clone(), Vec::new()),
+        };
+
+        if let Some(step) = filter.step_size {
+            let quantity = floor_to_step(corrected.quantity, step);
+            if quantity != corrected.quantity {
+                notes.push(format!("quantity {} → {}", corrected.quantity, quantity));
+                corrected.quantity = quantity;
+            }
+        }
+
+        let min_qty = filter.min_qty.unwrap_or(Decimal::ZERO);
+        if corrected.quantity <= Decimal::ZERO || corrected.quantity < min_qty {
+            return RuleOutcome::Reject(ComplianceViolation::new(
+                ComplianceCode::LotSizeViolation,
+                self.name(),
+                format!(
+                    "{} quantity {} below minimum {}",
+                    order.ticker, corrected.quantity, min_qty
+                ),
+            ));
+        }
+        if let Some(max_qty) = filter.max_qty.filter(|max| corrected.quantity > *max) {
+            return RuleOutcome::Reject(ComplianceViolation::new(
+                ComplianceCode::LotSizeViolation,
+                self.name(),
+                format!(
+                    "{} quantity {} above maximum {}",
+                    order.ticker, corrected.quantity, max_qty
+                ),
+            ));
+        }
+
+        if let (Some(min_notional), Some(price)) =
+            (filter.min_notional, corrected.price.or(ctx.last_price))
+        {
+            let notional = corrected.quantity * price;
+            if notional < min_notional {
+                return RuleOutcome::Reject(ComplianceViolation::new(
+                    ComplianceCode::MinNotionalViolation,
+                    self.name(),
+                    format!(
+                        "{} notional {} below minimum {}",
+                        order.ticker, notional, min_notional
+                    ),
+                ));
+            }
+        }
+
+        if notes.is_empty() {
+            RuleOutcome::Pass
+        } else {
+            RuleOutcome::Corrected {
+                order: corrected,
+                note: notes.join(", "),
+            }
+        }
+    }
+}
+
+/// 패턴 데이 트레이더 규칙 (미국 주식).
+///
+/// 계좌 자산이 기준 미만이면 5영업일 동안 허용 횟수를 넘는 데이 트레이드를 거부합니다.
+/// 계좌 자산을 모르면 기준 미만으로 간주합니다.
+#[derive(Debug, Clone)]
+pub struct PatternDayTraderRule {
+    min_equity: Decimal,
+    max_day_trades: u32,
+}
+
+impl PatternDayTraderRule {
+    /// 최소 자산과 허용 횟수로 생성.
+    pub fn new(min_equity: Decimal, max_day_trades: u32) -> Self {
+        Self {
+            min_equity,
+            max_day_trades,
+        }
+    }
+}
+
+impl ComplianceRule for PatternDayTraderRule {
+    fn name(&self) -> &str {
+        "pattern_day_trader"
+    }
+
+    fn applies_to(&self, market: MarketType) -> bool {
+        market == MarketType::UsStock
+    }
+
+    fn check(&self, order: &OrderRequest, ctx: &ComplianceContext) -> RuleOutcome {
+        let exempt = ctx
+            .account_equity
+            .is_some_and(|equity| equity >= self.min_equity);
+        if exempt || !ctx.closes_same_day_position || ctx.day_trades < self.max_day_trades {
+            return RuleOutcome::Pass;
+        }
+
+        RuleOutcome::Reject(ComplianceViolation::new(
+            ComplianceCode::PatternDayTrader,
+            self.name(),
+            format!(
+                "{} day trade would exceed {} day trades in {} business days (equity below {})",
+                order.ticker, self.max_day_trades, DAY_TRADE_WINDOW_BUSINESS_DAYS, self.min_equity
+            ),
+        ))
+    }
+}
+
+/// 공매도 규칙 (주식).
+///
+/// 공매도를 허용하지 않으면 보유 수량을 넘는 매도를 보유 수량으로 축소하고,
+/// 보유 수량이 없으면 거부합니다. 공매도를 허용해도 SSR이 발동한 종목은
+/// 최우선 매수 호가(없으면 최근 체결가)보다 높은 지정가 공매도만 허용합니다.
+#[derive(Debug, Clone)]
+pub struct ShortSaleRule {
+    allow_short: bool,
+}
+
+impl ShortSaleRule {
+    /// 공매도 허용 여부로 생성.
+    pub fn new(allow_short: bool) -> Self {
+        Self { allow_short }
+    }
+}
+
+impl ComplianceRule for ShortSaleRule {
+    fn name(&self) -> &str {
+        "short_sale"
+    }
+
+    fn applies_to(&self, market: MarketType) -> bool {
+        matches!(market.normalize(), MarketType::Stock)
+    }
+
+    fn check(&self, order: &OrderRequest, ctx: &ComplianceContext) -> RuleOutcome {
+        if order.side != Side::Sell || order.quantity <= ctx.position_quantity {
+            return RuleOutcome::Pass;
+        }
+
+        if !self.allow_short {
+            if ctx.position_quantity > Decimal::ZERO {
+                let mut corrected = order.clone();
+                corrected.quantity = ctx.position_quantity;
+                return RuleOutcome::Corrected {
+                    order: corrected,
+                    note: format!(
+                        "quantity {} → {} (held quantity)",
+                        order.quantity, ctx.position_quantity
+                    ),
+                };
+            }
+            return RuleOutcome::Reject(ComplianceViolation::new(
+                ComplianceCode::ShortSaleNotAllowed,
+                self.name(),
+                format!(
+                    "{} sell without holdings (short selling disabled)",
+                    order.ticker
+                ),
+            ));
+        }
+
+        if ctx.short_sale_restricted {
+            let above_bid = match (order.order_type, order.price) {
+                (OrderType::Limit, Some(price)) => ctx
+                    .best_bid
+                    .or(ctx.last_price)
+                    .map_or(true, |bid| price > bid),
+                _ => false,
+            };
+            if !above_bid {
+                return RuleOutcome::Reject(ComplianceViolation::new(
+                    ComplianceCode::ShortSaleRestricted,
+                    self.name(),
+                    format!(
+                        "{} is under short sale restriction; short sales must be limit orders above the best bid",
+                        order.ticker
+                    ),
+                ));
+            }
+        }
+
+        RuleOutcome::Pass
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn limit(ticker: &str, side: Side, quantity: Decimal, price: Decimal) -> OrderRequest {
+        match side {
+            Side::Buy => OrderRequest::limit_buy(ticker.to_string(), quantity, price),
+            Side::Sell => OrderRequest::limit_sell(ticker.to_string(), quantity, price),
+        }
+    }
+
+    fn enabled_chain() -> ComplianceChain {
+        ComplianceChain::from_config(&ComplianceConfig {
+            enabled: true,
+            restricted_tickers: vec!["gme".to_string()],
+            ..ComplianceConfig::default()
+        })
+    }
+
+    #[test]
+    fn test_krx_price_limit_and_tick_size() {
+        let chain = enabled_chain();
+        let ctx = ComplianceContext::default().with_reference_price(dec!(50000));
+
+        // 호가 단위 보정: 매수는 내림
+        let result = chain.check(&limit("005930", Side::Buy, dec!(10), dec!(50_030)), &ctx);
+        assert!(result.is_approved());
+        assert_eq!(result.order.price, Some(dec!(50_000)));
+        assert!(result.is_corrected());
+
+        // 매도는 올림
+        let held = ComplianceContext {
+            position_quantity: dec!(10),
+            ..ctx.clone()
+        };
+        let result = chain.check(&limit("005930", Side::Sell, dec!(10), dec!(50_030)), &held);
+        assert_eq!(result.order.price, Some(dec!(50_100)));
+
+        // 상한가(65,000) 초과
+        let result = chain.check(&limit("005930", Side::Buy, dec!(10), dec!(65_100)), &ctx);
+        let violation = result.violation.expect("price band");
+        assert_eq!(violation.code, ComplianceCode::PriceLimitExceeded);
+        assert!(violation.to_string().starts_with("PRICE_LIMIT_EXCEEDED"));
+
+        // 하한가(35,000)는 허용
+        let result = chain.check(&limit("005930", Side::Buy, dec!(10), dec!(35_000)), &ctx);
+        assert!(result.is_approved());
+
+        // 제한 종목
+        let result = chain.check(&OrderRequest::market_buy("GME".to_string(), dec!(1)), &ctx);
+        assert_eq!(
+            result.violation.map(|v| v.code),
+            Some(ComplianceCode::RestrictedTicker)
+        );
+
+        // 비활성화 시 빈 체인
+        assert!(ComplianceChain::from_config(&ComplianceConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_symbol_filter() {
+        let chain = ComplianceChain::new().with_rule(SymbolFilterRule::default().with_symbol(
+            "BTC/USDT",
+            SymbolFilter {
+                tick_size: Some(dec!(0.01)),
+                step_size: Some(dec!(0.001)),
+                min_qty: Some(dec!(0.001)),
+                max_qty: Some(dec!(100)),
+                min_notional: Some(dec!(10)),
+            },
+        ));
+        let ctx = ComplianceContext::default().with_last_price(dec!(50000));
+
+        let result = chain.check(
+            &limit("BTC/USDT", Side::Buy, dec!(0.0123), dec!(50000.005)),
+            &ctx,
+        );
+        assert!(result.is_approved());
+        assert_eq!(result.order.quantity, dec!(0.012));
+        assert_eq!(result.order.price, Some(dec!(50000)));
+
+        let result = chain.check(
+            &OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.0001)),
+            &ctx,
+        );
+        assert_eq!(
+            result.violation.map(|v| v.code),
+            Some(ComplianceCode::LotSizeViolation)
+        );
+
+        let result = chain.check(&limit("BTC/USDT", Side::Buy, dec!(0.001), dec!(5000)), &ctx);
+        assert_eq!(
+            result.violation.map(|v| v.code),
+            Some(ComplianceCode::MinNotionalViolation)
+        );
+
+        // 필터가 없는 종목은 통과
+        let result = chain.check(
+            &OrderRequest::market_buy("ETH/USDT".to_string(), dec!(0.0001)),
+            &ctx,
+        );
+        assert!(result.is_approved());
+    }
+
+    #[test]
+    fn test_pattern_day_trader_and_short_sale() {
+        let chain = enabled_chain();
+        let sell = OrderRequest::market_sell("AAPL".to_string(), dec!(10));
+
+        let day_trade = ComplianceContext {
+            position_quantity: dec!(10),
+            closes_same_day_position: true,
+            day_trades: 3,
+            account_equity: Some(dec!(10000)),
+            ..ComplianceContext::default()
+        };
+        assert_eq!(
+            chain.check(&sell, &day_trade).violation.map(|v| v.code),
+            Some(ComplianceCode::PatternDayTrader)
+        );
+
+        let well_funded = ComplianceContext {
+            account_equity: Some(dec!(30000)),
+            ..day_trade.clone()
+        };
+        assert!(chain.check(&sell, &well_funded).is_approved());
+
+        // 보유 수량 초과 매도 → 보유 수량으로 축소
+        let partial = ComplianceContext {
+            position_quantity: dec!(4),
+            ..ComplianceContext::default()
+        };
+        let result = chain.check(&sell, &partial);
+        assert!(result.is_approved());
+        assert_eq!(result.order.quantity, dec!(4));
+
+        // 보유 없이 매도 → 거부
+        assert_eq!(
+            chain
+                .check(&sell, &ComplianceContext::default())
+                .violation
+                .map(|v| v.code),
+            Some(ComplianceCode::ShortSaleNotAllowed)
+        );
+
+        // 공매도 허용 + SSR: 최우선 매수 호가 이하 공매도 거부
+        let shorts = ComplianceChain::new().with_rule(ShortSaleRule::new(true));
+        let ssr = ComplianceContext::default()
+            .with_best_bid(dec!(150))
+            .with_short_sale_restricted(true);
+        assert_eq!(
+            shorts.check(&sell, &ssr).violation.map(|v| v.code),
+            Some(ComplianceCode::ShortSaleRestricted)
+        );
+        assert!(shorts
+            .check(&limit("AAPL", Side::Sell, dec!(10), dec!(150.01)), &ssr)
+            .is_approved());
+
+        // 암호화폐에는 적용하지 않음
+        assert!(chain
+            .check(
+                &OrderRequest::market_sell("BTC/USDT".to_string(), dec!(1)),
+                &ComplianceContext::default()
+            )
+            .is_approved());
+    }
+
+    #[test]
+    fn test_count_day_trades() {
+        // 2024-03-11 (월)
+        let now = Utc.with_ymd_and_hms(2024, 3, 11, 15, 0, 0).unwrap();
+        let closed = |opened: DateTime<Utc>, closed: DateTime<Utc>| {
+            let mut position =
+                Position::new("test", "AAPL".to_string(), Side::Buy, dec!(1), dec!(100));
+            position.opened_at = opened;
+            position.closed_at = Some(closed);
+            position
+        };
+
+        let positions = vec![
+            // 당일 진입/청산
+            closed(now - Duration::hours(2), now - Duration::hours(1)),
+            // 3/5 (화) - 5영업일 기간 안
+            closed(
+                Utc.with_ymd_and_hms(2024, 3, 5, 14, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 3, 5, 19, 0, 0).unwrap(),
+            ),
+            // 3/4 (월) - 기간 밖
+            closed(
+                Utc.with_ymd_and_hms(2024, 3, 4, 14, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 3, 4, 19, 0, 0).unwrap(),
+            ),
+            // 오버나이트 보유
+            closed(now - Duration::days(1), now),
+        ];
+        assert_eq!(count_day_trades(&positions, now), 2);
+    }
+}
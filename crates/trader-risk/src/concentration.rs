@@ -0,0 +1,499 @@
+//! 섹터 / 국가 / 통화 / 상관 클러스터 집중도 한도.
+//!
+//! 종목별 한도(`SymbolRiskConfig`)만으로는 같은 섹터나 서로 강하게 상관된 종목
+//! (예: 미국 3배 레버리지 ETF 묶음)에 노출이 쏠리는 것을 막을 수 없습니다.
+//! 이 모듈은 종목을 그룹으로 묶고 그룹 단위 총 노출을 계좌 잔고 대비 비율로 제한합니다.
+//!
+//! - **섹터 / 국가 / 통화**: 종목 프로필(`SymbolProfile`) 기준
+//! - **상관 클러스터**: 수익률 상관행렬에 평균 연결(average linkage) 계층적 군집화를 적용해
+//!   평균 상관계수가 기준 이상인 종목끼리 묶음
+//!
+//! 그룹 노출은 종목별 순노출의 절대값 합(gross)으로 계산합니다.
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use trader_analytics::correlation::CorrelationMatrix;
+use trader_core::{Country, OrderRequest, Side};
+
+/// 축소한 주문 수량의 소수점 자릿수.
+const ADJUSTED_QUANTITY_DECIMALS: u32 = 8;
+
+/// 집중도 한도 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConcentrationConfig {
+    /// 섹터별 최대 노출 비율 (%) - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_sector_pct: Option<f64>,
+
+    /// 국가별 최대 노출 비율 (%) - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_country_pct: Option<f64>,
+
+    /// 통화별 최대 노출 비율 (%) - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_currency_pct: Option<f64>,
+
+    /// 상관 클러스터별 최대 노출 비율 (%) - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cluster_pct: Option<f64>,
+
+    /// 같은 클러스터로 묶을 최소 평균 상관계수 (기본값: 0.7)
+    #[serde(default = "default_cluster_min_correlation")]
+    pub cluster_min_correlation: f64,
+}
+
+fn default_cluster_min_correlation() -> f64 {
+    0.7
+}
+
+impl Default for ConcentrationConfig {
+    fn default() -> Self {
+        Self {
+            max_sector_pct: None,
+            max_country_pct: None,
+            max_currency_pct: None,
+            max_cluster_pct: None,
+            cluster_min_correlation: default_cluster_min_correlation(),
+        }
+    }
+}
+
+impl ConcentrationConfig {
+    /// 설정된 한도가 하나라도 있는지 확인.
+    pub fn has_limit(&self) -> bool {
+        self.limits().iter().any(Option::is_some)
+    }
+
+    /// 모든 한도 값 (검증용).
+    pub(crate) fn limits(&self) -> [Option<f64>; 4] {
+        [
+            self.max_sector_pct,
+            self.max_country_pct,
+            self.max_currency_pct,
+            self.max_cluster_pct,
+        ]
+    }
+}
+
+/// 종목 분류 정보 (섹터, 국가, 통화).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolProfile {
+    /// 섹터/업종
+    pub sector: Option<String>,
+    /// 상장 국가
+    pub country: Option<Country>,
+    /// 거래 통화 (없으면 국가 기본 통화)
+    pub currency: Option<String>,
+}
+
+impl SymbolProfile {
+    /// 거래 통화 (명시되지 않으면 국가 기본 통화).
+    pub fn currency(&self) -> Option<String> {
+        self.currency
+            .clone()
+            .or_else(|| self.country.map(|c| c.default_currency().to_string()))
+    }
+}
+
+/// 집중도 한도를 적용하는 종목 그룹.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConcentrationBucket {
+    /// 섹터
+    Sector(String),
+    /// 국가
+    Country(Country),
+    /// 통화
+    Currency(String),
+    /// 상관 클러스터 (구성 종목)
+    Cluster(Vec<String>),
+}
+
+impl fmt::Display for ConcentrationBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConcentrationBucket::Sector(sector) => write!(f, "sector {}", sector),
+            ConcentrationBucket::Country(country) => write!(f, "country {}", country),
+            ConcentrationBucket::Currency(currency) => write!(f, "currency {}", currency),
+            ConcentrationBucket::Cluster(members) => {
+                write!(f, "correlation cluster [{}]", members.join(", "))
+            }
+        }
+    }
+}
+
+/// 집중도 한도 초과 내역.
+#[derive(Debug, Clone)]
+pub struct ConcentrationBreach {
+    /// 한도를 넘은 그룹
+    pub bucket: ConcentrationBucket,
+    /// 주문 후 그룹 노출 비율 (%)
+    pub exposure_pct: f64,
+    /// 한도 비율 (%)
+    pub limit_pct: f64,
+    /// 한도 안에 들어가는 최대 주문 수량 (여유가 없으면 None)
+    pub max_quantity: Option<Decimal>,
+}
+
+impl ConcentrationBreach {
+    /// 검증 메시지.
+    pub fn message(&self) -> String {
+        format!(
+            "Concentration limit: {} exposure {:.1}% exceeds limit {:.1}%",
+            self.bucket, self.exposure_pct, self.limit_pct
+        )
+    }
+}
+
+/// 종목 프로필을 보관하고 주문의 집중도 한도 초과 여부를 검사.
+#[derive(Debug, Clone, Default)]
+pub struct ConcentrationChecker {
+    config: ConcentrationConfig,
+    profiles: HashMap<String, SymbolProfile>,
+}
+
+impl ConcentrationChecker {
+    /// 새 검사기 생성.
+    pub fn new(config: ConcentrationConfig) -> Self {
+        Self {
+            config,
+            profiles: HashMap::new(),
+        }
+    }
+
+    /// 설정 참조 조회.
+    pub fn config(&self) -> &ConcentrationConfig {
+        &self.config
+    }
+
+    /// 종목 프로필 설정.
+    pub fn set_profile(&mut self, symbol: &str, profile: SymbolProfile) {
+        self.profiles.insert(symbol.to_string(), profile);
+    }
+
+    /// 종목 프로필 조회.
+    pub fn profile(&self, symbol: &str) -> Option<&SymbolProfile> {
+        self.profiles.get(symbol)
+    }
+
+    /// 주문 후 노출이 한도를 넘는 그룹 검사.
+    ///
+    /// 주문으로 노출이 늘어나는 그룹만 검사하므로 청산/축소 주문은 통과합니다.
+    ///
+    /// # Arguments
+    /// * `order` - 검증할 주문
+    /// * `price` - 주문 가격
+    /// * `before` - 주문 전 종목별 순노출 (매수 +, 매도 -)
+    /// * `after` - 주문 후 종목별 순노출
+    /// * `balance` - 계좌 잔고
+    /// * `correlation` - 노출 종목의 수익률 상관행렬 (없으면 클러스터 검사 생략)
+    pub fn check(
+        &self,
+        order: &OrderRequest,
+        price: Decimal,
+        before: &BTreeMap<String, Decimal>,
+        after: &BTreeMap<String, Decimal>,
+        balance: Decimal,
+        correlation: Option<&CorrelationMatrix>,
+    ) -> Vec<ConcentrationBreach> {
+        if balance <= Decimal::ZERO || price <= Decimal::ZERO {
+            return Vec::new();
+        }
+
+        self.buckets_for(&order.ticker, after, correlation)
+            .into_iter()
+            .filter_map(|(bucket, members, limit_pct)| {
+                let gross = |exposures: &BTreeMap<String, Decimal>| -> Decimal {
+                    members
+                        .iter()
+                        .filter_map(|m| exposures.get(m))
+                        .map(|v| v.abs())
+                        .sum()
+                };
+                let (gross_before, gross_after) = (gross(before), gross(after));
+                let limit = balance * Decimal::from_f64(limit_pct / 100.0)?;
+                if gross_after <= limit || gross_after <= gross_before {
+                    return None;
+                }
+
+                // 그룹 내 다른 종목 노출을 뺀 여유로 최대 수량 계산
+                let own_before = before.get(&order.ticker).copied().unwrap_or_default();
+                let room = limit - (gross_before - own_before.abs());
+                let direction = match order.side {
+                    Side::Buy => Decimal::ONE,
+                    Side::Sell => -Decimal::ONE,
+                };
+                let max_quantity = ((room - direction * own_before) / price)
+                    .min(order.quantity)
+                    .round_dp_with_strategy(ADJUSTED_QUANTITY_DECIMALS, RoundingStrategy::ToZero);
+
+                Some(ConcentrationBreach {
+                    bucket,
+                    exposure_pct: pct(gross_after, balance),
+                    limit_pct,
+                    max_quantity: (max_quantity > Decimal::ZERO).then_some(max_quantity),
+                })
+            })
+            .collect()
+    }
+
+    /// 주문 종목이 속한 그룹과 구성 종목, 한도.
+    fn buckets_for(
+        &self,
+        symbol: &str,
+        exposures: &BTreeMap<String, Decimal>,
+        correlation: Option<&CorrelationMatrix>,
+    ) -> Vec<(ConcentrationBucket, Vec<String>, f64)> {
+        let mut buckets = Vec::new();
+        let members_where = |matches: &dyn Fn(&SymbolProfile) -> bool| -> Vec<String> {
+            exposures
+                .keys()
+                .filter(|s| self.profiles.get(*s).is_some_and(matches))
+                .cloned()
+                .collect()
+        };
+
+        if let Some(profile) = self.profiles.get(symbol) {
+            if let (Some(limit), Some(sector)) = (self.config.max_sector_pct, &profile.sector) {
+                let members = members_where(&|p| p.sector.as_ref() == Some(sector));
+                buckets.push((ConcentrationBucket::Sector(sector.clone()), members, limit));
+            }
+            if let (Some(limit), Some(country)) = (self.config.max_country_pct, profile.country) {
+                let members = members_where(&|p| p.country == Some(country));
+                buckets.push((ConcentrationBucket::Country(country), members, limit));
+            }
+            if let (Some(limit), Some(currency)) =
+                (self.config.max_currency_pct, profile.currency())
+            {
+                let members = members_where(&|p| p.currency().as_ref() == Some(&currency));
+                buckets.push((ConcentrationBucket::Currency(currency), members, limit));
+            }
+        }
+
+        if let (Some(limit), Some(matrix)) = (self.config.max_cluster_pct, correlation) {
+            let cluster = correlation_clusters(matrix, self.config.cluster_min_correlation)
+                .into_iter()
+                .find(|cluster| cluster.iter().any(|s| s == symbol));
+            // 단일 종목 클러스터는 종목별 한도로 충분
+            if let Some(cluster) = cluster.filter(|c| c.len() > 1) {
+                buckets.push((
+                    ConcentrationBucket::Cluster(cluster.clone()),
+                    cluster,
+                    limit,
+                ));
+            }
+        }
+
+        buckets
+    }
+}
+
+/// 상관행렬에 평균 연결 계층적 군집화를 적용해 클러스터 목록 반환.
+///
+/// 클러스터 간 평균 상관계수가 가장 높은 쌍부터 병합하며,
+/// 가장 높은 평균 상관계수가 `min_correlation` 미만이 되면 중단합니다.
+pub fn correlation_clusters(matrix: &CorrelationMatrix, min_correlation: f64) -> Vec<Vec<String>> {
+    let mut clusters: Vec<Vec<usize>> = (0..matrix.symbols.len()).map(|i| vec![i]).collect();
+
+    let average = |a: &[usize], b: &[usize]| -> f64 {
+        let total: f64 = a
+            .iter()
+            .flat_map(|&i| b.iter().map(move |&j| matrix.matrix[i][j]))
+            .sum();
+        total / (a.len() * b.len()) as f64
+    };
+
+    loop {
+        let mut best: Option<(usize, usize, f64)> = None;
+        for i in 0..clusters.len() {
+            for j in (i + 1)..clusters.len() {
+                let corr = average(&clusters[i], &clusters[j]);
+                if corr >= min_correlation && best.map_or(true, |(_, _, b)| corr > b) {
+                    best = Some((i, j, corr));
+                }
+            }
+        }
+
+        let Some((i, j, _)) = best else {
+            break;
+        };
+        let merged = clusters.remove(j);
+        clusters[i].extend(merged);
+    }
+
+    clusters
+        .into_iter()
+        .map(|cluster| {
+            let mut symbols: Vec<String> = cluster
+                .into_iter()
+                .map(|i| matrix.symbols[i].clone())
+                .collect();
+            symbols.sort();
+            symbols
+        })
+        .collect()
+}
+
+fn pct(value: Decimal, balance: Decimal) -> f64 {
+    (value / balance * Decimal::ONE_HUNDRED)
+        .to_f64()
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn profile(sector: &str, country: Country) -> SymbolProfile {
+        SymbolProfile {
+            sector: Some(sector.to_string()),
+            country: Some(country),
+            currency: None,
+        }
+    }
+
+    fn exposures(entries: &[(&str, Decimal)]) -> BTreeMap<String, Decimal> {
+        entries.iter().map(|(s, v)| (s.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn test_correlation_clusters() {
+        let matrix = CorrelationMatrix {
+            symbols: vec!["SOXL".into(), "TQQQ".into(), "TLT".into(), "UPRO".into()],
+            matrix: vec![
+                vec![1.0, 0.9, -0.3, 0.8],
+                vec![0.9, 1.0, -0.2, 0.85],
+                vec![-0.3, -0.2, 1.0, -0.25],
+                vec![0.8, 0.85, -0.25, 1.0],
+            ],
+            period: 60,
+        };
+
+        let clusters = correlation_clusters(&matrix, 0.7);
+        assert_eq!(clusters.len(), 2);
+        assert!(clusters.contains(&vec!["SOXL".into(), "TQQQ".into(), "UPRO".into()]));
+        assert!(clusters.contains(&vec!["TLT".into()]));
+
+        // 기준이 높으면 가장 강한 쌍만 묶임
+        let clusters = correlation_clusters(&matrix, 0.88);
+        assert!(clusters.contains(&vec!["SOXL".into(), "TQQQ".into()]));
+        assert_eq!(clusters.len(), 3);
+    }
+
+    #[test]
+    fn test_sector_limit_downsizes_order() {
+        let mut checker = ConcentrationChecker::new(ConcentrationConfig {
+            max_sector_pct: Some(20.0),
+            ..Default::default()
+        });
+        checker.set_profile("005930", profile("반도체", Country::KR));
+        checker.set_profile("000660", profile("반도체", Country::KR));
+        checker.set_profile("035420", profile("인터넷", Country::KR));
+
+        // 반도체 15% 보유 상태에서 SK하이닉스 10% 추가 매수 → 25%
+        let before = exposures(&[("005930", dec!(15000)), ("035420", dec!(10000))]);
+        let order = OrderRequest::market_buy("000660".to_string(), dec!(100));
+        let mut after = before.clone();
+        after.insert("000660".into(), dec!(10000));
+
+        let breaches = checker.check(&order, dec!(100), &before, &after, dec!(100000), None);
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(
+            breaches[0].bucket,
+            ConcentrationBucket::Sector("반도체".into())
+        );
+        assert!((breaches[0].exposure_pct - 25.0).abs() < 1e-9);
+        // 여유 5,000 / 100 = 50주
+        assert_eq!(breaches[0].max_quantity, Some(dec!(50)));
+
+        // 다른 섹터 매수는 통과
+        let order = OrderRequest::market_buy("035420".to_string(), dec!(100));
+        let mut after = before.clone();
+        after.insert("035420".into(), dec!(20000));
+        assert!(checker
+            .check(&order, dec!(100), &before, &after, dec!(100000), None)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_country_and_currency_limits() {
+        let mut checker = ConcentrationChecker::new(ConcentrationConfig {
+            max_country_pct: Some(50.0),
+            max_currency_pct: Some(40.0),
+            ..Default::default()
+        });
+        checker.set_profile("AAPL", profile("Technology", Country::US));
+        checker.set_profile("XOM", profile("Energy", Country::US));
+
+        let before = exposures(&[("AAPL", dec!(35000))]);
+        let order = OrderRequest::market_buy("XOM".to_string(), dec!(100));
+        let after = exposures(&[("AAPL", dec!(35000)), ("XOM", dec!(10000))]);
+
+        let breaches = checker.check(&order, dec!(100), &before, &after, dec!(100000), None);
+        // 국가 45% (한도 50%) 통과, USD 45% (한도 40%) 초과
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(
+            breaches[0].bucket,
+            ConcentrationBucket::Currency("USD".into())
+        );
+        assert_eq!(breaches[0].max_quantity, Some(dec!(50)));
+
+        // 국가 여유 5,000은 남았지만 USD는 이미 한도를 넘어 제안 수량 없음
+        let before = exposures(&[("AAPL", dec!(45000))]);
+        let after = exposures(&[("AAPL", dec!(45000)), ("XOM", dec!(10000))]);
+        let breaches = checker.check(&order, dec!(100), &before, &after, dec!(100000), None);
+        assert_eq!(breaches.len(), 2);
+        assert_eq!(breaches[0].max_quantity, Some(dec!(50)));
+        assert_eq!(breaches[1].max_quantity, None);
+    }
+
+    #[test]
+    fn test_cluster_limit_and_reducing_order() {
+        let checker = ConcentrationChecker::new(ConcentrationConfig {
+            max_cluster_pct: Some(30.0),
+            ..Default::default()
+        });
+        let matrix = CorrelationMatrix {
+            symbols: vec!["SOXL".into(), "TQQQ".into()],
+            matrix: vec![vec![1.0, 0.92], vec![0.92, 1.0]],
+            period: 60,
+        };
+
+        let before = exposures(&[("SOXL", dec!(20000)), ("TQQQ", dec!(5000))]);
+        let order = OrderRequest::market_buy("TQQQ".to_string(), dec!(100));
+        let after = exposures(&[("SOXL", dec!(20000)), ("TQQQ", dec!(15000))]);
+        let breaches = checker.check(
+            &order,
+            dec!(100),
+            &before,
+            &after,
+            dec!(100000),
+            Some(&matrix),
+        );
+        assert_eq!(breaches.len(), 1);
+        assert!(matches!(
+            breaches[0].bucket,
+            ConcentrationBucket::Cluster(_)
+        ));
+        // 여유 30,000 - 20,000 - 5,000 = 5,000 → 50주
+        assert_eq!(breaches[0].max_quantity, Some(dec!(50)));
+
+        // 한도를 넘은 상태라도 노출을 줄이는 매도는 통과
+        let before = exposures(&[("SOXL", dec!(30000)), ("TQQQ", dec!(15000))]);
+        let order = OrderRequest::market_sell("TQQQ".to_string(), dec!(50));
+        let after = exposures(&[("SOXL", dec!(30000)), ("TQQQ", dec!(10000))]);
+        assert!(checker
+            .check(
+                &order,
+                dec!(100),
+                &before,
+                &after,
+                dec!(100000),
+                Some(&matrix)
+            )
+            .is_empty());
+    }
+}
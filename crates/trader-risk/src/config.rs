@@ -3,9 +3,14 @@
 //! 리스크 한도, 포지션 사이징, 보호 주문(손절/익절)을 위한
 //! 설정 구조체를 정의합니다.
 
+use crate::compliance::ComplianceConfig;
+use crate::concentration::ConcentrationConfig;
+use crate::drawdown::DrawdownConfig;
+use crate::var::PortfolioVarConfig;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use trader_core::PositionSizingConfig;
 
 /// 전역 리스크 관리 설정.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +60,26 @@ pub struct RiskConfig {
     /// 심볼별 리스크 설정 (전역 설정을 재정의함)
     #[serde(default)]
     pub symbol_configs: HashMap<String, SymbolRiskConfig>,
+
+    /// 포트폴리오 VaR/ES 한도 (기본값: 한도 없음)
+    #[serde(default)]
+    pub portfolio_var: PortfolioVarConfig,
+
+    /// 섹터/국가/통화/상관 클러스터 집중도 한도 (기본값: 한도 없음)
+    #[serde(default)]
+    pub concentration: ConcentrationConfig,
+
+    /// 드로다운 / 연속 손실 서킷 브레이커 (기본값: 한도 없음)
+    #[serde(default)]
+    pub drawdown: DrawdownConfig,
+
+    /// 주문 전 컴플라이언스 규칙 (기본값: 비활성화)
+    #[serde(default)]
+    pub compliance: ComplianceConfig,
+
+    /// 진입 포지션 사이징 방식 (기본값: 고정 비율 `max_position_pct`)
+    #[serde(default)]
+    pub position_sizing: PositionSizingConfig,
 }
 
 /// 심볼별 리스크 설정.
@@ -137,6 +162,11 @@ impl Default for RiskConfig {
             enable_trailing_stop: false,
             trailing_stop_pct: default_trailing_stop_pct(),
             symbol_configs: HashMap::new(),
+            portfolio_var: PortfolioVarConfig::default(),
+            concentration: ConcentrationConfig::default(),
+            drawdown: DrawdownConfig::default(),
+            compliance: ComplianceConfig::default(),
+            position_sizing: PositionSizingConfig::default(),
         }
     }
 }
@@ -173,6 +203,11 @@ impl RiskConfig {
             enable_trailing_stop: true,
             trailing_stop_pct: 1.0,
             symbol_configs: HashMap::new(),
+            portfolio_var: PortfolioVarConfig::default(),
+            concentration: ConcentrationConfig::default(),
+            drawdown: DrawdownConfig::default(),
+            compliance: ComplianceConfig::default(),
+            position_sizing: PositionSizingConfig::default(),
         }
     }
 
@@ -190,6 +225,11 @@ impl RiskConfig {
             enable_trailing_stop: false,
             trailing_stop_pct: 2.0,
             symbol_configs: HashMap::new(),
+            portfolio_var: PortfolioVarConfig::default(),
+            concentration: ConcentrationConfig::default(),
+            drawdown: DrawdownConfig::default(),
+            compliance: ComplianceConfig::default(),
+            position_sizing: PositionSizingConfig::default(),
         }
     }
 
@@ -271,6 +311,113 @@ impl RiskConfig {
             ));
         }
 
+        let var = &self.portfolio_var;
+        if var.confidence <= 0.5 || var.confidence >= 1.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "portfolio_var.confidence must be between 0.5 and 1".into(),
+            ));
+        }
+
+        if [var.max_var_pct, var.max_es_pct]
+            .iter()
+            .flatten()
+            .any(|pct| *pct <= 0.0 || *pct > 100.0)
+        {
+            return Err(ConfigValidationError::InvalidValue(
+                "portfolio_var limits must be between 0 and 100".into(),
+            ));
+        }
+
+        let concentration = &self.concentration;
+        if concentration
+            .limits()
+            .iter()
+            .flatten()
+            .any(|pct| *pct <= 0.0 || *pct > 100.0)
+        {
+            return Err(ConfigValidationError::InvalidValue(
+                "concentration limits must be between 0 and 100".into(),
+            ));
+        }
+
+        if concentration.cluster_min_correlation <= 0.0
+            || concentration.cluster_min_correlation > 1.0
+        {
+            return Err(ConfigValidationError::InvalidValue(
+                "concentration.cluster_min_correlation must be between 0 and 1".into(),
+            ));
+        }
+
+        let drawdown = &self.drawdown;
+        if [
+            drawdown.max_drawdown_pct,
+            drawdown.max_window_drawdown_pct,
+            drawdown.recovery_drawdown_pct,
+        ]
+        .iter()
+        .flatten()
+        .any(|pct| *pct <= 0.0 || *pct > 100.0)
+        {
+            return Err(ConfigValidationError::InvalidValue(
+                "drawdown limits must be between 0 and 100".into(),
+            ));
+        }
+
+        if drawdown.window_days == 0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "drawdown.window_days must be greater than 0".into(),
+            ));
+        }
+
+        if [
+            drawdown.max_consecutive_losses,
+            drawdown.max_strategy_consecutive_losses,
+        ]
+        .contains(&Some(0))
+        {
+            return Err(ConfigValidationError::InvalidValue(
+                "consecutive loss limits must be greater than 0".into(),
+            ));
+        }
+
+        if drawdown.size_multiplier <= 0.0 || drawdown.size_multiplier > 1.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "drawdown.size_multiplier must be between 0 and 1".into(),
+            ));
+        }
+
+        if self.compliance.krx_price_limit_pct <= 0.0 || self.compliance.krx_price_limit_pct > 100.0
+        {
+            return Err(ConfigValidationError::InvalidValue(
+                "compliance.krx_price_limit_pct must be between 0 and 100".into(),
+            ));
+        }
+
+        let sizing = &self.position_sizing;
+        if sizing.target_volatility_pct <= 0.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "position_sizing.target_volatility_pct must be greater than 0".into(),
+            ));
+        }
+
+        if sizing.lookback < 2 {
+            return Err(ConfigValidationError::InvalidValue(
+                "position_sizing.lookback must be at least 2".into(),
+            ));
+        }
+
+        if sizing.periods_per_year <= 0.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "position_sizing.periods_per_year must be greater than 0".into(),
+            ));
+        }
+
+        if sizing.max_weight <= 0.0 || sizing.max_weight > 1.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "position_sizing.max_weight must be between 0 and 1".into(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -363,6 +510,31 @@ mod tests {
         let mut invalid = RiskConfig::default();
         invalid.default_stop_loss_pct = -1.0;
         assert!(invalid.validate().is_err());
+
+        // 유효하지 않은 VaR 한도
+        let mut invalid = RiskConfig::default();
+        invalid.portfolio_var.max_var_pct = Some(0.0);
+        assert!(invalid.validate().is_err());
+
+        // 유효하지 않은 집중도 설정
+        let mut invalid = RiskConfig::default();
+        invalid.concentration.cluster_min_correlation = 1.5;
+        assert!(invalid.validate().is_err());
+
+        // 유효하지 않은 서킷 브레이커 설정
+        let mut invalid = RiskConfig::default();
+        invalid.drawdown.size_multiplier = 0.0;
+        assert!(invalid.validate().is_err());
+
+        // 유효하지 않은 컴플라이언스 설정
+        let mut invalid = RiskConfig::default();
+        invalid.compliance.krx_price_limit_pct = 0.0;
+        assert!(invalid.validate().is_err());
+
+        // 유효하지 않은 포지션 사이징 설정
+        let mut invalid = RiskConfig::default();
+        invalid.position_sizing.max_weight = 1.5;
+        assert!(invalid.validate().is_err());
     }
 
     #[test]
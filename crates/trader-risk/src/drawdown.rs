@@ -0,0 +1,668 @@
+//! 드로다운 / 연속 손실 서킷 브레이커.
+//!
+//! `DailyLossTracker`는 당일 손익만 봅니다. 이 모듈은 자산 곡선과 거래 결과를 추적해
+//! 다음 조건에서 신규 진입을 제한합니다:
+//!
+//! - **고점 대비 드로다운**: 최고 자산 대비 현재 자산 하락률
+//! - **기간 드로다운**: 최근 N일 자산 곡선 안의 고점 대비 하락률
+//! - **연속 손실**: 계좌 전체 / 전략별 연속 손실 거래 수
+//!
+//! 발동하면 설정에 따라 신규 진입 수량을 줄이거나(`ReduceSize`) 신규 진입을 막습니다(`Halt`).
+//! 드로다운 브레이커는 드로다운이 회복 기준 이하로 내려오면, 연속 손실 브레이커는 같은
+//! 범위에서 수익 거래가 나오면 자동으로 해제되며, 그 외에는 수동 리셋으로만 해제됩니다.
+//! 상태(`CircuitBreakerState`)는 직렬화할 수 있어 재시작 후 복원할 수 있습니다.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// 서킷 브레이커 발동 시 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerAction {
+    /// 신규 진입 수량 축소 (기본값)
+    #[default]
+    ReduceSize,
+    /// 신규 진입 중지
+    Halt,
+}
+
+/// 드로다운 / 연속 손실 서킷 브레이커 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawdownConfig {
+    /// 고점 대비 최대 드로다운 (%) - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_drawdown_pct: Option<f64>,
+
+    /// 최근 `window_days`일 안의 최대 드로다운 (%) - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_window_drawdown_pct: Option<f64>,
+
+    /// 기간 드로다운 계산 기간 (일) (기본값: 20)
+    #[serde(default = "default_window_days")]
+    pub window_days: u32,
+
+    /// 계좌 전체 최대 연속 손실 거래 수 - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_consecutive_losses: Option<u32>,
+
+    /// 전략별 최대 연속 손실 거래 수 - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_strategy_consecutive_losses: Option<u32>,
+
+    /// 발동 시 처리 방식 (기본값: reduce_size)
+    #[serde(default)]
+    pub action: BreakerAction,
+
+    /// `ReduceSize` 발동 중 신규 진입 수량 배율 (기본값: 0.5)
+    #[serde(default = "default_size_multiplier")]
+    pub size_multiplier: f64,
+
+    /// 드로다운이 이 값(%) 이하로 회복되면 드로다운 브레이커 자동 해제
+    /// (없으면 수동 리셋으로만 해제)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_drawdown_pct: Option<f64>,
+}
+
+fn default_window_days() -> u32 {
+    20
+}
+
+fn default_size_multiplier() -> f64 {
+    0.5
+}
+
+impl Default for DrawdownConfig {
+    fn default() -> Self {
+        Self {
+            max_drawdown_pct: None,
+            max_window_drawdown_pct: None,
+            window_days: default_window_days(),
+            max_consecutive_losses: None,
+            max_strategy_consecutive_losses: None,
+            action: BreakerAction::default(),
+            size_multiplier: default_size_multiplier(),
+            recovery_drawdown_pct: None,
+        }
+    }
+}
+
+impl DrawdownConfig {
+    /// 설정된 한도가 하나라도 있는지 확인.
+    pub fn has_limit(&self) -> bool {
+        self.max_drawdown_pct.is_some()
+            || self.max_window_drawdown_pct.is_some()
+            || self.max_consecutive_losses.is_some()
+            || self.max_strategy_consecutive_losses.is_some()
+    }
+}
+
+/// 서킷 브레이커 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerKind {
+    /// 고점 대비 드로다운
+    PeakDrawdown,
+    /// 기간 드로다운
+    WindowDrawdown,
+    /// 연속 손실
+    ConsecutiveLosses,
+}
+
+impl fmt::Display for BreakerKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BreakerKind::PeakDrawdown => write!(f, "peak drawdown"),
+            BreakerKind::WindowDrawdown => write!(f, "rolling drawdown"),
+            BreakerKind::ConsecutiveLosses => write!(f, "consecutive losses"),
+        }
+    }
+}
+
+/// 발동 중인 서킷 브레이커.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreakerTrip {
+    /// 브레이커 종류
+    pub kind: BreakerKind,
+    /// 대상 전략 (None이면 계좌 전체)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<String>,
+    /// 발동 시 처리 방식
+    pub action: BreakerAction,
+    /// 현재 값 (드로다운 % 또는 연속 손실 수)
+    pub value: f64,
+    /// 한도
+    pub limit: f64,
+    /// 발동 시각
+    pub tripped_at: DateTime<Utc>,
+}
+
+impl BreakerTrip {
+    /// 사람이 읽을 수 있는 발동 사유.
+    pub fn message(&self) -> String {
+        let scope = match &self.strategy_id {
+            Some(id) => format!(" for strategy {}", id),
+            None => String::new(),
+        };
+        match self.kind {
+            BreakerKind::ConsecutiveLosses => format!(
+                "Circuit breaker: {} consecutive losses{} (limit {})",
+                self.value, scope, self.limit
+            ),
+            _ => format!(
+                "Circuit breaker: {} {:.2}%{} exceeds limit {:.2}%",
+                self.kind, self.value, scope, self.limit
+            ),
+        }
+    }
+}
+
+/// 일별 자산 표본.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquitySample {
+    /// 날짜 (UTC)
+    pub date: NaiveDate,
+    /// 총 자산
+    pub equity: Decimal,
+}
+
+/// 서킷 브레이커 상태 (재시작 시 복원용).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreakerState {
+    /// 최고 자산 (마지막 리셋 이후)
+    #[serde(default)]
+    pub peak_equity: Option<Decimal>,
+    /// 기간 드로다운 계산용 일별 자산 (날짜 오름차순)
+    #[serde(default)]
+    pub equity_window: Vec<EquitySample>,
+    /// 계좌 전체 연속 손실 거래 수
+    #[serde(default)]
+    pub account_loss_streak: u32,
+    /// 전략별 연속 손실 거래 수
+    #[serde(default)]
+    pub strategy_loss_streaks: BTreeMap<String, u32>,
+    /// 발동 중인 브레이커
+    #[serde(default)]
+    pub trips: Vec<BreakerTrip>,
+    /// 관리자 재정의로 발동 중인 브레이커를 무시하는지 여부
+    #[serde(default)]
+    pub overridden: bool,
+    /// 마지막 수동 리셋 날짜 (이전 날짜의 자산 표본은 무시)
+    #[serde(default)]
+    pub reset_on: Option<NaiveDate>,
+    /// 마지막 변경 시각
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// 신규 진입 주문에 대한 서킷 브레이커 판단.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntryDecision {
+    /// 제한 없음
+    Allow,
+    /// 수량을 배율만큼 줄여 허용
+    Reduce {
+        /// 수량 배율
+        multiplier: f64,
+        /// 발동 사유
+        reason: String,
+    },
+    /// 신규 진입 중지
+    Halt {
+        /// 발동 사유
+        reason: String,
+    },
+}
+
+/// 드로다운 / 연속 손실 서킷 브레이커.
+#[derive(Debug, Clone)]
+pub struct DrawdownCircuitBreaker {
+    config: DrawdownConfig,
+    state: CircuitBreakerState,
+}
+
+impl DrawdownCircuitBreaker {
+    /// 새 서킷 브레이커 생성.
+    pub fn new(config: DrawdownConfig) -> Self {
+        Self {
+            config,
+            state: CircuitBreakerState::default(),
+        }
+    }
+
+    /// 설정 참조 조회.
+    pub fn config(&self) -> &DrawdownConfig {
+        &self.config
+    }
+
+    /// 현재 상태 조회.
+    pub fn state(&self) -> &CircuitBreakerState {
+        &self.state
+    }
+
+    /// 저장된 상태 복원.
+    pub fn restore(&mut self, state: CircuitBreakerState) {
+        self.state = state;
+        self.prune_window();
+    }
+
+    /// 발동 중인 브레이커.
+    pub fn active_trips(&self) -> &[BreakerTrip] {
+        &self.state.trips
+    }
+
+    /// 가장 최근 자산.
+    pub fn latest_equity(&self) -> Option<Decimal> {
+        self.state.equity_window.last().map(|s| s.equity)
+    }
+
+    /// 고점 대비 현재 드로다운 (%).
+    pub fn drawdown_pct(&self) -> f64 {
+        match (self.state.peak_equity, self.latest_equity()) {
+            (Some(peak), Some(latest)) => drawdown_pct(peak, latest),
+            _ => 0.0,
+        }
+    }
+
+    /// 최근 `window_days`일 안의 고점 대비 현재 드로다운 (%).
+    pub fn window_drawdown_pct(&self) -> f64 {
+        let peak = self.state.equity_window.iter().map(|s| s.equity).max();
+        match (peak, self.latest_equity()) {
+            (Some(peak), Some(latest)) => drawdown_pct(peak, latest),
+            _ => 0.0,
+        }
+    }
+
+    /// 자산 갱신 후 드로다운 브레이커 확인.
+    ///
+    /// 같은 날짜의 표본은 덮어쓰고, 과거 날짜는 순서에 맞게 끼워 넣습니다.
+    /// 마지막 리셋 이전 날짜의 표본은 무시합니다.
+    ///
+    /// # Returns
+    /// 이번 갱신으로 새로 발동한 브레이커
+    pub fn update_equity(&mut self, at: DateTime<Utc>, equity: Decimal) -> Vec<BreakerTrip> {
+        if equity <= Decimal::ZERO {
+            return Vec::new();
+        }
+
+        let date = at.date_naive();
+        if self.state.reset_on.is_some_and(|reset_on| date < reset_on) {
+            return Vec::new();
+        }
+
+        let window = &mut self.state.equity_window;
+        match window.binary_search_by(|s| s.date.cmp(&date)) {
+            Ok(i) => window[i].equity = equity,
+            Err(i) => window.insert(i, EquitySample { date, equity }),
+        }
+        self.prune_window();
+
+        self.state.peak_equity = Some(self.state.peak_equity.map_or(equity, |p| p.max(equity)));
+        self.state.updated_at = Some(at);
+
+        let mut tripped = Vec::new();
+        let checks = [
+            (
+                BreakerKind::PeakDrawdown,
+                self.config.max_drawdown_pct,
+                self.drawdown_pct(),
+            ),
+            (
+                BreakerKind::WindowDrawdown,
+                self.config.max_window_drawdown_pct,
+                self.window_drawdown_pct(),
+            ),
+        ];
+        for (kind, limit, value) in checks {
+            let Some(limit) = limit else { continue };
+            if value > limit {
+                tripped.extend(self.trip(kind, None, value, limit, at));
+            } else if self
+                .config
+                .recovery_drawdown_pct
+                .is_some_and(|recovery| value <= recovery)
+            {
+                self.clear(kind, None);
+            }
+        }
+        tripped
+    }
+
+    /// 청산된 거래의 실현 손익을 기록하고 연속 손실 브레이커 확인.
+    ///
+    /// 수익 거래는 해당 범위(계좌 전체, 전략)의 연속 손실을 0으로 되돌리고
+    /// 연속 손실 브레이커를 해제합니다.
+    ///
+    /// # Returns
+    /// 이번 거래로 새로 발동한 브레이커
+    pub fn record_trade_result(
+        &mut self,
+        strategy_id: Option<&str>,
+        pnl: Decimal,
+    ) -> Vec<BreakerTrip> {
+        let now = Utc::now();
+        self.state.updated_at = Some(now);
+
+        if pnl > Decimal::ZERO {
+            self.state.account_loss_streak = 0;
+            self.clear(BreakerKind::ConsecutiveLosses, None);
+            if let Some(id) = strategy_id {
+                self.state.strategy_loss_streaks.remove(id);
+                self.clear(BreakerKind::ConsecutiveLosses, Some(id));
+            }
+            return Vec::new();
+        }
+        if pnl == Decimal::ZERO {
+            return Vec::new();
+        }
+
+        let mut tripped = Vec::new();
+
+        self.state.account_loss_streak += 1;
+        let streak = self.state.account_loss_streak;
+        if let Some(limit) = self.config.max_consecutive_losses {
+            if streak >= limit {
+                tripped.extend(self.trip(
+                    BreakerKind::ConsecutiveLosses,
+                    None,
+                    streak as f64,
+                    limit as f64,
+                    now,
+                ));
+            }
+        }
+
+        if let Some(id) = strategy_id {
+            let streak = self
+                .state
+                .strategy_loss_streaks
+                .entry(id.to_string())
+                .or_insert(0);
+            *streak += 1;
+            let streak = *streak;
+            if let Some(limit) = self.config.max_strategy_consecutive_losses {
+                if streak >= limit {
+                    tripped.extend(self.trip(
+                        BreakerKind::ConsecutiveLosses,
+                        Some(id),
+                        streak as f64,
+                        limit as f64,
+                        now,
+                    ));
+                }
+            }
+        }
+
+        tripped
+    }
+
+    /// 전략의 신규 진입 주문에 적용할 제한.
+    ///
+    /// 계좌 전체 브레이커와 해당 전략의 브레이커를 함께 봅니다.
+    /// 하나라도 `Halt`이면 중지, 그 외 발동 중인 브레이커가 있으면 수량을 줄입니다.
+    pub fn entry_decision(&self, strategy_id: Option<&str>) -> EntryDecision {
+        if self.state.overridden {
+            return EntryDecision::Allow;
+        }
+
+        let relevant: Vec<&BreakerTrip> = self
+            .state
+            .trips
+            .iter()
+            .filter(|t| t.strategy_id.is_none() || t.strategy_id.as_deref() == strategy_id)
+            .collect();
+        if relevant.is_empty() {
+            return EntryDecision::Allow;
+        }
+
+        let reason = relevant
+            .iter()
+            .map(|t| t.message())
+            .collect::<Vec<_>>()
+            .join("; ");
+        if relevant.iter().any(|t| t.action == BreakerAction::Halt) {
+            EntryDecision::Halt { reason }
+        } else {
+            EntryDecision::Reduce {
+                multiplier: self.config.size_multiplier,
+                reason,
+            }
+        }
+    }
+
+    /// 모든 브레이커와 연속 손실을 초기화 (관리자 기능).
+    ///
+    /// 드로다운은 현재 자산을 새 고점으로 삼아 다시 측정하며, 이후 리셋 이전 날짜의
+    /// 자산 표본은 반영하지 않습니다.
+    pub fn reset(&mut self) {
+        let now = Utc::now();
+        let latest = self.state.equity_window.last().cloned();
+        self.state = CircuitBreakerState {
+            peak_equity: latest.as_ref().map(|s| s.equity),
+            reset_on: Some(latest.as_ref().map_or(now.date_naive(), |s| s.date)),
+            equity_window: latest.into_iter().collect(),
+            updated_at: Some(now),
+            ..Default::default()
+        };
+    }
+
+    /// 발동 중인 브레이커 재정의 (관리자 기능).
+    ///
+    /// `allow_trading`이 true이면 현재 발동 중인 브레이커를 무시하고 거래를 허용합니다.
+    /// 이후 새 브레이커가 발동하면 재정의는 해제됩니다.
+    pub fn override_halt(&mut self, allow_trading: bool) {
+        self.state.overridden = allow_trading;
+        self.state.updated_at = Some(Utc::now());
+    }
+
+    /// 브레이커 발동 (이미 발동 중이면 값만 갱신).
+    fn trip(
+        &mut self,
+        kind: BreakerKind,
+        strategy_id: Option<&str>,
+        value: f64,
+        limit: f64,
+        at: DateTime<Utc>,
+    ) -> Option<BreakerTrip> {
+        if let Some(existing) = self
+            .state
+            .trips
+            .iter_mut()
+            .find(|t| t.kind == kind && t.strategy_id.as_deref() == strategy_id)
+        {
+            existing.value = value;
+            return None;
+        }
+
+        let trip = BreakerTrip {
+            kind,
+            strategy_id: strategy_id.map(str::to_string),
+            action: self.config.action,
+            value,
+            limit,
+            tripped_at: at,
+        };
+        self.state.trips.push(trip.clone());
+        self.state.overridden = false;
+        Some(trip)
+    }
+
+    /// 브레이커 해제.
+    fn clear(&mut self, kind: BreakerKind, strategy_id: Option<&str>) {
+        self.state
+            .trips
+            .retain(|t| !(t.kind == kind && t.strategy_id.as_deref() == strategy_id));
+    }
+
+    /// 기간 밖의 자산 표본 제거.
+    fn prune_window(&mut self) {
+        let Some(latest) = self.state.equity_window.last().map(|s| s.date) else {
+            return;
+        };
+        let cutoff = latest - Duration::days(i64::from(self.config.window_days.max(1)));
+        self.state.equity_window.retain(|s| s.date > cutoff);
+    }
+}
+
+/// 고점 대비 하락률 (%).
+fn drawdown_pct(peak: Decimal, equity: Decimal) -> f64 {
+    if peak <= Decimal::ZERO || equity >= peak {
+        return 0.0;
+    }
+    ((peak - equity) / peak * Decimal::from(100))
+        .to_f64()
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, d, 15, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_peak_drawdown_halts_until_recovery() {
+        let mut breaker = DrawdownCircuitBreaker::new(DrawdownConfig {
+            max_drawdown_pct: Some(10.0),
+            action: BreakerAction::Halt,
+            recovery_drawdown_pct: Some(5.0),
+            ..Default::default()
+        });
+
+        assert!(breaker.update_equity(day(1), dec!(10000)).is_empty());
+        assert!(breaker.update_equity(day(2), dec!(9500)).is_empty());
+        assert_eq!(breaker.entry_decision(None), EntryDecision::Allow);
+
+        let tripped = breaker.update_equity(day(3), dec!(8800));
+        assert_eq!(tripped.len(), 1);
+        assert_eq!(tripped[0].kind, BreakerKind::PeakDrawdown);
+        assert!((breaker.drawdown_pct() - 12.0).abs() < 1e-9);
+        assert!(matches!(
+            breaker.entry_decision(Some("s1")),
+            EntryDecision::Halt { .. }
+        ));
+
+        // 회복 기준(5%) 위에서는 유지
+        assert!(breaker.update_equity(day(4), dec!(9400)).is_empty());
+        assert_eq!(breaker.active_trips().len(), 1);
+
+        // 회복 기준 이하로 돌아오면 해제
+        breaker.update_equity(day(5), dec!(9600));
+        assert!(breaker.active_trips().is_empty());
+        assert_eq!(breaker.entry_decision(None), EntryDecision::Allow);
+    }
+
+    #[test]
+    fn test_window_drawdown_ignores_old_peak() {
+        let mut breaker = DrawdownCircuitBreaker::new(DrawdownConfig {
+            max_window_drawdown_pct: Some(10.0),
+            window_days: 3,
+            ..Default::default()
+        });
+
+        breaker.update_equity(day(1), dec!(20000));
+        breaker.update_equity(day(5), dec!(10000));
+        breaker.update_equity(day(6), dec!(9500));
+
+        // 3일 전 고점(20000)은 기간 밖
+        assert_eq!(breaker.state().equity_window.len(), 2);
+        assert!((breaker.window_drawdown_pct() - 5.0).abs() < 1e-9);
+        assert!(breaker.drawdown_pct() > 50.0);
+        assert!(breaker.active_trips().is_empty());
+
+        let tripped = breaker.update_equity(day(7), dec!(8900));
+        assert_eq!(tripped[0].kind, BreakerKind::WindowDrawdown);
+        match breaker.entry_decision(None) {
+            EntryDecision::Reduce { multiplier, reason } => {
+                assert_eq!(multiplier, 0.5);
+                assert!(reason.contains("rolling drawdown"));
+            }
+            other => panic!("expected reduce, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_consecutive_losses_per_strategy() {
+        let mut breaker = DrawdownCircuitBreaker::new(DrawdownConfig {
+            max_consecutive_losses: Some(4),
+            max_strategy_consecutive_losses: Some(2),
+            action: BreakerAction::Halt,
+            ..Default::default()
+        });
+
+        assert!(breaker.record_trade_result(Some("a"), dec!(-10)).is_empty());
+        assert!(breaker.record_trade_result(Some("b"), dec!(-10)).is_empty());
+        let tripped = breaker.record_trade_result(Some("a"), dec!(-10));
+        assert_eq!(tripped.len(), 1);
+        assert_eq!(tripped[0].strategy_id.as_deref(), Some("a"));
+
+        // 전략 a만 중지
+        assert!(matches!(
+            breaker.entry_decision(Some("a")),
+            EntryDecision::Halt { .. }
+        ));
+        assert_eq!(breaker.entry_decision(Some("b")), EntryDecision::Allow);
+
+        // 계좌 전체 4연속 손실
+        let tripped = breaker.record_trade_result(Some("b"), dec!(-10));
+        assert!(tripped.iter().any(|t| t.strategy_id.is_none()));
+        assert!(matches!(
+            breaker.entry_decision(Some("c")),
+            EntryDecision::Halt { .. }
+        ));
+
+        // 전략 a의 수익 거래는 a와 계좌 전체 연속 손실을 해제
+        breaker.record_trade_result(Some("a"), dec!(25));
+        assert_eq!(breaker.state().account_loss_streak, 0);
+        assert_eq!(breaker.entry_decision(Some("a")), EntryDecision::Allow);
+        assert!(matches!(
+            breaker.entry_decision(Some("b")),
+            EntryDecision::Halt { .. }
+        ));
+    }
+
+    #[test]
+    fn test_override_reset_and_restore() {
+        let mut breaker = DrawdownCircuitBreaker::new(DrawdownConfig {
+            max_drawdown_pct: Some(10.0),
+            action: BreakerAction::Halt,
+            ..Default::default()
+        });
+        breaker.update_equity(day(1), dec!(10000));
+        breaker.update_equity(day(2), dec!(8000));
+
+        breaker.override_halt(true);
+        assert_eq!(breaker.entry_decision(None), EntryDecision::Allow);
+
+        // 상태 직렬화 후 복원
+        let json = serde_json::to_string(breaker.state()).unwrap();
+        let mut restored = DrawdownCircuitBreaker::new(breaker.config().clone());
+        restored.restore(serde_json::from_str(&json).unwrap());
+        assert_eq!(restored.state(), breaker.state());
+        assert_eq!(restored.entry_decision(None), EntryDecision::Allow);
+
+        // 재정의 해제 시 다시 중지
+        restored.override_halt(false);
+        assert!(matches!(
+            restored.entry_decision(None),
+            EntryDecision::Halt { .. }
+        ));
+
+        // 리셋하면 현재 자산이 새 고점
+        restored.reset();
+        assert!(restored.active_trips().is_empty());
+        assert_eq!(restored.state().peak_equity, Some(dec!(8000)));
+        assert!(restored.update_equity(day(3), dec!(7500)).is_empty());
+
+        // 리셋 이전 표본은 다시 공급되어도 무시
+        restored.update_equity(day(1), dec!(10000));
+        assert_eq!(restored.state().peak_equity, Some(dec!(8000)));
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! 이 crate는 다음 기능을 제공합니다:
 //! - 리스크 한도에 대한 주문 검증
-//! - 포지션 사이징
+//! - 포지션 사이징 (고정 비율, Kelly, 변동성 타깃, 역변동성, 리스크 패리티)
 //! - Stop-loss/Take-profit 관리
 //! - 일일 손실 한도
 //! - 변동성 필터
+//! - 포트폴리오 VaR / Expected Shortfall 한도
+//! - 섹터 / 국가 / 통화 / 상관 클러스터 집중도 한도
+//! - 드로다운 / 연속 손실 서킷 브레이커
+//! - 주문 전 컴플라이언스 규칙 (가격제한폭, 호가 단위, 거래소 필터, PDT, 공매도, 제한 종목)
 //!
 //! # 예제
 //!
@@ -22,15 +26,33 @@
 //! }
 //! ```
 
+pub mod compliance;
+pub mod concentration;
 pub mod config;
+pub mod drawdown;
 pub mod limits;
 pub mod manager;
 pub mod position_sizing;
 pub mod stop_loss;
 pub mod trailing_stop;
+pub mod var;
 
 // 주요 타입 재내보내기
+pub use compliance::{
+    count_day_trades, floor_to_step, infer_market, ComplianceChain, ComplianceCode,
+    ComplianceConfig, ComplianceContext, ComplianceResult, ComplianceRule, ComplianceViolation,
+    PatternDayTraderRule, PriceLimitRule, RestrictedListRule, RuleOutcome, ShortSaleRule,
+    SymbolFilter, SymbolFilterRule, TickSizeRule,
+};
+pub use concentration::{
+    correlation_clusters, ConcentrationBreach, ConcentrationBucket, ConcentrationChecker,
+    ConcentrationConfig, SymbolProfile,
+};
 pub use config::{ConfigValidationError, RiskConfig, SymbolRiskConfig};
+pub use drawdown::{
+    BreakerAction, BreakerKind, BreakerTrip, CircuitBreakerState, DrawdownCircuitBreaker,
+    DrawdownConfig, EntryDecision, EquitySample,
+};
 pub use limits::{DailyLimitStatus, DailyLossTracker, PnLRecord, RiskLimits};
 pub use manager::{RiskManager, RiskValidation};
 pub use position_sizing::{PositionSizer, SizingValidation};
@@ -38,3 +60,4 @@ pub use stop_loss::{StopOrder, StopOrderGenerator, StopType, TrailingStopState};
 pub use trailing_stop::{
     EnhancedTrailingStop, ProfitLevel, StepTrailingStopBuilder, TrailingStopMode, TrailingStopStats,
 };
+pub use var::{PortfolioRisk, PortfolioVarConfig, VarBreachAction, VarCalculator, VarMethod};
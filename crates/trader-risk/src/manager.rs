@@ -5,14 +5,26 @@
 //! - 일일 손실 한도 추적
 //! - Stop-loss/Take-profit 주문 생성
 //! - 변동성 필터링
+//! - 포트폴리오 VaR/ES 한도 (한도 초과 주문 거부 또는 축소)
+//! - 섹터/국가/통화/상관 클러스터 집중도 한도
+//! - 드로다운/연속 손실 서킷 브레이커 (신규 진입 축소 또는 중지)
 
+use crate::compliance::{floor_to_step, ComplianceChain, ComplianceContext, ComplianceResult};
+use crate::concentration::{ConcentrationBreach, ConcentrationChecker, SymbolProfile};
 use crate::config::RiskConfig;
+use crate::drawdown::{BreakerTrip, CircuitBreakerState, DrawdownCircuitBreaker, EntryDecision};
 use crate::limits::DailyLossTracker;
 use crate::position_sizing::PositionSizer;
 use crate::stop_loss::{StopOrder, StopOrderGenerator, TrailingStopState};
+use crate::var::{PortfolioRisk, VarBreachAction, VarCalculator};
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use std::collections::HashMap;
-use trader_core::{OrderRequest, Position, TraderResult};
+use trader_core::{Kline, OrderRequest, Position, TraderResult};
+
+/// VaR 한도 안의 주문 수량을 찾을 때의 이분 탐색 횟수.
+const VAR_DOWNSIZE_ITERATIONS: usize = 20;
 
 /// 리스크 검증 결과.
 #[derive(Debug, Clone)]
@@ -84,6 +96,14 @@ pub struct RiskManager {
     volatility_data: HashMap<String, VolatilityData>,
     /// 활성 Trailing Stop (position_id -> state)
     trailing_stops: HashMap<String, TrailingStopState>,
+    /// 포트폴리오 VaR/ES 계산기 (종목별 가격 이력 보관)
+    var_calculator: VarCalculator,
+    /// 섹터/국가/통화/상관 클러스터 집중도 검사기 (종목 프로필 보관)
+    concentration: ConcentrationChecker,
+    /// 드로다운/연속 손실 서킷 브레이커
+    circuit_breaker: DrawdownCircuitBreaker,
+    /// 주문 전 컴플라이언스 규칙 체인
+    compliance: ComplianceChain,
 }
 
 impl RiskManager {
@@ -92,6 +112,10 @@ impl RiskManager {
         let position_sizer = PositionSizer::new(config.clone());
         let daily_tracker = DailyLossTracker::from_config(&config, starting_balance);
         let stop_generator = StopOrderGenerator::new(config.clone());
+        let var_calculator = VarCalculator::new(config.portfolio_var.clone());
+        let concentration = ConcentrationChecker::new(config.concentration.clone());
+        let circuit_breaker = DrawdownCircuitBreaker::new(config.drawdown.clone());
+        let compliance = ComplianceChain::from_config(&config.compliance);
 
         Self {
             config,
@@ -101,6 +125,10 @@ impl RiskManager {
             balance: starting_balance,
             volatility_data: HashMap::new(),
             trailing_stops: HashMap::new(),
+            var_calculator,
+            concentration,
+            circuit_breaker,
+            compliance,
         }
     }
 
@@ -152,7 +180,14 @@ impl RiskManager {
             ));
         }
 
-        // Check 2: Symbol enabled
+        // Check 2: Drawdown / consecutive-loss circuit breakers (new entries only)
+        let reduced_order = match self.check_circuit_breakers(order, positions, &mut warnings) {
+            Ok(reduced_order) => reduced_order,
+            Err(reason) => return Ok(RiskValidation::invalid(reason)),
+        };
+        let order = reduced_order.as_ref().unwrap_or(order);
+
+        // Check 3: Symbol enabled
         if !self.config.is_symbol_enabled(&symbol) {
             return Ok(RiskValidation::invalid(format!(
                 "Trading disabled for symbol: {}",
@@ -160,7 +195,7 @@ impl RiskManager {
             )));
         }
 
-        // Check 3: Volatility filter
+        // Check 4: Volatility filter
         if let Some(volatility) = self.volatility_data.get(&symbol) {
             if volatility.current_volatility > self.config.volatility_threshold {
                 return Ok(RiskValidation::invalid(format!(
@@ -178,7 +213,7 @@ impl RiskManager {
             }
         }
 
-        // Check 4: Position sizing limits
+        // Check 5: Position sizing limits
         let sizing_result =
             self.position_sizer
                 .validate_order(order, positions, self.balance, current_price);
@@ -204,7 +239,41 @@ impl RiskManager {
             return Ok(validation);
         }
 
-        // Check 5: Daily limit status warning
+        // Check 6: Sector/country/currency/correlation-cluster concentration
+        let breaches = self.concentration_breaches(order, positions, current_price);
+        if let Some((first, rest)) = breaches.split_first() {
+            let mut validation = RiskValidation::invalid(first.message());
+            validation
+                .messages
+                .extend(rest.iter().map(ConcentrationBreach::message));
+
+            // Suggest a size that fits every breached bucket
+            let suggested_qty = breaches
+                .iter()
+                .map(|b| b.max_quantity)
+                .collect::<Option<Vec<_>>>()
+                .and_then(|quantities| quantities.into_iter().min());
+            if let Some(suggested_qty) = suggested_qty {
+                let mut adjusted_order = order.clone();
+                adjusted_order.quantity = suggested_qty;
+                validation = validation.with_modified_order(adjusted_order);
+                validation
+                    .messages
+                    .push(format!("Suggested adjusted quantity: {}", suggested_qty));
+            }
+
+            return Ok(validation);
+        }
+
+        // Check 7: Portfolio VaR/ES limit (keep warnings collected so far)
+        if let Some(mut validation) =
+            self.check_portfolio_var(order, positions, current_price, &mut warnings)
+        {
+            validation.messages.extend(warnings);
+            return Ok(validation);
+        }
+
+        // Check 8: Daily limit status warning
         let daily_status = self.daily_tracker.get_status();
         if let Some(warning) = daily_status.warning {
             warnings.push(warning);
@@ -215,10 +284,158 @@ impl RiskManager {
         for warning in warnings {
             result = result.with_warning(warning);
         }
+        if let Some(reduced_order) = reduced_order {
+            result = result.with_modified_order(reduced_order);
+        }
 
         Ok(result)
     }
 
+    /// 서킷 브레이커 발동 중이면 신규 진입을 막거나 수량을 줄임.
+    ///
+    /// 기존 포지션을 줄이는 주문(청산)은 보유 수량까지만 제한하지 않으며,
+    /// 보유 수량을 넘어 반대 포지션을 여는 부분은 신규 진입으로 취급합니다.
+    /// 축소한 수량은 종목의 수량 단위로 내림합니다.
+    ///
+    /// # Returns
+    /// 수량을 줄인 주문 (줄이지 않았으면 None), 진입을 막으면 거부 사유
+    fn check_circuit_breakers(
+        &self,
+        order: &OrderRequest,
+        positions: &[Position],
+        warnings: &mut Vec<String>,
+    ) -> Result<Option<OrderRequest>, String> {
+        // 청산 가능한 보유 수량 (반대 방향 포지션)
+        let closing: Decimal = positions
+            .iter()
+            .filter(|p| p.ticker == order.ticker && p.side != order.side)
+            .map(|p| p.quantity.max(Decimal::ZERO))
+            .sum::<Decimal>()
+            .min(order.quantity);
+        if closing == order.quantity {
+            return Ok(None);
+        }
+        let opening = order.quantity - closing;
+
+        let quantity = match self
+            .circuit_breaker
+            .entry_decision(order.strategy_id.as_deref())
+        {
+            EntryDecision::Allow => return Ok(None),
+            EntryDecision::Halt { reason } => {
+                if closing.is_zero() {
+                    return Err(format!("Trading halted: {}", reason));
+                }
+                warnings.push(format!(
+                    "Trading halted: {}; order limited to closing {}",
+                    reason, closing
+                ));
+                closing
+            }
+            EntryDecision::Reduce { multiplier, reason } => {
+                let reduced = floor_to_step(
+                    opening * Decimal::from_f64(multiplier).unwrap_or_default(),
+                    self.lot_step(&order.ticker),
+                );
+                let quantity = closing + reduced;
+                if quantity <= Decimal::ZERO {
+                    return Err(reason);
+                }
+                warnings.push(format!("{}; order size reduced to {}", reason, quantity));
+                quantity
+            }
+        };
+
+        let mut limited = order.clone();
+        limited.quantity = quantity;
+        Ok(Some(limited))
+    }
+
+    /// 주문 후 포트폴리오 VaR/ES가 한도를 넘는지 검사.
+    ///
+    /// 한도를 넘으면 설정에 따라 거부하거나, 한도 안으로 축소한 주문을 담아 허용합니다.
+    /// 리스크를 줄이는 주문(청산, 헤지)은 한도를 넘더라도 허용합니다.
+    fn check_portfolio_var(
+        &self,
+        order: &OrderRequest,
+        positions: &[Position],
+        current_price: Decimal,
+        warnings: &mut Vec<String>,
+    ) -> Option<RiskValidation> {
+        let config = self.var_calculator.config();
+        if !config.has_limit() {
+            return None;
+        }
+
+        let Some(after) = self.pre_trade_risk(order, positions, current_price) else {
+            warnings.push(format!(
+                "Portfolio VaR unavailable: no price history for {}",
+                order.ticker
+            ));
+            return None;
+        };
+        if !after.missing_symbols.is_empty() {
+            warnings.push(format!(
+                "Portfolio VaR excludes symbols without price history: {}",
+                after.missing_symbols.join(", ")
+            ));
+        }
+        let breach = after.breach(config)?;
+
+        // 리스크를 줄이는 주문은 허용
+        if let Some(before) = self.portfolio_risk(positions) {
+            if after.var <= before.var && after.expected_shortfall <= before.expected_shortfall {
+                warnings.push(format!("{} (order reduces portfolio risk)", breach));
+                return None;
+            }
+        }
+
+        if config.breach_action == VarBreachAction::Downsize {
+            if let Some(quantity) = self.max_quantity_within_var(order, positions, current_price) {
+                let mut adjusted = order.clone();
+                adjusted.quantity = quantity;
+                return Some(
+                    RiskValidation::valid()
+                        .with_warning(format!("{}; order downsized to {}", breach, quantity))
+                        .with_modified_order(adjusted),
+                );
+            }
+        }
+
+        Some(RiskValidation::invalid(breach))
+    }
+
+    /// VaR/ES 한도를 넘지 않는 최대 주문 수량 (이분 탐색, 종목의 수량 단위로 내림).
+    fn max_quantity_within_var(
+        &self,
+        order: &OrderRequest,
+        positions: &[Position],
+        current_price: Decimal,
+    ) -> Option<Decimal> {
+        let config = self.var_calculator.config();
+        let within = |quantity: Decimal| {
+            let mut candidate = order.clone();
+            candidate.quantity = quantity;
+            self.pre_trade_risk(&candidate, positions, current_price)
+                .is_some_and(|risk| risk.breach(config).is_none())
+        };
+        let scaled =
+            |fraction: f64| order.quantity * Decimal::from_f64(fraction).unwrap_or_default();
+
+        let (mut low, mut high) = (0.0_f64, 1.0_f64);
+        for _ in 0..VAR_DOWNSIZE_ITERATIONS {
+            let mid = (low + high) / 2.0;
+            if within(scaled(mid)) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        let quantity = floor_to_step(scaled(low), self.lot_step(&order.ticker));
+        (quantity > Decimal::ZERO && within(quantity)).then_some(quantity)
+    }
+
     /// 거래 가능 여부 빠른 확인.
     pub fn can_trade(&mut self) -> bool {
         self.daily_tracker.can_trade()
@@ -255,6 +472,77 @@ impl RiskManager {
         self.daily_tracker.force_reset();
     }
 
+    // ==================== Drawdown Circuit Breakers ====================
+
+    /// 계좌 총 자산 갱신 (드로다운 브레이커 확인).
+    ///
+    /// # Returns
+    /// 새로 발동한 브레이커
+    pub fn update_equity(&mut self, at: DateTime<Utc>, equity: Decimal) -> Vec<BreakerTrip> {
+        self.circuit_breaker.update_equity(at, equity)
+    }
+
+    /// 청산된 거래의 실현 손익 기록 (연속 손실 브레이커 확인).
+    ///
+    /// # Returns
+    /// 새로 발동한 브레이커
+    pub fn record_trade_result(
+        &mut self,
+        strategy_id: Option<&str>,
+        pnl: Decimal,
+    ) -> Vec<BreakerTrip> {
+        self.circuit_breaker.record_trade_result(strategy_id, pnl)
+    }
+
+    /// 서킷 브레이커 조회.
+    pub fn circuit_breaker(&self) -> &DrawdownCircuitBreaker {
+        &self.circuit_breaker
+    }
+
+    /// 저장된 서킷 브레이커 상태 복원.
+    pub fn restore_circuit_breaker(&mut self, state: CircuitBreakerState) {
+        self.circuit_breaker.restore(state);
+    }
+
+    /// 서킷 브레이커 강제 리셋 (관리자 기능).
+    pub fn reset_circuit_breakers(&mut self) {
+        self.circuit_breaker.reset();
+    }
+
+    /// 서킷 브레이커 재정의 (관리자 기능).
+    pub fn override_circuit_breakers(&mut self, allow_trading: bool) {
+        self.circuit_breaker.override_halt(allow_trading);
+    }
+
+    // ==================== Pre-Trade Compliance ====================
+
+    /// 컴플라이언스 규칙 체인 조회.
+    pub fn compliance(&self) -> &ComplianceChain {
+        &self.compliance
+    }
+
+    /// 컴플라이언스 규칙 체인 교체 (사용자 정의 규칙 연결).
+    pub fn set_compliance(&mut self, chain: ComplianceChain) {
+        self.compliance = chain;
+    }
+
+    /// 거래소 제출 전 컴플라이언스 검사.
+    ///
+    /// 컨텍스트에 계좌 자산이 없으면 현재 잔고를 사용합니다.
+    pub fn check_compliance(
+        &self,
+        order: &OrderRequest,
+        mut ctx: ComplianceContext,
+    ) -> ComplianceResult {
+        ctx.account_equity.get_or_insert(self.balance);
+        self.compliance.check(order, &ctx)
+    }
+
+    /// 종목의 주문 수량 단위 (심볼 필터 `step_size`, 없으면 시장별 기본값).
+    pub fn lot_step(&self, ticker: &str) -> Decimal {
+        self.config.compliance.lot_step(ticker)
+    }
+
     // ==================== Stop Orders ====================
 
     /// 포지션에 대한 Stop-loss 주문 생성.
@@ -366,6 +654,87 @@ impl RiskManager {
         self.volatility_data.get(symbol)
     }
 
+    // ==================== Portfolio VaR ====================
+
+    /// 종목의 종가 이력 갱신 (시간순, VaR/ES 및 상관 클러스터 계산용).
+    pub fn update_price_history(&mut self, symbol: &str, closes: &[Decimal]) {
+        self.var_calculator.update_prices(symbol, closes);
+    }
+
+    /// 캔들로 종목의 가격 이력 갱신 (시간순, VaR/ES, 상관 클러스터, 변동성 기반 사이징용).
+    pub fn update_klines(&mut self, symbol: &str, klines: &[Kline]) {
+        self.var_calculator.update_klines(symbol, klines);
+        self.position_sizer.update_klines(symbol, klines);
+    }
+
+    /// 현재 포지션의 포트폴리오 VaR/ES.
+    ///
+    /// 노출 종목의 가격 이력이 하나도 없으면 `None`을 반환합니다.
+    pub fn portfolio_risk(&self, positions: &[Position]) -> Option<PortfolioRisk> {
+        let exposures = VarCalculator::exposures(positions, None);
+        self.var_calculator.calculate(&exposures, self.balance)
+    }
+
+    /// 주문 체결을 가정한 포트폴리오 VaR/ES.
+    pub fn pre_trade_risk(
+        &self,
+        order: &OrderRequest,
+        positions: &[Position],
+        current_price: Decimal,
+    ) -> Option<PortfolioRisk> {
+        let exposures = VarCalculator::exposures(positions, Some((order, current_price)));
+        self.var_calculator.calculate(&exposures, self.balance)
+    }
+
+    /// 포트폴리오 VaR/ES 한도 초과 사유 (한도 이내이거나 계산할 수 없으면 None).
+    pub fn portfolio_var_breach(&self, positions: &[Position]) -> Option<String> {
+        self.portfolio_risk(positions)?
+            .breach(self.var_calculator.config())
+    }
+
+    // ==================== Concentration ====================
+
+    /// 종목 프로필(섹터, 국가, 통화) 설정.
+    pub fn set_symbol_profile(&mut self, symbol: &str, profile: SymbolProfile) {
+        self.concentration.set_profile(symbol, profile);
+    }
+
+    /// 종목 프로필 조회.
+    pub fn symbol_profile(&self, symbol: &str) -> Option<&SymbolProfile> {
+        self.concentration.profile(symbol)
+    }
+
+    /// 주문 체결 시 집중도 한도를 넘는 그룹 목록.
+    ///
+    /// 상관 클러스터는 가격 이력이 있는 노출 종목으로만 구성됩니다.
+    pub fn concentration_breaches(
+        &self,
+        order: &OrderRequest,
+        positions: &[Position],
+        current_price: Decimal,
+    ) -> Vec<ConcentrationBreach> {
+        let config = self.concentration.config();
+        if !config.has_limit() {
+            return Vec::new();
+        }
+
+        let before = VarCalculator::exposures(positions, None);
+        let after = VarCalculator::exposures(positions, Some((order, current_price)));
+        let correlation = config.max_cluster_pct.and_then(|_| {
+            let symbols: Vec<String> = after.keys().cloned().collect();
+            self.var_calculator.correlation_matrix(&symbols)
+        });
+
+        self.concentration.check(
+            order,
+            current_price,
+            &before,
+            &after,
+            self.balance,
+            correlation.as_ref(),
+        )
+    }
+
     // ==================== Position Sizing ====================
 
     /// 심볼의 최대 포지션 크기 계산.
@@ -373,6 +742,13 @@ impl RiskManager {
         self.position_sizer.calculate_max_size(self.balance, symbol)
     }
 
+    /// 설정된 사이징 방식(고정 비율, 변동성 타깃, 역변동성, 리스크 패리티)으로
+    /// 신규 진입 포지션 크기 계산 (최대 포지션 크기로 제한).
+    pub fn calculate_target_size(&self, symbol: &str) -> Decimal {
+        self.position_sizer
+            .calculate_target_size(self.balance, symbol)
+    }
+
     /// 현재 총 노출 계산.
     pub fn calculate_exposure(&self, positions: &[Position]) -> Decimal {
         self.position_sizer.calculate_current_exposure(positions)
@@ -628,6 +1004,235 @@ mod tests {
         assert_eq!(suggested.quantity, dec!(0.02));
     }
 
+    /// 매일 ±2%씩 교대로 움직이는 종가.
+    #[test]
+    fn test_validate_order_sector_concentration_limit() {
+        let config = RiskConfig {
+            max_position_pct: 100.0,
+            max_total_exposure_pct: 100.0,
+            concentration: crate::concentration::ConcentrationConfig {
+                max_sector_pct: Some(20.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut manager = RiskManager::new(config, dec!(100000));
+        let semiconductor = SymbolProfile {
+            sector: Some("반도체".to_string()),
+            country: Some(trader_core::Country::KR),
+            currency: None,
+        };
+        manager.set_symbol_profile("005930", semiconductor.clone());
+        manager.set_symbol_profile("000660", semiconductor);
+
+        // 삼성전자 15% 보유
+        let held = Position::new(
+            "test_exchange",
+            "005930".to_string(),
+            Side::Buy,
+            dec!(150),
+            dec!(100),
+        );
+        let positions = vec![held];
+
+        // SK하이닉스 10% 추가 매수 → 반도체 25% (한도 20%)
+        let order = OrderRequest::market_buy("000660".to_string(), dec!(100));
+        let result = manager
+            .validate_order(&order, &positions, dec!(100))
+            .unwrap();
+        assert!(!result.is_valid);
+        assert!(result.messages[0].contains("sector 반도체"));
+        let adjusted = result.modified_order.unwrap();
+        assert_eq!(adjusted.quantity, dec!(50));
+
+        // 제안 수량은 통과
+        let result = manager
+            .validate_order(&adjusted, &positions, dec!(100))
+            .unwrap();
+        assert!(result.is_valid, "{:?}", result.messages);
+
+        // 프로필이 없는 종목은 검사하지 않음
+        let order = OrderRequest::market_buy("035420".to_string(), dec!(100));
+        let result = manager
+            .validate_order(&order, &positions, dec!(100))
+            .unwrap();
+        assert!(result.is_valid);
+    }
+
+    #[test]
+    fn test_validate_order_drawdown_circuit_breaker() {
+        use crate::drawdown::{BreakerAction, DrawdownConfig};
+        use chrono::TimeZone;
+
+        let config = RiskConfig {
+            drawdown: DrawdownConfig {
+                max_drawdown_pct: Some(10.0),
+                max_strategy_consecutive_losses: Some(2),
+                action: BreakerAction::ReduceSize,
+                ..Default::default()
+            },
+            max_position_pct: 100.0,
+            ..Default::default()
+        };
+        let mut manager = RiskManager::new(config, dec!(10000));
+        let order = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.02));
+
+        // 고점 대비 15% 하락 → 신규 진입 수량 절반
+        let day = |d| Utc.with_ymd_and_hms(2024, 3, d, 0, 0, 0).unwrap();
+        assert!(manager.update_equity(day(1), dec!(10000)).is_empty());
+        assert_eq!(manager.update_equity(day(2), dec!(8500)).len(), 1);
+
+        let result = manager.validate_order(&order, &[], dec!(50000)).unwrap();
+        assert!(result.is_valid);
+        assert!(result.messages[0].contains("peak drawdown"));
+        assert_eq!(result.modified_order.unwrap().quantity, dec!(0.01));
+
+        // 청산 주문은 축소하지 않음
+        let held = create_test_position(
+            &Symbol::crypto("BTC", "USDT"),
+            Side::Buy,
+            dec!(0.02),
+            dec!(50000),
+        );
+        let exit = OrderRequest::market_sell("BTC/USDT".to_string(), dec!(0.02));
+        let result = manager
+            .validate_order(&exit, std::slice::from_ref(&held), dec!(50000))
+            .unwrap();
+        assert!(result.is_valid);
+        assert!(result.modified_order.is_none());
+
+        // 보유 수량을 넘는 매도는 초과분(신규 숏)만 축소
+        let flip = OrderRequest::market_sell("BTC/USDT".to_string(), dec!(0.05));
+        let result = manager
+            .validate_order(&flip, std::slice::from_ref(&held), dec!(50000))
+            .unwrap();
+        assert!(result.is_valid, "{:?}", result.messages);
+        assert_eq!(result.modified_order.unwrap().quantity, dec!(0.035));
+
+        // 주식은 정수 주 단위로 내림
+        let stock = OrderRequest::market_buy("005930".to_string(), dec!(3));
+        let result = manager.validate_order(&stock, &[], dec!(100)).unwrap();
+        assert_eq!(result.modified_order.unwrap().quantity, dec!(1));
+
+        // 리셋 후에는 제한 없음
+        manager.reset_circuit_breakers();
+        let result = manager.validate_order(&order, &[], dec!(50000)).unwrap();
+        assert!(result.modified_order.is_none());
+    }
+
+    #[test]
+    fn test_halted_breaker_only_allows_closing_quantity() {
+        use crate::drawdown::{BreakerAction, DrawdownConfig};
+        use chrono::TimeZone;
+
+        let config = RiskConfig {
+            drawdown: DrawdownConfig {
+                max_drawdown_pct: Some(10.0),
+                action: BreakerAction::Halt,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut manager = RiskManager::new(config, dec!(10000));
+        let day = |d| Utc.with_ymd_and_hms(2024, 3, d, 0, 0, 0).unwrap();
+        manager.update_equity(day(1), dec!(10000));
+        manager.update_equity(day(2), dec!(8500));
+
+        let entry = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.02));
+        let result = manager.validate_order(&entry, &[], dec!(50000)).unwrap();
+        assert!(!result.is_valid);
+
+        // 청산은 허용하되, 보유 수량을 넘어 숏으로 전환하는 부분은 잘라냄
+        let held = create_test_position(
+            &Symbol::crypto("BTC", "USDT"),
+            Side::Buy,
+            dec!(0.02),
+            dec!(50000),
+        );
+        let exit = OrderRequest::market_sell("BTC/USDT".to_string(), dec!(0.02));
+        let result = manager
+            .validate_order(&exit, std::slice::from_ref(&held), dec!(50000))
+            .unwrap();
+        assert!(result.is_valid);
+        assert!(result.modified_order.is_none());
+
+        let flip = OrderRequest::market_sell("BTC/USDT".to_string(), dec!(0.05));
+        let result = manager
+            .validate_order(&flip, std::slice::from_ref(&held), dec!(50000))
+            .unwrap();
+        assert!(result.is_valid, "{:?}", result.messages);
+        assert_eq!(result.modified_order.unwrap().quantity, dec!(0.02));
+    }
+
+    fn zigzag_closes(len: usize) -> Vec<Decimal> {
+        (0..len)
+            .map(|i| if i % 2 == 0 { dec!(100) } else { dec!(102) })
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_order_portfolio_var_limit() {
+        let mut config = RiskConfig {
+            max_position_pct: 100.0,
+            max_total_exposure_pct: 100.0,
+            portfolio_var: crate::var::PortfolioVarConfig {
+                max_var_pct: Some(1.0),
+                breach_action: crate::var::VarBreachAction::Reject,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut manager = RiskManager::new(config.clone(), dec!(100000));
+        manager.update_price_history("005930", &zigzag_closes(60));
+
+        // 50,000 × 약 2% = 1,000 (1%) 이하
+        let small = OrderRequest::market_buy("005930".to_string(), dec!(400));
+        let result = manager.validate_order(&small, &[], dec!(100)).unwrap();
+        assert!(result.is_valid, "{:?}", result.messages);
+        let risk = manager.pre_trade_risk(&small, &[], dec!(100)).unwrap();
+        assert!(risk.var_pct <= 1.0);
+
+        // 100,000 × 약 2% = 2,000 (2%) → 거부
+        let large = OrderRequest::market_buy("005930".to_string(), dec!(1000));
+        let result = manager.validate_order(&large, &[], dec!(100)).unwrap();
+        assert!(!result.is_valid);
+        assert!(result.messages[0].contains("Portfolio VaR"));
+
+        // 축소 모드에서는 한도 안의 수량으로 조정해 허용
+        config.portfolio_var.breach_action = crate::var::VarBreachAction::Downsize;
+        let mut manager = RiskManager::new(config, dec!(100000));
+        manager.update_price_history("005930", &zigzag_closes(60));
+        manager.update_volatility("005930", 4.0, 2.0);
+        let result = manager.validate_order(&large, &[], dec!(100)).unwrap();
+        assert!(result.is_valid);
+        assert!(result.messages[0].contains("order downsized"));
+        assert!(result
+            .messages
+            .iter()
+            .any(|m| m.contains("Elevated volatility")));
+        let adjusted = result.modified_order.unwrap();
+        assert!(adjusted.quantity > dec!(400) && adjusted.quantity < dec!(1000));
+        assert_eq!(adjusted.quantity.fract(), Decimal::ZERO);
+        let risk = manager.pre_trade_risk(&adjusted, &[], dec!(100)).unwrap();
+        assert!(risk.var_pct <= 1.0);
+
+        // 보유 포지션을 줄이는 주문은 한도를 넘어도 허용
+        let held = Position::new(
+            "test_exchange",
+            "005930".to_string(),
+            Side::Buy,
+            dec!(900),
+            dec!(100),
+        );
+        assert!(manager
+            .portfolio_var_breach(std::slice::from_ref(&held))
+            .is_some());
+        let reduce = OrderRequest::market_sell("005930".to_string(), dec!(100));
+        let result = manager.validate_order(&reduce, &[held], dec!(100)).unwrap();
+        assert!(result.is_valid, "{:?}", result.messages);
+        assert!(result.modified_order.is_none());
+    }
+
     #[test]
     fn test_daily_reset() {
         let config = RiskConfig::default();
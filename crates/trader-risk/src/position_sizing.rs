@@ -4,12 +4,14 @@
 //! - 계좌 잔고 기반 최대 허용 포지션 크기 계산
 //! - 리스크 한도 대비 주문 크기 검증
 //! - 다양한 방법(고정 비율, Kelly)을 사용한 최적 포지션 크기 계산
+//! - 변동성 기반 포지션 크기 계산 (변동성 타깃, 역변동성 가중, 리스크 패리티)
 
 use crate::config::RiskConfig;
 use crate::manager::RiskValidation;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use trader_core::{OrderRequest, Position};
+use std::collections::HashMap;
+use trader_core::{inverse_volatility_weights, risk_parity_weights, Kline, OrderRequest, Position};
 
 /// 정밀도를 위해 정수 연산을 사용하여 퍼센트를 금액으로 변환.
 /// 예시: pct_to_amount(1000, 10.0) = 100 (1000의 10%)
@@ -24,6 +26,8 @@ fn pct_to_amount(amount: Decimal, pct: f64) -> Decimal {
 #[derive(Debug, Clone)]
 pub struct PositionSizer {
     config: RiskConfig,
+    /// 변동성 기반 사이징용 종목별 최근 캔들 (시간순)
+    history: HashMap<String, Vec<Kline>>,
 }
 
 /// 상세 정보가 포함된 포지션 크기 검증 결과.
@@ -91,7 +95,10 @@ impl SizingValidation {
 impl PositionSizer {
     /// 주어진 설정으로 새 포지션 사이저를 생성.
     pub fn new(config: RiskConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            history: HashMap::new(),
+        }
     }
 
     /// 단일 거래에 대한 최대 허용 포지션 크기를 계산.
@@ -315,6 +322,135 @@ impl PositionSizer {
         kelly_size.min(max_size)
     }
 
+    /// 변동성 타깃 방식으로 포지션 크기를 계산.
+    ///
+    /// 포지션의 연율화 변동성 기여가 `position_sizing.target_volatility_pct`가
+    /// 되도록 `목표 변동성 / 종목 변동성` 비중을 사용합니다.
+    ///
+    /// # 인자
+    /// * `balance` - 총 계좌 잔고
+    /// * `symbol` - 거래 심볼
+    /// * `volatility` - 종목의 연율화 변동성 (0.2 = 20%)
+    ///
+    /// # 반환값
+    /// 기준 통화로 된 권장 포지션 크기 (최대 허용량으로 제한)
+    pub fn calculate_volatility_target(
+        &self,
+        balance: Decimal,
+        symbol: &str,
+        volatility: f64,
+    ) -> Decimal {
+        if !volatility.is_finite() || volatility <= 0.0 {
+            return Decimal::ZERO;
+        }
+        let weight = self.config.position_sizing.target_volatility_pct / 100.0 / volatility;
+        self.weight_to_size(balance, symbol, weight)
+    }
+
+    /// 역변동성 가중 방식으로 종목별 포지션 크기를 계산.
+    ///
+    /// # 인자
+    /// * `balance` - 총 계좌 잔고
+    /// * `volatilities` - 종목별 연율화 변동성
+    ///
+    /// # 반환값
+    /// 종목별 권장 포지션 크기 (각각 최대 허용량으로 제한, 계산 불가 시 빈 맵)
+    pub fn calculate_inverse_volatility(
+        &self,
+        balance: Decimal,
+        volatilities: &[(String, f64)],
+    ) -> HashMap<String, Decimal> {
+        let vols: Vec<f64> = volatilities.iter().map(|(_, vol)| *vol).collect();
+        let Some(weights) = inverse_volatility_weights(&vols) else {
+            return HashMap::new();
+        };
+        volatilities
+            .iter()
+            .zip(weights)
+            .map(|((symbol, _), weight)| {
+                (symbol.clone(), self.weight_to_size(balance, symbol, weight))
+            })
+            .collect()
+    }
+
+    /// 동일 위험 기여(ERC) 리스크 패리티 방식으로 종목별 포지션 크기를 계산.
+    ///
+    /// # 인자
+    /// * `balance` - 총 계좌 잔고
+    /// * `symbols` - 바스켓 종목 (공분산 행렬과 같은 순서)
+    /// * `covariance` - 수익률 공분산 행렬
+    ///
+    /// # 반환값
+    /// 종목별 권장 포지션 크기 (각각 최대 허용량으로 제한, 계산 불가 시 빈 맵)
+    pub fn calculate_risk_parity(
+        &self,
+        balance: Decimal,
+        symbols: &[String],
+        covariance: &[Vec<f64>],
+    ) -> HashMap<String, Decimal> {
+        if symbols.len() != covariance.len() {
+            return HashMap::new();
+        }
+        let Some(weights) = risk_parity_weights(covariance) else {
+            return HashMap::new();
+        };
+        symbols
+            .iter()
+            .zip(weights)
+            .map(|(symbol, weight)| (symbol.clone(), self.weight_to_size(balance, symbol, weight)))
+            .collect()
+    }
+
+    /// 변동성 기반 사이징용 캔들 이력 갱신 (시간순).
+    ///
+    /// 고정 비율 방식이면 이력을 보관하지 않습니다.
+    pub fn update_klines(&mut self, symbol: &str, klines: &[Kline]) {
+        let sizing = &self.config.position_sizing;
+        if sizing.is_fixed() {
+            return;
+        }
+        let start = klines.len().saturating_sub(sizing.history_len());
+        self.history
+            .insert(symbol.to_string(), klines[start..].to_vec());
+    }
+
+    /// 설정된 `position_sizing` 방식으로 신규 진입 포지션 크기를 계산.
+    ///
+    /// 변동성 기반 방식은 캔들 이력이 있는 종목 전체를 바스켓으로 비중을 구합니다.
+    /// 고정 비율 방식이거나 종목의 비중을 구할 수 없으면 최대 허용 크기를 반환합니다.
+    ///
+    /// # 인자
+    /// * `balance` - 총 계좌 잔고
+    /// * `symbol` - 거래 심볼
+    ///
+    /// # 반환값
+    /// 기준 통화로 된 포지션 크기 (최대 허용량으로 제한)
+    pub fn calculate_target_size(&self, balance: Decimal, symbol: &str) -> Decimal {
+        let sizing = &self.config.position_sizing;
+        if sizing.is_fixed() {
+            return self.calculate_max_size(balance, symbol);
+        }
+
+        let basket: Vec<(&str, &[Kline])> = self
+            .history
+            .iter()
+            .map(|(ticker, klines)| (ticker.as_str(), klines.as_slice()))
+            .collect();
+        match sizing.target_weights(&basket).get(symbol) {
+            Some(weight) => self.weight_to_size(balance, symbol, *weight),
+            None => self.calculate_max_size(balance, symbol),
+        }
+    }
+
+    /// 자본 대비 비중을 포지션 크기로 변환 (`max_weight`와 최대 허용량으로 제한).
+    fn weight_to_size(&self, balance: Decimal, symbol: &str, weight: f64) -> Decimal {
+        if !weight.is_finite() || weight <= 0.0 {
+            return Decimal::ZERO;
+        }
+        let weight = weight.min(self.config.position_sizing.max_weight);
+        pct_to_amount(balance, weight * 100.0).min(self.calculate_max_size(balance, symbol))
+    }
+
     /// 한도 내에 맞는 조정된 주문 크기를 제안.
     ///
     /// # 인자
@@ -588,4 +724,90 @@ mod tests {
         assert!(!validation.is_valid);
         assert!(validation.messages[0].contains("Trading disabled"));
     }
+
+    /// 종가 수익률이 +r, -r로 번갈아 나오는 일봉.
+    fn alternating_klines(symbol: &str, r: f64, len: usize) -> Vec<Kline> {
+        use chrono::{Duration, TimeZone, Utc};
+        use rust_decimal::prelude::FromPrimitive;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut close = 100.0;
+        (0..len)
+            .map(|i| {
+                if i > 0 {
+                    close *= if i % 2 == 1 { 1.0 + r } else { 1.0 - r };
+                }
+                let price = Decimal::from_f64(close).unwrap();
+                let time = start + Duration::days(i as i64);
+                Kline::new(
+                    symbol.to_string(),
+                    trader_core::Timeframe::D1,
+                    time,
+                    price,
+                    price,
+                    price,
+                    price,
+                    dec!(1000),
+                    time,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_volatility_based_sizing() {
+        use trader_core::{PositionSizingConfig, SizingMode};
+
+        let config = RiskConfig {
+            max_position_pct: 50.0,
+            position_sizing: PositionSizingConfig::new(SizingMode::VolatilityTarget),
+            ..RiskConfig::default()
+        };
+        let sizer = PositionSizer::new(config.clone());
+        let balance = dec!(10000);
+
+        // 목표 10% / 변동성 40% = 25%
+        assert_eq!(
+            sizer.calculate_volatility_target(balance, "AAA", 0.4),
+            dec!(2500)
+        );
+        // 최대 포지션 비율(50%)로 제한
+        assert_eq!(
+            sizer.calculate_volatility_target(balance, "AAA", 0.1),
+            dec!(5000)
+        );
+        assert_eq!(
+            sizer.calculate_volatility_target(balance, "AAA", 0.0),
+            Decimal::ZERO
+        );
+
+        let sizes = sizer.calculate_inverse_volatility(
+            balance,
+            &[("AAA".to_string(), 0.1), ("BBB".to_string(), 0.3)],
+        );
+        assert_eq!(sizes["AAA"], dec!(5000)); // 75% → 50%로 제한
+        assert_eq!(sizes["BBB"], dec!(2500));
+
+        let sizes = sizer.calculate_risk_parity(
+            balance,
+            &["AAA".to_string(), "BBB".to_string()],
+            &[vec![0.09, 0.0], vec![0.0, 0.09]],
+        );
+        assert_eq!(sizes["AAA"], dec!(5000));
+        assert_eq!(sizes["BBB"], dec!(5000));
+
+        // 캔들 이력 기반: 이력이 없으면 최대 크기
+        let mut sizer = PositionSizer::new(RiskConfig {
+            position_sizing: PositionSizingConfig::new(SizingMode::InverseVolatility),
+            ..config
+        });
+        assert_eq!(sizer.calculate_target_size(balance, "AAA"), dec!(5000));
+
+        sizer.update_klines("AAA", &alternating_klines("AAA", 0.01, 40));
+        sizer.update_klines("BBB", &alternating_klines("BBB", 0.02, 40));
+        let size_a = sizer.calculate_target_size(balance, "AAA");
+        let size_b = sizer.calculate_target_size(balance, "BBB");
+        assert_eq!(size_a, dec!(5000)); // 66.7% → 50%로 제한
+        assert!((size_b - dec!(3333.33)).abs() < dec!(0.1));
+    }
 }
@@ -136,12 +136,20 @@ impl StopOrder {
     }
 
     /// OrderRequest로 변환.
+    ///
+    /// 이익실현 스탑은 `TakeProfit`/`TakeProfitLimit`, 나머지는 `StopLoss`/`StopLossLimit`으로
+    /// 변환됩니다 (트리거 방향이 반대이므로 구분 필요).
     pub fn to_order_request(&self) -> OrderRequest {
+        let is_take_profit = self.stop_type == StopType::FixedTakeProfit;
         match self.limit_price {
             Some(limit) => OrderRequest {
                 ticker: self.symbol.clone(),
                 side: self.side,
-                order_type: OrderType::StopLossLimit,
+                order_type: if is_take_profit {
+                    OrderType::TakeProfitLimit
+                } else {
+                    OrderType::StopLossLimit
+                },
                 quantity: self.quantity,
                 price: Some(limit),
                 stop_price: Some(self.trigger_price),
@@ -152,7 +160,11 @@ impl StopOrder {
             None => OrderRequest {
                 ticker: self.symbol.clone(),
                 side: self.side,
-                order_type: OrderType::StopLoss,
+                order_type: if is_take_profit {
+                    OrderType::TakeProfit
+                } else {
+                    OrderType::StopLoss
+                },
                 quantity: self.quantity,
                 price: None,
                 stop_price: Some(self.trigger_price),
@@ -699,6 +711,13 @@ mod tests {
         assert_eq!(request.ticker, symbol_str);
         assert_eq!(request.side, Side::Sell);
         assert_eq!(request.quantity, dec!(0.1));
+        assert_eq!(request.order_type, OrderType::StopLoss);
+
+        // 이익실현은 TakeProfit 유형으로 변환되어야 함
+        let tp = StopOrder::take_profit(symbol_str, Side::Buy, dec!(0.1), dec!(52000), dec!(50000));
+        let request = tp.to_order_request();
+        assert_eq!(request.order_type, OrderType::TakeProfit);
+        assert_eq!(request.stop_price, Some(dec!(52000)));
     }
 
     #[test]
@@ -0,0 +1,520 @@
+//! 포트폴리오 VaR / Expected Shortfall.
+//!
+//! 보유 포지션(과 검증 중인 주문)의 노출 금액에 종목별 캔들 종가 수익률을 적용해
+//! 1일 손실 분포를 추정합니다.
+//!
+//! - **Historical**: 과거 수익률을 현재 노출에 그대로 적용한 손익 시계열의 분위수
+//! - **Parametric**: 종목별 변동성과 상관행렬(`trader_analytics::correlation`)로 구한
+//!   포트폴리오 표준편차에 정규분포를 가정
+//!
+//! 매수 포지션은 양(+), 매도(공매도) 포지션은 음(-)의 노출로 계산하므로
+//! 상관관계가 높은 반대 포지션은 서로 상쇄됩니다.
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use trader_analytics::correlation::{
+    calculate_correlation_matrix, prices_to_returns, CorrelationMatrix,
+};
+use trader_core::{Kline, OrderRequest, Position, Side};
+
+/// VaR 계산에 필요한 최소 수익률 관측 수.
+const MIN_OBSERVATIONS: usize = 20;
+
+/// VaR 계산 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VarMethod {
+    /// 과거 수익률 시뮬레이션 (기본값)
+    #[default]
+    Historical,
+    /// 분산-공분산 (정규분포 가정)
+    Parametric,
+}
+
+/// VaR/ES 한도 초과 시 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VarBreachAction {
+    /// 주문 거부
+    Reject,
+    /// 한도 안으로 수량을 줄여 허용 (기본값)
+    #[default]
+    Downsize,
+}
+
+/// 포트폴리오 VaR/ES 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioVarConfig {
+    /// 계산 방식 (기본값: historical)
+    #[serde(default)]
+    pub method: VarMethod,
+
+    /// 신뢰수준 (기본값: 0.95)
+    #[serde(default = "default_confidence")]
+    pub confidence: f64,
+
+    /// 수익률 계산에 사용할 최대 캔들 수 (기본값: 250)
+    #[serde(default = "default_lookback")]
+    pub lookback: usize,
+
+    /// 계좌 잔고 대비 최대 1일 VaR 비율 (%) - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_var_pct: Option<f64>,
+
+    /// 계좌 잔고 대비 최대 1일 Expected Shortfall 비율 (%) - 없으면 검사하지 않음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_es_pct: Option<f64>,
+
+    /// 한도 초과 시 처리 방식 (기본값: downsize)
+    #[serde(default)]
+    pub breach_action: VarBreachAction,
+}
+
+fn default_confidence() -> f64 {
+    0.95
+}
+
+fn default_lookback() -> usize {
+    250
+}
+
+impl Default for PortfolioVarConfig {
+    fn default() -> Self {
+        Self {
+            method: VarMethod::default(),
+            confidence: default_confidence(),
+            lookback: default_lookback(),
+            max_var_pct: None,
+            max_es_pct: None,
+            breach_action: VarBreachAction::default(),
+        }
+    }
+}
+
+impl PortfolioVarConfig {
+    /// VaR 또는 ES 한도가 설정되어 있는지 확인.
+    pub fn has_limit(&self) -> bool {
+        self.max_var_pct.is_some() || self.max_es_pct.is_some()
+    }
+}
+
+/// 포트폴리오 리스크 추정 결과 (1일 기준).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioRisk {
+    /// 계산 방식
+    pub method: VarMethod,
+    /// 신뢰수준
+    pub confidence: f64,
+    /// Value-at-Risk (금액)
+    pub var: Decimal,
+    /// Expected Shortfall (금액)
+    pub expected_shortfall: Decimal,
+    /// 잔고 대비 VaR 비율 (%)
+    pub var_pct: f64,
+    /// 잔고 대비 ES 비율 (%)
+    pub es_pct: f64,
+    /// 총 노출 (절대값 합)
+    pub gross_exposure: Decimal,
+    /// 사용한 수익률 관측 수
+    pub observations: usize,
+    /// 가격 이력이 부족해 제외된 종목
+    pub missing_symbols: Vec<String>,
+}
+
+impl PortfolioRisk {
+    /// 한도 초과 사유 (초과하지 않으면 None).
+    pub fn breach(&self, config: &PortfolioVarConfig) -> Option<String> {
+        if let Some(limit) = config.max_var_pct {
+            if self.var_pct > limit {
+                return Some(format!(
+                    "Portfolio VaR {:.2}% exceeds limit {:.2}%",
+                    self.var_pct, limit
+                ));
+            }
+        }
+        if let Some(limit) = config.max_es_pct {
+            if self.es_pct > limit {
+                return Some(format!(
+                    "Portfolio expected shortfall {:.2}% exceeds limit {:.2}%",
+                    self.es_pct, limit
+                ));
+            }
+        }
+        None
+    }
+}
+
+/// 종목별 가격 이력을 보관하고 포트폴리오 VaR/ES를 계산.
+#[derive(Debug, Clone)]
+pub struct VarCalculator {
+    config: PortfolioVarConfig,
+    /// 종목별 종가 (시간순, 최대 lookback + 1개)
+    prices: HashMap<String, Vec<f64>>,
+}
+
+impl VarCalculator {
+    /// 새 계산기 생성.
+    pub fn new(config: PortfolioVarConfig) -> Self {
+        Self {
+            config,
+            prices: HashMap::new(),
+        }
+    }
+
+    /// 설정 참조 조회.
+    pub fn config(&self) -> &PortfolioVarConfig {
+        &self.config
+    }
+
+    /// 종목의 종가 이력 갱신 (시간순).
+    pub fn update_prices(&mut self, symbol: &str, closes: &[Decimal]) {
+        let keep = self.config.lookback + 1;
+        let start = closes.len().saturating_sub(keep);
+        let prices: Vec<f64> = closes[start..].iter().filter_map(|c| c.to_f64()).collect();
+        self.prices.insert(symbol.to_string(), prices);
+    }
+
+    /// 캔들로 종가 이력 갱신 (시간순).
+    pub fn update_klines(&mut self, symbol: &str, klines: &[Kline]) {
+        let closes: Vec<Decimal> = klines.iter().map(|k| k.close).collect();
+        self.update_prices(symbol, &closes);
+    }
+
+    /// VaR 계산에 충분한 가격 이력이 있는지 확인.
+    pub fn has_history(&self, symbol: &str) -> bool {
+        self.prices
+            .get(symbol)
+            .is_some_and(|p| p.len() > MIN_OBSERVATIONS)
+    }
+
+    /// 가격 이력이 충분한 종목들의 수익률 상관행렬 (최근 구간으로 길이를 맞춤).
+    ///
+    /// 가격 이력이 부족한 종목은 행렬에서 제외됩니다.
+    pub fn correlation_matrix(&self, symbols: &[String]) -> Option<CorrelationMatrix> {
+        let included: Vec<String> = symbols
+            .iter()
+            .filter(|s| self.has_history(s))
+            .cloned()
+            .collect();
+        let len = included.iter().map(|s| self.prices[s].len()).min()?;
+        let prices: HashMap<String, Vec<f64>> = included
+            .iter()
+            .map(|s| {
+                let p = &self.prices[s];
+                (s.clone(), p[p.len() - len..].to_vec())
+            })
+            .collect();
+        calculate_correlation_matrix(&prices, Some(included))
+    }
+
+    /// 포지션과 (선택) 주문으로 종목별 순노출 계산 (매수 +, 매도 -).
+    pub fn exposures(
+        positions: &[Position],
+        order: Option<(&OrderRequest, Decimal)>,
+    ) -> BTreeMap<String, Decimal> {
+        let mut exposures: BTreeMap<String, Decimal> = BTreeMap::new();
+        for position in positions.iter().filter(|p| p.is_open()) {
+            *exposures.entry(position.ticker.clone()).or_default() +=
+                signed(position.side, position.notional_value());
+        }
+        if let Some((order, price)) = order {
+            *exposures.entry(order.ticker.clone()).or_default() +=
+                signed(order.side, order.quantity * price);
+        }
+        exposures.retain(|_, value| !value.is_zero());
+        exposures
+    }
+
+    /// 순노출에 대한 1일 VaR/ES 계산.
+    ///
+    /// 노출이 있는 종목 중 가격 이력이 있는 종목이 하나도 없으면 `None`을 반환합니다.
+    pub fn calculate(
+        &self,
+        exposures: &BTreeMap<String, Decimal>,
+        balance: Decimal,
+    ) -> Option<PortfolioRisk> {
+        let gross_exposure: Decimal = exposures.values().map(|v| v.abs()).sum();
+        let (included, missing_symbols): (Vec<&String>, Vec<&String>) =
+            exposures.keys().partition(|s| self.has_history(s));
+        let missing_symbols: Vec<String> = missing_symbols.into_iter().cloned().collect();
+
+        if exposures.is_empty() {
+            return Some(self.result(0.0, 0.0, balance, gross_exposure, 0, missing_symbols));
+        }
+        if included.is_empty() {
+            return None;
+        }
+
+        // 최근 구간으로 길이를 맞춘 종가
+        let len = included.iter().map(|s| self.prices[*s].len()).min()?;
+        let prices: HashMap<String, Vec<f64>> = included
+            .iter()
+            .map(|s| {
+                let p = &self.prices[*s];
+                ((*s).clone(), p[p.len() - len..].to_vec())
+            })
+            .collect();
+        let weights: Vec<f64> = included
+            .iter()
+            .map(|s| exposures[*s].to_f64().unwrap_or(0.0))
+            .collect();
+        let returns: Vec<Vec<f64>> = included
+            .iter()
+            .map(|s| prices_to_returns(&prices[*s]))
+            .collect();
+        let observations = len - 1;
+
+        let (var, es) = match self.config.method {
+            VarMethod::Historical => historical_var(&weights, &returns, self.config.confidence),
+            VarMethod::Parametric => {
+                let symbols: Vec<String> = included.iter().map(|s| (*s).clone()).collect();
+                let matrix = calculate_correlation_matrix(&prices, Some(symbols))?;
+                let vols: Vec<f64> = returns.iter().map(|r| std_dev(r)).collect();
+                parametric_var(&weights, &vols, &matrix.matrix, self.config.confidence)
+            }
+        };
+
+        Some(self.result(
+            var,
+            es,
+            balance,
+            gross_exposure,
+            observations,
+            missing_symbols,
+        ))
+    }
+
+    fn result(
+        &self,
+        var: f64,
+        es: f64,
+        balance: Decimal,
+        gross_exposure: Decimal,
+        observations: usize,
+        missing_symbols: Vec<String>,
+    ) -> PortfolioRisk {
+        let balance = balance.to_f64().unwrap_or(0.0);
+        let pct = |value: f64| {
+            if balance > 0.0 {
+                value / balance * 100.0
+            } else {
+                0.0
+            }
+        };
+        PortfolioRisk {
+            method: self.config.method,
+            confidence: self.config.confidence,
+            var: Decimal::from_f64(var).unwrap_or_default().round_dp(2),
+            expected_shortfall: Decimal::from_f64(es).unwrap_or_default().round_dp(2),
+            var_pct: pct(var),
+            es_pct: pct(es),
+            gross_exposure,
+            observations,
+            missing_symbols,
+        }
+    }
+}
+
+impl Default for VarCalculator {
+    fn default() -> Self {
+        Self::new(PortfolioVarConfig::default())
+    }
+}
+
+fn signed(side: Side, value: Decimal) -> Decimal {
+    match side {
+        Side::Buy => value,
+        Side::Sell => -value,
+    }
+}
+
+/// 과거 수익률 시뮬레이션 VaR/ES (손실을 양수로 반환).
+fn historical_var(weights: &[f64], returns: &[Vec<f64>], confidence: f64) -> (f64, f64) {
+    let n = returns.first().map_or(0, Vec::len);
+    let mut losses: Vec<f64> = (0..n)
+        .map(|t| {
+            -weights
+                .iter()
+                .zip(returns)
+                .map(|(w, r)| w * r[t])
+                .sum::<f64>()
+        })
+        .collect();
+    if losses.is_empty() {
+        return (0.0, 0.0);
+    }
+    losses.sort_by(|a, b| a.total_cmp(b));
+
+    let index = ((confidence * n as f64).ceil() as usize).clamp(1, n) - 1;
+    let var = losses[index].max(0.0);
+    let tail = &losses[index..];
+    let es = (tail.iter().sum::<f64>() / tail.len() as f64).max(var);
+    (var, es)
+}
+
+/// 분산-공분산 VaR/ES (평균 수익률 0, 정규분포 가정).
+fn parametric_var(
+    weights: &[f64],
+    vols: &[f64],
+    correlation: &[Vec<f64>],
+    confidence: f64,
+) -> (f64, f64) {
+    let mut variance = 0.0;
+    for i in 0..weights.len() {
+        for j in 0..weights.len() {
+            variance += weights[i] * weights[j] * vols[i] * vols[j] * correlation[i][j];
+        }
+    }
+    let sigma = variance.max(0.0).sqrt();
+    let z = normal_quantile(confidence);
+    let density = (-0.5 * z * z).exp() / (2.0 * std::f64::consts::PI).sqrt();
+    (z * sigma, sigma * density / (1.0 - confidence))
+}
+
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+}
+
+/// 표준정규분포 분위수 (Acklam 근사, 상대 오차 1.15e-9).
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let p = p.clamp(1e-12, 1.0 - 1e-12);
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -normal_quantile(1.0 - p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    /// 일정한 변동(+a, -a 교대)을 갖는 종가 시계열.
+    fn zigzag(start: f64, amplitude: f64, len: usize, phase: bool) -> Vec<Decimal> {
+        let mut price = start;
+        (0..len)
+            .map(|i| {
+                let up = (i % 2 == 0) == phase;
+                price *= if up { 1.0 + amplitude } else { 1.0 - amplitude };
+                Decimal::from_f64(price).unwrap().round_dp(4)
+            })
+            .collect()
+    }
+
+    fn position(ticker: &str, side: Side, qty: Decimal, price: Decimal) -> Position {
+        Position::new("test", ticker.to_string(), side, qty, price)
+    }
+
+    #[test]
+    fn test_normal_quantile() {
+        assert!((normal_quantile(0.95) - 1.644_853_6).abs() < 1e-6);
+        assert!((normal_quantile(0.99) - 2.326_347_9).abs() < 1e-6);
+        assert!((normal_quantile(0.5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_historical_and_parametric_var() {
+        let mut calc = VarCalculator::default();
+        calc.update_prices("AAA", &zigzag(100.0, 0.02, 60, true));
+
+        let positions = vec![position("AAA", Side::Buy, dec!(100), dec!(100))];
+        let exposures = VarCalculator::exposures(&positions, None);
+        let risk = calc.calculate(&exposures, dec!(100000)).unwrap();
+
+        // 노출 10,000 × 약 2% 하락
+        assert!(risk.var > dec!(150) && risk.var < dec!(250), "{:?}", risk);
+        assert!(risk.expected_shortfall >= risk.var);
+        assert_eq!(risk.observations, 59);
+
+        let mut parametric = VarCalculator::new(PortfolioVarConfig {
+            method: VarMethod::Parametric,
+            ..PortfolioVarConfig::default()
+        });
+        parametric.update_prices("AAA", &zigzag(100.0, 0.02, 60, true));
+        let risk = parametric.calculate(&exposures, dec!(100000)).unwrap();
+        // 1.645 × 약 2% × 10,000
+        assert!(risk.var > dec!(300) && risk.var < dec!(360), "{:?}", risk);
+        assert!(risk.expected_shortfall > risk.var);
+    }
+
+    #[test]
+    fn test_correlated_hedge_reduces_var() {
+        let mut calc = VarCalculator::new(PortfolioVarConfig {
+            method: VarMethod::Parametric,
+            ..PortfolioVarConfig::default()
+        });
+        calc.update_prices("AAA", &zigzag(100.0, 0.02, 60, true));
+        calc.update_prices("BBB", &zigzag(50.0, 0.02, 60, true));
+
+        let long_only =
+            VarCalculator::exposures(&[position("AAA", Side::Buy, dec!(100), dec!(100))], None);
+        let single = calc.calculate(&long_only, dec!(100000)).unwrap();
+
+        // 같은 방향으로 움직이는 종목을 매도하면 VaR 감소
+        let sell = OrderRequest::market_sell("BBB".to_string(), dec!(100));
+        let hedged = VarCalculator::exposures(
+            &[position("AAA", Side::Buy, dec!(100), dec!(100))],
+            Some((&sell, dec!(50))),
+        );
+        let hedged = calc.calculate(&hedged, dec!(100000)).unwrap();
+        assert!(hedged.var < single.var);
+    }
+
+    #[test]
+    fn test_missing_history() {
+        let calc = VarCalculator::default();
+        let exposures =
+            VarCalculator::exposures(&[position("AAA", Side::Buy, dec!(1), dec!(100))], None);
+        assert!(calc.calculate(&exposures, dec!(1000)).is_none());
+
+        // 노출이 없으면 0
+        let risk = calc.calculate(&BTreeMap::new(), dec!(1000)).unwrap();
+        assert_eq!(risk.var, Decimal::ZERO);
+    }
+}
@@ -22,7 +22,7 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields};
 /// ## Field attributes
 /// - `#[fragment("fragment_id")]`: 이 필드가 Fragment를 사용함을 표시
 /// - `#[fragment("fragment_id", optional)]`: 선택적 Fragment
-/// - `#[schema(label = "...", min = ..., max = ...)]`: 커스텀 필드 메타데이터
+/// - `#[schema(label = "...", min = ..., max = ..., step = ...)]`: 커스텀 필드 메타데이터
 ///
 /// # Examples
 ///
@@ -110,6 +110,7 @@ pub fn derive_strategy_config(input: TokenStream) -> TokenStream {
             let description = schema_attrs.values.get("description");
             let min = schema_attrs.values.get("min");
             let max = schema_attrs.values.get("max");
+            let step = schema_attrs.values.get("step");
 
             // 필드 타입: 명시적 지정 > 자동 추론
             let field_type = if let Some(ft) = &schema_attrs.field_type {
@@ -123,6 +124,7 @@ pub fn derive_strategy_config(input: TokenStream) -> TokenStream {
                     "symbol" => quote! { trader_core::FieldType::Symbol },
                     "symbols" => quote! { trader_core::FieldType::Symbols },
                     "multi_timeframe" => quote! { trader_core::FieldType::MultiTimeframe },
+                    "rules" => quote! { trader_core::FieldType::Rules },
                     _ => infer_field_type(&field.ty),
                 }
             } else {
@@ -149,6 +151,12 @@ pub fn derive_strategy_config(input: TokenStream) -> TokenStream {
                 quote! { None }
             };
 
+            let step_expr = if let Some(step_val) = step.and_then(|v| v.parse::<f64>().ok()) {
+                quote! { Some(#step_val) }
+            } else {
+                quote! { None }
+            };
+
             // options 배열 생성
             let options = &schema_attrs.options;
             let options_expr = if options.is_empty() {
@@ -200,6 +208,7 @@ pub fn derive_strategy_config(input: TokenStream) -> TokenStream {
                     default: #default_expr,
                     min: #min_expr,
                     max: #max_expr,
+                    step: #step_expr,
                     options: #options_expr,
                     required: true,
                     hidden: #is_hidden,
@@ -295,7 +304,7 @@ fn parse_fragment_attribute(attr: &syn::Attribute) -> (String, bool) {
 
 /// 스키마 속성 결과.
 struct SchemaAttributes {
-    /// 기본 키-값 속성 (label, description, min, max, default)
+    /// 기본 키-값 속성 (label, description, min, max, step, default)
     values: std::collections::HashMap<String, String>,
     /// 필드 타입 (select, symbol 등 명시적 지정)
     field_type: Option<String>,
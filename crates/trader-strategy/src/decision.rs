@@ -0,0 +1,579 @@
+//! 전략 의사결정 로그 (신호 감사 추적).
+//!
+//! 전략이 매매하지 않은 이유를 추적하기 위해, 캔들마다 평가한 조건·지표 값·거부한 필터를
+//! [`DecisionRecord`]로 기록합니다.
+//!
+//! ```text
+//! 캔들 ──▶ Strategy (조건 평가, Decision 기록) ──▶ 생명주기 게이트 ──▶ RiskManager ──▶ 주문 제출
+//!            stage=strategy                       stage=lifecycle      stage=risk     stage=execution
+//! ```
+//!
+//! 같은 캔들에서 나온 기록은 같은 `trace_id`를 공유합니다. 엔진은 생성된 신호의 메타데이터에
+//! `trace_id`와 전략 인스턴스 ID를 넣어 두고, 실행 단계(리스크 검증, 주문 제출)는 이를 읽어
+//! 같은 전략·추적에 결과를 기록합니다.
+//!
+//! 신호가 없는 평가(`no_signal`)는 캔들마다 발생하므로 전략·티커별 분당 기록 수를 제한합니다.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+use trader_core::Signal;
+use uuid::Uuid;
+
+use crate::strategies::common::signal_filters::FilteredSignal;
+
+/// 신호 메타데이터의 의사결정 추적 ID 키.
+pub const DECISION_TRACE_KEY: &str = "decision_trace_id";
+
+/// 신호 메타데이터의 전략 인스턴스 ID 키 (엔진 등록 ID).
+pub const STRATEGY_INSTANCE_KEY: &str = "strategy_instance_id";
+
+/// 메모리에 보관하는 최근 의사결정 기록 수.
+const DEFAULT_DECISION_HISTORY: usize = 5000;
+
+/// 전략·티커별 분당 `no_signal` 기록 한도.
+const DEFAULT_NO_SIGNAL_PER_MINUTE: u32 = 60;
+
+/// 의사결정 이벤트 브로드캐스트 버퍼 크기.
+const DECISION_BROADCAST_BUFFER: usize = 1024;
+
+/// 의사결정 단계.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionStage {
+    /// 전략 내부 조건/필터 평가
+    Strategy,
+    /// 엔진 생명주기 게이트 (일시 정지, 청산 대기 등)
+    Lifecycle,
+    /// 리스크 검증 (RiskManager, 거래 중단)
+    Risk,
+    /// 주문 변환 및 거래소 제출
+    Execution,
+}
+
+impl DecisionStage {
+    /// 단계 문자열 (snake_case).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DecisionStage::Strategy => "strategy",
+            DecisionStage::Lifecycle => "lifecycle",
+            DecisionStage::Risk => "risk",
+            DecisionStage::Execution => "execution",
+        }
+    }
+}
+
+impl std::fmt::Display for DecisionStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 의사결정 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionOutcome {
+    /// 신호 생성
+    Emitted,
+    /// 조건 미충족 (신호 없음)
+    NoSignal,
+    /// 전략 내부 필터가 거부
+    Filtered,
+    /// 생명주기 상태로 인해 억제
+    Suppressed,
+    /// 리스크 검증 또는 주문 변환/제출 단계에서 거부
+    Rejected,
+    /// 주문 제출 완료
+    Submitted,
+}
+
+impl DecisionOutcome {
+    /// 결과 문자열 (snake_case).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DecisionOutcome::Emitted => "emitted",
+            DecisionOutcome::NoSignal => "no_signal",
+            DecisionOutcome::Filtered => "filtered",
+            DecisionOutcome::Suppressed => "suppressed",
+            DecisionOutcome::Rejected => "rejected",
+            DecisionOutcome::Submitted => "submitted",
+        }
+    }
+}
+
+impl std::fmt::Display for DecisionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 평가한 조건 1건.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConditionCheck {
+    /// 조건 설명 (예: "rsi < 30")
+    pub name: String,
+    /// 충족 여부
+    pub passed: bool,
+    /// 부가 설명 (실제 값 등)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// 전략이 기록하는 의사결정.
+///
+/// 전략은 `on_market_data`에서 평가 결과를 `Decision`으로 쌓아 두고,
+/// [`Strategy::take_decisions`](crate::Strategy::take_decisions)로 엔진에 넘깁니다.
+/// 결과(신호 생성/미생성/필터 거부)는 엔진이 실제 신호와 대조해 결정합니다.
+///
+/// ```rust,ignore
+/// self.decisions.push(
+///     Decision::new(&ticker)
+///         .condition("rsi < 30", rsi < dec!(30))
+///         .indicator("rsi", rsi)
+///         .with_filter("VolumeFilter", &volume_filter.filter(true, &ctx)),
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Decision {
+    /// 대상 티커
+    pub ticker: String,
+    /// 평가한 조건
+    pub conditions: Vec<ConditionCheck>,
+    /// 평가 시점의 지표 값
+    pub indicators: BTreeMap<String, Value>,
+    /// 신호를 거부한 필터 이름
+    pub rejected_by: Option<String>,
+    /// 거부/판단 사유
+    pub reason: Option<String>,
+}
+
+impl Decision {
+    /// 티커의 의사결정 생성.
+    pub fn new(ticker: impl Into<String>) -> Self {
+        Self {
+            ticker: ticker.into(),
+            ..Self::default()
+        }
+    }
+
+    /// 평가한 조건 추가.
+    pub fn condition(mut self, name: impl Into<String>, passed: bool) -> Self {
+        self.conditions.push(ConditionCheck {
+            name: name.into(),
+            passed,
+            detail: None,
+        });
+        self
+    }
+
+    /// 부가 설명이 있는 조건 추가.
+    pub fn condition_with(
+        mut self,
+        name: impl Into<String>,
+        passed: bool,
+        detail: impl Into<String>,
+    ) -> Self {
+        self.conditions.push(ConditionCheck {
+            name: name.into(),
+            passed,
+            detail: Some(detail.into()),
+        });
+        self
+    }
+
+    /// 지표 값 추가.
+    pub fn indicator(mut self, name: impl Into<String>, value: impl Serialize) -> Self {
+        let value = serde_json::to_value(value).unwrap_or(Value::Null);
+        self.indicators.insert(name.into(), value);
+        self
+    }
+
+    /// 필터가 신호를 거부했음을 기록.
+    pub fn rejected(mut self, filter: impl Into<String>, reason: impl Into<String>) -> Self {
+        self.rejected_by = Some(filter.into());
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// 신호 필터 결과 기록 (통과 시 조건으로, 거부 시 거부 필터로).
+    pub fn with_filter(self, filter: &str, result: &FilteredSignal) -> Self {
+        if result.is_valid {
+            self.condition(filter, true)
+        } else {
+            let reason = result.reason.clone().unwrap_or_default();
+            self.condition_with(filter, false, reason.clone())
+                .rejected(filter, reason)
+        }
+    }
+
+    /// 판단 사유 설정.
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+/// 의사결정 기록 (감사 로그 1행).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    /// 기록 ID
+    pub id: Uuid,
+    /// 캔들 단위 추적 ID (같은 캔들의 전략/리스크/실행 기록을 묶음)
+    pub trace_id: String,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 티커
+    pub ticker: String,
+    /// 의사결정 단계
+    pub stage: DecisionStage,
+    /// 결과
+    pub outcome: DecisionOutcome,
+    /// 평가한 조건
+    #[serde(default)]
+    pub conditions: Vec<ConditionCheck>,
+    /// 지표 값
+    #[serde(default)]
+    pub indicators: BTreeMap<String, Value>,
+    /// 거부한 필터/검증기
+    pub rejected_by: Option<String>,
+    /// 사유
+    pub reason: Option<String>,
+    /// 관련 신호 ID
+    pub signal_id: Option<Uuid>,
+    /// 평가한 캔들/시장 데이터 시각
+    pub data_time: Option<DateTime<Utc>>,
+    /// 기록 시각
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl DecisionRecord {
+    /// 단계와 결과만 지정한 기록 생성.
+    pub fn new(
+        trace_id: impl Into<String>,
+        strategy_id: impl Into<String>,
+        ticker: impl Into<String>,
+        stage: DecisionStage,
+        outcome: DecisionOutcome,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            trace_id: trace_id.into(),
+            strategy_id: strategy_id.into(),
+            ticker: ticker.into(),
+            stage,
+            outcome,
+            conditions: Vec::new(),
+            indicators: BTreeMap::new(),
+            rejected_by: None,
+            reason: None,
+            signal_id: None,
+            data_time: None,
+            recorded_at: Utc::now(),
+        }
+    }
+
+    /// 신호 처리 결과 기록 생성 (리스크/실행 단계용).
+    ///
+    /// 엔진이 넣어 둔 전략 인스턴스 ID로 기록하며, 없으면 신호의 전략 ID를 사용합니다.
+    /// 신호에 추적 ID가 없으면 신호 ID로 새 추적을 만듭니다.
+    pub fn for_signal(signal: &Signal, stage: DecisionStage, outcome: DecisionOutcome) -> Self {
+        let trace_id = signal_trace_id(signal)
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("signal:{}", signal.id));
+        let strategy_id = signal_instance_id(signal).unwrap_or(&signal.strategy_id);
+        let mut record = Self::new(
+            trace_id,
+            strategy_id.to_string(),
+            signal.ticker.clone(),
+            stage,
+            outcome,
+        );
+        record.signal_id = Some(signal.id);
+        record
+    }
+
+    /// 거부 주체와 사유 설정.
+    pub fn with_rejection(
+        mut self,
+        rejected_by: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        self.rejected_by = Some(rejected_by.into());
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// 사유 설정.
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+/// 캔들 단위 추적 ID.
+pub fn decision_trace_id(strategy_id: &str, ticker: &str, data_time: DateTime<Utc>) -> String {
+    format!(
+        "{}:{}:{}",
+        strategy_id,
+        ticker,
+        data_time.timestamp_millis()
+    )
+}
+
+/// 신호 메타데이터의 추적 ID.
+pub fn signal_trace_id(signal: &Signal) -> Option<&str> {
+    signal
+        .metadata
+        .get(DECISION_TRACE_KEY)
+        .and_then(Value::as_str)
+}
+
+/// 신호 메타데이터의 전략 인스턴스 ID.
+pub fn signal_instance_id(signal: &Signal) -> Option<&str> {
+    signal
+        .metadata
+        .get(STRATEGY_INSTANCE_KEY)
+        .and_then(Value::as_str)
+}
+
+/// 의사결정 로그 설정.
+#[derive(Debug, Clone)]
+pub struct DecisionLogConfig {
+    /// 메모리에 보관할 최대 기록 수
+    pub capacity: usize,
+    /// 전략·티커별 분당 `no_signal` 기록 한도 (0이면 무제한)
+    pub max_no_signal_per_minute: u32,
+}
+
+impl Default for DecisionLogConfig {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_DECISION_HISTORY,
+            max_no_signal_per_minute: DEFAULT_NO_SIGNAL_PER_MINUTE,
+        }
+    }
+}
+
+/// 의사결정 기록 조회 조건.
+#[derive(Debug, Clone, Default)]
+pub struct DecisionQuery {
+    /// 전략 ID
+    pub strategy_id: Option<String>,
+    /// 티커
+    pub ticker: Option<String>,
+    /// 추적 ID
+    pub trace_id: Option<String>,
+    /// 시작 시각 (포함)
+    pub from: Option<DateTime<Utc>>,
+    /// 종료 시각 (포함)
+    pub to: Option<DateTime<Utc>>,
+    /// 최대 건수
+    pub limit: usize,
+}
+
+impl DecisionQuery {
+    fn matches(&self, record: &DecisionRecord) -> bool {
+        self.strategy_id
+            .as_deref()
+            .map_or(true, |id| record.strategy_id == id)
+            && self.ticker.as_deref().map_or(true, |t| record.ticker == t)
+            && self
+                .trace_id
+                .as_deref()
+                .map_or(true, |t| record.trace_id == t)
+            && self.from.map_or(true, |from| record.recorded_at >= from)
+            && self.to.map_or(true, |to| record.recorded_at <= to)
+    }
+}
+
+/// no_signal 기록 제한 상태: (전략, 티커) → (분 시작 시각, 해당 분의 기록 수).
+type NoSignalRate = HashMap<(String, String), (DateTime<Utc>, u32)>;
+
+/// 전략 의사결정 로그.
+///
+/// 최근 기록을 메모리에 보관하고, 구독자(DB 기록 서비스 등)에게 브로드캐스트합니다.
+pub struct DecisionLog {
+    config: DecisionLogConfig,
+    entries: Mutex<VecDeque<DecisionRecord>>,
+    rate: Mutex<NoSignalRate>,
+    dropped: AtomicU64,
+    tx: broadcast::Sender<DecisionRecord>,
+}
+
+impl Default for DecisionLog {
+    fn default() -> Self {
+        Self::new(DecisionLogConfig::default())
+    }
+}
+
+impl DecisionLog {
+    /// 설정으로 생성.
+    pub fn new(config: DecisionLogConfig) -> Self {
+        let (tx, _) = broadcast::channel(DECISION_BROADCAST_BUFFER);
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(config.capacity.min(1024))),
+            config,
+            rate: Mutex::new(HashMap::new()),
+            dropped: AtomicU64::new(0),
+            tx,
+        }
+    }
+
+    /// 기록 추가. 한도 초과로 버려지면 false.
+    pub fn record(&self, record: DecisionRecord) -> bool {
+        if record.outcome == DecisionOutcome::NoSignal && !self.allow_no_signal(&record) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
+        {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if entries.len() >= self.config.capacity {
+                entries.pop_front();
+            }
+            entries.push_back(record.clone());
+        }
+        // 구독자가 없으면 전송 실패는 무시
+        let _ = self.tx.send(record);
+        true
+    }
+
+    /// 전략·티커별 분당 `no_signal` 한도 확인.
+    ///
+    /// 티커별로 한도를 두어 유니버스 뒤쪽 티커의 기록도 남도록 합니다.
+    fn allow_no_signal(&self, record: &DecisionRecord) -> bool {
+        let limit = self.config.max_no_signal_per_minute;
+        if limit == 0 {
+            return true;
+        }
+
+        let mut rate = self.rate.lock().unwrap_or_else(|e| e.into_inner());
+        let now = record.recorded_at;
+        let key = (record.strategy_id.clone(), record.ticker.clone());
+        let (window_start, count) = rate.entry(key).or_insert((now, 0));
+        if now - *window_start >= Duration::minutes(1) {
+            *window_start = now;
+            *count = 0;
+        }
+        if *count >= limit {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+
+    /// 의사결정 이벤트 구독.
+    pub fn subscribe(&self) -> broadcast::Receiver<DecisionRecord> {
+        self.tx.subscribe()
+    }
+
+    /// 조건에 맞는 최근 기록 (최신순).
+    pub fn query(&self, query: &DecisionQuery) -> Vec<DecisionRecord> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .iter()
+            .rev()
+            .filter(|r| query.matches(r))
+            .take(query.limit)
+            .cloned()
+            .collect()
+    }
+
+    /// 한도 초과로 버려진 기록 수.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::common::signal_filters::SignalStrength;
+    use trader_core::Side;
+
+    fn no_signal(strategy_id: &str, at: DateTime<Utc>) -> DecisionRecord {
+        no_signal_for(strategy_id, "AAPL", at)
+    }
+
+    fn no_signal_for(strategy_id: &str, ticker: &str, at: DateTime<Utc>) -> DecisionRecord {
+        let mut record = DecisionRecord::new(
+            decision_trace_id(strategy_id, ticker, at),
+            strategy_id,
+            ticker,
+            DecisionStage::Strategy,
+            DecisionOutcome::NoSignal,
+        );
+        record.recorded_at = at;
+        record
+    }
+
+    #[test]
+    fn test_no_signal_records_are_rate_limited_per_strategy() {
+        let log = DecisionLog::new(DecisionLogConfig {
+            capacity: 100,
+            max_no_signal_per_minute: 2,
+        });
+        let start = Utc::now();
+
+        assert!(log.record(no_signal("a", start)));
+        assert!(log.record(no_signal("a", start)));
+        assert!(!log.record(no_signal("a", start)));
+        // 다른 전략·티커와 거부/신호 기록은 한도와 무관
+        assert!(log.record(no_signal("b", start)));
+        assert!(log.record(no_signal_for("a", "MSFT", start)));
+        let rejected = DecisionRecord::new(
+            "t",
+            "a",
+            "AAPL",
+            DecisionStage::Risk,
+            DecisionOutcome::Rejected,
+        );
+        assert!(log.record(rejected));
+        // 1분 후 한도 초기화
+        assert!(log.record(no_signal("a", start + Duration::minutes(1))));
+
+        assert_eq!(log.dropped(), 1);
+        let query = DecisionQuery {
+            strategy_id: Some("a".to_string()),
+            limit: 10,
+            ..Default::default()
+        };
+        assert_eq!(log.query(&query).len(), 5);
+    }
+
+    #[test]
+    fn test_filter_rejection_and_signal_trace() {
+        let decision = Decision::new("AAPL")
+            .condition("rsi < 30", true)
+            .indicator("rsi", 25.5)
+            .with_filter(
+                "VolumeFilter",
+                &FilteredSignal {
+                    is_valid: false,
+                    strength: SignalStrength::Weak,
+                    reason: Some("거래량 부족".to_string()),
+                },
+            );
+        assert_eq!(decision.rejected_by.as_deref(), Some("VolumeFilter"));
+        assert_eq!(decision.conditions.len(), 2);
+        assert_eq!(decision.indicators["rsi"], serde_json::json!(25.5));
+
+        let signal = Signal::entry("s", "AAPL".to_string(), Side::Buy)
+            .with_metadata(DECISION_TRACE_KEY, serde_json::json!("s:AAPL:1"));
+        let record =
+            DecisionRecord::for_signal(&signal, DecisionStage::Risk, DecisionOutcome::Rejected);
+        assert_eq!(record.trace_id, "s:AAPL:1");
+        assert_eq!(record.strategy_id, "s");
+        assert_eq!(record.signal_id, Some(signal.id));
+
+        // 엔진 인스턴스 ID가 있으면 신호의 전략 이름 대신 사용
+        let signal = signal.with_metadata(STRATEGY_INSTANCE_KEY, serde_json::json!("rsi-1"));
+        let record =
+            DecisionRecord::for_signal(&signal, DecisionStage::Risk, DecisionOutcome::Rejected);
+        assert_eq!(record.strategy_id, "rsi-1");
+    }
+}
@@ -2,15 +2,44 @@
 //!
 //! 엔진은 전략 생명주기를 관리하고, 시장 데이터를 전략에 라우팅하며,
 //! 전략으로부터 트레이딩 신호를 수집합니다.
+//!
+//! 시장 데이터는 티커 구독 인덱스를 통해 해당 티커를 구독한 전략에만 전달되며,
+//! 각 전략 인스턴스는 자체 락을 가지고 별도 태스크에서 병렬로 실행됩니다.
+//! 느리거나 패닉이 발생한 전략은 다른 전략의 처리를 막지 않습니다.
+//!
+//! 상태 저장소가 설정되면 전략 중지/주기적 스냅샷 시 `Strategy::save_state` 결과를
+//! 저장하고, 전략 시작 시 마지막 스냅샷으로 상태를 복원합니다.
+//!
+//! 각 전략은 [`StrategyLifecycle`] 상태 머신을 따르며, 상태에 따라 신호를 걸러냅니다
+//! (워밍업/일시 정지 중 신호 억제, 청산 대기 중 진입 차단).
+//!
+//! 섀도 전략([`StrategyEngine::register_shadow_strategy`])은 실전 전략과 같은 시장 데이터를
+//! 받지만, 신호가 주문 채널로 가지 않고 [`StrategySignalEvent`]로만 발행되어 가상 원장에서
+//! 체결됩니다. 실거래 체결/포지션 알림도 섀도 전략에는 전달되지 않습니다.
+//!
+//! 시장 데이터 처리마다 전략의 의사결정([`Strategy::take_decisions`])과 생성/억제된 신호를
+//! [`DecisionLog`]에 기록하고, 신호 메타데이터에 캔들 단위 추적 ID를 넣어 리스크 검증·주문
+//! 제출 결과가 같은 추적으로 이어지도록 합니다.
 
+use crate::decision::{
+    decision_trace_id, Decision, DecisionLog, DecisionOutcome, DecisionQuery, DecisionRecord,
+    DecisionStage, DECISION_TRACE_KEY, STRATEGY_INSTANCE_KEY,
+};
+use crate::lifecycle::{LifecycleAction, LifecycleTransition, StrategyLifecycle, TransitionLog};
+use crate::schedule::{ScheduleEvent, StrategyScheduler, TradingCalendar};
+use crate::state_store::{StrategySnapshot, StrategyStateStore};
+use crate::subscription::SubscriptionIndex;
 use crate::Strategy;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
-use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
+use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 use trader_core::{
     domain::{MarketDataType, StrategyContext},
@@ -38,6 +67,12 @@ pub enum EngineError {
     #[error("채널 에러: {0}")]
     ChannelError(String),
 
+    #[error("허용되지 않는 상태 전이: {0}")]
+    InvalidTransition(String),
+
+    #[error("잘못된 섀도 전략 요청: {0}")]
+    InvalidShadow(String),
+
     #[error("내부 에러: {0}")]
     InternalError(String),
 }
@@ -48,14 +83,61 @@ pub struct StrategyInstance {
     strategy: Box<dyn Strategy>,
     /// 전략 설정
     config: Value,
-    /// 전략 실행 중 여부
-    running: bool,
+    /// 생명주기 상태
+    lifecycle: StrategyLifecycle,
+    /// 워밍업 완료까지 남은 캔들 수
+    warmup_remaining: usize,
+    /// 이 전략이 보유한 포지션 티커 (청산 대기 완료 판단용)
+    open_positions: HashSet<String>,
     /// 전략 통계
     stats: StrategyStats,
     /// 사용자 지정 이름 (없으면 전략 기본 이름 사용)
     custom_name: Option<String>,
     /// 전략 컨텍스트 (다중 타임프레임 데이터 등)
     context: Arc<RwLock<StrategyContext>>,
+    /// API로 지정한 구독 티커 (None이면 `Strategy::subscribed_tickers` 사용)
+    subscription_override: Option<Vec<String>>,
+    /// 섀도 전략이면 비교 대상 실전 전략 ID
+    shadow_of: Option<String>,
+}
+
+impl StrategyInstance {
+    /// 실제 적용되는 구독 티커 (빈 목록 = 전체 티커).
+    fn effective_subscriptions(&self) -> Vec<String> {
+        self.subscription_override
+            .clone()
+            .unwrap_or_else(|| self.strategy.subscribed_tickers())
+    }
+
+    /// 현재 상태 조회.
+    fn status(&self) -> StrategyStatus {
+        // 커스텀 이름이 있으면 커스텀 이름 사용, 없으면 전략 기본 이름 사용
+        let display_name = self
+            .custom_name
+            .clone()
+            .unwrap_or_else(|| self.strategy.name().to_string());
+
+        StrategyStatus {
+            name: display_name,
+            version: self.strategy.version().to_string(),
+            description: self.strategy.description().to_string(),
+            running: self.lifecycle.is_running(),
+            lifecycle: self.lifecycle,
+            shadow_of: self.shadow_of.clone(),
+            stats: self.stats.clone(),
+            state: self.strategy.get_state(),
+        }
+    }
+}
+
+/// 전략 인스턴스별 락을 가진 공유 핸들.
+type SharedInstance = Arc<Mutex<StrategyInstance>>;
+
+/// 제한 시간을 넘겨 아직 실행 중인 전략 태스크.
+struct StalledTask {
+    handle: JoinHandle<Vec<Signal>>,
+    /// 태스크가 끝나기를 기다리며 건너뛴 이벤트 수
+    skipped: u64,
 }
 
 /// 전략 통계.
@@ -75,6 +157,9 @@ pub struct StrategyStats {
     pub started_at: Option<DateTime<Utc>>,
     /// 총 실행 시간(초)
     pub total_runtime_secs: u64,
+    /// 이전 처리가 제한 시간을 넘겨 아직 실행 중이라 건너뛴 이벤트 수
+    #[serde(default)]
+    pub ticks_skipped: u64,
 }
 
 /// 전략 상태.
@@ -88,12 +173,40 @@ pub struct StrategyStatus {
     pub description: String,
     /// 전략 실행 중 여부
     pub running: bool,
+    /// 생명주기 상태
+    #[serde(default)]
+    pub lifecycle: StrategyLifecycle,
+    /// 섀도 전략이면 비교 대상 실전 전략 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shadow_of: Option<String>,
     /// 전략 통계
     pub stats: StrategyStats,
     /// 현재 전략 상태
     pub state: Value,
 }
 
+/// 전략이 내보낸 신호 이벤트.
+///
+/// 실전 전략과 섀도 전략의 신호를 모두 포함하며, 섀도 전략의 신호는 이 이벤트로만 발행됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategySignalEvent {
+    /// 신호를 낸 전략 ID (엔진 등록 ID)
+    pub strategy_id: String,
+    /// 섀도 전략이면 비교 대상 실전 전략 ID
+    pub shadow_of: Option<String>,
+    /// 신호
+    pub signal: Signal,
+    /// 엔진이 신호를 수집한 시각
+    pub emitted_at: DateTime<Utc>,
+}
+
+impl StrategySignalEvent {
+    /// 섀도 전략의 신호인지 여부.
+    pub fn is_shadow(&self) -> bool {
+        self.shadow_of.is_some()
+    }
+}
+
 /// 엔진 설정.
 #[derive(Debug, Clone, Deserialize)]
 pub struct EngineConfig {
@@ -116,6 +229,12 @@ pub struct EngineConfig {
     /// 신호 중복 제거 윈도우(밀리초)
     #[serde(default = "default_dedup_window")]
     pub dedup_window_ms: u64,
+
+    /// 시장 데이터 1건당 전략 처리 제한 시간(밀리초, 0 = 제한 없음)
+    ///
+    /// 초과한 전략의 신호는 폐기되고, 다른 전략의 결과는 기다리지 않고 반환됩니다.
+    #[serde(default = "default_strategy_timeout")]
+    pub strategy_timeout_ms: u64,
 }
 
 fn default_max_strategies() -> usize {
@@ -133,6 +252,9 @@ fn default_true() -> bool {
 fn default_dedup_window() -> u64 {
     1000
 }
+fn default_strategy_timeout() -> u64 {
+    5000
+}
 
 impl Default for EngineConfig {
     fn default() -> Self {
@@ -142,6 +264,7 @@ impl Default for EngineConfig {
             broadcast_buffer_size: default_broadcast_buffer(),
             deduplicate_signals: default_true(),
             dedup_window_ms: default_dedup_window(),
+            strategy_timeout_ms: default_strategy_timeout(),
         }
     }
 }
@@ -153,8 +276,13 @@ pub struct StrategyEngine {
     /// 엔진 설정
     config: EngineConfig,
 
-    /// 등록된 전략들
-    strategies: Arc<RwLock<HashMap<String, StrategyInstance>>>,
+    /// 등록된 전략들 (인스턴스별 락)
+    ///
+    /// 맵 락을 잡은 채로 인스턴스 락을 기다리지 않습니다 (데드락 방지).
+    strategies: Arc<RwLock<HashMap<String, SharedInstance>>>,
+
+    /// 티커 → 전략 구독 인덱스
+    subscriptions: Arc<RwLock<SubscriptionIndex>>,
 
     /// 시장 데이터 브로드캐스터
     market_data_tx: broadcast::Sender<MarketData>,
@@ -165,30 +293,104 @@ pub struct StrategyEngine {
     /// 신호 수신기 (소비자용)
     signal_rx: Option<mpsc::Receiver<Signal>>,
 
+    /// 실전/섀도 전략 신호 이벤트 브로드캐스터
+    signal_events_tx: broadcast::Sender<StrategySignalEvent>,
+
+    /// 섀도 전략 ID → 비교 대상 실전 전략 ID
+    shadow_links: Arc<RwLock<HashMap<String, String>>>,
+
+    /// 전략 ID → 할당 자본 (목표 포트폴리오 신호의 현금 기준)
+    allocated_capital: Arc<RwLock<HashMap<String, Decimal>>>,
+
     /// 엔진 실행 상태
     running: Arc<RwLock<bool>>,
 
     /// 중복 제거를 위한 최근 신호 (signal_id -> timestamp)
     recent_signals: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+
+    /// 전략 상태 저장소 (None이면 상태 영속화 비활성화)
+    state_store: Option<Arc<dyn StrategyStateStore>>,
+
+    /// 실행 중 전략의 스케줄 트리거
+    scheduler: Mutex<StrategyScheduler>,
+
+    /// 생명주기 전이 감사 로그
+    transitions: Arc<TransitionLog>,
+
+    /// 전략 의사결정 로그
+    decisions: Arc<DecisionLog>,
+
+    /// 전략 ID → 제한 시간을 넘겨 아직 실행 중인 태스크
+    stalled: Mutex<HashMap<String, StalledTask>>,
 }
 
+/// `run()` 루프의 스케줄 확인 주기.
+const SCHEDULE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 엔진이 스스로 수행한 전이의 요청 주체.
+const ENGINE_ACTOR: &str = "engine";
+
+/// 워밍업용 과거 캔들의 데이터 출처.
+const WARMUP_SOURCE: &str = "warmup";
+
 impl StrategyEngine {
     /// 새 전략 엔진 생성.
     pub fn new(config: EngineConfig) -> Self {
         let (market_data_tx, _) = broadcast::channel(config.broadcast_buffer_size);
         let (signal_tx, signal_rx) = mpsc::channel(config.signal_buffer_size);
+        let (signal_events_tx, _) = broadcast::channel(config.broadcast_buffer_size);
 
         Self {
             config,
             strategies: Arc::new(RwLock::new(HashMap::new())),
+            subscriptions: Arc::new(RwLock::new(SubscriptionIndex::new())),
             market_data_tx,
             signal_tx,
             signal_rx: Some(signal_rx),
+            signal_events_tx,
+            shadow_links: Arc::new(RwLock::new(HashMap::new())),
+            allocated_capital: Arc::new(RwLock::new(HashMap::new())),
             running: Arc::new(RwLock::new(false)),
             recent_signals: Arc::new(RwLock::new(HashMap::new())),
+            state_store: None,
+            scheduler: Mutex::new(StrategyScheduler::default()),
+            transitions: Arc::new(TransitionLog::default()),
+            decisions: Arc::new(DecisionLog::default()),
+            stalled: Mutex::new(HashMap::new()),
         }
     }
 
+    /// 전략 상태 저장소 설정 (빌더).
+    pub fn with_state_store(mut self, store: Arc<dyn StrategyStateStore>) -> Self {
+        self.state_store = Some(store);
+        self
+    }
+
+    /// 전략 상태 저장소 설정.
+    ///
+    /// 엔진이 이미 공유 상태에 들어간 뒤 DB 연결이 준비되는 경우에 사용합니다.
+    pub fn set_state_store(&mut self, store: Arc<dyn StrategyStateStore>) {
+        self.state_store = Some(store);
+    }
+
+    /// 스케줄 트리거용 거래일 캘린더 설정 (빌더).
+    ///
+    /// 기본값은 주말만 휴장으로 보는 `WeekdayCalendar`입니다.
+    pub fn with_calendar(mut self, calendar: Arc<dyn TradingCalendar>) -> Self {
+        self.set_calendar(calendar);
+        self
+    }
+
+    /// 스케줄 트리거용 거래일 캘린더 설정.
+    pub fn set_calendar(&mut self, calendar: Arc<dyn TradingCalendar>) {
+        self.scheduler.get_mut().set_calendar(calendar);
+    }
+
+    /// 상태 저장소 설정 여부.
+    pub fn has_state_store(&self) -> bool {
+        self.state_store.is_some()
+    }
+
     /// 신호 수신기 가져오기 (한 번만 호출 가능).
     pub fn take_signal_receiver(&mut self) -> Option<mpsc::Receiver<Signal>> {
         self.signal_rx.take()
@@ -199,6 +401,13 @@ impl StrategyEngine {
         self.market_data_tx.clone()
     }
 
+    /// 실전/섀도 전략의 신호 이벤트 구독.
+    ///
+    /// 같은 시장 데이터의 신호보다 시장 데이터 브로드캐스트가 먼저 발행됩니다.
+    pub fn subscribe_signal_events(&self) -> broadcast::Receiver<StrategySignalEvent> {
+        self.signal_events_tx.subscribe()
+    }
+
     /// 전략 등록.
     ///
     /// # Arguments
@@ -209,11 +418,87 @@ impl StrategyEngine {
     pub async fn register_strategy(
         &self,
         id: impl Into<String>,
-        mut strategy: Box<dyn Strategy>,
+        strategy: Box<dyn Strategy>,
+        config: Value,
+        custom_name: Option<String>,
+    ) -> Result<(), EngineError> {
+        self.register_instance(id.into(), strategy, config, custom_name, None)
+            .await
+    }
+
+    /// 섀도 전략 등록.
+    ///
+    /// 섀도 전략은 일반 전략처럼 시작/중지하며 같은 시장 데이터를 받지만, 신호는
+    /// 주문 채널 대신 [`Self::subscribe_signal_events`]로만 발행됩니다.
+    /// 가상 체결은 [`Self::notify_shadow_order_filled`]로 전달합니다.
+    ///
+    /// # Arguments
+    /// * `id` - 섀도 전략 고유 ID
+    /// * `live_id` - 비교 대상 실전 전략 ID (섀도 전략은 지정 불가)
+    /// * `strategy` - 전략 구현체 (보통 실전 전략과 같은 타입, 다른 설정)
+    /// * `config` - 전략 설정 (JSON)
+    /// * `custom_name` - 사용자 지정 이름
+    pub async fn register_shadow_strategy(
+        &self,
+        id: impl Into<String>,
+        live_id: &str,
+        strategy: Box<dyn Strategy>,
         config: Value,
         custom_name: Option<String>,
     ) -> Result<(), EngineError> {
+        self.instance(live_id).await?;
+        if self.shadow_links.read().await.contains_key(live_id) {
+            return Err(EngineError::InvalidShadow(format!(
+                "{} is itself a shadow strategy",
+                live_id
+            )));
+        }
+
         let id = id.into();
+        self.register_instance(
+            id.clone(),
+            strategy,
+            config,
+            custom_name,
+            Some(live_id.to_string()),
+        )
+        .await?;
+        self.shadow_links
+            .write()
+            .await
+            .insert(id.clone(), live_id.to_string());
+
+        info!(strategy_id = %id, live_id = %live_id, "Registered shadow strategy");
+        Ok(())
+    }
+
+    /// 실전 전략에 연결된 섀도 전략 ID 목록 (ID 순 정렬).
+    pub async fn get_shadow_strategies(&self, live_id: &str) -> Vec<String> {
+        let mut ids: Vec<_> = self
+            .shadow_links
+            .read()
+            .await
+            .iter()
+            .filter(|(_, target)| target.as_str() == live_id)
+            .map(|(id, _)| id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// 섀도 전략의 비교 대상 실전 전략 ID (섀도 전략이 아니면 None).
+    pub async fn get_shadow_target(&self, id: &str) -> Option<String> {
+        self.shadow_links.read().await.get(id).cloned()
+    }
+
+    async fn register_instance(
+        &self,
+        id: String,
+        mut strategy: Box<dyn Strategy>,
+        config: Value,
+        custom_name: Option<String>,
+        shadow_of: Option<String>,
+    ) -> Result<(), EngineError> {
         let mut strategies = self.strategies.write().await;
 
         if strategies.len() >= self.config.max_strategies {
@@ -241,85 +526,222 @@ impl StrategyEngine {
         let context = Arc::new(RwLock::new(StrategyContext::default()));
         strategy.set_context(Arc::clone(&context));
 
-        strategies.insert(
-            id,
-            StrategyInstance {
-                strategy,
-                config,
-                running: false,
-                stats: StrategyStats::default(),
-                custom_name,
-                context,
-            },
-        );
+        let instance = StrategyInstance {
+            strategy,
+            config,
+            lifecycle: StrategyLifecycle::Stopped,
+            warmup_remaining: 0,
+            open_positions: HashSet::new(),
+            stats: StrategyStats::default(),
+            custom_name,
+            context,
+            subscription_override: None,
+            shadow_of,
+        };
+        self.subscriptions
+            .write()
+            .await
+            .set(&id, &instance.effective_subscriptions());
+        strategies.insert(id, Arc::new(Mutex::new(instance)));
 
         Ok(())
     }
 
     /// 전략 등록 해제.
     pub async fn unregister_strategy(&self, id: &str) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
+        let shared = self.instance(id).await?;
+        let instance = shared.lock().await;
 
-        if let Some(instance) = strategies.get(id) {
-            if instance.running {
-                return Err(EngineError::AlreadyRunning(format!(
-                    "Cannot unregister running strategy: {}",
-                    id
-                )));
-            }
+        if instance.lifecycle.is_running() {
+            return Err(EngineError::AlreadyRunning(format!(
+                "Cannot unregister running strategy: {}",
+                id
+            )));
         }
 
-        strategies
-            .remove(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+        // 인스턴스 락을 잡은 상태로 제거하여 확인과 제거 사이의 시작을 방지
+        self.strategies.write().await.remove(id);
+        self.subscriptions.write().await.remove(id);
+        self.shadow_links.write().await.remove(id);
+        self.allocated_capital.write().await.remove(id);
+        self.stalled.lock().await.remove(id);
+        drop(instance);
+
+        // 삭제된 전략의 스냅샷이 같은 ID로 재등록된 전략에 복원되지 않도록 제거
+        if let Some(store) = &self.state_store {
+            if let Err(e) = store.delete(id).await {
+                warn!(strategy_id = %id, error = %e, "Failed to delete strategy state snapshot");
+            }
+        }
 
         info!(strategy_id = %id, "Unregistered strategy");
         Ok(())
     }
 
     /// 전략 시작.
+    ///
+    /// 상태 저장소에 스냅샷이 있으면 초기화 후 `Strategy::load_state`로 복원합니다.
+    /// 워밍업이 필요한 전략은 `WarmingUp` 상태로 시작합니다 ([`Self::warm_up_strategy`] 참조).
     pub async fn start_strategy(&self, id: &str) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
+        self.start_strategy_as(id, ENGINE_ACTOR, "start").await
+    }
+
+    /// 전략 중지.
+    ///
+    /// 상태 저장소가 설정되어 있으면 종료 직전 상태를 스냅샷으로 저장합니다.
+    pub async fn stop_strategy(&self, id: &str) -> Result<(), EngineError> {
+        self.stop_strategy_as(id, ENGINE_ACTOR, "stop").await
+    }
+
+    /// 생명주기 동작 적용 (API, 텔레그램 등 외부 요청용).
+    ///
+    /// 모든 전이는 `actor`와 `reason`과 함께 감사 로그에 기록됩니다.
+    /// `Drain` 요청 시 보유 포지션이 없으면 바로 중지됩니다. 적용 후 상태를 반환합니다.
+    pub async fn apply_lifecycle_action(
+        &self,
+        id: &str,
+        action: LifecycleAction,
+        actor: &str,
+        reason: &str,
+    ) -> Result<StrategyLifecycle, EngineError> {
+        let target = match action {
+            LifecycleAction::Start => {
+                self.start_strategy_as(id, actor, reason).await?;
+                return self.lifecycle_of(id).await;
+            }
+            LifecycleAction::Stop => {
+                self.stop_strategy_as(id, actor, reason).await?;
+                return Ok(StrategyLifecycle::Stopped);
+            }
+            LifecycleAction::Pause => StrategyLifecycle::Paused,
+            LifecycleAction::Resume => StrategyLifecycle::Active,
+            LifecycleAction::Drain => StrategyLifecycle::Draining,
+        };
 
-        let instance = strategies
-            .get_mut(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+        let shared = self.instance(id).await?;
+        let mut instance = shared.lock().await;
+        transition(&self.transitions, id, &mut instance, target, actor, reason)?;
+        let flat = target == StrategyLifecycle::Draining && instance.open_positions.is_empty();
+        drop(instance);
 
-        if instance.running {
+        if flat {
+            self.stop_strategy_as(id, ENGINE_ACTOR, "drained").await?;
+            return Ok(StrategyLifecycle::Stopped);
+        }
+        Ok(target)
+    }
+
+    async fn start_strategy_as(
+        &self,
+        id: &str,
+        actor: &str,
+        reason: &str,
+    ) -> Result<(), EngineError> {
+        let shared = self.instance(id).await?;
+        let snapshot = self.load_snapshot(id).await;
+        let mut instance = shared.lock().await;
+
+        if instance.lifecycle.is_running() {
             return Err(EngineError::AlreadyRunning(id.to_string()));
         }
 
-        // 전략 초기화
-        instance
-            .strategy
-            .initialize(instance.config.clone())
+        transition(
+            &self.transitions,
+            id,
+            &mut instance,
+            StrategyLifecycle::Initializing,
+            actor,
+            reason,
+        )?;
+
+        let restored = match initialize_instance(id, &mut instance, snapshot).await {
+            Ok(restored) => restored,
+            Err(e) => {
+                instance.stats.last_error = Some(e.to_string());
+                transition(
+                    &self.transitions,
+                    id,
+                    &mut instance,
+                    StrategyLifecycle::Errored,
+                    ENGINE_ACTOR,
+                    &e.to_string(),
+                )?;
+                return Err(e);
+            }
+        };
+
+        // 초기화 후 설정된 티커로 구독 갱신
+        self.subscriptions
+            .write()
             .await
-            .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
+            .set(id, &instance.effective_subscriptions());
+
+        // 시작 시점 이후의 스케줄 트리거만 발생
+        self.scheduler
+            .lock()
+            .await
+            .register(id, instance.strategy.schedule_triggers(), Utc::now());
 
-        instance.running = true;
         instance.stats.started_at = Some(Utc::now());
 
+        // 스냅샷으로 상태를 복원했으면 과거 캔들을 다시 넣지 않음
+        instance.warmup_remaining = if restored {
+            0
+        } else {
+            instance
+                .strategy
+                .warmup_requirement()
+                .map_or(0, |(_, bars)| bars)
+        };
+        let next = if instance.warmup_remaining > 0 || !instance.strategy.is_ready() {
+            StrategyLifecycle::WarmingUp
+        } else {
+            StrategyLifecycle::Active
+        };
+        transition(&self.transitions, id, &mut instance, next, actor, reason)?;
+
         info!(
             strategy_id = %id,
             strategy_name = instance.strategy.name(),
+            lifecycle = %next,
             "Started strategy"
         );
 
         Ok(())
     }
 
-    /// 전략 중지.
-    pub async fn stop_strategy(&self, id: &str) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
-
-        let instance = strategies
-            .get_mut(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+    async fn stop_strategy_as(
+        &self,
+        id: &str,
+        actor: &str,
+        reason: &str,
+    ) -> Result<(), EngineError> {
+        let shared = self.instance(id).await?;
+        let mut instance = shared.lock().await;
+
+        // 오류로 중단된 전략은 이미 종료된 상태이므로 상태만 정리
+        if instance.lifecycle == StrategyLifecycle::Errored {
+            transition(
+                &self.transitions,
+                id,
+                &mut instance,
+                StrategyLifecycle::Stopped,
+                actor,
+                reason,
+            )?;
+            return Ok(());
+        }
 
-        if !instance.running {
+        if !instance.lifecycle.is_running() {
             return Err(EngineError::NotRunning(id.to_string()));
         }
 
+        let snapshot = if self.state_store.is_some() {
+            capture_snapshot(id, &instance)
+        } else {
+            None
+        };
+
         // 전략 종료
         if let Err(e) = instance.strategy.shutdown().await {
             warn!(
@@ -329,50 +751,230 @@ impl StrategyEngine {
             );
         }
 
-        instance.running = false;
+        transition(
+            &self.transitions,
+            id,
+            &mut instance,
+            StrategyLifecycle::Stopped,
+            actor,
+            reason,
+        )?;
+        self.scheduler.lock().await.remove(id);
 
         // 실행 시간 업데이트
         if let Some(started) = instance.stats.started_at {
             let runtime = Utc::now().signed_duration_since(started);
             instance.stats.total_runtime_secs += runtime.num_seconds() as u64;
         }
+        drop(instance);
+
+        if let Some(snapshot) = snapshot {
+            self.persist_snapshot(&snapshot).await;
+        }
 
         info!(strategy_id = %id, "Stopped strategy");
         Ok(())
     }
 
-    /// 전략 상태 조회.
-    pub async fn get_strategy_status(&self, id: &str) -> Result<StrategyStatus, EngineError> {
-        let strategies = self.strategies.read().await;
+    /// 전략의 현재 생명주기 상태.
+    async fn lifecycle_of(&self, id: &str) -> Result<StrategyLifecycle, EngineError> {
+        Ok(self.instance(id).await?.lock().await.lifecycle)
+    }
 
-        let instance = strategies
-            .get(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+    /// 전략을 오류 상태로 전환하고 스케줄 트리거를 해제.
+    async fn mark_errored(&self, id: &str, shared: &SharedInstance, message: String) {
+        let mut instance = shared.lock().await;
+        if transition(
+            &self.transitions,
+            id,
+            &mut instance,
+            StrategyLifecycle::Errored,
+            ENGINE_ACTOR,
+            &message,
+        )
+        .is_ok()
+        {
+            self.scheduler.lock().await.remove(id);
+        }
+        instance.stats.last_error = Some(message);
+    }
 
-        // 커스텀 이름이 있으면 커스텀 이름 사용, 없으면 전략 기본 이름 사용
-        let display_name = instance
-            .custom_name
-            .clone()
-            .unwrap_or_else(|| instance.strategy.name().to_string());
+    // =========================================================================
+    // 워밍업 / 생명주기 조회
+    // =========================================================================
 
-        Ok(StrategyStatus {
-            name: display_name,
-            version: instance.strategy.version().to_string(),
-            description: instance.strategy.description().to_string(),
-            running: instance.running,
-            stats: instance.stats.clone(),
-            state: instance.strategy.get_state(),
-        })
+    /// 전략의 워밍업 요구사항 (타임프레임, 캔들 수).
+    ///
+    /// 전략이 `WarmingUp` 상태일 때 호출자가 과거 캔들을 조회하는 데 사용합니다.
+    pub async fn get_strategy_warmup(
+        &self,
+        id: &str,
+    ) -> Result<Option<(Timeframe, usize)>, EngineError> {
+        let shared = self.instance(id).await?;
+        let instance = shared.lock().await;
+        Ok(instance.strategy.warmup_requirement())
     }
 
-    /// 전략 설정 조회.
-    pub async fn get_strategy_config(&self, id: &str) -> Result<Value, EngineError> {
-        let strategies = self.strategies.read().await;
+    /// 과거 캔들로 워밍업 중인 전략의 지표를 채움.
+    ///
+    /// 생성된 신호는 모두 버리며, 요구 캔들 수를 채우고 `Strategy::is_ready()`가 true가 되면
+    /// `Active`로 전환합니다. 워밍업 중이 아니면 아무것도 하지 않습니다.
+    /// 처리 후 생명주기 상태를 반환합니다.
+    pub async fn warm_up_strategy(
+        &self,
+        id: &str,
+        klines: Vec<Kline>,
+    ) -> Result<StrategyLifecycle, EngineError> {
+        let shared = self.instance(id).await?;
+        let mut instance = shared.lock().await;
+        if instance.lifecycle != StrategyLifecycle::WarmingUp {
+            return Ok(instance.lifecycle);
+        }
 
-        let instance = strategies
-            .get(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+        let total = klines.len();
+        for kline in klines {
+            let data = MarketData::from_kline(WARMUP_SOURCE, kline);
+            if let Err(e) = evaluate_market_data(&mut instance, &data).await {
+                warn!(strategy_id = %id, error = %e, "Strategy error during warm-up");
+            }
+            // 워밍업 캔들의 의사결정은 기록하지 않음
+            instance.strategy.take_decisions();
+            instance.warmup_remaining = instance.warmup_remaining.saturating_sub(1);
+        }
+        finish_warmup_if_ready(&self.transitions, id, &mut instance);
+
+        debug!(
+            strategy_id = %id,
+            klines = total,
+            remaining = instance.warmup_remaining,
+            lifecycle = %instance.lifecycle,
+            "Strategy warm-up fed"
+        );
+        Ok(instance.lifecycle)
+    }
+
+    /// 전략이 보유한 포지션 목록을 동기화.
+    ///
+    /// 청산 대기(`Drain`) 요청 전에 실행기의 열린 포지션으로 추적 상태를 맞출 때 사용합니다.
+    /// `notify_position_update`와 같이 구독 티커의 포지션만 보며,
+    /// `strategy_id`가 다른 전략의 포지션은 무시합니다.
+    pub async fn sync_strategy_positions(
+        &self,
+        id: &str,
+        positions: &[Position],
+    ) -> Result<(), EngineError> {
+        let shared = self.instance(id).await?;
+        // 구독 조회를 먼저 끝내고 인스턴스 락을 잡음 (전략 시작은 인스턴스 → 구독 순서로 잠금)
+        let tickers = self.get_strategy_subscriptions(id).await?;
+        let mut instance = shared.lock().await;
+        instance.open_positions = positions
+            .iter()
+            .filter(|p| {
+                p.is_open()
+                    && position_belongs_to(p, id)
+                    && (tickers.is_empty() || tickers.contains(&p.ticker))
+            })
+            .map(|p| p.ticker.clone())
+            .collect();
+        Ok(())
+    }
+
+    /// 생명주기 전이 이벤트 구독 (감사 로그 저장 등).
+    pub fn subscribe_transitions(&self) -> broadcast::Receiver<LifecycleTransition> {
+        self.transitions.subscribe()
+    }
+
+    /// 최근 생명주기 전이 기록 (최신순). `id`가 None이면 전체 전략.
+    pub fn recent_transitions(&self, id: Option<&str>, limit: usize) -> Vec<LifecycleTransition> {
+        self.transitions.recent(id, limit)
+    }
+
+    /// 전략 의사결정 로그.
+    ///
+    /// 실행 단계(리스크 검증, 주문 제출)에서 같은 추적 ID로 결과를 기록할 때 사용합니다.
+    pub fn decision_log(&self) -> Arc<DecisionLog> {
+        Arc::clone(&self.decisions)
+    }
+
+    /// 의사결정 기록 이벤트 구독 (DB 저장 등).
+    pub fn subscribe_decisions(&self) -> broadcast::Receiver<DecisionRecord> {
+        self.decisions.subscribe()
+    }
+
+    /// 조건에 맞는 최근 의사결정 기록 (최신순).
+    pub fn query_decisions(&self, query: &DecisionQuery) -> Vec<DecisionRecord> {
+        self.decisions.query(query)
+    }
+
+    /// 실행 중인 모든 전략의 상태를 저장소에 저장.
+    ///
+    /// 주기적 스냅샷과 프로세스 종료 시 호출됩니다. 저장된 스냅샷 수를 반환합니다.
+    pub async fn snapshot_all_strategies(&self) -> usize {
+        if self.state_store.is_none() {
+            return 0;
+        }
+
+        // 저장소 I/O 동안 전략 락을 잡고 있지 않도록 먼저 직렬화만 수행
+        let mut snapshots: Vec<StrategySnapshot> = Vec::new();
+        for (id, shared) in self.instances().await {
+            let instance = shared.lock().await;
+            if instance.lifecycle.is_running() {
+                snapshots.extend(capture_snapshot(&id, &instance));
+            }
+        }
+
+        let mut saved = 0;
+        for snapshot in &snapshots {
+            if self.persist_snapshot(snapshot).await {
+                saved += 1;
+            }
+        }
+
+        debug!(saved, "Strategy state snapshots saved");
+        saved
+    }
+
+    /// 저장소에서 전략의 마지막 스냅샷 조회 (실패 시 경고 후 None).
+    async fn load_snapshot(&self, id: &str) -> Option<StrategySnapshot> {
+        let store = self.state_store.as_ref()?;
+        match store.load(id).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                warn!(strategy_id = %id, error = %e, "Failed to load strategy state snapshot");
+                None
+            }
+        }
+    }
+
+    /// 스냅샷을 저장소에 저장 (실패 시 경고 후 false).
+    async fn persist_snapshot(&self, snapshot: &StrategySnapshot) -> bool {
+        let Some(store) = &self.state_store else {
+            return false;
+        };
+        match store.save(snapshot).await {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(
+                    strategy_id = %snapshot.strategy_id,
+                    error = %e,
+                    "Failed to save strategy state snapshot"
+                );
+                false
+            }
+        }
+    }
+
+    /// 전략 상태 조회.
+    pub async fn get_strategy_status(&self, id: &str) -> Result<StrategyStatus, EngineError> {
+        let shared = self.instance(id).await?;
+        let instance = shared.lock().await;
+        Ok(instance.status())
+    }
 
+    /// 전략 설정 조회.
+    pub async fn get_strategy_config(&self, id: &str) -> Result<Value, EngineError> {
+        let shared = self.instance(id).await?;
+        let instance = shared.lock().await;
         Ok(instance.config.clone())
     }
 
@@ -405,52 +1007,99 @@ impl StrategyEngine {
 
     /// 모든 전략 상태 조회.
     pub async fn get_all_statuses(&self) -> HashMap<String, StrategyStatus> {
-        let strategies = self.strategies.read().await;
         let mut statuses = HashMap::new();
 
-        for (id, instance) in strategies.iter() {
-            // 커스텀 이름이 있으면 커스텀 이름 사용, 없으면 전략 기본 이름 사용
-            let display_name = instance
-                .custom_name
-                .clone()
-                .unwrap_or_else(|| instance.strategy.name().to_string());
-
-            statuses.insert(
-                id.clone(),
-                StrategyStatus {
-                    name: display_name,
-                    version: instance.strategy.version().to_string(),
-                    description: instance.strategy.description().to_string(),
-                    running: instance.running,
-                    stats: instance.stats.clone(),
-                    state: instance.strategy.get_state(),
-                },
-            );
+        for (id, shared) in self.instances().await {
+            let status = shared.lock().await.status();
+            statuses.insert(id, status);
         }
 
         statuses
     }
 
     // =========================================================================
-    // 다중 타임프레임 지원 메서드 (Phase 1.4)
+    // 할당 자본
     // =========================================================================
 
-    /// 전략의 다중 타임프레임 설정 조회.
-    ///
-    /// # 반환
+    /// 전략의 할당 자본 조회.
+    pub async fn get_allocated_capital(&self, id: &str) -> Option<Decimal> {
+        self.allocated_capital.read().await.get(id).copied()
+    }
+
+    /// 전략의 할당 자본 설정 (`None`이면 해제).
     ///
-    /// 전략이 다중 타임프레임을 사용하면 `Some(config)`, 아니면 `None`
-    pub async fn get_strategy_multi_tf_config(
+    /// 실시간 매매에서 목표 포트폴리오 신호는 계좌 전체가 아닌 이 금액을 기준으로 분해됩니다.
+    pub async fn set_allocated_capital(
         &self,
         id: &str,
-    ) -> Result<Option<trader_core::domain::MultiTimeframeConfig>, EngineError> {
-        let strategies = self.strategies.read().await;
+        capital: Option<Decimal>,
+    ) -> Result<(), EngineError> {
+        self.instance(id).await?;
+        let mut allocations = self.allocated_capital.write().await;
+        match capital {
+            Some(capital) => allocations.insert(id.to_string(), capital),
+            None => allocations.remove(id),
+        };
+        Ok(())
+    }
 
-        let instance = strategies
-            .get(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+    // =========================================================================
+    // 티커 구독
+    // =========================================================================
 
-        Ok(instance.strategy.multi_timeframe_config())
+    /// 전략의 구독 티커 조회 (빈 목록 = 전체 티커).
+    pub async fn get_strategy_subscriptions(&self, id: &str) -> Result<Vec<String>, EngineError> {
+        self.subscriptions
+            .read()
+            .await
+            .tickers_for(id)
+            .map(|tickers| tickers.to_vec())
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))
+    }
+
+    /// 전략의 구독 티커를 동적으로 변경.
+    ///
+    /// `Some(tickers)`는 전략이 선언한 구독을 대체하고 (빈 목록 = 전체 티커),
+    /// `None`은 전략이 선언한 구독(`Strategy::subscribed_tickers`)으로 되돌립니다.
+    pub async fn set_strategy_subscriptions(
+        &self,
+        id: &str,
+        tickers: Option<Vec<String>>,
+    ) -> Result<(), EngineError> {
+        let shared = self.instance(id).await?;
+        let mut instance = shared.lock().await;
+
+        instance.subscription_override = tickers;
+        let effective = instance.effective_subscriptions();
+        self.subscriptions.write().await.set(id, &effective);
+
+        info!(strategy_id = %id, tickers = ?effective, "Updated strategy subscriptions");
+        Ok(())
+    }
+
+    /// 하나 이상의 전략이 구독 중인 티커 목록 (전체 구독 전략 제외).
+    ///
+    /// 시장 데이터 수집기가 필요한 티커만 구독할 때 사용합니다.
+    pub async fn subscribed_tickers(&self) -> Vec<String> {
+        self.subscriptions.read().await.subscribed_tickers()
+    }
+
+    // =========================================================================
+    // 다중 타임프레임 지원 메서드 (Phase 1.4)
+    // =========================================================================
+
+    /// 전략의 다중 타임프레임 설정 조회.
+    ///
+    /// # 반환
+    ///
+    /// 전략이 다중 타임프레임을 사용하면 `Some(config)`, 아니면 `None`
+    pub async fn get_strategy_multi_tf_config(
+        &self,
+        id: &str,
+    ) -> Result<Option<trader_core::domain::MultiTimeframeConfig>, EngineError> {
+        let shared = self.instance(id).await?;
+        let instance = shared.lock().await;
+        Ok(instance.strategy.multi_timeframe_config())
     }
 
     /// 전략 컨텍스트 참조 반환.
@@ -461,12 +1110,8 @@ impl StrategyEngine {
         &self,
         id: &str,
     ) -> Result<Arc<RwLock<StrategyContext>>, EngineError> {
-        let strategies = self.strategies.read().await;
-
-        let instance = strategies
-            .get(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
-
+        let shared = self.instance(id).await?;
+        let instance = shared.lock().await;
         Ok(Arc::clone(&instance.context))
     }
 
@@ -499,170 +1144,204 @@ impl StrategyEngine {
         Ok(())
     }
 
-    /// 시장 데이터 처리 및 모든 실행 중 전략에 라우팅.
+    /// 시장 데이터 처리 및 구독 중인 실행 전략에 라우팅.
+    ///
+    /// 티커를 구독한 전략만 호출하며, 각 전략은 별도 태스크에서 병렬로 실행됩니다.
+    /// `strategy_timeout_ms` 안에 끝나지 않은 전략의 신호는 폐기하고, 그 태스크가 끝날
+    /// 때까지 해당 전략에 오는 데이터는 건너뜁니다 (`ticks_skipped`로 집계).
+    /// 패닉이 발생한 전략은 에러로 기록한 뒤 다른 전략의 신호만 반환합니다.
     ///
     /// 다중 타임프레임 전략의 경우:
     /// - Secondary TF 데이터: 컨텍스트만 업데이트, 전략 재평가 안 함
     /// - Primary TF 데이터: 모든 TF 데이터와 함께 `on_multi_timeframe_data()` 호출
+    ///
+    /// 반환값과 주문 채널에는 실전 전략의 신호만 포함됩니다 (섀도 전략 신호는 이벤트로만 발행).
     pub async fn process_market_data(&self, data: MarketData) -> Result<Vec<Signal>, EngineError> {
-        let mut all_signals = Vec::new();
-        let mut strategies = self.strategies.write().await;
+        let targets = self.subscribed_instances(&data.ticker).await;
+        let data = Arc::new(data);
 
-        for (id, instance) in strategies.iter_mut() {
-            if !instance.running {
+        let mut tasks = Vec::new();
+        for (id, shared) in targets {
+            if self.skip_if_stalled(&id, &shared).await {
                 continue;
             }
+            let handle = tokio::spawn(run_strategy_on_data(
+                id.clone(),
+                Arc::clone(&shared),
+                Arc::clone(&data),
+                Arc::clone(&self.transitions),
+                Arc::clone(&self.decisions),
+            ));
+            tasks.push((id, shared, handle));
+        }
 
-            // 다중 타임프레임 전략 처리
-            let signals_result =
-                if let Some(mtf_config) = instance.strategy.multi_timeframe_config() {
-                    self.process_multi_timeframe_data(instance, &data, &mtf_config)
-                        .await
-                } else {
-                    // 일반 전략: 기존 방식대로 처리
-                    instance.strategy.on_market_data(&data).await
-                };
-
-            match signals_result {
-                Ok(signals) => {
-                    instance.stats.market_data_processed += 1;
-
-                    for signal in signals {
-                        instance.stats.signals_generated += 1;
-                        instance.stats.last_signal_time = Some(Utc::now());
-
-                        debug!(
-                            strategy_id = %id,
-                            signal_type = %signal.signal_type,
-                            ticker = %signal.ticker,
-                            side = ?signal.side,
-                            "Strategy generated signal"
-                        );
-
-                        all_signals.push(signal);
-                    }
-                }
-                Err(e) => {
-                    instance.stats.last_error = Some(e.to_string());
+        let deadline = (self.config.strategy_timeout_ms > 0).then(|| {
+            tokio::time::Instant::now() + Duration::from_millis(self.config.strategy_timeout_ms)
+        });
+        let mut outputs = Vec::new();
+
+        for (id, shared, mut handle) in tasks {
+            let result = match deadline {
+                Some(deadline) => tokio::time::timeout_at(deadline, &mut handle).await,
+                None => Ok((&mut handle).await),
+            };
+            match result {
+                Ok(Ok(signals)) => outputs.push((id, signals)),
+                Ok(Err(join_error)) => {
+                    let message = panic_message(join_error);
                     error!(
                         strategy_id = %id,
-                        error = %e,
-                        "Strategy error processing market data"
+                        error = %message,
+                        "Strategy panicked processing market data"
                     );
+                    self.mark_errored(&id, &shared, message).await;
+                }
+                Err(_) => {
+                    warn!(
+                        strategy_id = %id,
+                        ticker = %data.ticker,
+                        timeout_ms = self.config.strategy_timeout_ms,
+                        "Strategy timed out processing market data, signals discarded"
+                    );
+                    self.mark_stalled(id, handle).await;
                 }
             }
         }
 
-        // 활성화된 경우 신호 중복 제거
-        if self.config.deduplicate_signals {
-            all_signals = self.deduplicate_signals(all_signals).await;
-        }
-
-        // 출력 채널로 신호 전송
-        for signal in &all_signals {
-            if let Err(e) = self.signal_tx.send(signal.clone()).await {
-                error!(error = %e, "Failed to send signal to channel");
-            }
-        }
-
-        // 시장 데이터도 브로드캐스트
+        // 시장 데이터 브로드캐스트 (가상 원장이 신호보다 먼저 시세를 반영하도록 신호 전송 전에)
+        let data = Arc::try_unwrap(data).unwrap_or_else(|shared| (*shared).clone());
         let _ = self.market_data_tx.send(data);
 
-        Ok(all_signals)
+        Ok(self.dispatch_signals(outputs).await)
     }
 
-    /// 다중 타임프레임 데이터 처리.
+    /// 도래한 스케줄 트리거 처리.
     ///
-    /// - Kline 데이터가 아니면 기존 방식대로 `on_market_data()` 호출
-    /// - Kline 데이터면:
-    ///   - 컨텍스트에 캔들 데이터 업데이트
-    ///   - Primary TF인 경우에만 `on_multi_timeframe_data()` 호출
-    ///   - Secondary TF인 경우 빈 벡터 반환 (캐시만 업데이트)
-    async fn process_multi_timeframe_data(
-        &self,
-        instance: &mut StrategyInstance,
-        data: &MarketData,
-        mtf_config: &trader_core::domain::MultiTimeframeConfig,
-    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
-        // Kline 데이터가 아니면 일반 처리
-        let kline = match &data.data {
-            MarketDataType::Kline(k) => k.clone(),
-            _ => return instance.strategy.on_market_data(data).await,
-        };
-
-        let incoming_tf = kline.timeframe;
-        let ticker = &kline.ticker;
-
-        // 컨텍스트에 캔들 데이터 업데이트
-        {
-            let mut ctx = instance.context.write().await;
-            let candle_count = mtf_config.get_candle_count(incoming_tf);
-
-            // 기존 캔들 목록 가져오기
-            let mut klines = ctx.get_klines(ticker, incoming_tf).to_vec();
+    /// 마지막 호출 이후 `now`까지 발생 시각이 지난 트리거마다 해당 전략의 `on_schedule()`을
+    /// 발생 순서대로 호출하고, 생성된 신호를 출력 채널로 전송합니다.
+    pub async fn process_schedule(&self, now: DateTime<Utc>) -> Result<Vec<Signal>, EngineError> {
+        let events = self.scheduler.lock().await.advance(now).await;
+        let mut outputs = Vec::new();
+
+        for event in events {
+            let Ok(shared) = self.instance(&event.strategy_id).await else {
+                continue;
+            };
+            let id = event.strategy_id.clone();
+            if self.skip_if_stalled(&id, &shared).await {
+                continue;
+            }
+            let trigger_id = event.trigger_id.clone();
+            let mut handle = tokio::spawn(run_strategy_on_schedule(Arc::clone(&shared), event));
 
-            // 새 캔들 추가 (시간순 정렬 유지)
-            if klines.last().map(|k| k.open_time) != Some(kline.open_time) {
-                klines.push(kline.clone());
+            let result = if self.config.strategy_timeout_ms > 0 {
+                let timeout = Duration::from_millis(self.config.strategy_timeout_ms);
+                tokio::time::timeout(timeout, &mut handle).await
             } else {
-                // 같은 시간의 캔들이면 업데이트
-                if let Some(last) = klines.last_mut() {
-                    *last = kline.clone();
+                Ok((&mut handle).await)
+            };
+            match result {
+                Ok(Ok(signals)) => outputs.push((id, signals)),
+                Ok(Err(join_error)) => {
+                    let message = panic_message(join_error);
+                    error!(
+                        strategy_id = %id,
+                        trigger_id = %trigger_id,
+                        error = %message,
+                        "Strategy panicked processing schedule trigger"
+                    );
+                    self.mark_errored(&id, &shared, message).await;
+                }
+                Err(_) => {
+                    warn!(
+                        strategy_id = %id,
+                        trigger_id = %trigger_id,
+                        timeout_ms = self.config.strategy_timeout_ms,
+                        "Strategy timed out processing schedule trigger, signals discarded"
+                    );
+                    self.mark_stalled(id, handle).await;
                 }
             }
+        }
 
-            // 캔들 수 제한 (오래된 것부터 제거)
-            if klines.len() > candle_count {
-                klines.drain(0..klines.len() - candle_count);
-            }
+        Ok(self.dispatch_signals(outputs).await)
+    }
+
+    /// 제한 시간을 넘긴 태스크를 기록 (끝날 때까지 이후 이벤트를 건너뜀).
+    async fn mark_stalled(&self, id: String, handle: JoinHandle<Vec<Signal>>) {
+        self.stalled
+            .lock()
+            .await
+            .insert(id, StalledTask { handle, skipped: 0 });
+    }
 
-            ctx.update_klines(ticker, incoming_tf, klines);
+    /// 이전 태스크가 아직 실행 중이면 이번 이벤트를 건너뛰고 true를 반환.
+    ///
+    /// 끝난 태스크는 정리하고, 그동안 건너뛴 이벤트 수를 전략 통계에 반영합니다.
+    async fn skip_if_stalled(&self, id: &str, shared: &SharedInstance) -> bool {
+        let mut stalled = self.stalled.lock().await;
+        let Some(task) = stalled.get_mut(id) else {
+            return false;
+        };
+        if !task.handle.is_finished() {
+            task.skipped += 1;
+            debug!(strategy_id = %id, skipped = task.skipped, "Strategy still busy, event skipped");
+            return true;
         }
 
-        // Primary TF 확인
-        let primary_tf = mtf_config.get_primary_timeframe();
+        let skipped = task.skipped;
+        stalled.remove(id);
+        drop(stalled);
+        shared.lock().await.stats.ticks_skipped += skipped;
+        false
+    }
 
-        // Primary TF가 아니면 캐시만 업데이트하고 신호 생성 안 함
-        if primary_tf.is_some() && Some(incoming_tf) != primary_tf {
-            debug!(
-                ticker = %ticker,
-                incoming_tf = ?incoming_tf,
-                primary_tf = ?primary_tf,
-                "Secondary TF 데이터 캐시 업데이트 (전략 재평가 스킵)"
-            );
-            return Ok(vec![]);
+    /// 전략별 신호를 중복 제거한 뒤 발행하고 실전 전략의 신호를 반환.
+    ///
+    /// 실전 전략 신호는 주문 채널과 신호 이벤트로, 섀도 전략 신호는 신호 이벤트로만 보냅니다.
+    async fn dispatch_signals(&self, outputs: Vec<(String, Vec<Signal>)>) -> Vec<Signal> {
+        let emitted_at = Utc::now();
+        let mut events = Vec::new();
+        {
+            let shadow_links = self.shadow_links.read().await;
+            for (id, signals) in outputs {
+                let shadow_of = shadow_links.get(&id).cloned();
+                events.extend(signals.into_iter().map(|signal| StrategySignalEvent {
+                    strategy_id: id.clone(),
+                    shadow_of: shadow_of.clone(),
+                    signal,
+                    emitted_at,
+                }));
+            }
         }
 
-        // Primary TF 데이터 → 모든 타임프레임 데이터와 함께 전략 호출
-        let secondary_data = {
-            let ctx = instance.context.read().await;
-            let mut data_map: HashMap<Timeframe, Vec<Kline>> = HashMap::new();
+        // 활성화된 경우 신호 중복 제거
+        if self.config.deduplicate_signals {
+            events = self.deduplicate_signals(events).await;
+        }
 
-            for &tf in mtf_config.timeframes.keys() {
-                if Some(tf) != primary_tf {
-                    let klines = ctx.get_klines(ticker, tf).to_vec();
-                    data_map.insert(tf, klines);
+        let mut live_signals = Vec::new();
+        for event in events {
+            if !event.is_shadow() {
+                // 출력 채널로 신호 전송
+                if let Err(e) = self.signal_tx.send(event.signal.clone()).await {
+                    error!(error = %e, "Failed to send signal to channel");
                 }
+                live_signals.push(event.signal.clone());
             }
+            let _ = self.signal_events_tx.send(event);
+        }
 
-            data_map
-        };
-
-        debug!(
-            ticker = %ticker,
-            primary_tf = ?primary_tf,
-            secondary_tfs = ?secondary_data.keys().collect::<Vec<_>>(),
-            "Primary TF 완료 - 전략 재평가 실행"
-        );
-
-        instance
-            .strategy
-            .on_multi_timeframe_data(data, &secondary_data)
-            .await
+        live_signals
     }
 
     /// 중복 제거 윈도우 내 신호 중복 제거.
-    async fn deduplicate_signals(&self, signals: Vec<Signal>) -> Vec<Signal> {
+    ///
+    /// 섀도 전략의 신호는 같은 전략 타입의 실전 신호와 섞이지 않도록 전략 ID별로 구분합니다.
+    async fn deduplicate_signals(
+        &self,
+        events: Vec<StrategySignalEvent>,
+    ) -> Vec<StrategySignalEvent> {
         let mut recent = self.recent_signals.write().await;
         let now = Utc::now();
         let window_ms = self.config.dedup_window_ms as i64;
@@ -672,17 +1351,21 @@ impl StrategyEngine {
 
         let mut unique_signals = Vec::new();
 
-        for signal in signals {
-            let key = format!(
+        for event in events {
+            let signal = &event.signal;
+            let mut key = format!(
                 "{}:{}:{}:{:?}",
                 signal.strategy_id, signal.ticker, signal.signal_type, signal.side
             );
+            if event.is_shadow() {
+                key = format!("shadow:{}:{}", event.strategy_id, key);
+            }
 
             use std::collections::hash_map::Entry;
             match recent.entry(key) {
                 Entry::Vacant(entry) => {
                     entry.insert(now);
-                    unique_signals.push(signal);
+                    unique_signals.push(event);
                 }
                 Entry::Occupied(_) => {
                     debug!(
@@ -697,50 +1380,66 @@ impl StrategyEngine {
         unique_signals
     }
 
-    /// 전략에 주문 체결 알림.
+    /// 전략에 주문 체결 알림 (주문 티커를 구독한 실전 전략만).
     pub async fn notify_order_filled(&self, order: &Order) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
+        for (id, shared) in self.live_subscribed_instances(&order.ticker).await {
+            let mut instance = shared.lock().await;
+            apply_order_filled(&id, &mut instance, order).await;
+        }
 
-        for (id, instance) in strategies.iter_mut() {
-            if !instance.running {
-                continue;
-            }
+        Ok(())
+    }
 
-            if let Err(e) = instance.strategy.on_order_filled(order).await {
-                instance.stats.last_error = Some(e.to_string());
-                error!(
-                    strategy_id = %id,
-                    error = %e,
-                    "Strategy error handling order fill"
-                );
-            } else {
-                instance.stats.orders_filled += 1;
+    /// 전략에 포지션 업데이트 알림 (포지션 티커를 구독한 실전 전략만).
+    ///
+    /// 청산 대기 중인 전략의 포지션이 모두 청산되면 전략을 중지합니다.
+    pub async fn notify_position_update(&self, position: &Position) -> Result<(), EngineError> {
+        let mut drained = Vec::new();
+
+        for (id, shared) in self.live_subscribed_instances(&position.ticker).await {
+            let mut instance = shared.lock().await;
+            if apply_position_update(&id, &mut instance, position).await {
+                drained.push(id);
             }
         }
 
+        self.stop_drained(drained).await;
         Ok(())
     }
 
-    /// 전략에 포지션 업데이트 알림.
-    pub async fn notify_position_update(&self, position: &Position) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
+    /// 섀도 전략에 가상 원장의 주문 체결 알림.
+    pub async fn notify_shadow_order_filled(
+        &self,
+        id: &str,
+        order: &Order,
+    ) -> Result<(), EngineError> {
+        let shared = self.shadow_instance(id).await?;
+        let mut instance = shared.lock().await;
+        apply_order_filled(id, &mut instance, order).await;
+        Ok(())
+    }
 
-        for (id, instance) in strategies.iter_mut() {
-            if !instance.running {
-                continue;
-            }
+    /// 섀도 전략에 가상 원장의 포지션 업데이트 알림.
+    pub async fn notify_shadow_position_update(
+        &self,
+        id: &str,
+        position: &Position,
+    ) -> Result<(), EngineError> {
+        let shared = self.shadow_instance(id).await?;
+        let drained = apply_position_update(id, &mut *shared.lock().await, position).await;
+        if drained {
+            self.stop_drained(vec![id.to_string()]).await;
+        }
+        Ok(())
+    }
 
-            if let Err(e) = instance.strategy.on_position_update(position).await {
-                instance.stats.last_error = Some(e.to_string());
-                error!(
-                    strategy_id = %id,
-                    error = %e,
-                    "Strategy error handling position update"
-                );
+    /// 청산이 끝난 청산 대기 전략 중지.
+    async fn stop_drained(&self, drained: Vec<String>) {
+        for id in drained {
+            if let Err(e) = self.stop_strategy_as(&id, ENGINE_ACTOR, "drained").await {
+                error!(strategy_id = %id, error = %e, "Error stopping drained strategy");
             }
         }
-
-        Ok(())
     }
 
     /// 엔진 메인 루프 시작.
@@ -756,9 +1455,15 @@ impl StrategyEngine {
         info!("Strategy engine started");
 
         let mut market_data_rx = self.market_data_tx.subscribe();
+        let mut schedule_ticker = tokio::time::interval(SCHEDULE_POLL_INTERVAL);
 
         loop {
             tokio::select! {
+                _ = schedule_ticker.tick() => {
+                    if let Err(e) = self.process_schedule(Utc::now()).await {
+                        error!(error = %e, "Error processing schedule triggers");
+                    }
+                }
                 result = market_data_rx.recv() => {
                     match result {
                         Ok(data) => {
@@ -813,14 +1518,12 @@ impl StrategyEngine {
 
     /// 모든 실행 중 전략 중지.
     pub async fn stop_all_strategies(&self) {
-        let strategy_ids: Vec<String> = {
-            let strategies = self.strategies.read().await;
-            strategies
-                .iter()
-                .filter(|(_, inst)| inst.running)
-                .map(|(id, _)| id.clone())
-                .collect()
-        };
+        let mut strategy_ids = Vec::new();
+        for (id, shared) in self.instances().await {
+            if shared.lock().await.lifecycle.is_running() {
+                strategy_ids.push(id);
+            }
+        }
 
         for id in strategy_ids {
             if let Err(e) = self.stop_strategy(&id).await {
@@ -831,14 +1534,12 @@ impl StrategyEngine {
 
     /// 모든 등록된 전략 시작.
     pub async fn start_all_strategies(&self) -> Result<(), EngineError> {
-        let strategy_ids: Vec<String> = {
-            let strategies = self.strategies.read().await;
-            strategies
-                .iter()
-                .filter(|(_, inst)| !inst.running)
-                .map(|(id, _)| id.clone())
-                .collect()
-        };
+        let mut strategy_ids = Vec::new();
+        for (id, shared) in self.instances().await {
+            if !shared.lock().await.lifecycle.is_running() {
+                strategy_ids.push(id);
+            }
+        }
 
         for id in strategy_ids {
             self.start_strategy(&id).await?;
@@ -849,24 +1550,25 @@ impl StrategyEngine {
 
     /// 엔진 통계 조회.
     pub async fn get_engine_stats(&self) -> EngineStats {
-        let strategies = self.strategies.read().await;
+        let instances = self.instances().await;
 
         let mut total_signals = 0u64;
         let mut total_orders = 0u64;
         let mut total_data_processed = 0u64;
         let mut running_strategies = 0usize;
 
-        for instance in strategies.values() {
+        for (_, shared) in &instances {
+            let instance = shared.lock().await;
             total_signals += instance.stats.signals_generated;
             total_orders += instance.stats.orders_filled;
             total_data_processed += instance.stats.market_data_processed;
-            if instance.running {
+            if instance.lifecycle.is_running() {
                 running_strategies += 1;
             }
         }
 
         EngineStats {
-            total_strategies: strategies.len(),
+            total_strategies: instances.len(),
             running_strategies,
             total_signals_generated: total_signals,
             total_orders_filled: total_orders,
@@ -876,11 +1578,8 @@ impl StrategyEngine {
 
     /// 전략 설정 업데이트 (핫 리로드).
     pub async fn update_strategy_config(&self, id: &str, config: Value) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
-
-        let instance = strategies
-            .get_mut(id)
-            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+        let shared = self.instance(id).await?;
+        let mut instance = shared.lock().await;
 
         // config에서 name 필드 추출하여 custom_name으로 저장
         let mut config_for_strategy = config.clone();
@@ -897,7 +1596,7 @@ impl StrategyEngine {
         instance.config = config_for_strategy.clone();
 
         // 실행 중이면 전략 재초기화
-        if instance.running {
+        if instance.lifecycle.is_running() {
             info!(strategy_id = %id, "Hot reloading strategy configuration");
 
             instance
@@ -905,109 +1604,750 @@ impl StrategyEngine {
                 .initialize(config_for_strategy)
                 .await
                 .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
+
+            // 설정 변경으로 티커가 바뀌었을 수 있으므로 구독 갱신
+            self.subscriptions
+                .write()
+                .await
+                .set(id, &instance.effective_subscriptions());
         }
 
         Ok(())
     }
-}
 
-/// 엔진 통계.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EngineStats {
-    /// 등록된 전략 총 수
-    pub total_strategies: usize,
-    /// 현재 실행 중인 전략 수
-    pub running_strategies: usize,
-    /// 모든 전략에서 생성된 총 신호 수
-    pub total_signals_generated: u64,
-    /// 총 체결된 주문 수
-    pub total_orders_filled: u64,
-    /// 처리된 총 시장 데이터 이벤트 수
-    pub total_market_data_processed: u64,
-}
+    /// 전략 인스턴스 핸들 조회.
+    async fn instance(&self, id: &str) -> Result<SharedInstance, EngineError> {
+        self.strategies
+            .read()
+            .await
+            .get(id)
+            .cloned()
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use async_trait::async_trait;
+    /// 모든 전략 인스턴스 핸들 (ID 순 정렬).
+    ///
+    /// 맵 락을 해제한 뒤 반환하므로 호출자는 인스턴스 락을 자유롭게 잡을 수 있습니다.
+    async fn instances(&self) -> Vec<(String, SharedInstance)> {
+        let mut instances: Vec<_> = self
+            .strategies
+            .read()
+            .await
+            .iter()
+            .map(|(id, shared)| (id.clone(), Arc::clone(shared)))
+            .collect();
+        instances.sort_by(|a, b| a.0.cmp(&b.0));
+        instances
+    }
 
-    /// 간단한 테스트 전략.
-    struct TestStrategy {
-        name: String,
-        signal_count: u32,
+    /// 티커를 구독한 전략 인스턴스 핸들 (ID 순 정렬).
+    async fn subscribed_instances(&self, ticker: &str) -> Vec<(String, SharedInstance)> {
+        let ids = self.subscriptions.read().await.strategies_for(ticker);
+        let strategies = self.strategies.read().await;
+        ids.into_iter()
+            .filter_map(|id| {
+                let shared = Arc::clone(strategies.get(&id)?);
+                Some((id, shared))
+            })
+            .collect()
     }
 
-    impl TestStrategy {
-        fn new(name: &str) -> Self {
-            Self {
-                name: name.to_string(),
-                signal_count: 0,
-            }
-        }
+    /// 티커를 구독한 실전 전략 인스턴스 핸들 (섀도 전략 제외, ID 순 정렬).
+    async fn live_subscribed_instances(&self, ticker: &str) -> Vec<(String, SharedInstance)> {
+        let mut instances = self.subscribed_instances(ticker).await;
+        let shadow_links = self.shadow_links.read().await;
+        instances.retain(|(id, _)| !shadow_links.contains_key(id));
+        instances
     }
 
-    #[async_trait]
-    impl Strategy for TestStrategy {
-        fn name(&self) -> &str {
-            &self.name
+    /// 섀도 전략 인스턴스 핸들 조회 (섀도 전략이 아니면 에러).
+    async fn shadow_instance(&self, id: &str) -> Result<SharedInstance, EngineError> {
+        let shared = self.instance(id).await?;
+        if !self.shadow_links.read().await.contains_key(id) {
+            return Err(EngineError::InvalidShadow(format!(
+                "{} is not a shadow strategy",
+                id
+            )));
         }
+        Ok(shared)
+    }
+}
 
-        fn version(&self) -> &str {
-            "1.0.0"
-        }
+/// 전략 인스턴스에 주문 체결 전달.
+async fn apply_order_filled(id: &str, instance: &mut StrategyInstance, order: &Order) {
+    if !instance.lifecycle.is_running() {
+        return;
+    }
 
-        fn description(&self) -> &str {
-            "Test strategy"
-        }
+    if let Err(e) = instance.strategy.on_order_filled(order).await {
+        instance.stats.last_error = Some(e.to_string());
+        error!(
+            strategy_id = %id,
+            error = %e,
+            "Strategy error handling order fill"
+        );
+    } else {
+        instance.stats.orders_filled += 1;
+    }
+}
 
-        async fn initialize(
-            &mut self,
-            _config: Value,
-        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-            Ok(())
+/// 전략 인스턴스에 포지션 업데이트 전달.
+///
+/// 청산 대기 중인 전략의 포지션이 모두 청산되었으면 true를 반환합니다.
+async fn apply_position_update(
+    id: &str,
+    instance: &mut StrategyInstance,
+    position: &Position,
+) -> bool {
+    if position_belongs_to(position, id) {
+        if position.is_open() {
+            instance.open_positions.insert(position.ticker.clone());
+        } else {
+            instance.open_positions.remove(&position.ticker);
         }
+    }
+    if !instance.lifecycle.is_running() {
+        return false;
+    }
 
-        async fn on_market_data(
-            &mut self,
-            data: &MarketData,
-        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
-            self.signal_count += 1;
+    if let Err(e) = instance.strategy.on_position_update(position).await {
+        instance.stats.last_error = Some(e.to_string());
+        error!(
+            strategy_id = %id,
+            error = %e,
+            "Strategy error handling position update"
+        );
+    }
 
-            // 10개 데이터 포인트마다 신호 생성
-            if self.signal_count % 10 == 0 {
-                let signal = Signal::entry(&self.name, data.ticker.clone(), trader_core::Side::Buy);
-                Ok(vec![signal])
-            } else {
-                Ok(vec![])
+    instance.lifecycle == StrategyLifecycle::Draining && instance.open_positions.is_empty()
+}
+
+/// 단일 전략 인스턴스에서 시장 데이터 처리 (전략별 태스크에서 실행).
+async fn run_strategy_on_data(
+    id: String,
+    shared: SharedInstance,
+    data: Arc<MarketData>,
+    transitions: Arc<TransitionLog>,
+    decisions: Arc<DecisionLog>,
+) -> Vec<Signal> {
+    let mut instance = shared.lock().await;
+    if !instance.lifecycle.is_running() {
+        return Vec::new();
+    }
+
+    match evaluate_market_data(&mut instance, &data).await {
+        Ok(signals) => {
+            instance.stats.market_data_processed += 1;
+
+            // 워밍업 중에는 실시간 캔들로도 지표를 채우고 신호는 버림
+            if instance.lifecycle == StrategyLifecycle::WarmingUp {
+                instance.warmup_remaining = instance.warmup_remaining.saturating_sub(1);
+                finish_warmup_if_ready(&transitions, &id, &mut instance);
+                instance.strategy.take_decisions();
+                return Vec::new();
             }
-        }
 
-        async fn on_order_filled(
-            &mut self,
-            _order: &Order,
-        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-            Ok(())
-        }
+            let trace_id = decision_trace_id(&id, &data.ticker, data.timestamp);
+            let signals: Vec<Signal> = signals
+                .into_iter()
+                .map(|s| {
+                    s.with_metadata(DECISION_TRACE_KEY, Value::String(trace_id.clone()))
+                        .with_metadata(STRATEGY_INSTANCE_KEY, Value::String(id.clone()))
+                })
+                .collect();
+            let (signals, suppressed) = gate_signals(&id, instance.lifecycle, signals);
+            record_decisions(
+                &decisions,
+                DecisionTrace {
+                    strategy_id: &id,
+                    trace_id: &trace_id,
+                    data_time: data.timestamp,
+                    lifecycle: instance.lifecycle,
+                },
+                instance.strategy.take_decisions(),
+                &signals,
+                &suppressed,
+            );
 
-        async fn on_position_update(
-            &mut self,
-            _position: &Position,
-        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-            Ok(())
-        }
+            for signal in &signals {
+                instance.stats.signals_generated += 1;
+                instance.stats.last_signal_time = Some(Utc::now());
 
-        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-            Ok(())
-        }
+                debug!(
+                    strategy_id = %id,
+                    signal_type = %signal.signal_type,
+                    ticker = %signal.ticker,
+                    side = ?signal.side,
+                    "Strategy generated signal"
+                );
+            }
 
-        fn get_state(&self) -> Value {
-            serde_json::json!({
-                "signal_count": self.signal_count
-            })
+            signals
+        }
+        Err(e) => {
+            instance.stats.last_error = Some(e.to_string());
+            error!(
+                strategy_id = %id,
+                error = %e,
+                "Strategy error processing market data"
+            );
+            Vec::new()
         }
     }
+}
 
-    #[tokio::test]
+/// 단일 전략 인스턴스에서 스케줄 이벤트 처리 (전략별 태스크에서 실행).
+async fn run_strategy_on_schedule(shared: SharedInstance, event: ScheduleEvent) -> Vec<Signal> {
+    let mut instance = shared.lock().await;
+    if !instance.lifecycle.is_running() {
+        return Vec::new();
+    }
+
+    match instance.strategy.on_schedule(&event).await {
+        Ok(signals) => {
+            let signals: Vec<Signal> = signals
+                .into_iter()
+                .map(|s| {
+                    s.with_metadata(
+                        STRATEGY_INSTANCE_KEY,
+                        Value::String(event.strategy_id.clone()),
+                    )
+                })
+                .collect();
+            let (signals, _) = gate_signals(&event.strategy_id, instance.lifecycle, signals);
+            if !signals.is_empty() {
+                instance.stats.signals_generated += signals.len() as u64;
+                instance.stats.last_signal_time = Some(Utc::now());
+            }
+            debug!(
+                strategy_id = %event.strategy_id,
+                trigger_id = %event.trigger_id,
+                signals = signals.len(),
+                "Strategy handled schedule trigger"
+            );
+            signals
+        }
+        Err(e) => {
+            instance.stats.last_error = Some(e.to_string());
+            error!(
+                strategy_id = %event.strategy_id,
+                trigger_id = %event.trigger_id,
+                error = %e,
+                "Strategy error processing schedule trigger"
+            );
+            Vec::new()
+        }
+    }
+}
+
+/// 전략의 시장 데이터 평가 (다중 타임프레임 전략은 컨텍스트 갱신 포함).
+async fn evaluate_market_data(
+    instance: &mut StrategyInstance,
+    data: &MarketData,
+) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+    if let Some(mtf_config) = instance.strategy.multi_timeframe_config() {
+        process_multi_timeframe_data(instance, data, &mtf_config).await
+    } else {
+        // 일반 전략: 기존 방식대로 처리
+        instance.strategy.on_market_data(data).await
+    }
+}
+
+/// 생명주기 전이를 검증한 뒤 적용하고 감사 로그에 기록.
+fn transition(
+    log: &TransitionLog,
+    id: &str,
+    instance: &mut StrategyInstance,
+    to: StrategyLifecycle,
+    actor: &str,
+    reason: &str,
+) -> Result<(), EngineError> {
+    let from = instance.lifecycle;
+    if !from.can_transition_to(to) {
+        return Err(EngineError::InvalidTransition(format!(
+            "{}: {} -> {}",
+            id, from, to
+        )));
+    }
+
+    instance.lifecycle = to;
+    log.record(LifecycleTransition {
+        strategy_id: id.to_string(),
+        from,
+        to,
+        actor: actor.to_string(),
+        reason: reason.to_string(),
+        at: Utc::now(),
+    });
+    info!(
+        strategy_id = %id,
+        from = %from,
+        to = %to,
+        actor = %actor,
+        reason = %reason,
+        "Strategy lifecycle transition"
+    );
+    Ok(())
+}
+
+/// 워밍업 캔들을 모두 채웠고 전략이 준비되었으면 `Active`로 전환.
+fn finish_warmup_if_ready(log: &TransitionLog, id: &str, instance: &mut StrategyInstance) {
+    if instance.lifecycle == StrategyLifecycle::WarmingUp
+        && instance.warmup_remaining == 0
+        && instance.strategy.is_ready()
+    {
+        let _ = transition(
+            log,
+            id,
+            instance,
+            StrategyLifecycle::Active,
+            ENGINE_ACTOR,
+            "warm-up complete",
+        );
+    }
+}
+
+/// 생명주기 상태에서 허용되지 않는 신호 분리 (허용, 억제).
+///
+/// 허용된 신호는 [`StrategyLifecycle::restrict_signal`]로 상태에 맞게 제한됩니다.
+fn gate_signals(
+    id: &str,
+    lifecycle: StrategyLifecycle,
+    signals: Vec<Signal>,
+) -> (Vec<Signal>, Vec<Signal>) {
+    let (allowed, suppressed): (Vec<Signal>, Vec<Signal>) = signals
+        .into_iter()
+        .partition(|signal| lifecycle.allows_signal(signal));
+    let allowed: Vec<Signal> = allowed
+        .into_iter()
+        .map(|signal| lifecycle.restrict_signal(signal))
+        .collect();
+
+    if !suppressed.is_empty() {
+        debug!(
+            strategy_id = %id,
+            lifecycle = %lifecycle,
+            suppressed = suppressed.len(),
+            "Signals suppressed by strategy lifecycle"
+        );
+    }
+    (allowed, suppressed)
+}
+
+/// 한 번의 시장 데이터 평가에 대한 추적 정보.
+struct DecisionTrace<'a> {
+    strategy_id: &'a str,
+    trace_id: &'a str,
+    data_time: DateTime<Utc>,
+    lifecycle: StrategyLifecycle,
+}
+
+/// 전략 의사결정과 신호 생성/억제 결과를 의사결정 로그에 기록.
+///
+/// 전략이 남긴 [`Decision`]은 필터 거부 여부와 해당 종목의 신호 생성 여부로 결과를 정하고,
+/// 의사결정 없이 생성된 신호는 신호 자체로 기록합니다.
+fn record_decisions(
+    log: &DecisionLog,
+    trace: DecisionTrace<'_>,
+    decisions: Vec<Decision>,
+    allowed: &[Signal],
+    suppressed: &[Signal],
+) {
+    let emitted = || allowed.iter().chain(suppressed.iter());
+    let mut covered: HashSet<&str> = HashSet::new();
+
+    for decision in decisions {
+        let signal = emitted().find(|s| s.ticker == decision.ticker);
+        let outcome = if decision.rejected_by.is_some() {
+            DecisionOutcome::Filtered
+        } else if signal.is_some() {
+            DecisionOutcome::Emitted
+        } else {
+            DecisionOutcome::NoSignal
+        };
+        if let Some(signal) = signal {
+            covered.insert(signal.ticker.as_str());
+        }
+
+        let mut record = DecisionRecord::new(
+            trace.trace_id,
+            trace.strategy_id,
+            decision.ticker,
+            DecisionStage::Strategy,
+            outcome,
+        );
+        record.conditions = decision.conditions;
+        record.indicators = decision.indicators;
+        record.rejected_by = decision.rejected_by;
+        record.reason = decision.reason;
+        record.signal_id = signal.map(|s| s.id);
+        record.data_time = Some(trace.data_time);
+        log.record(record);
+    }
+
+    for signal in emitted().filter(|s| !covered.contains(s.ticker.as_str())) {
+        let mut record =
+            DecisionRecord::for_signal(signal, DecisionStage::Strategy, DecisionOutcome::Emitted);
+        record.strategy_id = trace.strategy_id.to_string();
+        record.data_time = Some(trace.data_time);
+        log.record(record);
+    }
+
+    for signal in suppressed {
+        let mut record = DecisionRecord::for_signal(
+            signal,
+            DecisionStage::Lifecycle,
+            DecisionOutcome::Suppressed,
+        )
+        .with_rejection("lifecycle", trace.lifecycle.to_string());
+        record.strategy_id = trace.strategy_id.to_string();
+        record.data_time = Some(trace.data_time);
+        log.record(record);
+    }
+}
+
+/// 포지션이 해당 전략 소유인지 여부 (전략 ID가 없는 포지션은 구독 전략 모두의 것으로 간주).
+fn position_belongs_to(position: &Position, id: &str) -> bool {
+    position
+        .strategy_id
+        .as_deref()
+        .map_or(true, |owner| owner == id)
+}
+
+/// 전략 초기화 후 스냅샷 복원. 스냅샷 상태를 복원했으면 true를 반환합니다.
+async fn initialize_instance(
+    id: &str,
+    instance: &mut StrategyInstance,
+    snapshot: Option<StrategySnapshot>,
+) -> Result<bool, EngineError> {
+    let config = instance.config.clone();
+
+    instance
+        .strategy
+        .initialize(config.clone())
+        .await
+        .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
+
+    let Some(snapshot) = snapshot else {
+        return Ok(false);
+    };
+    if restore_snapshot(id, instance, &snapshot) {
+        return Ok(snapshot.strategy_name == instance.strategy.name());
+    }
+
+    // 일부만 복원된 상태가 남지 않도록 다시 초기화
+    instance
+        .strategy
+        .initialize(config)
+        .await
+        .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
+    Ok(false)
+}
+
+/// 패닉한 태스크의 에러 메시지 추출.
+fn panic_message(join_error: tokio::task::JoinError) -> String {
+    if !join_error.is_panic() {
+        return join_error.to_string();
+    }
+
+    let payload = join_error.into_panic();
+    let detail = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string());
+    format!("panic: {}", detail)
+}
+
+/// 다중 타임프레임 데이터 처리.
+///
+/// - Kline 데이터가 아니면 기존 방식대로 `on_market_data()` 호출
+/// - Kline 데이터면:
+///   - 컨텍스트에 캔들 데이터 업데이트
+///   - Primary TF인 경우에만 `on_multi_timeframe_data()` 호출
+///   - Secondary TF인 경우 빈 벡터 반환 (캐시만 업데이트)
+async fn process_multi_timeframe_data(
+    instance: &mut StrategyInstance,
+    data: &MarketData,
+    mtf_config: &trader_core::domain::MultiTimeframeConfig,
+) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+    // Kline 데이터가 아니면 일반 처리
+    let kline = match &data.data {
+        MarketDataType::Kline(k) => k.clone(),
+        _ => return instance.strategy.on_market_data(data).await,
+    };
+
+    let incoming_tf = kline.timeframe;
+    let ticker = &kline.ticker;
+
+    // 컨텍스트에 캔들 데이터 업데이트
+    {
+        let mut ctx = instance.context.write().await;
+        let candle_count = mtf_config.get_candle_count(incoming_tf);
+
+        // 기존 캔들 목록 가져오기
+        let mut klines = ctx.get_klines(ticker, incoming_tf).to_vec();
+
+        // 새 캔들 추가 (시간순 정렬 유지)
+        if klines.last().map(|k| k.open_time) != Some(kline.open_time) {
+            klines.push(kline.clone());
+        } else {
+            // 같은 시간의 캔들이면 업데이트
+            if let Some(last) = klines.last_mut() {
+                *last = kline.clone();
+            }
+        }
+
+        // 캔들 수 제한 (오래된 것부터 제거)
+        if klines.len() > candle_count {
+            klines.drain(0..klines.len() - candle_count);
+        }
+
+        ctx.update_klines(ticker, incoming_tf, klines);
+    }
+
+    // Primary TF 확인
+    let primary_tf = mtf_config.get_primary_timeframe();
+
+    // Primary TF가 아니면 캐시만 업데이트하고 신호 생성 안 함
+    if primary_tf.is_some() && Some(incoming_tf) != primary_tf {
+        debug!(
+            ticker = %ticker,
+            incoming_tf = ?incoming_tf,
+            primary_tf = ?primary_tf,
+            "Secondary TF 데이터 캐시 업데이트 (전략 재평가 스킵)"
+        );
+        return Ok(vec![]);
+    }
+
+    // Primary TF 데이터 → 모든 타임프레임 데이터와 함께 전략 호출
+    let secondary_data = {
+        let ctx = instance.context.read().await;
+        let mut data_map: HashMap<Timeframe, Vec<Kline>> = HashMap::new();
+
+        for &tf in mtf_config.timeframes.keys() {
+            if Some(tf) != primary_tf {
+                let klines = ctx.get_klines(ticker, tf).to_vec();
+                data_map.insert(tf, klines);
+            }
+        }
+
+        data_map
+    };
+
+    debug!(
+        ticker = %ticker,
+        primary_tf = ?primary_tf,
+        secondary_tfs = ?secondary_data.keys().collect::<Vec<_>>(),
+        "Primary TF 완료 - 전략 재평가 실행"
+    );
+
+    instance
+        .strategy
+        .on_multi_timeframe_data(data, &secondary_data)
+        .await
+}
+
+/// 전략 상태를 스냅샷으로 직렬화.
+///
+/// 상태가 없는 전략(빈 바이트)이나 직렬화 실패 시 None을 반환합니다.
+fn capture_snapshot(id: &str, instance: &StrategyInstance) -> Option<StrategySnapshot> {
+    match instance.strategy.save_state() {
+        Ok(state) if state.is_empty() => None,
+        Ok(state) => Some(StrategySnapshot {
+            strategy_id: id.to_string(),
+            strategy_name: instance.strategy.name().to_string(),
+            strategy_version: instance.strategy.version().to_string(),
+            state,
+            saved_at: Utc::now(),
+        }),
+        Err(e) => {
+            warn!(strategy_id = %id, error = %e, "Failed to serialize strategy state");
+            None
+        }
+    }
+}
+
+/// 스냅샷으로 전략 상태 복원.
+///
+/// 다른 전략 구현의 스냅샷이면 무시하고, 복원에 실패하면 false를 반환합니다.
+fn restore_snapshot(
+    id: &str,
+    instance: &mut StrategyInstance,
+    snapshot: &StrategySnapshot,
+) -> bool {
+    if snapshot.strategy_name != instance.strategy.name() {
+        warn!(
+            strategy_id = %id,
+            snapshot_strategy = %snapshot.strategy_name,
+            "Ignoring state snapshot from a different strategy implementation"
+        );
+        return true;
+    }
+
+    match instance.strategy.load_state(&snapshot.state) {
+        Ok(()) => {
+            info!(
+                strategy_id = %id,
+                snapshot_version = %snapshot.strategy_version,
+                saved_at = %snapshot.saved_at,
+                "Restored strategy state"
+            );
+            true
+        }
+        Err(e) => {
+            warn!(
+                strategy_id = %id,
+                snapshot_version = %snapshot.strategy_version,
+                error = %e,
+                "Failed to restore strategy state, starting fresh"
+            );
+            false
+        }
+    }
+}
+
+/// 엔진 통계.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineStats {
+    /// 등록된 전략 총 수
+    pub total_strategies: usize,
+    /// 현재 실행 중인 전략 수
+    pub running_strategies: usize,
+    /// 모든 전략에서 생성된 총 신호 수
+    pub total_signals_generated: u64,
+    /// 총 체결된 주문 수
+    pub total_orders_filled: u64,
+    /// 처리된 총 시장 데이터 이벤트 수
+    pub total_market_data_processed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decision::{signal_instance_id, signal_trace_id};
+    use crate::schedule::{ScheduleMarket, ScheduleTime, ScheduleTrigger};
+    use async_trait::async_trait;
+
+    /// 간단한 테스트 전략.
+    struct TestStrategy {
+        name: String,
+        signal_count: u32,
+        decisions: Vec<Decision>,
+    }
+
+    impl TestStrategy {
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+                signal_count: 0,
+                decisions: Vec::new(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Strategy for TestStrategy {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Test strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            self.signal_count += 1;
+            let ready = self.signal_count % 10 == 0;
+            self.decisions.push(
+                Decision::new(data.ticker.clone())
+                    .condition("every_10th", ready)
+                    .indicator("count", self.signal_count),
+            );
+
+            // 10개 데이터 포인트마다 신호 생성
+            if ready {
+                let signal = Signal::entry(&self.name, data.ticker.clone(), trader_core::Side::Buy);
+                Ok(vec![signal])
+            } else {
+                Ok(vec![])
+            }
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn take_decisions(&mut self) -> Vec<Decision> {
+            std::mem::take(&mut self.decisions)
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({
+                "signal_count": self.signal_count
+            })
+        }
+
+        fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(self.signal_count.to_le_bytes().to_vec())
+        }
+
+        fn load_state(
+            &mut self,
+            data: &[u8],
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            let bytes: [u8; 4] = data.try_into()?;
+            self.signal_count = u32::from_le_bytes(bytes);
+            Ok(())
+        }
+    }
+
+    fn test_market_data(ticker: &str) -> MarketData {
+        let now = Utc::now();
+        let price = rust_decimal::Decimal::ONE;
+        let kline = Kline::new(
+            ticker.to_string(),
+            Timeframe::M1,
+            now,
+            price,
+            price,
+            price,
+            price,
+            price,
+            now,
+        );
+        MarketData::from_kline("test", kline)
+    }
+
+    #[tokio::test]
     async fn test_engine_register_strategy() {
         let engine = StrategyEngine::new(EngineConfig::default());
 
@@ -1082,4 +2422,782 @@ mod tests {
 
         assert!(matches!(result, Err(EngineError::StrategyAlreadyExists(_))));
     }
+
+    #[tokio::test]
+    async fn test_strategy_state_restored_after_restart() {
+        let store: Arc<dyn StrategyStateStore> =
+            Arc::new(crate::state_store::InMemoryStateStore::new());
+
+        let engine = StrategyEngine::new(EngineConfig::default()).with_state_store(store.clone());
+        engine
+            .register_strategy("s1", Box::new(TestStrategy::new("test")), Value::Null, None)
+            .await
+            .unwrap();
+        engine.start_strategy("s1").await.unwrap();
+        for _ in 0..3 {
+            engine
+                .process_market_data(test_market_data("BTC/USDT"))
+                .await
+                .unwrap();
+        }
+        engine.stop_strategy("s1").await.unwrap();
+
+        // 재시작된 프로세스: 새 엔진 + 새 전략 인스턴스
+        let restarted = StrategyEngine::new(EngineConfig::default()).with_state_store(store);
+        restarted
+            .register_strategy("s1", Box::new(TestStrategy::new("test")), Value::Null, None)
+            .await
+            .unwrap();
+        restarted.start_strategy("s1").await.unwrap();
+
+        let status = restarted.get_strategy_status("s1").await.unwrap();
+        assert_eq!(status.state["signal_count"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_all_and_unregister_cleanup() {
+        let store: Arc<dyn StrategyStateStore> =
+            Arc::new(crate::state_store::InMemoryStateStore::new());
+        let engine = StrategyEngine::new(EngineConfig::default()).with_state_store(store.clone());
+
+        engine
+            .register_strategy("s1", Box::new(TestStrategy::new("test")), Value::Null, None)
+            .await
+            .unwrap();
+        engine
+            .register_strategy("s2", Box::new(TestStrategy::new("test")), Value::Null, None)
+            .await
+            .unwrap();
+        engine.start_strategy("s1").await.unwrap();
+
+        // 실행 중인 전략만 스냅샷 대상
+        assert_eq!(engine.snapshot_all_strategies().await, 1);
+        assert!(store.load("s1").await.unwrap().is_some());
+        assert!(store.load("s2").await.unwrap().is_none());
+
+        engine.stop_strategy("s1").await.unwrap();
+        engine.unregister_strategy("s1").await.unwrap();
+        assert!(store.load("s1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_snapshot_starts_fresh() {
+        let store: Arc<dyn StrategyStateStore> =
+            Arc::new(crate::state_store::InMemoryStateStore::new());
+        store
+            .save(&StrategySnapshot {
+                strategy_id: "s1".to_string(),
+                strategy_name: "test".to_string(),
+                strategy_version: "1.0.0".to_string(),
+                state: vec![1, 2],
+                saved_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        let engine = StrategyEngine::new(EngineConfig::default()).with_state_store(store);
+        engine
+            .register_strategy("s1", Box::new(TestStrategy::new("test")), Value::Null, None)
+            .await
+            .unwrap();
+        engine.start_strategy("s1").await.unwrap();
+
+        let status = engine.get_strategy_status("s1").await.unwrap();
+        assert!(status.running);
+        assert_eq!(status.state["signal_count"], 0);
+    }
+
+    /// 티커 구독/지연/패닉 동작을 지정할 수 있는 테스트 전략.
+    struct RoutedStrategy {
+        tickers: Vec<String>,
+        delay_ms: u64,
+        panic: bool,
+    }
+
+    impl RoutedStrategy {
+        fn subscribed(tickers: &[&str]) -> Self {
+            Self {
+                tickers: tickers.iter().map(|t| t.to_string()).collect(),
+                delay_ms: 0,
+                panic: false,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Strategy for RoutedStrategy {
+        fn name(&self) -> &str {
+            "routed"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Routed test strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            if self.panic {
+                panic!("strategy bug");
+            }
+            if self.delay_ms > 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
+            }
+            Ok(vec![Signal::entry(
+                "routed",
+                data.ticker.clone(),
+                trader_core::Side::Buy,
+            )])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn subscribed_tickers(&self) -> Vec<String> {
+            self.tickers.clone()
+        }
+
+        fn get_state(&self) -> Value {
+            Value::Null
+        }
+    }
+
+    async fn start_routed(engine: &StrategyEngine, id: &str, strategy: RoutedStrategy) {
+        engine
+            .register_strategy(id, Box::new(strategy), Value::Null, None)
+            .await
+            .unwrap();
+        engine.start_strategy(id).await.unwrap();
+    }
+
+    fn no_dedup_config() -> EngineConfig {
+        EngineConfig {
+            deduplicate_signals: false,
+            ..EngineConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_market_data_routed_to_subscribers_only() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        start_routed(&engine, "kr", RoutedStrategy::subscribed(&["005930"])).await;
+        start_routed(&engine, "us", RoutedStrategy::subscribed(&["AAPL"])).await;
+
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+
+        let kr = engine.get_strategy_status("kr").await.unwrap();
+        let us = engine.get_strategy_status("us").await.unwrap();
+        assert_eq!(kr.stats.market_data_processed, 1);
+        assert_eq!(us.stats.market_data_processed, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_subscription_update() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        start_routed(&engine, "s1", RoutedStrategy::subscribed(&["005930"])).await;
+
+        engine
+            .set_strategy_subscriptions("s1", Some(vec!["000660".to_string()]))
+            .await
+            .unwrap();
+        assert_eq!(
+            engine.get_strategy_subscriptions("s1").await.unwrap(),
+            vec!["000660".to_string()]
+        );
+        assert_eq!(
+            engine.subscribed_tickers().await,
+            vec!["000660".to_string()]
+        );
+
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        assert!(signals.is_empty());
+
+        // 전략이 선언한 구독으로 복귀
+        engine.set_strategy_subscriptions("s1", None).await.unwrap();
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_slow_strategy_does_not_stall_others() {
+        let engine = StrategyEngine::new(EngineConfig {
+            strategy_timeout_ms: 50,
+            ..no_dedup_config()
+        });
+        let slow = RoutedStrategy {
+            delay_ms: 5_000,
+            ..RoutedStrategy::subscribed(&["005930"])
+        };
+        start_routed(&engine, "fast", RoutedStrategy::subscribed(&["005930"])).await;
+        start_routed(&engine, "slow", slow).await;
+
+        let started = std::time::Instant::now();
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+
+        assert!(started.elapsed() < std::time::Duration::from_secs(2));
+        assert_eq!(signals.len(), 1);
+        let fast = engine.get_strategy_status("fast").await.unwrap();
+        assert_eq!(fast.stats.market_data_processed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_strategy_skips_data_until_finished() {
+        let engine = StrategyEngine::new(EngineConfig {
+            strategy_timeout_ms: 50,
+            ..no_dedup_config()
+        });
+        let slow = RoutedStrategy {
+            delay_ms: 300,
+            ..RoutedStrategy::subscribed(&["005930"])
+        };
+        start_routed(&engine, "fast", RoutedStrategy::subscribed(&["005930"])).await;
+        start_routed(&engine, "slow", slow).await;
+
+        // 첫 데이터에서 제한 시간 초과, 태스크가 끝날 때까지 다음 데이터는 건너뜀
+        for _ in 0..2 {
+            let signals = engine
+                .process_market_data(test_market_data("005930"))
+                .await
+                .unwrap();
+            assert_eq!(signals.len(), 1);
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+        engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+
+        let slow = engine.get_strategy_status("slow").await.unwrap();
+        assert_eq!(slow.stats.ticks_skipped, 1);
+        assert_eq!(slow.stats.market_data_processed, 2);
+        let fast = engine.get_strategy_status("fast").await.unwrap();
+        assert_eq!(fast.stats.market_data_processed, 3);
+        assert_eq!(fast.stats.ticks_skipped, 0);
+    }
+
+    #[tokio::test]
+    async fn test_panicking_strategy_is_isolated() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        let buggy = RoutedStrategy {
+            panic: true,
+            ..RoutedStrategy::subscribed(&[])
+        };
+        start_routed(&engine, "buggy", buggy).await;
+        start_routed(&engine, "healthy", RoutedStrategy::subscribed(&[])).await;
+
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+
+        let buggy = engine.get_strategy_status("buggy").await.unwrap();
+        assert!(buggy.stats.last_error.unwrap().contains("strategy bug"));
+
+        // 패닉 이후에도 엔진은 계속 동작
+        let signals = engine
+            .process_market_data(test_market_data("000660"))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+    }
+    /// 매일 자정(UTC) 트리거마다 신호를 내는 스케줄 전략.
+    struct ScheduledStrategy;
+
+    #[async_trait]
+    impl Strategy for ScheduledStrategy {
+        fn name(&self) -> &str {
+            "scheduled"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Scheduled test strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            _data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(vec![])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn schedule_triggers(&self) -> Vec<ScheduleTrigger> {
+            vec![ScheduleTrigger::daily(
+                "midnight",
+                ScheduleMarket::Crypto,
+                ScheduleTime::at(chrono::NaiveTime::MIN),
+            )]
+        }
+
+        async fn on_schedule(
+            &mut self,
+            event: &ScheduleEvent,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            let signal = Signal::entry("scheduled", "BTC/USDT".to_string(), trader_core::Side::Buy)
+                .with_metadata("trading_date", serde_json::json!(event.trading_date));
+            Ok(vec![signal])
+        }
+
+        fn get_state(&self) -> Value {
+            Value::Null
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schedule_triggers_call_on_schedule() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        engine
+            .register_strategy("sched", Box::new(ScheduledStrategy), Value::Null, None)
+            .await
+            .unwrap();
+
+        // 시작 전에는 트리거가 등록되지 않음
+        let later = Utc::now() + chrono::Duration::days(2);
+        assert!(engine.process_schedule(later).await.unwrap().is_empty());
+
+        engine.start_strategy("sched").await.unwrap();
+        let signals = engine.process_schedule(later).await.unwrap();
+        assert_eq!(signals.len(), 2);
+        let status = engine.get_strategy_status("sched").await.unwrap();
+        assert_eq!(status.stats.signals_generated, 2);
+
+        // 이미 처리한 구간은 다시 발생하지 않고, 중지하면 트리거가 해제됨
+        assert!(engine.process_schedule(later).await.unwrap().is_empty());
+        engine.stop_strategy("sched").await.unwrap();
+        let signals = engine
+            .process_schedule(later + chrono::Duration::days(3))
+            .await
+            .unwrap();
+        assert!(signals.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pause_suppresses_signals_and_records_transitions() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        start_routed(&engine, "s1", RoutedStrategy::subscribed(&[])).await;
+
+        let state = engine
+            .apply_lifecycle_action("s1", LifecycleAction::Pause, "api", "manual pause")
+            .await
+            .unwrap();
+        assert_eq!(state, StrategyLifecycle::Paused);
+
+        // 일시 정지 중에도 데이터는 처리하지만 신호는 내보내지 않음
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        assert!(signals.is_empty());
+        let status = engine.get_strategy_status("s1").await.unwrap();
+        assert!(status.running);
+        assert_eq!(status.stats.market_data_processed, 1);
+
+        engine
+            .apply_lifecycle_action("s1", LifecycleAction::Resume, "api", "")
+            .await
+            .unwrap();
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+
+        let history = engine.recent_transitions(Some("s1"), 10);
+        let path: Vec<_> = history.iter().rev().map(|t| t.to).collect();
+        assert_eq!(
+            path,
+            vec![
+                StrategyLifecycle::Initializing,
+                StrategyLifecycle::Active,
+                StrategyLifecycle::Paused,
+                StrategyLifecycle::Active,
+            ]
+        );
+        assert_eq!(history[1].actor, "api");
+        assert_eq!(history[1].reason, "manual pause");
+
+        // 중지된 전략은 일시 정지할 수 없음
+        engine.stop_strategy("s1").await.unwrap();
+        let result = engine
+            .apply_lifecycle_action("s1", LifecycleAction::Pause, "api", "")
+            .await;
+        assert!(matches!(result, Err(EngineError::InvalidTransition(_))));
+    }
+
+    #[tokio::test]
+    async fn test_decisions_traced_from_candle_to_signal() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        engine
+            .register_strategy("s1", Box::new(TestStrategy::new("s1")), Value::Null, None)
+            .await
+            .unwrap();
+        engine.start_strategy("s1").await.unwrap();
+
+        let mut signals = Vec::new();
+        for _ in 0..10 {
+            signals.extend(
+                engine
+                    .process_market_data(test_market_data("005930"))
+                    .await
+                    .unwrap(),
+            );
+        }
+        assert_eq!(signals.len(), 1);
+        let trace_id = signal_trace_id(&signals[0]).unwrap().to_string();
+        assert_eq!(signal_instance_id(&signals[0]), Some("s1"));
+
+        let query = DecisionQuery {
+            strategy_id: Some("s1".to_string()),
+            limit: 100,
+            ..DecisionQuery::default()
+        };
+        let records = engine.query_decisions(&query);
+        assert_eq!(records.len(), 10);
+        assert_eq!(
+            records
+                .iter()
+                .filter(|r| r.outcome == DecisionOutcome::NoSignal)
+                .count(),
+            9
+        );
+
+        // 신호를 낸 캔들의 기록은 조건/지표와 신호 ID를 함께 가짐
+        let emitted = &records[0];
+        assert_eq!(emitted.outcome, DecisionOutcome::Emitted);
+        assert_eq!(emitted.trace_id, trace_id);
+        assert_eq!(emitted.signal_id, Some(signals[0].id));
+        assert!(emitted.conditions[0].passed);
+        assert_eq!(emitted.indicators["count"], serde_json::json!(10));
+
+        // 일시 정지 중 억제된 신호도 사유와 함께 기록
+        start_routed(&engine, "s2", RoutedStrategy::subscribed(&[])).await;
+        engine
+            .apply_lifecycle_action("s2", LifecycleAction::Pause, "api", "")
+            .await
+            .unwrap();
+        engine
+            .process_market_data(test_market_data("000660"))
+            .await
+            .unwrap();
+        let query = DecisionQuery {
+            strategy_id: Some("s2".to_string()),
+            limit: 100,
+            ..DecisionQuery::default()
+        };
+        let records = engine.query_decisions(&query);
+        let suppressed = records
+            .iter()
+            .find(|r| r.outcome == DecisionOutcome::Suppressed)
+            .unwrap();
+        assert_eq!(suppressed.stage, DecisionStage::Lifecycle);
+        assert_eq!(suppressed.rejected_by.as_deref(), Some("lifecycle"));
+    }
+
+    #[tokio::test]
+    async fn test_shadow_signals_bypass_order_channel() {
+        // 중복 제거를 켠 상태에서도 섀도 신호가 같은 타입의 실전 신호와 섞이지 않아야 함
+        let mut engine = StrategyEngine::new(EngineConfig::default());
+        let mut signal_rx = engine.take_signal_receiver().unwrap();
+        let mut events = engine.subscribe_signal_events();
+
+        start_routed(&engine, "live", RoutedStrategy::subscribed(&["005930"])).await;
+        engine
+            .register_shadow_strategy(
+                "shadow",
+                "live",
+                Box::new(RoutedStrategy::subscribed(&["005930"])),
+                Value::Null,
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("shadow").await.unwrap();
+
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+        assert!(signal_rx.try_recv().is_ok());
+        assert!(signal_rx.try_recv().is_err());
+
+        let mut emitted: Vec<_> = std::iter::from_fn(|| events.try_recv().ok()).collect();
+        emitted.sort_by(|a, b| a.strategy_id.cmp(&b.strategy_id));
+        assert_eq!(emitted.len(), 2);
+        assert_eq!(emitted[0].strategy_id, "live");
+        assert!(!emitted[0].is_shadow());
+        assert_eq!(emitted[1].shadow_of.as_deref(), Some("live"));
+
+        let status = engine.get_strategy_status("shadow").await.unwrap();
+        assert_eq!(status.shadow_of.as_deref(), Some("live"));
+        assert_eq!(engine.get_shadow_strategies("live").await, vec!["shadow"]);
+
+        // 실거래 체결은 섀도 전략에 전달되지 않고, 가상 체결은 섀도 전략에만 전달
+        let order = Order::from_request(
+            trader_core::OrderRequest::market_buy("005930".to_string(), rust_decimal::Decimal::ONE),
+            "test",
+        );
+        engine.notify_order_filled(&order).await.unwrap();
+        engine
+            .notify_shadow_order_filled("shadow", &order)
+            .await
+            .unwrap();
+        let live = engine.get_strategy_status("live").await.unwrap();
+        let shadow = engine.get_strategy_status("shadow").await.unwrap();
+        assert_eq!(live.stats.orders_filled, 1);
+        assert_eq!(shadow.stats.orders_filled, 1);
+
+        assert!(matches!(
+            engine.notify_shadow_order_filled("live", &order).await,
+            Err(EngineError::InvalidShadow(_))
+        ));
+        let nested = engine
+            .register_shadow_strategy(
+                "nested",
+                "shadow",
+                Box::new(RoutedStrategy::subscribed(&[])),
+                Value::Null,
+                None,
+            )
+            .await;
+        assert!(matches!(nested, Err(EngineError::InvalidShadow(_))));
+    }
+
+    #[tokio::test]
+    async fn test_drain_blocks_entries_and_stops_when_flat() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        start_routed(&engine, "s1", RoutedStrategy::subscribed(&["005930"])).await;
+
+        let mut position = Position::new(
+            "test",
+            "005930".to_string(),
+            trader_core::Side::Buy,
+            rust_decimal::Decimal::TEN,
+            rust_decimal::Decimal::ONE,
+        )
+        .with_strategy("s1");
+        engine.notify_position_update(&position).await.unwrap();
+
+        let state = engine
+            .apply_lifecycle_action("s1", LifecycleAction::Drain, "telegram", "")
+            .await
+            .unwrap();
+        assert_eq!(state, StrategyLifecycle::Draining);
+
+        // 신규 진입 신호는 차단
+        let signals = engine
+            .process_market_data(test_market_data("005930"))
+            .await
+            .unwrap();
+        assert!(signals.is_empty());
+
+        // 포지션이 청산되면 자동 중지
+        position.close(rust_decimal::Decimal::ONE);
+        engine.notify_position_update(&position).await.unwrap();
+        let status = engine.get_strategy_status("s1").await.unwrap();
+        assert_eq!(status.lifecycle, StrategyLifecycle::Stopped);
+        assert_eq!(
+            engine.recent_transitions(Some("s1"), 1)[0].reason,
+            "drained"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drain_without_positions_stops_immediately() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        start_routed(&engine, "s1", RoutedStrategy::subscribed(&[])).await;
+
+        let state = engine
+            .apply_lifecycle_action("s1", LifecycleAction::Drain, "api", "")
+            .await
+            .unwrap();
+        assert_eq!(state, StrategyLifecycle::Stopped);
+    }
+
+    /// 지정한 캔들 수만큼 워밍업이 필요한 전략.
+    struct WarmupStrategy {
+        seen: usize,
+    }
+
+    #[async_trait]
+    impl Strategy for WarmupStrategy {
+        fn name(&self) -> &str {
+            "warmup"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Warm-up test strategy"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            self.seen += 1;
+            Ok(vec![Signal::entry(
+                "warmup",
+                data.ticker.clone(),
+                trader_core::Side::Buy,
+            )])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn warmup_requirement(&self) -> Option<(Timeframe, usize)> {
+            Some((Timeframe::M1, 3))
+        }
+
+        fn is_ready(&self) -> bool {
+            self.seen >= 3
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({ "seen": self.seen })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warm_up_suppresses_signals_until_ready() {
+        let engine = StrategyEngine::new(no_dedup_config());
+        engine
+            .register_strategy(
+                "w1",
+                Box::new(WarmupStrategy { seen: 0 }),
+                Value::Null,
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("w1").await.unwrap();
+
+        let status = engine.get_strategy_status("w1").await.unwrap();
+        assert_eq!(status.lifecycle, StrategyLifecycle::WarmingUp);
+        assert_eq!(
+            engine.get_strategy_warmup("w1").await.unwrap(),
+            Some((Timeframe::M1, 3))
+        );
+
+        // 과거 캔들 2개로는 부족
+        let history: Vec<Kline> = (0..2)
+            .map(|_| match test_market_data("BTC/USDT").data {
+                MarketDataType::Kline(kline) => kline,
+                _ => unreachable!(),
+            })
+            .collect();
+        let state = engine.warm_up_strategy("w1", history).await.unwrap();
+        assert_eq!(state, StrategyLifecycle::WarmingUp);
+
+        // 실시간 캔들로 워밍업을 마치고, 그 캔들의 신호는 버림
+        let signals = engine
+            .process_market_data(test_market_data("BTC/USDT"))
+            .await
+            .unwrap();
+        assert!(signals.is_empty());
+        let status = engine.get_strategy_status("w1").await.unwrap();
+        assert_eq!(status.lifecycle, StrategyLifecycle::Active);
+
+        let signals = engine
+            .process_market_data(test_market_data("BTC/USDT"))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+    }
 }
@@ -5,6 +5,10 @@
 //! - 동적 전략 로딩을 위한 플러그인 로더
 //! - 전략 실행 엔진
 //! - 내장 전략 (그리드 트레이딩, RSI 평균 회귀)
+//! - 런타임 로드 스크립트 전략 (Rhai)
+//! - 거래일 캘린더 기반 스케줄 트리거
+//! - 전략 생명주기 상태 머신 (워밍업, 일시 정지, 청산 대기)
+//! - 전략 의사결정 로그 (조건 평가·필터 거부·리스크 거부 추적)
 //!
 //! # 예제
 //!
@@ -30,24 +34,51 @@
 //! }
 //! ```
 
+pub mod decision;
 pub mod engine;
+pub mod lifecycle;
 pub mod macros;
 pub mod plugin;
 pub mod registry;
+pub mod schedule;
 pub mod schema_composer;
 pub mod schema_registry;
+pub mod state_store;
 pub mod strategies;
+pub mod subscription;
 pub mod traits;
 
 // 주요 타입 재내보내기
+pub use decision::{
+    decision_trace_id, signal_instance_id, signal_trace_id, ConditionCheck, Decision, DecisionLog,
+    DecisionLogConfig, DecisionOutcome, DecisionQuery, DecisionRecord, DecisionStage,
+    DECISION_TRACE_KEY, STRATEGY_INSTANCE_KEY,
+};
 pub use engine::{
-    EngineConfig, EngineError, EngineStats, StrategyEngine, StrategyStats, StrategyStatus,
+    EngineConfig, EngineError, EngineStats, StrategyEngine, StrategySignalEvent, StrategyStats,
+    StrategyStatus,
+};
+pub use lifecycle::{LifecycleAction, LifecycleTransition, StrategyLifecycle, TransitionLog};
+pub use plugin::{
+    BuiltinStrategyFactory, LoaderConfig, PluginError, PluginLoader, PluginMetadata, PluginSignal,
+    WasmLimits, WasmStrategy, WASM_ABI_VERSION,
 };
-pub use plugin::{BuiltinStrategyFactory, LoaderConfig, PluginError, PluginLoader, PluginMetadata};
 pub use registry::{StrategyCategory, StrategyMeta, StrategyRegistry};
+pub use schedule::{
+    ScheduleEvent, ScheduleMarket, ScheduleRule, ScheduleTime, ScheduleTrigger, StrategyScheduler,
+    TradingCalendar, WeekdayCalendar,
+};
 pub use schema_composer::SchemaComposer;
 pub use schema_registry::FragmentRegistry;
-pub use strategies::{MeanReversionConfig, MeanReversionStrategy, MeanReversionVariant};
+pub use state_store::{
+    decode_versioned_state, encode_versioned_state, InMemoryStateStore, StateStoreError,
+    StrategySnapshot, StrategyStateStore,
+};
+pub use strategies::{
+    rule_catalog, MeanReversionConfig, MeanReversionStrategy, MeanReversionVariant, RuleCatalog,
+    RuleError, RuleStrategy, ScriptError, ScriptStrategy, RULE_STRATEGY_ID, SCRIPT_STRATEGY_ID,
+};
+pub use subscription::SubscriptionIndex;
 pub use traits::{Strategy, StrategyMetadata};
 
 // 프로시저 매크로 재내보내기
@@ -0,0 +1,312 @@
+//! 전략 생명주기 상태 머신.
+//!
+//! ```text
+//!            start                 워밍업 완료
+//! Stopped ─────────▶ Initializing ──▶ WarmingUp ──────▶ Active ◀──▶ Paused
+//!    ▲                   │                               │  ▲         │
+//!    │                   ▼ 초기화 실패                    ▼  │ resume  │ drain
+//!    │                Errored                          Draining ◀─────┘
+//!    └──────────── stop (모든 실행 상태) / 청산 완료 ◀──────┘
+//! ```
+//!
+//! - `WarmingUp`: 과거 캔들로 지표를 채우는 중. 신호는 모두 버립니다.
+//! - `Paused`: 시장 데이터는 계속 받지만 신호를 내보내지 않습니다.
+//! - `Draining`: 신규 진입은 막고 청산 신호만 통과시키며, 포지션이 모두 청산되면 중지됩니다.
+//!   목표 포트폴리오(`Rebalance`) 신호는 모든 목표 비중을 0으로 바꿔 매도만 하도록 통과시킵니다.
+//!
+//! 모든 전이는 [`LifecycleTransition`]으로 기록되어 감사 로그와 구독자에게 전달됩니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+use trader_core::{Signal, SignalType};
+
+/// 메모리에 보관하는 최근 전이 기록 수.
+const DEFAULT_TRANSITION_HISTORY: usize = 1000;
+
+/// 전이 이벤트 브로드캐스트 버퍼 크기.
+const TRANSITION_BROADCAST_BUFFER: usize = 256;
+
+/// 전략 생명주기 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StrategyLifecycle {
+    /// 초기화 중
+    Initializing,
+    /// 과거 데이터로 지표 워밍업 중 (신호 억제)
+    WarmingUp,
+    /// 정상 실행
+    Active,
+    /// 일시 정지 (데이터 수신, 신호 억제)
+    Paused,
+    /// 청산 대기 (신규 진입 차단, 청산만 허용)
+    Draining,
+    /// 중지
+    #[default]
+    Stopped,
+    /// 오류로 중단
+    Errored,
+}
+
+impl StrategyLifecycle {
+    /// 시장 데이터를 처리하는 상태인지 여부.
+    pub fn is_running(self) -> bool {
+        matches!(
+            self,
+            StrategyLifecycle::WarmingUp
+                | StrategyLifecycle::Active
+                | StrategyLifecycle::Paused
+                | StrategyLifecycle::Draining
+        )
+    }
+
+    /// 허용된 상태 전이인지 확인.
+    pub fn can_transition_to(self, next: StrategyLifecycle) -> bool {
+        use StrategyLifecycle::*;
+        match (self, next) {
+            (Stopped | Errored, Initializing) => true,
+            (Initializing, WarmingUp | Active | Errored) => true,
+            (WarmingUp, Active) => true,
+            (Active, Paused | Draining) => true,
+            (Paused, Active | Draining) => true,
+            (Draining, Active) => true,
+            // 실행 중 상태는 언제든 중지되거나 오류로 전환될 수 있음
+            (WarmingUp | Active | Paused | Draining, Stopped | Errored) => true,
+            (Errored, Stopped) => true,
+            _ => false,
+        }
+    }
+
+    /// 이 상태에서 신호를 내보낼 수 있는지 확인.
+    ///
+    /// `Draining` 상태에서는 청산/축소 신호와 목표 포트폴리오 신호만 허용합니다.
+    /// 목표 포트폴리오 신호는 [`Self::restrict_signal`]로 매도 전용으로 바꿔야 합니다.
+    pub fn allows_signal(self, signal: &Signal) -> bool {
+        match self {
+            StrategyLifecycle::Active => true,
+            StrategyLifecycle::Draining => matches!(
+                signal.signal_type,
+                SignalType::Exit | SignalType::ReducePosition | SignalType::Rebalance
+            ),
+            _ => false,
+        }
+    }
+
+    /// 허용된 신호를 이 상태에 맞게 제한합니다.
+    ///
+    /// `Draining` 상태의 목표 포트폴리오 신호는 모든 목표 비중을 0으로 바꿔
+    /// 보유 종목을 매도만 하도록 합니다.
+    pub fn restrict_signal(self, mut signal: Signal) -> Signal {
+        if self == StrategyLifecycle::Draining && signal.is_rebalance() {
+            for weight in signal.target_weights.values_mut() {
+                *weight = Decimal::ZERO;
+            }
+        }
+        signal
+    }
+
+    /// 상태 문자열 (snake_case).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StrategyLifecycle::Initializing => "initializing",
+            StrategyLifecycle::WarmingUp => "warming_up",
+            StrategyLifecycle::Active => "active",
+            StrategyLifecycle::Paused => "paused",
+            StrategyLifecycle::Draining => "draining",
+            StrategyLifecycle::Stopped => "stopped",
+            StrategyLifecycle::Errored => "errored",
+        }
+    }
+}
+
+impl std::fmt::Display for StrategyLifecycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 외부(API, 텔레그램 등)에서 요청하는 생명주기 동작.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleAction {
+    /// 시작 (초기화 → 워밍업/실행)
+    Start,
+    /// 일시 정지
+    Pause,
+    /// 재개 (일시 정지/청산 대기 취소)
+    Resume,
+    /// 청산 후 중지
+    Drain,
+    /// 즉시 중지
+    Stop,
+}
+
+impl LifecycleAction {
+    /// 동작 문자열 (snake_case).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LifecycleAction::Start => "start",
+            LifecycleAction::Pause => "pause",
+            LifecycleAction::Resume => "resume",
+            LifecycleAction::Drain => "drain",
+            LifecycleAction::Stop => "stop",
+        }
+    }
+}
+
+/// 생명주기 전이 기록 (감사 로그).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifecycleTransition {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 이전 상태
+    pub from: StrategyLifecycle,
+    /// 다음 상태
+    pub to: StrategyLifecycle,
+    /// 요청 주체 (예: "api", "telegram", "engine")
+    pub actor: String,
+    /// 전이 사유
+    pub reason: String,
+    /// 전이 시각
+    pub at: DateTime<Utc>,
+}
+
+/// 생명주기 전이 감사 로그.
+///
+/// 최근 전이를 메모리에 보관하고, 구독자(DB 기록 서비스 등)에게 브로드캐스트합니다.
+pub struct TransitionLog {
+    entries: Mutex<VecDeque<LifecycleTransition>>,
+    capacity: usize,
+    tx: broadcast::Sender<LifecycleTransition>,
+}
+
+impl Default for TransitionLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_TRANSITION_HISTORY)
+    }
+}
+
+impl TransitionLog {
+    /// 최대 보관 수를 지정해 생성.
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(TRANSITION_BROADCAST_BUFFER);
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            tx,
+        }
+    }
+
+    /// 전이 기록.
+    pub fn record(&self, transition: LifecycleTransition) {
+        {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if entries.len() >= self.capacity {
+                entries.pop_front();
+            }
+            entries.push_back(transition.clone());
+        }
+        // 구독자가 없으면 전송 실패는 무시
+        let _ = self.tx.send(transition);
+    }
+
+    /// 전이 이벤트 구독.
+    pub fn subscribe(&self) -> broadcast::Receiver<LifecycleTransition> {
+        self.tx.subscribe()
+    }
+
+    /// 최근 전이 기록 (최신순). `strategy_id`가 None이면 전체 전략.
+    pub fn recent(&self, strategy_id: Option<&str>, limit: usize) -> Vec<LifecycleTransition> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .iter()
+            .rev()
+            .filter(|t| strategy_id.map_or(true, |id| t.strategy_id == id))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+    use StrategyLifecycle::*;
+
+    #[test]
+    fn test_transition_table() {
+        assert!(Stopped.can_transition_to(Initializing));
+        assert!(Initializing.can_transition_to(WarmingUp));
+        assert!(WarmingUp.can_transition_to(Active));
+        assert!(Active.can_transition_to(Draining));
+        assert!(Draining.can_transition_to(Stopped));
+        assert!(Paused.can_transition_to(Active));
+
+        assert!(!Stopped.can_transition_to(Active));
+        assert!(!Stopped.can_transition_to(Paused));
+        assert!(!Draining.can_transition_to(Paused));
+        assert!(!Errored.can_transition_to(Active));
+    }
+
+    #[test]
+    fn test_signal_gating_by_state() {
+        let entry = Signal::entry("s", "AAPL".to_string(), Side::Buy);
+        let exit = Signal::exit("s", "AAPL".to_string(), Side::Sell);
+
+        assert!(Active.allows_signal(&entry));
+        assert!(!Draining.allows_signal(&entry));
+        assert!(Draining.allows_signal(&exit));
+        assert!(!Paused.allows_signal(&exit));
+        assert!(!WarmingUp.allows_signal(&exit));
+    }
+
+    #[test]
+    fn test_draining_rebalance_becomes_sell_only() {
+        let rebalance = Signal::target_portfolio(
+            "s",
+            vec![
+                ("SPY".to_string(), dec!(0.6)),
+                ("TLT".to_string(), dec!(0.4)),
+            ],
+        );
+
+        assert!(Draining.allows_signal(&rebalance));
+        assert!(!Paused.allows_signal(&rebalance));
+
+        let restricted = Draining.restrict_signal(rebalance.clone());
+        assert!(restricted.is_rebalance());
+        assert_eq!(restricted.target_weights.len(), 2);
+        assert!(restricted.target_weights.values().all(|w| w.is_zero()));
+
+        // Active 상태에서는 그대로 통과
+        let unchanged = Active.restrict_signal(rebalance.clone());
+        assert_eq!(unchanged.target_weights, rebalance.target_weights);
+    }
+
+    #[test]
+    fn test_transition_log_keeps_recent_entries() {
+        let log = TransitionLog::new(2);
+        let mut rx = log.subscribe();
+        for (id, to) in [("a", Initializing), ("b", Initializing), ("a", Active)] {
+            log.record(LifecycleTransition {
+                strategy_id: id.to_string(),
+                from: Stopped,
+                to,
+                actor: "test".to_string(),
+                reason: String::new(),
+                at: Utc::now(),
+            });
+        }
+
+        let recent = log.recent(None, 10);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].to, Active);
+        assert_eq!(log.recent(Some("a"), 10).len(), 1);
+        assert_eq!(rx.try_recv().unwrap().strategy_id, "a");
+    }
+}
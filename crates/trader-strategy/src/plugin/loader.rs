@@ -1,23 +1,26 @@
-//! 동적 전략 플러그인 로더.
+//! 전략 플러그인 로더.
 //!
-//! 동적 라이브러리(Windows의 .dll, Linux의 .so)에서 전략 플러그인을 로드합니다.
-//! 플러그인 업데이트 시 핫 리로딩을 지원합니다.
+//! WebAssembly(`.wasm`) 모듈로 배포된 전략 플러그인을 로드합니다.
+//! 호스트/게스트 간 값은 직렬화되어 전달되므로 컴파일러 버전이나 메모리 레이아웃이
+//! 달라도 안전하며, 파일 변경 시 핫 리로딩을 지원합니다. ABI는 [`super::wasm`] 참고.
 
+use super::wasm::{compile_module, new_engine, WasmLimits, WasmStrategy};
 use crate::Strategy;
-use libloading::Library;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::SystemTime;
 use thiserror::Error;
 use tokio::sync::RwLock;
 use tracing::{info, warn};
+use wasmi::{Engine, Module};
 
 /// 플러그인 로더 에러.
 #[derive(Error, Debug)]
 pub enum PluginError {
-    #[error("라이브러리 로드 실패: {0}")]
+    #[error("플러그인 로드 실패: {0}")]
     LoadError(String),
 
     #[error("심볼을 찾을 수 없음: {0}")]
@@ -32,11 +35,17 @@ pub enum PluginError {
     #[error("이미 로드된 플러그인: {0}")]
     AlreadyLoaded(String),
 
+    #[error("ABI 버전 불일치: 호스트 {expected}, 플러그인 {found}")]
+    AbiMismatch { expected: i32, found: i32 },
+
+    #[error("플러그인 실행 에러: {0}")]
+    Runtime(String),
+
     #[error("IO 에러: {0}")]
     IoError(#[from] std::io::Error),
 }
 
-/// 플러그인이 반환하는 플러그인 메타데이터.
+/// 플러그인이 내보내는 플러그인 메타데이터.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginMetadata {
     /// 플러그인 이름
@@ -44,82 +53,49 @@ pub struct PluginMetadata {
     /// 플러그인 버전
     pub version: String,
     /// 플러그인 설명
+    #[serde(default)]
     pub description: String,
     /// 필수 설정 키
+    #[serde(default)]
     pub required_config: Vec<String>,
     /// 지원되는 심볼 (비어있으면 전체)
+    #[serde(default)]
     pub supported_tickers: Vec<String>,
 }
 
-// 참고: 이러한 FFI 타입은 엄격히 FFI 안전하지 않은 트레이트 객체를 사용하지만,
-// 양쪽이 동일한 메모리 레이아웃과 ABI를 사용하는 Rust-to-Rust 플러그인 로딩에서는
-// 실제로 동작합니다.
-#[allow(improper_ctypes_definitions)]
-/// create_strategy 함수 시그니처에 대한 타입 별칭.
-type CreateStrategyFn = unsafe extern "C" fn() -> *mut dyn Strategy;
-
-#[allow(improper_ctypes_definitions)]
-/// destroy_strategy 함수 시그니처에 대한 타입 별칭.
-#[allow(dead_code)] // 향후 플러그인 정리 구현을 위해 예약됨
-type DestroyStrategyFn = unsafe extern "C" fn(*mut dyn Strategy);
-
-#[allow(improper_ctypes_definitions)]
-/// get_metadata 함수 시그니처에 대한 타입 별칭.
-type GetMetadataFn = unsafe extern "C" fn() -> PluginMetadata;
-
 /// 로드된 플러그인.
 pub struct LoadedPlugin {
     /// 플러그인 파일 경로
     path: PathBuf,
 
-    /// 로드된 라이브러리
-    library: Library,
+    /// 컴파일된 WASM 모듈
+    module: Module,
 
-    /// 플러그인 메타데이터
+    /// 게스트가 내보낸 플러그인 메타데이터
     metadata: PluginMetadata,
+
+    /// 로드 시점의 파일 수정 시각 (핫 리로드 판단용)
+    modified: Option<SystemTime>,
 }
 
 impl LoadedPlugin {
     /// 파일 경로에서 플러그인 로드.
     ///
-    /// # Safety
-    ///
-    /// 호출자는 다음을 보장해야 합니다:
-    /// - 경로가 동일한 Rust 버전으로 빌드된 유효한 동적 라이브러리를 가리킴
-    /// - 라이브러리가 올바른 시그니처를 가진 `create_strategy` 및 `get_metadata` 함수를 내보냄
-    /// - 라이브러리가 호환 가능한 메모리 레이아웃으로 컴파일됨 (동일한 Rust 컴파일러 버전)
-    pub unsafe fn load<P: AsRef<Path>>(path: P) -> Result<Self, PluginError> {
+    /// 모듈을 컴파일하고 임시 인스턴스에서 ABI 버전과 메타데이터를 확인합니다.
+    pub fn load<P: AsRef<Path>>(
+        engine: &Engine,
+        path: P,
+        limits: WasmLimits,
+    ) -> Result<Self, PluginError> {
         let path = path.as_ref().to_path_buf();
 
         info!(path = %path.display(), "Loading plugin");
 
-        let library = Library::new(&path)
+        let bytes = std::fs::read(&path)
+            .map_err(|e| PluginError::LoadError(format!("{}: {}", path.display(), e)))?;
+        let modified = file_modified(&path);
+        let (module, metadata) = compile_module(engine, &bytes, limits)
             .map_err(|e| PluginError::LoadError(format!("{}: {}", path.display(), e)))?;
-
-        // Try to get metadata (optional)
-        let metadata = if let Ok(get_metadata) =
-            library.get::<libloading::Symbol<GetMetadataFn>>(b"get_metadata")
-        {
-            get_metadata()
-        } else {
-            // Default metadata if not provided
-            PluginMetadata {
-                name: path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("unknown")
-                    .to_string(),
-                version: "1.0.0".to_string(),
-                description: "Strategy plugin".to_string(),
-                required_config: Vec::new(),
-                supported_tickers: Vec::new(),
-            }
-        };
-
-        // Verify required tickers exist
-        let _: libloading::Symbol<CreateStrategyFn> = library
-            .get(b"create_strategy")
-            .map_err(|_| PluginError::SymbolNotFound("create_strategy".to_string()))?;
 
         info!(
             name = %metadata.name,
@@ -129,32 +105,21 @@ impl LoadedPlugin {
 
         Ok(Self {
             path,
-            library,
+            module,
             metadata,
+            modified,
         })
     }
 
     /// 이 플러그인에서 새 전략 인스턴스 생성.
-    ///
-    /// # Safety
-    ///
-    /// 호출자는 다음을 보장해야 합니다:
-    /// - 플러그인이 `LoadedPlugin::load`를 통해 올바르게 로드됨
-    /// - 반환된 `Box<dyn Strategy>`가 스레드 안전한 방식으로 사용됨
-    pub unsafe fn create_strategy(&self) -> Result<Box<dyn Strategy>, PluginError> {
-        let create_fn: libloading::Symbol<CreateStrategyFn> = self
-            .library
-            .get(b"create_strategy")
-            .map_err(|_| PluginError::SymbolNotFound("create_strategy".to_string()))?;
-
-        let raw_ptr = create_fn();
-        if raw_ptr.is_null() {
-            return Err(PluginError::InvalidPlugin(
-                "create_strategy returned null".to_string(),
-            ));
-        }
-
-        Ok(Box::from_raw(raw_ptr))
+    pub fn create_strategy(
+        &self,
+        engine: &Engine,
+        limits: WasmLimits,
+    ) -> Result<Box<dyn Strategy>, PluginError> {
+        let strategy =
+            WasmStrategy::instantiate(engine, &self.module, self.metadata.clone(), limits)?;
+        Ok(Box::new(strategy))
     }
 
     /// 플러그인 메타데이터 반환.
@@ -166,6 +131,15 @@ impl LoadedPlugin {
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    /// 로드 이후 파일이 변경되었는지 확인.
+    fn is_modified(&self) -> bool {
+        file_modified(&self.path) != self.modified
+    }
+}
+
+fn file_modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
 }
 
 /// 플러그인 설정.
@@ -205,6 +179,10 @@ pub struct LoaderConfig {
     /// 검색할 플러그인 파일 확장자
     #[serde(default = "default_extension")]
     pub extension: String,
+
+    /// 플러그인 실행 제한 (연료/메모리)
+    #[serde(default)]
+    pub limits: WasmLimits,
 }
 
 fn default_plugins_dir() -> PathBuf {
@@ -212,22 +190,7 @@ fn default_plugins_dir() -> PathBuf {
 }
 
 fn default_extension() -> String {
-    #[cfg(target_os = "windows")]
-    {
-        "dll".to_string()
-    }
-    #[cfg(target_os = "linux")]
-    {
-        "so".to_string()
-    }
-    #[cfg(target_os = "macos")]
-    {
-        "dylib".to_string()
-    }
-    #[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
-    {
-        "so".to_string()
-    }
+    "wasm".to_string()
 }
 
 impl Default for LoaderConfig {
@@ -236,6 +199,7 @@ impl Default for LoaderConfig {
             plugins_dir: default_plugins_dir(),
             hot_reload: false,
             extension: default_extension(),
+            limits: WasmLimits::default(),
         }
     }
 }
@@ -245,6 +209,9 @@ pub struct PluginLoader {
     /// 로더 설정
     config: LoaderConfig,
 
+    /// 모든 플러그인이 공유하는 WASM 엔진
+    engine: Engine,
+
     /// 이름별 로드된 플러그인
     plugins: Arc<RwLock<HashMap<String, LoadedPlugin>>>,
 }
@@ -254,6 +221,7 @@ impl PluginLoader {
     pub fn new(config: LoaderConfig) -> Self {
         Self {
             config,
+            engine: new_engine(),
             plugins: Arc::new(RwLock::new(HashMap::new())),
         }
     }
@@ -279,7 +247,7 @@ impl PluginLoader {
             )));
         }
 
-        let plugin = unsafe { LoadedPlugin::load(&full_path)? };
+        let plugin = LoadedPlugin::load(&self.engine, &full_path, self.config.limits)?;
         let metadata = plugin.metadata().clone();
         let name = metadata.name.clone();
 
@@ -307,18 +275,20 @@ impl PluginLoader {
         Ok(())
     }
 
-    /// 로드된 플러그인에서 전략 인스턴스 생성.
+    /// 로드된 플러그인 또는 내장 전략에서 전략 인스턴스 생성.
+    ///
+    /// 같은 이름의 플러그인이 로드되어 있으면 플러그인을 우선합니다.
     pub async fn create_strategy(
         &self,
         plugin_name: &str,
     ) -> Result<Box<dyn Strategy>, PluginError> {
         let plugins = self.plugins.read().await;
 
-        let plugin = plugins
-            .get(plugin_name)
-            .ok_or_else(|| PluginError::PluginNotFound(plugin_name.to_string()))?;
-
-        unsafe { plugin.create_strategy() }
+        match plugins.get(plugin_name) {
+            Some(plugin) => plugin.create_strategy(&self.engine, self.config.limits),
+            None => BuiltinStrategyFactory::create(plugin_name)
+                .ok_or_else(|| PluginError::PluginNotFound(plugin_name.to_string())),
+        }
     }
 
     /// 로드된 플러그인의 메타데이터 반환.
@@ -380,6 +350,9 @@ impl PluginLoader {
     }
 
     /// 이름으로 플러그인 리로드.
+    ///
+    /// 새 모듈 로드에 실패하면 기존 플러그인을 유지합니다. 이미 생성된 전략
+    /// 인스턴스는 기존 모듈로 계속 실행되고, 이후 생성되는 인스턴스부터 새 모듈을 사용합니다.
     pub async fn reload_plugin(&self, name: &str) -> Result<PluginMetadata, PluginError> {
         let path = {
             let plugins = self.plugins.read().await;
@@ -389,9 +362,43 @@ impl PluginLoader {
             plugin.path().to_path_buf()
         };
 
-        // Unload and reload
-        self.unload_plugin(name).await?;
-        self.load_plugin(&path).await
+        let plugin = LoadedPlugin::load(&self.engine, &path, self.config.limits)?;
+        let metadata = plugin.metadata().clone();
+
+        let mut plugins = self.plugins.write().await;
+        plugins.remove(name);
+        plugins.insert(metadata.name.clone(), plugin);
+
+        info!(plugin = %name, version = %metadata.version, "Plugin reloaded");
+
+        Ok(metadata)
+    }
+
+    /// 파일이 변경된 플러그인을 모두 리로드.
+    ///
+    /// `hot_reload`가 비활성화되어 있으면 아무것도 하지 않습니다.
+    pub async fn reload_changed(&self) -> Vec<PluginMetadata> {
+        if !self.config.hot_reload {
+            return Vec::new();
+        }
+
+        let changed: Vec<String> = {
+            let plugins = self.plugins.read().await;
+            plugins
+                .iter()
+                .filter(|(_, plugin)| plugin.is_modified())
+                .map(|(name, _)| name.clone())
+                .collect()
+        };
+
+        let mut reloaded = Vec::new();
+        for name in changed {
+            match self.reload_plugin(&name).await {
+                Ok(metadata) => reloaded.push(metadata),
+                Err(e) => warn!(plugin = %name, error = %e, "Failed to hot reload plugin"),
+            }
+        }
+        reloaded
     }
 
     /// 플러그인이 로드되었는지 확인.
@@ -449,12 +456,7 @@ mod tests {
 
         assert_eq!(config.plugins_dir, PathBuf::from("plugins"));
         assert!(!config.hot_reload);
-
-        #[cfg(target_os = "windows")]
-        assert_eq!(config.extension, "dll");
-
-        #[cfg(target_os = "linux")]
-        assert_eq!(config.extension, "so");
+        assert_eq!(config.extension, "wasm");
     }
 
     #[test]
@@ -484,4 +486,47 @@ mod tests {
 
         assert_eq!(loader.plugin_count().await, 0);
     }
+
+    #[tokio::test]
+    async fn test_scan_loads_wasm_and_hot_reloads_changes() {
+        use super::super::wasm::tests::guest_module;
+
+        let dir = std::env::temp_dir().join(format!("zeroquant-plugins-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("echo.wasm");
+        std::fs::write(&path, guest_module("echo", "1.0.0", 1, false)).unwrap();
+        std::fs::write(dir.join("ignored.so"), b"not a plugin").unwrap();
+
+        let loader = PluginLoader::new(LoaderConfig {
+            plugins_dir: dir.clone(),
+            hot_reload: true,
+            ..Default::default()
+        });
+
+        let loaded = loader.scan_and_load().await.unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].version, "1.0.0");
+
+        let strategy = loader.create_strategy("echo").await.unwrap();
+        assert_eq!(strategy.name(), "echo");
+        // 내장 전략은 그대로 사용 가능
+        assert!(loader.create_strategy("grid").await.is_ok());
+
+        // 변경 없음 → 리로드 없음
+        assert!(loader.reload_changed().await.is_empty());
+
+        std::fs::write(&path, guest_module("echo", "1.1.0", 1, false)).unwrap();
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(60))
+            .unwrap();
+
+        let reloaded = loader.reload_changed().await;
+        assert_eq!(reloaded.len(), 1);
+        assert_eq!(
+            loader.get_plugin_metadata("echo").await.unwrap().version,
+            "1.1.0"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -1,5 +1,7 @@
 //! 플러그인 로더 시스템.
 
 pub mod loader;
+pub mod wasm;
 
 pub use loader::*;
+pub use wasm::{PluginSignal, WasmLimits, WasmStrategy, WASM_ABI_VERSION};
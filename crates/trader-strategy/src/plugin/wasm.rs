@@ -0,0 +1,678 @@
+//! WebAssembly 전략 플러그인 런타임.
+//!
+//! 플러그인은 아래 ABI를 구현한 `.wasm` 모듈이며, 호스트와 게스트 사이의 모든 값은
+//! JSON으로 직렬화되어 게스트 선형 메모리를 통해 전달됩니다. 컴파일러 버전이나
+//! 메모리 레이아웃에 의존하지 않으므로 어떤 언어/툴체인으로 빌드해도 됩니다.
+//!
+//! # ABI (버전 1)
+//!
+//! 게스트 export:
+//!
+//! | 이름 | 시그니처 | 필수 |
+//! |------|----------|------|
+//! | `memory` | 선형 메모리 | ✅ |
+//! | `abi_version` | `() -> i32` (1 반환) | ✅ |
+//! | `alloc` | `(len: i32) -> i32` | ✅ |
+//! | `dealloc` | `(ptr: i32, len: i32)` | ✅ |
+//! | `get_metadata` | `() -> i64` | ✅ |
+//! | `initialize` | `(ptr: i32, len: i32) -> i64` | ✅ |
+//! | `on_market_data` | `(ptr: i32, len: i32) -> i64` | ✅ |
+//! | `on_order_filled` | `(ptr: i32, len: i32) -> i64` | |
+//! | `on_position_update` | `(ptr: i32, len: i32) -> i64` | |
+//! | `shutdown` | `() -> i64` | |
+//! | `save_state` | `() -> i64` | |
+//! | `load_state` | `(ptr: i32, len: i32) -> i64` | |
+//!
+//! - 입력: 호스트가 `alloc`으로 받은 버퍼에 JSON을 쓰고 `(ptr, len)`으로 전달한 뒤,
+//!   호출이 끝나면 `dealloc`으로 해제합니다.
+//! - 출력: 게스트는 `(ptr << 32) | len`으로 패킹한 i64를 반환하고, 호스트는 읽은 뒤
+//!   `dealloc`으로 해제합니다. 내용은 `{"ok": 값}` 또는 `{"error": "메시지"}` JSON입니다.
+//! - `get_metadata`의 값은 [`PluginMetadata`], `on_market_data`의 값은 [`PluginSignal`]
+//!   배열입니다. 시장 데이터·주문·포지션은 `trader-core` 타입의 serde JSON 표현입니다.
+//!
+//! 호스트 import (`env` 모듈, 선택):
+//!
+//! - `log(level: i32, ptr: i32, len: i32)`: 0=trace … 4=error 레벨로 UTF-8 메시지 기록
+//!
+//! # 제한
+//!
+//! 각 export 호출은 [`WasmLimits::fuel_per_call`]만큼의 연료로 실행되어 무한 루프가
+//! 중단되고, 선형 메모리는 [`WasmLimits::max_memory_bytes`]를 넘어 증가할 수 없습니다.
+
+use super::loader::{PluginError, PluginMetadata};
+use crate::Strategy;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use tracing::{debug, error, info, trace, warn};
+use trader_core::{MarketData, Order, Position, Side, Signal, SignalType};
+use wasmi::{
+    Caller, Config, Engine, Extern, Instance, Linker, Memory, Module, Store, StoreLimits,
+    StoreLimitsBuilder, TypedFunc,
+};
+
+/// 호스트가 지원하는 ABI 버전.
+pub const WASM_ABI_VERSION: i32 = 1;
+
+/// WASM 플러그인 실행 제한.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WasmLimits {
+    /// export 호출당 연료 (대략 실행 명령어 수)
+    #[serde(default = "default_fuel_per_call")]
+    pub fuel_per_call: u64,
+
+    /// 선형 메모리 최대 크기 (바이트)
+    #[serde(default = "default_max_memory_bytes")]
+    pub max_memory_bytes: usize,
+}
+
+fn default_fuel_per_call() -> u64 {
+    10_000_000
+}
+
+fn default_max_memory_bytes() -> usize {
+    64 * 1024 * 1024
+}
+
+impl Default for WasmLimits {
+    fn default() -> Self {
+        Self {
+            fuel_per_call: default_fuel_per_call(),
+            max_memory_bytes: default_max_memory_bytes(),
+        }
+    }
+}
+
+/// 게스트가 반환하는 신호.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginSignal {
+    /// 거래 티커
+    pub ticker: String,
+    /// 매수/매도
+    pub side: Side,
+    /// 신호 유형 (생략 시 매수 = Entry, 매도 = Exit)
+    #[serde(default)]
+    pub signal_type: Option<SignalType>,
+    /// 신호 강도 (0.0 ~ 1.0)
+    #[serde(default = "default_strength")]
+    pub strength: f64,
+    /// 제안 진입 가격
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    /// 제안 손절가
+    #[serde(default)]
+    pub stop_loss: Option<Decimal>,
+    /// 제안 익절가
+    #[serde(default)]
+    pub take_profit: Option<Decimal>,
+    /// 추가 메타데이터
+    #[serde(default)]
+    pub metadata: HashMap<String, Value>,
+}
+
+fn default_strength() -> f64 {
+    1.0
+}
+
+impl PluginSignal {
+    /// 코어 [`Signal`]로 변환.
+    pub fn into_signal(self, strategy_id: &str) -> Signal {
+        let signal_type = self.signal_type.unwrap_or(match self.side {
+            Side::Buy => SignalType::Entry,
+            Side::Sell => SignalType::Exit,
+        });
+
+        let mut signal = Signal::new(strategy_id, self.ticker, self.side, signal_type)
+            .with_strength(self.strength)
+            .with_prices(self.price, self.stop_loss, self.take_profit);
+        signal.metadata.extend(self.metadata);
+        signal
+    }
+}
+
+/// 게스트 응답 봉투.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum GuestResponse<T> {
+    Ok(T),
+    Error(String),
+}
+
+/// 스토어 호스트 데이터.
+struct HostState {
+    plugin: String,
+    limits: StoreLimits,
+}
+
+/// 연료 측정이 활성화된 WASM 엔진 생성.
+pub(crate) fn new_engine() -> Engine {
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    Engine::new(&config)
+}
+
+/// 호스트 import 정의.
+fn new_linker(engine: &Engine) -> Result<Linker<HostState>, PluginError> {
+    let mut linker = Linker::new(engine);
+    linker
+        .func_wrap(
+            "env",
+            "log",
+            |caller: Caller<'_, HostState>, level: i32, ptr: i32, len: i32| {
+                let Some(Extern::Memory(memory)) = caller.get_export("memory") else {
+                    return;
+                };
+                let mut buffer = vec![0u8; len.max(0) as usize];
+                if memory
+                    .read(&caller, ptr as u32 as usize, &mut buffer)
+                    .is_err()
+                {
+                    return;
+                }
+                let message = String::from_utf8_lossy(&buffer);
+                let plugin = caller.data().plugin.as_str();
+                match level {
+                    0 => trace!(plugin, "{}", message),
+                    1 => debug!(plugin, "{}", message),
+                    2 => info!(plugin, "{}", message),
+                    3 => warn!(plugin, "{}", message),
+                    _ => error!(plugin, "{}", message),
+                }
+            },
+        )
+        .map_err(|e| PluginError::InvalidPlugin(e.to_string()))?;
+    Ok(linker)
+}
+
+/// 인스턴스화된 게스트 모듈.
+struct GuestInstance {
+    store: Store<HostState>,
+    instance: Instance,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    dealloc: TypedFunc<(i32, i32), ()>,
+    fuel_per_call: u64,
+}
+
+impl GuestInstance {
+    /// 모듈 인스턴스화 및 ABI 버전 확인.
+    fn new(
+        engine: &Engine,
+        module: &Module,
+        plugin: &str,
+        limits: WasmLimits,
+    ) -> Result<Self, PluginError> {
+        let host = HostState {
+            plugin: plugin.to_string(),
+            limits: StoreLimitsBuilder::new()
+                .memory_size(limits.max_memory_bytes)
+                .build(),
+        };
+        let mut store = Store::new(engine, host);
+        store.limiter(|host| &mut host.limits);
+        store
+            .set_fuel(limits.fuel_per_call)
+            .map_err(|e| PluginError::Runtime(e.to_string()))?;
+
+        let instance = new_linker(engine)?
+            .instantiate(&mut store, module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(|e| PluginError::InvalidPlugin(e.to_string()))?;
+
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| PluginError::SymbolNotFound("memory".to_string()))?;
+        let alloc = typed_func::<i32, i32>(&instance, &store, "alloc")?;
+        let dealloc = typed_func::<(i32, i32), ()>(&instance, &store, "dealloc")?;
+
+        let mut guest = Self {
+            store,
+            instance,
+            memory,
+            alloc,
+            dealloc,
+            fuel_per_call: limits.fuel_per_call,
+        };
+
+        let version = typed_func::<(), i32>(&guest.instance, &guest.store, "abi_version")?;
+        guest.refuel()?;
+        let found = version
+            .call(&mut guest.store, ())
+            .map_err(|e| PluginError::Runtime(e.to_string()))?;
+        if found != WASM_ABI_VERSION {
+            return Err(PluginError::AbiMismatch {
+                expected: WASM_ABI_VERSION,
+                found,
+            });
+        }
+
+        Ok(guest)
+    }
+
+    fn refuel(&mut self) -> Result<(), PluginError> {
+        self.store
+            .set_fuel(self.fuel_per_call)
+            .map_err(|e| PluginError::Runtime(e.to_string()))
+    }
+
+    fn has_export(&self, name: &str) -> bool {
+        self.instance.get_export(&self.store, name).is_some()
+    }
+
+    /// export 호출 (입력 JSON → 출력 JSON 바이트).
+    ///
+    /// 정의되지 않은 export는 `None`을 반환합니다.
+    fn call_raw(
+        &mut self,
+        name: &str,
+        input: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>, PluginError> {
+        if !self.has_export(name) {
+            return Ok(None);
+        }
+        self.refuel()?;
+
+        let packed = match input {
+            Some(bytes) => {
+                let len = i32::try_from(bytes.len())
+                    .map_err(|_| PluginError::Runtime("입력이 너무 큽니다".to_string()))?;
+                let ptr = self
+                    .alloc
+                    .call(&mut self.store, len)
+                    .map_err(|e| PluginError::Runtime(e.to_string()))?;
+                self.memory
+                    .write(&mut self.store, ptr as u32 as usize, bytes)
+                    .map_err(|e| PluginError::Runtime(e.to_string()))?;
+
+                let func = typed_func::<(i32, i32), i64>(&self.instance, &self.store, name)?;
+                // 트랩(연료 소진 등) 시 인스턴스가 중단된 상태이므로 해제하지 않고 에러 반환
+                let packed = func
+                    .call(&mut self.store, (ptr, len))
+                    .map_err(|e| PluginError::Runtime(format!("{}: {}", name, e)))?;
+                self.dealloc
+                    .call(&mut self.store, (ptr, len))
+                    .map_err(|e| PluginError::Runtime(e.to_string()))?;
+                packed
+            }
+            None => typed_func::<(), i64>(&self.instance, &self.store, name)?
+                .call(&mut self.store, ())
+                .map_err(|e| PluginError::Runtime(format!("{}: {}", name, e)))?,
+        };
+
+        let (ptr, len) = unpack(packed);
+        let mut output = vec![0u8; len as usize];
+        self.memory
+            .read(&self.store, ptr as usize, &mut output)
+            .map_err(|e| PluginError::Runtime(format!("{} 출력 읽기 실패: {}", name, e)))?;
+        self.dealloc
+            .call(&mut self.store, (ptr as i32, len as i32))
+            .map_err(|e| PluginError::Runtime(e.to_string()))?;
+
+        Ok(Some(output))
+    }
+
+    /// export 호출 후 응답 봉투 해석.
+    fn call<T: DeserializeOwned>(
+        &mut self,
+        name: &str,
+        input: Option<&[u8]>,
+    ) -> Result<Option<T>, PluginError> {
+        let Some(output) = self.call_raw(name, input)? else {
+            return Ok(None);
+        };
+        let response: GuestResponse<T> = serde_json::from_slice(&output)
+            .map_err(|e| PluginError::InvalidPlugin(format!("{} 응답 형식 오류: {}", name, e)))?;
+
+        match response {
+            GuestResponse::Ok(value) => Ok(Some(value)),
+            GuestResponse::Error(message) => {
+                Err(PluginError::Runtime(format!("{}: {}", name, message)))
+            }
+        }
+    }
+
+    /// 필수 export 호출.
+    fn call_required<T: DeserializeOwned>(
+        &mut self,
+        name: &str,
+        input: Option<&[u8]>,
+    ) -> Result<T, PluginError> {
+        self.call(name, input)?
+            .ok_or_else(|| PluginError::SymbolNotFound(name.to_string()))
+    }
+}
+
+fn typed_func<Params, Results>(
+    instance: &Instance,
+    store: &Store<HostState>,
+    name: &str,
+) -> Result<TypedFunc<Params, Results>, PluginError>
+where
+    Params: wasmi::WasmParams,
+    Results: wasmi::WasmResults,
+{
+    instance
+        .get_typed_func::<Params, Results>(store, name)
+        .map_err(|e| PluginError::SymbolNotFound(format!("{}: {}", name, e)))
+}
+
+/// `(ptr << 32) | len` 언패킹.
+fn unpack(packed: i64) -> (u32, u32) {
+    let packed = packed as u64;
+    ((packed >> 32) as u32, packed as u32)
+}
+
+/// 모듈 바이트 컴파일 및 게스트 메타데이터 조회.
+pub(crate) fn compile_module(
+    engine: &Engine,
+    bytes: &[u8],
+    limits: WasmLimits,
+) -> Result<(Module, PluginMetadata), PluginError> {
+    let module = Module::new(engine, bytes).map_err(|e| PluginError::LoadError(e.to_string()))?;
+    let mut guest = GuestInstance::new(engine, &module, "<loading>", limits)?;
+    let metadata = guest.call_required::<PluginMetadata>("get_metadata", None)?;
+
+    for export in ["initialize", "on_market_data"] {
+        if !guest.has_export(export) {
+            return Err(PluginError::SymbolNotFound(export.to_string()));
+        }
+    }
+
+    Ok((module, metadata))
+}
+
+/// WASM 플러그인 전략.
+///
+/// 인스턴스마다 독립된 스토어와 선형 메모리를 가지므로 같은 플러그인에서
+/// 생성한 전략끼리 상태를 공유하지 않습니다.
+pub struct WasmStrategy {
+    metadata: PluginMetadata,
+    /// `save_state(&self)`에서도 게스트를 호출할 수 있도록 Mutex로 감쌈
+    guest: Mutex<GuestInstance>,
+    fuel_per_call: u64,
+    signals_generated: u64,
+}
+
+impl WasmStrategy {
+    /// 컴파일된 모듈에서 새 전략 인스턴스 생성.
+    pub fn instantiate(
+        engine: &Engine,
+        module: &Module,
+        metadata: PluginMetadata,
+        limits: WasmLimits,
+    ) -> Result<Self, PluginError> {
+        let guest = GuestInstance::new(engine, module, &metadata.name, limits)?;
+        Ok(Self {
+            metadata,
+            guest: Mutex::new(guest),
+            fuel_per_call: limits.fuel_per_call,
+            signals_generated: 0,
+        })
+    }
+
+    /// 플러그인 메타데이터.
+    pub fn metadata(&self) -> &PluginMetadata {
+        &self.metadata
+    }
+
+    fn call_with<I: Serialize, T: DeserializeOwned>(
+        &mut self,
+        name: &str,
+        input: &I,
+    ) -> Result<Option<T>, PluginError> {
+        let bytes = serde_json::to_vec(input)
+            .map_err(|e| PluginError::Runtime(format!("{} 입력 직렬화 실패: {}", name, e)))?;
+        self.guest().call(name, Some(&bytes))
+    }
+
+    fn guest(&mut self) -> &mut GuestInstance {
+        self.guest.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl Strategy for WasmStrategy {
+    fn name(&self) -> &str {
+        &self.metadata.name
+    }
+
+    fn version(&self) -> &str {
+        &self.metadata.version
+    }
+
+    fn description(&self) -> &str {
+        &self.metadata.description
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let missing: Vec<&String> = self
+            .metadata
+            .required_config
+            .iter()
+            .filter(|key| config.get(key.as_str()).is_none())
+            .collect();
+        if !missing.is_empty() {
+            return Err(
+                PluginError::InvalidPlugin(format!("필수 설정 누락: {:?}", missing)).into(),
+            );
+        }
+
+        self.call_with::<_, Value>("initialize", &config)?;
+        self.signals_generated = 0;
+
+        info!(plugin = %self.metadata.name, "WASM 플러그인 전략 초기화");
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let signals: Vec<PluginSignal> = self
+            .call_with::<_, Option<Vec<PluginSignal>>>("on_market_data", data)?
+            .flatten()
+            .unwrap_or_default();
+        self.signals_generated += signals.len() as u64;
+
+        Ok(signals
+            .into_iter()
+            .map(|s| s.into_signal(&self.metadata.name))
+            .collect())
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.call_with::<_, Value>("on_order_filled", order)?;
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.call_with::<_, Value>("on_position_update", position)?;
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.guest().call::<Value>("shutdown", None)?;
+        info!(
+            plugin = %self.metadata.name,
+            signals = self.signals_generated,
+            "WASM 플러그인 전략 종료"
+        );
+        Ok(())
+    }
+
+    fn subscribed_tickers(&self) -> Vec<String> {
+        self.metadata.supported_tickers.clone()
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "plugin": self.metadata.name,
+            "version": self.metadata.version,
+            "abi_version": WASM_ABI_VERSION,
+            "signals_generated": self.signals_generated,
+            "fuel_per_call": self.fuel_per_call,
+        })
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let mut guest = self.guest.lock().unwrap_or_else(PoisonError::into_inner);
+        match guest.call::<Value>("save_state", None)? {
+            Some(state) => Ok(serde_json::to_vec(&state)?),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.guest().call::<Value>("load_state", Some(data))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use rust_decimal_macros::dec;
+    use trader_core::{Kline, Timeframe};
+
+    const METADATA_PTR: u64 = 1024;
+    const NULL_PTR: u64 = 2048;
+    const SIGNALS_PTR: u64 = 3072;
+
+    fn packed(ptr: u64, json: &str) -> i64 {
+        ((ptr << 32) | json.len() as u64) as i64
+    }
+
+    fn wat_string(json: &str) -> String {
+        json.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    /// 테스트용 게스트 모듈 (WAT → wasm 바이트).
+    ///
+    /// `on_market_data`는 BTC/USDT 매수 신호 하나를 반환하고, `spin`이면 무한 루프를 돕니다.
+    pub(crate) fn guest_module(name: &str, version: &str, abi: i32, spin: bool) -> Vec<u8> {
+        let metadata = format!(
+            r#"{{"ok":{{"name":"{}","version":"{}","description":"test plugin","supported_tickers":["BTC/USDT"]}}}}"#,
+            name, version
+        );
+        let null = r#"{"ok":null}"#;
+        let signals = r#"{"ok":[{"ticker":"BTC/USDT","side":"buy","strength":0.8}]}"#;
+        let on_market_data = if spin {
+            "(loop $spin (br $spin)) (unreachable)".to_string()
+        } else {
+            format!("(i64.const {})", packed(SIGNALS_PTR, signals))
+        };
+
+        let wat = format!(
+            r#"(module
+                (memory (export "memory") 1)
+                (data (i32.const {metadata_ptr}) "{metadata}")
+                (data (i32.const {null_ptr}) "{null}")
+                (data (i32.const {signals_ptr}) "{signals}")
+                (func (export "abi_version") (result i32) (i32.const {abi}))
+                (func (export "alloc") (param i32) (result i32) (i32.const 16384))
+                (func (export "dealloc") (param i32 i32))
+                (func (export "get_metadata") (result i64) (i64.const {metadata_packed}))
+                (func (export "initialize") (param i32 i32) (result i64) (i64.const {null_packed}))
+                (func (export "on_market_data") (param i32 i32) (result i64) {on_market_data}))"#,
+            metadata_ptr = METADATA_PTR,
+            metadata = wat_string(&metadata),
+            null_ptr = NULL_PTR,
+            null = wat_string(null),
+            signals_ptr = SIGNALS_PTR,
+            signals = wat_string(signals),
+            abi = abi,
+            metadata_packed = packed(METADATA_PTR, &metadata),
+            null_packed = packed(NULL_PTR, null),
+            on_market_data = on_market_data,
+        );
+        wat::parse_str(wat).unwrap()
+    }
+
+    fn market_data() -> MarketData {
+        let time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        MarketData::from_kline(
+            "test",
+            Kline::new(
+                "BTC/USDT".to_string(),
+                Timeframe::D1,
+                time,
+                dec!(100),
+                dec!(100),
+                dec!(100),
+                dec!(100),
+                dec!(1),
+                time,
+            ),
+        )
+    }
+
+    async fn strategy(spin: bool, limits: WasmLimits) -> WasmStrategy {
+        let engine = new_engine();
+        let bytes = guest_module("echo", "1.0.0", WASM_ABI_VERSION, spin);
+        let (module, metadata) = compile_module(&engine, &bytes, limits).unwrap();
+        let mut strategy = WasmStrategy::instantiate(&engine, &module, metadata, limits).unwrap();
+        strategy.initialize(json!({})).await.unwrap();
+        strategy
+    }
+
+    #[tokio::test]
+    async fn test_guest_exports_metadata_and_signals() {
+        let mut strategy = strategy(false, WasmLimits::default()).await;
+        assert_eq!(strategy.name(), "echo");
+        assert_eq!(strategy.subscribed_tickers(), vec!["BTC/USDT".to_string()]);
+
+        let signals = strategy.on_market_data(&market_data()).await.unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].ticker, "BTC/USDT");
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].signal_type, SignalType::Entry);
+        assert_eq!(signals[0].strength, 0.8);
+
+        // save_state 미구현 플러그인은 빈 상태
+        assert!(strategy.save_state().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_infinite_loop_runs_out_of_fuel() {
+        let limits = WasmLimits {
+            fuel_per_call: 100_000,
+            ..Default::default()
+        };
+        let mut strategy = strategy(true, limits).await;
+
+        let err = strategy.on_market_data(&market_data()).await.unwrap_err();
+        assert!(err.to_string().contains("on_market_data"));
+    }
+
+    #[test]
+    fn test_abi_mismatch_is_rejected() {
+        let engine = new_engine();
+        let bytes = guest_module("old", "0.1.0", 99, false);
+
+        let err = compile_module(&engine, &bytes, WasmLimits::default()).unwrap_err();
+        assert!(matches!(
+            err,
+            PluginError::AbiMismatch {
+                expected: WASM_ABI_VERSION,
+                found: 99
+            }
+        ));
+    }
+}
@@ -0,0 +1,599 @@
+//! 캘린더 기반 전략 스케줄러.
+//!
+//! 리밸런싱처럼 정해진 날짜에만 동작하는 전략은 매 캔들마다 `on_market_data`에서
+//! 날짜를 검사하는 대신 스케줄 트리거를 등록합니다. 스케줄러는 거래일 캘린더를 기준으로
+//! 트리거 발생 시각을 계산하고, 엔진은 해당 시각이 지나면 `Strategy::on_schedule`을 호출합니다.
+//!
+//! # 트리거 예시
+//!
+//! - 매월 첫 KRX 거래일 09:05 KST: `ScheduleTrigger::monthly("rebalance", ScheduleMarket::Krx, 1, ScheduleTime::at(..))`
+//! - 매주 금요일 미국 장 마감 15분 전: `ScheduleTrigger::weekly("weekly", ScheduleMarket::Us, Weekday::Fri, ScheduleTime::before_close(15))`
+//!
+//! 실시간 엔진은 현재 시각으로, 백테스트는 캔들 시각(시뮬레이션 시간)으로 같은
+//! `StrategyScheduler::advance`를 호출하므로 두 환경에서 동일한 시점에 트리거가 발생합니다.
+
+use async_trait::async_trait;
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Timelike, Utc, Weekday,
+};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// 한 번의 조회에서 검사하는 최대 일수 (장기간 중단 후 재개 시 과도한 캘린더 조회 방지).
+const MAX_SCAN_DAYS: i64 = 400;
+
+/// 스케줄 기준 시장.
+///
+/// 시장별 시간대, 정규장 시간, 거래일 캘린더가 달라집니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleMarket {
+    /// 한국거래소 (KST, 09:00~15:30)
+    Krx,
+    /// 미국 주식시장 (ET, 09:30~16:00)
+    Us,
+    /// 암호화폐 (UTC, 24시간, 휴장 없음)
+    Crypto,
+}
+
+impl ScheduleMarket {
+    /// 시장 현지 시간대.
+    pub fn timezone(&self) -> Tz {
+        match self {
+            ScheduleMarket::Krx => chrono_tz::Asia::Seoul,
+            ScheduleMarket::Us => chrono_tz::America::New_York,
+            ScheduleMarket::Crypto => chrono_tz::UTC,
+        }
+    }
+
+    /// 정규장 시작/종료 시각 (현지 자정 기준 분).
+    ///
+    /// 암호화폐는 종료 시각이 다음 날 자정(1440분)입니다.
+    pub fn session_minutes(&self) -> (i64, i64) {
+        match self {
+            ScheduleMarket::Krx => (9 * 60, 15 * 60 + 30),
+            ScheduleMarket::Us => (9 * 60 + 30, 16 * 60),
+            ScheduleMarket::Crypto => (0, 24 * 60),
+        }
+    }
+}
+
+/// 거래일 내 트리거 발생 시각.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "anchor", rename_all = "snake_case")]
+pub enum ScheduleTime {
+    /// 현지 시각
+    At { time: NaiveTime },
+    /// 정규장 시작 기준 (분, 음수면 시작 전)
+    Open {
+        #[serde(default)]
+        offset_minutes: i64,
+    },
+    /// 정규장 종료 기준 (분, 음수면 종료 전)
+    Close {
+        #[serde(default)]
+        offset_minutes: i64,
+    },
+}
+
+impl ScheduleTime {
+    /// 현지 시각 지정.
+    pub fn at(time: NaiveTime) -> Self {
+        ScheduleTime::At { time }
+    }
+
+    /// 정규장 시작 후 `minutes`분.
+    pub fn after_open(minutes: i64) -> Self {
+        ScheduleTime::Open {
+            offset_minutes: minutes,
+        }
+    }
+
+    /// 정규장 종료 `minutes`분 전.
+    pub fn before_close(minutes: i64) -> Self {
+        ScheduleTime::Close {
+            offset_minutes: -minutes,
+        }
+    }
+
+    /// 현지 자정 기준 분 단위 오프셋.
+    fn minutes_from_midnight(&self, market: ScheduleMarket) -> i64 {
+        let (open, close) = market.session_minutes();
+        match self {
+            ScheduleTime::At { time } => i64::from(time.num_seconds_from_midnight() / 60),
+            ScheduleTime::Open { offset_minutes } => open + offset_minutes,
+            ScheduleTime::Close { offset_minutes } => close + offset_minutes,
+        }
+    }
+}
+
+/// 트리거가 발생하는 날짜 규칙.
+///
+/// 모든 규칙은 거래일에만 발생합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "every", rename_all = "snake_case")]
+pub enum ScheduleRule {
+    /// 매 거래일
+    TradingDay,
+    /// 매주 지정 요일 (휴장일이면 건너뜀)
+    Week { weekday: Weekday },
+    /// 매월 N번째 거래일 (1 = 첫 거래일, -1 = 마지막 거래일)
+    Month { trading_day: i32 },
+}
+
+/// 전략이 등록하는 스케줄 트리거.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleTrigger {
+    /// 트리거 ID (전략 내에서 고유, `ScheduleEvent::trigger_id`로 전달)
+    pub id: String,
+    /// 기준 시장
+    pub market: ScheduleMarket,
+    /// 날짜 규칙
+    pub rule: ScheduleRule,
+    /// 발생 시각
+    pub time: ScheduleTime,
+}
+
+impl ScheduleTrigger {
+    /// 새 트리거 생성.
+    pub fn new(
+        id: impl Into<String>,
+        market: ScheduleMarket,
+        rule: ScheduleRule,
+        time: ScheduleTime,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            market,
+            rule,
+            time,
+        }
+    }
+
+    /// 매 거래일 트리거.
+    pub fn daily(id: impl Into<String>, market: ScheduleMarket, time: ScheduleTime) -> Self {
+        Self::new(id, market, ScheduleRule::TradingDay, time)
+    }
+
+    /// 매주 지정 요일 트리거.
+    pub fn weekly(
+        id: impl Into<String>,
+        market: ScheduleMarket,
+        weekday: Weekday,
+        time: ScheduleTime,
+    ) -> Self {
+        Self::new(id, market, ScheduleRule::Week { weekday }, time)
+    }
+
+    /// 매월 N번째 거래일 트리거 (음수면 월말부터).
+    pub fn monthly(
+        id: impl Into<String>,
+        market: ScheduleMarket,
+        trading_day: i32,
+        time: ScheduleTime,
+    ) -> Self {
+        Self::new(id, market, ScheduleRule::Month { trading_day }, time)
+    }
+
+    /// 현지 날짜의 발생 시각 (UTC). DST로 존재하지 않는 시각이면 None.
+    fn fire_time(&self, date: NaiveDate) -> Option<DateTime<Utc>> {
+        let local = date.and_time(NaiveTime::MIN)
+            + Duration::minutes(self.time.minutes_from_midnight(self.market));
+        self.market
+            .timezone()
+            .from_local_datetime(&local)
+            .earliest()
+            .map(|at| at.with_timezone(&Utc))
+    }
+}
+
+/// 발생한 스케줄 이벤트.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleEvent {
+    /// 트리거를 등록한 전략 ID
+    pub strategy_id: String,
+    /// 트리거 ID
+    pub trigger_id: String,
+    /// 기준 시장
+    pub market: ScheduleMarket,
+    /// 시장 현지 거래일
+    pub trading_date: NaiveDate,
+    /// 예정 발생 시각
+    pub scheduled_at: DateTime<Utc>,
+}
+
+/// 거래일 캘린더.
+///
+/// 실시간 환경에서는 거래소 휴장일 API(KIS 등)를 조회하는 구현을 사용합니다.
+#[async_trait]
+pub trait TradingCalendar: Send + Sync {
+    /// 해당 현지 날짜가 거래일인지 확인.
+    async fn is_trading_day(&self, market: ScheduleMarket, date: NaiveDate) -> bool;
+}
+
+/// 주말과 등록된 휴장일만 제외하는 기본 캘린더.
+///
+/// 암호화폐는 매일 거래일입니다. 백테스트와 휴장일 API가 없는 환경에서 사용합니다.
+#[derive(Debug, Clone, Default)]
+pub struct WeekdayCalendar {
+    holidays: HashMap<ScheduleMarket, HashSet<NaiveDate>>,
+}
+
+impl WeekdayCalendar {
+    /// 새 캘린더 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 시장 휴장일 추가 (빌더).
+    pub fn with_holidays(
+        mut self,
+        market: ScheduleMarket,
+        dates: impl IntoIterator<Item = NaiveDate>,
+    ) -> Self {
+        self.holidays.entry(market).or_default().extend(dates);
+        self
+    }
+
+    /// 동기 거래일 판정.
+    pub fn is_open(&self, market: ScheduleMarket, date: NaiveDate) -> bool {
+        if market != ScheduleMarket::Crypto && matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+        {
+            return false;
+        }
+        !self
+            .holidays
+            .get(&market)
+            .is_some_and(|dates| dates.contains(&date))
+    }
+}
+
+#[async_trait]
+impl TradingCalendar for WeekdayCalendar {
+    async fn is_trading_day(&self, market: ScheduleMarket, date: NaiveDate) -> bool {
+        self.is_open(market, date)
+    }
+}
+
+/// 전략별 등록 트리거와 마지막 처리 시각.
+#[derive(Debug, Clone)]
+struct ScheduleEntry {
+    triggers: Vec<ScheduleTrigger>,
+    cursor: DateTime<Utc>,
+}
+
+/// 전략 스케줄러.
+///
+/// 전략별로 트리거와 마지막 처리 시각(커서)을 관리하며, `advance` 호출 시
+/// 커서 이후 `now`까지 도래한 이벤트를 발생 시각 순으로 반환합니다.
+pub struct StrategyScheduler {
+    calendar: Arc<dyn TradingCalendar>,
+    entries: HashMap<String, ScheduleEntry>,
+}
+
+impl Default for StrategyScheduler {
+    fn default() -> Self {
+        Self::new(Arc::new(WeekdayCalendar::default()))
+    }
+}
+
+impl StrategyScheduler {
+    /// 새 스케줄러 생성.
+    pub fn new(calendar: Arc<dyn TradingCalendar>) -> Self {
+        Self {
+            calendar,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// 거래일 캘린더 교체.
+    pub fn set_calendar(&mut self, calendar: Arc<dyn TradingCalendar>) {
+        self.calendar = calendar;
+    }
+
+    /// 전략 트리거 등록.
+    ///
+    /// `since` 이후의 이벤트부터 발생합니다. 트리거가 비어 있으면 등록을 해제합니다.
+    pub fn register(
+        &mut self,
+        strategy_id: impl Into<String>,
+        triggers: Vec<ScheduleTrigger>,
+        since: DateTime<Utc>,
+    ) {
+        let strategy_id = strategy_id.into();
+        if triggers.is_empty() {
+            self.entries.remove(&strategy_id);
+            return;
+        }
+        self.entries.insert(
+            strategy_id,
+            ScheduleEntry {
+                triggers,
+                cursor: since,
+            },
+        );
+    }
+
+    /// 전략 트리거 등록 해제.
+    pub fn remove(&mut self, strategy_id: &str) {
+        self.entries.remove(strategy_id);
+    }
+
+    /// 등록된 트리거가 없는지 여부.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 전략에 등록된 트리거 목록.
+    pub fn triggers(&self, strategy_id: &str) -> &[ScheduleTrigger] {
+        self.entries
+            .get(strategy_id)
+            .map(|entry| entry.triggers.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 커서 이후 `now`까지 도래한 이벤트를 반환하고 커서를 `now`로 이동.
+    ///
+    /// 이벤트는 발생 시각, 전략 ID 순으로 정렬됩니다.
+    pub async fn advance(&mut self, now: DateTime<Utc>) -> Vec<ScheduleEvent> {
+        let mut events = Vec::new();
+        for (strategy_id, entry) in &mut self.entries {
+            if now <= entry.cursor {
+                continue;
+            }
+            for trigger in &entry.triggers {
+                for (trading_date, scheduled_at) in
+                    occurrences(self.calendar.as_ref(), trigger, entry.cursor, now).await
+                {
+                    events.push(ScheduleEvent {
+                        strategy_id: strategy_id.clone(),
+                        trigger_id: trigger.id.clone(),
+                        market: trigger.market,
+                        trading_date,
+                        scheduled_at,
+                    });
+                }
+            }
+            entry.cursor = now;
+        }
+        events.sort_by(|a, b| {
+            a.scheduled_at
+                .cmp(&b.scheduled_at)
+                .then_with(|| a.strategy_id.cmp(&b.strategy_id))
+        });
+        events
+    }
+}
+
+/// `(from, to]` 구간에 발생하는 트리거 시각 목록 (거래일, UTC 시각).
+pub async fn occurrences(
+    calendar: &dyn TradingCalendar,
+    trigger: &ScheduleTrigger,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<(NaiveDate, DateTime<Utc>)> {
+    if to <= from {
+        return Vec::new();
+    }
+
+    let tz = trigger.market.timezone();
+    // 장 마감 기준 오프셋은 현지 날짜 경계를 넘을 수 있으므로 하루 여유를 둠
+    let last = to.with_timezone(&tz).date_naive() + Duration::days(1);
+    let first = (from.with_timezone(&tz).date_naive() - Duration::days(1))
+        .max(last - Duration::days(MAX_SCAN_DAYS));
+
+    let mut month_cache: HashMap<(i32, u32), Vec<NaiveDate>> = HashMap::new();
+    let mut result = Vec::new();
+
+    for date in first.iter_days().take_while(|d| *d <= last) {
+        // 시각 범위를 먼저 확인하여 불필요한 캘린더 조회를 줄임
+        let Some(at) = trigger.fire_time(date) else {
+            continue;
+        };
+        if at <= from || at > to {
+            continue;
+        }
+
+        let fires = match trigger.rule {
+            ScheduleRule::TradingDay => calendar.is_trading_day(trigger.market, date).await,
+            ScheduleRule::Week { weekday } => {
+                date.weekday() == weekday && calendar.is_trading_day(trigger.market, date).await
+            }
+            ScheduleRule::Month { trading_day } => {
+                let days = match month_cache.entry((date.year(), date.month())) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        entry.insert(trading_days_in_month(calendar, trigger.market, date).await)
+                    }
+                };
+                nth_trading_day(days, trading_day) == Some(date)
+            }
+        };
+
+        if fires {
+            result.push((date, at));
+        }
+    }
+
+    result
+}
+
+/// 날짜가 속한 달의 거래일 목록.
+async fn trading_days_in_month(
+    calendar: &dyn TradingCalendar,
+    market: ScheduleMarket,
+    date: NaiveDate,
+) -> Vec<NaiveDate> {
+    let mut days = Vec::new();
+    let Some(start) = date.with_day(1) else {
+        return days;
+    };
+    for day in start.iter_days().take_while(|d| d.month() == start.month()) {
+        if calendar.is_trading_day(market, day).await {
+            days.push(day);
+        }
+    }
+    days
+}
+
+/// N번째 거래일 (1부터 시작, 음수면 뒤에서부터).
+fn nth_trading_day(days: &[NaiveDate], n: i32) -> Option<NaiveDate> {
+    let index = match n {
+        0 => return None,
+        n if n > 0 => (n - 1) as usize,
+        n => days.len().checked_sub(n.unsigned_abs() as usize)?,
+    };
+    days.get(index).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_first_krx_trading_day_of_month() {
+        // 2024-01-01(월)은 신정 휴장 → 첫 거래일은 1월 2일
+        let calendar =
+            WeekdayCalendar::new().with_holidays(ScheduleMarket::Krx, [date(2024, 1, 1)]);
+        let trigger = ScheduleTrigger::monthly(
+            "rebalance",
+            ScheduleMarket::Krx,
+            1,
+            ScheduleTime::at(NaiveTime::from_hms_opt(9, 5, 0).unwrap()),
+        );
+
+        let fired = occurrences(
+            &calendar,
+            &trigger,
+            utc(2023, 12, 20, 0, 0),
+            utc(2024, 3, 1, 0, 0),
+        )
+        .await;
+
+        // 09:05 KST = 00:05 UTC
+        assert_eq!(
+            fired,
+            vec![
+                (date(2024, 1, 2), utc(2024, 1, 2, 0, 5)),
+                (date(2024, 2, 1), utc(2024, 2, 1, 0, 5)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_friday_us_close_follows_dst() {
+        let calendar = WeekdayCalendar::new();
+        let trigger = ScheduleTrigger::weekly(
+            "weekly",
+            ScheduleMarket::Us,
+            Weekday::Fri,
+            ScheduleTime::before_close(15),
+        );
+
+        // 2024-03-08 (EST, UTC-5) → 20:45 UTC, 2024-03-15 (EDT, UTC-4) → 19:45 UTC
+        let fired = occurrences(
+            &calendar,
+            &trigger,
+            utc(2024, 3, 4, 0, 0),
+            utc(2024, 3, 16, 0, 0),
+        )
+        .await;
+        assert_eq!(
+            fired,
+            vec![
+                (date(2024, 3, 8), utc(2024, 3, 8, 20, 45)),
+                (date(2024, 3, 15), utc(2024, 3, 15, 19, 45)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_last_trading_day_and_holiday_skip() {
+        // 2024-05-31(금)이 휴장이면 마지막 거래일은 5월 30일
+        let calendar =
+            WeekdayCalendar::new().with_holidays(ScheduleMarket::Us, [date(2024, 5, 31)]);
+        let month_end = ScheduleTrigger::monthly(
+            "month_end",
+            ScheduleMarket::Us,
+            -1,
+            ScheduleTime::after_open(0),
+        );
+        let friday = ScheduleTrigger::weekly(
+            "friday",
+            ScheduleMarket::Us,
+            Weekday::Fri,
+            ScheduleTime::after_open(0),
+        );
+
+        let from = utc(2024, 5, 27, 0, 0);
+        let to = utc(2024, 6, 1, 0, 0);
+        let month_end_fired = occurrences(&calendar, &month_end, from, to).await;
+        assert_eq!(
+            month_end_fired,
+            vec![(date(2024, 5, 30), utc(2024, 5, 30, 13, 30))]
+        );
+        assert!(occurrences(&calendar, &friday, from, to).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scheduler_advance_fires_once() {
+        let mut scheduler = StrategyScheduler::default();
+        scheduler.register(
+            "s1",
+            vec![ScheduleTrigger::daily(
+                "daily",
+                ScheduleMarket::Crypto,
+                ScheduleTime::at(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+            )],
+            utc(2024, 1, 1, 0, 0),
+        );
+
+        assert!(scheduler.advance(utc(2024, 1, 1, 11, 59)).await.is_empty());
+
+        let events = scheduler.advance(utc(2024, 1, 1, 12, 0)).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].strategy_id, "s1");
+        assert_eq!(events[0].trigger_id, "daily");
+        assert_eq!(events[0].scheduled_at, utc(2024, 1, 1, 12, 0));
+
+        // 같은 구간을 다시 진행해도 중복 발생하지 않음
+        assert!(scheduler.advance(utc(2024, 1, 1, 12, 0)).await.is_empty());
+
+        // 여러 날을 건너뛰면 놓친 이벤트가 순서대로 발생 (암호화폐는 주말도 거래일)
+        let events = scheduler.advance(utc(2024, 1, 4, 0, 0)).await;
+        let dates: Vec<_> = events.iter().map(|e| e.trading_date).collect();
+        assert_eq!(dates, vec![date(2024, 1, 2), date(2024, 1, 3)]);
+
+        scheduler.remove("s1");
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    fn test_trigger_serde_format() {
+        let trigger = ScheduleTrigger::weekly(
+            "weekly",
+            ScheduleMarket::Us,
+            Weekday::Fri,
+            ScheduleTime::before_close(15),
+        );
+        let json = serde_json::to_value(&trigger).unwrap();
+        assert_eq!(json["market"], "us");
+        assert_eq!(json["rule"]["every"], "week");
+        assert_eq!(json["time"]["anchor"], "close");
+        assert_eq!(json["time"]["offset_minutes"], -15);
+
+        let parsed: ScheduleTrigger = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, trigger);
+    }
+}
@@ -0,0 +1,167 @@
+//! 전략 상태 영속화.
+//!
+//! `Strategy::save_state`/`load_state`로 직렬화한 전략 내부 상태를 저장소에
+//! 보관하여, 프로세스가 재시작되어도 라운드 카운터·돌파 레벨 같은 상태를
+//! 이어서 사용할 수 있게 합니다.
+//!
+//! # 포맷 버전
+//!
+//! 전략은 [`encode_versioned_state`]로 상태를 포맷 버전과 함께 감싸 저장하고,
+//! [`decode_versioned_state`]로 읽어 버전별로 마이그레이션합니다.
+//! 버전 정보가 없는 기존 JSON 상태는 버전 0으로 취급됩니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+/// 상태 저장소 에러.
+#[derive(Error, Debug)]
+pub enum StateStoreError {
+    #[error("저장소 에러: {0}")]
+    Storage(String),
+
+    #[error("직렬화 에러: {0}")]
+    Serialization(String),
+}
+
+/// 저장된 전략 상태 스냅샷.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategySnapshot {
+    /// 전략 인스턴스 ID
+    pub strategy_id: String,
+    /// 전략 구현 이름 (`Strategy::name`)
+    pub strategy_name: String,
+    /// 전략 구현 버전 (`Strategy::version`)
+    pub strategy_version: String,
+    /// `Strategy::save_state` 결과
+    pub state: Vec<u8>,
+    /// 저장 시각
+    pub saved_at: DateTime<Utc>,
+}
+
+/// 전략 상태 저장소.
+///
+/// 엔진은 전략 ID당 가장 최근 스냅샷 하나만 복원에 사용합니다.
+#[async_trait]
+pub trait StrategyStateStore: Send + Sync {
+    /// 스냅샷 저장 (같은 전략 ID/버전의 기존 스냅샷은 덮어씀).
+    async fn save(&self, snapshot: &StrategySnapshot) -> Result<(), StateStoreError>;
+
+    /// 전략 ID의 가장 최근 스냅샷 조회.
+    async fn load(&self, strategy_id: &str) -> Result<Option<StrategySnapshot>, StateStoreError>;
+
+    /// 전략 ID의 모든 스냅샷 삭제.
+    async fn delete(&self, strategy_id: &str) -> Result<(), StateStoreError>;
+}
+
+/// 메모리 기반 상태 저장소 (테스트/백테스트용).
+#[derive(Debug, Default)]
+pub struct InMemoryStateStore {
+    snapshots: RwLock<HashMap<String, StrategySnapshot>>,
+}
+
+impl InMemoryStateStore {
+    /// 빈 저장소 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl StrategyStateStore for InMemoryStateStore {
+    async fn save(&self, snapshot: &StrategySnapshot) -> Result<(), StateStoreError> {
+        self.snapshots
+            .write()
+            .await
+            .insert(snapshot.strategy_id.clone(), snapshot.clone());
+        Ok(())
+    }
+
+    async fn load(&self, strategy_id: &str) -> Result<Option<StrategySnapshot>, StateStoreError> {
+        Ok(self.snapshots.read().await.get(strategy_id).cloned())
+    }
+
+    async fn delete(&self, strategy_id: &str) -> Result<(), StateStoreError> {
+        self.snapshots.write().await.remove(strategy_id);
+        Ok(())
+    }
+}
+
+/// 포맷 버전이 포함된 상태 봉투.
+#[derive(Debug, Serialize, Deserialize)]
+struct VersionedState {
+    format_version: u32,
+    data: Value,
+}
+
+/// 상태를 포맷 버전과 함께 직렬화.
+pub fn encode_versioned_state<T: Serialize>(
+    format_version: u32,
+    state: &T,
+) -> Result<Vec<u8>, serde_json::Error> {
+    serde_json::to_vec(&VersionedState {
+        format_version,
+        data: serde_json::to_value(state)?,
+    })
+}
+
+/// 직렬화된 상태를 (포맷 버전, 데이터)로 역직렬화.
+///
+/// 봉투 없이 저장된 기존 JSON 상태는 버전 0으로 반환합니다.
+pub fn decode_versioned_state(data: &[u8]) -> Result<(u32, Value), serde_json::Error> {
+    let value: Value = serde_json::from_slice(data)?;
+    let is_envelope = value.get("format_version").is_some() && value.get("data").is_some();
+    if is_envelope {
+        let envelope: VersionedState = serde_json::from_value(value)?;
+        Ok((envelope.format_version, envelope.data))
+    } else {
+        Ok((0, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_versioned_state_roundtrip() {
+        let bytes = encode_versioned_state(3, &json!({"round": 2})).unwrap();
+        let (version, data) = decode_versioned_state(&bytes).unwrap();
+
+        assert_eq!(version, 3);
+        assert_eq!(data, json!({"round": 2}));
+    }
+
+    #[test]
+    fn test_unversioned_state_is_version_zero() {
+        let bytes = serde_json::to_vec(&json!({"trades_count": 5})).unwrap();
+        let (version, data) = decode_versioned_state(&bytes).unwrap();
+
+        assert_eq!(version, 0);
+        assert_eq!(data["trades_count"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_keeps_latest_snapshot() {
+        let store = InMemoryStateStore::new();
+        let snapshot = |state: &[u8]| StrategySnapshot {
+            strategy_id: "s1".to_string(),
+            strategy_name: "test".to_string(),
+            strategy_version: "1.0.0".to_string(),
+            state: state.to_vec(),
+            saved_at: Utc::now(),
+        };
+
+        store.save(&snapshot(b"first")).await.unwrap();
+        store.save(&snapshot(b"second")).await.unwrap();
+        assert_eq!(store.load("s1").await.unwrap().unwrap().state, b"second");
+
+        store.delete("s1").await.unwrap();
+        assert!(store.load("s1").await.unwrap().is_none());
+    }
+}
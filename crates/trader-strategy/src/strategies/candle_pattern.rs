@@ -1005,6 +1005,10 @@ impl Strategy for CandlePatternStrategy {
         Ok(())
     }
 
+    fn subscribed_tickers(&self) -> Vec<String> {
+        self.config.iter().map(|c| c.ticker.clone()).collect()
+    }
+
     fn get_state(&self) -> Value {
         json!({
             "config": self.config,
@@ -4,7 +4,7 @@
 //!
 //! - **defaults**: 전략 기본 상수 (지표, 리스크, 그리드, 모멘텀, 배분)
 //! - **indicators**: 기술적 지표 계산 (RSI, SMA, EMA, BB, MACD, ATR)
-//! - **position_sizing**: 포지션 크기 계산 (Kelly, FixedRatio, ATR 기반)
+//! - **position_sizing**: 포지션 크기 계산 (Kelly, FixedRatio, ATR 기반, 변동성 타깃, 리스크 패리티)
 //! - **risk_checks**: 리스크 검증 및 관리
 //! - **signal_filters**: 신호 필터링 및 확인
 //! - **모멘텀**: 자산 배분 전략을 위한 다기간 모멘텀 스코어링
@@ -50,8 +50,10 @@ pub use indicators::{
 };
 
 pub use position_sizing::{
-    AtrPositionSizer, FixedRatioSizer, GlobalScorePositionSizer, KellyPositionSizer, PositionSize,
-    PositionSizer,
+    atr_volatility, covariance_matrix, inverse_volatility_weights, realized_volatility,
+    risk_parity_weights, AtrPositionSizer, FixedRatioSizer, GlobalScorePositionSizer,
+    KellyPositionSizer, PositionSize, PositionSizer, PositionSizingConfig, SizingMode,
+    VolatilityEstimator,
 };
 
 pub use global_score_utils::{
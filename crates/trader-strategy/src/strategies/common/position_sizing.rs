@@ -1,11 +1,19 @@
 //! 포지션 사이징 전략.
 //!
 //! 이 모듈은 자금 관리를 위한 다양한 포지션 사이징 방법을 제공합니다.
+//!
+//! 변동성 기반 비중 계산([`PositionSizingConfig`])은 리스크 모듈과 공유하기 위해
+//! `trader_core`에 정의되어 있으며, 여기서 다시 내보냅니다.
 
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
+pub use trader_core::{
+    atr_volatility, covariance_matrix, inverse_volatility_weights, realized_volatility,
+    risk_parity_weights, PositionSizingConfig, SizingMode, VolatilityEstimator,
+};
+
 /// 포지션 사이징 결과.
 #[derive(Debug, Clone)]
 pub struct PositionSize {
@@ -7,6 +7,7 @@
 //! - 목표 배분 달성을 위한 주문 계산 (매수/매도)
 //! - 최소 거래 금액 필터링
 //! - 수수료 및 세금 고려
+//! - 목표 포트폴리오 신호(`SignalType::Rebalance`)를 종목별 주문 신호로 분해
 //!
 //! # 예제
 //!
@@ -35,7 +36,10 @@
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::collections::HashMap;
+use tracing::warn;
+use trader_core::{Side, Signal, SignalType};
 
 /// 리밸런싱 설정.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -263,12 +267,46 @@ impl RebalanceResult {
 #[derive(Debug, Clone)]
 pub struct RebalanceCalculator {
     config: RebalanceConfig,
+    /// 종목별 수량 단위 (미지정 종목은 1주 단위)
+    lot_steps: HashMap<String, Decimal>,
 }
 
 impl RebalanceCalculator {
     /// 새 리밸런싱 계산기 생성.
     pub fn new(config: RebalanceConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            lot_steps: HashMap::new(),
+        }
+    }
+
+    /// 종목별 수량 단위 설정 (거래소 LOT_SIZE `step_size` 등).
+    ///
+    /// 지정하지 않은 종목은 정수 수량으로 반올림됩니다.
+    pub fn with_lot_steps(mut self, lot_steps: HashMap<String, Decimal>) -> Self {
+        self.lot_steps = lot_steps;
+        self
+    }
+
+    /// 종목의 수량 단위.
+    fn lot_step(&self, ticker: &str) -> Decimal {
+        self.lot_steps
+            .get(ticker)
+            .copied()
+            .filter(|step| *step > Decimal::ZERO)
+            .unwrap_or(Decimal::ONE)
+    }
+
+    /// 수량을 단위 배수로 내림.
+    fn floor_to_lot(&self, ticker: &str, quantity: Decimal) -> Decimal {
+        let step = self.lot_step(ticker);
+        (quantity / step).floor() * step
+    }
+
+    /// 수량을 단위 배수로 올림.
+    fn ceil_to_lot(&self, ticker: &str, quantity: Decimal) -> Decimal {
+        let step = self.lot_step(ticker);
+        (quantity / step).ceil() * step
     }
 
     /// 기본 설정으로 계산기 생성.
@@ -378,14 +416,14 @@ impl RebalanceCalculator {
                 (RebalanceOrderSide::Sell, value_diff.abs())
             };
 
-            // Calculate quantity (round down for buys, round up for sells)
+            // Calculate quantity in lot steps (round down for buys, round up for sells)
             let quantity = if current_price.is_zero() {
                 dec!(0)
             } else {
                 let raw_qty = amount / current_price;
                 match side {
-                    RebalanceOrderSide::Buy => raw_qty.floor(),
-                    RebalanceOrderSide::Sell => raw_qty.ceil(),
+                    RebalanceOrderSide::Buy => self.floor_to_lot(&target.ticker, raw_qty),
+                    RebalanceOrderSide::Sell => self.ceil_to_lot(&target.ticker, raw_qty),
                 }
             };
 
@@ -553,7 +591,10 @@ impl RebalanceCalculator {
             } else if remaining_funds > self.config.min_trade_amount {
                 // Partial order with remaining funds
                 let adjusted_amount = remaining_funds - (remaining_funds * self.config.fee_rate);
-                let adjusted_qty = (adjusted_amount / (order.amount / order.quantity)).floor();
+                let adjusted_qty = self.floor_to_lot(
+                    &order.ticker,
+                    adjusted_amount / (order.amount / order.quantity),
+                );
 
                 if adjusted_qty > dec!(0) {
                     let actual_amount = adjusted_qty * (order.amount / order.quantity);
@@ -586,6 +627,118 @@ impl RebalanceCalculator {
 
         result
     }
+
+    /// 목표 포트폴리오 신호를 종목별 주문 신호로 분해.
+    ///
+    /// 현재 보유 현황과 목표 비중의 차이를 현금 제약 조건 하에서 계산하고,
+    /// 매도 신호를 매수 신호보다 먼저 반환합니다. 각 신호의 `quantity` 메타데이터에
+    /// 주문 수량이 담기며, 최대 편차가 `rebalance_threshold` 이하이면 빈 목록을 반환합니다.
+    ///
+    /// # 인수
+    ///
+    /// * `signal` - `SignalType::Rebalance` 신호
+    /// * `positions` - 현재 보유 포지션 (현금 포지션 포함)
+    /// * `prices` - 미보유 목표 종목의 현재 가격 (가격이 없는 종목은 건너뜀)
+    pub fn expand_target_signal(
+        &self,
+        signal: &Signal,
+        positions: &[PortfolioPosition],
+        prices: &HashMap<String, Decimal>,
+    ) -> Vec<Signal> {
+        if !signal.is_rebalance() {
+            return Vec::new();
+        }
+
+        let held: HashMap<&str, &PortfolioPosition> = positions
+            .iter()
+            .filter(|p| p.ticker != self.config.cash_ticker && !p.quantity.is_zero())
+            .map(|p| (p.ticker.as_str(), p))
+            .collect();
+
+        let mut all_positions = positions.to_vec();
+        let mut targets = Vec::new();
+        let mut invested = dec!(0);
+
+        for (ticker, &weight) in &signal.target_weights {
+            if weight <= dec!(0) || *ticker == self.config.cash_ticker {
+                continue;
+            }
+            if !held.contains_key(ticker.as_str()) {
+                match prices.get(ticker) {
+                    Some(&price) if price > dec!(0) => {
+                        all_positions.push(PortfolioPosition::new(ticker.clone(), dec!(0), price));
+                    }
+                    _ => {
+                        warn!(ticker = %ticker, "가격 정보가 없어 리밸런싱 대상에서 제외");
+                        continue;
+                    }
+                }
+            }
+            targets.push(TargetAllocation::new(ticker.clone(), weight));
+            invested += weight;
+        }
+
+        // 비중 합계가 1 미만이면 나머지는 현금으로 유지
+        if invested < dec!(1) {
+            targets.push(TargetAllocation::new(
+                self.config.cash_ticker.clone(),
+                dec!(1) - invested,
+            ));
+        }
+
+        let result = self.calculate_orders_with_cash_constraint(&all_positions, &targets);
+        if !result.rebalance_needed {
+            return Vec::new();
+        }
+
+        result
+            .orders
+            .iter()
+            .map(|order| {
+                let held_qty = held
+                    .get(order.ticker.as_str())
+                    .map(|p| p.quantity)
+                    .unwrap_or(dec!(0));
+                let price = order.amount / order.quantity;
+
+                let (side, signal_type, quantity) = match order.side {
+                    RebalanceOrderSide::Sell if order.quantity >= held_qty => {
+                        (Side::Sell, SignalType::Exit, held_qty)
+                    }
+                    RebalanceOrderSide::Sell => {
+                        (Side::Sell, SignalType::ReducePosition, order.quantity)
+                    }
+                    RebalanceOrderSide::Buy if held_qty.is_zero() => {
+                        (Side::Buy, SignalType::Entry, order.quantity)
+                    }
+                    RebalanceOrderSide::Buy => {
+                        (Side::Buy, SignalType::AddToPosition, order.quantity)
+                    }
+                };
+
+                let mut child = Signal::new(
+                    signal.strategy_id.clone(),
+                    order.ticker.clone(),
+                    side,
+                    signal_type,
+                )
+                .with_strength(signal.strength)
+                .with_prices(Some(price), None, None)
+                .with_metadata("quantity", json!(quantity.to_string()))
+                .with_metadata("target_weight", json!(order.target_weight.to_string()))
+                .with_metadata("rebalance_signal_id", json!(signal.id.to_string()));
+                // 추적 ID, 전략 인스턴스 ID 등 원 신호의 메타데이터 유지
+                for (key, value) in &signal.metadata {
+                    child
+                        .metadata
+                        .entry(key.clone())
+                        .or_insert_with(|| value.clone());
+                }
+                child.timestamp = signal.timestamp;
+                child
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -720,4 +873,89 @@ mod tests {
             assert!(first_is_sell || result.sell_orders().is_empty());
         }
     }
+
+    fn us_config() -> RebalanceConfig {
+        RebalanceConfig {
+            min_trade_amount: dec!(10),
+            fee_rate: dec!(0),
+            sell_tax_rate: dec!(0),
+            slippage_rate: dec!(0),
+            rebalance_threshold: dec!(0.03),
+            cash_ticker: "USD".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_expand_target_signal() {
+        let calculator = RebalanceCalculator::new(us_config());
+
+        // SPY 60%, OLD 20%, 현금 20% -> SPY 50%, TLT 30%, 현금 20%
+        let positions = vec![
+            PortfolioPosition::new("SPY", dec!(60), dec!(100)),
+            PortfolioPosition::new("OLD", dec!(20), dec!(100)),
+            PortfolioPosition::cash(dec!(2000), "USD"),
+        ];
+        let prices = HashMap::from([("TLT".to_string(), dec!(50))]);
+        let signal = Signal::target_portfolio(
+            "allocation",
+            [
+                ("SPY".to_string(), dec!(0.5)),
+                ("TLT".to_string(), dec!(0.3)),
+            ],
+        )
+        .with_metadata("decision_trace_id", json!("allocation:trace"));
+
+        let signals = calculator.expand_target_signal(&signal, &positions, &prices);
+        assert!(signals
+            .iter()
+            .all(|s| s.metadata["decision_trace_id"] == json!("allocation:trace")));
+
+        let find = |ticker: &str| signals.iter().find(|s| s.ticker == ticker).unwrap();
+        assert_eq!(signals.len(), 3);
+        assert_eq!(find("OLD").signal_type, SignalType::Exit);
+        assert_eq!(find("OLD").metadata_quantity(), Some(dec!(20)));
+        assert_eq!(find("SPY").signal_type, SignalType::ReducePosition);
+        assert_eq!(find("SPY").metadata_quantity(), Some(dec!(10)));
+        assert_eq!(find("TLT").signal_type, SignalType::Entry);
+        assert_eq!(find("TLT").metadata_quantity(), Some(dec!(60)));
+        assert_eq!(find("TLT").suggested_price, Some(dec!(50)));
+
+        // 매도 신호가 매수 신호보다 먼저
+        let last_sell = signals.iter().rposition(|s| s.side == Side::Sell).unwrap();
+        let first_buy = signals.iter().position(|s| s.side == Side::Buy).unwrap();
+        assert!(last_sell < first_buy);
+    }
+
+    #[test]
+    fn test_expand_target_signal_rounds_to_lot_step() {
+        let signal = Signal::target_portfolio("allocation", [("BTC/USDT".to_string(), dec!(0.5))]);
+        let positions = vec![PortfolioPosition::cash(dec!(1000), "USD")];
+        let prices = HashMap::from([("BTC/USDT".to_string(), dec!(30000))]);
+
+        // 단위 미지정 시 정수 수량 -> 0.0166 BTC는 0으로 내림되어 주문 없음
+        let calculator = RebalanceCalculator::new(us_config());
+        assert!(calculator
+            .expand_target_signal(&signal, &positions, &prices)
+            .is_empty());
+
+        let calculator = RebalanceCalculator::new(us_config())
+            .with_lot_steps(HashMap::from([("BTC/USDT".to_string(), dec!(0.001))]));
+        let signals = calculator.expand_target_signal(&signal, &positions, &prices);
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].metadata_quantity(), Some(dec!(0.016)));
+    }
+
+    #[test]
+    fn test_expand_target_signal_within_threshold() {
+        let calculator = RebalanceCalculator::new(us_config());
+
+        let positions = vec![
+            PortfolioPosition::new("SPY", dec!(59), dec!(100)),
+            PortfolioPosition::cash(dec!(4100), "USD"),
+        ];
+        let signal = Signal::target_portfolio("allocation", [("SPY".to_string(), dec!(0.6))]);
+
+        let signals = calculator.expand_target_signal(&signal, &positions, &HashMap::new());
+        assert!(signals.is_empty());
+    }
 }
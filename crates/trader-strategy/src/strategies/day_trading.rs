@@ -49,6 +49,7 @@
 //! });
 //! ```
 
+use crate::state_store::{decode_versioned_state, encode_versioned_state};
 use crate::strategies::common::adjust_strength_by_score;
 use crate::Strategy;
 use async_trait::async_trait;
@@ -570,7 +571,7 @@ impl Default for DayTradingConfig {
 }
 
 /// 캔들 데이터.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct CandleData {
     open: Decimal,
     high: Decimal,
@@ -581,7 +582,7 @@ struct CandleData {
 }
 
 /// 포지션 상태.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct PositionState {
     side: Side,
     entry_price: Decimal,
@@ -590,6 +591,34 @@ struct PositionState {
     lowest_price: Decimal,
 }
 
+/// 영속화 상태 포맷 버전.
+const STATE_FORMAT_VERSION: u32 = 1;
+
+/// 재시작 후 복원할 상태 (포맷 버전 1).
+///
+/// 지표 계산에 필요한 가격 히스토리와 돌파 레벨, 보유 포지션을 포함합니다.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    ticker: Option<String>,
+    candles: VecDeque<CandleData>,
+    current_candle: Option<CandleData>,
+    position: Option<PositionState>,
+    prices: VecDeque<Decimal>,
+    volumes: VecDeque<Decimal>,
+    tr_history: VecDeque<Decimal>,
+    current_atr: Option<Decimal>,
+    prev_range: Option<Decimal>,
+    prev_short_sma: Option<Decimal>,
+    prev_long_sma: Option<Decimal>,
+    upper_breakout: Option<Decimal>,
+    lower_breakout: Option<Decimal>,
+    triggered_this_period: bool,
+    trades_count: u32,
+    wins: u32,
+    losses_count: u32,
+    total_pnl: Decimal,
+}
+
 /// 일간 트레이딩 통합 전략.
 pub struct DayTradingStrategy {
     config: Option<DayTradingConfig>,
@@ -1616,6 +1645,10 @@ impl Strategy for DayTradingStrategy {
         Ok(())
     }
 
+    fn subscribed_tickers(&self) -> Vec<String> {
+        self.ticker.iter().cloned().collect()
+    }
+
     fn get_state(&self) -> Value {
         let variant = self.config.as_ref().map(|c| format!("{:?}", c.variant));
 
@@ -1641,6 +1674,73 @@ impl Strategy for DayTradingStrategy {
         self.context = Some(context);
         info!("StrategyContext injected into DayTrading strategy");
     }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let persisted = PersistedState {
+            ticker: self.ticker.clone(),
+            candles: self.candles.clone(),
+            current_candle: self.current_candle.clone(),
+            position: self.position.clone(),
+            prices: self.prices.clone(),
+            volumes: self.volumes.clone(),
+            tr_history: self.tr_history.clone(),
+            current_atr: self.current_atr,
+            prev_range: self.prev_range,
+            prev_short_sma: self.prev_short_sma,
+            prev_long_sma: self.prev_long_sma,
+            upper_breakout: self.upper_breakout,
+            lower_breakout: self.lower_breakout,
+            triggered_this_period: self.triggered_this_period,
+            trades_count: self.trades_count,
+            wins: self.wins,
+            losses_count: self.losses_count,
+            total_pnl: self.total_pnl,
+        };
+        Ok(encode_versioned_state(STATE_FORMAT_VERSION, &persisted)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let (version, value) = decode_versioned_state(data)?;
+        let persisted: PersistedState = match version {
+            1 => serde_json::from_value(value)?,
+            v => return Err(format!("지원하지 않는 상태 포맷 버전: {}", v).into()),
+        };
+
+        // 설정에서 티커가 바뀐 경우 이전 티커의 가격 히스토리는 사용하지 않음
+        if persisted.ticker != self.ticker {
+            info!(
+                saved = ?persisted.ticker,
+                current = ?self.ticker,
+                "티커가 변경되어 DayTrading 상태 복원 생략"
+            );
+            return Ok(());
+        }
+
+        self.candles = persisted.candles;
+        self.current_candle = persisted.current_candle;
+        self.position = persisted.position;
+        self.prices = persisted.prices;
+        self.volumes = persisted.volumes;
+        self.tr_history = persisted.tr_history;
+        self.current_atr = persisted.current_atr;
+        self.prev_range = persisted.prev_range;
+        self.prev_short_sma = persisted.prev_short_sma;
+        self.prev_long_sma = persisted.prev_long_sma;
+        self.upper_breakout = persisted.upper_breakout;
+        self.lower_breakout = persisted.lower_breakout;
+        self.triggered_this_period = persisted.triggered_this_period;
+        self.trades_count = persisted.trades_count;
+        self.wins = persisted.wins;
+        self.losses_count = persisted.losses_count;
+        self.total_pnl = persisted.total_pnl;
+
+        info!(
+            has_position = self.position.is_some(),
+            upper_breakout = ?self.upper_breakout,
+            "DayTrading 상태 복원"
+        );
+        Ok(())
+    }
 }
 
 // ============================================================================
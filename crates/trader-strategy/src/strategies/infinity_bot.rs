@@ -18,6 +18,7 @@
 //! - `MarketRegime`: 진입 조건 결정
 //! - `RouteState`: Attack/Armed 시 적극 진입
 
+use crate::state_store::{decode_versioned_state, encode_versioned_state};
 use crate::Strategy;
 use async_trait::async_trait;
 use rust_decimal::Decimal;
@@ -187,6 +188,16 @@ impl InfinityBotState {
     }
 }
 
+/// 영속화 상태 포맷 버전
+const STATE_FORMAT_VERSION: u32 = 1;
+
+/// 재시작 후 복원할 상태 (포맷 버전 1)
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    state: InfinityBotState,
+    last_entry_price: Option<Decimal>,
+}
+
 // ============================================================================
 // 전략 구현
 // ============================================================================
@@ -638,6 +649,31 @@ impl Strategy for InfinityBotStrategy {
             "last_entry_price": self.last_entry_price.map(|d| d.to_string()),
         })
     }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let persisted = PersistedState {
+            state: self.state.clone(),
+            last_entry_price: self.last_entry_price,
+        };
+        Ok(encode_versioned_state(STATE_FORMAT_VERSION, &persisted)?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let (version, value) = decode_versioned_state(data)?;
+        let persisted: PersistedState = match version {
+            1 => serde_json::from_value(value)?,
+            v => return Err(format!("지원하지 않는 상태 포맷 버전: {}", v).into()),
+        };
+
+        info!(
+            rounds = persisted.state.current_round,
+            total_qty = %persisted.state.total_quantity,
+            "InfinityBot 상태 복원"
+        );
+        self.state = persisted.state;
+        self.last_entry_price = persisted.last_entry_price;
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -722,4 +758,48 @@ mod tests {
         assert!(strategy.config.is_some());
         assert_eq!(strategy.state.current_round, 0);
     }
+
+    #[tokio::test]
+    async fn test_state_survives_restart() {
+        let config = json!({ "ticker": "005930" });
+
+        let mut strategy = InfinityBotStrategy::new();
+        strategy.initialize(config.clone()).await.unwrap();
+        strategy.state.current_round = 2;
+        strategy.state.rounds = vec![
+            RoundInfo {
+                round: 1,
+                entry_price: dec!(1000),
+                quantity: dec!(10),
+                timestamp: 1,
+            },
+            RoundInfo {
+                round: 2,
+                entry_price: dec!(800),
+                quantity: dec!(10),
+                timestamp: 2,
+            },
+        ];
+        strategy.state.invested_amount = dec!(18000);
+        strategy.state.total_quantity = dec!(20);
+        strategy.state.avg_price = strategy.state.calculate_avg_price();
+        strategy.last_entry_price = Some(dec!(800));
+        let saved = strategy.save_state().unwrap();
+
+        let mut restarted = InfinityBotStrategy::new();
+        restarted.initialize(config).await.unwrap();
+        restarted.load_state(&saved).unwrap();
+
+        assert_eq!(restarted.state.current_round, 2);
+        assert_eq!(restarted.state.rounds.len(), 2);
+        assert_eq!(restarted.state.avg_price, Some(dec!(900)));
+        assert_eq!(restarted.last_entry_price, Some(dec!(800)));
+    }
+
+    #[test]
+    fn test_load_state_rejects_unknown_format() {
+        let mut strategy = InfinityBotStrategy::new();
+        let data = encode_versioned_state(99, &json!({})).unwrap();
+        assert!(strategy.load_state(&data).is_err());
+    }
 }
@@ -181,17 +181,17 @@ pub struct RsiConfig {
 
     /// RSI 기간.
     #[serde(default = "default_rsi_period")]
-    #[schema(label = "RSI 기간", field_type = "integer", min = 2, max = 100, default = 14, section = "indicator")]
+    #[schema(label = "RSI 기간", field_type = "integer", min = 2, max = 100, step = 2, default = 14, section = "indicator")]
     pub rsi_period: usize,
 
     /// 과매도 임계값.
     #[serde(default = "default_oversold")]
-    #[schema(label = "과매도 임계값", field_type = "number", min = 0, max = 50, default = 30, section = "indicator")]
+    #[schema(label = "과매도 임계값", field_type = "number", min = 0, max = 50, step = 5, default = 30, section = "indicator")]
     pub oversold: Decimal,
 
     /// 과매수 임계값.
     #[serde(default = "default_overbought")]
-    #[schema(label = "과매수 임계값", field_type = "number", min = 50, max = 100, default = 70, section = "indicator")]
+    #[schema(label = "과매수 임계값", field_type = "number", min = 50, max = 100, step = 5, default = 70, section = "indicator")]
     pub overbought: Decimal,
 
     /// 청산 설정.
@@ -1677,6 +1677,10 @@ impl Strategy for MeanReversionStrategy {
         Ok(())
     }
 
+    fn subscribed_tickers(&self) -> Vec<String> {
+        self.config.iter().map(|c| c.ticker.clone()).collect()
+    }
+
     fn get_state(&self) -> Value {
         json!({
             "name": self.name(),
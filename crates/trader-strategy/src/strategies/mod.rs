@@ -21,6 +21,8 @@
 //! - **Pension Bot**: 연금 자동화 정적+동적 자산배분.
 //! - **US 3X Leverage**: 미국 3배 레버리지/인버스 ETF 조합 전략.
 //! - **RSI Multi TF**: RSI 다중 타임프레임 전략.
+//! - **Script**: 런타임에 로드되는 Rhai 스크립트 전략.
+//! - **Rule**: 지표 조건을 선언적으로 조합하는 규칙 기반 전략.
 //!
 //! ## 한국 지수 전략
 //!
@@ -51,6 +53,8 @@ pub mod momentum_surge;
 pub mod pension_bot;
 pub mod range_trading;
 pub mod rsi_multi_tf;
+pub mod rule;
+pub mod script;
 pub mod sector_vb;
 pub mod small_cap_quant;
 pub mod us_3x_leverage;
@@ -87,6 +91,11 @@ pub use momentum_surge::*;
 pub use pension_bot::*;
 pub use range_trading::*;
 pub use rsi_multi_tf::*;
+pub use rule::{
+    rule_catalog, Comparator, FragmentOperand, Operand, RuleCatalog, RuleCondition, RuleConfig,
+    RuleError, RuleGroup, RuleLogic, RuleStrategy, RULE_STRATEGY_ID,
+};
+pub use script::{ScriptConfig, ScriptError, ScriptStrategy, SCRIPT_STRATEGY_ID};
 pub use sector_vb::*;
 pub use small_cap_quant::*;
 pub use us_3x_leverage::*;
@@ -29,6 +29,7 @@
 //! // on_multi_timeframe_data()가 호출됩니다.
 //! ```
 
+use crate::state_store::{decode_versioned_state, encode_versioned_state};
 use crate::strategies::common::{deserialize_ticker, ExitConfig};
 use crate::{register_strategy, Strategy};
 use trader_strategy_macro::StrategyConfig;
@@ -168,7 +169,7 @@ impl Default for RsiMultiTfConfig {
 }
 
 /// 포지션 상태
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 enum PositionState {
     /// 포지션 없음
     Flat,
@@ -176,6 +177,51 @@ enum PositionState {
     Long,
 }
 
+/// 영속화 상태 포맷 버전.
+///
+/// - 0: 버전 정보 없는 JSON (캔들 히스토리/쿨다운 미포함)
+/// - 1: 캔들 히스토리와 쿨다운 카운터 포함
+const STATE_FORMAT_VERSION: u32 = 1;
+
+/// 재시작 후 복원할 상태 (포맷 버전 1)
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    position_state: PositionState,
+    entry_price: Option<Decimal>,
+    trades_count: u32,
+    wins: u32,
+    losses: u32,
+    total_pnl: Decimal,
+    m5_prev_rsi: Option<Decimal>,
+    candle_history: HashMap<Timeframe, Vec<Decimal>>,
+    cooldown_counter: usize,
+}
+
+impl PersistedState {
+    /// 버전 0 상태를 현재 포맷으로 변환.
+    ///
+    /// 캔들 히스토리는 저장되지 않았으므로 비워두고 컨텍스트에서 다시 채웁니다.
+    fn from_legacy(state: &Value) -> Self {
+        let decimal = |v: Option<&Value>| v.and_then(|v| v.as_str()).and_then(|s| s.parse().ok());
+        let count = |key: &str| state.get(key).and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+
+        Self {
+            position_state: match state.get("position_state").and_then(|v| v.as_str()) {
+                Some("Long") => PositionState::Long,
+                _ => PositionState::Flat,
+            },
+            entry_price: decimal(state.get("entry_price")),
+            trades_count: count("trades_count"),
+            wins: count("wins"),
+            losses: count("losses"),
+            total_pnl: decimal(state.get("total_pnl")).unwrap_or(Decimal::ZERO),
+            m5_prev_rsi: decimal(state.get("rsi_values").and_then(|r| r.get("m5_prev"))),
+            candle_history: HashMap::new(),
+            cooldown_counter: 0,
+        }
+    }
+}
+
 /// 타임프레임별 RSI 값
 #[derive(Debug, Clone, Default)]
 struct MultiTfRsiValues {
@@ -787,55 +833,37 @@ impl Strategy for RsiMultiTfStrategy {
     }
 
     fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
-        let state = json!({
-            "position_state": format!("{:?}", self.position_state),
-            "entry_price": self.entry_price,
-            "trades_count": self.trades_count,
-            "wins": self.wins,
-            "losses": self.losses,
-            "total_pnl": self.total_pnl.to_string(),
-            "rsi_values": {
-                "m5_prev": self.rsi_values.m5_prev.map(|v| v.to_string())
-            }
-        });
-        Ok(serde_json::to_vec(&state)?)
+        let persisted = PersistedState {
+            position_state: self.position_state,
+            entry_price: self.entry_price,
+            trades_count: self.trades_count,
+            wins: self.wins,
+            losses: self.losses,
+            total_pnl: self.total_pnl,
+            m5_prev_rsi: self.rsi_values.m5_prev,
+            candle_history: self.candle_history.clone(),
+            cooldown_counter: self.cooldown_counter,
+        };
+        Ok(encode_versioned_state(STATE_FORMAT_VERSION, &persisted)?)
     }
 
     fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let state: Value = serde_json::from_slice(data)?;
-
-        if let Some(pos_str) = state.get("position_state").and_then(|v| v.as_str()) {
-            self.position_state = match pos_str {
-                "Long" => PositionState::Long,
-                _ => PositionState::Flat,
-            };
-        }
-
-        if let Some(price) = state.get("entry_price").and_then(|v| v.as_str()) {
-            self.entry_price = price.parse().ok();
-        }
-
-        if let Some(count) = state.get("trades_count").and_then(|v| v.as_u64()) {
-            self.trades_count = count as u32;
-        }
-
-        if let Some(wins) = state.get("wins").and_then(|v| v.as_u64()) {
-            self.wins = wins as u32;
-        }
-
-        if let Some(losses) = state.get("losses").and_then(|v| v.as_u64()) {
-            self.losses = losses as u32;
-        }
-
-        if let Some(pnl) = state.get("total_pnl").and_then(|v| v.as_str()) {
-            self.total_pnl = pnl.parse().unwrap_or(Decimal::ZERO);
-        }
+        let (version, state) = decode_versioned_state(data)?;
+        let persisted: PersistedState = match version {
+            0 => PersistedState::from_legacy(&state),
+            1 => serde_json::from_value(state)?,
+            v => return Err(format!("지원하지 않는 상태 포맷 버전: {}", v).into()),
+        };
 
-        if let Some(rsi_obj) = state.get("rsi_values") {
-            if let Some(m5_prev) = rsi_obj.get("m5_prev").and_then(|v| v.as_str()) {
-                self.rsi_values.m5_prev = m5_prev.parse().ok();
-            }
-        }
+        self.position_state = persisted.position_state;
+        self.entry_price = persisted.entry_price;
+        self.trades_count = persisted.trades_count;
+        self.wins = persisted.wins;
+        self.losses = persisted.losses;
+        self.total_pnl = persisted.total_pnl;
+        self.rsi_values.m5_prev = persisted.m5_prev_rsi;
+        self.candle_history = persisted.candle_history;
+        self.cooldown_counter = persisted.cooldown_counter;
 
         Ok(())
     }
@@ -927,4 +955,53 @@ mod tests {
         assert_eq!(strategy.ticker.as_deref(), Some("ETH/USDT"));
         assert!(strategy.initialized);
     }
+
+    #[test]
+    fn test_state_roundtrip() {
+        let mut strategy = RsiMultiTfStrategy::new();
+        strategy.position_state = PositionState::Long;
+        strategy.entry_price = Some(dec!(2500));
+        strategy.trades_count = 4;
+        strategy.total_pnl = dec!(120.5);
+        strategy
+            .candle_history
+            .insert(Timeframe::H1, vec![dec!(2400), dec!(2450)]);
+        strategy.cooldown_counter = 2;
+        let saved = strategy.save_state().unwrap();
+
+        let mut restored = RsiMultiTfStrategy::new();
+        restored.load_state(&saved).unwrap();
+
+        assert_eq!(restored.position_state, PositionState::Long);
+        assert_eq!(restored.entry_price, Some(dec!(2500)));
+        assert_eq!(restored.trades_count, 4);
+        assert_eq!(restored.total_pnl, dec!(120.5));
+        assert_eq!(restored.candle_history[&Timeframe::H1].len(), 2);
+        assert_eq!(restored.cooldown_counter, 2);
+    }
+
+    #[test]
+    fn test_load_legacy_state() {
+        // 포맷 버전 도입 이전에 저장된 상태
+        let legacy = serde_json::to_vec(&json!({
+            "position_state": "Long",
+            "entry_price": "2500",
+            "trades_count": 3,
+            "wins": 2,
+            "losses": 1,
+            "total_pnl": "45.5",
+            "rsi_values": { "m5_prev": "28.1" }
+        }))
+        .unwrap();
+
+        let mut strategy = RsiMultiTfStrategy::new();
+        strategy.load_state(&legacy).unwrap();
+
+        assert_eq!(strategy.position_state, PositionState::Long);
+        assert_eq!(strategy.entry_price, Some(dec!(2500)));
+        assert_eq!(strategy.wins, 2);
+        assert_eq!(strategy.total_pnl, dec!(45.5));
+        assert_eq!(strategy.rsi_values.m5_prev, Some(dec!(28.1)));
+        assert!(strategy.candle_history.is_empty());
+    }
 }
@@ -0,0 +1,1618 @@
+//! 규칙 기반 전략 (선언적 조건 조합).
+//!
+//! 코드 작성 없이 JSON으로 진입/청산 규칙을 조합합니다. 각 조건은 SDUI 지표
+//! Fragment(`indicator.rsi`, `indicator.ma` 등)를 피연산자로 하는 비교식이며,
+//! 지표 파라미터의 기본값과 허용 범위는 [`FragmentRegistry`]의 필드 스키마를 따릅니다.
+//!
+//! ```json
+//! {
+//!   "ticker": "005930",
+//!   "entry": {
+//!     "logic": "all",
+//!     "conditions": [
+//!       { "left": { "fragment": "indicator.rsi", "params": { "period": 14 } }, "op": "<", "right": 30 },
+//!       { "left": { "fragment": "price", "output": "close" }, "op": ">",
+//!         "right": { "fragment": "indicator.ma", "params": { "ma_type": "sma", "period": 200 } } },
+//!       { "left": { "fragment": "filter.route_state" }, "op": "==", "right": "ATTACK" }
+//!     ]
+//!   },
+//!   "exit": {
+//!     "logic": "any",
+//!     "conditions": [
+//!       { "left": { "fragment": "indicator.rsi" }, "op": ">", "right": 70 }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! ## 피연산자
+//!
+//! - 숫자/문자열 리터럴
+//! - `price`: `close`, `open`, `high`, `low`, `volume`
+//! - 지표 Fragment: `indicator.rsi`, `indicator.ma`, `indicator.bollinger`
+//!   (`upper`/`middle`/`lower`/`width`), `indicator.macd` (`macd`/`signal`/`histogram`),
+//!   `indicator.atr`
+//! - StrategyContext: `filter.route_state`, `filter.market_regime`, `context.global_score`
+//!
+//! 지표는 수신한 캔들 이력으로 계산합니다. 분석 크레이트의 `IndicatorEngine`은 이 크레이트에
+//! 의존하므로, 다른 내장 전략과 같은 [`crate::strategies::common::indicators`] 함수를 사용합니다.
+//!
+//! 데이터 부족 등으로 값을 구할 수 없는 조건은 거짓으로 평가됩니다.
+
+use crate::decision::Decision;
+use crate::schema_registry::FragmentRegistry;
+use crate::state_store::{decode_versioned_state, encode_versioned_state};
+use crate::strategies::common::indicators::{
+    calculate_atr, calculate_bollinger_bands, calculate_ema, calculate_macd, calculate_rsi,
+    calculate_sma,
+};
+use crate::strategies::common::{deserialize_ticker, ExitConfig};
+use crate::{register_strategy, Strategy};
+use async_trait::async_trait;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+use trader_core::{
+    domain::StrategyContext, FieldSchema, FieldType, Kline, MarketData, MarketDataType,
+    MarketRegime, Order, Position, RouteState, Side, Signal,
+};
+use trader_strategy_macro::StrategyConfig;
+
+/// 레지스트리 전략 ID.
+pub const RULE_STRATEGY_ID: &str = "rule";
+
+/// 영속화 상태 포맷 버전.
+const STATE_FORMAT_VERSION: u32 = 1;
+
+/// 엔진이 가져가지 않을 때(백테스트 등) 보관하는 최대 의사결정 수.
+const MAX_PENDING_DECISIONS: usize = 256;
+
+// ============================================================================
+// 에러
+// ============================================================================
+
+/// 규칙 전략 에러.
+#[derive(Error, Debug)]
+pub enum RuleError {
+    #[error("설정 에러: {0}")]
+    Config(String),
+
+    #[error("{group} 규칙 #{index}: {message}")]
+    InvalidRule {
+        group: &'static str,
+        index: usize,
+        message: String,
+    },
+}
+
+// ============================================================================
+// 규칙 정의
+// ============================================================================
+
+/// 비교 연산자.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparator {
+    #[serde(rename = "<", alias = "lt")]
+    Lt,
+    #[serde(rename = "<=", alias = "le")]
+    Le,
+    #[serde(rename = ">", alias = "gt")]
+    Gt,
+    #[serde(rename = ">=", alias = "ge")]
+    Ge,
+    #[serde(rename = "==", alias = "eq")]
+    Eq,
+    #[serde(rename = "!=", alias = "ne")]
+    Ne,
+    /// 직전 캔들에서 아래 → 현재 캔들에서 위
+    #[serde(rename = "cross_above")]
+    CrossAbove,
+    /// 직전 캔들에서 위 → 현재 캔들에서 아래
+    #[serde(rename = "cross_below")]
+    CrossBelow,
+}
+
+impl Comparator {
+    const ALL: [Comparator; 8] = [
+        Comparator::Lt,
+        Comparator::Le,
+        Comparator::Gt,
+        Comparator::Ge,
+        Comparator::Eq,
+        Comparator::Ne,
+        Comparator::CrossAbove,
+        Comparator::CrossBelow,
+    ];
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Comparator::Lt => "<",
+            Comparator::Le => "<=",
+            Comparator::Gt => ">",
+            Comparator::Ge => ">=",
+            Comparator::Eq => "==",
+            Comparator::Ne => "!=",
+            Comparator::CrossAbove => "cross_above",
+            Comparator::CrossBelow => "cross_below",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Comparator::Lt => "미만",
+            Comparator::Le => "이하",
+            Comparator::Gt => "초과",
+            Comparator::Ge => "이상",
+            Comparator::Eq => "같음",
+            Comparator::Ne => "다름",
+            Comparator::CrossAbove => "상향 돌파",
+            Comparator::CrossBelow => "하향 돌파",
+        }
+    }
+
+    fn is_cross(self) -> bool {
+        matches!(self, Comparator::CrossAbove | Comparator::CrossBelow)
+    }
+
+    fn is_equality(self) -> bool {
+        matches!(self, Comparator::Eq | Comparator::Ne)
+    }
+}
+
+/// 지표/컨텍스트 참조.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FragmentOperand {
+    /// Fragment ID (예: "indicator.rsi") 또는 내장 소스 ID ("price", "context.global_score")
+    pub fragment: String,
+
+    /// 출력 값 (여러 값을 내는 지표용, 생략 시 첫 번째 출력)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+
+    /// 지표 파라미터 (생략 시 Fragment 필드 기본값)
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub params: Map<String, Value>,
+}
+
+/// 조건 피연산자.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Operand {
+    /// 숫자 리터럴
+    Number(f64),
+    /// 문자열 리터럴 (RouteState, MarketRegime 비교용)
+    Text(String),
+    /// 지표/컨텍스트 참조
+    Fragment(FragmentOperand),
+}
+
+/// 단일 비교 조건.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleCondition {
+    pub left: Operand,
+    pub op: Comparator,
+    pub right: Operand,
+}
+
+/// 조건 결합 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleLogic {
+    /// 모든 조건 충족 (AND)
+    #[default]
+    All,
+    /// 하나 이상 충족 (OR)
+    Any,
+}
+
+/// 조건 그룹.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleGroup {
+    #[serde(default)]
+    pub logic: RuleLogic,
+
+    #[serde(default)]
+    pub conditions: Vec<RuleCondition>,
+}
+
+// ============================================================================
+// 설정 (Config)
+// ============================================================================
+
+/// 규칙 기반 전략 설정.
+#[derive(Debug, Clone, Serialize, Deserialize, StrategyConfig)]
+#[strategy(
+    id = "rule",
+    name = "규칙 기반 전략",
+    description = "지표 조건을 조합해 진입/청산 규칙을 정의하는 전략",
+    category = "Daily"
+)]
+pub struct RuleConfig {
+    /// 거래 종목
+    #[serde(deserialize_with = "deserialize_ticker")]
+    #[schema(
+        label = "거래 종목",
+        field_type = "symbol",
+        default = "005930",
+        section = "asset"
+    )]
+    pub ticker: String,
+
+    /// 진입 규칙
+    #[schema(label = "진입 규칙", field_type = "rules", section = "indicator")]
+    pub entry: RuleGroup,
+
+    /// 청산 규칙 (비어 있으면 손절/익절로만 청산)
+    #[serde(default)]
+    #[schema(label = "청산 규칙", field_type = "rules", section = "indicator")]
+    pub exit: RuleGroup,
+
+    /// 지표 계산에 보관할 캔들 수
+    #[serde(default = "default_history_size")]
+    #[schema(
+        label = "캔들 이력 크기",
+        min = 50,
+        max = 2000,
+        default = 300,
+        section = "timing"
+    )]
+    pub history_size: usize,
+
+    /// 청산 설정 (손절/익절).
+    #[serde(default)]
+    #[fragment("risk.exit_config")]
+    pub exit_config: ExitConfig,
+}
+
+fn default_history_size() -> usize {
+    300
+}
+
+impl RuleConfig {
+    /// JSON 설정 파싱 및 규칙 컴파일.
+    fn parse(config: &Value) -> Result<(Self, CompiledRules), RuleError> {
+        let parsed: Self =
+            serde_json::from_value(config.clone()).map_err(|e| RuleError::Config(e.to_string()))?;
+
+        if parsed.ticker.trim().is_empty() {
+            return Err(RuleError::Config("ticker가 비어 있습니다".to_string()));
+        }
+        if parsed.entry.conditions.is_empty() {
+            return Err(RuleError::Config(
+                "진입 규칙이 최소 1개 필요합니다".to_string(),
+            ));
+        }
+
+        let registry = FragmentRegistry::with_builtins();
+        let compiler = RuleCompiler {
+            registry: &registry,
+            history_size: parsed.history_size,
+        };
+        let rules = CompiledRules {
+            entry: compiler.compile_group("진입", &parsed.entry)?,
+            exit: compiler.compile_group("청산", &parsed.exit)?,
+        };
+
+        Ok((parsed, rules))
+    }
+}
+
+// ============================================================================
+// 피연산자 소스 카탈로그
+// ============================================================================
+
+/// 피연산자 값 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperandKind {
+    /// 캔들 이력에서 계산되는 수치 (돌파 비교 가능)
+    Series,
+    /// 컨텍스트 수치 (현재 값만 존재)
+    Number,
+    /// 범주형 값 (같음/다름 비교만 가능)
+    Category,
+}
+
+/// 피연산자 소스 정의.
+struct SourceSpec {
+    id: &'static str,
+    label: &'static str,
+    kind: OperandKind,
+    outputs: &'static [&'static str],
+    /// Fragment 필드 중 지표 파라미터로 사용하는 필드
+    params: &'static [&'static str],
+}
+
+const SOURCES: &[SourceSpec] = &[
+    SourceSpec {
+        id: "price",
+        label: "가격",
+        kind: OperandKind::Series,
+        outputs: &["close", "open", "high", "low", "volume"],
+        params: &[],
+    },
+    SourceSpec {
+        id: "indicator.rsi",
+        label: "RSI",
+        kind: OperandKind::Series,
+        outputs: &["value"],
+        params: &["period"],
+    },
+    SourceSpec {
+        id: "indicator.ma",
+        label: "이동평균",
+        kind: OperandKind::Series,
+        outputs: &["value"],
+        params: &["ma_type", "period"],
+    },
+    SourceSpec {
+        id: "indicator.bollinger",
+        label: "볼린저 밴드",
+        kind: OperandKind::Series,
+        outputs: &["upper", "middle", "lower", "width"],
+        params: &["period", "std_dev"],
+    },
+    SourceSpec {
+        id: "indicator.macd",
+        label: "MACD",
+        kind: OperandKind::Series,
+        outputs: &["macd", "signal", "histogram"],
+        params: &["fast_period", "slow_period", "signal_period"],
+    },
+    SourceSpec {
+        id: "indicator.atr",
+        label: "ATR",
+        kind: OperandKind::Series,
+        outputs: &["value"],
+        params: &["period"],
+    },
+    SourceSpec {
+        id: "context.global_score",
+        label: "GlobalScore",
+        kind: OperandKind::Number,
+        outputs: &["value"],
+        params: &[],
+    },
+    SourceSpec {
+        id: "filter.route_state",
+        label: "RouteState",
+        kind: OperandKind::Category,
+        outputs: &["value"],
+        params: &[],
+    },
+    SourceSpec {
+        id: "filter.market_regime",
+        label: "시장 레짐",
+        kind: OperandKind::Category,
+        outputs: &["value"],
+        params: &[],
+    },
+];
+
+const ROUTE_STATES: [RouteState; 5] = [
+    RouteState::Attack,
+    RouteState::Armed,
+    RouteState::Wait,
+    RouteState::Overheat,
+    RouteState::Neutral,
+];
+
+const MARKET_REGIMES: [MarketRegime; 5] = [
+    MarketRegime::StrongUptrend,
+    MarketRegime::Correction,
+    MarketRegime::Sideways,
+    MarketRegime::BottomBounce,
+    MarketRegime::Downtrend,
+];
+
+/// serde 직렬화 이름 (SCREAMING_SNAKE_CASE enum용).
+fn serde_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// 범주형 소스의 허용 값.
+fn category_values(source: &str) -> Vec<String> {
+    match source {
+        "filter.route_state" => ROUTE_STATES.iter().map(serde_name).collect(),
+        "filter.market_regime" => MARKET_REGIMES.iter().map(serde_name).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// "Attack", "strong_uptrend", "StrongUptrend" → "ATTACK", "STRONG_UPTREND".
+fn normalize_category(value: &str) -> String {
+    let mut normalized = String::with_capacity(value.len() + 4);
+    let mut prev_lower = false;
+    for c in value.trim().chars() {
+        if c == '-' || c == ' ' {
+            normalized.push('_');
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower {
+            normalized.push('_');
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        normalized.extend(c.to_uppercase());
+    }
+    normalized
+}
+
+/// 규칙 빌더용 피연산자 소스 정보.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleSourceInfo {
+    pub id: String,
+    pub label: String,
+    pub kind: OperandKind,
+    pub outputs: Vec<String>,
+    /// 파라미터 필드 (Fragment 필드 스키마)
+    pub params: Vec<FieldSchema>,
+    /// 범주형 소스의 허용 값
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+}
+
+/// 비교 연산자 정보.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparatorInfo {
+    pub id: String,
+    pub label: String,
+}
+
+/// 규칙 빌더 카탈로그 (프론트엔드 렌더링용).
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleCatalog {
+    pub sources: Vec<RuleSourceInfo>,
+    pub comparators: Vec<ComparatorInfo>,
+}
+
+/// 규칙 빌더가 사용할 수 있는 피연산자와 연산자 목록.
+pub fn rule_catalog(registry: &FragmentRegistry) -> RuleCatalog {
+    let sources = SOURCES
+        .iter()
+        .map(|spec| RuleSourceInfo {
+            id: spec.id.to_string(),
+            label: spec.label.to_string(),
+            kind: spec.kind,
+            outputs: spec.outputs.iter().map(|o| o.to_string()).collect(),
+            params: spec
+                .params
+                .iter()
+                .filter_map(|name| param_field(registry, spec.id, name).cloned())
+                .collect(),
+            values: category_values(spec.id),
+        })
+        .collect();
+
+    let comparators = Comparator::ALL
+        .iter()
+        .map(|op| ComparatorInfo {
+            id: op.symbol().to_string(),
+            label: op.label().to_string(),
+        })
+        .collect();
+
+    RuleCatalog {
+        sources,
+        comparators,
+    }
+}
+
+fn param_field<'a>(
+    registry: &'a FragmentRegistry,
+    fragment: &str,
+    name: &str,
+) -> Option<&'a FieldSchema> {
+    registry
+        .get(fragment)?
+        .fields
+        .iter()
+        .find(|f| f.name == name)
+}
+
+// ============================================================================
+// 규칙 컴파일 (검증)
+// ============================================================================
+
+/// 파라미터가 확정된 지표.
+#[derive(Debug, Clone, PartialEq)]
+enum Indicator {
+    Price,
+    Rsi {
+        period: usize,
+    },
+    Ma {
+        ema: bool,
+        period: usize,
+    },
+    Bollinger {
+        period: usize,
+        std_dev: Decimal,
+    },
+    Macd {
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    },
+    Atr {
+        period: usize,
+    },
+}
+
+impl Indicator {
+    /// 값 계산에 필요한 최소 캔들 수.
+    fn lookback(&self) -> usize {
+        match self {
+            Indicator::Price => 1,
+            Indicator::Rsi { period } | Indicator::Atr { period } => period + 1,
+            Indicator::Ma { period, .. } | Indicator::Bollinger { period, .. } => *period,
+            Indicator::Macd { slow, signal, .. } => slow + signal,
+        }
+    }
+}
+
+/// 컴파일된 피연산자.
+#[derive(Debug, Clone, PartialEq)]
+enum CompiledOperand {
+    Number(Decimal),
+    Category(String),
+    Series {
+        indicator: Indicator,
+        output: &'static str,
+    },
+    GlobalScore,
+    RouteState,
+    MarketRegime,
+}
+
+impl CompiledOperand {
+    fn kind(&self) -> Option<OperandKind> {
+        match self {
+            CompiledOperand::Number(_) | CompiledOperand::Category(_) => None,
+            CompiledOperand::Series { .. } => Some(OperandKind::Series),
+            CompiledOperand::GlobalScore => Some(OperandKind::Number),
+            CompiledOperand::RouteState | CompiledOperand::MarketRegime => {
+                Some(OperandKind::Category)
+            }
+        }
+    }
+
+    fn is_category(&self) -> bool {
+        matches!(
+            self,
+            CompiledOperand::Category(_)
+                | CompiledOperand::RouteState
+                | CompiledOperand::MarketRegime
+        )
+    }
+}
+
+impl fmt::Display for CompiledOperand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompiledOperand::Number(value) => write!(f, "{}", value.normalize()),
+            CompiledOperand::Category(value) => write!(f, "{}", value),
+            CompiledOperand::GlobalScore => write!(f, "global_score"),
+            CompiledOperand::RouteState => write!(f, "route_state"),
+            CompiledOperand::MarketRegime => write!(f, "market_regime"),
+            CompiledOperand::Series { indicator, output } => match indicator {
+                Indicator::Price => write!(f, "{}", output),
+                Indicator::Rsi { period } => write!(f, "RSI({})", period),
+                Indicator::Ma { ema, period } => {
+                    write!(f, "{}({})", if *ema { "EMA" } else { "SMA" }, period)
+                }
+                Indicator::Bollinger { period, std_dev } => {
+                    write!(f, "BB({},{}).{}", period, std_dev.normalize(), output)
+                }
+                Indicator::Macd { fast, slow, signal } => {
+                    write!(f, "MACD({},{},{}).{}", fast, slow, signal, output)
+                }
+                Indicator::Atr { period } => write!(f, "ATR({})", period),
+            },
+        }
+    }
+}
+
+/// 컴파일된 조건.
+#[derive(Debug, Clone)]
+struct CompiledCondition {
+    left: CompiledOperand,
+    op: Comparator,
+    right: CompiledOperand,
+}
+
+impl fmt::Display for CompiledCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.left, self.op.symbol(), self.right)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct CompiledGroup {
+    logic: RuleLogic,
+    conditions: Vec<CompiledCondition>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CompiledRules {
+    entry: CompiledGroup,
+    exit: CompiledGroup,
+}
+
+struct RuleCompiler<'a> {
+    registry: &'a FragmentRegistry,
+    history_size: usize,
+}
+
+impl RuleCompiler<'_> {
+    fn compile_group(
+        &self,
+        group: &'static str,
+        rules: &RuleGroup,
+    ) -> Result<CompiledGroup, RuleError> {
+        let conditions = rules
+            .conditions
+            .iter()
+            .enumerate()
+            .map(|(i, condition)| {
+                self.compile_condition(condition)
+                    .map_err(|message| RuleError::InvalidRule {
+                        group,
+                        index: i + 1,
+                        message,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(CompiledGroup {
+            logic: rules.logic,
+            conditions,
+        })
+    }
+
+    fn compile_condition(&self, condition: &RuleCondition) -> Result<CompiledCondition, String> {
+        let left = self.compile_operand(&condition.left)?;
+        let right = self.compile_operand(&condition.right)?;
+        let op = condition.op;
+
+        if left.kind().is_none() && right.kind().is_none() {
+            return Err("양쪽 모두 리터럴입니다. 최소 한쪽은 지표여야 합니다".to_string());
+        }
+
+        if left.is_category() || right.is_category() {
+            if !op.is_equality() {
+                return Err(format!(
+                    "범주형 값에는 == 또는 != 만 사용할 수 있습니다 ({})",
+                    op.symbol()
+                ));
+            }
+            let (source, literal) = match (&left, &right) {
+                (CompiledOperand::Category(value), source)
+                | (source, CompiledOperand::Category(value)) => (source, value),
+                _ => {
+                    return Err("범주형 값은 문자열 리터럴과 비교해야 합니다".to_string());
+                }
+            };
+            let source_id = match source {
+                CompiledOperand::RouteState => "filter.route_state",
+                CompiledOperand::MarketRegime => "filter.market_regime",
+                _ => return Err("문자열 리터럴은 범주형 값과만 비교할 수 있습니다".to_string()),
+            };
+            let allowed = category_values(source_id);
+            if !allowed.contains(literal) {
+                return Err(format!(
+                    "{}에 없는 값입니다: {} (허용: {})",
+                    source_id,
+                    literal,
+                    allowed.join(", ")
+                ));
+            }
+        }
+
+        if op.is_cross() {
+            let series_or_literal = |o: &CompiledOperand| {
+                matches!(
+                    o,
+                    CompiledOperand::Series { .. } | CompiledOperand::Number(_)
+                )
+            };
+            if !series_or_literal(&left) || !series_or_literal(&right) {
+                return Err(format!(
+                    "{}는 캔들 이력이 있는 지표/가격에만 사용할 수 있습니다",
+                    op.symbol()
+                ));
+            }
+        }
+
+        Ok(CompiledCondition { left, op, right })
+    }
+
+    fn compile_operand(&self, operand: &Operand) -> Result<CompiledOperand, String> {
+        match operand {
+            Operand::Number(value) => Decimal::from_f64(*value)
+                .map(CompiledOperand::Number)
+                .ok_or_else(|| format!("숫자로 변환할 수 없습니다: {}", value)),
+            Operand::Text(value) => Ok(CompiledOperand::Category(normalize_category(value))),
+            Operand::Fragment(fragment) => self.compile_fragment(fragment),
+        }
+    }
+
+    fn compile_fragment(&self, operand: &FragmentOperand) -> Result<CompiledOperand, String> {
+        let spec = SOURCES
+            .iter()
+            .find(|s| s.id == operand.fragment)
+            .ok_or_else(|| {
+                format!(
+                    "지원하지 않는 Fragment입니다: {} (지원: {})",
+                    operand.fragment,
+                    SOURCES.iter().map(|s| s.id).collect::<Vec<_>>().join(", ")
+                )
+            })?;
+
+        let output = match &operand.output {
+            Some(output) => spec
+                .outputs
+                .iter()
+                .copied()
+                .find(|o| o == output)
+                .ok_or_else(|| {
+                    format!(
+                        "{}의 출력이 아닙니다: {} (지원: {})",
+                        spec.id,
+                        output,
+                        spec.outputs.join(", ")
+                    )
+                })?,
+            None => spec.outputs[0],
+        };
+
+        if let Some(unknown) = operand
+            .params
+            .keys()
+            .find(|k| !spec.params.contains(&k.as_str()))
+        {
+            return Err(format!("{}의 파라미터가 아닙니다: {}", spec.id, unknown));
+        }
+
+        let params = Params {
+            registry: self.registry,
+            fragment: spec.id,
+            values: &operand.params,
+        };
+
+        let indicator = match spec.id {
+            "price" => Indicator::Price,
+            "indicator.rsi" => Indicator::Rsi {
+                period: params.period("period")?,
+            },
+            "indicator.ma" => Indicator::Ma {
+                ema: params.text("ma_type")? == "ema",
+                period: params.period("period")?,
+            },
+            "indicator.bollinger" => Indicator::Bollinger {
+                period: params.period("period")?,
+                std_dev: params.number("std_dev")?,
+            },
+            "indicator.macd" => {
+                let fast = params.period("fast_period")?;
+                let slow = params.period("slow_period")?;
+                if fast >= slow {
+                    return Err(format!(
+                        "MACD 단기 기간({})은 장기 기간({})보다 작아야 합니다",
+                        fast, slow
+                    ));
+                }
+                Indicator::Macd {
+                    fast,
+                    slow,
+                    signal: params.period("signal_period")?,
+                }
+            }
+            "indicator.atr" => Indicator::Atr {
+                period: params.period("period")?,
+            },
+            "context.global_score" => return Ok(CompiledOperand::GlobalScore),
+            "filter.route_state" => return Ok(CompiledOperand::RouteState),
+            "filter.market_regime" => return Ok(CompiledOperand::MarketRegime),
+            other => return Err(format!("지원하지 않는 Fragment입니다: {}", other)),
+        };
+
+        let lookback = indicator.lookback() + 1;
+        if lookback > self.history_size {
+            return Err(format!(
+                "{}에 캔들 {}개가 필요하지만 history_size가 {}입니다",
+                spec.id, lookback, self.history_size
+            ));
+        }
+
+        Ok(CompiledOperand::Series { indicator, output })
+    }
+}
+
+/// Fragment 필드 스키마 기반 파라미터 조회/검증.
+struct Params<'a> {
+    registry: &'a FragmentRegistry,
+    fragment: &'static str,
+    values: &'a Map<String, Value>,
+}
+
+impl Params<'_> {
+    fn field(&self, name: &str) -> Result<&FieldSchema, String> {
+        param_field(self.registry, self.fragment, name)
+            .ok_or_else(|| format!("{} Fragment에 {} 필드가 없습니다", self.fragment, name))
+    }
+
+    fn value(&self, field: &FieldSchema) -> Result<Value, String> {
+        self.values
+            .get(&field.name)
+            .or(field.default.as_ref())
+            .cloned()
+            .ok_or_else(|| format!("{}.{} 값이 필요합니다", self.fragment, field.name))
+    }
+
+    fn check_range(&self, field: &FieldSchema, value: f64) -> Result<(), String> {
+        let below = field.min.is_some_and(|min| value < min);
+        let above = field.max.is_some_and(|max| value > max);
+        if below || above {
+            return Err(format!(
+                "{}.{} 값 {}이(가) 허용 범위를 벗어났습니다 ({} ~ {})",
+                self.fragment,
+                field.name,
+                value,
+                field.min.map_or("-∞".to_string(), |v| v.to_string()),
+                field.max.map_or("∞".to_string(), |v| v.to_string()),
+            ));
+        }
+        Ok(())
+    }
+
+    fn period(&self, name: &str) -> Result<usize, String> {
+        let field = self.field(name)?;
+        let value = self.value(field)?;
+        let period = value
+            .as_u64()
+            .filter(|p| *p > 0)
+            .ok_or_else(|| format!("{}.{}는 양의 정수여야 합니다", self.fragment, name))?;
+        self.check_range(field, period as f64)?;
+        Ok(period as usize)
+    }
+
+    fn number(&self, name: &str) -> Result<Decimal, String> {
+        let field = self.field(name)?;
+        let value = self.value(field)?;
+        let number = value
+            .as_f64()
+            .ok_or_else(|| format!("{}.{}는 숫자여야 합니다", self.fragment, name))?;
+        self.check_range(field, number)?;
+        Decimal::from_f64(number)
+            .ok_or_else(|| format!("{}.{} 값을 변환할 수 없습니다", self.fragment, name))
+    }
+
+    fn text(&self, name: &str) -> Result<String, String> {
+        let field = self.field(name)?;
+        let value = self.value(field)?;
+        let text = value
+            .as_str()
+            .ok_or_else(|| format!("{}.{}는 문자열이어야 합니다", self.fragment, name))?;
+        if field.field_type == FieldType::Select && !field.options.iter().any(|o| o == text) {
+            return Err(format!(
+                "{}.{} 값이 올바르지 않습니다: {} (허용: {})",
+                self.fragment,
+                name,
+                text,
+                field.options.join(", ")
+            ));
+        }
+        Ok(text.to_string())
+    }
+}
+
+// ============================================================================
+// 평가
+// ============================================================================
+
+/// 평가된 값.
+#[derive(Debug, Clone, PartialEq)]
+enum RuleValue {
+    Number(Decimal),
+    Category(String),
+}
+
+/// 한 캔들 평가 시점의 컨텍스트 값.
+#[derive(Debug, Default)]
+struct ContextValues {
+    global_score: Option<Decimal>,
+    route_state: Option<String>,
+    market_regime: Option<String>,
+}
+
+/// 캔들 이력 기준 지표 값.
+fn series_value(indicator: &Indicator, output: &str, klines: &[Kline]) -> Option<Decimal> {
+    let last = klines.last()?;
+    let closes: Vec<Decimal> = klines.iter().map(|k| k.close).collect();
+
+    match indicator {
+        Indicator::Price => Some(match output {
+            "open" => last.open,
+            "high" => last.high,
+            "low" => last.low,
+            "volume" => last.volume,
+            _ => last.close,
+        }),
+        Indicator::Rsi { period } => calculate_rsi(&closes, *period),
+        Indicator::Ma { ema: true, period } => calculate_ema(&closes, *period),
+        Indicator::Ma { ema: false, period } => calculate_sma(&closes, *period),
+        Indicator::Bollinger { period, std_dev } => {
+            let bands = calculate_bollinger_bands(&closes, *period, *std_dev)?;
+            Some(match output {
+                "upper" => bands.upper,
+                "lower" => bands.lower,
+                "width" => bands.width,
+                _ => bands.middle,
+            })
+        }
+        Indicator::Macd { fast, slow, signal } => {
+            let macd = calculate_macd(&closes, *fast, *slow, *signal)?;
+            Some(match output {
+                "signal" => macd.signal,
+                "histogram" => macd.histogram,
+                _ => macd.macd,
+            })
+        }
+        Indicator::Atr { period } => {
+            let highs: Vec<Decimal> = klines.iter().map(|k| k.high).collect();
+            let lows: Vec<Decimal> = klines.iter().map(|k| k.low).collect();
+            calculate_atr(&highs, &lows, &closes, *period)
+        }
+    }
+}
+
+fn operand_value(
+    operand: &CompiledOperand,
+    klines: &[Kline],
+    context: &ContextValues,
+) -> Option<RuleValue> {
+    match operand {
+        CompiledOperand::Number(value) => Some(RuleValue::Number(*value)),
+        CompiledOperand::Category(value) => Some(RuleValue::Category(value.clone())),
+        CompiledOperand::Series { indicator, output } => {
+            series_value(indicator, output, klines).map(RuleValue::Number)
+        }
+        CompiledOperand::GlobalScore => context.global_score.map(RuleValue::Number),
+        CompiledOperand::RouteState => context.route_state.clone().map(RuleValue::Category),
+        CompiledOperand::MarketRegime => context.market_regime.clone().map(RuleValue::Category),
+    }
+}
+
+fn compare(op: Comparator, left: &RuleValue, right: &RuleValue) -> bool {
+    match (left, right) {
+        (RuleValue::Number(l), RuleValue::Number(r)) => match op {
+            Comparator::Lt => l < r,
+            Comparator::Le => l <= r,
+            Comparator::Gt => l > r,
+            Comparator::Ge => l >= r,
+            Comparator::Eq => l == r,
+            Comparator::Ne => l != r,
+            Comparator::CrossAbove | Comparator::CrossBelow => false,
+        },
+        (RuleValue::Category(l), RuleValue::Category(r)) => match op {
+            Comparator::Eq => l == r,
+            Comparator::Ne => l != r,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+impl CompiledCondition {
+    fn evaluate(&self, klines: &[Kline], context: &ContextValues) -> bool {
+        let value =
+            |operand: &CompiledOperand, klines: &[Kline]| operand_value(operand, klines, context);
+
+        if self.op.is_cross() {
+            if klines.len() < 2 {
+                return false;
+            }
+            let previous = &klines[..klines.len() - 1];
+            let (Some(prev_l), Some(prev_r), Some(cur_l), Some(cur_r)) = (
+                value(&self.left, previous),
+                value(&self.right, previous),
+                value(&self.left, klines),
+                value(&self.right, klines),
+            ) else {
+                return false;
+            };
+
+            return match self.op {
+                Comparator::CrossAbove => {
+                    compare(Comparator::Le, &prev_l, &prev_r)
+                        && compare(Comparator::Gt, &cur_l, &cur_r)
+                }
+                _ => {
+                    compare(Comparator::Ge, &prev_l, &prev_r)
+                        && compare(Comparator::Lt, &cur_l, &cur_r)
+                }
+            };
+        }
+
+        match (value(&self.left, klines), value(&self.right, klines)) {
+            (Some(left), Some(right)) => compare(self.op, &left, &right),
+            _ => false,
+        }
+    }
+}
+
+impl CompiledGroup {
+    /// 그룹 평가. 충족 시 충족된 조건 설명 목록 반환.
+    fn evaluate(&self, klines: &[Kline], context: &ContextValues) -> Option<Vec<String>> {
+        if self.conditions.is_empty() {
+            return None;
+        }
+
+        let matched: Vec<String> = self
+            .conditions
+            .iter()
+            .filter(|c| c.evaluate(klines, context))
+            .map(|c| c.to_string())
+            .collect();
+
+        let satisfied = match self.logic {
+            RuleLogic::All => matched.len() == self.conditions.len(),
+            RuleLogic::Any => !matched.is_empty(),
+        };
+        satisfied.then_some(matched)
+    }
+
+    fn uses_context(&self) -> bool {
+        self.conditions.iter().any(|c| {
+            [&c.left, &c.right].iter().any(|o| {
+                matches!(
+                    o.kind(),
+                    Some(OperandKind::Number) | Some(OperandKind::Category)
+                )
+            })
+        })
+    }
+}
+
+// ============================================================================
+// 전략 구현
+// ============================================================================
+
+/// 영속화 상태 (포맷 버전 1).
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    /// 보유 중이면 진입가
+    entry_price: Option<Decimal>,
+}
+
+/// 선언적 규칙 기반 전략.
+pub struct RuleStrategy {
+    config: Option<RuleConfig>,
+    rules: CompiledRules,
+    context: Option<Arc<RwLock<StrategyContext>>>,
+    history: VecDeque<Kline>,
+    /// 보유 중이면 진입가
+    entry_price: Option<Decimal>,
+    signals_generated: u64,
+    /// 엔진이 가져가기 전까지 쌓인 캔들별 조건 평가 결과
+    decisions: Vec<Decision>,
+}
+
+impl RuleStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            rules: CompiledRules::default(),
+            context: None,
+            history: VecDeque::new(),
+            entry_price: None,
+            signals_generated: 0,
+            decisions: Vec::new(),
+        }
+    }
+
+    /// 설정 검증 (파싱 + 규칙 컴파일).
+    ///
+    /// DB 저장 전에 호출하여 평가할 수 없는 규칙을 거부합니다.
+    pub fn validate_config(config: &Value) -> Result<(), RuleError> {
+        RuleConfig::parse(config).map(|_| ())
+    }
+
+    /// 수신 캔들 추가 (같은 시작 시각의 캔들은 갱신).
+    fn push_kline(&mut self, kline: &Kline, capacity: usize) {
+        match self.history.back_mut() {
+            Some(last) if last.open_time == kline.open_time => *last = kline.clone(),
+            _ => self.history.push_back(kline.clone()),
+        }
+        while self.history.len() > capacity {
+            self.history.pop_front();
+        }
+    }
+
+    async fn context_values(&self, ticker: &str) -> ContextValues {
+        let Some(context) = self.context.as_ref() else {
+            return ContextValues::default();
+        };
+        let ctx = context.read().await;
+
+        ContextValues {
+            global_score: ctx.get_global_score(ticker).map(|gs| gs.overall_score),
+            route_state: ctx.get_route_state(ticker).map(serde_name),
+            market_regime: ctx.get_market_regime(ticker).map(serde_name),
+        }
+    }
+
+    /// 손절/익절 도달 여부.
+    fn exit_by_price(&self, config: &RuleConfig, close: Decimal) -> Option<&'static str> {
+        let entry = self.entry_price?;
+        if let Some(pct) = config.exit_config.stop_loss() {
+            if close <= entry * (dec!(1) - pct / dec!(100)) {
+                return Some("stop_loss");
+            }
+        }
+        if let Some(pct) = config.exit_config.take_profit() {
+            if close >= entry * (dec!(1) + pct / dec!(100)) {
+                return Some("take_profit");
+            }
+        }
+        None
+    }
+}
+
+impl Default for RuleStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Strategy Trait 구현
+// ============================================================================
+
+#[async_trait]
+impl Strategy for RuleStrategy {
+    fn name(&self) -> &str {
+        "Rule"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "지표 조건 조합으로 정의하는 규칙 기반 전략"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let (config, rules) = RuleConfig::parse(&config)?;
+
+        info!(
+            ticker = %config.ticker,
+            entry_rules = rules.entry.conditions.len(),
+            exit_rules = rules.exit.conditions.len(),
+            "규칙 전략 초기화"
+        );
+
+        self.rules = rules;
+        self.config = Some(config);
+        self.history.clear();
+        self.entry_price = None;
+        self.signals_generated = 0;
+        self.decisions.clear();
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(config) = self.config.clone() else {
+            return Ok(vec![]);
+        };
+        if data.ticker != config.ticker {
+            return Ok(vec![]);
+        }
+        let MarketDataType::Kline(kline) = &data.data else {
+            return Ok(vec![]);
+        };
+
+        self.push_kline(kline, config.history_size);
+        let close = kline.close;
+
+        let (group_name, group) = if self.entry_price.is_some() {
+            ("exit", &self.rules.exit)
+        } else {
+            ("entry", &self.rules.entry)
+        };
+        let context = if group.uses_context() {
+            self.context_values(&config.ticker).await
+        } else {
+            ContextValues::default()
+        };
+        let klines = self.history.make_contiguous();
+
+        // 그룹의 조건별 통과 여부 기록 (신호가 나오지 않은 이유 추적)
+        let decision = group.conditions.iter().fold(
+            Decision::new(config.ticker.clone())
+                .indicator("close", close)
+                .indicator("entry_price", self.entry_price)
+                .with_reason(format!("{} rules ({:?})", group_name, group.logic)),
+            |decision, c| decision.condition(c.to_string(), c.evaluate(klines, &context)),
+        );
+
+        let signal = match self.entry_price {
+            None => self.rules.entry.evaluate(klines, &context).map(|matched| {
+                let stop_loss = config
+                    .exit_config
+                    .stop_loss()
+                    .map(|pct| close * (dec!(1) - pct / dec!(100)));
+                let take_profit = config
+                    .exit_config
+                    .take_profit()
+                    .map(|pct| close * (dec!(1) + pct / dec!(100)));
+
+                self.entry_price = Some(close);
+                Signal::entry(RULE_STRATEGY_ID, config.ticker.clone(), Side::Buy)
+                    .with_prices(Some(close), stop_loss, take_profit)
+                    .with_metadata("conditions", json!(matched))
+            }),
+            Some(_) => {
+                let reason = self
+                    .rules
+                    .exit
+                    .evaluate(klines, &context)
+                    .map(|matched| ("rule", matched))
+                    .or_else(|| {
+                        self.exit_by_price(&config, close)
+                            .map(|reason| (reason, Vec::new()))
+                    });
+
+                reason.map(|(reason, matched)| {
+                    self.entry_price = None;
+                    Signal::exit(RULE_STRATEGY_ID, config.ticker.clone(), Side::Sell)
+                        .with_prices(Some(close), None, None)
+                        .with_metadata("reason", json!(reason))
+                        .with_metadata("conditions", json!(matched))
+                })
+            }
+        };
+
+        if self.decisions.len() >= MAX_PENDING_DECISIONS {
+            self.decisions.remove(0);
+        }
+        self.decisions.push(decision);
+        let Some(signal) = signal else {
+            return Ok(vec![]);
+        };
+        self.signals_generated += 1;
+        debug!(
+            ticker = %config.ticker,
+            signal_type = ?signal.signal_type,
+            metadata = ?signal.metadata,
+            "규칙 신호 생성"
+        );
+        Ok(vec![signal])
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        _order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(config) = self.config.as_ref() else {
+            return Ok(());
+        };
+        if position.ticker != config.ticker {
+            return Ok(());
+        }
+
+        // 외부 청산(손절 주문 체결 등)과 보유 상태 동기화
+        self.entry_price = if position.quantity.is_zero() {
+            None
+        } else {
+            Some(position.entry_price)
+        };
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!(signals = self.signals_generated, "규칙 전략 종료");
+        Ok(())
+    }
+
+    fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
+        self.context = Some(context);
+        debug!("StrategyContext 주입 완료");
+    }
+
+    fn subscribed_tickers(&self) -> Vec<String> {
+        self.config
+            .as_ref()
+            .map(|c| vec![c.ticker.clone()])
+            .unwrap_or_default()
+    }
+
+    fn take_decisions(&mut self) -> Vec<Decision> {
+        std::mem::take(&mut self.decisions)
+    }
+
+    fn get_state(&self) -> Value {
+        let describe = |group: &CompiledGroup| {
+            group
+                .conditions
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        json!({
+            "ticker": self.config.as_ref().map(|c| c.ticker.clone()),
+            "entry_rules": describe(&self.rules.entry),
+            "exit_rules": describe(&self.rules.exit),
+            "holding": self.entry_price.is_some(),
+            "entry_price": self.entry_price,
+            "history_len": self.history.len(),
+            "signals_generated": self.signals_generated,
+        })
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(encode_versioned_state(
+            STATE_FORMAT_VERSION,
+            &PersistedState {
+                entry_price: self.entry_price,
+            },
+        )?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let (_, value) = decode_versioned_state(data)?;
+        let persisted: PersistedState = serde_json::from_value(value)?;
+        self.entry_price = persisted.entry_price;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// 레지스트리 등록
+// ============================================================================
+
+register_strategy! {
+    id: "rule",
+    aliases: ["rule_based", "규칙"],
+    name: "Rule Strategy",
+    description: "지표 조건을 조합해 진입/청산 규칙을 정의하는 전략 (코딩 불필요)",
+    timeframe: "1d",
+    tickers: [],
+    category: Daily,
+    markets: [Crypto, Stock],
+    type: RuleStrategy,
+    config: RuleConfig
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+    use trader_core::Timeframe;
+
+    fn kline_data(day: i64, close: Decimal) -> MarketData {
+        let time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::days(day);
+        MarketData::from_kline(
+            "test",
+            Kline::new(
+                "005930".to_string(),
+                Timeframe::D1,
+                time,
+                close,
+                close,
+                close,
+                close,
+                dec!(1000),
+                time,
+            ),
+        )
+    }
+
+    fn rsi_config() -> Value {
+        json!({
+            "ticker": "005930",
+            "entry": {
+                "conditions": [
+                    { "left": { "fragment": "indicator.rsi", "params": { "period": 5 } }, "op": "<", "right": 30 }
+                ]
+            },
+            "exit": {
+                "logic": "any",
+                "conditions": [
+                    { "left": { "fragment": "indicator.rsi", "params": { "period": 5 } }, "op": ">", "right": 70 }
+                ]
+            },
+            "exit_config": { "stop_loss_enabled": false, "take_profit_enabled": false }
+        })
+    }
+
+    async fn feed(strategy: &mut RuleStrategy, closes: &[Decimal]) -> Vec<Signal> {
+        let mut signals = vec![];
+        for (i, close) in closes.iter().enumerate() {
+            signals.extend(
+                strategy
+                    .on_market_data(&kline_data(i as i64, *close))
+                    .await
+                    .unwrap(),
+            );
+        }
+        signals
+    }
+
+    #[tokio::test]
+    async fn test_entry_and_exit_rules_generate_signals() {
+        let mut strategy = RuleStrategy::new();
+        strategy.initialize(rsi_config()).await.unwrap();
+
+        let closes = [
+            dec!(100),
+            dec!(98),
+            dec!(96),
+            dec!(94),
+            dec!(92),
+            dec!(90),
+            dec!(95),
+            dec!(100),
+            dec!(105),
+            dec!(110),
+        ];
+        let signals = feed(&mut strategy, &closes).await;
+
+        assert_eq!(signals.len(), 2);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].metadata["conditions"], json!(["RSI(5) < 30"]));
+        assert_eq!(signals[1].side, Side::Sell);
+        assert_eq!(signals[1].metadata["reason"], json!("rule"));
+
+        // 캔들마다 평가한 조건과 통과 여부가 기록됨
+        let decisions = strategy.take_decisions();
+        assert_eq!(decisions.len(), closes.len());
+        assert_eq!(decisions[0].conditions[0].name, "RSI(5) < 30");
+        assert!(decisions.iter().any(|d| d.conditions[0].passed));
+        assert!(strategy.take_decisions().is_empty());
+
+        // 진입가는 상태로 영속화됨
+        let mut restored = RuleStrategy::new();
+        restored.initialize(rsi_config()).await.unwrap();
+        strategy.entry_price = Some(dec!(90));
+        restored
+            .load_state(&strategy.save_state().unwrap())
+            .unwrap();
+        assert_eq!(restored.entry_price, Some(dec!(90)));
+    }
+
+    #[tokio::test]
+    async fn test_route_state_condition_reads_context() {
+        let mut config = rsi_config();
+        config["entry"] = json!({
+            "conditions": [
+                { "left": { "fragment": "price" }, "op": ">", "right": 0 },
+                { "left": { "fragment": "filter.route_state" }, "op": "==", "right": "Attack" }
+            ]
+        });
+
+        let context = Arc::new(RwLock::new(StrategyContext::default()));
+        let mut strategy = RuleStrategy::new();
+        strategy.initialize(config).await.unwrap();
+        strategy.set_context(context.clone());
+
+        assert!(feed(&mut strategy, &[dec!(100)]).await.is_empty());
+
+        context
+            .write()
+            .await
+            .route_states
+            .insert("005930".to_string(), RouteState::Attack);
+        let signals = feed(&mut strategy, &[dec!(101)]).await;
+        assert_eq!(signals.len(), 1);
+        assert_eq!(
+            signals[0].metadata["conditions"],
+            json!(["close > 0", "route_state == ATTACK"])
+        );
+    }
+
+    #[test]
+    fn test_validation_rejects_bad_rules() {
+        let with_entry = |condition: Value| {
+            let mut config = rsi_config();
+            config["entry"] = json!({ "conditions": [condition] });
+            RuleStrategy::validate_config(&config)
+                .unwrap_err()
+                .to_string()
+        };
+
+        let err =
+            with_entry(json!({ "left": { "fragment": "indicator.foo" }, "op": "<", "right": 1 }));
+        assert!(err.contains("진입 규칙 #1"), "{}", err);
+        assert!(err.contains("indicator.foo"), "{}", err);
+
+        let err = with_entry(json!({
+            "left": { "fragment": "indicator.rsi", "params": { "period": 1 } }, "op": "<", "right": 30
+        }));
+        assert!(err.contains("허용 범위"), "{}", err);
+
+        let err = with_entry(
+            json!({ "left": { "fragment": "filter.route_state" }, "op": "<", "right": "ATTACK" }),
+        );
+        assert!(err.contains("범주형"), "{}", err);
+
+        let err = with_entry(
+            json!({ "left": { "fragment": "filter.route_state" }, "op": "==", "right": "MOON" }),
+        );
+        assert!(err.contains("MOON"), "{}", err);
+
+        let err = with_entry(json!({ "left": 1, "op": "<", "right": 2 }));
+        assert!(err.contains("리터럴"), "{}", err);
+
+        let err = with_entry(json!({
+            "left": { "fragment": "indicator.bollinger", "output": "top" }, "op": ">", "right": 1
+        }));
+        assert!(err.contains("top"), "{}", err);
+
+        let mut config = rsi_config();
+        config["entry"] = json!({ "conditions": [] });
+        assert!(RuleStrategy::validate_config(&config).is_err());
+
+        assert!(RuleStrategy::validate_config(&rsi_config()).is_ok());
+    }
+
+    #[test]
+    fn test_catalog_and_schema_expose_rule_builder() {
+        let catalog = rule_catalog(&FragmentRegistry::with_builtins());
+        let rsi = catalog
+            .sources
+            .iter()
+            .find(|s| s.id == "indicator.rsi")
+            .unwrap();
+        assert_eq!(rsi.params.len(), 1);
+        assert_eq!(rsi.params[0].default, Some(json!(14)));
+
+        let route = catalog
+            .sources
+            .iter()
+            .find(|s| s.id == "filter.route_state")
+            .unwrap();
+        assert!(route.values.contains(&"ATTACK".to_string()));
+        assert_eq!(catalog.comparators.len(), 8);
+
+        let schema = RuleConfig::ui_schema();
+        let entry = schema
+            .custom_fields
+            .iter()
+            .find(|f| f.name == "entry")
+            .unwrap();
+        assert_eq!(entry.field_type, FieldType::Rules);
+    }
+}
@@ -0,0 +1,1165 @@
+//! 스크립트 전략 (Rhai).
+//!
+//! 워크스페이스를 재컴파일하거나 `.so` 플러그인을 빌드하지 않고, 런타임에 로드되는
+//! Rhai 스크립트로 매매 로직을 정의합니다. 스크립트 소스는 전략 설정의 `source`
+//! 필드에 포함되므로 다른 전략과 동일하게 DB에 저장되며, 레지스트리의 `script`
+//! 전략으로 생성·실행·백테스트됩니다.
+//!
+//! ## 스크립트 훅
+//!
+//! | 함수 | 호출 시점 | 반환값 |
+//! |------|-----------|--------|
+//! | `init()` | 초기화 직후 | 무시 |
+//! | `on_market_data(data)` | 시장 데이터 수신 | 신호 맵, 신호 배열 또는 `()` |
+//! | `on_order_filled(order)` | 주문 체결 | 무시 |
+//! | `on_position_update(position)` | 포지션 변경 | 무시 |
+//!
+//! `on_market_data`만 필수입니다. 모든 훅에서 `this`는 호출 간 유지되는 상태 맵이며,
+//! `save_state`/`load_state`로 영속화됩니다.
+//!
+//! ## 호스트 함수
+//!
+//! - 설정: `param(key)`, `param(key, default)`
+//! - 신호: `buy(ticker)`, `sell(ticker)` (반환된 맵의 필드를 수정해 사용)
+//! - 수신 캔들 이력: `history(ticker)`, `closes(ticker)`
+//! - 지표: `sma`, `ema`, `rsi`, `bollinger`, `macd`, `atr` (데이터 부족 시 `()`)
+//! - 컨텍스트 (읽기 전용): `klines(ticker, timeframe)`, `position(ticker)`,
+//!   `has_position(ticker)`, `account()`, `global_score(ticker)`,
+//!   `route_state(ticker)`, `market_regime(ticker)`
+//!
+//! ## 샌드박스
+//!
+//! 모듈 import와 `eval`은 사용할 수 없고, 호출당 연산 수·호출 깊이·문자열/배열/맵
+//! 크기가 제한됩니다. `print`/`debug` 출력은 tracing 로그로 전달됩니다.
+//!
+//! ## 예시
+//!
+//! ```text
+//! fn init() {
+//!     this.holding = false;
+//! }
+//!
+//! fn on_market_data(data) {
+//!     let value = rsi(closes(data.ticker), param("period", 14));
+//!     if value == () { return; }
+//!
+//!     if value < 30.0 && !this.holding {
+//!         this.holding = true;
+//!         return buy(data.ticker);
+//!     }
+//!     if value > 70.0 && this.holding {
+//!         this.holding = false;
+//!         return sell(data.ticker);
+//!     }
+//! }
+//! ```
+
+use crate::state_store::{decode_versioned_state, encode_versioned_state};
+use crate::strategies::common::indicators::{
+    calculate_atr, calculate_bollinger_bands, calculate_ema, calculate_macd, calculate_rsi,
+    calculate_sma,
+};
+use crate::Strategy;
+use async_trait::async_trait;
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Array, CallFnOptions, Dynamic, Engine, EvalAltResult, FuncArgs, Map, Scope, AST};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info};
+use trader_core::{
+    domain::StrategyContext, Kline, MarketData, MarketDataType, Order, Position, Side, Signal,
+    SignalType, Timeframe,
+};
+
+/// 레지스트리 전략 ID.
+pub const SCRIPT_STRATEGY_ID: &str = "script";
+
+/// 영속화 상태 포맷 버전.
+const STATE_FORMAT_VERSION: u32 = 1;
+
+/// 함수 호출 최대 깊이.
+const MAX_CALL_LEVELS: usize = 32;
+/// 전역 표현식 최대 중첩 깊이.
+const MAX_EXPR_DEPTH: usize = 64;
+/// 함수 내부 표현식 최대 중첩 깊이.
+const MAX_FUNCTION_EXPR_DEPTH: usize = 32;
+/// 문자열 최대 길이 (바이트).
+const MAX_STRING_SIZE: usize = 64 * 1024;
+/// 배열 최대 원소 수.
+const MAX_ARRAY_SIZE: usize = 100_000;
+/// 맵 최대 항목 수.
+const MAX_MAP_SIZE: usize = 10_000;
+
+// ============================================================================
+// 에러
+// ============================================================================
+
+/// 스크립트 전략 에러.
+#[derive(Error, Debug)]
+pub enum ScriptError {
+    #[error("설정 에러: {0}")]
+    Config(String),
+
+    #[error("컴파일 에러: {0}")]
+    Compile(String),
+
+    #[error("필수 함수 누락: {0}")]
+    MissingHook(&'static str),
+
+    #[error("실행 에러 ({hook}): {message}")]
+    Runtime { hook: &'static str, message: String },
+
+    #[error("잘못된 신호: {0}")]
+    InvalidSignal(String),
+
+    #[error("상태 직렬화 에러: {0}")]
+    State(String),
+}
+
+// ============================================================================
+// 설정 (Config)
+// ============================================================================
+
+/// 스크립트 전략 설정.
+///
+/// `source` 외의 모든 필드(추가 필드 포함)는 스크립트에서 `param(key)`로 읽을 수 있습니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptConfig {
+    /// Rhai 스크립트 소스
+    pub source: String,
+
+    /// 대상 티커 (백테스트 시 자동 주입)
+    #[serde(default)]
+    pub ticker: Option<String>,
+
+    /// 추가 구독 티커 (`ticker`와 함께 비어 있으면 전체 티커 수신)
+    #[serde(default)]
+    pub tickers: Vec<String>,
+
+    /// 훅 호출당 최대 연산 수
+    #[serde(default = "default_max_operations")]
+    pub max_operations: u64,
+
+    /// 티커별 보관할 수신 캔들 수
+    #[serde(default = "default_history_size")]
+    pub history_size: usize,
+}
+
+/// 설정 가능한 훅 호출당 최대 연산 수의 상한.
+const MAX_OPERATIONS_LIMIT: u64 = 50_000_000;
+/// 설정 가능한 티커별 캔들 보관 수의 상한.
+const MAX_HISTORY_SIZE: usize = 10_000;
+
+fn default_max_operations() -> u64 {
+    1_000_000
+}
+fn default_history_size() -> usize {
+    500
+}
+
+impl ScriptConfig {
+    /// JSON 설정 파싱 및 값 검증.
+    fn from_value(config: &Value) -> Result<Self, ScriptError> {
+        let parsed: Self = serde_json::from_value(config.clone())
+            .map_err(|e| ScriptError::Config(e.to_string()))?;
+
+        if parsed.source.trim().is_empty() {
+            return Err(ScriptError::Config("source가 비어 있습니다".to_string()));
+        }
+        if parsed.max_operations == 0 {
+            return Err(ScriptError::Config(
+                "max_operations는 0보다 커야 합니다".to_string(),
+            ));
+        }
+        if parsed.max_operations > MAX_OPERATIONS_LIMIT {
+            return Err(ScriptError::Config(format!(
+                "max_operations는 {} 이하여야 합니다",
+                MAX_OPERATIONS_LIMIT
+            )));
+        }
+        if parsed.history_size > MAX_HISTORY_SIZE {
+            return Err(ScriptError::Config(format!(
+                "history_size는 {} 이하여야 합니다",
+                MAX_HISTORY_SIZE
+            )));
+        }
+
+        Ok(parsed)
+    }
+
+    /// 구독 티커 (`ticker` + `tickers`, 중복 제거).
+    fn subscribed_tickers(&self) -> Vec<String> {
+        let mut tickers: Vec<String> = self
+            .ticker
+            .iter()
+            .chain(self.tickers.iter())
+            .cloned()
+            .collect();
+        tickers.sort();
+        tickers.dedup();
+        tickers
+    }
+}
+
+// ============================================================================
+// 호스트 상태
+// ============================================================================
+
+/// 스크립트 호스트 함수가 공유하는 상태.
+#[derive(Default)]
+struct ScriptHost {
+    /// 엔진이 주입한 StrategyContext
+    context: std::sync::RwLock<Option<Arc<RwLock<StrategyContext>>>>,
+    /// 티커별 수신 캔들 이력
+    history: std::sync::RwLock<HashMap<String, VecDeque<Kline>>>,
+}
+
+impl ScriptHost {
+    /// 컨텍스트 읽기 (미주입 또는 쓰기 중이면 None).
+    fn with_context<T>(&self, f: impl FnOnce(&StrategyContext) -> T) -> Option<T> {
+        let slot = self.context.read().ok()?;
+        let context = slot.as_ref()?.try_read().ok()?;
+        Some(f(&context))
+    }
+
+    /// 수신 캔들 추가 (같은 시작 시각의 캔들은 갱신).
+    fn push_kline(&self, kline: &Kline, capacity: usize) {
+        let Ok(mut history) = self.history.write() else {
+            return;
+        };
+        let klines = history.entry(kline.ticker.clone()).or_default();
+
+        match klines.back_mut() {
+            Some(last) if last.open_time == kline.open_time => *last = kline.clone(),
+            _ => klines.push_back(kline.clone()),
+        }
+        while klines.len() > capacity {
+            klines.pop_front();
+        }
+    }
+
+    /// 티커의 수신 캔들 이력.
+    fn history(&self, ticker: &str) -> Vec<Kline> {
+        self.history
+            .read()
+            .ok()
+            .and_then(|history| history.get(ticker).map(|k| k.iter().cloned().collect()))
+            .unwrap_or_default()
+    }
+
+    fn clear_history(&self) {
+        if let Ok(mut history) = self.history.write() {
+            history.clear();
+        }
+    }
+}
+
+// ============================================================================
+// 값 변환
+// ============================================================================
+
+fn decimal_to_dynamic(value: Decimal) -> Dynamic {
+    value
+        .to_f64()
+        .map(Dynamic::from_float)
+        .unwrap_or(Dynamic::UNIT)
+}
+
+fn dynamic_to_f64(value: &Dynamic) -> Option<f64> {
+    value
+        .as_float()
+        .ok()
+        .or_else(|| value.as_int().ok().map(|v| v as f64))
+}
+
+fn dynamic_to_decimal(value: &Dynamic) -> Option<Decimal> {
+    dynamic_to_f64(value).and_then(Decimal::from_f64)
+}
+
+fn array_to_decimals(values: &Array) -> Option<Vec<Decimal>> {
+    values.iter().map(dynamic_to_decimal).collect()
+}
+
+fn to_period(period: rhai::INT) -> Option<usize> {
+    usize::try_from(period).ok().filter(|p| *p > 0)
+}
+
+/// serde 문자열 표현 (예: `RouteState::Attack` → `"ATTACK"`).
+fn serde_name<T: Serialize>(value: &T) -> Dynamic {
+    match serde_json::to_value(value) {
+        Ok(Value::String(name)) => name.into(),
+        _ => Dynamic::UNIT,
+    }
+}
+
+fn kline_to_dynamic(kline: &Kline) -> Dynamic {
+    let mut map = Map::new();
+    map.insert("ticker".into(), kline.ticker.clone().into());
+    map.insert("timeframe".into(), kline.timeframe.to_string().into());
+    map.insert("time".into(), kline.open_time.timestamp_millis().into());
+    map.insert("open".into(), decimal_to_dynamic(kline.open));
+    map.insert("high".into(), decimal_to_dynamic(kline.high));
+    map.insert("low".into(), decimal_to_dynamic(kline.low));
+    map.insert("close".into(), decimal_to_dynamic(kline.close));
+    map.insert("volume".into(), decimal_to_dynamic(kline.volume));
+    map.into()
+}
+
+/// 시장 데이터 → 스크립트 맵 (호가창은 None).
+fn market_data_to_dynamic(data: &MarketData) -> Option<Dynamic> {
+    let mut map = Map::new();
+    map.insert("ticker".into(), data.ticker.clone().into());
+    map.insert("exchange".into(), data.exchange.clone().into());
+    map.insert("time".into(), data.timestamp.timestamp_millis().into());
+
+    match &data.data {
+        MarketDataType::Kline(kline) => {
+            map.insert("kind".into(), "kline".into());
+            map.insert("timeframe".into(), kline.timeframe.to_string().into());
+            map.insert("price".into(), decimal_to_dynamic(kline.close));
+            map.insert("open".into(), decimal_to_dynamic(kline.open));
+            map.insert("high".into(), decimal_to_dynamic(kline.high));
+            map.insert("low".into(), decimal_to_dynamic(kline.low));
+            map.insert("close".into(), decimal_to_dynamic(kline.close));
+            map.insert("volume".into(), decimal_to_dynamic(kline.volume));
+        }
+        MarketDataType::Ticker(ticker) => {
+            map.insert("kind".into(), "ticker".into());
+            map.insert("price".into(), decimal_to_dynamic(ticker.last));
+            map.insert("bid".into(), decimal_to_dynamic(ticker.bid));
+            map.insert("ask".into(), decimal_to_dynamic(ticker.ask));
+            map.insert("volume".into(), decimal_to_dynamic(ticker.volume_24h));
+        }
+        MarketDataType::Trade(trade) => {
+            map.insert("kind".into(), "trade".into());
+            map.insert("price".into(), decimal_to_dynamic(trade.price));
+            map.insert("quantity".into(), decimal_to_dynamic(trade.quantity));
+            map.insert("side".into(), serde_name(&trade.side));
+        }
+        MarketDataType::OrderBook(_) => return None,
+    }
+
+    Some(map.into())
+}
+
+fn order_to_dynamic(order: &Order) -> Dynamic {
+    let mut map = Map::new();
+    map.insert("id".into(), order.id.to_string().into());
+    map.insert("ticker".into(), order.ticker.clone().into());
+    map.insert("side".into(), serde_name(&order.side));
+    map.insert("status".into(), serde_name(&order.status));
+    map.insert("quantity".into(), decimal_to_dynamic(order.quantity));
+    map.insert(
+        "filled_quantity".into(),
+        decimal_to_dynamic(order.filled_quantity),
+    );
+    map.insert(
+        "price".into(),
+        order
+            .average_fill_price
+            .or(order.price)
+            .map(decimal_to_dynamic)
+            .unwrap_or(Dynamic::UNIT),
+    );
+    map.into()
+}
+
+fn position_to_dynamic(position: &Position) -> Dynamic {
+    let mut map = Map::new();
+    map.insert("ticker".into(), position.ticker.clone().into());
+    map.insert("side".into(), serde_name(&position.side));
+    map.insert("quantity".into(), decimal_to_dynamic(position.quantity));
+    map.insert(
+        "entry_price".into(),
+        decimal_to_dynamic(position.entry_price),
+    );
+    map.insert(
+        "current_price".into(),
+        decimal_to_dynamic(position.current_price),
+    );
+    map.insert(
+        "unrealized_pnl".into(),
+        decimal_to_dynamic(position.unrealized_pnl),
+    );
+    map.insert(
+        "realized_pnl".into(),
+        decimal_to_dynamic(position.realized_pnl),
+    );
+    map.into()
+}
+
+// ============================================================================
+// 신호 변환
+// ============================================================================
+
+/// 스크립트가 반환하는 신호 맵.
+#[derive(Debug, Deserialize)]
+struct ScriptSignal {
+    ticker: String,
+    side: Side,
+    /// 생략 시 매수 = Entry, 매도 = Exit
+    #[serde(default)]
+    signal_type: Option<SignalType>,
+    #[serde(default = "default_strength")]
+    strength: f64,
+    #[serde(default)]
+    price: Option<Decimal>,
+    #[serde(default)]
+    stop_loss: Option<Decimal>,
+    #[serde(default)]
+    take_profit: Option<Decimal>,
+    #[serde(default)]
+    metadata: HashMap<String, Value>,
+}
+
+fn default_strength() -> f64 {
+    1.0
+}
+
+impl ScriptSignal {
+    fn into_signal(self) -> Signal {
+        let signal_type = self.signal_type.unwrap_or(match self.side {
+            Side::Buy => SignalType::Entry,
+            Side::Sell => SignalType::Exit,
+        });
+
+        let mut signal = Signal::new(SCRIPT_STRATEGY_ID, self.ticker, self.side, signal_type)
+            .with_strength(self.strength)
+            .with_prices(self.price, self.stop_loss, self.take_profit);
+        signal.metadata.extend(self.metadata);
+        signal
+    }
+}
+
+/// 훅 반환값 → 신호 목록 (`()` = 없음, 맵 = 단일 신호, 배열 = 복수 신호).
+fn parse_signals(result: Dynamic) -> Result<Vec<Signal>, ScriptError> {
+    let items: Array = if result.is_unit() {
+        Array::new()
+    } else if result.is_array() {
+        result.cast::<Array>()
+    } else {
+        vec![result]
+    };
+
+    items
+        .iter()
+        .map(|item| {
+            // Dynamic → JSON을 거쳐 정수/실수 구분 없이 숫자 필드를 읽음
+            let value: Value = rhai::serde::from_dynamic(item)
+                .map_err(|e| ScriptError::InvalidSignal(e.to_string()))?;
+            let signal: ScriptSignal = serde_json::from_value(value)
+                .map_err(|e| ScriptError::InvalidSignal(e.to_string()))?;
+            Ok(signal.into_signal())
+        })
+        .collect()
+}
+
+fn signal_map(ticker: &str, side: Side) -> Map {
+    let mut map = Map::new();
+    map.insert("ticker".into(), ticker.into());
+    map.insert("side".into(), serde_name(&side));
+    map
+}
+
+// ============================================================================
+// 엔진 구성
+// ============================================================================
+
+/// 샌드박스 제한과 호스트 함수가 등록된 엔진 생성.
+fn build_engine(config: &ScriptConfig, params: Map, host: Arc<ScriptHost>) -> Engine {
+    let mut engine = Engine::new();
+
+    engine
+        .set_module_resolver(DummyModuleResolver::new())
+        .disable_symbol("eval")
+        .set_max_operations(config.max_operations.min(MAX_OPERATIONS_LIMIT))
+        .set_max_call_levels(MAX_CALL_LEVELS)
+        .set_max_expr_depths(MAX_EXPR_DEPTH, MAX_FUNCTION_EXPR_DEPTH)
+        .set_max_string_size(MAX_STRING_SIZE)
+        .set_max_array_size(MAX_ARRAY_SIZE)
+        .set_max_map_size(MAX_MAP_SIZE);
+
+    engine.on_print(|text| info!(output = text, "스크립트 출력"));
+    engine.on_debug(|text, _source, pos| debug!(output = text, %pos, "스크립트 디버그"));
+
+    register_param_fns(&mut engine, params);
+    register_signal_fns(&mut engine);
+    register_indicator_fns(&mut engine);
+    register_history_fns(&mut engine, host.clone());
+    register_context_fns(&mut engine, host);
+
+    engine
+}
+
+fn register_param_fns(engine: &mut Engine, params: Map) {
+    let params = Arc::new(params);
+
+    let values = params.clone();
+    engine.register_fn("param", move |key: &str| {
+        values.get(key).cloned().unwrap_or(Dynamic::UNIT)
+    });
+    engine.register_fn("param", move |key: &str, default: Dynamic| {
+        params.get(key).cloned().unwrap_or(default)
+    });
+}
+
+fn register_signal_fns(engine: &mut Engine) {
+    engine.register_fn("buy", |ticker: &str| signal_map(ticker, Side::Buy));
+    engine.register_fn("sell", |ticker: &str| signal_map(ticker, Side::Sell));
+}
+
+fn register_indicator_fns(engine: &mut Engine) {
+    fn single(
+        values: &Array,
+        period: rhai::INT,
+        calculate: fn(&[Decimal], usize) -> Option<Decimal>,
+    ) -> Dynamic {
+        match (array_to_decimals(values), to_period(period)) {
+            (Some(prices), Some(period)) => calculate(&prices, period)
+                .map(decimal_to_dynamic)
+                .unwrap_or(Dynamic::UNIT),
+            _ => Dynamic::UNIT,
+        }
+    }
+
+    fn bollinger(values: &Array, period: rhai::INT, std_dev: f64) -> Dynamic {
+        let (Some(prices), Some(period), Some(std_dev)) = (
+            array_to_decimals(values),
+            to_period(period),
+            Decimal::from_f64(std_dev),
+        ) else {
+            return Dynamic::UNIT;
+        };
+        let Some(bands) = calculate_bollinger_bands(&prices, period, std_dev) else {
+            return Dynamic::UNIT;
+        };
+
+        let mut map = Map::new();
+        map.insert("upper".into(), decimal_to_dynamic(bands.upper));
+        map.insert("middle".into(), decimal_to_dynamic(bands.middle));
+        map.insert("lower".into(), decimal_to_dynamic(bands.lower));
+        map.insert("width".into(), decimal_to_dynamic(bands.width));
+        map.into()
+    }
+
+    engine.register_fn("sma", |values: Array, period: rhai::INT| {
+        single(&values, period, calculate_sma)
+    });
+    engine.register_fn("ema", |values: Array, period: rhai::INT| {
+        single(&values, period, calculate_ema)
+    });
+    engine.register_fn("rsi", |values: Array, period: rhai::INT| {
+        single(&values, period, calculate_rsi)
+    });
+    engine.register_fn(
+        "bollinger",
+        |values: Array, period: rhai::INT, std_dev: rhai::FLOAT| {
+            bollinger(&values, period, std_dev)
+        },
+    );
+    engine.register_fn(
+        "bollinger",
+        |values: Array, period: rhai::INT, std_dev: rhai::INT| {
+            bollinger(&values, period, std_dev as f64)
+        },
+    );
+    engine.register_fn(
+        "macd",
+        |values: Array, fast: rhai::INT, slow: rhai::INT, signal: rhai::INT| {
+            let (Some(prices), Some(fast), Some(slow), Some(signal)) = (
+                array_to_decimals(&values),
+                to_period(fast),
+                to_period(slow),
+                to_period(signal),
+            ) else {
+                return Dynamic::UNIT;
+            };
+            let Some(result) = calculate_macd(&prices, fast, slow, signal) else {
+                return Dynamic::UNIT;
+            };
+
+            let mut map = Map::new();
+            map.insert("macd".into(), decimal_to_dynamic(result.macd));
+            map.insert("signal".into(), decimal_to_dynamic(result.signal));
+            map.insert("histogram".into(), decimal_to_dynamic(result.histogram));
+            Dynamic::from_map(map)
+        },
+    );
+    engine.register_fn("atr", |klines: Array, period: rhai::INT| {
+        let field = |name: &str| -> Option<Vec<Decimal>> {
+            klines
+                .iter()
+                .map(|k| {
+                    k.read_lock::<Map>()
+                        .and_then(|m| m.get(name).and_then(dynamic_to_decimal))
+                })
+                .collect()
+        };
+        match (
+            field("high"),
+            field("low"),
+            field("close"),
+            to_period(period),
+        ) {
+            (Some(highs), Some(lows), Some(closes), Some(period)) => {
+                calculate_atr(&highs, &lows, &closes, period)
+                    .map(decimal_to_dynamic)
+                    .unwrap_or(Dynamic::UNIT)
+            }
+            _ => Dynamic::UNIT,
+        }
+    });
+}
+
+fn register_history_fns(engine: &mut Engine, host: Arc<ScriptHost>) {
+    let history_host = host.clone();
+    engine.register_fn("history", move |ticker: &str| -> Array {
+        history_host
+            .history(ticker)
+            .iter()
+            .map(kline_to_dynamic)
+            .collect()
+    });
+    engine.register_fn("closes", move |ticker: &str| -> Array {
+        host.history(ticker)
+            .iter()
+            .map(|k| decimal_to_dynamic(k.close))
+            .collect()
+    });
+}
+
+fn register_context_fns(engine: &mut Engine, host: Arc<ScriptHost>) {
+    let h = host.clone();
+    engine.register_fn(
+        "klines",
+        move |ticker: &str, timeframe: &str| -> Result<Array, Box<EvalAltResult>> {
+            let timeframe: Timeframe = timeframe
+                .parse()
+                .map_err(|_| format!("잘못된 타임프레임: {}", timeframe))?;
+            Ok(h.with_context(|ctx| {
+                ctx.get_klines(ticker, timeframe)
+                    .iter()
+                    .map(kline_to_dynamic)
+                    .collect()
+            })
+            .unwrap_or_default())
+        },
+    );
+
+    let h = host.clone();
+    engine.register_fn("position", move |ticker: &str| -> Dynamic {
+        h.with_context(|ctx| {
+            ctx.get_position(ticker).map(|p| {
+                let mut map = Map::new();
+                map.insert("ticker".into(), p.ticker.clone().into());
+                map.insert("side".into(), serde_name(&p.side));
+                map.insert("quantity".into(), decimal_to_dynamic(p.quantity));
+                map.insert("entry_price".into(), decimal_to_dynamic(p.avg_entry_price));
+                map.insert("current_price".into(), decimal_to_dynamic(p.current_price));
+                map.insert(
+                    "unrealized_pnl".into(),
+                    decimal_to_dynamic(p.unrealized_pnl),
+                );
+                map.insert(
+                    "unrealized_pnl_pct".into(),
+                    decimal_to_dynamic(p.unrealized_pnl_pct),
+                );
+                Dynamic::from_map(map)
+            })
+        })
+        .flatten()
+        .unwrap_or(Dynamic::UNIT)
+    });
+
+    let h = host.clone();
+    engine.register_fn("has_position", move |ticker: &str| -> bool {
+        h.with_context(|ctx| ctx.has_position(ticker))
+            .unwrap_or(false)
+    });
+
+    let h = host.clone();
+    engine.register_fn("account", move || -> Dynamic {
+        h.with_context(|ctx| {
+            let account = &ctx.account;
+            let mut map = Map::new();
+            map.insert(
+                "total_balance".into(),
+                decimal_to_dynamic(account.total_balance),
+            );
+            map.insert(
+                "available_balance".into(),
+                decimal_to_dynamic(account.available_balance),
+            );
+            map.insert(
+                "margin_used".into(),
+                decimal_to_dynamic(account.margin_used),
+            );
+            map.insert(
+                "unrealized_pnl".into(),
+                decimal_to_dynamic(account.unrealized_pnl),
+            );
+            map.insert("currency".into(), account.currency.clone().into());
+            Dynamic::from_map(map)
+        })
+        .unwrap_or(Dynamic::UNIT)
+    });
+
+    let h = host.clone();
+    engine.register_fn("global_score", move |ticker: &str| -> Dynamic {
+        h.with_context(|ctx| {
+            ctx.get_global_score(ticker)
+                .map(|score| decimal_to_dynamic(score.overall_score))
+        })
+        .flatten()
+        .unwrap_or(Dynamic::UNIT)
+    });
+
+    let h = host.clone();
+    engine.register_fn("route_state", move |ticker: &str| -> Dynamic {
+        h.with_context(|ctx| ctx.get_route_state(ticker).map(serde_name))
+            .flatten()
+            .unwrap_or(Dynamic::UNIT)
+    });
+
+    engine.register_fn("market_regime", move |ticker: &str| -> Dynamic {
+        host.with_context(|ctx| ctx.get_market_regime(ticker).map(serde_name))
+            .flatten()
+            .unwrap_or(Dynamic::UNIT)
+    });
+}
+
+/// 스크립트 정의 함수 존재 여부.
+fn has_hook(ast: &AST, name: &str, arity: usize) -> bool {
+    ast.iter_functions()
+        .any(|f| f.name == name && f.params.len() == arity)
+}
+
+// ============================================================================
+// 전략 구현
+// ============================================================================
+
+/// 영속화 상태 (포맷 버전 1).
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    /// 스크립트 `this` 상태 맵
+    state: Value,
+}
+
+/// Rhai 스크립트 기반 전략.
+pub struct ScriptStrategy {
+    config: Option<ScriptConfig>,
+    engine: Engine,
+    ast: Option<AST>,
+    /// 훅 호출 간 유지되는 `this` 상태
+    state: Dynamic,
+    host: Arc<ScriptHost>,
+    signals_generated: u64,
+}
+
+impl ScriptStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            engine: Engine::new_raw(),
+            ast: None,
+            state: Dynamic::from_map(Map::new()),
+            host: Arc::new(ScriptHost::default()),
+            signals_generated: 0,
+        }
+    }
+
+    /// 설정 검증 (파싱 + 컴파일 + 필수 훅 확인).
+    ///
+    /// DB 저장 전에 호출하여 실행할 수 없는 스크립트를 거부합니다.
+    pub fn validate_config(config: &Value) -> Result<(), ScriptError> {
+        let parsed = ScriptConfig::from_value(config)?;
+        let engine = build_engine(&parsed, Map::new(), Arc::new(ScriptHost::default()));
+        compile(&engine, &parsed.source).map(|_| ())
+    }
+
+    /// 스크립트 훅 호출 (정의되지 않은 훅은 `()` 반환).
+    fn call_hook(
+        &mut self,
+        hook: &'static str,
+        arity: usize,
+        args: impl FuncArgs,
+    ) -> Result<Dynamic, ScriptError> {
+        let Some(ast) = self.ast.as_ref() else {
+            return Ok(Dynamic::UNIT);
+        };
+        if !has_hook(ast, hook, arity) {
+            return Ok(Dynamic::UNIT);
+        }
+
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .bind_this_ptr(&mut self.state);
+        self.engine
+            .call_fn_with_options::<Dynamic>(options, &mut Scope::new(), ast, hook, args)
+            .map_err(|e| ScriptError::Runtime {
+                hook,
+                message: e.to_string(),
+            })
+    }
+}
+
+/// 소스 컴파일 및 필수 훅 확인.
+fn compile(engine: &Engine, source: &str) -> Result<AST, ScriptError> {
+    let ast = engine
+        .compile(source)
+        .map_err(|e| ScriptError::Compile(e.to_string()))?;
+    if !has_hook(&ast, "on_market_data", 1) {
+        return Err(ScriptError::MissingHook("on_market_data(data)"));
+    }
+    Ok(ast)
+}
+
+impl Default for ScriptStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Strategy Trait 구현
+// ============================================================================
+
+#[async_trait]
+impl Strategy for ScriptStrategy {
+    fn name(&self) -> &str {
+        "Script"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "Rhai 스크립트 기반 사용자 정의 전략"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let cfg = ScriptConfig::from_value(&config)?;
+
+        let params: Map = match rhai::serde::to_dynamic(&config)?.try_cast::<Map>() {
+            Some(mut params) => {
+                params.remove("source");
+                params
+            }
+            None => Map::new(),
+        };
+
+        self.host.clear_history();
+        self.engine = build_engine(&cfg, params, self.host.clone());
+        self.ast = Some(compile(&self.engine, &cfg.source)?);
+        self.state = Dynamic::from_map(Map::new());
+        self.signals_generated = 0;
+
+        info!(
+            tickers = ?cfg.subscribed_tickers(),
+            max_operations = cfg.max_operations,
+            "스크립트 전략 초기화"
+        );
+        self.config = Some(cfg);
+
+        self.call_hook("init", 0, ()).map(drop)?;
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(config) = self.config.as_ref() else {
+            return Ok(vec![]);
+        };
+
+        if let MarketDataType::Kline(kline) = &data.data {
+            self.host
+                .push_kline(kline, config.history_size.min(MAX_HISTORY_SIZE));
+        }
+
+        let Some(input) = market_data_to_dynamic(data) else {
+            return Ok(vec![]);
+        };
+
+        let result = self.call_hook("on_market_data", 1, (input,))?;
+        let signals = parse_signals(result)?;
+        self.signals_generated += signals.len() as u64;
+
+        if !signals.is_empty() {
+            debug!(
+                ticker = %data.ticker,
+                count = signals.len(),
+                "스크립트 신호 생성"
+            );
+        }
+        Ok(signals)
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.call_hook("on_order_filled", 1, (order_to_dynamic(order),))
+            .map(drop)?;
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.call_hook("on_position_update", 1, (position_to_dynamic(position),))
+            .map(drop)?;
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!(signals = self.signals_generated, "스크립트 전략 종료");
+        Ok(())
+    }
+
+    fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
+        if let Ok(mut slot) = self.host.context.write() {
+            *slot = Some(context);
+        }
+        debug!("StrategyContext 주입 완료");
+    }
+
+    fn subscribed_tickers(&self) -> Vec<String> {
+        self.config
+            .as_ref()
+            .map(|c| c.subscribed_tickers())
+            .unwrap_or_default()
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "tickers": self.subscribed_tickers(),
+            "state": rhai::serde::from_dynamic::<Value>(&self.state).unwrap_or(Value::Null),
+            "signals_generated": self.signals_generated,
+            "compiled": self.ast.is_some(),
+        })
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let state = rhai::serde::from_dynamic::<Value>(&self.state)
+            .map_err(|e| ScriptError::State(e.to_string()))?;
+        Ok(encode_versioned_state(
+            STATE_FORMAT_VERSION,
+            &PersistedState { state },
+        )?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let (_, value) = decode_versioned_state(data)?;
+        let persisted: PersistedState = serde_json::from_value(value)?;
+        let state = rhai::serde::to_dynamic(&persisted.state)
+            .map_err(|e| ScriptError::State(e.to_string()))?;
+
+        if !state.is_map() {
+            return Err(ScriptError::State("상태는 맵이어야 합니다".to_string()).into());
+        }
+        self.state = state;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// 레지스트리 등록
+// ============================================================================
+
+use crate::register_strategy;
+
+register_strategy! {
+    id: "script",
+    aliases: ["rhai", "스크립트"],
+    name: "Script Strategy",
+    description: "Rhai 스크립트로 정의하는 사용자 전략 (재컴파일 불필요)",
+    timeframe: "1d",
+    tickers: [],
+    category: Daily,
+    markets: [Crypto, Stock],
+    type: ScriptStrategy
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use rust_decimal_macros::dec;
+
+    const RSI_SCRIPT: &str = r#"
+        fn init() {
+            this.holding = false;
+            this.fills = 0;
+        }
+
+        fn on_market_data(data) {
+            let value = rsi(closes(data.ticker), param("period", 3));
+            if value == () { return; }
+
+            if value < 30.0 && !this.holding {
+                this.holding = true;
+                let signal = buy(data.ticker);
+                signal.strength = 0.5;
+                return signal;
+            }
+            if value > 70.0 && this.holding {
+                this.holding = false;
+                return [sell(data.ticker)];
+            }
+        }
+
+        fn on_order_filled(order) {
+            this.fills += 1;
+        }
+    "#;
+
+    fn kline_data(day: u32, close: Decimal) -> MarketData {
+        let time = Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+        MarketData::from_kline(
+            "test",
+            Kline::new(
+                "005930".to_string(),
+                Timeframe::D1,
+                time,
+                close,
+                close,
+                close,
+                close,
+                dec!(1000),
+                time,
+            ),
+        )
+    }
+
+    async fn initialized(source: &str) -> ScriptStrategy {
+        let mut strategy = ScriptStrategy::new();
+        strategy
+            .initialize(json!({ "source": source, "ticker": "005930" }))
+            .await
+            .unwrap();
+        strategy
+    }
+
+    async fn feed(strategy: &mut ScriptStrategy, closes: &[Decimal]) -> Vec<Signal> {
+        let mut signals = vec![];
+        for (i, close) in closes.iter().enumerate() {
+            signals.extend(
+                strategy
+                    .on_market_data(&kline_data(i as u32 + 1, *close))
+                    .await
+                    .unwrap(),
+            );
+        }
+        signals
+    }
+
+    #[tokio::test]
+    async fn test_script_generates_signals_from_indicators() {
+        let mut strategy = initialized(RSI_SCRIPT).await;
+        let signals = feed(
+            &mut strategy,
+            &[
+                dec!(100),
+                dec!(95),
+                dec!(90),
+                dec!(85),
+                dec!(90),
+                dec!(95),
+                dec!(100),
+            ],
+        )
+        .await;
+
+        assert_eq!(signals.len(), 2);
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].signal_type, SignalType::Entry);
+        assert_eq!(signals[0].strength, 0.5);
+        assert_eq!(signals[1].side, Side::Sell);
+        assert_eq!(signals[1].signal_type, SignalType::Exit);
+        assert_eq!(strategy.subscribed_tickers(), vec!["005930".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_state_survives_save_and_load() {
+        let mut strategy = initialized(RSI_SCRIPT).await;
+        feed(&mut strategy, &[dec!(100), dec!(95), dec!(90), dec!(85)]).await;
+        let saved = strategy.save_state().unwrap();
+
+        let mut restored = initialized(RSI_SCRIPT).await;
+        restored.load_state(&saved).unwrap();
+
+        assert_eq!(restored.get_state()["state"]["holding"], json!(true));
+    }
+
+    #[tokio::test]
+    async fn test_infinite_loop_is_aborted() {
+        let mut strategy = ScriptStrategy::new();
+        strategy
+            .initialize(json!({
+                "source": "fn on_market_data(data) { loop { } }",
+                "max_operations": 10_000
+            }))
+            .await
+            .unwrap();
+
+        let result = strategy.on_market_data(&kline_data(1, dec!(100))).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_config_rejects_invalid_scripts() {
+        assert!(matches!(
+            ScriptStrategy::validate_config(&json!({ "source": "fn on_market_data(data) {" })),
+            Err(ScriptError::Compile(_))
+        ));
+        assert!(matches!(
+            ScriptStrategy::validate_config(&json!({ "source": "fn init() {}" })),
+            Err(ScriptError::MissingHook(_))
+        ));
+        assert!(ScriptStrategy::validate_config(&json!({
+            "source": "fn on_market_data(data) { eval(\"1\") }"
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_config_rejects_limits_above_host_maximum() {
+        let source = "fn on_market_data(data) { [] }";
+        assert!(ScriptStrategy::validate_config(&json!({
+            "source": source,
+            "max_operations": MAX_OPERATIONS_LIMIT
+        }))
+        .is_ok());
+        assert!(matches!(
+            ScriptStrategy::validate_config(&json!({
+                "source": source,
+                "max_operations": MAX_OPERATIONS_LIMIT + 1
+            })),
+            Err(ScriptError::Config(_))
+        ));
+        assert!(matches!(
+            ScriptStrategy::validate_config(&json!({
+                "source": source,
+                "history_size": MAX_HISTORY_SIZE + 1
+            })),
+            Err(ScriptError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+//! 티커 구독 인덱스.
+//!
+//! 전략별 구독 티커를 티커 → 전략 ID 역인덱스로 관리하여, 시장 데이터가
+//! 들어왔을 때 해당 티커를 구독한 전략에만 라우팅할 수 있게 합니다.
+//! 구독 목록이 비어 있는 전략은 모든 티커를 수신합니다.
+
+use std::collections::{HashMap, HashSet};
+
+/// 티커 → 전략 ID 구독 인덱스.
+#[derive(Debug, Default)]
+pub struct SubscriptionIndex {
+    /// 티커별 구독 전략 ID
+    by_ticker: HashMap<String, HashSet<String>>,
+    /// 모든 티커를 구독하는 전략 ID
+    all_tickers: HashSet<String>,
+    /// 전략별 구독 티커 (인덱스 갱신용)
+    by_strategy: HashMap<String, Vec<String>>,
+}
+
+impl SubscriptionIndex {
+    /// 빈 인덱스 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 전략의 구독 티커 설정 (기존 구독 대체, 빈 목록 = 전체 티커).
+    pub fn set(&mut self, strategy_id: &str, tickers: &[String]) {
+        self.remove(strategy_id);
+
+        if tickers.is_empty() {
+            self.all_tickers.insert(strategy_id.to_string());
+        } else {
+            for ticker in tickers {
+                self.by_ticker
+                    .entry(ticker.clone())
+                    .or_default()
+                    .insert(strategy_id.to_string());
+            }
+        }
+
+        let mut tickers = tickers.to_vec();
+        tickers.sort();
+        tickers.dedup();
+        self.by_strategy.insert(strategy_id.to_string(), tickers);
+    }
+
+    /// 전략의 구독 제거.
+    pub fn remove(&mut self, strategy_id: &str) {
+        self.all_tickers.remove(strategy_id);
+
+        if let Some(tickers) = self.by_strategy.remove(strategy_id) {
+            for ticker in tickers {
+                if let Some(ids) = self.by_ticker.get_mut(&ticker) {
+                    ids.remove(strategy_id);
+                    if ids.is_empty() {
+                        self.by_ticker.remove(&ticker);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 티커를 수신할 전략 ID 목록 (ID 순 정렬).
+    pub fn strategies_for(&self, ticker: &str) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .all_tickers
+            .iter()
+            .chain(self.by_ticker.get(ticker).into_iter().flatten())
+            .cloned()
+            .collect();
+        ids.sort();
+        ids.dedup();
+        ids
+    }
+
+    /// 전략의 구독 티커 (빈 목록 = 전체 티커, None = 미등록).
+    pub fn tickers_for(&self, strategy_id: &str) -> Option<&[String]> {
+        self.by_strategy.get(strategy_id).map(|t| t.as_slice())
+    }
+
+    /// 구독 중인 모든 티커 (전체 구독 전략 제외).
+    pub fn subscribed_tickers(&self) -> Vec<String> {
+        let mut tickers: Vec<String> = self.by_ticker.keys().cloned().collect();
+        tickers.sort();
+        tickers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tickers(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_routes_only_to_subscribers() {
+        let mut index = SubscriptionIndex::new();
+        index.set("kr", &tickers(&["005930", "000660"]));
+        index.set("us", &tickers(&["AAPL"]));
+        index.set("all", &[]);
+
+        assert_eq!(index.strategies_for("005930"), tickers(&["all", "kr"]));
+        assert_eq!(index.strategies_for("AAPL"), tickers(&["all", "us"]));
+        assert_eq!(index.strategies_for("TSLA"), tickers(&["all"]));
+    }
+
+    #[test]
+    fn test_set_replaces_previous_subscription() {
+        let mut index = SubscriptionIndex::new();
+        index.set("s1", &tickers(&["005930"]));
+        index.set("s1", &tickers(&["000660"]));
+
+        assert!(index.strategies_for("005930").is_empty());
+        assert_eq!(index.strategies_for("000660"), tickers(&["s1"]));
+        assert_eq!(index.subscribed_tickers(), tickers(&["000660"]));
+    }
+
+    #[test]
+    fn test_remove_clears_all_entries() {
+        let mut index = SubscriptionIndex::new();
+        index.set("s1", &tickers(&["005930"]));
+        index.set("s2", &[]);
+        index.remove("s1");
+        index.remove("s2");
+
+        assert!(index.strategies_for("005930").is_empty());
+        assert!(index.tickers_for("s1").is_none());
+        assert!(index.subscribed_tickers().is_empty());
+    }
+}
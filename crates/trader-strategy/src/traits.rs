@@ -1,5 +1,7 @@
 //! Strategy trait 정의.
 
+use crate::decision::Decision;
+use crate::schedule::{ScheduleEvent, ScheduleTrigger};
 use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -141,6 +143,73 @@ pub trait Strategy: Send + Sync {
         self.on_market_data(primary_data).await
     }
 
+    /// 구독할 티커 목록.
+    ///
+    /// 엔진은 `initialize()` 이후 이 목록으로 티커 → 전략 라우팅 인덱스를 구성하고,
+    /// 구독한 티커의 시장 데이터만 전달합니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 빈 목록을 반환하며, 이 경우 모든 티커의 시장 데이터를 수신합니다.
+    fn subscribed_tickers(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// 등록할 스케줄 트리거 목록.
+    ///
+    /// 엔진은 `initialize()` 이후 이 목록을 스케줄러에 등록하고, 트리거 시각이 지나면
+    /// `on_schedule()`을 호출합니다. 백테스트에서는 캔들 시각 기준으로 같은 트리거가 발생합니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 빈 목록을 반환합니다 (스케줄 미사용).
+    fn schedule_triggers(&self) -> Vec<ScheduleTrigger> {
+        Vec::new()
+    }
+
+    /// 스케줄 트리거 발생 시 호출.
+    ///
+    /// 리밸런싱처럼 정해진 시점에만 동작하는 로직을 `on_market_data`의 날짜 검사 대신
+    /// 여기에 구현합니다.
+    async fn on_schedule(
+        &mut self,
+        _event: &ScheduleEvent,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(vec![])
+    }
+
+    /// 워밍업에 필요한 과거 캔들 (타임프레임, 캔들 수).
+    ///
+    /// 값이 있으면 엔진은 전략을 `WarmingUp` 상태로 시작하고, 지정한 수의 캔들을
+    /// 처리할 때까지 신호를 내보내지 않습니다. 과거 캔들은 호출자가
+    /// `StrategyEngine::warm_up_strategy`로 공급합니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// `None`을 반환합니다 (워밍업 없음).
+    fn warmup_requirement(&self) -> Option<(Timeframe, usize)> {
+        None
+    }
+
+    /// 지표 계산에 필요한 데이터가 모두 준비되었는지 여부.
+    ///
+    /// 워밍업 캔들 수를 채운 뒤에도 false이면 준비될 때까지 `WarmingUp` 상태를 유지합니다.
+    fn is_ready(&self) -> bool {
+        true
+    }
+
+    /// 마지막 호출 이후 쌓인 의사결정 기록을 꺼냄.
+    ///
+    /// 엔진은 시장 데이터 처리 직후 호출하여, 평가한 조건·지표 값·거부 필터를
+    /// 의사결정 로그에 남깁니다. 신호 생성 여부는 엔진이 실제 신호와 대조해 판단합니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 빈 목록을 반환합니다 (엔진은 생성된 신호만 기록).
+    fn take_decisions(&mut self) -> Vec<Decision> {
+        Vec::new()
+    }
+
     /// 현재 전략 상태를 JSON으로 반환 (디버깅/모니터링용).
     fn get_state(&self) -> Value;
 
@@ -551,7 +551,8 @@ export type UiFieldType =
   | 'split_levels'
   | 'symbol_category_group'
   | 'date'
-  | 'timeframe';
+  | 'timeframe'
+  | 'rules';
 
 /** 유효성 검사 규칙 */
 export interface UiValidation {
@@ -1,11 +1,12 @@
 /**
  * SDUI 스키마 API 함수
  *
- * 전략 스키마 및 Fragment 조회 API를 호출합니다.
+ * 전략 스키마, Fragment, 규칙 빌더 카탈로그 조회 API를 호출합니다.
  * ts-rs 자동 생성 타입 사용
  */
 import api from './client';
 import type {
+  FieldSchema,
   StrategyUISchema,
   SchemaFragment,
 } from '../types/generated/sdui';
@@ -106,3 +107,48 @@ export async function getFragmentDetails(
   }
   return map;
 }
+
+// ==================== 규칙 빌더 ====================
+
+/**
+ * 규칙 피연산자 소스 종류
+ *
+ * - `series`: 캔들 이력 기반 수치 (돌파 비교 가능)
+ * - `number`: 컨텍스트 수치
+ * - `category`: 범주형 값 (==, != 만 가능)
+ */
+export type RuleOperandKind = 'series' | 'number' | 'category';
+
+/** 규칙 피연산자 소스 */
+export interface RuleSource {
+  id: string;
+  label: string;
+  kind: RuleOperandKind;
+  outputs: string[];
+  /** 지표 파라미터 필드 (Fragment 필드 스키마) */
+  params: FieldSchema[];
+  /** 범주형 소스의 허용 값 */
+  values?: string[];
+}
+
+/** 비교 연산자 */
+export interface RuleComparator {
+  id: string;
+  label: string;
+}
+
+/** 규칙 빌더 카탈로그 */
+export interface RuleCatalog {
+  sources: RuleSource[];
+  comparators: RuleComparator[];
+}
+
+/**
+ * 규칙 빌더 카탈로그 조회
+ *
+ * @returns 피연산자 소스와 비교 연산자 목록
+ */
+export async function getRuleCatalog(): Promise<RuleCatalog> {
+  const response = await api.get('/schema/rules');
+  return response.data;
+}
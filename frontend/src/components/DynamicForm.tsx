@@ -8,6 +8,7 @@ import { createSignal, For, Show, createMemo } from 'solid-js'
 import { ChevronDown, ChevronRight, HelpCircle, Plus, X } from 'lucide-solid'
 import type { UiSchema, UiField, UiFieldGroup, UiValidation, UiSelectOption, SymbolCategory } from '../api/client'
 import { SymbolSearch } from './SymbolSearch'
+import { RuleBuilderField, type RuleGroupValue } from './strategy/SDUIRenderer/fields'
 
 interface DynamicFormProps {
   schema: UiSchema
@@ -302,6 +303,15 @@ function renderFieldByType(
         />
       )
 
+    case 'rules':
+      return (
+        <RuleBuilderField
+          value={value as RuleGroupValue | null}
+          onChange={onChange}
+          readOnly={disabled}
+        />
+      )
+
     default:
       return (
         <TextField
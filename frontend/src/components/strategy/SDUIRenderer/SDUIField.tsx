@@ -12,7 +12,13 @@ import {
   createMemo,
 } from 'solid-js';
 import type { FieldSchema } from '../../../types/sdui';
-import { MultiSymbolInput, MultiTimeframeField, type MultiTimeframeValue } from './fields';
+import {
+  MultiSymbolInput,
+  MultiTimeframeField,
+  RuleBuilderField,
+  type MultiTimeframeValue,
+  type RuleGroupValue,
+} from './fields';
 import { SymbolSearch } from '../../../components/SymbolSearch';
 
 // ==================== 유틸리티 함수 ====================
@@ -232,6 +238,17 @@ export const SDUIField: Component<SDUIFieldProps> = (props) => {
             hasError={!!props.error}
           />
         </Match>
+
+        {/* 조건 규칙 그룹 (규칙 빌더) */}
+        <Match when={props.field.field_type === 'rules'}>
+          <RuleBuilderField
+            id={props.field.name}
+            value={props.value as RuleGroupValue | null}
+            onChange={(v) => props.onChange(v)}
+            readOnly={props.readOnly}
+            hasError={!!props.error}
+          />
+        </Match>
       </Switch>
 
       {/* 에러 메시지 */}
@@ -0,0 +1,340 @@
+/**
+ * 규칙 빌더 필드 컴포넌트
+ *
+ * 규칙 기반 전략의 진입/청산 조건 그룹을 편집합니다.
+ * 피연산자 소스와 파라미터 범위는 서버 카탈로그(`/schema/rules`)를 따릅니다.
+ */
+import {
+  type Component,
+  createResource,
+  For,
+  Show,
+} from 'solid-js';
+import {
+  getRuleCatalog,
+  type RuleCatalog,
+  type RuleSource,
+} from '../../../../api/schema';
+
+// ==================== 타입 ====================
+
+/** 지표/컨텍스트 참조 피연산자 */
+export interface RuleFragmentOperand {
+  fragment: string;
+  output?: string;
+  params?: Record<string, unknown>;
+}
+
+/** 조건 피연산자 (숫자/문자열 리터럴 또는 지표 참조) */
+export type RuleOperand = number | string | RuleFragmentOperand;
+
+/** 단일 비교 조건 */
+export interface RuleConditionValue {
+  left: RuleOperand;
+  op: string;
+  right: RuleOperand;
+}
+
+/** 조건 그룹 */
+export interface RuleGroupValue {
+  logic: 'all' | 'any';
+  conditions: RuleConditionValue[];
+}
+
+export interface RuleBuilderFieldProps {
+  /** HTML id */
+  id?: string;
+  /** 현재 값 */
+  value: RuleGroupValue | null;
+  /** 값 변경 핸들러 */
+  onChange: (value: RuleGroupValue) => void;
+  /** 읽기 전용 */
+  readOnly?: boolean;
+  /** 에러 상태 */
+  hasError?: boolean;
+}
+
+// ==================== 상수 ====================
+
+/** 리터럴 피연산자 선택 값 */
+const LITERAL = '__literal__';
+
+const DEFAULT_CONDITION: RuleConditionValue = {
+  left: { fragment: 'indicator.rsi' },
+  op: '<',
+  right: 30,
+};
+
+const selectClass =
+  'px-2 py-1.5 rounded-md text-sm bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-300 border border-gray-300 dark:border-gray-600';
+
+// 카탈로그는 모든 규칙 필드가 공유
+let catalogCache: Promise<RuleCatalog> | null = null;
+const loadCatalog = () => (catalogCache ??= getRuleCatalog());
+
+// ==================== 유틸리티 ====================
+
+const isFragment = (operand: RuleOperand): operand is RuleFragmentOperand =>
+  typeof operand === 'object' && operand !== null;
+
+const findSource = (catalog: RuleCatalog | undefined, operand: RuleOperand) =>
+  isFragment(operand)
+    ? catalog?.sources.find((s) => s.id === operand.fragment)
+    : undefined;
+
+// ==================== 내부 컴포넌트 ====================
+
+interface OperandEditorProps {
+  catalog: RuleCatalog;
+  value: RuleOperand;
+  onChange: (value: RuleOperand) => void;
+  /** 비교 대상이 범주형이면 허용 값 목록 */
+  categoryValues?: string[];
+  readOnly?: boolean;
+}
+
+const OperandEditor: Component<OperandEditorProps> = (props) => {
+  const source = () => findSource(props.catalog, props.value);
+  const fragment = () => (isFragment(props.value) ? props.value : null);
+
+  const handleSourceChange = (id: string) => {
+    if (id === LITERAL) {
+      props.onChange(props.categoryValues?.[0] ?? 0);
+    } else {
+      props.onChange({ fragment: id });
+    }
+  };
+
+  const updateFragment = (patch: Partial<RuleFragmentOperand>) => {
+    const current = fragment();
+    if (current) props.onChange({ ...current, ...patch });
+  };
+
+  const setParam = (name: string, value: unknown) =>
+    updateFragment({ params: { ...(fragment()?.params ?? {}), [name]: value } });
+
+  return (
+    <div class="flex flex-wrap items-center gap-1">
+      <select
+        class={selectClass}
+        value={fragment()?.fragment ?? LITERAL}
+        onChange={(e) => handleSourceChange(e.currentTarget.value)}
+        disabled={props.readOnly}
+      >
+        <option value={LITERAL}>값</option>
+        <For each={props.catalog.sources}>
+          {(s) => <option value={s.id}>{s.label}</option>}
+        </For>
+      </select>
+
+      <Show
+        when={source()}
+        fallback={
+          <Show
+            when={props.categoryValues && props.categoryValues.length > 0}
+            fallback={
+              <input
+                type="number"
+                class={`${selectClass} w-24`}
+                value={typeof props.value === 'number' ? props.value : ''}
+                onInput={(e) => props.onChange(parseFloat(e.currentTarget.value) || 0)}
+                disabled={props.readOnly}
+              />
+            }
+          >
+            <select
+              class={selectClass}
+              value={String(props.value)}
+              onChange={(e) => props.onChange(e.currentTarget.value)}
+              disabled={props.readOnly}
+            >
+              <For each={props.categoryValues}>
+                {(v) => <option value={v}>{v}</option>}
+              </For>
+            </select>
+          </Show>
+        }
+      >
+        {(s) => (
+          <>
+            <Show when={s().outputs.length > 1}>
+              <select
+                class={selectClass}
+                value={fragment()?.output ?? s().outputs[0]}
+                onChange={(e) => updateFragment({ output: e.currentTarget.value })}
+                disabled={props.readOnly}
+              >
+                <For each={s().outputs}>{(o) => <option value={o}>{o}</option>}</For>
+              </select>
+            </Show>
+            <For each={s().params}>
+              {(param) => {
+                const current = () => fragment()?.params?.[param.name] ?? param.default;
+                return (
+                  <label class="flex items-center gap-1 text-xs text-gray-500">
+                    {param.label}
+                    <Show
+                      when={param.field_type === 'select'}
+                      fallback={
+                        <input
+                          type="number"
+                          class={`${selectClass} w-20`}
+                          value={(current() as number) ?? ''}
+                          min={param.min ?? undefined}
+                          max={param.max ?? undefined}
+                          onInput={(e) => setParam(param.name, parseFloat(e.currentTarget.value))}
+                          disabled={props.readOnly}
+                        />
+                      }
+                    >
+                      <select
+                        class={selectClass}
+                        value={String(current() ?? '')}
+                        onChange={(e) => setParam(param.name, e.currentTarget.value)}
+                        disabled={props.readOnly}
+                      >
+                        <For each={param.options}>{(o) => <option value={o}>{o}</option>}</For>
+                      </select>
+                    </Show>
+                  </label>
+                );
+              }}
+            </For>
+          </>
+        )}
+      </Show>
+    </div>
+  );
+};
+
+// ==================== 컴포넌트 ====================
+
+/**
+ * 규칙 빌더 필드
+ *
+ * @example
+ * ```tsx
+ * <RuleBuilderField
+ *   value={{ logic: 'all', conditions: [{ left: { fragment: 'indicator.rsi' }, op: '<', right: 30 }] }}
+ *   onChange={(v) => setConfig(prev => ({ ...prev, entry: v }))}
+ * />
+ * ```
+ */
+export const RuleBuilderField: Component<RuleBuilderFieldProps> = (props) => {
+  const [catalog] = createResource(loadCatalog);
+
+  const value = (): RuleGroupValue => ({
+    logic: props.value?.logic ?? 'all',
+    conditions: props.value?.conditions ?? [],
+  });
+
+  const updateCondition = (index: number, patch: Partial<RuleConditionValue>) => {
+    const conditions = value().conditions.map((c, i) => (i === index ? { ...c, ...patch } : c));
+    props.onChange({ ...value(), conditions });
+  };
+
+  const addCondition = () => {
+    if (props.readOnly) return;
+    props.onChange({ ...value(), conditions: [...value().conditions, { ...DEFAULT_CONDITION }] });
+  };
+
+  const removeCondition = (index: number) => {
+    if (props.readOnly) return;
+    props.onChange({ ...value(), conditions: value().conditions.filter((_, i) => i !== index) });
+  };
+
+  // 범주형 소스와 비교하는 반대편 리터럴의 허용 값
+  const categoryValues = (other: RuleOperand) => {
+    const source: RuleSource | undefined = findSource(catalog(), other);
+    return source?.kind === 'category' ? source.values : undefined;
+  };
+
+  return (
+    <div
+      id={props.id}
+      class={`
+        rounded-lg border p-4 space-y-3
+        ${props.hasError
+          ? 'border-red-500'
+          : 'border-gray-300 dark:border-gray-600'
+        }
+        ${props.readOnly ? 'opacity-60' : ''}
+      `}
+    >
+      <Show when={catalog()} fallback={<p class="text-sm text-gray-500">규칙 카탈로그 불러오는 중...</p>}>
+        {(cat) => (
+          <>
+            {/* 조건 결합 방식 */}
+            <div class="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300">
+              <span>다음 조건을</span>
+              <select
+                class={selectClass}
+                value={value().logic}
+                onChange={(e) =>
+                  props.onChange({ ...value(), logic: e.currentTarget.value as RuleGroupValue['logic'] })
+                }
+                disabled={props.readOnly}
+              >
+                <option value="all">모두 충족 (AND)</option>
+                <option value="any">하나 이상 충족 (OR)</option>
+              </select>
+            </div>
+
+            {/* 조건 목록 */}
+            <For each={value().conditions}>
+              {(condition, index) => (
+                <div class="flex flex-wrap items-center gap-2 p-2 rounded-md bg-gray-50 dark:bg-gray-800">
+                  <OperandEditor
+                    catalog={cat()}
+                    value={condition.left}
+                    onChange={(left) => updateCondition(index(), { left })}
+                    categoryValues={categoryValues(condition.right)}
+                    readOnly={props.readOnly}
+                  />
+                  <select
+                    class={selectClass}
+                    value={condition.op}
+                    onChange={(e) => updateCondition(index(), { op: e.currentTarget.value })}
+                    disabled={props.readOnly}
+                  >
+                    <For each={cat().comparators}>
+                      {(op) => <option value={op.id}>{op.id} ({op.label})</option>}
+                    </For>
+                  </select>
+                  <OperandEditor
+                    catalog={cat()}
+                    value={condition.right}
+                    onChange={(right) => updateCondition(index(), { right })}
+                    categoryValues={categoryValues(condition.left)}
+                    readOnly={props.readOnly}
+                  />
+                  <Show when={!props.readOnly}>
+                    <button
+                      type="button"
+                      class="ml-auto text-sm text-red-500 hover:text-red-600"
+                      onClick={() => removeCondition(index())}
+                    >
+                      삭제
+                    </button>
+                  </Show>
+                </div>
+              )}
+            </For>
+
+            <Show when={!props.readOnly}>
+              <button
+                type="button"
+                class="px-3 py-1.5 rounded-md text-sm font-medium bg-blue-500 text-white hover:bg-blue-600"
+                onClick={addCondition}
+              >
+                + 조건 추가
+              </button>
+            </Show>
+          </>
+        )}
+      </Show>
+    </div>
+  );
+};
+
+export default RuleBuilderField;
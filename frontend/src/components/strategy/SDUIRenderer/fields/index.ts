@@ -20,3 +20,11 @@ export {
   type MultiTimeframeFieldProps,
   type MultiTimeframeValue,
 } from './MultiTimeframeField';
+
+export {
+  RuleBuilderField,
+  type RuleBuilderFieldProps,
+  type RuleGroupValue,
+  type RuleConditionValue,
+  type RuleOperand,
+} from './RuleBuilderField';
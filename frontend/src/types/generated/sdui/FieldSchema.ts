@@ -33,6 +33,10 @@ min: number | null,
  * 최대값 (number/integer 타입)
  */
 max: number | null, 
+/**
+ * 값 증감 단위 (number/integer 타입, 파라미터 최적화 격자 간격으로도 사용)
+ */
+step: number | null, 
 /**
  * 선택 옵션 (select/multi_select 타입)
  */
@@ -0,0 +1,17 @@
+-- =====================================================
+-- 08_backtest_robustness.sql
+-- 백테스트 강건성 분석 결과 저장
+-- =====================================================
+-- 포함 내용:
+-- 1. backtest_results.monte_carlo: 거래 시퀀스 몬테카를로 분석 결과
+-- =====================================================
+
+-- =====================================================
+-- BACKTEST_RESULTS TABLE 확장
+-- =====================================================
+
+-- 몬테카를로 분석 결과 (방식별 최종 자산/MDD/CAGR 분포, 파산 위험)
+ALTER TABLE backtest_results
+ADD COLUMN IF NOT EXISTS monte_carlo JSONB DEFAULT NULL;
+
+COMMENT ON COLUMN backtest_results.monte_carlo IS '몬테카를로 강건성 분석 결과 (JSONB)';
@@ -0,0 +1,27 @@
+-- =====================================================
+-- 09_strategy_state.sql
+-- 전략 상태 스냅샷 저장
+-- =====================================================
+-- 포함 내용:
+-- 1. strategy_state_snapshots: Strategy::save_state 결과 (재시작 시 복원)
+-- =====================================================
+
+-- =====================================================
+-- STRATEGY_STATE_SNAPSHOTS TABLE
+-- 전략 ID + 전략 버전별 최신 상태 스냅샷
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS strategy_state_snapshots (
+    strategy_id VARCHAR(100) NOT NULL,              -- 전략 인스턴스 ID
+    strategy_version VARCHAR(20) NOT NULL,          -- 전략 구현 버전
+    strategy_name VARCHAR(200) NOT NULL,            -- 전략 구현 이름
+    state BYTEA NOT NULL,                           -- 직렬화된 전략 상태
+    saved_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (strategy_id, strategy_version)
+);
+
+-- 전략별 최신 스냅샷 조회용
+CREATE INDEX IF NOT EXISTS idx_strategy_state_snapshots_latest
+ON strategy_state_snapshots (strategy_id, saved_at DESC);
+
+COMMENT ON TABLE strategy_state_snapshots IS '전략 내부 상태 스냅샷 (재시작 시 복원)';
@@ -0,0 +1,28 @@
+-- =====================================================
+-- 10_strategy_lifecycle.sql
+-- 전략 생명주기 전이 감사 로그
+-- =====================================================
+-- 포함 내용:
+-- 1. strategy_lifecycle_events: 시작/워밍업/일시 정지/청산 대기/중지/오류 전이 기록
+-- =====================================================
+
+-- =====================================================
+-- STRATEGY_LIFECYCLE_EVENTS TABLE
+-- 전략 상태 전이 1건당 1행 (요청 주체와 사유 포함)
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS strategy_lifecycle_events (
+    id BIGSERIAL PRIMARY KEY,
+    strategy_id VARCHAR(100) NOT NULL,              -- 전략 인스턴스 ID
+    from_state VARCHAR(20) NOT NULL,                -- 이전 상태 (snake_case)
+    to_state VARCHAR(20) NOT NULL,                  -- 다음 상태 (snake_case)
+    actor VARCHAR(50) NOT NULL,                     -- 요청 주체 (api, telegram, engine)
+    reason TEXT NOT NULL DEFAULT '',                -- 전이 사유
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 전략별 최근 전이 조회용
+CREATE INDEX IF NOT EXISTS idx_strategy_lifecycle_events_strategy
+ON strategy_lifecycle_events (strategy_id, occurred_at DESC);
+
+COMMENT ON TABLE strategy_lifecycle_events IS '전략 생명주기 상태 전이 감사 로그';
@@ -0,0 +1,42 @@
+-- =====================================================
+-- 11_strategy_decisions.sql
+-- 전략 의사결정 로그
+-- =====================================================
+-- 포함 내용:
+-- 1. strategy_decisions: 캔들 평가 → 신호 → 리스크 검증 → 주문 제출 단계별 결정 기록
+-- =====================================================
+
+-- =====================================================
+-- STRATEGY_DECISIONS TABLE
+-- 의사결정 1건당 1행 (같은 trace_id로 단계별 기록을 연결)
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS strategy_decisions (
+    id UUID PRIMARY KEY,
+    trace_id VARCHAR(200) NOT NULL,                 -- 추적 ID ({strategy_id}:{ticker}:{ms})
+    strategy_id VARCHAR(100) NOT NULL,              -- 전략 ID
+    ticker VARCHAR(50) NOT NULL,                    -- 종목
+    stage VARCHAR(20) NOT NULL,                     -- strategy, lifecycle, risk, execution
+    outcome VARCHAR(20) NOT NULL,                   -- emitted, no_signal, filtered, suppressed, rejected, submitted
+    conditions JSONB NOT NULL DEFAULT '[]',         -- 평가한 조건과 통과 여부
+    indicators JSONB NOT NULL DEFAULT '{}',         -- 판단에 사용한 지표 값
+    rejected_by VARCHAR(50),                        -- 거부 주체 (필터명, RiskManager 등)
+    reason TEXT,                                    -- 사유
+    signal_id UUID,                                 -- 관련 신호 ID
+    data_time TIMESTAMPTZ,                          -- 평가한 데이터 시각
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 전략별 최근 결정 조회용
+CREATE INDEX IF NOT EXISTS idx_strategy_decisions_strategy
+ON strategy_decisions (strategy_id, recorded_at DESC);
+
+-- 추적 ID로 단계별 기록 조회용
+CREATE INDEX IF NOT EXISTS idx_strategy_decisions_trace
+ON strategy_decisions (trace_id);
+
+-- 종목별 조회용
+CREATE INDEX IF NOT EXISTS idx_strategy_decisions_ticker
+ON strategy_decisions (ticker, recorded_at DESC);
+
+COMMENT ON TABLE strategy_decisions IS '전략 의사결정 로그 (신호가 나오거나 나오지 않은 이유)';
@@ -0,0 +1,20 @@
+-- =====================================================
+-- 12_risk_circuit_breakers.sql
+-- 드로다운 / 연속 손실 서킷 브레이커 상태 저장
+-- =====================================================
+-- 포함 내용:
+-- 1. risk_circuit_breaker_state: RiskManager 서킷 브레이커 상태 (재시작 시 복원)
+-- =====================================================
+
+-- =====================================================
+-- RISK_CIRCUIT_BREAKER_STATE TABLE
+-- 리스크 매니저별 최신 서킷 브레이커 상태 1행
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS risk_circuit_breaker_state (
+    scope VARCHAR(50) PRIMARY KEY,                  -- 리스크 매니저 구분 (live)
+    state JSONB NOT NULL,                           -- 고점, 일별 자산, 연속 손실, 발동 중인 브레이커
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE risk_circuit_breaker_state IS '드로다운/연속 손실 서킷 브레이커 상태 (재시작 시 복원)';